@@ -1,7 +1,7 @@
 use node_runtime::{
     constants::{currency, MINUTES},
     days, dollars, hours, ChannelStateBloatBondValue, ContentConfig, ExpectedBlockTime,
-    VideoStateBloatBondValue,
+    PlaylistStateBloatBondValue, SubscriptionStateBloatBondValue, VideoStateBloatBondValue,
 };
 use sp_runtime::Perbill;
 
@@ -31,6 +31,8 @@ pub fn production_config() -> ContentConfig {
         nft_limits_enabled: true,
         channel_state_bloat_bond_value: ChannelStateBloatBondValue::get(),
         video_state_bloat_bond_value: VideoStateBloatBondValue::get(),
+        playlist_state_bloat_bond_value: PlaylistStateBloatBondValue::get(),
+        subscription_state_bloat_bond_value: SubscriptionStateBloatBondValue::get(),
     }
 }
 
@@ -60,5 +62,7 @@ pub fn testing_config() -> ContentConfig {
         nft_limits_enabled: false,
         channel_state_bloat_bond_value: ChannelStateBloatBondValue::get(),
         video_state_bloat_bond_value: VideoStateBloatBondValue::get(),
+        playlist_state_bloat_bond_value: PlaylistStateBloatBondValue::get(),
+        subscription_state_bloat_bond_value: SubscriptionStateBloatBondValue::get(),
     }
 }
@@ -335,6 +335,7 @@ pub fn testnet_genesis(
         storage: storage_cfg,
         project_token: project_token_cfg,
         proposals_discussion: Default::default(),
+        proposals_codex: Default::default(),
         members: Default::default(),
     }
 }
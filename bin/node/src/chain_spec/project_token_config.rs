@@ -11,6 +11,8 @@ pub fn production_config() -> ProjectTokenConfig {
         min_revenue_split_duration: days!(21),
         min_revenue_split_time_to_start: 0,
         sale_platform_fee: Permill::from_percent(2),
+        amm_trading_fee: Permill::from_percent(1),
+        amm_deactivation_threshold: Permill::from_percent(5),
         ..Default::default()
     }
 }
@@ -23,6 +25,8 @@ pub fn testing_config() -> ProjectTokenConfig {
         min_revenue_split_duration: 5,
         min_revenue_split_time_to_start: 0,
         sale_platform_fee: Permill::from_percent(2),
+        amm_trading_fee: Permill::from_percent(1),
+        amm_deactivation_threshold: Permill::from_percent(5),
         ..Default::default()
     }
 }
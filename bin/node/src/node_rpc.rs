@@ -30,10 +30,23 @@
 
 #![warn(missing_docs)]
 
+pub mod council;
+pub mod project_token;
+pub mod referendum;
+pub mod storage;
+
 use std::sync::Arc;
 
-use jsonrpsee::RpcModule;
-use node_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, Index};
+use jsonrpsee::{
+    core::Error as JsonRpseeError,
+    types::error::{CallError, ErrorObject},
+    RpcModule,
+};
+use node_runtime::{
+    opaque::Block, AccountId, ActorId, Balance, BlockNumber, ChannelId, DataObjectId,
+    DistributionBucketFamilyId, DistributionBucketIndex, Hash, Index, MemberId, StorageBucketId,
+    TokenId,
+};
 use sc_client_api::AuxStore;
 use sc_consensus_babe::{Config, Epoch};
 use sc_consensus_epochs::SharedEpochChanges;
@@ -50,6 +63,19 @@ use sp_consensus::SelectChain;
 use sp_consensus_babe::BabeApi;
 use sp_keystore::SyncCryptoStorePtr;
 
+/// Error code returned when a Joystream runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Converts a failed Joystream runtime API call into an RPC error.
+fn runtime_error(message: &str, error: impl std::fmt::Display) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        RUNTIME_ERROR,
+        message,
+        Some(error.to_string()),
+    ))
+    .into()
+}
+
 /// Extra dependencies for BABE.
 pub struct BabeDeps {
     /// BABE protocol config.
@@ -110,16 +136,58 @@ where
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BabeApi<Block>,
     C::Api: BlockBuilder<Block>,
+    C::Api: node_runtime::council::runtime_api::CouncilApi<
+        Block,
+        AccountId,
+        MemberId,
+        Balance,
+        BlockNumber,
+        Hash,
+        Balance,
+    >,
+    C::Api: node_runtime::referendum::runtime_api::ReferendumApi<
+        Block,
+        AccountId,
+        MemberId,
+        Balance,
+        BlockNumber,
+        Hash,
+        Balance,
+    >,
+    C::Api: node_runtime::project_token::runtime_api::ProjectTokenApi<
+        Block,
+        TokenId,
+        MemberId,
+        Balance,
+        Balance,
+        BlockNumber,
+    >,
+    C::Api: node_runtime::storage::runtime_api::StorageApi<
+        Block,
+        AccountId,
+        Balance,
+        MemberId,
+        ChannelId,
+        DataObjectId,
+        StorageBucketId,
+        DistributionBucketFamilyId,
+        DistributionBucketIndex,
+        ActorId,
+    >,
     P: TransactionPool + 'static,
     SC: SelectChain<Block> + 'static,
     B: sc_client_api::Backend<Block> + Send + Sync + 'static,
     B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashFor<Block>>,
 {
+    use council::{Council, CouncilApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+    use project_token::{ProjectToken, ProjectTokenApiServer};
+    use referendum::{Referendum, ReferendumApiServer};
     use sc_consensus_babe_rpc::{Babe, BabeApiServer};
     use sc_finality_grandpa_rpc::{Grandpa, GrandpaApiServer};
     use sc_rpc::dev::{Dev, DevApiServer};
     use sc_sync_state_rpc::{SyncState, SyncStateApiServer};
+    use storage::{Storage, StorageApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
     use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
 
@@ -185,6 +253,10 @@ where
     )?;
 
     io.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
+    io.merge(Council::new(client.clone()).into_rpc())?;
+    io.merge(Referendum::new(client.clone()).into_rpc())?;
+    io.merge(ProjectToken::new(client.clone()).into_rpc())?;
+    io.merge(Storage::new(client.clone()).into_rpc())?;
     io.merge(Dev::new(client, deny_unsafe).into_rpc())?;
 
     Ok(io)
@@ -0,0 +1,112 @@
+//! RPC methods exposing the council state.
+
+use std::sync::Arc;
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use node_runtime::council::{
+    runtime_api::CouncilApi as CouncilRuntimeApi, Candidate, CouncilMember, CouncilStageUpdate,
+};
+use node_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, MemberId};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+
+use super::runtime_error;
+
+/// Council state queries.
+#[rpc(server, namespace = "council")]
+pub trait CouncilApi {
+    /// Current council stage and the block it was entered at.
+    #[method(name = "stage")]
+    fn stage(&self, at: Option<Hash>) -> RpcResult<CouncilStageUpdate<BlockNumber>>;
+
+    /// Candidates announced in the current election cycle.
+    #[method(name = "candidates")]
+    fn candidates(
+        &self,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<(MemberId, Candidate<AccountId, Balance, Hash, Balance>)>>;
+
+    /// Members of the current council.
+    #[method(name = "councilors")]
+    fn councilors(
+        &self,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<CouncilMember<AccountId, MemberId, Balance, BlockNumber>>>;
+
+    /// Current council budget.
+    #[method(name = "budget")]
+    fn budget(&self, at: Option<Hash>) -> RpcResult<Balance>;
+
+    /// Block of the next council budget refill.
+    #[method(name = "nextBudgetRefill")]
+    fn next_budget_refill(&self, at: Option<Hash>) -> RpcResult<BlockNumber>;
+}
+
+/// Implementation of the council RPC methods.
+pub struct Council<C> {
+    client: Arc<C>,
+}
+
+impl<C> Council<C> {
+    /// Create new `Council` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> Council<C>
+where
+    C: HeaderBackend<Block>,
+{
+    fn block_id(&self, at: Option<Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+impl<C> CouncilApiServer for Council<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: CouncilRuntimeApi<Block, AccountId, MemberId, Balance, BlockNumber, Hash, Balance>,
+{
+    fn stage(&self, at: Option<Hash>) -> RpcResult<CouncilStageUpdate<BlockNumber>> {
+        self.client
+            .runtime_api()
+            .stage(&self.block_id(at))
+            .map_err(|e| runtime_error("Unable to query council stage.", e))
+    }
+
+    fn candidates(
+        &self,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<(MemberId, Candidate<AccountId, Balance, Hash, Balance>)>> {
+        self.client
+            .runtime_api()
+            .candidates(&self.block_id(at))
+            .map_err(|e| runtime_error("Unable to query council candidates.", e))
+    }
+
+    fn councilors(
+        &self,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<CouncilMember<AccountId, MemberId, Balance, BlockNumber>>> {
+        self.client
+            .runtime_api()
+            .councilors(&self.block_id(at))
+            .map_err(|e| runtime_error("Unable to query councilors.", e))
+    }
+
+    fn budget(&self, at: Option<Hash>) -> RpcResult<Balance> {
+        self.client
+            .runtime_api()
+            .budget(&self.block_id(at))
+            .map_err(|e| runtime_error("Unable to query council budget.", e))
+    }
+
+    fn next_budget_refill(&self, at: Option<Hash>) -> RpcResult<BlockNumber> {
+        self.client
+            .runtime_api()
+            .next_budget_refill(&self.block_id(at))
+            .map_err(|e| runtime_error("Unable to query next council budget refill.", e))
+    }
+}
@@ -0,0 +1,97 @@
+//! RPC methods quoting creator token sale purchases and revenue split dividends.
+
+use std::sync::Arc;
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use node_runtime::project_token::{
+    runtime_api::ProjectTokenApi as ProjectTokenRuntimeApi, types::SalePurchaseQuote,
+};
+use node_runtime::{opaque::Block, Balance, BlockNumber, Hash, MemberId, TokenId};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+
+use super::runtime_error;
+
+/// Creator token quotes.
+#[rpc(server, namespace = "projectToken")]
+pub trait ProjectTokenApi {
+    /// JOY cost, vesting schedule and violated preconditions of purchasing `amount` tokens
+    /// on the active sale of the token.
+    #[method(name = "quoteSalePurchase")]
+    fn quote_sale_purchase(
+        &self,
+        token_id: TokenId,
+        member_id: MemberId,
+        amount: Balance,
+        at: Option<Hash>,
+    ) -> RpcResult<SalePurchaseQuote<Balance, Balance, BlockNumber>>;
+
+    /// JOY dividend for staking `staked_amount` tokens in the current revenue split of the token.
+    #[method(name = "quoteSplitDividend")]
+    fn quote_split_dividend(
+        &self,
+        token_id: TokenId,
+        staked_amount: Balance,
+        at: Option<Hash>,
+    ) -> RpcResult<Balance>;
+}
+
+/// Implementation of the project token RPC methods.
+pub struct ProjectToken<C> {
+    client: Arc<C>,
+}
+
+impl<C> ProjectToken<C> {
+    /// Create new `ProjectToken` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> ProjectToken<C>
+where
+    C: HeaderBackend<Block>,
+{
+    fn block_id(&self, at: Option<Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+impl<C> ProjectTokenApiServer for ProjectToken<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ProjectTokenRuntimeApi<Block, TokenId, MemberId, Balance, Balance, BlockNumber>,
+{
+    fn quote_sale_purchase(
+        &self,
+        token_id: TokenId,
+        member_id: MemberId,
+        amount: Balance,
+        at: Option<Hash>,
+    ) -> RpcResult<SalePurchaseQuote<Balance, Balance, BlockNumber>> {
+        self.client
+            .runtime_api()
+            .quote_sale_purchase(&self.block_id(at), token_id, member_id, amount)
+            .map_err(|e| runtime_error("Unable to quote token sale purchase.", e))?
+            .map_err(|e| runtime_error("Token sale purchase cannot be quoted.", format!("{:?}", e)))
+    }
+
+    fn quote_split_dividend(
+        &self,
+        token_id: TokenId,
+        staked_amount: Balance,
+        at: Option<Hash>,
+    ) -> RpcResult<Balance> {
+        self.client
+            .runtime_api()
+            .quote_split_dividend(&self.block_id(at), token_id, staked_amount)
+            .map_err(|e| runtime_error("Unable to quote revenue split dividend.", e))?
+            .map_err(|e| {
+                runtime_error(
+                    "Revenue split dividend cannot be quoted.",
+                    format!("{:?}", e),
+                )
+            })
+    }
+}
@@ -0,0 +1,98 @@
+//! RPC methods exposing the council election referendum state.
+
+use std::sync::Arc;
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use node_runtime::referendum::{
+    runtime_api::ReferendumApi as ReferendumRuntimeApi, CastVote, OptionResult, ReferendumStage,
+};
+use node_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Hash, MemberId};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+
+use super::runtime_error;
+
+/// Referendum state queries.
+#[rpc(server, namespace = "referendum")]
+pub trait ReferendumApi {
+    /// Current referendum stage.
+    #[method(name = "stage")]
+    fn stage(
+        &self,
+        at: Option<Hash>,
+    ) -> RpcResult<ReferendumStage<BlockNumber, Vec<OptionResult<MemberId, Balance>>>>;
+
+    /// Options currently winning the referendum.
+    #[method(name = "intermediateWinners")]
+    fn intermediate_winners(
+        &self,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<OptionResult<MemberId, Balance>>>;
+
+    /// Vote cast from the given staking account.
+    #[method(name = "voteOf")]
+    fn vote_of(
+        &self,
+        account_id: AccountId,
+        at: Option<Hash>,
+    ) -> RpcResult<Option<CastVote<Hash, Balance, MemberId>>>;
+}
+
+/// Implementation of the referendum RPC methods.
+pub struct Referendum<C> {
+    client: Arc<C>,
+}
+
+impl<C> Referendum<C> {
+    /// Create new `Referendum` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> Referendum<C>
+where
+    C: HeaderBackend<Block>,
+{
+    fn block_id(&self, at: Option<Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+impl<C> ReferendumApiServer for Referendum<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ReferendumRuntimeApi<Block, AccountId, MemberId, Balance, BlockNumber, Hash, Balance>,
+{
+    fn stage(
+        &self,
+        at: Option<Hash>,
+    ) -> RpcResult<ReferendumStage<BlockNumber, Vec<OptionResult<MemberId, Balance>>>> {
+        self.client
+            .runtime_api()
+            .stage(&self.block_id(at))
+            .map_err(|e| runtime_error("Unable to query referendum stage.", e))
+    }
+
+    fn intermediate_winners(
+        &self,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<OptionResult<MemberId, Balance>>> {
+        self.client
+            .runtime_api()
+            .intermediate_winners(&self.block_id(at))
+            .map_err(|e| runtime_error("Unable to query referendum intermediate winners.", e))
+    }
+
+    fn vote_of(
+        &self,
+        account_id: AccountId,
+        at: Option<Hash>,
+    ) -> RpcResult<Option<CastVote<Hash, Balance, MemberId>>> {
+        self.client
+            .runtime_api()
+            .vote_of(&self.block_id(at), account_id)
+            .map_err(|e| runtime_error("Unable to query referendum vote.", e))
+    }
+}
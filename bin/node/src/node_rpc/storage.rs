@@ -0,0 +1,137 @@
+//! RPC methods querying storage bags, buckets, data objects and upload fees.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use node_runtime::storage::{
+    runtime_api::StorageApi as StorageRuntimeApi, BagIdType, BagInfo, DataObject,
+    DataObjectCreationParameters, UploadFeeQuote,
+};
+use node_runtime::{
+    common::bloat_bond::RepayableBloatBond, opaque::Block, AccountId, ActorId, Balance, ChannelId,
+    DataObjectId, DistributionBucketFamilyId, DistributionBucketIndex, Hash, MemberId,
+    StorageBucketId,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::generic::BlockId;
+
+use super::runtime_error;
+
+/// Bag identifier.
+pub type BagId = BagIdType<MemberId, ChannelId>;
+
+/// Bag with its storage and distribution buckets.
+pub type BagInfoOf = BagInfo<
+    StorageBucketId,
+    ActorId,
+    AccountId,
+    DistributionBucketFamilyId,
+    DistributionBucketIndex,
+>;
+
+/// Data object with its ID.
+pub type DataObjectWithId = (
+    DataObjectId,
+    DataObject<RepayableBloatBond<AccountId, Balance>, BTreeSet<StorageBucketId>>,
+);
+
+/// Storage bags, buckets and upload fees queries.
+#[rpc(server, namespace = "storage")]
+pub trait StorageApi {
+    /// Storage and distribution buckets of the bag, including the operators metadata.
+    #[method(name = "bagInfo")]
+    fn bag_info(&self, bag_id: BagId, at: Option<Hash>) -> RpcResult<BagInfoOf>;
+
+    /// At most `limit` data objects of the bag following the `start_after` data object,
+    /// in the storage order.
+    #[method(name = "bagDataObjects")]
+    fn bag_data_objects(
+        &self,
+        bag_id: BagId,
+        start_after: Option<DataObjectId>,
+        limit: u32,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<DataObjectWithId>>;
+
+    /// Data size fee and state bloat bonds required to upload the data objects.
+    #[method(name = "uploadFeeQuote")]
+    fn upload_fee_quote(
+        &self,
+        objects: Vec<DataObjectCreationParameters>,
+        at: Option<Hash>,
+    ) -> RpcResult<UploadFeeQuote<Balance>>;
+}
+
+/// Implementation of the storage RPC methods.
+pub struct Storage<C> {
+    client: Arc<C>,
+}
+
+impl<C> Storage<C> {
+    /// Create new `Storage` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> Storage<C>
+where
+    C: HeaderBackend<Block>,
+{
+    fn block_id(&self, at: Option<Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+impl<C> StorageApiServer for Storage<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: StorageRuntimeApi<
+        Block,
+        AccountId,
+        Balance,
+        MemberId,
+        ChannelId,
+        DataObjectId,
+        StorageBucketId,
+        DistributionBucketFamilyId,
+        DistributionBucketIndex,
+        ActorId,
+    >,
+{
+    fn bag_info(&self, bag_id: BagId, at: Option<Hash>) -> RpcResult<BagInfoOf> {
+        self.client
+            .runtime_api()
+            .bag_info(&self.block_id(at), bag_id)
+            .map_err(|e| runtime_error("Unable to query bag.", e))?
+            .map_err(|e| runtime_error("Bag cannot be queried.", format!("{:?}", e)))
+    }
+
+    fn bag_data_objects(
+        &self,
+        bag_id: BagId,
+        start_after: Option<DataObjectId>,
+        limit: u32,
+        at: Option<Hash>,
+    ) -> RpcResult<Vec<DataObjectWithId>> {
+        self.client
+            .runtime_api()
+            .bag_data_objects(&self.block_id(at), bag_id, start_after, limit)
+            .map_err(|e| runtime_error("Unable to query bag data objects.", e))?
+            .map_err(|e| runtime_error("Bag data objects cannot be queried.", format!("{:?}", e)))
+    }
+
+    fn upload_fee_quote(
+        &self,
+        objects: Vec<DataObjectCreationParameters>,
+        at: Option<Hash>,
+    ) -> RpcResult<UploadFeeQuote<Balance>> {
+        self.client
+            .runtime_api()
+            .upload_fee_quote(&self.block_id(at), objects)
+            .map_err(|e| runtime_error("Unable to quote upload fees.", e))?
+            .map_err(|e| runtime_error("Upload fees cannot be quoted.", format!("{:?}", e)))
+    }
+}
@@ -2,8 +2,8 @@
 
 use crate::{
     AssuranceContractType, BalanceOf, Bounties, BountyActor, BountyCreationParameters,
-    BountyMilestone, Call, Config, Entries, Event, FundingType, Module as Bounty,
-    OracleWorkEntryJudgment, Pallet,
+    BountyMilestone, BountyWorkMilestone, Call, Config, Entries, Event, FundingType,
+    MilestoneRewards, Module as Bounty, OracleWorkEntryJudgment, Pallet,
 };
 use balances::Pallet as Balances;
 use common::council::CouncilBudgetManager;
@@ -211,6 +211,14 @@ fn create_funded_bounty<T: Config>(params: BountyCreationParameters<T>) -> T::Bo
     bounty_id
 }
 
+// Single milestone with the whole funding due in the next block.
+fn single_milestone<T: Config>() -> Vec<BountyWorkMilestone<T::BlockNumber>> {
+    vec![BountyWorkMilestone {
+        deadline: System::<T>::block_number() + One::one(),
+        funding_share: Perbill::one(),
+    }]
+}
+
 const MAX_KILOBYTES_METADATA: u32 = 100;
 const SEED: u32 = 0;
 const _MAX_MEMBERS: u32 = 150; //Same as mocks
@@ -1975,6 +1983,262 @@ benchmarks! {
             Event::<T>::BountyCreatorRemarked(creator, bounty_id, msg).into()
         );
     }
+    submit_milestone_judgment_by_council {
+        let j in 0 .. MAX_KILOBYTES_METADATA; //rationale size,
+        let k in 0 .. MAX_KILOBYTES_METADATA; //sum of each action_justification size
+        let w in 1 .. MAX_WORK_ENTRIES_WINNERS; //total winner entries
+        let r in 1 .. MAX_WORK_ENTRIES_REJECTED; //total rejected entries
+
+        let rationale = vec![0u8].repeat((j * 1000) as usize);
+        let cherry: BalanceOf<T> = 100u32.into();
+        let oracle_reward: BalanceOf<T> = 100u32.into();
+        let funding_amount: BalanceOf<T> = 100u32.into();
+        let oracle = BountyActor::Council;
+        let entrant_stake: BalanceOf<T> = T::MinWorkEntrantStake::get();
+
+        let params = BountyCreationParameters::<T> {
+            creator: BountyActor::Council,
+            cherry,
+            oracle_reward,
+            entrant_stake,
+            funding_type: FundingType::Perpetual{ target: funding_amount },
+            oracle: oracle.clone(),
+            milestones: single_milestone::<T>(),
+            ..Default::default()
+        };
+
+        let bounty_id = create_funded_bounty::<T>(params);
+
+        let entries = (0..r+w)
+            .into_iter()
+            .map(|id| {
+                let entry_id =
+                    announce_entry_and_submit_work::<T>(&bounty_id, id.into());
+
+                let entry = <Entries<T>>::get(bounty_id, entry_id).unwrap();
+
+                (entry_id, entry)
+            })
+            .collect::<Vec<_>>();
+
+        let winner_reward: BalanceOf<T> = funding_amount / w.into();
+
+        let slashing_share = Perbill::from_percent(50);
+
+        let judgment = entries.clone().into_iter().map(|(entry_id, _)|{
+
+            let work_entry_judgment = if entry_id <= w.into() {
+
+                //Gives the correction ammount to the first winner entity,
+                //so the total sum is the milestone tranche
+                let corrected_winner_reward = if entry_id == w.into() {
+                    winner_reward + funding_amount % w.into()
+                } else {
+                    winner_reward
+                };
+                OracleWorkEntryJudgment::Winner {
+                    reward : corrected_winner_reward
+                }
+
+            } else {
+
+                OracleWorkEntryJudgment::Rejected {
+                    slashing_share,
+                    action_justification: match entry_id == (w + r).into() {
+                        true => vec![0u8].repeat(((k / r + k % r) * 1000) as usize),
+                        false => vec![0u8].repeat(((k / r) * 1000) as usize)
+                    },
+                }
+            };
+
+            (entry_id, work_entry_judgment)
+
+        }).collect::<BTreeMap<_, _>>();
+
+        run_to_block::<T>(System::<T>::block_number() + 2u32.into());
+
+    }: submit_milestone_judgment(
+        RawOrigin::Root, bounty_id, 0, judgment.clone(), rationale.clone())
+    verify {
+
+        for (entry_id, entry) in entries.into_iter(){
+            if entry_id <= w.into() {
+                assert!(<MilestoneRewards<T>>::get(bounty_id, entry_id) > Zero::zero());
+            }
+            else{
+                assert!(!<Entries<T>>::contains_key(bounty_id, entry_id));
+
+                assert_was_fired::<T>(
+                    Event::<T>::WorkEntrantStakeSlashed(
+                        bounty_id,
+                        entry_id,
+                        entry.staking_account_id,
+                        slashing_share * entrant_stake).into()
+                );
+            }
+        }
+
+        assert_last_event::<T>(
+            Event::<T>::MilestoneJudgmentSubmitted(
+                bounty_id, 0, oracle, judgment, rationale).into()
+        );
+    }
+
+    submit_milestone_judgment_by_member {
+        let j in 0 .. MAX_KILOBYTES_METADATA; //rationale size,
+        let k in 0 .. MAX_KILOBYTES_METADATA; //sum of each action_justification size
+        let w in 1 .. MAX_WORK_ENTRIES_WINNERS; //total winner entries
+        let r in 1 .. MAX_WORK_ENTRIES_REJECTED - 1; //total rejected entries - 1 for oracle
+
+        let rationale = vec![0u8].repeat((j * 1000) as usize);
+        let cherry: BalanceOf<T> = 100u32.into();
+        let oracle_reward: BalanceOf<T> = 100u32.into();
+        let funding_amount: BalanceOf<T> = 100u32.into();
+        let (oracle_account_id, oracle_member_id) =
+            member_funded_account::<T>(0);
+        let oracle = BountyActor::Member(oracle_member_id);
+        let entrant_stake: BalanceOf<T> = T::MinWorkEntrantStake::get();
+
+        let params = BountyCreationParameters::<T> {
+            creator: BountyActor::Council,
+            cherry,
+            oracle_reward,
+            entrant_stake,
+            funding_type: FundingType::Perpetual{ target: funding_amount },
+            oracle: oracle.clone(),
+            milestones: single_milestone::<T>(),
+            ..Default::default()
+        };
+
+        let bounty_id = create_funded_bounty::<T>(params);
+
+        let entries = (1..=r+w)
+            .into_iter()
+            .map(|id| {
+                let entry_id =
+                    announce_entry_and_submit_work::<T>(&bounty_id, id.into());
+
+                let entry = <Entries<T>>::get(bounty_id, entry_id).unwrap();
+
+                (entry_id, entry)
+            })
+            .collect::<Vec<_>>();
+
+        let winner_reward: BalanceOf<T> = funding_amount / w.into();
+
+        let slashing_share = Perbill::from_percent(50);
+
+        let judgment = entries.clone().into_iter().map(|(entry_id, _)|{
+
+            let work_entry_judgment = if entry_id <= w.into() {
+
+                //Gives the correction ammount to the first winner entity,
+                //so the total sum is the milestone tranche
+                let corrected_winner_reward = if entry_id == w.into() {
+                    winner_reward + funding_amount % w.into()
+                } else {
+                    winner_reward
+                };
+                OracleWorkEntryJudgment::Winner {
+                    reward : corrected_winner_reward
+                }
+
+            } else {
+
+                OracleWorkEntryJudgment::Rejected {
+                    slashing_share,
+                    action_justification: match entry_id == (w + r).into() {
+                        true => vec![0u8].repeat(((k / r + k % r) * 1000) as usize),
+                        false => vec![0u8].repeat(((k / r) * 1000) as usize)
+                    },
+                }
+            };
+
+            (entry_id, work_entry_judgment)
+
+        }).collect::<BTreeMap<_, _>>();
+
+        run_to_block::<T>(System::<T>::block_number() + 2u32.into());
+
+    }: submit_milestone_judgment(
+        RawOrigin::Signed(oracle_account_id),
+        bounty_id,
+        0,
+        judgment.clone(),
+        rationale.clone())
+    verify {
+
+        for (entry_id, entry) in entries.into_iter(){
+            if entry_id <= w.into(){
+                assert!(<MilestoneRewards<T>>::get(bounty_id, entry_id) > Zero::zero());
+            }
+            else{
+                assert!(!<Entries<T>>::contains_key(bounty_id, entry_id));
+
+                assert_was_fired::<T>(
+                    Event::<T>::WorkEntrantStakeSlashed(
+                        bounty_id,
+                        entry_id,
+                        entry.staking_account_id,
+                        slashing_share * entrant_stake).into()
+                );
+            }
+        }
+
+        assert_last_event::<T>(
+            Event::<T>::MilestoneJudgmentSubmitted(
+                bounty_id, 0, oracle, judgment, rationale).into()
+        );
+    }
+
+    withdraw_milestone_reward {
+        let cherry: BalanceOf<T> = 100u32.into();
+        let oracle_reward: BalanceOf<T> = 100u32.into();
+        let funding_amount: BalanceOf<T> = 100u32.into();
+        let oracle = BountyActor::Council;
+        let entrant_stake: BalanceOf<T> = T::MinWorkEntrantStake::get();
+
+        let params = BountyCreationParameters::<T> {
+            creator: BountyActor::Council,
+            cherry,
+            oracle_reward,
+            entrant_stake,
+            funding_type: FundingType::Perpetual{ target: funding_amount },
+            oracle,
+            milestones: single_milestone::<T>(),
+            ..Default::default()
+        };
+
+        let bounty_id = create_funded_bounty::<T>(params);
+
+        let entry_id = announce_entry_and_submit_work::<T>(&bounty_id, 1);
+        let entry = <Entries<T>>::get(bounty_id, entry_id).unwrap();
+        let member_id = entry.member_id;
+
+        let judgment = vec![entry_id].iter()
+            .map(|entry_id| (
+                *entry_id,
+                OracleWorkEntryJudgment::Winner {reward : funding_amount}))
+            .collect::<BTreeMap<_, _>>();
+
+        run_to_block::<T>(System::<T>::block_number() + 2u32.into());
+
+        Bounty::<T>::submit_milestone_judgment(
+            RawOrigin::Root.into(),
+            bounty_id,
+            0,
+            judgment,
+            Vec::new()
+        ).unwrap();
+
+    }: _(RawOrigin::Signed(entry.staking_account_id), member_id, bounty_id, entry_id)
+    verify {
+        assert!(!<MilestoneRewards<T>>::contains_key(bounty_id, entry_id));
+        assert_last_event::<T>(
+            Event::<T>::MilestoneRewardWithdrawn(
+                bounty_id, entry_id, member_id, funding_amount).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -2203,4 +2467,24 @@ mod tests {
             assert_ok!(Bounty::test_benchmark_creator_remark());
         });
     }
+    #[test]
+    fn submit_milestone_judgment_by_council() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_submit_milestone_judgment_by_council());
+        });
+    }
+
+    #[test]
+    fn submit_milestone_judgment_by_member() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_submit_milestone_judgment_by_member());
+        });
+    }
+
+    #[test]
+    fn withdraw_milestone_reward() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_withdraw_milestone_reward());
+        });
+    }
 }
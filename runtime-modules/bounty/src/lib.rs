@@ -14,6 +14,13 @@
 //!     along with a split cherry, none judged work entrants can unlock their stakes,
 //!     Oracle can withdraw his reward,
 //!
+//! ### Milestone bounties
+//! A bounty created with a non-empty milestone list pays its funding in tranches. Each milestone
+//! defines a deadline and a share of the total funding. After the milestone deadline the oracle
+//! judges the milestone work, and the winners can withdraw their tranche right away. The bounty
+//! succeeds when its last milestone is approved and fails when a milestone judgment has no
+//! winners. Contributors of a failed milestone bounty get back the unpaid share of their funding.
+//!
 //! A detailed description could be found [here](https://github.com/Joystream/joystream/issues/1998).
 //!
 //! ### Supported extrinsics
@@ -36,10 +43,18 @@
 //! - [submit_work](./struct.Module.html#method.submit_work) - submit work for a bounty.
 //! - [end_working_period](./struct.Module.html#method.end_working_period) - end working period by oracle.
 //! - [terminate_bounty](./struct.Module.html#method.terminate_bounty) - terminate bounty (into failed stage or remove bounty).
+//! - [submit_milestone_judgment](./struct.Module.html#method.submit_milestone_judgment) - submits an
+//! oracle judgment for a milestone of a milestone bounty.
+//! - [withdraw_milestone_reward](./struct.Module.html#method.withdraw_milestone_reward) - withdraw
+//! approved milestone rewards.
 //!
 //! #### Judgment stage
 //! - [submit_oracle_judgment](./struct.Module.html#method.submit_oracle_judgment) - submits an
 //! oracle judgment for a bounty.
+//! - [submit_milestone_judgment](./struct.Module.html#method.submit_milestone_judgment) - submits an
+//! oracle judgment for a milestone of a milestone bounty.
+//! - [withdraw_milestone_reward](./struct.Module.html#method.withdraw_milestone_reward) - withdraw
+//! approved milestone rewards.
 //!  - [switch_oracle](./struct.Module.html#method.switch_oracle) - switch the current oracle
 //! by another one.
 //! - [terminate_bounty](./struct.Module.html#method.terminate_bounty) - terminate bounty (into failed stage or remove bounty).
@@ -47,6 +62,8 @@
 //! #### SuccessfulBountyWithdrawal stage
 //! - [withdraw_entrant_stake](./struct.Module.html#method.withdraw_entrant_stake) -
 //! unlock stake accounts refering to none judged work entries.
+//! - [withdraw_milestone_reward](./struct.Module.html#method.withdraw_milestone_reward) - withdraw
+//! approved milestone rewards.
 //!  - [withdraw_funding](./struct.Module.html#method.withdraw_funding) -
 //! withdraw contributor's state bloat bond.
 //!
@@ -80,6 +97,7 @@ extern crate common;
 pub(crate) mod tests;
 
 mod actors;
+mod migrations;
 mod stages;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -107,7 +125,7 @@ use common::membership::{
 };
 use common::to_kb;
 use frame_support::dispatch::{DispatchError, DispatchResult};
-use frame_support::storage::bounded_btree_set::BoundedBTreeSet;
+use frame_support::storage::{bounded_btree_set::BoundedBTreeSet, bounded_vec::BoundedVec};
 use frame_support::traits::{Currency, ExistenceRequirement, Get, LockIdentifier};
 use frame_support::weights::Weight;
 use frame_support::{
@@ -173,6 +191,9 @@ pub trait Config:
     /// Current state bloat bond a creator has to pay to create a bounty.
     /// The creator can withdraw the bond after he or someone else removes the bounty
     type CreatorStateBloatBondAmount: Get<BalanceOf<Self>>;
+
+    /// Defines max milestone number for a milestone bounty.
+    type MaxMilestonesPerBounty: Get<u32>;
 }
 
 /// Alias type for the BountyParameters.
@@ -181,6 +202,7 @@ pub type BountyCreationParameters<T> = BountyParameters<
     <T as frame_system::Config>::BlockNumber,
     <T as MembershipTypes>::MemberId,
     BTreeSet<<T as MembershipTypes>::MemberId>,
+    Vec<BountyWorkMilestone<<T as frame_system::Config>::BlockNumber>>,
 >;
 
 /// Alias type for stored BountyParameters.
@@ -189,6 +211,7 @@ pub type BountyStoredCreationParameters<T> = BountyParameters<
     <T as frame_system::Config>::BlockNumber,
     <T as MembershipTypes>::MemberId,
     ClosedContractWhitelist<T>,
+    BountyWorkMilestones<T>,
 >;
 
 /// Defines who can submit the work.
@@ -239,10 +262,32 @@ impl<BlockNumber, Balance: Default> Default for FundingType<BlockNumber, Balance
     }
 }
 
+/// Defines a work milestone of a milestone bounty.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BountyWorkMilestone<BlockNumber> {
+    /// Last block of the milestone work. The oracle can judge the milestone after it.
+    pub deadline: BlockNumber,
+
+    /// The share (0 - 1) of the total bounty funding paid out to the milestone winners.
+    pub funding_share: Perbill,
+}
+
+pub type BountyWorkMilestones<T> = BoundedVec<
+    BountyWorkMilestone<<T as frame_system::Config>::BlockNumber>,
+    <T as Config>::MaxMilestonesPerBounty,
+>;
+
 /// Defines parameters for the bounty creation.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct BountyParameters<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist> {
+pub struct BountyParameters<
+    Balance,
+    BlockNumber,
+    MemberId: Ord,
+    ClosedContractWhitelist,
+    Milestones,
+> {
     /// Origin that will select winner(s), is either a given member or a council.
     pub oracle: BountyActor<MemberId>,
 
@@ -269,23 +314,49 @@ pub struct BountyParameters<Balance, BlockNumber, MemberId: Ord, ClosedContractW
 
     /// Defines parameters for different funding types.
     pub funding_type: FundingType<BlockNumber, Balance>,
+
+    /// Work milestones with staged payouts. The bounty has a single judgment when empty.
+    pub milestones: Milestones,
 }
 
-impl<Balance: Clone, BlockNumber: Clone, MemberId: Ord + Clone, ClosedContractSizeLimit>
-    TryFrom<BountyParameters<Balance, BlockNumber, MemberId, BTreeSet<MemberId>>>
+impl<
+        Balance: Clone,
+        BlockNumber: Clone,
+        MemberId: Ord + Clone,
+        ClosedContractSizeLimit,
+        MaxMilestonesPerBounty,
+    >
+    TryFrom<
+        BountyParameters<
+            Balance,
+            BlockNumber,
+            MemberId,
+            BTreeSet<MemberId>,
+            Vec<BountyWorkMilestone<BlockNumber>>,
+        >,
+    >
     for BountyParameters<
         Balance,
         BlockNumber,
         MemberId,
         BoundedBTreeSet<MemberId, ClosedContractSizeLimit>,
+        BoundedVec<BountyWorkMilestone<BlockNumber>, MaxMilestonesPerBounty>,
     >
 where
     BoundedBTreeSet<MemberId, ClosedContractSizeLimit>: TryFrom<BTreeSet<MemberId>>,
+    BoundedVec<BountyWorkMilestone<BlockNumber>, MaxMilestonesPerBounty>:
+        TryFrom<Vec<BountyWorkMilestone<BlockNumber>>>,
 {
     type Error = ();
 
     fn try_from(
-        params: BountyParameters<Balance, BlockNumber, MemberId, BTreeSet<MemberId>>,
+        params: BountyParameters<
+            Balance,
+            BlockNumber,
+            MemberId,
+            BTreeSet<MemberId>,
+            Vec<BountyWorkMilestone<BlockNumber>>,
+        >,
     ) -> Result<Self, Self::Error> {
         let contract_type = match params.contract_type.clone() {
             AssuranceContractType::Closed(whitelist) => {
@@ -303,6 +374,7 @@ where
             oracle_reward: params.oracle_reward,
             entrant_stake: params.entrant_stake,
             funding_type: params.funding_type,
+            milestones: params.milestones.try_into().map_err(|_| ())?,
         })
     }
 }
@@ -417,14 +489,16 @@ pub type Bounty<T> = BountyRecord<
     <T as frame_system::Config>::BlockNumber,
     <T as common::membership::MembershipTypes>::MemberId,
     ClosedContractWhitelist<T>,
+    BountyWorkMilestones<T>,
 >;
 
 /// Crowdfunded bounty record.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct BountyRecord<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist> {
+pub struct BountyRecord<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist, Milestones> {
     /// Bounty creation parameters.
-    pub creation_params: BountyParameters<Balance, BlockNumber, MemberId, ClosedContractWhitelist>,
+    pub creation_params:
+        BountyParameters<Balance, BlockNumber, MemberId, ClosedContractWhitelist, Milestones>,
 
     /// Total funding balance reached so far.
     /// Includes initial funding by a creator and other members funding.
@@ -441,8 +515,13 @@ pub struct BountyRecord<Balance, BlockNumber, MemberId: Ord, ClosedContractWhite
     pub has_unpaid_oracle_reward: bool,
 }
 
-impl<Balance: PartialOrd + Clone, BlockNumber: Clone, MemberId: Ord, ClosedContractWhitelist>
-    BountyRecord<Balance, BlockNumber, MemberId, ClosedContractWhitelist>
+impl<
+        Balance: PartialOrd + Clone,
+        BlockNumber: Clone,
+        MemberId: Ord,
+        ClosedContractWhitelist,
+        Milestones,
+    > BountyRecord<Balance, BlockNumber, MemberId, ClosedContractWhitelist, Milestones>
 {
     // Increments bounty active work entry counter.
     fn increment_active_work_entry_counter(&mut self) {
@@ -553,9 +632,10 @@ impl<T: Config> Contribution<T> {
         }
     }
 
-    ///Returns the sum of state bloat and amount
-    fn total_bloat_bond_and_funding(&self) -> BalanceOf<T> {
-        self.amount
+    ///Returns the sum of state bloat and the unpaid share of the amount
+    fn unpaid_funding_and_bloat_bond(&self, unpaid_funding_share: Perbill) -> BalanceOf<T> {
+        unpaid_funding_share
+            .mul_floor(self.amount)
             .saturating_add(self.funder_state_bloat_bond_amount)
     }
 }
@@ -608,6 +688,15 @@ decl_storage! { generate_storage_info
 
         /// Count of all work entries that have been created.
         pub EntryCount get(fn entry_count): u32;
+
+        /// Number of approved milestones for the milestone bounties.
+        pub ApprovedMilestoneCount get(fn approved_milestone_count):
+            map hasher(blake2_128_concat) T::BountyId => u32;
+
+        /// Approved milestone rewards not yet withdrawn by the work entrants.
+        pub MilestoneRewards get(fn milestone_rewards): double_map
+            hasher(blake2_128_concat) T::BountyId,
+            hasher(blake2_128_concat) T::EntryId => BalanceOf<T>;
     }
 }
 
@@ -795,6 +884,23 @@ decl_event! {
             BountyId,
             BountyActor<MemberId>,
             Balance),
+
+        /// Submit oracle judgment for a bounty milestone.
+        /// Params:
+        /// - bounty ID
+        /// - milestone index
+        /// - oracle
+        /// - judgment data
+        /// - rationale
+        MilestoneJudgmentSubmitted(BountyId, u32, BountyActor<MemberId>, OracleJudgment, Vec<u8>),
+
+        /// Work entrant has withdrawn the approved milestone rewards.
+        /// Params:
+        /// - bounty ID
+        /// - entry ID
+        /// - entrant member ID
+        /// - reward amount
+        MilestoneRewardWithdrawn(BountyId, EntryId, MemberId, Balance),
     }
 }
 
@@ -906,7 +1012,38 @@ decl_error! {
         WorkEntryDoesntBelongToWorker,
 
         ///Oracle have already been withdrawn
-        OracleRewardAlreadyWithdrawn
+        OracleRewardAlreadyWithdrawn,
+
+        /// Cannot create a milestone bounty with milestone list larger than allowed max
+        /// milestone limit.
+        MilestoneListIsTooLarge,
+
+        /// Cannot create a milestone bounty with zero milestone funding share.
+        MilestoneFundingShareCannotBeZero,
+
+        /// The milestone funding shares should sum to 100%.
+        MilestoneFundingSharesShouldSumToOne,
+
+        /// Milestone deadlines should be in the future and increase with each milestone.
+        InvalidMilestoneDeadline,
+
+        /// The bounty has no milestones.
+        NotAMilestoneBounty,
+
+        /// Milestone bounties are judged per milestone.
+        JudgmentNotAllowedForMilestoneBounty,
+
+        /// Milestones should be judged in order.
+        UnexpectedMilestoneIndex,
+
+        /// The milestone work deadline has not passed yet.
+        MilestoneDeadlineNotReached,
+
+        /// The total reward for winners should be equal to the milestone funding tranche.
+        TotalRewardShouldBeEqualToMilestoneTranche,
+
+        /// The work entry has no approved milestone rewards.
+        NoMilestoneRewardToWithdraw
     }
 }
 
@@ -931,6 +1068,9 @@ decl_module! {
         /// Exports const - creator state bloat bond amount for a bounty.
         const CreatorStateBloatBondAmount: BalanceOf<T> = T::CreatorStateBloatBondAmount::get();
 
+        /// Exports const - max milestone number for a milestone bounty.
+        const MaxMilestonesPerBounty: u32 = T::MaxMilestonesPerBounty::get();
+
         /// Creates a bounty. Metadata stored in the transaction log but discarded after that.
         /// <weight>
         ///
@@ -1443,13 +1583,26 @@ decl_module! {
                 bounty.creation_params.oracle.clone(),
             )?;
 
+            ensure!(
+                bounty.creation_params.milestones.is_empty(),
+                Error::<T>::JudgmentNotAllowedForMilestoneBounty
+            );
+
             let bounty_creator_manager = Self::ensure_creator_actor_manager(&bounty)?;
 
             let current_bounty_stage = Self::get_bounty_stage(&bounty);
 
             Self::ensure_bounty_stage(current_bounty_stage, BountyStage::Judgment)?;
 
-            Self::validate_judgment(&bounty_id, &bounty, &judgment)?;
+            let reward_sum_from_judgment = Self::validate_judgment(&bounty_id, &judgment)?;
+
+            // Check for invalid total sum for successful bounty.
+            if reward_sum_from_judgment != Zero::zero() {
+                ensure!(
+                    reward_sum_from_judgment == bounty.total_funding, // 100% bounty distribution
+                    Error::<T>::TotalRewardShouldBeEqualToTotalFunding
+                );
+            }
 
             // Lookup for any winners in the judgment.
             let successful_bounty = Self::judgment_has_winners(&judgment);
@@ -1501,39 +1654,198 @@ decl_module! {
                         slashing_share,
                         ..
                     } => {
+                        Self::slash_rejected_work_entry(
+                            &bounty_id,
+                            &bounty,
+                            entry_id,
+                            entry,
+                            slashing_share
+                        )?;
+                    }
+                }
+            }
+            // Fire a judgment event.
+            Self::deposit_event(RawEvent::OracleJudgmentSubmitted(
+                bounty_id,
+                bounty.creation_params.oracle,
+                judgment,
+                rationale,
+            ));
+        }
 
-                        let slashing_amount = slashing_share * bounty.creation_params.entrant_stake;
+        /// Submits an oracle judgment for a milestone of a milestone bounty. Milestones are judged
+        /// in order after their deadlines. The winners share the milestone funding tranche and can
+        /// withdraw it right away, the rejected entries are slashed. A judgment without winners
+        /// fails the bounty, the last milestone approval makes the bounty successful.
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (J + K + W + R)`
+        /// - `J` is rationale size in kilobytes,
+        /// - `K` is the sum of all action_justification sizes (in kilobytes) inside OracleJudgment,
+        /// - `W` is number of winner judgment entries,
+        /// - `R` is number of rejected judgment entries,
+        /// - db:
+        ///    - `O(W + R)`
+        /// # </weight>
+        #[weight = Module::<T>::submit_milestone_judgment_weight(
+            judgment,
+            to_kb(rationale.len().saturated_into())
+        )]
+        pub fn submit_milestone_judgment(
+            origin,
+            bounty_id: T::BountyId,
+            milestone_index: u32,
+            judgment: OracleJudgment<T::EntryId, BalanceOf<T>>,
+            rationale: Vec<u8>,
+        ) {
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+            BountyActorManager::<T>::ensure_bounty_actor_manager(
+                origin,
+                bounty.creation_params.oracle.clone(),
+            )?;
 
-                        if slashing_amount > Zero::zero() {
-                            T::StakingHandler::slash(&entry.staking_account_id, Some(slashing_amount));
-                        }
+            let bounty_creator_manager = Self::ensure_creator_actor_manager(&bounty)?;
 
-                        T::StakingHandler::unlock(&entry.staking_account_id);
+            let current_bounty_stage = Self::get_bounty_stage(&bounty);
 
-                        Self::remove_work_entry(&bounty_id, entry_id);
+            ensure!(
+                matches!(current_bounty_stage,
+                    BountyStage::WorkSubmission |
+                    BountyStage::Judgment),
+                Self::unexpected_bounty_stage_error(current_bounty_stage)
+            );
 
-                        // Fire a WorkEntrantStakeSlashed event.
-                        Self::deposit_event(RawEvent::WorkEntrantStakeSlashed(
-                            bounty_id,
-                            *entry_id,
-                            entry.staking_account_id,
-                            slashing_amount
-                        ));
+            Self::ensure_milestone_can_be_judged(&bounty_id, &bounty, milestone_index)?;
+
+            let reward_sum_from_judgment = Self::validate_judgment(&bounty_id, &judgment)?;
+
+            // Lookup for any winners in the judgment.
+            let milestone_approved = Self::judgment_has_winners(&judgment);
+
+            if milestone_approved {
+                ensure!(
+                    reward_sum_from_judgment ==
+                        Self::get_milestone_tranche(&bounty, milestone_index),
+                    Error::<T>::TotalRewardShouldBeEqualToMilestoneTranche
+                );
+            }
+
+            let last_milestone = milestone_index.saturating_add(1) ==
+                bounty.creation_params.milestones.len().saturated_into::<u32>();
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if milestone_approved {
+                ApprovedMilestoneCount::<T>::insert(bounty_id, milestone_index.saturating_add(1));
+            }
+
+            // The bounty ends on the last milestone approval or on a failed milestone.
+            if last_milestone || !milestone_approved {
+                // Return a cherry to a creator.
+                if milestone_approved {
+                    Self::return_bounty_cherry_to_creator(
+                        bounty_id,
+                        &bounty,
+                        &bounty_creator_manager
+                    );
+                }
+
+                <Bounties<T>>::mutate(bounty_id, |bounty| {
+                    bounty.milestone = BountyMilestone::JudgmentSubmitted{
+                        successful_bounty: milestone_approved
+                    };
+                });
+            }
+
+            // Judgments triage.
+            for (entry_id, work_entry_judgment) in judgment.iter() {
+
+                let entry = Self::ensure_work_entry_exists(&bounty_id, entry_id)?;
+
+                match *work_entry_judgment{
+                    OracleWorkEntryJudgment::Winner{ reward } => {
+                        // Winner work entries are kept for the next milestones.
+                        <MilestoneRewards<T>>::mutate(bounty_id, entry_id, |milestone_reward| {
+                            *milestone_reward = milestone_reward.saturating_add(reward);
+                        });
+                    },
+                    OracleWorkEntryJudgment::Rejected{
+                        slashing_share,
+                        ..
+                    } => {
+                        Self::slash_rejected_work_entry(
+                            &bounty_id,
+                            &bounty,
+                            entry_id,
+                            entry,
+                            slashing_share
+                        )?;
                     }
                 }
             }
+
             // Fire a judgment event.
-            Self::deposit_event(RawEvent::OracleJudgmentSubmitted(
+            Self::deposit_event(RawEvent::MilestoneJudgmentSubmitted(
                 bounty_id,
+                milestone_index,
                 bounty.creation_params.oracle,
                 judgment,
                 rationale,
             ));
         }
 
+        /// Withdraws the approved milestone rewards of a work entry.
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoBounty::<T>::withdraw_milestone_reward()]
+        pub fn withdraw_milestone_reward(
+            origin,
+            member_id: MemberId<T>,
+            bounty_id: T::BountyId,
+            entry_id: T::EntryId,
+        ) {
+            T::Membership::ensure_member_controller_account_origin(origin, member_id)?;
+
+            Self::ensure_bounty_exists(&bounty_id)?;
+
+            let entry = Self::ensure_work_entry_exists(&bounty_id, &entry_id)?;
+
+            Self::ensure_work_entry_ownership(&entry, &member_id)?;
+
+            let milestone_reward = Self::milestone_rewards(bounty_id, entry_id);
+
+            ensure!(
+                milestone_reward != Zero::zero(),
+                Error::<T>::NoMilestoneRewardToWithdraw
+            );
+
+            let worker_account_id = T::Membership::controller_account_id(member_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::withdraw_milestone_reward_mutation(
+                &bounty_id,
+                &entry_id,
+                member_id,
+                &worker_account_id,
+                milestone_reward
+            );
+        }
+
         ///Unlocks the stake related to a work entry
         ///After the oracle makes the judgment or the council terminates the bounty by calling terminate_bounty(...),
         ///each worker whose entry has not been judged, can unlock the totality of their stake.
+        ///Approved milestone rewards of the work entry are withdrawn as well.
         /// # <weight>
         ///
         /// ## weight
@@ -1569,10 +1881,28 @@ decl_module! {
                 bounty.creation_params.creator.clone(),
             )?;
 
+            let milestone_reward = Self::milestone_rewards(bounty_id, entry_id);
+
+            let worker_account_id = if milestone_reward != Zero::zero() {
+                Some(T::Membership::controller_account_id(member_id)?)
+            } else {
+                None
+            };
+
             //
             // == MUTATION SAFE ==
             //
 
+            if let Some(worker_account_id) = worker_account_id {
+                Self::withdraw_milestone_reward_mutation(
+                    &bounty_id,
+                    &entry_id,
+                    member_id,
+                    &worker_account_id,
+                    milestone_reward
+                );
+            }
+
             T::StakingHandler::unlock(&entry.staking_account_id);
 
             Self::deposit_event(
@@ -1807,8 +2137,11 @@ impl<T: Config> Module<T> {
     ) {
         let cherry_fraction = Self::get_cherry_fraction_for_member(bounty, funding.amount);
 
+        // Milestone rewards already paid out are not returned.
+        let unpaid_funding_share = Self::get_unpaid_funding_share(bounty_id, bounty);
+
         let withdrawal_amount = funding
-            .total_bloat_bond_and_funding()
+            .unpaid_funding_and_bloat_bond(unpaid_funding_share)
             .saturating_add(cherry_fraction);
 
         bounty_funder_manager.transfer_funds_from_bounty_account(*bounty_id, withdrawal_amount);
@@ -1914,6 +2247,48 @@ impl<T: Config> Module<T> {
             );
         }
 
+        Self::ensure_milestones_valid(&params.milestones)?;
+
+        Ok(())
+    }
+
+    // Validates milestones for a bounty creation. Empty milestone list is valid.
+    fn ensure_milestones_valid(
+        milestones: &[BountyWorkMilestone<T::BlockNumber>],
+    ) -> DispatchResult {
+        if milestones.is_empty() {
+            return Ok(());
+        }
+
+        ensure!(
+            milestones.len() <= T::MaxMilestonesPerBounty::get().saturated_into(),
+            Error::<T>::MilestoneListIsTooLarge
+        );
+
+        let mut previous_deadline = Self::current_block();
+        let mut total_share_parts: u64 = 0;
+
+        for milestone in milestones {
+            ensure!(
+                !milestone.funding_share.is_zero(),
+                Error::<T>::MilestoneFundingShareCannotBeZero
+            );
+
+            ensure!(
+                milestone.deadline > previous_deadline,
+                Error::<T>::InvalidMilestoneDeadline
+            );
+
+            previous_deadline = milestone.deadline;
+            total_share_parts =
+                total_share_parts.saturating_add(milestone.funding_share.deconstruct().into());
+        }
+
+        ensure!(
+            total_share_parts == u64::from(Perbill::one().deconstruct()),
+            Error::<T>::MilestoneFundingSharesShouldSumToOne
+        );
+
         Ok(())
     }
 
@@ -2072,6 +2447,7 @@ impl<T: Config> Module<T> {
         ));
 
         <Bounties<T>>::remove(bounty_id);
+        ApprovedMilestoneCount::<T>::remove(bounty_id);
 
         Self::deposit_event(RawEvent::BountyRemoved(*bounty_id));
     }
@@ -2239,12 +2615,11 @@ impl<T: Config> Module<T> {
         sc.get_bounty_stage()
     }
 
-    // Validates oracle judgment.
+    // Validates oracle judgment. Returns the total winner reward.
     fn validate_judgment(
         bounty_id: &T::BountyId,
-        bounty: &Bounty<T>,
         judgment: &OracleJudgmentOf<T>,
-    ) -> DispatchResult {
+    ) -> Result<BalanceOf<T>, DispatchError> {
         // Total judgment reward accumulator.
         let mut reward_sum_from_judgment: BalanceOf<T> = Zero::zero();
 
@@ -2265,17 +2640,139 @@ impl<T: Config> Module<T> {
             }
         }
 
-        // Check for invalid total sum for successful bounty.
-        if reward_sum_from_judgment != Zero::zero() {
-            ensure!(
-                reward_sum_from_judgment == bounty.total_funding, // 100% bounty distribution
-                Error::<T>::TotalRewardShouldBeEqualToTotalFunding
+        Ok(reward_sum_from_judgment)
+    }
+
+    // Slashes the rejected work entry stake, pays the approved milestone rewards
+    // and removes the work entry. The work entrant membership is checked by validate_judgment().
+    fn slash_rejected_work_entry(
+        bounty_id: &T::BountyId,
+        bounty: &Bounty<T>,
+        entry_id: &T::EntryId,
+        entry: Entry<T>,
+        slashing_share: Perbill,
+    ) -> DispatchResult {
+        let worker_account_id = T::Membership::controller_account_id(entry.member_id)?;
+
+        let slashing_amount = slashing_share * bounty.creation_params.entrant_stake;
+
+        if slashing_amount > Zero::zero() {
+            T::StakingHandler::slash(&entry.staking_account_id, Some(slashing_amount));
+        }
+
+        T::StakingHandler::unlock(&entry.staking_account_id);
+
+        // Approved milestone rewards were earned before the rejection.
+        let milestone_reward = Self::milestone_rewards(bounty_id, entry_id);
+        if milestone_reward != Zero::zero() {
+            Self::withdraw_milestone_reward_mutation(
+                bounty_id,
+                entry_id,
+                entry.member_id,
+                &worker_account_id,
+                milestone_reward,
             );
         }
 
+        Self::remove_work_entry(bounty_id, entry_id);
+
+        // Fire a WorkEntrantStakeSlashed event.
+        Self::deposit_event(RawEvent::WorkEntrantStakeSlashed(
+            *bounty_id,
+            *entry_id,
+            entry.staking_account_id,
+            slashing_amount,
+        ));
+
+        Ok(())
+    }
+
+    // Transfers the approved milestone rewards to the work entrant and fires an event.
+    fn withdraw_milestone_reward_mutation(
+        bounty_id: &T::BountyId,
+        entry_id: &T::EntryId,
+        member_id: MemberId<T>,
+        worker_account_id: &T::AccountId,
+        reward: BalanceOf<T>,
+    ) {
+        Self::transfer_funds_from_bounty_account(worker_account_id, *bounty_id, reward);
+
+        <MilestoneRewards<T>>::remove(bounty_id, entry_id);
+
+        Self::deposit_event(RawEvent::MilestoneRewardWithdrawn(
+            *bounty_id, *entry_id, member_id, reward,
+        ));
+    }
+
+    // Verifies that the milestone is the next one to judge and its deadline has passed.
+    fn ensure_milestone_can_be_judged(
+        bounty_id: &T::BountyId,
+        bounty: &Bounty<T>,
+        milestone_index: u32,
+    ) -> DispatchResult {
+        ensure!(
+            !bounty.creation_params.milestones.is_empty(),
+            Error::<T>::NotAMilestoneBounty
+        );
+
+        ensure!(
+            milestone_index == Self::approved_milestone_count(bounty_id),
+            Error::<T>::UnexpectedMilestoneIndex
+        );
+
+        let milestone = bounty
+            .creation_params
+            .milestones
+            .get(milestone_index.saturated_into::<usize>())
+            .ok_or(Error::<T>::UnexpectedMilestoneIndex)?;
+
+        ensure!(
+            Self::current_block() > milestone.deadline,
+            Error::<T>::MilestoneDeadlineNotReached
+        );
+
         Ok(())
     }
 
+    // Calculates the funding tranche of the milestone. The last milestone gets
+    // the funding left after the previous tranches, so no rounding leftovers remain.
+    fn get_milestone_tranche(bounty: &Bounty<T>, milestone_index: u32) -> BalanceOf<T> {
+        let milestones = &bounty.creation_params.milestones;
+        let index: usize = milestone_index.saturated_into();
+
+        if index.saturating_add(1) >= milestones.len() {
+            let paid_tranches =
+                milestones
+                    .iter()
+                    .take(index)
+                    .fold(BalanceOf::<T>::zero(), |sum, milestone| {
+                        sum.saturating_add(milestone.funding_share.mul_floor(bounty.total_funding))
+                    });
+
+            bounty.total_funding.saturating_sub(paid_tranches)
+        } else {
+            milestones
+                .get(index)
+                .map(|milestone| milestone.funding_share.mul_floor(bounty.total_funding))
+                .unwrap_or_else(Zero::zero)
+        }
+    }
+
+    // Calculates the share of the bounty funding not paid out to the milestone winners.
+    // It is 100% for the bounties without milestones.
+    fn get_unpaid_funding_share(bounty_id: &T::BountyId, bounty: &Bounty<T>) -> Perbill {
+        let paid_share = bounty
+            .creation_params
+            .milestones
+            .iter()
+            .take(Self::approved_milestone_count(bounty_id).saturated_into())
+            .fold(Perbill::zero(), |share, milestone| {
+                share.saturating_add(milestone.funding_share)
+            });
+
+        Perbill::one().saturating_sub(paid_share)
+    }
+
     // Removes the work entry and decrements active entry count in a bounty.
     fn remove_work_entry(bounty_id: &T::BountyId, entry_id: &T::EntryId) {
         <Entries<T>>::remove(bounty_id, entry_id);
@@ -2361,24 +2858,43 @@ impl<T: Config> Module<T> {
 
         let j = rationale;
 
-        let (w, r, k) =
-            judgment_map.iter().fold(
-                (0u32, 0u32, 0u32),
-                |(w, r, k), (_, judgment)| match judgment {
-                    OracleWorkEntryJudgment::Winner { .. } => (w.saturating_add(1), r, k),
-                    OracleWorkEntryJudgment::Rejected {
-                        action_justification,
-                        ..
-                    } => (
-                        w,
-                        r.saturating_add(1),
-                        k.saturating_add(to_kb(action_justification.len() as u32)),
-                    ),
-                },
-            );
+        let (w, r, k) = Self::get_judgment_weight_parameters(judgment_map);
 
         WeightInfoBounty::<T>::submit_oracle_judgment_by_council(j, k, w, r).max(
             WeightInfoBounty::<T>::submit_oracle_judgment_by_member(j, k, w, r),
         )
     }
+
+    // Calculates weight for submit_milestone_judgment extrinsic.
+    fn submit_milestone_judgment_weight(
+        judgment_map: &OracleJudgmentOf<T>,
+        rationale: u32,
+    ) -> Weight {
+        let j = rationale;
+
+        let (w, r, k) = Self::get_judgment_weight_parameters(judgment_map);
+
+        WeightInfoBounty::<T>::submit_milestone_judgment_by_council(j, k, w, r).max(
+            WeightInfoBounty::<T>::submit_milestone_judgment_by_member(j, k, w, r),
+        )
+    }
+
+    // Returns total winner entries, total rejected entries and the sum of each
+    // action_justification size for the judgment weight calculation.
+    fn get_judgment_weight_parameters(judgment_map: &OracleJudgmentOf<T>) -> (u32, u32, u32) {
+        judgment_map.iter().fold(
+            (0u32, 0u32, 0u32),
+            |(w, r, k), (_, judgment)| match judgment {
+                OracleWorkEntryJudgment::Winner { .. } => (w.saturating_add(1), r, k),
+                OracleWorkEntryJudgment::Rejected {
+                    action_justification,
+                    ..
+                } => (
+                    w,
+                    r.saturating_add(1),
+                    k.saturating_add(to_kb(action_justification.len() as u32)),
+                ),
+            },
+        )
+    }
 }
@@ -0,0 +1,72 @@
+use codec::Decode;
+use frame_support::storage::IterableStorageMap;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+use common::membership::MembershipTypes;
+
+use crate::{
+    AssuranceContractType, BalanceOf, Bounties, Bounty, BountyActor, BountyMilestone,
+    BountyParameters, ClosedContractWhitelist, Config, FundingType, Module,
+};
+
+/// Bounty creation parameters layout preceding the work milestones
+#[derive(Decode)]
+struct BountyParametersV0<Balance, BlockNumber, MemberId, ClosedContractWhitelist> {
+    oracle: BountyActor<MemberId>,
+    contract_type: AssuranceContractType<ClosedContractWhitelist>,
+    creator: BountyActor<MemberId>,
+    cherry: Balance,
+    oracle_reward: Balance,
+    entrant_stake: Balance,
+    funding_type: FundingType<BlockNumber, Balance>,
+}
+
+/// Bounty layout preceding the work milestones
+#[derive(Decode)]
+struct BountyRecordV0<Balance, BlockNumber, MemberId, ClosedContractWhitelist> {
+    creation_params: BountyParametersV0<Balance, BlockNumber, MemberId, ClosedContractWhitelist>,
+    total_funding: Balance,
+    milestone: BountyMilestone<BlockNumber>,
+    active_work_entry_count: u32,
+    has_unpaid_oracle_reward: bool,
+}
+
+type BountyV0<T> = BountyRecordV0<
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+    <T as MembershipTypes>::MemberId,
+    ClosedContractWhitelist<T>,
+>;
+
+impl<T: Config> Module<T> {
+    /// Migrate the existing bounties to the layout with the work milestones. The existing
+    /// bounties have no milestones and are judged once.
+    pub fn migrate_bounties_to_milestones_layout() -> Weight {
+        let mut bounties_number: u64 = 0;
+
+        Bounties::<T>::translate::<BountyV0<T>, _>(|_, bounty| {
+            bounties_number = bounties_number.saturating_add(1);
+
+            let params = bounty.creation_params;
+            Some(Bounty::<T> {
+                creation_params: BountyParameters {
+                    oracle: params.oracle,
+                    contract_type: params.contract_type,
+                    creator: params.creator,
+                    cherry: params.cherry,
+                    oracle_reward: params.oracle_reward,
+                    entrant_stake: params.entrant_stake,
+                    funding_type: params.funding_type,
+                    milestones: Default::default(),
+                },
+                total_funding: bounty.total_funding,
+                milestone: bounty.milestone,
+                active_work_entry_count: bounty.active_work_entry_count,
+                has_unpaid_oracle_reward: bounty.has_unpaid_oracle_reward,
+            })
+        });
+
+        T::DbWeight::get().reads_writes(bounties_number, bounties_number)
+    }
+}
@@ -1,7 +1,8 @@
 use super::mocks::{Balances, Bounty, Event, System, Test};
 use crate::{
     AssuranceContractType, BountyActor, BountyCreationParameters, BountyMilestone, BountyRecord,
-    ClosedContractWhitelist, Config, Entry, FundingType, OracleJudgmentOf, RawEvent,
+    BountyWorkMilestone, BountyWorkMilestones, ClosedContractWhitelist, Config, Entry, FundingType,
+    OracleJudgmentOf, RawEvent,
 };
 use common::council::CouncilBudgetManager;
 use frame_support::dispatch::DispatchResult;
@@ -128,6 +129,7 @@ pub struct CreateBountyFixture {
     entrant_stake: u64,
     contract_type: AssuranceContractType<BTreeSet<u64>>,
     oracle: BountyActor<u64>,
+    milestones: Vec<BountyWorkMilestone<u64>>,
 }
 
 impl CreateBountyFixture {
@@ -145,6 +147,7 @@ impl CreateBountyFixture {
             entrant_stake: DEFAULT_BOUNTY_ENTRANT_STAKE,
             contract_type: AssuranceContractType::Open,
             oracle: BountyActor::Council,
+            milestones: Vec::new(),
         }
     }
 
@@ -234,6 +237,10 @@ impl CreateBountyFixture {
         }
     }
 
+    pub fn with_milestones(self, milestones: Vec<BountyWorkMilestone<u64>>) -> Self {
+        Self { milestones, ..self }
+    }
+
     pub fn get_bounty_creation_parameters(&self) -> BountyCreationParameters<Test> {
         BountyCreationParameters::<Test> {
             creator: self.creator.clone(),
@@ -243,7 +250,7 @@ impl CreateBountyFixture {
             entrant_stake: self.entrant_stake,
             contract_type: self.contract_type.clone(),
             oracle: self.oracle.clone(),
-            ..Default::default()
+            milestones: self.milestones.clone(),
         }
     }
 
@@ -273,7 +280,13 @@ impl CreateBountyFixture {
                 },
             };
 
-            let expected_bounty = BountyRecord::<u64, u64, u64, ClosedContractWhitelist<Test>> {
+            let expected_bounty = BountyRecord::<
+                u64,
+                u64,
+                u64,
+                ClosedContractWhitelist<Test>,
+                BountyWorkMilestones<Test>,
+            > {
                 creation_params: params.clone().try_into().unwrap(),
                 total_funding: 0,
                 milestone: expected_milestone,
@@ -632,6 +645,143 @@ impl SubmitJudgmentFixture {
     }
 }
 
+pub struct SubmitMilestoneJudgmentFixture {
+    origin: RawOrigin<u128>,
+    bounty_id: u64,
+    milestone_index: u32,
+    judgment: OracleJudgmentOf<Test>,
+    rationale: Vec<u8>,
+}
+
+impl SubmitMilestoneJudgmentFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            bounty_id: 1,
+            milestone_index: 0,
+            judgment: Default::default(),
+            rationale: Default::default(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u128>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_milestone_index(self, milestone_index: u32) -> Self {
+        Self {
+            milestone_index,
+            ..self
+        }
+    }
+
+    pub fn with_judgment(self, judgment: OracleJudgmentOf<Test>) -> Self {
+        Self { judgment, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bounty = Bounty::bounties(self.bounty_id);
+        let old_approved_milestone_count = Bounty::approved_milestone_count(self.bounty_id);
+        let actual_result = Bounty::submit_milestone_judgment(
+            self.origin.clone().into(),
+            self.bounty_id,
+            self.milestone_index,
+            self.judgment.clone(),
+            self.rationale.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bounty = Bounty::bounties(self.bounty_id);
+
+        if actual_result.is_ok() {
+            let milestone_approved = Bounty::judgment_has_winners(&self.judgment);
+            let expected_approved_milestone_count = if milestone_approved {
+                self.milestone_index + 1
+            } else {
+                self.milestone_index
+            };
+            assert_eq!(
+                Bounty::approved_milestone_count(self.bounty_id),
+                expected_approved_milestone_count
+            );
+
+            let last_milestone =
+                self.milestone_index as usize + 1 == new_bounty.creation_params.milestones.len();
+            if last_milestone || !milestone_approved {
+                assert_eq!(
+                    new_bounty.milestone,
+                    BountyMilestone::JudgmentSubmitted {
+                        successful_bounty: milestone_approved
+                    }
+                );
+            } else {
+                assert_eq!(new_bounty.milestone, old_bounty.milestone);
+            }
+        } else {
+            assert_eq!(new_bounty, old_bounty);
+            assert_eq!(
+                Bounty::approved_milestone_count(self.bounty_id),
+                old_approved_milestone_count
+            );
+        }
+    }
+}
+
+pub struct WithdrawMilestoneRewardFixture {
+    origin: RawOrigin<u128>,
+    member_id: u64,
+    bounty_id: u64,
+    entry_id: u64,
+}
+
+impl WithdrawMilestoneRewardFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            member_id: 1,
+            bounty_id: 1,
+            entry_id: 1,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u128>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_entry_id(self, entry_id: u64) -> Self {
+        Self { entry_id, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_reward = Bounty::milestone_rewards(self.bounty_id, self.entry_id);
+        let actual_result = Bounty::withdraw_milestone_reward(
+            self.origin.clone().into(),
+            self.member_id,
+            self.bounty_id,
+            self.entry_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert!(!<crate::MilestoneRewards<Test>>::contains_key(
+                self.bounty_id,
+                self.entry_id
+            ));
+        } else {
+            assert_eq!(
+                Bounty::milestone_rewards(self.bounty_id, self.entry_id),
+                old_reward
+            );
+        }
+    }
+}
+
 pub struct SwitchOracleFixture {
     origin: RawOrigin<u128>,
     new_oracle: BountyActor<u64>,
@@ -4,6 +4,7 @@ use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::{ConstU32, Currency, EnsureOneOf, LockIdentifier};
 use frame_support::{ensure, parameter_types, PalletId};
 use frame_system::{ensure_signed, EnsureRoot, EnsureSigned};
+use referendum::BallotMode;
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -45,6 +46,7 @@ parameter_types! {
     pub const MinWorkEntrantStake: u64 = 10;
     pub const CreatorStateBloatBondAmount: u64 = 10;
     pub const FunderStateBloatBondAmount: u64 = 10;
+    pub const MaxMilestonesPerBounty: u32 = 3;
 }
 
 impl frame_system::Config for Test {
@@ -87,6 +89,7 @@ impl Config for Test {
     type MinWorkEntrantStake = MinWorkEntrantStake;
     type CreatorStateBloatBondAmount = CreatorStateBloatBondAmount;
     type FunderStateBloatBondAmount = FunderStateBloatBondAmount;
+    type MaxMilestonesPerBounty = MaxMilestonesPerBounty;
 }
 
 pub const STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER: u128 = 10000;
@@ -325,6 +328,9 @@ parameter_types! {
     pub const MaxSaltLength: u64 = 32; // use some multiple of 8 for ez testing
     pub const VotingLockId: LockIdentifier = *b"referend";
     pub const MaxWinnerTargetCount: u32 = 10;
+    pub const MaxVoteLockPeriod: u32 = 6;
+    pub const ReferendumBallotMode: BallotMode = BallotMode::FirstPastThePost;
+    pub const MaxRankedOptions: u32 = 5;
 }
 
 impl referendum::Config<ReferendumInstance> for Test {
@@ -338,10 +344,14 @@ impl referendum::Config<ReferendumInstance> for Test {
     type MinimumStake = MinimumVotingStake;
     type WeightInfo = ();
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxVoteLockPeriod = MaxVoteLockPeriod;
+    type BallotMode = ReferendumBallotMode;
+    type MaxRankedOptions = MaxRankedOptions;
 
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
         _: &Self::Balance,
+        _: u32,
     ) -> Self::VotePower {
         1
     }
@@ -4,24 +4,27 @@ pub(crate) mod fixtures;
 pub(crate) mod mocks;
 
 use crate::{
-    Bounties, BountyActor, BountyContributions, BountyMilestone, BountyRecord, BountyStage,
-    BountyStoredCreationParameters, Entries, Error, FundingType, OracleJudgment,
-    OracleWorkEntryJudgment, RawEvent,
+    AssuranceContractType, Bounties, BountyActor, BountyContributions, BountyMilestone,
+    BountyRecord, BountyStage, BountyStoredCreationParameters, BountyWorkMilestone,
+    ClosedContractWhitelist, Entries, Error, FundingType, OracleJudgment, OracleWorkEntryJudgment,
+    RawEvent,
 };
+use codec::Encode;
 use fixtures::{
     get_council_budget, get_creator_state_bloat_bond_amount, get_funder_state_bloat_bond_amount,
     increase_account_balance, increase_total_balance_issuance_using_account_id, run_to_block,
     set_council_budget, AnnounceWorkEntryFixture, CreateBountyFixture, EndWorkPeriodFixture,
-    EventFixture, FundBountyFixture, SubmitJudgmentFixture, SubmitWorkFixture, SwitchOracleFixture,
-    TerminateBountyFixture, WithdrawEntrantStakeFixture, WithdrawFundingFixture,
-    WithdrawOracleRewardFixture, DEFAULT_BOUNTY_CHERRY, DEFAULT_BOUNTY_ORACLE_REWARD,
+    EventFixture, FundBountyFixture, SubmitJudgmentFixture, SubmitMilestoneJudgmentFixture,
+    SubmitWorkFixture, SwitchOracleFixture, TerminateBountyFixture, WithdrawEntrantStakeFixture,
+    WithdrawFundingFixture, WithdrawMilestoneRewardFixture, WithdrawOracleRewardFixture,
+    DEFAULT_BOUNTY_CHERRY, DEFAULT_BOUNTY_ENTRANT_STAKE, DEFAULT_BOUNTY_ORACLE_REWARD,
 };
-use frame_support::storage::{StorageDoubleMap, StorageMap};
+use frame_support::storage::{unhashed, StorageDoubleMap, StorageMap};
 use frame_support::{assert_err, assert_ok};
 use frame_system::RawOrigin;
 use mocks::{
-    build_test_externalities, Balances, Bounty, ClosedContractSizeLimit, System, Test,
-    COUNCIL_BUDGET_ACCOUNT_ID, INVALID_ACCOUNT_ID, INVALID_MEMBER_ID, MAX_MEMBERS,
+    build_test_externalities, Balances, Bounty, ClosedContractSizeLimit, MaxMilestonesPerBounty,
+    System, Test, COUNCIL_BUDGET_ACCOUNT_ID, INVALID_ACCOUNT_ID, INVALID_MEMBER_ID, MAX_MEMBERS,
     STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER,
 };
 use sp_runtime::DispatchError;
@@ -6848,3 +6851,432 @@ fn entrant_remark_fails_invalid_member_controller_account() {
         );
     });
 }
+
+const MILESTONE_BOUNTY_TARGET_AMOUNT: u64 = 100;
+const FIRST_MILESTONE_DEADLINE: u64 = 10;
+const SECOND_MILESTONE_DEADLINE: u64 = 20;
+
+fn default_milestones() -> Vec<BountyWorkMilestone<u64>> {
+    vec![
+        BountyWorkMilestone {
+            deadline: FIRST_MILESTONE_DEADLINE,
+            funding_share: Perbill::from_percent(40),
+        },
+        BountyWorkMilestone {
+            deadline: SECOND_MILESTONE_DEADLINE,
+            funding_share: Perbill::from_percent(60),
+        },
+    ]
+}
+
+// Creates a council milestone bounty funded by the contributor with two work entries
+// submitted by the entrants.
+fn setup_milestone_bounty_environment(contributor_id: u64, entrant_ids: Vec<u64>) {
+    let initial_balance = 500;
+
+    set_council_budget(initial_balance);
+    CreateBountyFixture::default()
+        .with_perpetual_period_target_amount(MILESTONE_BOUNTY_TARGET_AMOUNT)
+        .with_milestones(default_milestones())
+        .call_and_assert(Ok(()));
+
+    let bounty_id = 1;
+
+    increase_account_balance(&to_account!(contributor_id), initial_balance);
+    FundBountyFixture::default()
+        .with_origin(to_origin!(contributor_id))
+        .with_bounty_id(bounty_id)
+        .with_amount(MILESTONE_BOUNTY_TARGET_AMOUNT)
+        .with_member_id(contributor_id)
+        .call_and_assert(Ok(()));
+
+    for (index, entrant_id) in entrant_ids.into_iter().enumerate() {
+        increase_account_balance(&to_account!(entrant_id), initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(to_origin!(entrant_id))
+            .with_member_id(entrant_id)
+            .with_staking_account_id(to_account!(entrant_id))
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        SubmitWorkFixture::default()
+            .with_origin(to_origin!(entrant_id))
+            .with_member_id(entrant_id)
+            .with_entry_id(index as u64 + 1)
+            .call_and_assert(Ok(()));
+    }
+}
+
+#[test]
+fn create_milestone_bounty_fails_with_invalid_milestones() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_council_budget(500);
+
+        let zero_share_milestones = vec![
+            BountyWorkMilestone {
+                deadline: FIRST_MILESTONE_DEADLINE,
+                funding_share: Perbill::zero(),
+            },
+            BountyWorkMilestone {
+                deadline: SECOND_MILESTONE_DEADLINE,
+                funding_share: Perbill::one(),
+            },
+        ];
+
+        CreateBountyFixture::default()
+            .with_milestones(zero_share_milestones)
+            .call_and_assert(Err(Error::<Test>::MilestoneFundingShareCannotBeZero.into()));
+
+        let incomplete_share_milestones = vec![BountyWorkMilestone {
+            deadline: FIRST_MILESTONE_DEADLINE,
+            funding_share: Perbill::from_percent(90),
+        }];
+
+        CreateBountyFixture::default()
+            .with_milestones(incomplete_share_milestones)
+            .call_and_assert(Err(
+                Error::<Test>::MilestoneFundingSharesShouldSumToOne.into()
+            ));
+
+        let mut unordered_milestones = default_milestones();
+        unordered_milestones.reverse();
+
+        CreateBountyFixture::default()
+            .with_milestones(unordered_milestones)
+            .call_and_assert(Err(Error::<Test>::InvalidMilestoneDeadline.into()));
+
+        let past_milestones = vec![BountyWorkMilestone {
+            deadline: starting_block,
+            funding_share: Perbill::one(),
+        }];
+
+        CreateBountyFixture::default()
+            .with_milestones(past_milestones)
+            .call_and_assert(Err(Error::<Test>::InvalidMilestoneDeadline.into()));
+
+        let too_many_milestones = (1..=MaxMilestonesPerBounty::get() + 1)
+            .map(|index| BountyWorkMilestone {
+                deadline: FIRST_MILESTONE_DEADLINE + index as u64,
+                funding_share: Perbill::from_percent(25),
+            })
+            .collect::<Vec<_>>();
+
+        CreateBountyFixture::default()
+            .with_milestones(too_many_milestones)
+            .call_and_assert(Err(Error::<Test>::MilestoneListIsTooLarge.into()));
+    });
+}
+
+#[test]
+fn milestone_bounty_pays_approved_tranches() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let (contributor_id, worker_id_1, worker_id_2) = (3, 1, 2);
+        let (entry_id_1, entry_id_2) = (1, 2);
+        let bounty_id = 1;
+        setup_milestone_bounty_environment(contributor_id, vec![worker_id_1, worker_id_2]);
+
+        let first_tranche = 40;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id_1,
+            OracleWorkEntryJudgment::Winner {
+                reward: first_tranche,
+            },
+        );
+
+        run_to_block(FIRST_MILESTONE_DEADLINE);
+        SubmitMilestoneJudgmentFixture::default()
+            .with_judgment(judgment.clone())
+            .call_and_assert(Err(Error::<Test>::MilestoneDeadlineNotReached.into()));
+
+        run_to_block(FIRST_MILESTONE_DEADLINE + 1);
+        SubmitMilestoneJudgmentFixture::default()
+            .with_milestone_index(1)
+            .with_judgment(judgment.clone())
+            .call_and_assert(Err(Error::<Test>::UnexpectedMilestoneIndex.into()));
+
+        let mut invalid_judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        invalid_judgment.insert(
+            entry_id_1,
+            OracleWorkEntryJudgment::Winner {
+                reward: MILESTONE_BOUNTY_TARGET_AMOUNT,
+            },
+        );
+        SubmitMilestoneJudgmentFixture::default()
+            .with_judgment(invalid_judgment)
+            .call_and_assert(Err(
+                Error::<Test>::TotalRewardShouldBeEqualToMilestoneTranche.into(),
+            ));
+
+        SubmitMilestoneJudgmentFixture::default()
+            .with_judgment(judgment.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::MilestoneJudgmentSubmitted(
+            bounty_id,
+            0,
+            BountyActor::Council,
+            judgment,
+            Vec::new(),
+        ));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::WorkSubmission
+        );
+
+        // The first tranche is available right after the milestone approval.
+        WithdrawMilestoneRewardFixture::default()
+            .with_origin(to_origin!(worker_id_1))
+            .with_member_id(worker_id_1)
+            .with_entry_id(entry_id_1)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::MilestoneRewardWithdrawn(
+            bounty_id,
+            entry_id_1,
+            worker_id_1,
+            first_tranche,
+        ));
+
+        assert_eq!(
+            Balances::usable_balance(&to_account!(worker_id_1)),
+            initial_balance - DEFAULT_BOUNTY_ENTRANT_STAKE + first_tranche
+        );
+
+        WithdrawMilestoneRewardFixture::default()
+            .with_origin(to_origin!(worker_id_1))
+            .with_member_id(worker_id_1)
+            .with_entry_id(entry_id_1)
+            .call_and_assert(Err(Error::<Test>::NoMilestoneRewardToWithdraw.into()));
+
+        let second_tranche = 60;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id_1,
+            OracleWorkEntryJudgment::Winner {
+                reward: second_tranche / 2,
+            },
+        );
+        judgment.insert(
+            entry_id_2,
+            OracleWorkEntryJudgment::Winner {
+                reward: second_tranche / 2,
+            },
+        );
+
+        run_to_block(SECOND_MILESTONE_DEADLINE + 1);
+        SubmitMilestoneJudgmentFixture::default()
+            .with_milestone_index(1)
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::SuccessfulBountyWithdrawal
+        );
+
+        EventFixture::contains_crate_event(RawEvent::BountyCreatorCherryWithdrawal(
+            bounty_id,
+            BountyActor::Council,
+        ));
+
+        // Unwithdrawn milestone rewards are paid out with the stake.
+        WithdrawEntrantStakeFixture::default()
+            .with_origin(to_origin!(worker_id_2))
+            .with_member_id(worker_id_2)
+            .with_entry_id(entry_id_2)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&to_account!(worker_id_2)),
+            initial_balance + second_tranche / 2
+        );
+    });
+}
+
+#[test]
+fn failed_milestone_bounty_returns_unpaid_funding() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let (contributor_id, worker_id) = (3, 1);
+        let entry_id = 1;
+        let bounty_id = 1;
+        setup_milestone_bounty_environment(contributor_id, vec![worker_id]);
+
+        let first_tranche = 40;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: first_tranche,
+            },
+        );
+
+        run_to_block(FIRST_MILESTONE_DEADLINE + 1);
+        SubmitMilestoneJudgmentFixture::default()
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Rejected {
+                slashing_share: Perbill::zero(),
+                action_justification: Vec::new(),
+            },
+        );
+
+        run_to_block(SECOND_MILESTONE_DEADLINE + 1);
+        SubmitMilestoneJudgmentFixture::default()
+            .with_milestone_index(1)
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+
+        // The rejected entrant keeps the reward for the approved milestone.
+        assert!(!Entries::<Test>::contains_key(bounty_id, entry_id));
+        assert_eq!(
+            Balances::usable_balance(&to_account!(worker_id)),
+            initial_balance + first_tranche
+        );
+
+        WithdrawFundingFixture::default()
+            .with_origin(to_origin!(contributor_id))
+            .with_member_id(contributor_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        // The contributor gets back the unpaid funding share, the cherry and the bloat bond.
+        assert_eq!(
+            Balances::usable_balance(&to_account!(contributor_id)),
+            initial_balance - first_tranche + DEFAULT_BOUNTY_CHERRY
+        );
+    });
+}
+
+#[test]
+fn milestone_judgment_is_exclusive_to_milestone_bounties() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let (contributor_id, worker_id) = (3, 1);
+        let entry_id = 1;
+        let bounty_id = 1;
+        setup_milestone_bounty_environment(contributor_id, vec![worker_id]);
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: MILESTONE_BOUNTY_TARGET_AMOUNT,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_judgment(judgment.clone())
+            .call_and_assert(Err(
+                Error::<Test>::JudgmentNotAllowedForMilestoneBounty.into()
+            ));
+
+        let regular_bounty_id = 2;
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(MILESTONE_BOUNTY_TARGET_AMOUNT)
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_origin(to_origin!(contributor_id))
+            .with_bounty_id(regular_bounty_id)
+            .with_amount(MILESTONE_BOUNTY_TARGET_AMOUNT)
+            .with_member_id(contributor_id)
+            .call_and_assert(Ok(()));
+
+        assert_err!(
+            Bounty::submit_milestone_judgment(
+                RawOrigin::Root.into(),
+                regular_bounty_id,
+                0,
+                judgment,
+                Vec::new(),
+            ),
+            Error::<Test>::NotAMilestoneBounty
+        );
+    });
+}
+
+#[test]
+fn migrate_bounties_to_milestones_layout_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let bounty_id = 1u64;
+        let oracle = BountyActor::Member(1u64);
+        let contract_type = AssuranceContractType::<ClosedContractWhitelist<Test>>::Open;
+        let creator = BountyActor::Council;
+        let funding_type = FundingType::Perpetual { target: 100u64 };
+        let milestone = BountyMilestone::Created {
+            created_at: 1u64,
+            has_contributions: true,
+        };
+
+        // bounty encoded with the layout preceding the work milestones
+        unhashed::put_raw(
+            &Bounties::<Test>::hashed_key_for(bounty_id),
+            &(
+                oracle.clone(),
+                contract_type.clone(),
+                creator.clone(),
+                10u64,
+                20u64,
+                30u64,
+                funding_type.clone(),
+                100u64,
+                milestone.clone(),
+                2u32,
+                true,
+            )
+                .encode(),
+        );
+        assert!(Bounties::<Test>::try_get(bounty_id).is_err());
+
+        Bounty::migrate_bounties_to_milestones_layout();
+
+        assert_eq!(
+            Bounties::<Test>::try_get(bounty_id),
+            Ok(BountyRecord {
+                creation_params: BountyStoredCreationParameters::<Test> {
+                    oracle,
+                    contract_type,
+                    creator,
+                    cherry: 10,
+                    oracle_reward: 20,
+                    entrant_stake: 30,
+                    funding_type,
+                    milestones: Default::default(),
+                },
+                total_funding: 100,
+                milestone,
+                active_work_entry_count: 2,
+                has_unpaid_oracle_reward: true,
+            })
+        );
+    });
+}
@@ -77,6 +77,9 @@ pub trait WeightInfo {
 	fn contributor_remark(_i: u32, ) -> Weight;
 	fn oracle_remark(_i: u32, ) -> Weight;
 	fn creator_remark(_i: u32, ) -> Weight;
+	fn submit_milestone_judgment_by_council(_j: u32, _k: u32, _w: u32, _r: u32, ) -> Weight;
+	fn submit_milestone_judgment_by_member(_j: u32, _k: u32, _w: u32, _r: u32, ) -> Weight;
+	fn withdraw_milestone_reward() -> Weight;
 }
 
 /// Weights for bounty using the Substrate node and recommended hardware.
@@ -394,6 +397,66 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((671_000 as Weight).saturating_mul(i as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 	}
+	// Storage: Bounty Bounties (r:1 w:1)
+	// Storage: Bounty ApprovedMilestoneCount (r:1 w:1)
+	// Storage: Bounty Entries (r:40 w:20)
+	// Storage: Membership MembershipById (r:40 w:0)
+	// Storage: Bounty MilestoneRewards (r:40 w:20)
+	// Storage: System Account (r:21 w:21)
+	// Storage: Council Budget (r:1 w:1)
+	// Storage: Balances Locks (r:20 w:20)
+	fn submit_milestone_judgment_by_council(j: u32, k: u32, w: u32, r: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 13_000
+			.saturating_add((884_000 as Weight).saturating_mul(j as Weight))
+			// Standard Error: 13_000
+			.saturating_add((826_000 as Weight).saturating_mul(k as Weight))
+			// Standard Error: 95_000
+			.saturating_add((31_519_000 as Weight).saturating_mul(w as Weight))
+			// Standard Error: 95_000
+			.saturating_add((79_342_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Bounty Bounties (r:1 w:1)
+	// Storage: Membership MembershipById (r:40 w:0)
+	// Storage: Bounty ApprovedMilestoneCount (r:1 w:1)
+	// Storage: Bounty Entries (r:39 w:19)
+	// Storage: Bounty MilestoneRewards (r:39 w:20)
+	// Storage: System Account (r:20 w:20)
+	// Storage: Council Budget (r:1 w:1)
+	// Storage: Balances Locks (r:19 w:19)
+	fn submit_milestone_judgment_by_member(j: u32, k: u32, w: u32, r: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 25_000
+			.saturating_add((968_000 as Weight).saturating_mul(j as Weight))
+			// Standard Error: 25_000
+			.saturating_add((1_041_000 as Weight).saturating_mul(k as Weight))
+			// Standard Error: 176_000
+			.saturating_add((31_872_000 as Weight).saturating_mul(w as Weight))
+			// Standard Error: 189_000
+			.saturating_add((78_964_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Bounty Bounties (r:1 w:0)
+	// Storage: Bounty Entries (r:1 w:0)
+	// Storage: Bounty MilestoneRewards (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn withdraw_milestone_reward() -> Weight {
+		(48_315_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -494,4 +557,13 @@ impl WeightInfo for () {
 	fn creator_remark(i: u32, ) -> Weight {
 		0
 	}
+	fn submit_milestone_judgment_by_council(j: u32, k: u32, w: u32, r: u32, ) -> Weight {
+		0
+	}
+	fn submit_milestone_judgment_by_member(j: u32, k: u32, w: u32, r: u32, ) -> Weight {
+		0
+	}
+	fn withdraw_milestone_reward() -> Weight {
+		0
+	}
 }
@@ -7,4 +7,7 @@ pub use crate::BudgetManager as CouncilBudgetManager;
 pub trait CouncilOriginValidator<Origin, MemberId, AccountId> {
     /// Check for valid combination of origin and member_id for a councilor.
     fn ensure_member_consulate(origin: Origin, member_id: MemberId) -> DispatchResult;
+
+    /// Check whether the member is a councilor of the current council.
+    fn is_council_member(member_id: &MemberId) -> bool;
 }
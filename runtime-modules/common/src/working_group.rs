@@ -75,3 +75,9 @@ pub trait WorkingGroupAuthenticator<T: crate::MembershipTypes> {
 
 /// Provides an interface for the working group budget.
 pub use crate::BudgetManager as WorkingGroupBudgetHandler;
+
+/// Working group interface to penalize its workers from other runtime modules.
+pub trait WorkingGroupStakeSlasher<T: crate::MembershipTypes, Balance> {
+    /// Slash the stake of the specified worker by the given amount.
+    fn slash_worker_stake(worker_id: &T::ActorId, penalty: Balance) -> DispatchResult;
+}
@@ -5,8 +5,10 @@ use crate::permissions::*;
 use crate::types::*;
 use crate::{
     assert_lt,
-    nft::{Nft, NftOwner, OpenAuctionParams, TransactionalStatus},
-    Call, ChannelById, Config, ContentActor, Event, Module as Pallet,
+    nft::{DutchAuctionParams, Nft, NftOwner, OpenAuctionParams, TransactionalStatus},
+    Call, ChannelById, Config, ContentActor, Event, Module as Pallet, PlaylistById,
+    ScheduledSubscriptionPaymentsByBlock, ScheduledVideoVisibilityChangesByBlock, SubscriptionById,
+    VideoVisibilityScheduleById,
 };
 use crate::{ContentTreasury, UpdateChannelPayoutsParameters};
 use balances::Pallet as Balances;
@@ -17,7 +19,7 @@ use common::{
 use frame_benchmarking::{benchmarks, Zero};
 use frame_support::{
     storage::StorageMap,
-    traits::{Currency, Get},
+    traits::{Currency, Get, OnInitialize},
     IterableStorageDoubleMap, StorageDoubleMap, StorageValue,
 };
 use frame_system::RawOrigin;
@@ -1481,7 +1483,8 @@ benchmarks! {
                 accounts_number: a as u64,
                 revenue_split_rate: params.revenue_split_rate,
                 revenue_split: RevenueSplitStateOf::<T>::Inactive,
-                next_revenue_split_id: 0
+                next_revenue_split_id: 0,
+                amm_curve: None,
             });
             assert_last_event::<T>(
                 <T as Config>::Event::from(
@@ -1617,6 +1620,88 @@ benchmarks! {
             );
         }
 
+    activate_amm {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let params = AmmParamsOf::<T> {
+            slope: 10u32.into(),
+            intercept: 1_000u32.into(),
+        };
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _ (
+        origin, actor, channel_id, params
+    )
+        verify {
+            let token = project_token::Pallet::<T>::token_info_by_id(token_id);
+            let amm_curve = token.amm_curve.unwrap();
+            assert_eq!(
+                amm_curve.fee_destination,
+                Some(ContentTreasury::<T>::account_for_channel(channel_id))
+            );
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::Event::from(
+                    project_token::Event::<T>::AmmActivated(
+                        token_id,
+                        amm_curve
+                    )
+                ).into()
+            );
+        }
+
+    deactivate_amm {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        Pallet::<T>::activate_amm(
+            origin.clone().into(),
+            actor,
+            channel_id,
+            AmmParamsOf::<T> {
+                slope: 10u32.into(),
+                intercept: 1_000u32.into(),
+            }
+        )?;
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _ (
+        origin, actor, channel_id
+    )
+        verify {
+            let token = project_token::Pallet::<T>::token_info_by_id(token_id);
+            assert!(token.amm_curve.is_none());
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::Event::from(
+                    project_token::Event::<T>::AmmDeactivated(
+                        token_id,
+                        ContentTreasury::<T>::account_for_channel(channel_id),
+                        Zero::zero()
+                    )
+                ).into()
+            );
+        }
+
     init_creator_token_sale {
         let a in 1 .. MAX_KILOBYTES_METADATA;
 
@@ -2070,6 +2155,7 @@ benchmarks! {
                 object_creation_params: storage::DataObjectCreationParameters {
                     size: 1u64,
                     ipfs_content_id: vec![1u8; 46],
+                    chunks_merkle_root: None,
                 },
                 expected_data_object_state_bloat_bond: Storage::<T>::data_object_state_bloat_bond_value(),
                 expected_data_size_fee: Storage::<T>::data_object_per_mega_byte_fee(),
@@ -2330,6 +2416,95 @@ benchmarks! {
             assert!(Pallet::<T>::video_by_id(video_id).nft_status.is_some());
         }
 
+    // WORST CASE SCENARIO:
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator group has max number of permissions per level
+    // - curator number is max
+    // - curator has max number of agent permissions
+    // - channel has max size:
+    //   - all feature paused (except necessary ones for extr to succeed)
+    //   - max channel assets
+    //   - max collaborators
+    // INPUT COMPLEXITY
+    // - collection whitelist size : w
+    // - royalty is some
+    create_nft_collection {
+        let w in 0..(T::MaxNftAuctionWhitelistLength::get());
+
+        let (channel_id, group_id, _, curator_id, curator_account_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let origin = RawOrigin::Signed(curator_account_id);
+        let params = worst_case_scenario_nft_collection_params::<T>(w);
+        let collection_id = Pallet::<T>::next_nft_collection_id();
+    }: _ (origin, actor, channel_id, params)
+        verify {
+            assert!(crate::NftCollectionById::<T>::contains_key(collection_id));
+        }
+
+    // WORST CASE SCENARIO:
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator group has max number of permissions per level
+    // - curator number is max
+    // - curator has max number of agent permissions
+    // - channel has max size:
+    //   - all feature paused (except necessary ones for extr to succeed)
+    //   - max channel assets
+    //   - max collaborators
+    // - collection has max whitelist size and some royalty
+    // - nft limits are set
+    // INPUT COMPLEXITY
+    // - number of videos : n
+    // - init transactional status of type EnglishAuction
+    batch_issue_nft {
+        let n in 1..(T::MaxNftsPerBatchIssuance::get());
+
+        let (
+            _,
+            (curator_account_id, actor, channel_id, video_params)
+        ) = setup_worst_case_scenario_mutable_video::<T>(
+            Some(T::MaxNumberOfAssetsPerVideo::get()),
+            T::MaxStorageBucketsPerBag::get(),
+        )?;
+        let video_ids = BTreeSet::from_iter(create_videos_without_assets::<T>(
+            curator_account_id.clone(),
+            actor,
+            channel_id,
+            &video_params,
+            n,
+        )?);
+
+        let collection_id = Pallet::<T>::next_nft_collection_id();
+        Pallet::<T>::create_nft_collection(
+            RawOrigin::Signed(curator_account_id.clone()).into(),
+            actor,
+            channel_id,
+            worst_case_scenario_nft_collection_params::<T>(T::MaxNftAuctionWhitelistLength::get()),
+        )?;
+
+        set_all_channel_paused_features_except::<T>(channel_id, vec![PausableChannelFeature::VideoNftIssuance]);
+        NftLimitsEnabled::set(true);
+        let limit: u64 = T::MaxNftsPerBatchIssuance::get().into();
+        Pallet::<T>::set_nft_limit(NftLimitId::GlobalDaily, limit);
+        Pallet::<T>::set_nft_limit(NftLimitId::GlobalWeekly, limit);
+        Pallet::<T>::set_nft_limit(NftLimitId::ChannelDaily(channel_id), limit);
+        Pallet::<T>::set_nft_limit(NftLimitId::ChannelWeekly(channel_id), limit);
+
+        let origin = RawOrigin::Signed(curator_account_id);
+        let init_transactional_status = worst_case_scenario_video_nft_issuance_params::<T>(0)
+            .init_transactional_status;
+    }: _ (origin, actor, collection_id, video_ids.clone(), init_transactional_status)
+        verify {
+            for video_id in video_ids.iter() {
+                assert!(matches!(Pallet::<T>::video_by_id(video_id).nft_status, Some(Nft::<T> {
+                    collection_id: Some(id),
+                    ..
+                }) if id == collection_id));
+            }
+        }
+
     // WORST CASE SCENARIO:
     // STATE COMPLEXITY
     // - curator owned channel
@@ -3265,93 +3440,122 @@ benchmarks! {
             })));
         }
 
-    // ================================================================================
-    // ============================== CHANNEL REMARKS =================================
-    // ================================================================================
-
     // WORST CASE SCENARIO
     // STATE COMPLEXITY
     // - curator owned channel
-    // - channel-owning curator group has max number of permissions per level
     // - curator number is max
     // - curator has max number of agent permissions
+    // - channel-owning curator group has max number of permissions per level
     // - channel has max size:
     //   - all feature paused (except necessary ones for extr to succeed)
     //   - max channel assets
     //   - max collaborators
+    // - video has max size
+    //   - max video assets
+    // - nft owner is channel owner
     // INPUT COMPLEXITY
-    // - remark message byte-length: b
-    channel_owner_remark {
-        let b in 1 .. MAX_KILOBYTES_METADATA;
-        let (channel_id, group_id, lead_account_id, curator_id, curator_account_id) =
-            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
-        let msg = vec![1u8].repeat((b * 1000) as usize);
-        let origin = RawOrigin::Signed(lead_account_id);
+    // - starts_at is some to trigger checks
+    // - dutch auction params Member whitelist : w
+    start_dutch_auction {
+        let w in 2..(T::MaxNftAuctionWhitelistLength::get());
+
+        let (
+            video_id,
+            (curator_account_id, actor, channel_id, _)
+        ) = setup_worst_case_scenario_mutable_video::<T>(
+            Some(T::MaxNumberOfAssetsPerVideo::get()),
+            T::MaxStorageBucketsPerBag::get(),
+        )?;
+
+        let _ = setup_idle_nft::<T>(
+            curator_account_id.clone(),
+            actor,
+            video_id,
+            false,
+        )?;
+
+        let origin = RawOrigin::Signed(curator_account_id);
+
         set_all_channel_paused_features::<T>(channel_id);
-    }: _(origin, channel_id, msg.clone())
+
+        let auction_params = DutchAuctionParams::<T> {
+            starting_price: nft_buy_now_price::<T>(),
+            floor_price: Pallet::<T>::min_starting_price(),
+            price_decrease_per_block: 1u32.into(),
+            starts_at: Some(System::<T>::block_number() + T::BlockNumber::one()),
+            whitelist: (0..(w as usize))
+                .map(|i| member_funded_account::<T>().1)
+                .collect(),
+        };
+    }: _(origin, actor, video_id, auction_params)
         verify {
-            assert_last_event::<T>(
-                <T as Config>::Event::from(
-                    Event::<T>::ChannelOwnerRemarked(
-                        channel_id,
-                        msg
-                    )
-                ).into()
-            );
+            assert!(matches!(Pallet::<T>::video_by_id(video_id).nft_status, Some(Nft::<T> {
+                transactional_status: TransactionalStatus::<T>::DutchAuction(..),
+                ..
+            })));
         }
 
     // WORST CASE SCENARIO
     // STATE COMPLEXITY
     // - curator owned channel
-    // - channel-owning curator group has max number of permissions per level
     // - curator number is max
+    // - channel-owning curator group has max number of permissions per level
     // - curator has max number of agent permissions
     // - channel has max size:
     //   - all feature paused (except necessary ones for extr to succeed)
     //   - max channel assets
     //   - max collaborators
+    // - video has max size
+    //   - max video assets
+    // - auction has max number of whitelisted members
+    // - nft owner is channel owner
     // INPUT COMPLEXITY
-    // - remark message byte-length: b
-    channel_agent_remark {
-        let b in 1 .. MAX_KILOBYTES_METADATA;
-        let (channel_id, group_id, lead_account_id, curator_id, curator_account_id) =
-            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+    cancel_dutch_auction {
+        let (
+            video_id,
+            (curator_account_id, actor, channel_id, _)
+        ) = setup_worst_case_scenario_mutable_video::<T>(
+            Some(T::MaxNumberOfAssetsPerVideo::get()),
+            T::MaxStorageBucketsPerBag::get(),
+        )?;
+
+        let ((nft_owner_actor, owner_account), _, _) = setup_nft_in_dutch_auction::<T>(
+            curator_account_id,
+            actor,
+            video_id,
+            false,
+        )?;
 
         set_all_channel_paused_features::<T>(channel_id);
-        let origin = RawOrigin::Signed(curator_account_id);
-        let actor = ContentActor::Curator(group_id, curator_id);
-        let msg = vec![1u8].repeat((b * 1000) as usize);
-    }: _(origin, actor, channel_id, msg.clone())
+
+        let origin = RawOrigin::Signed(owner_account);
+    }: _(origin, nft_owner_actor, video_id)
         verify {
-            assert_last_event::<T>(
-                <T as Config>::Event::from(
-                    Event::<T>::ChannelAgentRemarked(
-                        actor,
-                        channel_id,
-                        msg,
-                    )
-                ).into()
-            );
+            assert!(matches!(Pallet::<T>::video_by_id(video_id).nft_status, Some(Nft::<T> {
+                transactional_status: TransactionalStatus::<T>::Idle,
+                ..
+            })))
         }
 
-    // WORST CASE SCENARIO
+    // WORST CASE SCENARIO:
     // STATE COMPLEXITY
     // - curator owned channel
-    // - channel-owning curator group has max number of permissions per level
     // - curator number is max
     // - curator has max number of agent permissions
+    // - channel owning curator group has max number of permissions per level
     // - channel has max size:
     //   - all feature paused (except necessary ones for extr to succeed)
     //   - max channel assets
     //   - max collaborators
     // - video has max size
     //   - max video assets
-    // nft is channel owned
-    // - whitelist has max size
+    // - auction has max number of whitelisted members
+    // - complete payment has max complexity:
+    //   - nft owner is a member (different from channel owner)
+    //   - royalty is non-zero
+    //   - `price - royalty` is non-zero
     // INPUT COMPLEXITY
-    // - remark message byte-length: b
-    nft_owner_remark {
-        let b in 1 .. MAX_KILOBYTES_METADATA;
+    buy_nft_on_dutch_auction {
         let (
             video_id,
             (curator_account_id, actor, channel_id, _)
@@ -3360,30 +3564,520 @@ benchmarks! {
             T::MaxStorageBucketsPerBag::get(),
         )?;
 
-        let ((nft_owner_actor, owner_account), _) = setup_nft_in_english_auction::<T>(
+        let (_, participant_id, participant_account_id) = setup_nft_in_dutch_auction::<T>(
             curator_account_id,
             actor,
             video_id,
-            false,
+            true,
         )?;
 
         set_all_channel_paused_features::<T>(channel_id);
-        let origin = RawOrigin::Signed(owner_account);
-        let msg = vec![1u8].repeat((b * 1000) as usize);
-    }: _(origin, nft_owner_actor, video_id, msg.clone())
+        let origin = RawOrigin::Signed(participant_account_id.clone());
+
+        fastforward_by_blocks::<T>(2u32.into());
+
+        let max_price = nft_buy_now_price::<T>();
+        let balance_pre = Balances::<T>::usable_balance(participant_account_id.clone());
+    }: _(origin, video_id, participant_id, max_price)
         verify {
-            assert_last_event::<T>(
-                <T as Config>::Event::from(
-                    Event::<T>::NftOwnerRemarked(
-                        nft_owner_actor,
-                        video_id,
-                        msg
-                    )
-                ).into()
-            );
+            assert!(Balances::<T>::usable_balance(participant_account_id) < balance_pre);
+
+            assert!(matches!(Pallet::<T>::video_by_id(video_id).nft_status, Some(Nft::<T> {
+                transactional_status: TransactionalStatus::<T>::Idle,
+                ..
+            })));
         }
 
-    // WORST CASE SCENARIO
+    // WORST CASE SCENARIO:
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator number is max
+    // - curator has max number of agent permissions
+    // - channel owning curator group has max number of permissions per level
+    // - channel has max size:
+    //   - all feature paused (except necessary ones for extr to succeed)
+    //   - max channel assets
+    //   - max collaborators
+    // - video has max size
+    //   - max video assets
+    // - channel has a creator token issued
+    // - nft owner is a member (different from channel owner) holding the creator token
+    // INPUT COMPLEXITY
+    set_nft_priced_in_creator_token {
+        let (
+            video_id,
+            (curator_account_id, actor, channel_id, _)
+        ) = setup_worst_case_scenario_mutable_video::<T>(
+            Some(T::MaxNumberOfAssetsPerVideo::get()),
+            T::MaxStorageBucketsPerBag::get(),
+        )?;
+
+        let (nft_owner_actor, owner_account) = setup_idle_nft::<T>(
+            curator_account_id.clone(),
+            actor,
+            video_id,
+            true,
+        )?;
+        let owner_member_id = match nft_owner_actor {
+            ContentActor::Member(member_id) => member_id,
+            _ => unreachable!(),
+        };
+
+        issue_creator_token_with_worst_case_scenario_owner::<T>(
+            curator_account_id,
+            actor,
+            channel_id,
+            owner_member_id,
+        )?;
+
+        set_all_channel_paused_features::<T>(channel_id);
+        let origin = RawOrigin::Signed(owner_account);
+    }: _(origin, nft_owner_actor, video_id, true)
+        verify {
+            assert!(matches!(Pallet::<T>::video_by_id(video_id).nft_status, Some(Nft::<T> {
+                priced_in_creator_token: true,
+                ..
+            })));
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator has max number of agent permissions
+    // INPUT COMPLEXITY
+    // - r: number of royalty split recipients (all members)
+    set_channel_royalty_split {
+        let r in 1 .. T::MaxRoyaltySplitRecipients::get();
+
+        let (channel_id, group_id, _, curator_id, curator_account_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let origin = RawOrigin::Signed(curator_account_id);
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let royalty_split = worst_case_scenario_royalty_split::<T>(r);
+
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _(origin, actor, channel_id, royalty_split.clone())
+        verify {
+            assert_eq!(
+                Pallet::<T>::royalty_split_by_channel_id(channel_id).into_inner(),
+                royalty_split
+            );
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::ChannelRoyaltySplitSet(
+                        actor,
+                        channel_id,
+                        royalty_split
+                    )
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator has max number of agent permissions
+    // - video has both publishing and unpublishing already scheduled
+    // - all affected blocks have max number of scheduled visibility changes
+    set_video_visibility_schedule {
+        let (video_id, (curator_account_id, actor, _, _)) =
+            setup_worst_case_scenario_mutable_video::<T>(
+                None,
+                T::MaxStorageBucketsPerBag::get()
+            )?;
+        let origin = RawOrigin::Signed(curator_account_id);
+        let now = frame_system::Pallet::<T>::block_number();
+        let blocks: Vec<T::BlockNumber> = (1u32..=4).map(|i| now + i.into()).collect();
+        for block in blocks.iter() {
+            setup_scheduled_video_visibility_changes::<T>(
+                *block,
+                T::MaxVideoVisibilityChangesPerBlock::get() - 1
+            );
+        }
+        Pallet::<T>::set_video_visibility_schedule(
+            origin.clone().into(),
+            actor,
+            video_id,
+            VideoVisibilityScheduleOf::<T> {
+                publish_at: Some(blocks[0]),
+                unpublish_at: Some(blocks[1]),
+            }
+        )?;
+        let schedule = VideoVisibilityScheduleOf::<T> {
+            publish_at: Some(blocks[2]),
+            unpublish_at: Some(blocks[3]),
+        };
+    }: _(origin, actor, video_id, schedule.clone())
+        verify {
+            assert_eq!(Pallet::<T>::video_visibility_schedule_by_id(video_id), schedule);
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::VideoVisibilityScheduleSet(actor, video_id, schedule)
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - n: number of video visibility changes scheduled for the block
+    on_initialize_video_visibility_changes {
+        let n in 0 .. T::MaxVideoVisibilityChangesPerBlock::get();
+
+        let block = frame_system::Pallet::<T>::block_number() + One::one();
+        let video_ids = setup_scheduled_video_visibility_changes::<T>(block, n);
+    }: { Pallet::<T>::on_initialize(block); }
+        verify {
+            assert!(!ScheduledVideoVisibilityChangesByBlock::<T>::contains_key(block));
+            for video_id in video_ids.iter() {
+                assert!(!VideoVisibilityScheduleById::<T>::contains_key(video_id));
+            }
+        }
+
+    // ================================================================================
+    // ================================ SUBSCRIPTIONS =================================
+    // ================================================================================
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel with creator token issued
+    // - curator has max number of agent permissions
+    // INPUT COMPLEXITY
+    // - a: number of subscription tiers, each priced in creator tokens
+    set_channel_subscription_tiers {
+        let a in 1 .. T::MaxSubscriptionTiersPerChannel::get();
+
+        let (channel_id, group_id, _, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        issue_creator_token_with_worst_case_scenario_owner::<T>(
+            curator_acc_id,
+            actor,
+            channel_id,
+            curator_member_id
+        )?;
+        let tiers = worst_case_scenario_subscription_tiers::<T>(a);
+    }: _(origin, actor, channel_id, tiers.clone())
+        verify {
+            assert_eq!(
+                Pallet::<T>::subscription_tiers_by_channel_id(channel_id).into_inner(),
+                tiers.clone()
+            );
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::ChannelSubscriptionTiersSet(actor, channel_id, tiers)
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - payment in creator tokens
+    subscribe_to_channel {
+        let (channel_id, subscriber_acc_id, subscriber_id) =
+            setup_worst_case_scenario_subscription_channel::<T>()?;
+        let origin = RawOrigin::Signed(subscriber_acc_id);
+        let due = frame_system::Pallet::<T>::block_number() + T::MinSubscriptionPeriod::get();
+        let subscription_id = Pallet::<T>::next_subscription_id();
+        let subscription_bloat_bond = Pallet::<T>::subscription_state_bloat_bond_value();
+    }: _(origin, subscriber_id, channel_id, 0, true, 2, subscription_bloat_bond)
+        verify {
+            let subscription = Pallet::<T>::subscription_by_id(subscription_id);
+            assert_eq!(subscription.next_payment_at, due);
+            assert!(ScheduledSubscriptionPaymentsByBlock::<T>::contains_key(due, subscription_id));
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::ChannelSubscribed(subscriber_id, subscription_id, subscription)
+                ).into()
+            );
+        }
+
+    unsubscribe_from_channel {
+        let (channel_id, subscriber_acc_id, subscriber_id) =
+            setup_worst_case_scenario_subscription_channel::<T>()?;
+        let origin = RawOrigin::Signed(subscriber_acc_id);
+        let subscription_id = Pallet::<T>::next_subscription_id();
+        Pallet::<T>::subscribe_to_channel(
+            origin.clone().into(),
+            subscriber_id,
+            channel_id,
+            0,
+            true,
+            2,
+            Pallet::<T>::subscription_state_bloat_bond_value(),
+        )?;
+    }: _(origin, subscriber_id, subscription_id)
+        verify {
+            assert!(!SubscriptionById::<T>::contains_key(subscription_id));
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::ChannelUnsubscribed(subscriber_id, subscription_id)
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - n: number of subscription payments due at the block, each in creator tokens
+    on_initialize_subscription_payments {
+        let n in 0 .. T::MaxSubscriptionPaymentsPerBlock::get();
+
+        let (channel_id, subscriber_acc_id, subscriber_id) =
+            setup_worst_case_scenario_subscription_channel::<T>()?;
+        let first_subscription_id = Pallet::<T>::next_subscription_id();
+        for _ in 0..n {
+            Pallet::<T>::subscribe_to_channel(
+                RawOrigin::Signed(subscriber_acc_id.clone()).into(),
+                subscriber_id,
+                channel_id,
+                0,
+                true,
+                2,
+                Pallet::<T>::subscription_state_bloat_bond_value(),
+            )?;
+        }
+        let block = frame_system::Pallet::<T>::block_number() + T::MinSubscriptionPeriod::get();
+    }: { Pallet::<T>::on_initialize(block); }
+        verify {
+            assert!(ScheduledSubscriptionPaymentsByBlock::<T>::iter_prefix(block).next().is_none());
+            for i in 0..n {
+                let subscription_id = first_subscription_id + T::SubscriptionId::from(u64::from(i));
+                assert_eq!(Pallet::<T>::subscription_by_id(subscription_id).remaining_payments, 0);
+            }
+        }
+
+    // ================================================================================
+    // ================================== PLAYLISTS ===================================
+    // ================================================================================
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator has max number of agent permissions
+    // - non-zero playlist state bloat bond
+    // INPUT COMPLEXITY
+    // - a: number of playlist videos
+    // - b: playlist metadata kilobytes
+    create_playlist {
+        let a in 1 .. T::MaxVideosPerPlaylist::get();
+        let b in 1 .. MAX_KILOBYTES_METADATA;
+
+        let (video_ids, (curator_account_id, actor, channel_id, _)) =
+            setup_worst_case_scenario_playlist_videos::<T>(a)?;
+        let params = worst_case_scenario_playlist_creation_params::<T>(video_ids, b);
+        let playlist_id = Pallet::<T>::next_playlist_id();
+        let origin = RawOrigin::Signed(curator_account_id);
+    }: _(origin, actor, channel_id, params.clone())
+        verify {
+            assert!(PlaylistById::<T>::contains_key(playlist_id));
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::PlaylistCreated(
+                        actor,
+                        playlist_id,
+                        channel_id,
+                        params
+                    )
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator has max number of agent permissions
+    // INPUT COMPLEXITY
+    // - a: number of new playlist videos
+    // - b: new playlist metadata kilobytes
+    update_playlist {
+        let a in 1 .. T::MaxVideosPerPlaylist::get();
+        let b in 1 .. MAX_KILOBYTES_METADATA;
+
+        let (playlist_id, (curator_account_id, actor, _, _)) =
+            setup_worst_case_scenario_playlist::<T>(a)?;
+        let mut new_videos = Pallet::<T>::playlist_by_id(playlist_id).videos.into_inner();
+        new_videos.reverse();
+        let params = PlaylistUpdateParameters::<T> {
+            new_videos: Some(new_videos.clone()),
+            new_meta: Some(vec![1u8].repeat((b * 1000) as usize)),
+        };
+        let origin = RawOrigin::Signed(curator_account_id);
+    }: _(origin, actor, playlist_id, params.clone())
+        verify {
+            assert_eq!(
+                Pallet::<T>::playlist_by_id(playlist_id).videos.into_inner(),
+                new_videos
+            );
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::PlaylistUpdated(actor, playlist_id, params)
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator has max number of agent permissions
+    // - playlist with max number of videos
+    delete_playlist {
+        let (playlist_id, (curator_account_id, actor, _, _)) =
+            setup_worst_case_scenario_playlist::<T>(T::MaxVideosPerPlaylist::get())?;
+        let origin = RawOrigin::Signed(curator_account_id);
+    }: _(origin, actor, playlist_id)
+        verify {
+            assert!(!PlaylistById::<T>::contains_key(playlist_id));
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::PlaylistDeleted(actor, playlist_id)
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - curator group has max number of moderation permissions
+    // - playlist with max number of videos
+    // INPUT COMPLEXITY
+    // - a: rationale kilobytes
+    delete_playlist_as_moderator {
+        let a in 1 .. MAX_KILOBYTES_METADATA;
+
+        let (playlist_id, (curator_account_id, actor, _, _)) =
+            setup_worst_case_scenario_playlist::<T>(T::MaxVideosPerPlaylist::get())?;
+        let rationale = vec![1u8].repeat((a * 1000) as usize);
+        let origin = RawOrigin::Signed(curator_account_id);
+    }: _(origin, actor, playlist_id, rationale.clone())
+        verify {
+            assert!(!PlaylistById::<T>::contains_key(playlist_id));
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::PlaylistDeletedByModerator(actor, playlist_id, rationale)
+                ).into()
+            );
+        }
+
+    // ================================================================================
+    // ============================== CHANNEL REMARKS =================================
+    // ================================================================================
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - channel-owning curator group has max number of permissions per level
+    // - curator number is max
+    // - curator has max number of agent permissions
+    // - channel has max size:
+    //   - all feature paused (except necessary ones for extr to succeed)
+    //   - max channel assets
+    //   - max collaborators
+    // INPUT COMPLEXITY
+    // - remark message byte-length: b
+    channel_owner_remark {
+        let b in 1 .. MAX_KILOBYTES_METADATA;
+        let (channel_id, group_id, lead_account_id, curator_id, curator_account_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let msg = vec![1u8].repeat((b * 1000) as usize);
+        let origin = RawOrigin::Signed(lead_account_id);
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _(origin, channel_id, msg.clone())
+        verify {
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::ChannelOwnerRemarked(
+                        channel_id,
+                        msg
+                    )
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - channel-owning curator group has max number of permissions per level
+    // - curator number is max
+    // - curator has max number of agent permissions
+    // - channel has max size:
+    //   - all feature paused (except necessary ones for extr to succeed)
+    //   - max channel assets
+    //   - max collaborators
+    // INPUT COMPLEXITY
+    // - remark message byte-length: b
+    channel_agent_remark {
+        let b in 1 .. MAX_KILOBYTES_METADATA;
+        let (channel_id, group_id, lead_account_id, curator_id, curator_account_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+
+        set_all_channel_paused_features::<T>(channel_id);
+        let origin = RawOrigin::Signed(curator_account_id);
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let msg = vec![1u8].repeat((b * 1000) as usize);
+    }: _(origin, actor, channel_id, msg.clone())
+        verify {
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::ChannelAgentRemarked(
+                        actor,
+                        channel_id,
+                        msg,
+                    )
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // - curator owned channel
+    // - channel-owning curator group has max number of permissions per level
+    // - curator number is max
+    // - curator has max number of agent permissions
+    // - channel has max size:
+    //   - all feature paused (except necessary ones for extr to succeed)
+    //   - max channel assets
+    //   - max collaborators
+    // - video has max size
+    //   - max video assets
+    // nft is channel owned
+    // - whitelist has max size
+    // INPUT COMPLEXITY
+    // - remark message byte-length: b
+    nft_owner_remark {
+        let b in 1 .. MAX_KILOBYTES_METADATA;
+        let (
+            video_id,
+            (curator_account_id, actor, channel_id, _)
+        ) = setup_worst_case_scenario_mutable_video::<T>(
+            Some(T::MaxNumberOfAssetsPerVideo::get()),
+            T::MaxStorageBucketsPerBag::get(),
+        )?;
+
+        let ((nft_owner_actor, owner_account), _) = setup_nft_in_english_auction::<T>(
+            curator_account_id,
+            actor,
+            video_id,
+            false,
+        )?;
+
+        set_all_channel_paused_features::<T>(channel_id);
+        let origin = RawOrigin::Signed(owner_account);
+        let msg = vec![1u8].repeat((b * 1000) as usize);
+    }: _(origin, nft_owner_actor, video_id, msg.clone())
+        verify {
+            assert_last_event::<T>(
+                <T as Config>::Event::from(
+                    Event::<T>::NftOwnerRemarked(
+                        nft_owner_actor,
+                        video_id,
+                        msg
+                    )
+                ).into()
+            );
+        }
+
+    // WORST CASE SCENARIO
     // STATE COMPLEXITY
     // -
     // INPUT COMPLEXITY
@@ -3416,6 +4110,40 @@ benchmarks! {
                 new_video_bloat_bond
             );
         }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // -
+    // INPUT COMPLEXITY
+    // -
+    update_playlist_state_bloat_bond {
+        let (_, lead_account_id) = insert_content_leader::<T>();
+        let origin = RawOrigin::Signed(lead_account_id);
+        let new_playlist_bloat_bond: BalanceOf::<T> = 100u32.into();
+    }: _(origin, new_playlist_bloat_bond)
+        verify {
+            assert_eq!(
+                Pallet::<T>::playlist_state_bloat_bond_value(),
+                new_playlist_bloat_bond
+            );
+        }
+
+    // WORST CASE SCENARIO
+    // STATE COMPLEXITY
+    // -
+    // INPUT COMPLEXITY
+    // -
+    update_subscription_state_bloat_bond {
+        let (_, lead_account_id) = insert_content_leader::<T>();
+        let origin = RawOrigin::Signed(lead_account_id);
+        let new_subscription_bloat_bond: BalanceOf::<T> = 100u32.into();
+    }: _(origin, new_subscription_bloat_bond)
+        verify {
+            assert_eq!(
+                Pallet::<T>::subscription_state_bloat_bond_value(),
+                new_subscription_bloat_bond
+            );
+        }
 }
 
 #[cfg(test)]
@@ -3850,6 +4578,125 @@ pub mod tests {
         })
     }
 
+    #[test]
+    fn start_dutch_auction() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_start_dutch_auction());
+        })
+    }
+
+    #[test]
+    fn cancel_dutch_auction() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_cancel_dutch_auction());
+        })
+    }
+
+    #[test]
+    fn buy_nft_on_dutch_auction() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_buy_nft_on_dutch_auction());
+        })
+    }
+
+    #[test]
+    fn create_nft_collection() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_create_nft_collection());
+        })
+    }
+
+    #[test]
+    fn batch_issue_nft() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_batch_issue_nft());
+        })
+    }
+
+    #[test]
+    fn set_nft_priced_in_creator_token() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_set_nft_priced_in_creator_token());
+        })
+    }
+
+    #[test]
+    fn set_channel_royalty_split() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_set_channel_royalty_split());
+        })
+    }
+
+    #[test]
+    fn set_video_visibility_schedule() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_set_video_visibility_schedule());
+        })
+    }
+
+    #[test]
+    fn on_initialize_video_visibility_changes() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_on_initialize_video_visibility_changes());
+        })
+    }
+
+    #[test]
+    fn set_channel_subscription_tiers() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_set_channel_subscription_tiers());
+        })
+    }
+
+    #[test]
+    fn subscribe_to_channel() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_subscribe_to_channel());
+        })
+    }
+
+    #[test]
+    fn unsubscribe_from_channel() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_unsubscribe_from_channel());
+        })
+    }
+
+    #[test]
+    fn on_initialize_subscription_payments() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_on_initialize_subscription_payments());
+        })
+    }
+
+    #[test]
+    fn create_playlist() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_create_playlist());
+        })
+    }
+
+    #[test]
+    fn update_playlist() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_update_playlist());
+        })
+    }
+
+    #[test]
+    fn delete_playlist() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_delete_playlist());
+        })
+    }
+
+    #[test]
+    fn delete_playlist_as_moderator() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_delete_playlist_as_moderator());
+        })
+    }
+
     #[test]
     fn channel_owner_remark() {
         with_default_mock_builder(|| {
@@ -3885,6 +4732,20 @@ pub mod tests {
         })
     }
 
+    #[test]
+    fn update_playlist_state_bloat_bond() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_update_playlist_state_bloat_bond());
+        })
+    }
+
+    #[test]
+    fn update_subscription_state_bloat_bond() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_update_subscription_state_bloat_bond());
+        })
+    }
+
     #[test]
     fn make_creator_token_permissionless() {
         with_default_mock_builder(|| {
@@ -3899,6 +4760,20 @@ pub mod tests {
         });
     }
 
+    #[test]
+    fn activate_amm() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_activate_amm());
+        });
+    }
+
+    #[test]
+    fn deactivate_amm() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_deactivate_amm());
+        });
+    }
+
     #[test]
     fn init_creator_token_sale() {
         with_default_mock_builder(|| {
@@ -2,13 +2,15 @@ mod benchmarking;
 
 use crate::{
     nft::{
-        EnglishAuctionParams, InitTransactionalStatus, NftIssuanceParameters, OpenAuctionBid,
-        OpenAuctionParams,
+        DutchAuctionParams, EnglishAuctionParams, InitTransactionalStatus, NftCollectionParameters,
+        NftIssuanceParameters, OpenAuctionBid, OpenAuctionParams, RoyaltyRecipient,
+        RoyaltyRecipientOf,
     },
     permissions::*,
     types::*,
     Config, ContentModerationAction, InitTransferParametersOf, ModerationPermissionsByLevel,
-    Module as Pallet, NftLimitsEnabled,
+    Module as Pallet, NftLimitsEnabled, ScheduledVideoVisibilityChangesByBlock,
+    VideoVisibilityScheduleById,
 };
 
 use balances::Pallet as Balances;
@@ -24,7 +26,7 @@ use membership::Module as Membership;
 use project_token::{types::*, AccountInfoByTokenAndMember, MinSaleDuration};
 use sp_arithmetic::traits::One;
 use sp_core::U256;
-use sp_runtime::{traits::Hash, Permill, SaturatedConversion};
+use sp_runtime::{traits::Hash, Perbill, Permill, SaturatedConversion};
 use sp_std::{
     cmp::min,
     collections::{btree_map::BTreeMap, btree_set::BTreeSet},
@@ -87,7 +89,7 @@ const DEFAULT_CRT_SALE_UPPER_BOUND: u32 = DEFAULT_CRT_OWNER_ISSUANCE;
 const DEFAULT_CRT_REVENUE_SPLIT_RATE: Permill = Permill::from_percent(50);
 const DEFAULT_CRT_PATRONAGE_RATE: YearlyRate = YearlyRate(Permill::from_percent(10));
 
-const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 21] = [
+const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 25] = [
     ChannelActionPermission::UpdateChannelMetadata,
     ChannelActionPermission::ManageNonVideoChannelAssets,
     ChannelActionPermission::ManageChannelCollaborators,
@@ -109,9 +111,13 @@ const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 21] = [
     ChannelActionPermission::ReduceCreatorTokenPatronageRate,
     ChannelActionPermission::ManageRevenueSplits,
     ChannelActionPermission::DeissueCreatorToken,
+    ChannelActionPermission::ManageAmm,
+    ChannelActionPermission::ManageRoyaltySplit,
+    ChannelActionPermission::ManagePlaylists,
+    ChannelActionPermission::ManageSubscriptionTiers,
 ];
 
-const CONTENT_MODERATION_ACTIONS: [ContentModerationAction; 15] = [
+const CONTENT_MODERATION_ACTIONS: [ContentModerationAction; 16] = [
     ContentModerationAction::HideVideo,
     ContentModerationAction::HideChannel,
     ContentModerationAction::ChangeChannelFeatureStatus(
@@ -131,6 +137,7 @@ const CONTENT_MODERATION_ACTIONS: [ContentModerationAction; 15] = [
     ContentModerationAction::DeleteVideoAssets(false),
     ContentModerationAction::DeleteNonVideoChannelAssets,
     ContentModerationAction::UpdateChannelNftLimits,
+    ContentModerationAction::DeletePlaylist,
 ];
 
 #[macro_export]
@@ -629,6 +636,7 @@ pub fn create_data_object_candidates_helper(
         .map(|_| DataObjectCreationParameters {
             size,
             ipfs_content_id: vec![1u8; 46],
+            chunks_merkle_root: None,
         })
         .collect()
 }
@@ -1141,6 +1149,74 @@ where
     }
 }
 
+fn worst_case_scenario_nft_collection_params<T>(whitelist_size: u32) -> NftCollectionParameters<T>
+where
+    T: RuntimeConfig,
+    T::AccountId: CreateAccountId,
+{
+    NftCollectionParameters::<T> {
+        name_hash: T::Hashing::hash_of(&b"collection".to_vec()),
+        max_supply: T::MaxNftsPerBatchIssuance::get(),
+        royalty: Some(Pallet::<T>::max_creator_royalty()),
+        whitelist: (0..whitelist_size)
+            .map(|_| {
+                let (_, member_id) = member_funded_account::<T>();
+                member_id
+            })
+            .collect(),
+    }
+}
+
+// Royalty split among `recipients_num` members sharing the whole royalty
+fn worst_case_scenario_royalty_split<T>(
+    recipients_num: u32,
+) -> BTreeMap<RoyaltyRecipientOf<T>, Perbill>
+where
+    T: RuntimeConfig,
+    T::AccountId: CreateAccountId,
+{
+    (0..recipients_num)
+        .map(|_| {
+            let (_, member_id) = member_funded_account::<T>();
+            (
+                RoyaltyRecipient::Member(member_id),
+                Perbill::from_rational(1, recipients_num),
+            )
+        })
+        .collect()
+}
+
+// Create `videos_num` additional videos (without assets) in the channel
+fn create_videos_without_assets<T>(
+    sender: T::AccountId,
+    actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel_id: T::ChannelId,
+    params: &VideoCreationParameters<T>,
+    videos_num: u32,
+) -> Result<Vec<T::VideoId>, DispatchError>
+where
+    T: RuntimeConfig,
+    T::AccountId: CreateAccountId,
+{
+    (0..videos_num)
+        .map(|_| {
+            let video_id = Pallet::<T>::next_video_id();
+            Pallet::<T>::create_video(
+                RawOrigin::Signed(sender.clone()).into(),
+                actor,
+                channel_id,
+                VideoCreationParameters::<T> {
+                    assets: None,
+                    meta: None,
+                    auto_issue_nft: None,
+                    ..params.clone()
+                },
+            )?;
+            Ok(video_id)
+        })
+        .collect()
+}
+
 type VideoCreationInputParameters<T> = (
     <T as frame_system::Config>::AccountId,
     ContentActor<
@@ -1215,6 +1291,159 @@ where
     Ok((video_id, p))
 }
 
+// Create `videos_num` videos in a worst case scenario channel and set a non-zero
+// playlist state bloat bond
+fn setup_worst_case_scenario_playlist_videos<T>(
+    videos_num: u32,
+) -> Result<(Vec<T::VideoId>, VideoCreationInputParameters<T>), DispatchError>
+where
+    T: RuntimeConfig,
+    T::AccountId: CreateAccountId,
+{
+    let (video_id, p) = setup_worst_case_scenario_mutable_video::<T>(
+        Some(T::MaxNumberOfAssetsPerVideo::get()),
+        T::MaxStorageBucketsPerBag::get(),
+    )?;
+    let mut video_ids = vec![video_id];
+    video_ids.append(&mut create_videos_without_assets::<T>(
+        p.0.clone(),
+        p.1,
+        p.2,
+        &p.3,
+        videos_num.saturating_sub(1),
+    )?);
+
+    // Set non-zero playlist bloat bond
+    Pallet::<T>::update_playlist_state_bloat_bond(
+        RawOrigin::Signed(T::AccountId::create_account_id(
+            CONTENT_WG_LEADER_ACCOUNT_ID,
+        ))
+        .into(),
+        100u32.into(),
+    )?;
+
+    Ok((video_ids, p))
+}
+
+fn worst_case_scenario_playlist_creation_params<T: Config>(
+    videos: Vec<T::VideoId>,
+    meta_kilobytes: u32,
+) -> PlaylistCreationParameters<T> {
+    PlaylistCreationParameters::<T> {
+        videos,
+        meta: Some(vec![1u8].repeat((meta_kilobytes * 1000) as usize)),
+        expected_playlist_state_bloat_bond: Pallet::<T>::playlist_state_bloat_bond_value(),
+    }
+}
+
+// Create a playlist of `videos_num` videos in a worst case scenario channel
+fn setup_worst_case_scenario_playlist<T>(
+    videos_num: u32,
+) -> Result<(T::PlaylistId, VideoCreationInputParameters<T>), DispatchError>
+where
+    T: RuntimeConfig,
+    T::AccountId: CreateAccountId,
+{
+    let (video_ids, p) = setup_worst_case_scenario_playlist_videos::<T>(videos_num)?;
+    let playlist_id = Pallet::<T>::next_playlist_id();
+    Pallet::<T>::create_playlist(
+        RawOrigin::Signed(p.0.clone()).into(),
+        p.1,
+        p.2,
+        worst_case_scenario_playlist_creation_params::<T>(video_ids, MAX_KILOBYTES_METADATA),
+    )?;
+
+    Ok((playlist_id, p))
+}
+
+// Schedule publishing of `changes_num` (non-existing) videos at `block`
+fn setup_scheduled_video_visibility_changes<T: Config>(
+    block: T::BlockNumber,
+    changes_num: u32,
+) -> Vec<T::VideoId> {
+    // offset to avoid conflicts with ids of existing videos
+    let video_ids: Vec<T::VideoId> = (0..changes_num)
+        .map(|i| T::VideoId::from(u64::from(i) + 1_000_000))
+        .collect();
+
+    for video_id in video_ids.iter() {
+        VideoVisibilityScheduleById::<T>::insert(
+            video_id,
+            VideoVisibilityScheduleOf::<T> {
+                publish_at: Some(block),
+                unpublish_at: None,
+            },
+        );
+    }
+    let changes: ScheduledVideoVisibilityChanges<T> = video_ids.clone().try_into().unwrap();
+    ScheduledVideoVisibilityChangesByBlock::<T>::insert(block, changes);
+
+    video_ids
+}
+
+fn worst_case_scenario_subscription_tiers<T: Config>(tiers_num: u32) -> Vec<SubscriptionTier<T>> {
+    (0..tiers_num)
+        .map(|_| SubscriptionTier::<T> {
+            price: T::MinSubscriptionPrice::get(),
+            creator_token_price: Some(TokenBalanceOf::<T>::one()),
+            period: T::MinSubscriptionPeriod::get(),
+        })
+        .collect()
+}
+
+// Member owned channel with an issued creator token and a subscription tier priced
+// in creator tokens. The subscriber holds creator tokens for the subscription payments.
+fn setup_worst_case_scenario_subscription_channel<T>(
+) -> Result<(T::ChannelId, T::AccountId, T::MemberId), DispatchError>
+where
+    T: RuntimeConfig,
+    T::AccountId: CreateAccountId,
+{
+    let (channel_id, owner_id, owner_account_id, _) = setup_worst_case_scenario_member_channel::<T>(
+        0,
+        T::MaxStorageBucketsPerBag::get(),
+        T::MaxDistributionBucketsPerBag::get(),
+        false,
+    )?;
+    let (subscriber_account_id, subscriber_id) = member_funded_account::<T>();
+    let actor = ContentActor::Member(owner_id);
+
+    let initial_allocation = BTreeMap::<T::MemberId, TokenAllocationOf<T>>::from_iter(
+        vec![owner_id, subscriber_id].into_iter().map(|member_id| {
+            (
+                member_id,
+                TokenAllocationOf::<T> {
+                    amount: DEFAULT_CRT_OWNER_ISSUANCE.into(),
+                    vesting_schedule_params: None,
+                },
+            )
+        }),
+    );
+    Pallet::<T>::issue_creator_token(
+        RawOrigin::Signed(owner_account_id.clone()).into(),
+        actor,
+        channel_id,
+        create_token_issuance_params::<T>(initial_allocation),
+    )?;
+    Pallet::<T>::set_channel_subscription_tiers(
+        RawOrigin::Signed(owner_account_id).into(),
+        actor,
+        channel_id,
+        worst_case_scenario_subscription_tiers::<T>(1),
+    )?;
+
+    // Set non-zero subscription bloat bond
+    Pallet::<T>::update_subscription_state_bloat_bond(
+        RawOrigin::Signed(T::AccountId::create_account_id(
+            CONTENT_WG_LEADER_ACCOUNT_ID,
+        ))
+        .into(),
+        100u32.into(),
+    )?;
+
+    Ok((channel_id, subscriber_account_id, subscriber_id))
+}
+
 fn storage_buckets_num_witness<T: Config>(channel_id: T::ChannelId) -> Result<u32, DispatchError> {
     let bag_id = Pallet::<T>::bag_id_for_channel(&channel_id);
     let channel_bag = <T as Config>::DataObjectStorage::ensure_bag_exists(&bag_id)?;
@@ -1243,6 +1472,7 @@ fn create_token_issuance_params<T: Config>(
                 object_creation_params: DataObjectCreationParameters {
                     ipfs_content_id: vec![0],
                     size: T::MaxDataObjectSize::get(),
+                    chunks_merkle_root: None,
                 },
                 expected_data_object_state_bloat_bond:
                     Storage::<T>::data_object_state_bloat_bond_value(),
@@ -1603,6 +1833,42 @@ where
     Ok((nft_data, participant_id, participant_account_id))
 }
 
+fn setup_nft_in_dutch_auction<T>(
+    account_id: T::AccountId,
+    actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    video_id: T::VideoId,
+    non_channel_owner: bool,
+) -> Result<NftContext<T>, DispatchError>
+where
+    T::AccountId: CreateAccountId,
+    T: RuntimeConfig,
+{
+    let whitelist_size = T::MaxNftAuctionWhitelistLength::get();
+    assert!(whitelist_size > 1);
+    let whitelisted_members = (0..(whitelist_size as usize))
+        .map(|_| member_funded_account::<T>())
+        .collect::<Vec<_>>();
+
+    let (participant_account_id, participant_id) = whitelisted_members[0].clone();
+
+    let nft_data = setup_nft_with_transactional_status::<T>(
+        account_id,
+        actor,
+        video_id,
+        non_channel_owner,
+        InitTransactionalStatus::<T>::DutchAuction(DutchAuctionParams::<T> {
+            starting_price: nft_buy_now_price::<T>(),
+            floor_price: Pallet::<T>::min_starting_price(),
+            price_decrease_per_block: 1u32.into(),
+            starts_at: Some(System::<T>::block_number() + T::BlockNumber::one()),
+            whitelist: whitelisted_members.into_iter().map(|(_, id)| id).collect(),
+        }),
+    )
+    .unwrap();
+
+    Ok((nft_data, participant_id, participant_account_id))
+}
+
 fn setup_nft_with_transactional_status<T>(
     account_id: T::AccountId,
     actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
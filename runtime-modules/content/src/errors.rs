@@ -232,6 +232,21 @@ decl_error! {
         /// Auction type is not `English`
         IsNotEnglishAuctionType,
 
+        /// Auction type is not `Dutch`
+        IsNotDutchAuctionType,
+
+        /// Dutch auction floor price is lower than the min starting price
+        DutchAuctionFloorPriceLowerBoundExceeded,
+
+        /// Dutch auction floor price must be lower than its starting price
+        DutchAuctionFloorPriceMustBeLowerThanStartingPrice,
+
+        /// Dutch auction price decrease per block cannot be zero
+        DutchAuctionPriceDecreaseIsZero,
+
+        /// Current dutch auction price is greater than the provided `max_price`
+        DutchAuctionPriceExceedsMaxPrice,
+
         /// Bid lock duration is not expired
         BidLockDurationIsNotExpired,
 
@@ -409,5 +424,123 @@ decl_error! {
 
         /// Channel Transfers are blocked during token sales
         ChannelTransfersBlockedDuringTokenSales,
+
+        /// Insufficient transferrable creator token balance to cover the nft payment
+        InsufficientCreatorTokenBalance,
+
+        /// Nft amount cannot be represented in the creator token balance type
+        CreatorTokenAmountOverflow,
+
+        /// Nft owner (or channel owner member for channel owned nfts) has no account for
+        /// the channel's creator token, so it cannot receive nft payments in it
+        NftBeneficiaryHasNoCreatorTokenAccount,
+
+        /// Existing open auction bid was made in a different currency than the nft is priced in
+        OpenAuctionBidCurrencyMismatch,
+
+        // Nft collections
+        // ---------------------
+
+        /// Nft collection does not exist
+        NftCollectionDoesNotExist,
+
+        /// Issuing the nfts would exceed nft collection max supply
+        NftCollectionMaxSupplyExceeded,
+
+        /// Video does not belong to the nft collection channel
+        VideoNotInNftCollectionChannel,
+
+        /// No videos provided for nft batch issuance
+        NftBatchIssuanceIsEmpty,
+
+        /// Max number of nfts per batch issuance exceeded
+        MaxNftsPerBatchIssuanceExceeded,
+
+        // Royalty split
+        // ---------------------
+
+        /// Royalty split shares sum up to more than 100%
+        RoyaltySplitSharesExceedRoyalty,
+
+        /// Max number of royalty split recipients exceeded
+        MaxRoyaltySplitRecipientsExceeded,
+
+        // Playlists
+        // ---------------------
+
+        /// Playlist does not exist
+        PlaylistDoesNotExist,
+
+        /// Max number of playlists per channel exceeded
+        MaxPlaylistsPerChannelExceeded,
+
+        /// Max number of videos in a playlist exceeded
+        MaxVideosPerPlaylistExceeded,
+
+        /// Playlist cannot contain the same video more than once
+        PlaylistContainsDuplicateVideos,
+
+        /// Playlist can only contain videos of its own channel
+        VideoNotInPlaylistChannel,
+
+        /// Invalid extrinsic call: playlist state bloat bond changed.
+        PlaylistStateBloatBondChanged,
+
+        /// Cannot create the playlist: creator has insufficient balance
+        /// (budget for playlist state bloat bond + existential deposit)
+        InsufficientBalanceForPlaylistCreation,
+
+        // Video visibility schedule
+        // ---------------------
+
+        /// Video visibility change cannot be scheduled for a past or current block
+        VideoVisibilityScheduledInThePast,
+
+        /// Video publishing and unpublishing cannot be scheduled for the same block
+        InvalidVideoVisibilitySchedule,
+
+        /// Max number of video visibility changes scheduled for a single block exceeded
+        MaxVideoVisibilityChangesPerBlockExceeded,
+
+        // Subscriptions
+        // ---------------------
+
+        /// Max number of subscription tiers per channel exceeded
+        MaxSubscriptionTiersPerChannelExceeded,
+
+        /// Subscription period is shorter than the minimum allowed
+        SubscriptionPeriodTooShort,
+
+        /// Subscription tier price is lower than the minimum allowed
+        SubscriptionPriceTooLow,
+
+        /// Channel has no subscription tier with the provided index
+        SubscriptionTierDoesNotExist,
+
+        /// Subscription tier has no creator token price
+        SubscriptionTierNotPricedInCreatorToken,
+
+        /// At least one subscription payment must be authorized
+        SubscriptionPaymentsCapIsZero,
+
+        /// Subscription does not exist
+        SubscriptionDoesNotExist,
+
+        /// Subscription is not owned by the member
+        SubscriptionNotOwnedByMember,
+
+        /// Insufficient balance to cover the subscription payment
+        InsufficientBalanceForSubscriptionPayment,
+
+        /// Invalid extrinsic call: subscription state bloat bond changed.
+        SubscriptionStateBloatBondChanged,
+
+        /// Cannot subscribe to the channel: subscriber has insufficient balance
+        /// (budget for subscription state bloat bond + first payment + existential deposit)
+        InsufficientBalanceForSubscription,
+
+        /// Channel owner must be a member holding a creator token account
+        /// to receive subscription payments in creator tokens
+        SubscriptionBeneficiaryHasNoCreatorTokenAccount,
     }
 }
@@ -27,6 +27,7 @@ mod tests;
 mod benchmarks;
 
 mod errors;
+mod migrations;
 mod nft;
 mod permissions;
 mod types;
@@ -35,8 +36,8 @@ pub mod weights;
 use core::marker::PhantomData;
 use project_token::traits::PalletToken;
 use project_token::types::{
-    JoyBalanceOf, TokenIssuanceParametersOf, TokenSaleParamsOf, TransfersWithVestingOf,
-    UploadContextOf, YearlyRate,
+    AmmParamsOf, JoyBalanceOf, TokenBalanceOf, TokenIssuanceParametersOf, TokenSaleParamsOf,
+    TransfersWithVestingOf, UploadContextOf, YearlyRate,
 };
 use sp_std::vec;
 pub use weights::WeightInfo;
@@ -86,7 +87,11 @@ use sp_arithmetic::{
     Perbill,
 };
 use sp_runtime::traits::{AccountIdConversion, Hash, MaybeSerializeDeserialize, Member};
-use sp_std::{borrow::ToOwned, collections::btree_set::BTreeSet, vec::Vec};
+use sp_std::{
+    borrow::ToOwned,
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+    vec::Vec,
+};
 
 type WeightInfoContent<T> = <T as Config>::WeightInfo;
 
@@ -112,6 +117,15 @@ pub trait Config:
     /// Type of identifier for OpenAuction
     type OpenAuctionId: NumericIdentifier;
 
+    /// Type of identifier for nft collections
+    type NftCollectionId: NumericIdentifier;
+
+    /// Type of identifier for Playlists
+    type PlaylistId: NumericIdentifier;
+
+    /// Type of identifier for channel subscriptions
+    type SubscriptionId: NumericIdentifier;
+
     /// Type of identifier for TransferId
     type TransferId: NumericIdentifier;
 
@@ -142,6 +156,33 @@ pub trait Config:
     /// The maximum number of members that can be part of nft auction whitelist
     type MaxNftAuctionWhitelistLength: Get<MaxNumber>;
 
+    /// The maximum number of nfts that can be issued in a single batch
+    type MaxNftsPerBatchIssuance: Get<MaxNumber>;
+
+    /// The maximum number of recipients in a channel royalty split
+    type MaxRoyaltySplitRecipients: Get<MaxNumber>;
+
+    /// The maximum number of videos in a single playlist
+    type MaxVideosPerPlaylist: Get<MaxNumber>;
+
+    /// The maximum number of playlists per channel
+    type MaxPlaylistsPerChannel: Get<MaxNumber>;
+
+    /// The maximum number of scheduled video visibility changes processed in a single block
+    type MaxVideoVisibilityChangesPerBlock: Get<MaxNumber>;
+
+    /// The maximum number of subscription tiers per channel
+    type MaxSubscriptionTiersPerChannel: Get<MaxNumber>;
+
+    /// The maximum number of subscription payments processed in a single block
+    type MaxSubscriptionPaymentsPerBlock: Get<MaxNumber>;
+
+    /// The minimum subscription period length
+    type MinSubscriptionPeriod: Get<Self::BlockNumber>;
+
+    /// The minimum subscription tier price
+    type MinSubscriptionPrice: Get<BalanceOf<Self>>;
+
     // Channel's privilege level
     type ChannelPrivilegeLevel: Parameter
         + Member
@@ -185,6 +226,8 @@ pub trait Config:
         TokenSaleParamsOf<Self>,
         UploadContextOf<Self>,
         TransfersWithVestingOf<Self>,
+        AmmParamsOf<Self>,
+        TokenBalanceOf<Self>,
     >;
 
     /// Minimum cashout allowed limit
@@ -205,6 +248,42 @@ decl_storage! { generate_storage_info
 
         pub NextVideoId get(fn next_video_id) config(): T::VideoId;
 
+        pub PlaylistById get(fn playlist_by_id):
+        map hasher(blake2_128_concat) T::PlaylistId => Playlist<T>;
+
+        /// Playlists of each channel
+        pub PlaylistsByChannelId get(fn playlists_by_channel_id):
+        double_map hasher(blake2_128_concat) T::ChannelId,
+        hasher(blake2_128_concat) T::PlaylistId => ();
+
+        pub NextPlaylistId get(fn next_playlist_id): T::PlaylistId;
+
+        /// Scheduled publishing and unpublishing of videos
+        pub VideoVisibilityScheduleById get(fn video_visibility_schedule_by_id):
+        map hasher(blake2_128_concat) T::VideoId => VideoVisibilityScheduleOf<T>;
+
+        /// Queue of scheduled video visibility changes processed on block initialization
+        pub ScheduledVideoVisibilityChangesByBlock get(fn scheduled_video_visibility_changes_by_block):
+        map hasher(blake2_128_concat) T::BlockNumber => ScheduledVideoVisibilityChanges<T>;
+
+        /// Paid subscription tiers of each channel
+        pub SubscriptionTiersByChannelId get(fn subscription_tiers_by_channel_id):
+        map hasher(blake2_128_concat) T::ChannelId => SubscriptionTiers<T>;
+
+        pub SubscriptionById get(fn subscription_by_id):
+        map hasher(blake2_128_concat) T::SubscriptionId => Subscription<T>;
+
+        pub NextSubscriptionId get(fn next_subscription_id): T::SubscriptionId;
+
+        /// Queue of subscription payments pulled on block initialization
+        pub ScheduledSubscriptionPaymentsByBlock get(fn scheduled_subscription_payments_by_block):
+        double_map hasher(blake2_128_concat) T::BlockNumber,
+        hasher(blake2_128_concat) T::SubscriptionId => ();
+
+        /// First block of the subscription payments queue with payments not yet pulled
+        pub SubscriptionPaymentsQueueHead get(fn subscription_payments_queue_head):
+        Option<T::BlockNumber>;
+
         pub NextTransferId get(fn next_transfer_id) config(): T::TransferId;
 
         pub NextCuratorGroupId get(fn next_curator_group_id) config(): T::CuratorGroupId;
@@ -220,6 +299,12 @@ decl_storage! { generate_storage_info
         ///The state bloat bond for the video (helps preventing the state bloat).
         pub VideoStateBloatBondValue get (fn video_state_bloat_bond_value) config(): BalanceOf<T>;
 
+        /// The state bloat bond for the playlist (helps preventing the state bloat).
+        pub PlaylistStateBloatBondValue get (fn playlist_state_bloat_bond_value) config(): BalanceOf<T>;
+
+        /// The state bloat bond for the subscription (helps preventing the state bloat).
+        pub SubscriptionStateBloatBondValue get (fn subscription_state_bloat_bond_value) config(): BalanceOf<T>;
+
         pub MaxCashoutAllowed get(fn max_cashout_allowed) config(): BalanceOf<T>;
 
         pub MinCashoutAllowed get(fn min_cashout_allowed) config(): BalanceOf<T>;
@@ -295,6 +380,16 @@ decl_storage! { generate_storage_info
         /// Can be updated in flight by the Council
         pub NftLimitsEnabled get(fn nft_limits_enabled) config(): bool;
 
+        /// Nft collections
+        pub NftCollectionById get(fn nft_collection_by_id):
+        map hasher(blake2_128_concat) T::NftCollectionId => NftCollection<T>;
+
+        pub NextNftCollectionId get(fn next_nft_collection_id): T::NftCollectionId;
+
+        /// Split of the royalties paid on channel nfts secondary sales
+        pub RoyaltySplitByChannelId get(fn royalty_split_by_channel_id):
+        map hasher(blake2_128_concat) T::ChannelId => RoyaltySplit<T>;
+
     }
     add_extra_genesis {
         build(|_| {
@@ -317,6 +412,14 @@ decl_module! {
         /// Initializing events
         fn deposit_event() = default;
 
+        /// Apply video visibility changes and pull subscription payments
+        /// scheduled for the current block
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::process_scheduled_video_visibility_changes(now)
+                .saturating_add(Self::process_scheduled_subscription_payments(now))
+        }
+
+
         /// Exports const - max number of curators per group
         const MaxNumberOfCuratorsPerGroup: MaxNumber = T::MaxNumberOfCuratorsPerGroup::get();
 
@@ -327,6 +430,35 @@ decl_module! {
         /// Exports const - max nft auction whitelist length
         const MaxNftAuctionWhitelistLength: MaxNumber = T::MaxNftAuctionWhitelistLength::get();
 
+        /// Exports const - max number of nfts issued in a single batch
+        const MaxNftsPerBatchIssuance: MaxNumber = T::MaxNftsPerBatchIssuance::get();
+
+        /// Exports const - max number of recipients in a channel royalty split
+        const MaxRoyaltySplitRecipients: MaxNumber = T::MaxRoyaltySplitRecipients::get();
+
+        /// Exports const - max number of videos in a single playlist
+        const MaxVideosPerPlaylist: MaxNumber = T::MaxVideosPerPlaylist::get();
+
+        /// Exports const - max number of playlists per channel
+        const MaxPlaylistsPerChannel: MaxNumber = T::MaxPlaylistsPerChannel::get();
+
+        /// Exports const - max number of scheduled video visibility changes per block
+        const MaxVideoVisibilityChangesPerBlock: MaxNumber =
+            T::MaxVideoVisibilityChangesPerBlock::get();
+
+        /// Exports const - max number of subscription tiers per channel
+        const MaxSubscriptionTiersPerChannel: MaxNumber = T::MaxSubscriptionTiersPerChannel::get();
+
+        /// Exports const - max number of subscription payments processed per block
+        const MaxSubscriptionPaymentsPerBlock: MaxNumber =
+            T::MaxSubscriptionPaymentsPerBlock::get();
+
+        /// Exports const - min subscription period length
+        const MinSubscriptionPeriod: T::BlockNumber = T::MinSubscriptionPeriod::get();
+
+        /// Exports const - min subscription tier price
+        const MinSubscriptionPrice: BalanceOf<T> = T::MinSubscriptionPrice::get();
+
         /// Exports const - default global daily NFT limit.
         const DefaultGlobalDailyNftLimit: LimitPerPeriod<T::BlockNumber> =
             T::DefaultGlobalDailyNftLimit::get();
@@ -553,7 +685,8 @@ decl_module! {
         /// ## Weight
         /// `O (A + B + C + D + E)` where:
         /// - `A` is the number of entries in `params.collaborators`
-        /// - `B` is the number of items in `params.storage_buckets`
+        /// - `B` is the number of items in `params.storage_buckets` (`MaxStorageBucketsPerBag` if
+        ///   empty, all storage buckets are read to pick the ones with the most free capacity)
         /// - `C` is the number of items in `params.distribution_buckets`
         /// - `D` is the number of items in `params.assets.object_creation_list`
         /// - `E` is the size of  `params.meta` in kilobytes
@@ -647,6 +780,7 @@ decl_module! {
             let channel: Channel<T> = ChannelRecord {
                 owner: channel_owner,
                 num_videos: 0u64,
+                num_playlists: 0u64,
                 collaborators,
                 cumulative_reward_claimed: BalanceOf::<T>::zero(),
                 transfer_status: ChannelTransferStatus::NoActiveTransfer,
@@ -1125,7 +1259,7 @@ decl_module! {
             );
 
             if nft_status.is_some() {
-                Self::check_nft_limits(&channel)?;
+                Self::check_nft_limits(&channel, 1)?;
             }
 
             //
@@ -1175,7 +1309,7 @@ decl_module! {
             ChannelById::<T>::mutate(channel_id, |channel| {
                 channel.num_videos = channel.num_videos.saturating_add(1);
                 if nft_status.is_some() {
-                    Self::increment_nft_counters(channel);
+                    Self::increment_nft_counters(channel, 1);
                 }
             });
 
@@ -1272,7 +1406,7 @@ decl_module! {
                 )?;
 
             if nft_status.is_some() {
-                Self::check_nft_limits(&channel)?;
+                Self::check_nft_limits(&channel, 1)?;
             }
 
             //
@@ -1301,7 +1435,7 @@ decl_module! {
 
             if nft_status.is_some() {
                 ChannelById::<T>::mutate(channel_id, |channel| {
-                    Self::increment_nft_counters(channel);
+                    Self::increment_nft_counters(channel, 1);
                 });
                 VideoById::<T>::mutate(&video_id, |video| video.nft_status = nft_status);
             }
@@ -1524,235 +1658,731 @@ decl_module! {
             Ok(())
         }
 
-        /// Update channel payouts
+        /// Schedule publishing and/or unpublishing of a video, replacing the current schedule.
+        /// An empty schedule cancels the scheduled visibility changes of the video.
         ///
         /// <weight>
         ///
         /// ## Weight
-        /// `O (1)` where:
+        /// `O (1)`
         /// - DB:
-        /// - O(1)
+        ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoContent::<T>::update_channel_payouts()]
-        pub fn update_channel_payouts(
+        #[weight = WeightInfoContent::<T>::set_video_visibility_schedule()]
+        pub fn set_video_visibility_schedule(
             origin,
-            params: UpdateChannelPayoutsParameters<T>,
-            uploader_account: T::AccountId
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            video_id: T::VideoId,
+            schedule: VideoVisibilityScheduleOf<T>,
         ) {
-            ensure_root(origin)?;
-
-            Self::verify_cashout_limits(&params)?;
+            let sender = ensure_signed(origin)?;
 
-            let new_min_cashout_allowed = params.min_cashout_allowed
-                .unwrap_or_else(Self::min_cashout_allowed);
-            let new_max_cashout_allowed = params.max_cashout_allowed
-                .unwrap_or_else(Self::max_cashout_allowed);
+            // check that video exists
+            let video = Self::ensure_video_exists(&video_id)?;
 
-            ensure!(
-                new_min_cashout_allowed <= new_max_cashout_allowed,
-                Error::<T>::MinCashoutAllowedExceedsMaxCashoutAllowed
-            );
+            let channel = Self::get_channel_from_video(&video);
+            channel.ensure_has_no_active_transfer::<T>()?;
 
-            let payload_data_object_id = params.payload
-                .as_ref()
-                .map(|_| { Storage::<T>::next_data_object_id() });
+            // permissions check
+            ensure_actor_authorized_to_schedule_video_visibility::<T>(&sender, &actor, &channel)?;
 
-            if let Some(payload) = params.payload.as_ref() {
-                let upload_params = UploadParameters::<T> {
-                    bag_id: storage::BagId::<T>::from(StaticBagId::Council),
-                    object_creation_list: vec![payload.object_creation_params.clone()],
-                    state_bloat_bond_source_account_id: uploader_account.clone(),
-                    expected_data_size_fee: payload.expected_data_size_fee,
-                    expected_data_object_state_bloat_bond: payload.expected_data_object_state_bloat_bond,
-                };
-                Storage::<T>::upload_data_objects(upload_params)?;
-            }
+            Self::ensure_valid_video_visibility_schedule(video_id, &schedule)?;
 
             //
-            // == MUTATION_SAFE ==
+            // == MUTATION SAFE ==
             //
 
-            if let Some(min_cashout_allowed) = params.min_cashout_allowed.as_ref() {
-                <MinCashoutAllowed<T>>::put(min_cashout_allowed);
-            }
-
-            if let Some(max_cashout_allowed) = params.max_cashout_allowed.as_ref() {
-                <MaxCashoutAllowed<T>>::put(max_cashout_allowed);
-            }
+            Self::unschedule_video_visibility_changes(video_id);
 
-            if let Some(channel_cashouts_enabled) = params.channel_cashouts_enabled.as_ref() {
-                ChannelCashoutsEnabled::put(channel_cashouts_enabled);
+            for block in schedule.publish_at.iter().chain(schedule.unpublish_at.iter()) {
+                ScheduledVideoVisibilityChangesByBlock::<T>::mutate(block, |changes| {
+                    // capacity ensured above
+                    let _ = changes.try_push(video_id);
+                });
             }
 
-            if let Some(commitment) = params.commitment.as_ref() {
-                <Commitment<T>>::put(*commitment);
+            if schedule != VideoVisibilityScheduleOf::<T>::default() {
+                VideoVisibilityScheduleById::<T>::insert(video_id, schedule.clone());
             }
 
-            Self::deposit_event(RawEvent::ChannelPayoutsUpdated(
-                params,
-                payload_data_object_id,
-                uploader_account
-            ));
+            Self::deposit_event(RawEvent::VideoVisibilityScheduleSet(actor, video_id, schedule));
         }
 
-        /// Claim reward in JOY from channel account
+        /// Set the paid subscription tiers of a channel, replacing the current ones.
+        /// Existing subscriptions keep the terms they were created with.
         ///
         /// <weight>
         ///
         /// ## Weight
-        /// `O (H)` where:
-        /// - `H` is the lenght of the provided merkle `proof`
+        /// `O (A)` where:
+        /// - `A` is the number of tiers
         /// - DB:
-        ///    - O(1)
+        ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoContent::<T>::claim_channel_reward(proof.len() as u32)]
-        pub fn claim_channel_reward(
+        #[weight = WeightInfoContent::<T>::set_channel_subscription_tiers(tiers.len() as u32)]
+        pub fn set_channel_subscription_tiers(
             origin,
             actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
-            proof: Vec<ProofElement<T>>,
-            item: PullPayment<T>,
-        ) -> DispatchResult {
-            let (.., reward_account, cashout_amount) =
-                Self::ensure_can_claim_channel_reward(&origin, &actor, &item, &proof)?;
+            channel_id: T::ChannelId,
+            tiers: Vec<SubscriptionTier<T>>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // permissions check
+            ensure_actor_authorized_to_manage_subscription_tiers::<T>(&sender, &actor, &channel)?;
+
+            let bounded_tiers: SubscriptionTiers<T> = tiers
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::MaxSubscriptionTiersPerChannelExceeded)?;
+
+            for tier in tiers.iter() {
+                ensure!(
+                    tier.period >= T::MinSubscriptionPeriod::get(),
+                    Error::<T>::SubscriptionPeriodTooShort
+                );
+                ensure!(
+                    tier.price >= T::MinSubscriptionPrice::get(),
+                    Error::<T>::SubscriptionPriceTooLow
+                );
+                if let Some(creator_token_price) = tier.creator_token_price {
+                    ensure!(
+                        !creator_token_price.is_zero(),
+                        Error::<T>::SubscriptionPriceTooLow
+                    );
+                    channel.ensure_creator_token_issued::<T>()?;
+                }
+            }
 
             //
-            // == MUTATION_SAFE ==
+            // == MUTATION SAFE ==
             //
 
-            Self::execute_channel_reward_claim(item.channel_id, &reward_account, cashout_amount);
-
-            Self::deposit_event(
-                RawEvent::ChannelRewardUpdated(item.cumulative_reward_earned, cashout_amount, item.channel_id)
-            );
+            if bounded_tiers.is_empty() {
+                SubscriptionTiersByChannelId::<T>::remove(channel_id);
+            } else {
+                SubscriptionTiersByChannelId::<T>::insert(channel_id, bounded_tiers);
+            }
 
-            Ok(())
+            Self::deposit_event(RawEvent::ChannelSubscriptionTiersSet(actor, channel_id, tiers));
         }
 
-        /// Withdraw JOY from channel account
+        /// Subscribe to a channel tier, authorizing up to `max_payments` recurring payments
+        /// (the first one is pulled immediately). The subscription state bloat bond is repaid
+        /// when unsubscribing or once the subscription lapses.
         ///
         /// <weight>
         ///
         /// ## Weight
         /// `O (1)`
         /// - DB:
-        ///    - O(1)
+        ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoContent::<T>::withdraw_from_member_channel_balance()
-            .max(WeightInfoContent::<T>::withdraw_from_curator_channel_balance())]
-        pub fn withdraw_from_channel_balance(
+        #[weight = WeightInfoContent::<T>::subscribe_to_channel()]
+        pub fn subscribe_to_channel(
             origin,
-            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            member_id: T::MemberId,
             channel_id: T::ChannelId,
-            amount: BalanceOf<T>
-        ) -> DispatchResult {
-            let channel = Self::ensure_channel_exists(&channel_id)?;
+            tier_index: u32,
+            pay_in_creator_token: bool,
+            max_payments: u32,
+            expected_subscription_state_bloat_bond: BalanceOf<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+            ensure_member_auth_success::<T>(&sender, &member_id)?;
 
-            channel.ensure_has_no_active_transfer::<T>()?;
+            Self::ensure_channel_exists(&channel_id)?;
 
-            let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
-            ensure_actor_authorized_to_withdraw_from_channel::<T>(origin, &actor, &channel)?;
+            let tier = Self::subscription_tiers_by_channel_id(channel_id)
+                .get(tier_index as usize)
+                .cloned()
+                .ok_or(Error::<T>::SubscriptionTierDoesNotExist)?;
 
-            // Ensure channel funds transfer feature is not paused
-            channel.ensure_feature_not_paused::<T>(PausableChannelFeature::ChannelFundsTransfer)?;
+            ensure!(max_payments > 0, Error::<T>::SubscriptionPaymentsCapIsZero);
 
-            ensure!(
-                !amount.is_zero(),
-                Error::<T>::WithdrawFromChannelAmountIsZero
-            );
+            let payment = if pay_in_creator_token {
+                SubscriptionPayment::<T>::CreatorToken(
+                    tier.creator_token_price
+                        .ok_or(Error::<T>::SubscriptionTierNotPricedInCreatorToken)?
+                )
+            } else {
+                SubscriptionPayment::<T>::Joy(tier.price)
+            };
+
+            let subscription_state_bloat_bond = Self::subscription_state_bloat_bond_value();
 
+            // ensure expected subscription state bloat bond
             ensure!(
-                Self::channel_account_withdrawable_balance(&reward_account, &channel) >= amount,
-                Error::<T>::WithdrawalAmountExceedsChannelAccountWithdrawableBalance
+                expected_subscription_state_bloat_bond == subscription_state_bloat_bond,
+                Error::<T>::SubscriptionStateBloatBondChanged,
             );
 
+            let now = frame_system::Pallet::<T>::block_number();
+            let next_payment_at = now.saturating_add(tier.period);
+
+            let mut subscription = Subscription::<T> {
+                channel_id,
+                member_id,
+                payer: sender.clone(),
+                payment,
+                period: tier.period,
+                remaining_payments: max_payments.saturating_sub(1),
+                next_payment_at,
+                subscription_state_bloat_bond: Default::default(),
+            };
+
+            Self::ensure_can_pull_subscription_payment(&subscription)?;
+
+            // the first payment in JOY is pulled together with the bloat bond
+            let joy_payment = match payment {
+                SubscriptionPaymentRecord::Joy(amount) => amount,
+                SubscriptionPaymentRecord::CreatorToken(_) => Zero::zero(),
+            };
             ensure!(
-                channel.creator_token_id.is_none(),
-                Error::<T>::CannotWithdrawFromChannelWithCreatorTokenIssued
+                has_sufficient_balance_for_fees::<T>(
+                    &sender,
+                    subscription_state_bloat_bond.saturating_add(joy_payment)
+                ),
+                Error::<T>::InsufficientBalanceForSubscription
             );
 
-            let destination = Self::channel_funds_destination(&channel)?;
-
             //
-            // == MUTATION_SAFE ==
+            // == MUTATION SAFE ==
             //
 
-            Self::execute_channel_balance_withdrawal(&reward_account, &destination, amount)?;
+            Self::pull_subscription_payment(&subscription)?;
 
-            Self::deposit_event(RawEvent::ChannelFundsWithdrawn(
-                actor,
-                channel_id,
-                amount,
-                destination,
-            ));
+            // subscription bloat bond is held by the module account, same as the playlist one
+            subscription.subscription_state_bloat_bond =
+                Self::pay_video_bloat_bond(&sender, subscription_state_bloat_bond)?;
 
-            Ok(())
+            let subscription_id = Self::next_subscription_id();
+            Self::schedule_subscription_payment(subscription_id, next_payment_at);
+            SubscriptionById::<T>::insert(subscription_id, subscription.clone());
+            NextSubscriptionId::<T>::mutate(|id| *id = id.saturating_add(One::one()));
+
+            Self::deposit_event(
+                RawEvent::ChannelSubscribed(member_id, subscription_id, subscription)
+            );
         }
 
-        /// Updates channel state bloat bond value.
-        /// Only lead can upload this value
+        /// Cancel a channel subscription. No further payments are pulled.
         ///
         /// <weight>
         ///
         /// ## Weight
         /// `O (1)`
         /// - DB:
-        ///    - O(1)
+        ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoContent::<T>::update_channel_state_bloat_bond()]
-        pub fn update_channel_state_bloat_bond(
+        #[weight = WeightInfoContent::<T>::unsubscribe_from_channel()]
+        pub fn unsubscribe_from_channel(
             origin,
-            new_channel_state_bloat_bond: BalanceOf<T>,
+            member_id: T::MemberId,
+            subscription_id: T::SubscriptionId,
         ) {
             let sender = ensure_signed(origin)?;
-            ensure_authorized_to_update_channel_state_bloat_bond::<T>(&sender)?;
+            ensure_member_auth_success::<T>(&sender, &member_id)?;
 
+            let subscription = Self::ensure_subscription_exists(&subscription_id)?;
             ensure!(
-                new_channel_state_bloat_bond >= T::ExistentialDeposit::get(),
-                Error::<T>::ChannelStateBloatBondBelowExistentialDeposit
+                subscription.member_id == member_id,
+                Error::<T>::SubscriptionNotOwnedByMember
             );
 
             //
-            // == MUTATION_SAFE ==
+            // == MUTATION SAFE ==
             //
 
-            ChannelStateBloatBondValue::<T>::put(new_channel_state_bloat_bond);
-            Self::deposit_event(
-                RawEvent::ChannelStateBloatBondValueUpdated(
-                    new_channel_state_bloat_bond));
+            Self::remove_subscription(subscription_id, &subscription);
+
+            // Repay subscription state bloat bond
+            subscription.subscription_state_bloat_bond.repay::<T>(
+                &ContentTreasury::<T>::module_account_id(),
+                &sender,
+                false,
+            )?;
+
+            Self::deposit_event(RawEvent::ChannelUnsubscribed(member_id, subscription_id));
         }
 
-        /// Updates video state bloat bond value.
-        /// Only lead can upload this value
+        /// Create a playlist: an ordered list of channel videos
         ///
         /// <weight>
         ///
         /// ## Weight
-        /// `O (1)`
+        /// `O (A + B)` where:
+        /// - `A` is the number of videos in the playlist
+        /// - `B` is the size of `params.meta` in kilobytes
         /// - DB:
-        ///    - O(1)
+        ///    - `O(A)`
         /// # </weight>
-        #[weight = WeightInfoContent::<T>::update_video_state_bloat_bond()]
-        pub fn update_video_state_bloat_bond(
+        #[weight = Module::<T>::create_playlist_weight(params)]
+        pub fn create_playlist(
             origin,
-            new_video_state_bloat_bond: BalanceOf<T>,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            params: PlaylistCreationParameters<T>,
         ) {
             let sender = ensure_signed(origin)?;
-            ensure_authorized_to_update_video_state_bloat_bond::<T>(&sender)?;
+
+            // check that channel exists
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // permissions check
+            ensure_actor_authorized_to_manage_playlists::<T>(&sender, &actor, &channel)?;
+
+            ensure!(
+                channel.num_playlists < T::MaxPlaylistsPerChannel::get().into(),
+                Error::<T>::MaxPlaylistsPerChannelExceeded
+            );
+
+            let videos = Self::ensure_valid_playlist_videos(channel_id, &params.videos)?;
+
+            let playlist_state_bloat_bond = Self::playlist_state_bloat_bond_value();
+
+            // ensure expected playlist state bloat bond
+            ensure!(
+                params.expected_playlist_state_bloat_bond == playlist_state_bloat_bond,
+                Error::<T>::PlaylistStateBloatBondChanged,
+            );
+
+            ensure!(
+                has_sufficient_balance_for_fees::<T>(&sender, playlist_state_bloat_bond),
+                Error::<T>::InsufficientBalanceForPlaylistCreation
+            );
 
             //
-            // == MUTATION_SAFE ==
+            // == MUTATION SAFE ==
             //
 
-            VideoStateBloatBondValue::<T>::put(new_video_state_bloat_bond);
-            Self::deposit_event(
-                RawEvent::VideoStateBloatBondValueUpdated(
-                    new_video_state_bloat_bond));
-        }
+            // playlist bloat bond is held by the module account, same as the video one
+            let repayable_bloat_bond = Self::pay_video_bloat_bond(&sender, playlist_state_bloat_bond)?;
 
-        /// Claim and withdraw reward in JOY from channel account
-        ///
-        /// <weight>
-        ///
+            let playlist_id = NextPlaylistId::<T>::get();
+
+            PlaylistById::<T>::insert(playlist_id, Playlist::<T> {
+                in_channel: channel_id,
+                videos,
+                playlist_state_bloat_bond: repayable_bloat_bond,
+            });
+            PlaylistsByChannelId::<T>::insert(channel_id, playlist_id, ());
+
+            NextPlaylistId::<T>::mutate(|id| *id = id.saturating_add(One::one()));
+
+            ChannelById::<T>::mutate(channel_id, |channel| {
+                channel.num_playlists = channel.num_playlists.saturating_add(1)
+            });
+
+            Self::deposit_event(RawEvent::PlaylistCreated(actor, playlist_id, channel_id, params));
+        }
+
+        /// Update a playlist
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (A + B)` where:
+        /// - `A` is the number of videos in `params.new_videos`
+        /// - `B` is the size of `params.new_meta` in kilobytes
+        /// - DB:
+        ///    - `O(A)`
+        /// # </weight>
+        #[weight = Module::<T>::update_playlist_weight(params)]
+        pub fn update_playlist(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            playlist_id: T::PlaylistId,
+            params: PlaylistUpdateParameters<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let playlist = Self::ensure_playlist_exists(&playlist_id)?;
+
+            let channel = Self::channel_by_id(playlist.in_channel);
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // permissions check
+            ensure_actor_authorized_to_manage_playlists::<T>(&sender, &actor, &channel)?;
+
+            let new_videos = params
+                .new_videos
+                .as_ref()
+                .map(|videos| Self::ensure_valid_playlist_videos(playlist.in_channel, videos))
+                .transpose()?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some(videos) = new_videos {
+                PlaylistById::<T>::mutate(playlist_id, |playlist| playlist.videos = videos);
+            }
+
+            Self::deposit_event(RawEvent::PlaylistUpdated(actor, playlist_id, params));
+        }
+
+        /// Delete a playlist, repaying its state bloat bond
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::delete_playlist()]
+        pub fn delete_playlist(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            playlist_id: T::PlaylistId,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let playlist = Self::ensure_playlist_exists(&playlist_id)?;
+
+            let channel = Self::channel_by_id(playlist.in_channel);
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // permissions check
+            ensure_actor_authorized_to_manage_playlists::<T>(&sender, &actor, &channel)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Remove the playlist, repay the bloat bond
+            Self::try_to_perform_playlist_deletion(&sender, playlist_id, &playlist, false)?;
+
+            Self::deposit_event(RawEvent::PlaylistDeleted(actor, playlist_id));
+        }
+
+        /// Delete a playlist as moderator, slashing its state bloat bond
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (A)` where:
+        /// - `A` is the size of `rationale` in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::delete_playlist_as_moderator(to_kb(rationale.len() as u32))]
+        pub fn delete_playlist_as_moderator(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            playlist_id: T::PlaylistId,
+            rationale: Vec<u8>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let playlist = Self::ensure_playlist_exists(&playlist_id)?;
+
+            let channel = Self::channel_by_id(playlist.in_channel);
+
+            // Permissions check
+            let actions_to_perform = vec![ContentModerationAction::DeletePlaylist];
+            ensure_actor_authorized_to_perform_moderation_actions::<T>(&sender, &actor, &actions_to_perform, channel.privilege_level)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Remove the playlist, slash the bloat bond
+            Self::try_to_perform_playlist_deletion(&sender, playlist_id, &playlist, true)?;
+
+            Self::deposit_event(RawEvent::PlaylistDeletedByModerator(actor, playlist_id, rationale));
+        }
+
+        /// Update channel payouts
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)` where:
+        /// - DB:
+        /// - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::update_channel_payouts()]
+        pub fn update_channel_payouts(
+            origin,
+            params: UpdateChannelPayoutsParameters<T>,
+            uploader_account: T::AccountId
+        ) {
+            ensure_root(origin)?;
+
+            Self::verify_cashout_limits(&params)?;
+
+            let new_min_cashout_allowed = params.min_cashout_allowed
+                .unwrap_or_else(Self::min_cashout_allowed);
+            let new_max_cashout_allowed = params.max_cashout_allowed
+                .unwrap_or_else(Self::max_cashout_allowed);
+
+            ensure!(
+                new_min_cashout_allowed <= new_max_cashout_allowed,
+                Error::<T>::MinCashoutAllowedExceedsMaxCashoutAllowed
+            );
+
+            let payload_data_object_id = params.payload
+                .as_ref()
+                .map(|_| { Storage::<T>::next_data_object_id() });
+
+            if let Some(payload) = params.payload.as_ref() {
+                let upload_params = UploadParameters::<T> {
+                    bag_id: storage::BagId::<T>::from(StaticBagId::Council),
+                    object_creation_list: vec![payload.object_creation_params.clone()],
+                    state_bloat_bond_source_account_id: uploader_account.clone(),
+                    expected_data_size_fee: payload.expected_data_size_fee,
+                    expected_data_object_state_bloat_bond: payload.expected_data_object_state_bloat_bond,
+                };
+                Storage::<T>::upload_data_objects(upload_params)?;
+            }
+
+            //
+            // == MUTATION_SAFE ==
+            //
+
+            if let Some(min_cashout_allowed) = params.min_cashout_allowed.as_ref() {
+                <MinCashoutAllowed<T>>::put(min_cashout_allowed);
+            }
+
+            if let Some(max_cashout_allowed) = params.max_cashout_allowed.as_ref() {
+                <MaxCashoutAllowed<T>>::put(max_cashout_allowed);
+            }
+
+            if let Some(channel_cashouts_enabled) = params.channel_cashouts_enabled.as_ref() {
+                ChannelCashoutsEnabled::put(channel_cashouts_enabled);
+            }
+
+            if let Some(commitment) = params.commitment.as_ref() {
+                <Commitment<T>>::put(*commitment);
+            }
+
+            Self::deposit_event(RawEvent::ChannelPayoutsUpdated(
+                params,
+                payload_data_object_id,
+                uploader_account
+            ));
+        }
+
+        /// Claim reward in JOY from channel account
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (H)` where:
+        /// - `H` is the lenght of the provided merkle `proof`
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::claim_channel_reward(proof.len() as u32)]
+        pub fn claim_channel_reward(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            proof: Vec<ProofElement<T>>,
+            item: PullPayment<T>,
+        ) -> DispatchResult {
+            let (.., reward_account, cashout_amount) =
+                Self::ensure_can_claim_channel_reward(&origin, &actor, &item, &proof)?;
+
+            //
+            // == MUTATION_SAFE ==
+            //
+
+            Self::execute_channel_reward_claim(item.channel_id, &reward_account, cashout_amount);
+
+            Self::deposit_event(
+                RawEvent::ChannelRewardUpdated(item.cumulative_reward_earned, cashout_amount, item.channel_id)
+            );
+
+            Ok(())
+        }
+
+        /// Withdraw JOY from channel account
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::withdraw_from_member_channel_balance()
+            .max(WeightInfoContent::<T>::withdraw_from_curator_channel_balance())]
+        pub fn withdraw_from_channel_balance(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            amount: BalanceOf<T>
+        ) -> DispatchResult {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
+            ensure_actor_authorized_to_withdraw_from_channel::<T>(origin, &actor, &channel)?;
+
+            // Ensure channel funds transfer feature is not paused
+            channel.ensure_feature_not_paused::<T>(PausableChannelFeature::ChannelFundsTransfer)?;
+
+            ensure!(
+                !amount.is_zero(),
+                Error::<T>::WithdrawFromChannelAmountIsZero
+            );
+
+            ensure!(
+                Self::channel_account_withdrawable_balance(&reward_account, &channel) >= amount,
+                Error::<T>::WithdrawalAmountExceedsChannelAccountWithdrawableBalance
+            );
+
+            ensure!(
+                channel.creator_token_id.is_none(),
+                Error::<T>::CannotWithdrawFromChannelWithCreatorTokenIssued
+            );
+
+            let destination = Self::channel_funds_destination(&channel)?;
+
+            //
+            // == MUTATION_SAFE ==
+            //
+
+            Self::execute_channel_balance_withdrawal(&reward_account, &destination, amount)?;
+
+            Self::deposit_event(RawEvent::ChannelFundsWithdrawn(
+                actor,
+                channel_id,
+                amount,
+                destination,
+            ));
+
+            Ok(())
+        }
+
+        /// Updates channel state bloat bond value.
+        /// Only lead can upload this value
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::update_channel_state_bloat_bond()]
+        pub fn update_channel_state_bloat_bond(
+            origin,
+            new_channel_state_bloat_bond: BalanceOf<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+            ensure_authorized_to_update_channel_state_bloat_bond::<T>(&sender)?;
+
+            ensure!(
+                new_channel_state_bloat_bond >= T::ExistentialDeposit::get(),
+                Error::<T>::ChannelStateBloatBondBelowExistentialDeposit
+            );
+
+            //
+            // == MUTATION_SAFE ==
+            //
+
+            ChannelStateBloatBondValue::<T>::put(new_channel_state_bloat_bond);
+            Self::deposit_event(
+                RawEvent::ChannelStateBloatBondValueUpdated(
+                    new_channel_state_bloat_bond));
+        }
+
+        /// Updates video state bloat bond value.
+        /// Only lead can upload this value
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::update_video_state_bloat_bond()]
+        pub fn update_video_state_bloat_bond(
+            origin,
+            new_video_state_bloat_bond: BalanceOf<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+            ensure_authorized_to_update_video_state_bloat_bond::<T>(&sender)?;
+
+            //
+            // == MUTATION_SAFE ==
+            //
+
+            VideoStateBloatBondValue::<T>::put(new_video_state_bloat_bond);
+            Self::deposit_event(
+                RawEvent::VideoStateBloatBondValueUpdated(
+                    new_video_state_bloat_bond));
+        }
+
+        /// Updates playlist state bloat bond value.
+        /// Only lead can upload this value
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::update_playlist_state_bloat_bond()]
+        pub fn update_playlist_state_bloat_bond(
+            origin,
+            new_playlist_state_bloat_bond: BalanceOf<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+            ensure_authorized_to_update_playlist_state_bloat_bond::<T>(&sender)?;
+
+            //
+            // == MUTATION_SAFE ==
+            //
+
+            PlaylistStateBloatBondValue::<T>::put(new_playlist_state_bloat_bond);
+            Self::deposit_event(
+                RawEvent::PlaylistStateBloatBondValueUpdated(
+                    new_playlist_state_bloat_bond));
+        }
+
+        /// Updates subscription state bloat bond value.
+        /// Only lead can upload this value
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::update_subscription_state_bloat_bond()]
+        pub fn update_subscription_state_bloat_bond(
+            origin,
+            new_subscription_state_bloat_bond: BalanceOf<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+            ensure_authorized_to_update_subscription_state_bloat_bond::<T>(&sender)?;
+
+            //
+            // == MUTATION_SAFE ==
+            //
+
+            SubscriptionStateBloatBondValue::<T>::put(new_subscription_state_bloat_bond);
+            Self::deposit_event(
+                RawEvent::SubscriptionStateBloatBondValueUpdated(
+                    new_subscription_state_bloat_bond));
+        }
+
+        /// Claim and withdraw reward in JOY from channel account
+        ///
+        /// <weight>
+        ///
         /// ## Weight
         /// `O (H)` where:
         /// - `H` is the lenght of the provided merkle `proof`
@@ -1841,14 +2471,14 @@ decl_module! {
             let nft_status = Self::construct_owned_nft(&params)?;
 
             // Check channel's nft limits
-            Self::check_nft_limits(&channel)?;
+            Self::check_nft_limits(&channel, 1)?;
 
             //
             // == MUTATION SAFE ==
             //
 
             ChannelById::<T>::mutate(video.in_channel, |channel| {
-                Self::increment_nft_counters(channel);
+                Self::increment_nft_counters(channel, 1);
             });
 
             // Update the video
@@ -1861,6 +2491,162 @@ decl_module! {
             ));
         }
 
+        /// Create nft collection for the channel
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)`
+        /// - DB:
+        ///    - O(1)
+        /// where:
+        ///    - W : collection whitelist length
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::create_nft_collection(params.whitelist.len() as u32)]
+        pub fn create_nft_collection(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            params: NftCollectionParameters<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            // block extrinsics during transfers
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // permissions check
+            ensure_actor_authorized_to_manage_video_nfts::<T>(&sender, &actor, &channel)?;
+
+            // Enure royalty bounds satisfied, if provided
+            if let Some(royalty) = params.royalty {
+                Self::ensure_royalty_bounds_satisfied(royalty)?;
+            }
+
+            let whitelist: NftAuctionWhitelist<T> = params
+                .whitelist
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::MaxAuctionWhiteListLengthUpperBoundExceeded)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let collection_id = Self::next_nft_collection_id();
+
+            NftCollectionById::<T>::insert(collection_id, NftCollection::<T> {
+                channel_id,
+                name_hash: params.name_hash,
+                max_supply: params.max_supply,
+                issued: 0,
+                royalty: params.royalty,
+                whitelist,
+            });
+
+            NextNftCollectionId::<T>::mutate(|id| *id = id.saturating_add(One::one()));
+
+            Self::deposit_event(RawEvent::NftCollectionCreated(
+                actor,
+                channel_id,
+                collection_id,
+                params,
+            ));
+        }
+
+        /// Issue nfts for many videos of the channel as part of the nft collection
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (N)`
+        /// - DB:
+        ///    - O(N)
+        /// where:
+        ///    - N : number of videos to issue nfts for
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::batch_issue_nft(video_ids.len() as u32)]
+        pub fn batch_issue_nft(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            collection_id: T::NftCollectionId,
+            video_ids: BTreeSet<T::VideoId>,
+            init_transactional_status: InitTransactionalStatus<T>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let collection = Self::ensure_nft_collection_exists(&collection_id)?;
+
+            let channel = Self::ensure_channel_exists(&collection.channel_id)?;
+
+            // block extrinsics during transfers
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // permissions check
+            ensure_actor_authorized_to_manage_video_nfts::<T>(&sender, &actor, &channel)?;
+
+            // Ensure nft issuance is not paused
+            channel.ensure_feature_not_paused::<T>(PausableChannelFeature::VideoNftIssuance)?;
+
+            // Ensure batch size constraints are satisfied
+            ensure!(!video_ids.is_empty(), Error::<T>::NftBatchIssuanceIsEmpty);
+            ensure!(
+                video_ids.len() <= T::MaxNftsPerBatchIssuance::get() as usize,
+                Error::<T>::MaxNftsPerBatchIssuanceExceeded
+            );
+            let nfts_number = video_ids.len() as u32;
+            collection.ensure_can_issue::<T>(nfts_number)?;
+
+            // Ensure all videos belong to the collection channel and have no nft issued yet
+            for video_id in video_ids.iter() {
+                let video = Self::ensure_video_exists(video_id)?;
+                ensure!(
+                    video.in_channel == collection.channel_id,
+                    Error::<T>::VideoNotInNftCollectionChannel
+                );
+                video.ensure_nft_is_not_issued::<T>()?;
+            }
+
+            // All collection nfts share royalty & auction whitelist
+            let nft_status = Self::construct_owned_nft(&NftIssuanceParameters::<T> {
+                royalty: collection.royalty,
+                nft_metadata: Vec::new(),
+                non_channel_owner: None,
+                init_transactional_status: Self::with_nft_collection_whitelist(
+                    init_transactional_status.clone(),
+                    &collection,
+                ),
+            })?
+            .with_collection(collection_id);
+
+            // Check channel's nft limits
+            Self::check_nft_limits(&channel, nfts_number.into())?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ChannelById::<T>::mutate(collection.channel_id, |channel| {
+                Self::increment_nft_counters(channel, nfts_number.into());
+            });
+
+            NftCollectionById::<T>::mutate(collection_id, |collection| {
+                collection.issued = collection.issued.saturating_add(nfts_number);
+            });
+
+            for video_id in video_ids.iter() {
+                VideoById::<T>::mutate(video_id, |v| v.set_nft_status(nft_status.clone()));
+            }
+
+            Self::deposit_event(RawEvent::NftsBatchIssued(
+                actor,
+                collection_id,
+                video_ids,
+                init_transactional_status,
+            ));
+        }
+
         /// Destroy NFT
         ///
         /// <weight>
@@ -2126,6 +2912,117 @@ decl_module! {
             Self::deposit_event(RawEvent::AuctionCanceled(owner_id, video_id));
         }
 
+        /// Start video nft dutch auction
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - W : whitelist member list length
+        /// - DB:
+        ///    - O(W)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::start_dutch_auction(auction_params.whitelist.len() as u32)]
+        pub fn start_dutch_auction(
+            origin,
+            owner_id: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            video_id: T::VideoId,
+            auction_params: DutchAuctionParams<T>,
+        ) {
+            // Ensure given video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            // Ensure nft is already issued
+            let nft = video.ensure_nft_is_issued::<T>()?;
+
+            // block extrinsics during transfers
+            Self::channel_by_id(video.in_channel).ensure_has_no_active_transfer::<T>()?;
+
+            // Authorize nft owner
+            ensure_actor_authorized_to_manage_nft::<T>(
+                origin,
+                &owner_id,
+                &nft.owner,
+                video.in_channel
+            )?;
+
+            // Ensure there nft transactional status is set to idle.
+            Self::ensure_nft_transactional_status_is_idle(&nft)?;
+
+            // Validate prices, decay & whitelist
+            Self::validate_dutch_auction_params(&auction_params)?;
+
+            // Create new auction
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let auction = DutchAuction::<T>::try_new::<T>(auction_params.clone(), current_block)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Update the video
+            VideoById::<T>::mutate(
+                video_id,
+                |v| v.set_nft_status(
+                    nft.with_transactional_status(
+                        TransactionalStatus::<T>::DutchAuction(auction)
+                    )
+                )
+            );
+
+            // Trigger event
+            Self::deposit_event(
+                RawEvent::DutchAuctionStarted(owner_id, video_id, auction_params)
+            );
+        }
+
+        /// Cancel video nft dutch auction
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::cancel_dutch_auction()]
+        pub fn cancel_dutch_auction(
+            origin,
+            owner_id: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            video_id: T::VideoId,
+        ) {
+            // Ensure given video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            // Ensure nft is already issued
+            let nft = video.ensure_nft_is_issued::<T>()?;
+
+            // block extrinsics during transfers
+            Self::channel_by_id(video.in_channel).ensure_has_no_active_transfer::<T>()?;
+
+            // Authorize nft owner
+            ensure_actor_authorized_to_manage_nft::<T>(
+                origin,
+                &owner_id,
+                &nft.owner,
+                video.in_channel
+            )?;
+
+            // Ensure nft is in dutch auction state (no bids are ever locked, so it can always
+            // be canceled)
+            Self::ensure_in_dutch_auction_state(&nft)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Cancel auction
+            let updated_nft = nft.with_transactional_status(TransactionalStatus::<T>::Idle);
+
+            VideoById::<T>::mutate(video_id, |v| v.set_nft_status(updated_nft));
+
+            // Trigger event
+            Self::deposit_event(RawEvent::AuctionCanceled(owner_id, video_id));
+        }
+
         /// Cancel Nft offer
         ///
         /// <weight>
@@ -2290,14 +3187,6 @@ decl_module! {
             let participant_account_id = ensure_signed(origin)?;
             ensure_member_auth_success::<T>(&participant_account_id, &participant_id)?;
 
-            // Balance check
-            let maybe_old_bid = Self::ensure_open_bid_exists(video_id, participant_id).ok();
-            let old_bid_value = maybe_old_bid.as_ref().map(|bid| bid.amount);
-            Self::ensure_has_sufficient_balance_for_bid(&participant_account_id,
-                bid_amount,
-                old_bid_value
-            )?;
-
             // Ensure nft is already issued
             let video = Self::ensure_video_exists(&video_id)?;
             let nft = video.ensure_nft_is_issued::<T>()?;
@@ -2305,6 +3194,26 @@ decl_module! {
             // block during transfers
             Self::channel_by_id(video.in_channel).ensure_has_no_active_transfer::<T>()?;
 
+            // Currency the nft is priced in
+            let payment_token = Self::ensure_nft_payment_token(&video, &nft)?;
+
+            // Balance check
+            let maybe_old_bid = Self::ensure_open_bid_exists(video_id, participant_id).ok();
+            if let Some(old_bid) = maybe_old_bid.as_ref() {
+                ensure!(
+                    old_bid.in_creator_token == payment_token.is_some(),
+                    Error::<T>::OpenAuctionBidCurrencyMismatch
+                );
+            }
+            let old_bid_value = maybe_old_bid.as_ref().map(|bid| bid.amount);
+            Self::ensure_has_sufficient_balance_for_bid(
+                &participant_account_id,
+                participant_id,
+                bid_amount,
+                old_bid_value,
+                payment_token,
+            )?;
+
             // Validate parameters & return english auction
             let open_auction = Self::ensure_in_open_auction_state(&nft)?;
 
@@ -2316,9 +3225,13 @@ decl_module! {
             open_auction.ensure_auction_started::<T>(current_block)?;
 
             // ensure bid can be made
-            let maybe_old_bid = Self::ensure_open_bid_exists(video_id, participant_id).ok();
             open_auction.ensure_can_make_bid::<T>(current_block, bid_amount, &maybe_old_bid)?;
 
+            // creator token payment recipients, if any
+            let creator_token_payment = payment_token
+                .map(|token_id| Self::ensure_creator_token_payment(token_id, &video, &nft))
+                .transpose()?;
+
             //
             // == MUTATION_SAFE ==
             //
@@ -2328,8 +3241,10 @@ decl_module! {
                     // Make a new bid considering the old one (if any) and the "buy-now-price".
                     Self::transfer_bid_to_treasury(
                         &participant_account_id,
+                        participant_id,
                         buy_now_price,
-                        old_bid_value
+                        old_bid_value,
+                        payment_token,
                     )?;
 
                     // complete auction @ buy_now_price
@@ -2340,6 +3255,7 @@ decl_module! {
                         royalty_payment,
                         participant_id,
                         buy_now_price,
+                        creator_token_payment,
                     )?;
 
                     (
@@ -2351,14 +3267,16 @@ decl_module! {
                     // Make a new bid considering the old one (if any).
                     Self::transfer_bid_to_treasury(
                         &participant_account_id,
+                        participant_id,
                         bid_amount,
-                        old_bid_value
+                        old_bid_value,
+                        payment_token,
                     )?;
 
                     OpenAuctionBidByVideoAndMember::<T>::insert(
                         video_id,
                         participant_id,
-                        open_auction.make_bid(bid_amount, current_block),
+                        open_auction.make_bid(bid_amount, current_block, payment_token.is_some()),
                     );
 
                     (nft,RawEvent::AuctionBidMade(participant_id, video_id, bid_amount, None))
@@ -2402,6 +3320,9 @@ decl_module! {
             // Validate parameters & return english auction
             let eng_auction = Self::ensure_in_english_auction_state(&nft)?;
 
+            // Currency the nft is priced in
+            let payment_token = Self::ensure_nft_payment_token(&video, &nft)?;
+
             // Balance check
             let old_bid_value = eng_auction.top_bid.as_ref().map(|bid| {
                 if bid.bidder_id == participant_id {
@@ -2412,8 +3333,10 @@ decl_module! {
             });
             Self::ensure_has_sufficient_balance_for_bid(
                 &participant_account_id,
+                participant_id,
                 bid_amount,
-                old_bid_value
+                old_bid_value,
+                payment_token,
             )?;
 
             // Ensure auction is not expired
@@ -2431,6 +3354,11 @@ decl_module! {
 
             let prev_top_bidder = eng_auction.top_bid.as_ref().map(|b| b.bidder_id);
 
+            // creator token payment recipients, if any
+            let creator_token_payment = payment_token
+                .map(|token_id| Self::ensure_creator_token_payment(token_id, &video, &nft))
+                .transpose()?;
+
             //
             // == MUTATION_SAFE ==
             //
@@ -2438,7 +3366,12 @@ decl_module! {
             if let Some(bid) = eng_auction.top_bid.as_ref() {
                 let bidder_account_id =
                     T::MemberAuthenticator::controller_account_id(bid.bidder_id)?;
-                Self::withdraw_bid_payment(&bidder_account_id, bid.amount)?;
+                Self::withdraw_bid_payment(
+                    &bidder_account_id,
+                    bid.bidder_id,
+                    bid.amount,
+                    payment_token,
+                )?;
             };
 
             let (updated_nft, event) = match eng_auction.buy_now_price {
@@ -2446,8 +3379,10 @@ decl_module! {
                     // Make a new bid considering the "buy-now-price".
                     Self::transfer_bid_to_treasury(
                         &participant_account_id,
+                        participant_id,
                         buy_now_price,
-                        None
+                        None,
+                        payment_token,
                     )?;
 
                     // complete auction @ buy_now_price
@@ -2458,6 +3393,7 @@ decl_module! {
                         royalty_payment,
                         participant_id,
                         buy_now_price,
+                        creator_token_payment,
                     )?;
 
 
@@ -2471,8 +3407,10 @@ decl_module! {
                     // Make a new bid.
                     Self::transfer_bid_to_treasury(
                         &participant_account_id,
+                        participant_id,
                         bid_amount,
-                        None
+                        None,
+                        payment_token,
                     )?;
 
                     // update nft auction state
@@ -2532,11 +3470,23 @@ decl_module! {
                 open_auction.ensure_bid_can_be_canceled::<T>(current_block, &old_bid)?;
             } // else old bid
 
+            // Currency the bid was made in
+            let payment_token = if old_bid.in_creator_token {
+                Some(Self::channel_by_id(video.in_channel).ensure_creator_token_issued::<T>()?)
+            } else {
+                None
+            };
+
             //
             // == MUTATION SAFE ==
             //
 
-            Self::withdraw_bid_payment(&participant_account_id, old_bid.amount)?;
+            Self::withdraw_bid_payment(
+                &participant_account_id,
+                participant_id,
+                old_bid.amount,
+                payment_token,
+            )?;
 
             // remove
             OpenAuctionBidByVideoAndMember::<T>::remove(&video_id, &participant_id);
@@ -2580,6 +3530,9 @@ decl_module! {
             let current_block = <frame_system::Pallet<T>>::block_number();
             english_auction.ensure_auction_can_be_completed::<T>(current_block)?;
 
+            // creator token payment recipients, if any
+            let creator_token_payment = Self::ensure_nft_creator_token_payment(&video, &nft)?;
+
             //
             // == MUTATION SAFE ==
             //
@@ -2591,7 +3544,8 @@ decl_module! {
                 &video,
                 royalty_payment,
                 top_bidder_id,
-                top_bid.amount
+                top_bid.amount,
+                creator_token_payment,
             )?;
 
             // Update the video
@@ -2649,6 +3603,9 @@ decl_module! {
             // Ensure commit matches amount
             bid.ensure_valid_bid_commit::<T>(commit)?;
 
+            // creator token payment recipients, if any
+            let creator_token_payment = Self::ensure_nft_creator_token_payment(&video, &nft)?;
+
             //
             // == MUTATION SAFE ==
             //
@@ -2660,6 +3617,7 @@ decl_module! {
                 royalty_payment,
                 winner_id,
                 bid.amount,
+                creator_token_payment,
             )?;
 
             // remove bid
@@ -2814,8 +3772,16 @@ decl_module! {
             // Ensure nft is already issued
             let nft = video.ensure_nft_is_issued::<T>()?;
 
+            // creator token payment recipients, if any
+            let creator_token_payment = Self::ensure_nft_creator_token_payment(&video, &nft)?;
+
             // Ensure new pending offer is available to proceed
-            Self::ensure_new_pending_offer_available_to_proceed(&nft, &receiver_account_id, witness_price)?;
+            Self::ensure_new_pending_offer_available_to_proceed(
+                &nft,
+                &receiver_account_id,
+                witness_price,
+                creator_token_payment.as_ref().map(|payment| payment.token_id),
+            )?;
 
             // account_id where the nft offer price is deposited
             let nft_owner_account = Self::ensure_nft_owner_has_beneficiary_account(&video, &nft).ok();
@@ -2830,7 +3796,8 @@ decl_module! {
                 nft,
                 royalty_payment,
                 nft_owner_account,
-                receiver_account_id
+                receiver_account_id,
+                creator_token_payment,
             )?;
 
             VideoById::<T>::mutate(video_id, |v| v.set_nft_status(nft));
@@ -2921,8 +3888,17 @@ decl_module! {
             // Ensure nft is already issued
             let nft = video.ensure_nft_is_issued::<T>()?;
 
+            // creator token payment recipients, if any
+            let creator_token_payment = Self::ensure_nft_creator_token_payment(&video, &nft)?;
+
             // Ensure given participant can buy nft now
-            Self::ensure_can_buy_now(&nft, &participant_account_id, witness_price)?;
+            Self::ensure_can_buy_now(
+                &nft,
+                &participant_account_id,
+                participant_id,
+                witness_price,
+                creator_token_payment.as_ref().map(|payment| payment.token_id),
+            )?;
 
             // seller account
             let old_nft_owner_account_id = Self::ensure_nft_owner_has_beneficiary_account(&video, &nft).ok();
@@ -2938,7 +3914,8 @@ decl_module! {
                 royalty_payment,
                 old_nft_owner_account_id,
                 participant_account_id,
-                participant_id
+                participant_id,
+                creator_token_payment,
             )?;
 
             VideoById::<T>::mutate(video_id, |v| v.set_nft_status(nft));
@@ -2947,6 +3924,225 @@ decl_module! {
             Self::deposit_event(RawEvent::NftBought(video_id, participant_id));
         }
 
+        /// Buy Nft on dutch auction at its current price, settling the auction
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::buy_nft_on_dutch_auction()]
+        pub fn buy_nft_on_dutch_auction(
+            origin,
+            video_id: T::VideoId,
+            participant_id: T::MemberId,
+            max_price: BalanceOf<T>, // in order to avoid front running
+        ) {
+            // Authorize participant under given member id
+            let participant_account_id = ensure_signed(origin)?;
+            ensure_member_auth_success::<T>(&participant_account_id, &participant_id)?;
+
+            // Ensure given video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            // block during channel transfer
+            Self::channel_by_id(video.in_channel).ensure_has_no_active_transfer::<T>()?;
+
+            // Ensure nft is already issued
+            let nft = video.ensure_nft_is_issued::<T>()?;
+
+            // Ensure nft is in dutch auction state
+            let dutch_auction = Self::ensure_in_dutch_auction_state(&nft)?;
+
+            // Ensure auction started and participant is allowed to buy
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            dutch_auction.ensure_auction_started::<T>(current_block)?;
+            dutch_auction.ensure_whitelisted_participant::<T>(participant_id)?;
+
+            // Ensure current price is acceptable for the participant
+            let price = dutch_auction.current_price(current_block);
+            ensure!(price <= max_price, Error::<T>::DutchAuctionPriceExceedsMaxPrice);
+
+            // creator token payment recipients, if any
+            let creator_token_payment = Self::ensure_nft_creator_token_payment(&video, &nft)?;
+
+            Self::ensure_sufficient_balance_to_pay_for_nft(
+                &participant_account_id,
+                participant_id,
+                price,
+                creator_token_payment.as_ref().map(|payment| payment.token_id),
+            )?;
+
+            // seller account
+            let old_nft_owner_account_id = Self::ensure_nft_owner_has_beneficiary_account(&video, &nft).ok();
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Complete payment
+            if let Some(ref payment) = creator_token_payment {
+                T::ProjectToken::lock_in_escrow(
+                    payment.token_id,
+                    participant_id,
+                    Self::creator_token_amount(price)?,
+                )?;
+                Self::complete_creator_token_payment(
+                    payment,
+                    nft.creator_royalty,
+                    price,
+                    participant_id,
+                )?;
+            } else {
+                let royalty_payment = Self::build_royalty_payment(&video, nft.creator_royalty);
+                Self::complete_payment(
+                    royalty_payment,
+                    price,
+                    participant_account_id,
+                    old_nft_owner_account_id,
+                )?;
+            }
+
+            let updated_nft = nft
+                .with_transactional_status(TransactionalStatus::<T>::Idle)
+                .with_member_owner(participant_id);
+
+            VideoById::<T>::mutate(video_id, |v| v.set_nft_status(updated_nft));
+
+            // Trigger event
+            Self::deposit_event(RawEvent::NftBoughtOnDutchAuction(video_id, participant_id, price));
+        }
+
+        /// Set whether nft prices & bids are denominated in the channel's creator token
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1)
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::set_nft_priced_in_creator_token()]
+        pub fn set_nft_priced_in_creator_token(
+            origin,
+            owner_id: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            video_id: T::VideoId,
+            priced_in_creator_token: bool,
+        ) {
+            // Ensure given video exists
+            let video = Self::ensure_video_exists(&video_id)?;
+
+            // Ensure nft is already issued
+            let nft = video.ensure_nft_is_issued::<T>()?;
+
+            // block extrinsics during transfers
+            Self::channel_by_id(video.in_channel).ensure_has_no_active_transfer::<T>()?;
+
+            // Authorize nft owner
+            ensure_actor_authorized_to_manage_nft::<T>(
+                origin,
+                &owner_id,
+                &nft.owner,
+                video.in_channel
+            )?;
+
+            // Prices & bids currency cannot change while nft is on sale / auction
+            Self::ensure_nft_transactional_status_is_idle(&nft)?;
+
+            // Ensure channel has a creator token the nft beneficiary can be paid in
+            if priced_in_creator_token {
+                let token_id = Self::channel_by_id(video.in_channel)
+                    .ensure_creator_token_issued::<T>()?;
+                Self::ensure_creator_token_payment(token_id, &video, &nft)?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            VideoById::<T>::mutate(
+                video_id,
+                |v| v.set_nft_status(Nft::<T> {
+                    priced_in_creator_token,
+                    ..nft
+                })
+            );
+
+            // Trigger event
+            Self::deposit_event(
+                RawEvent::NftCreatorTokenPricingSet(owner_id, video_id, priced_in_creator_token)
+            );
+        }
+
+        /// Set split of the royalties paid on channel nfts secondary sales.
+        /// Each recipient receives its share of the royalty, the remaining royalty goes to
+        /// the channel. An empty split removes the existing one.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (R)`
+        /// - DB:
+        ///    - O(1)
+        /// where:
+        /// - R is the number of royalty split recipients
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::set_channel_royalty_split(royalty_split.len() as u32)]
+        pub fn set_channel_royalty_split(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            royalty_split: BTreeMap<RoyaltyRecipientOf<T>, Perbill>,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_manage_royalty_split::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure member recipients are valid
+            Self::validate_member_set(
+                &royalty_split
+                    .keys()
+                    .filter_map(|recipient| match recipient {
+                        RoyaltyRecipient::Member(member_id) => Some(*member_id),
+                        RoyaltyRecipient::Account(_) => None,
+                    })
+                    .collect()
+            )?;
+
+            // Ensure shares do not exceed the whole royalty
+            let total_shares = royalty_split
+                .values()
+                .fold(0u64, |total, share| total.saturating_add(share.deconstruct().into()));
+            ensure!(
+                total_shares <= u64::from(Perbill::one().deconstruct()),
+                Error::<T>::RoyaltySplitSharesExceedRoyalty
+            );
+
+            let stored_royalty_split: RoyaltySplit<T> = royalty_split
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::MaxRoyaltySplitRecipientsExceeded)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if stored_royalty_split.is_empty() {
+                RoyaltySplitByChannelId::<T>::remove(channel_id);
+            } else {
+                RoyaltySplitByChannelId::<T>::insert(channel_id, stored_royalty_split);
+            }
+
+            Self::deposit_event(RawEvent::ChannelRoyaltySplitSet(actor, channel_id, royalty_split));
+        }
+
         /// Only Council can toggle nft issuance limits constraints
         /// <weight>
         ///
@@ -3655,13 +4851,110 @@ decl_module! {
             let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
 
             // Call to ProjectToken - should be the first call before MUTATION SAFE!
-            T::ProjectToken::finalize_revenue_split(
-                token_id,
-                reward_account, // send any leftovers back to reward_account
+            T::ProjectToken::finalize_revenue_split(
+                token_id,
+                reward_account, // send any leftovers back to reward_account
+            )?;
+        }
+
+        /// Finalize an ended creator token sale
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::finalize_creator_token_sale()]
+        pub fn finalize_creator_token_sale(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            let (_, permissions) = ensure_actor_authorized_to_init_and_manage_creator_token_sale::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // If channel agent - make sure the sale was initialized by this agent!
+            if permissions.is_some() {
+                let member_id = get_member_id_of_actor::<T>(&actor)?;
+                ensure!(
+                    project_token::Module::<T>::token_info_by_id(token_id)
+                        .sale
+                        .map_or(false, |s| s.tokens_source == member_id),
+                    Error::<T>::ChannelAgentInsufficientPermissions
+                );
+            }
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            let joy_collected = T::ProjectToken::finalize_token_sale(token_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Curator channels - increase council budget by the amount of tokens collected
+            // and burned during the sale
+            if let ChannelOwner::CuratorGroup(_) = channel.owner {
+                T::CouncilBudgetManager::increase_budget(joy_collected);
+            }
+        }
+
+        /// Deissue channel's creator token
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::deissue_creator_token()]
+        pub fn deissue_creator_token(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_deissue_creator_token::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::deissue_token(
+                token_id
             )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ChannelById::<T>::mutate(&channel_id, |channel| {
+                channel.creator_token_id = None;
+            });
         }
 
-        /// Finalize an ended creator token sale
+        /// Activate bonding curve AMM for channel's creator token
         ///
         /// <weight>
         ///
@@ -3670,18 +4963,19 @@ decl_module! {
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoContent::<T>::finalize_creator_token_sale()]
-        pub fn finalize_creator_token_sale(
+        #[weight = WeightInfoContent::<T>::activate_amm()]
+        pub fn activate_amm(
             origin,
             actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
             channel_id: T::ChannelId,
+            params: AmmParamsOf<T>,
         ) {
             let channel = Self::ensure_channel_exists(&channel_id)?;
 
             channel.ensure_has_no_active_transfer::<T>()?;
 
             // Permissions check
-            let (_, permissions) = ensure_actor_authorized_to_init_and_manage_creator_token_sale::<T>(
+            ensure_actor_authorized_to_manage_amm::<T>(
                 origin,
                 &actor,
                 &channel
@@ -3690,32 +4984,18 @@ decl_module! {
             // Ensure token was issued
             let token_id = channel.ensure_creator_token_issued::<T>()?;
 
-            // If channel agent - make sure the sale was initialized by this agent!
-            if permissions.is_some() {
-                let member_id = get_member_id_of_actor::<T>(&actor)?;
-                ensure!(
-                    project_token::Module::<T>::token_info_by_id(token_id)
-                        .sale
-                        .map_or(false, |s| s.tokens_source == member_id),
-                    Error::<T>::ChannelAgentInsufficientPermissions
-                );
-            }
+            // Get channel's reward account
+            let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
 
             // Call to ProjectToken - should be the first call before MUTATION SAFE!
-            let joy_collected = T::ProjectToken::finalize_token_sale(token_id)?;
-
-            //
-            // == MUTATION SAFE ==
-            //
-
-            // Curator channels - increase council budget by the amount of tokens collected
-            // and burned during the sale
-            if let ChannelOwner::CuratorGroup(_) = channel.owner {
-                T::CouncilBudgetManager::increase_budget(joy_collected);
-            }
+            T::ProjectToken::activate_amm(
+                token_id,
+                Some(reward_account), // trading fees go to reward_account
+                params,
+            )?;
         }
 
-        /// Deissue channel's creator token
+        /// Deactivate bonding curve AMM of channel's creator token
         ///
         /// <weight>
         ///
@@ -3724,8 +5004,8 @@ decl_module! {
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoContent::<T>::deissue_creator_token()]
-        pub fn deissue_creator_token(
+        #[weight = WeightInfoContent::<T>::deactivate_amm()]
+        pub fn deactivate_amm(
             origin,
             actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
             channel_id: T::ChannelId,
@@ -3735,7 +5015,7 @@ decl_module! {
             channel.ensure_has_no_active_transfer::<T>()?;
 
             // Permissions check
-            ensure_actor_authorized_to_deissue_creator_token::<T>(
+            ensure_actor_authorized_to_manage_amm::<T>(
                 origin,
                 &actor,
                 &channel
@@ -3744,18 +5024,14 @@ decl_module! {
             // Ensure token was issued
             let token_id = channel.ensure_creator_token_issued::<T>()?;
 
+            // Get channel's reward account
+            let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
+
             // Call to ProjectToken - should be the first call before MUTATION SAFE!
-            T::ProjectToken::deissue_token(
-                token_id
+            T::ProjectToken::deactivate_amm(
+                token_id,
+                reward_account, // send remaining reserve to reward_account
             )?;
-
-            //
-            // == MUTATION SAFE ==
-            //
-
-            ChannelById::<T>::mutate(&channel_id, |channel| {
-                channel.creator_token_id = None;
-            });
         }
     }
 }
@@ -3836,6 +5112,46 @@ impl<T: Config> Module<T> {
                 )?;
                 Ok(TransactionalStatus::<T>::OpenAuction(open_auction))
             }
+            InitTransactionalStatus::<T>::DutchAuction(ref params) => {
+                Self::validate_dutch_auction_params(params)?;
+                let current_block = <frame_system::Pallet<T>>::block_number();
+                let dutch_auction = DutchAuction::<T>::try_new::<T>(params.clone(), current_block)?;
+                Ok(TransactionalStatus::<T>::DutchAuction(dutch_auction))
+            }
+        }
+    }
+
+    // Replace the whitelist of the auction nft is issued in with the collection one, unless the
+    // collection has no whitelist
+    fn with_nft_collection_whitelist(
+        init_status: InitTransactionalStatus<T>,
+        collection: &NftCollection<T>,
+    ) -> InitTransactionalStatus<T> {
+        if collection.whitelist.is_empty() {
+            return init_status;
+        }
+
+        let whitelist = collection.whitelist.clone().into_inner();
+        match init_status {
+            InitTransactionalStatus::<T>::EnglishAuction(params) => {
+                InitTransactionalStatus::<T>::EnglishAuction(EnglishAuctionParams::<T> {
+                    whitelist,
+                    ..params
+                })
+            }
+            InitTransactionalStatus::<T>::OpenAuction(params) => {
+                InitTransactionalStatus::<T>::OpenAuction(OpenAuctionParams::<T> {
+                    whitelist,
+                    ..params
+                })
+            }
+            InitTransactionalStatus::<T>::DutchAuction(params) => {
+                InitTransactionalStatus::<T>::DutchAuction(DutchAuctionParams::<T> {
+                    whitelist,
+                    ..params
+                })
+            }
+            status => status,
         }
     }
 
@@ -3965,6 +5281,12 @@ impl<T: Config> Module<T> {
         // Remove video
         VideoById::<T>::remove(video_id);
 
+        // Remove video from channel playlists
+        Self::remove_video_from_channel_playlists(channel_id, video_id);
+
+        // Cancel scheduled video visibility changes
+        Self::unschedule_video_visibility_changes(video_id);
+
         // Update corresponding channel
         // Remove recently deleted video from the channel
         ChannelById::<T>::mutate(channel_id, |channel| {
@@ -3984,6 +5306,359 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    fn process_scheduled_video_visibility_changes(now: T::BlockNumber) -> Weight {
+        let changes = ScheduledVideoVisibilityChangesByBlock::<T>::take(now);
+        let changes_num = changes.len() as u32;
+
+        for video_id in changes.into_iter() {
+            Self::apply_scheduled_video_visibility_change(now, video_id);
+        }
+
+        WeightInfoContent::<T>::on_initialize_video_visibility_changes(changes_num)
+    }
+
+    // Pull up to `MaxSubscriptionPaymentsPerBlock` due payments, starting from the queue head.
+    // Payments that do not fit are left in the queue and pulled in the following blocks.
+    fn process_scheduled_subscription_payments(now: T::BlockNumber) -> Weight {
+        let max_payments = T::MaxSubscriptionPaymentsPerBlock::get();
+        let mut block = Self::subscription_payments_queue_head().unwrap_or(now);
+        let mut payments_num = 0u32;
+        let mut blocks_num = 0u32;
+
+        while block <= now && payments_num < max_payments && blocks_num < max_payments {
+            let subscription_ids: Vec<_> =
+                ScheduledSubscriptionPaymentsByBlock::<T>::iter_prefix(block)
+                    .map(|(subscription_id, _)| subscription_id)
+                    .take(max_payments.saturating_sub(payments_num) as usize)
+                    .collect();
+
+            for subscription_id in subscription_ids.iter() {
+                ScheduledSubscriptionPaymentsByBlock::<T>::remove(block, subscription_id);
+                Self::process_subscription_payment(now, *subscription_id);
+            }
+
+            payments_num = payments_num.saturating_add(subscription_ids.len() as u32);
+            blocks_num = blocks_num.saturating_add(1);
+
+            if ScheduledSubscriptionPaymentsByBlock::<T>::iter_prefix(block)
+                .next()
+                .is_some()
+            {
+                break;
+            }
+            block = block.saturating_add(One::one());
+        }
+
+        SubscriptionPaymentsQueueHead::<T>::put(block);
+
+        WeightInfoContent::<T>::on_initialize_subscription_payments(payments_num)
+            .saturating_add(T::DbWeight::get().reads_writes(blocks_num.into(), 1))
+    }
+
+    // Pull a due subscription payment and schedule the next one, or terminate the subscription
+    fn process_subscription_payment(now: T::BlockNumber, subscription_id: T::SubscriptionId) {
+        if !SubscriptionById::<T>::contains_key(subscription_id) {
+            return;
+        }
+        let mut subscription = Self::subscription_by_id(subscription_id);
+
+        match Self::try_to_pull_subscription_payment(now, &subscription) {
+            Ok(next_payment_at) => {
+                subscription.remaining_payments = subscription.remaining_payments.saturating_sub(1);
+                subscription.next_payment_at = next_payment_at;
+                Self::schedule_subscription_payment(subscription_id, next_payment_at);
+                SubscriptionById::<T>::insert(subscription_id, subscription.clone());
+
+                Self::deposit_event(RawEvent::SubscriptionPaymentPulled(
+                    subscription_id,
+                    subscription.payment,
+                ));
+            }
+            Err(reason) => {
+                SubscriptionById::<T>::remove(subscription_id);
+
+                // The module account holds the bloat bonds, so the repayment cannot fail
+                let _ = subscription.subscription_state_bloat_bond.repay::<T>(
+                    &ContentTreasury::<T>::module_account_id(),
+                    &subscription.payer,
+                    false,
+                );
+
+                Self::deposit_event(RawEvent::SubscriptionLapsed(subscription_id, reason));
+            }
+        }
+    }
+
+    // Pull a due subscription payment, returning the block the next payment is scheduled for.
+    // A payment pulled late (queue backlog) is followed by the next one a full period later.
+    fn try_to_pull_subscription_payment(
+        now: T::BlockNumber,
+        subscription: &Subscription<T>,
+    ) -> Result<T::BlockNumber, SubscriptionLapseReason> {
+        ensure!(
+            ChannelById::<T>::contains_key(subscription.channel_id),
+            SubscriptionLapseReason::ChannelDeleted
+        );
+        ensure!(
+            subscription.remaining_payments > 0,
+            SubscriptionLapseReason::PaymentsCapReached
+        );
+
+        let next_payment_at = now
+            .max(subscription.next_payment_at)
+            .saturating_add(subscription.period);
+
+        Self::ensure_can_pull_subscription_payment(subscription)
+            .and_then(|_| Self::pull_subscription_payment(subscription))
+            .map_err(|_| SubscriptionLapseReason::PaymentFailed)?;
+
+        Ok(next_payment_at)
+    }
+
+    fn schedule_subscription_payment(subscription_id: T::SubscriptionId, block: T::BlockNumber) {
+        ScheduledSubscriptionPaymentsByBlock::<T>::insert(block, subscription_id, ());
+    }
+
+    fn remove_subscription(subscription_id: T::SubscriptionId, subscription: &Subscription<T>) {
+        ScheduledSubscriptionPaymentsByBlock::<T>::remove(
+            subscription.next_payment_at,
+            subscription_id,
+        );
+        SubscriptionById::<T>::remove(subscription_id);
+    }
+
+    fn ensure_subscription_exists(
+        subscription_id: &T::SubscriptionId,
+    ) -> Result<Subscription<T>, Error<T>> {
+        ensure!(
+            SubscriptionById::<T>::contains_key(subscription_id),
+            Error::<T>::SubscriptionDoesNotExist
+        );
+        Ok(SubscriptionById::<T>::get(subscription_id))
+    }
+
+    // Member receiving subscription payments in the channel's creator token
+    fn ensure_subscription_creator_token_beneficiary(
+        channel: &Channel<T>,
+    ) -> Result<(T::TokenId, T::MemberId), DispatchError> {
+        let token_id = channel.ensure_creator_token_issued::<T>()?;
+        match channel.owner {
+            ChannelOwner::Member(member_id)
+                if project_token::AccountInfoByTokenAndMember::<T>::contains_key(
+                    token_id, member_id,
+                ) =>
+            {
+                Ok((token_id, member_id))
+            }
+            _ => Err(Error::<T>::SubscriptionBeneficiaryHasNoCreatorTokenAccount.into()),
+        }
+    }
+
+    fn ensure_can_pull_subscription_payment(subscription: &Subscription<T>) -> DispatchResult {
+        let channel = Self::ensure_channel_exists(&subscription.channel_id)?;
+        match subscription.payment {
+            SubscriptionPaymentRecord::Joy(amount) => {
+                ensure!(
+                    has_sufficient_balance_for_payment::<T>(&subscription.payer, amount),
+                    Error::<T>::InsufficientBalanceForSubscriptionPayment
+                );
+            }
+            SubscriptionPaymentRecord::CreatorToken(amount) => {
+                let (token_id, _) = Self::ensure_subscription_creator_token_beneficiary(&channel)?;
+                let transferrable = project_token::Module::<T>::account_info_by_token_and_member(
+                    token_id,
+                    subscription.member_id,
+                )
+                .transferrable::<T>(<frame_system::Pallet<T>>::block_number());
+                ensure!(
+                    transferrable >= amount,
+                    Error::<T>::InsufficientCreatorTokenBalance
+                );
+            }
+        }
+        Ok(())
+    }
+
+    // Pull a subscription payment, checked with `ensure_can_pull_subscription_payment`
+    fn pull_subscription_payment(subscription: &Subscription<T>) -> DispatchResult {
+        match subscription.payment {
+            SubscriptionPaymentRecord::Joy(amount) => {
+                let channel_account =
+                    ContentTreasury::<T>::account_for_channel(subscription.channel_id);
+                <Balances<T> as Currency<T::AccountId>>::transfer(
+                    &subscription.payer,
+                    &channel_account,
+                    amount,
+                    ExistenceRequirement::KeepAlive,
+                )
+            }
+            SubscriptionPaymentRecord::CreatorToken(amount) => {
+                let channel = Self::channel_by_id(subscription.channel_id);
+                let (token_id, beneficiary_id) =
+                    Self::ensure_subscription_creator_token_beneficiary(&channel)?;
+                T::ProjectToken::lock_in_escrow(token_id, subscription.member_id, amount)?;
+                T::ProjectToken::release_from_escrow(
+                    token_id,
+                    subscription.member_id,
+                    beneficiary_id,
+                    amount,
+                )
+            }
+        }
+    }
+
+    // Ensure scheduled blocks are in the future, in order, and have room for the change
+    fn ensure_valid_video_visibility_schedule(
+        video_id: T::VideoId,
+        schedule: &VideoVisibilityScheduleOf<T>,
+    ) -> DispatchResult {
+        let now = frame_system::Pallet::<T>::block_number();
+
+        if let (Some(publish_at), Some(unpublish_at)) = (schedule.publish_at, schedule.unpublish_at)
+        {
+            ensure!(
+                publish_at != unpublish_at,
+                Error::<T>::InvalidVideoVisibilitySchedule
+            );
+        }
+
+        for block in schedule
+            .publish_at
+            .iter()
+            .chain(schedule.unpublish_at.iter())
+        {
+            ensure!(*block > now, Error::<T>::VideoVisibilityScheduledInThePast);
+
+            // changes of this video scheduled at the same block are going to be replaced
+            let other_changes_num = Self::scheduled_video_visibility_changes_by_block(block)
+                .iter()
+                .filter(|id| **id != video_id)
+                .count();
+            ensure!(
+                other_changes_num < T::MaxVideoVisibilityChangesPerBlock::get() as usize,
+                Error::<T>::MaxVideoVisibilityChangesPerBlockExceeded
+            );
+        }
+
+        Ok(())
+    }
+
+    // Remove the visibility schedule of a video together with its queued changes
+    fn unschedule_video_visibility_changes(video_id: T::VideoId) {
+        let schedule = VideoVisibilityScheduleById::<T>::take(video_id);
+        for block in schedule
+            .publish_at
+            .iter()
+            .chain(schedule.unpublish_at.iter())
+        {
+            ScheduledVideoVisibilityChangesByBlock::<T>::mutate_exists(block, |changes| {
+                if let Some(video_ids) = changes {
+                    video_ids.retain(|id| *id != video_id);
+                    if video_ids.is_empty() {
+                        *changes = None;
+                    }
+                }
+            });
+        }
+    }
+
+    fn apply_scheduled_video_visibility_change(now: T::BlockNumber, video_id: T::VideoId) {
+        let mut schedule = Self::video_visibility_schedule_by_id(video_id);
+
+        let is_hidden = if schedule.publish_at == Some(now) {
+            schedule.publish_at = None;
+            false
+        } else {
+            schedule.unpublish_at = None;
+            true
+        };
+
+        if schedule == VideoVisibilityScheduleOf::<T>::default() {
+            VideoVisibilityScheduleById::<T>::remove(video_id);
+        } else {
+            VideoVisibilityScheduleById::<T>::insert(video_id, schedule);
+        }
+
+        Self::deposit_event(RawEvent::VideoVisibilitySetBySchedule(video_id, is_hidden));
+    }
+
+    fn ensure_playlist_exists(playlist_id: &T::PlaylistId) -> Result<Playlist<T>, Error<T>> {
+        ensure!(
+            PlaylistById::<T>::contains_key(playlist_id),
+            Error::<T>::PlaylistDoesNotExist
+        );
+        Ok(PlaylistById::<T>::get(playlist_id))
+    }
+
+    // Ensure playlist videos are distinct videos of the channel
+    fn ensure_valid_playlist_videos(
+        channel_id: T::ChannelId,
+        videos: &[T::VideoId],
+    ) -> Result<PlaylistVideos<T>, DispatchError> {
+        ensure!(
+            videos.len() <= T::MaxVideosPerPlaylist::get() as usize,
+            Error::<T>::MaxVideosPerPlaylistExceeded
+        );
+
+        let distinct_videos: BTreeSet<_> = videos.iter().collect();
+        ensure!(
+            distinct_videos.len() == videos.len(),
+            Error::<T>::PlaylistContainsDuplicateVideos
+        );
+
+        for video_id in videos.iter() {
+            let video = Self::ensure_video_exists(video_id)?;
+            ensure!(
+                video.in_channel == channel_id,
+                Error::<T>::VideoNotInPlaylistChannel
+            );
+        }
+
+        videos
+            .to_vec()
+            .try_into()
+            .map_err(|_| Error::<T>::MaxVideosPerPlaylistExceeded.into())
+    }
+
+    fn try_to_perform_playlist_deletion(
+        sender: &T::AccountId,
+        playlist_id: T::PlaylistId,
+        playlist: &Playlist<T>,
+        slash_bloat_bond: bool,
+    ) -> DispatchResult {
+        // Remove playlist
+        PlaylistById::<T>::remove(playlist_id);
+        PlaylistsByChannelId::<T>::remove(playlist.in_channel, playlist_id);
+
+        // Update corresponding channel
+        ChannelById::<T>::mutate(playlist.in_channel, |channel| {
+            channel.num_playlists = channel.num_playlists.saturating_sub(1)
+        });
+
+        // Repay/slash playlist state bloat bond
+        let module_account = ContentTreasury::<T>::module_account_id();
+        if slash_bloat_bond {
+            burn_from_usable::<T>(&module_account, playlist.playlist_state_bloat_bond.amount)?;
+        } else {
+            playlist
+                .playlist_state_bloat_bond
+                .repay::<T>(&module_account, sender, false)?;
+        }
+
+        Ok(())
+    }
+
+    // Remove a deleted video from the playlists of its channel
+    fn remove_video_from_channel_playlists(channel_id: T::ChannelId, video_id: T::VideoId) {
+        for (playlist_id, _) in PlaylistsByChannelId::<T>::iter_prefix(channel_id) {
+            let mut playlist = PlaylistById::<T>::get(playlist_id);
+            if playlist.videos.contains(&video_id) {
+                playlist.videos.retain(|id| *id != video_id);
+                PlaylistById::<T>::insert(playlist_id, playlist);
+            }
+        }
+    }
+
     fn ensure_channel_bag_can_be_dropped(
         channel_id: T::ChannelId,
         num_objects_to_delete: u64,
@@ -4021,8 +5696,25 @@ impl<T: Config> Module<T> {
         // == MUTATION SAFE ==
         //
 
+        // remove channel playlists, repay or slash their bloat bonds
+        for (playlist_id, _) in
+            PlaylistsByChannelId::<T>::iter_prefix(channel_id).collect::<Vec<_>>()
+        {
+            let playlist = PlaylistById::<T>::get(playlist_id);
+            Self::try_to_perform_playlist_deletion(
+                sender,
+                playlist_id,
+                &playlist,
+                slash_bloat_bond,
+            )?;
+        }
+
+        // remove channel subscription tiers
+        SubscriptionTiersByChannelId::<T>::remove(channel_id);
+
         // remove channel from on chain state
         ChannelById::<T>::remove(channel_id);
+        RoyaltySplitByChannelId::<T>::remove(channel_id);
 
         // Slash or repay channel state bloat bond
         let channel_account = ContentTreasury::<T>::account_for_channel(channel_id);
@@ -4232,33 +5924,42 @@ impl<T: Config> Module<T> {
     }
 
     // Increment NFT numbers for a channel and global counters.
-    fn increment_nft_counters(channel: &mut Channel<T>) {
-        Self::increment_global_nft_counters();
-        channel.increment_channel_nft_counters(frame_system::Pallet::<T>::block_number());
+    fn increment_nft_counters(channel: &mut Channel<T>, number: u64) {
+        Self::increment_global_nft_counters(number);
+        channel.increment_channel_nft_counters(frame_system::Pallet::<T>::block_number(), number);
     }
 
     // Increment global NFT counters (daily and weekly).
-    fn increment_global_nft_counters() {
+    fn increment_global_nft_counters(number: u64) {
         let current_block = frame_system::Pallet::<T>::block_number();
 
         let daily_limit = Self::global_daily_nft_limit();
         GlobalDailyNftCounter::<T>::mutate(|nft_counter| {
-            nft_counter.update_for_current_period(current_block, daily_limit.block_number_period);
+            nft_counter.update_for_current_period(
+                current_block,
+                daily_limit.block_number_period,
+                number,
+            );
         });
 
         let weekly_limit = Self::global_weekly_nft_limit();
         GlobalWeeklyNftCounter::<T>::mutate(|nft_counter| {
-            nft_counter.update_for_current_period(current_block, weekly_limit.block_number_period);
+            nft_counter.update_for_current_period(
+                current_block,
+                weekly_limit.block_number_period,
+                number,
+            );
         });
     }
 
-    // Checks all NFT-limits
-    fn check_nft_limits(channel: &Channel<T>) -> DispatchResult {
+    // Checks all NFT-limits allow issuing `number` more nfts
+    fn check_nft_limits(channel: &Channel<T>, number: u64) -> DispatchResult {
         if Self::nft_limits_enabled() {
             // Global daily limit.
             Self::check_generic_nft_limit(
                 &Self::global_daily_nft_limit(),
                 &Self::global_daily_nft_counter(),
+                number,
                 Error::<T>::GlobalNftDailyLimitExceeded,
             )?;
 
@@ -4266,6 +5967,7 @@ impl<T: Config> Module<T> {
             Self::check_generic_nft_limit(
                 &Self::global_weekly_nft_limit(),
                 &Self::global_weekly_nft_counter(),
+                number,
                 Error::<T>::GlobalNftWeeklyLimitExceeded,
             )?;
 
@@ -4273,6 +5975,7 @@ impl<T: Config> Module<T> {
             Self::check_generic_nft_limit(
                 &channel.daily_nft_limit,
                 &channel.daily_nft_counter,
+                number,
                 Error::<T>::ChannelNftDailyLimitExceeded,
             )?;
 
@@ -4280,6 +5983,7 @@ impl<T: Config> Module<T> {
             Self::check_generic_nft_limit(
                 &channel.weekly_nft_limit,
                 &channel.weekly_nft_counter,
+                number,
                 Error::<T>::ChannelNftWeeklyLimitExceeded,
             )?;
         }
@@ -4290,14 +5994,19 @@ impl<T: Config> Module<T> {
     fn check_generic_nft_limit(
         nft_limit: &LimitPerPeriod<T::BlockNumber>,
         nft_counter: &NftCounter<T::BlockNumber>,
+        number: u64,
         error: Error<T>,
     ) -> DispatchResult {
         ensure!(!nft_limit.limit.is_zero(), error);
 
         let current_block = frame_system::Pallet::<T>::block_number();
-        if nft_counter.is_current_period(current_block, nft_limit.block_number_period) {
-            ensure!(nft_counter.counter < nft_limit.limit, error);
-        }
+        let counter = if nft_counter.is_current_period(current_block, nft_limit.block_number_period)
+        {
+            nft_counter.counter
+        } else {
+            0
+        };
+        ensure!(counter.saturating_add(number) <= nft_limit.limit, error);
 
         Ok(())
     }
@@ -4463,6 +6172,7 @@ impl<T: Config> Module<T> {
         (match &nft_params.init_transactional_status {
             InitTransactionalStatus::<T>::EnglishAuction(params) => params.whitelist.len(),
             InitTransactionalStatus::<T>::OpenAuction(params) => params.whitelist.len(),
+            InitTransactionalStatus::<T>::DutchAuction(params) => params.whitelist.len(),
             _ => 0,
         }) as u32
     }
@@ -4489,8 +6199,15 @@ impl<T: Config> Module<T> {
         //collaborators
         let a = params.collaborators.len() as u32;
 
-        //storage_buckets
-        let b = params.storage_buckets.len() as u32;
+        //storage_buckets (picked by the storage pallet among all storage buckets if empty)
+        let (b, storage_buckets_selection_weight) = if params.storage_buckets.is_empty() {
+            (
+                <T as storage::Config>::MaxStorageBucketsPerBag::get(),
+                T::DbWeight::get().reads(<T as storage::Config>::MaxStorageBucketNumber::get()),
+            )
+        } else {
+            (params.storage_buckets.len() as u32, 0)
+        };
 
         //distribution_buckets
         let c = params.distribution_buckets.len() as u32;
@@ -4505,6 +6222,7 @@ impl<T: Config> Module<T> {
         let e = to_kb(params.meta.as_ref().map_or(0, |v| v.len()) as u32);
 
         WeightInfoContent::<T>::create_channel(a, b, c, d, e)
+            .saturating_add(storage_buckets_selection_weight)
     }
 
     // Calculates weight for update_channel extrinsic.
@@ -4558,6 +6276,37 @@ impl<T: Config> Module<T> {
         let c = (*channel_bag_witness).distribution_buckets_num;
 
         WeightInfoContent::<T>::delete_channel(a, b, c)
+            .saturating_add(Self::delete_channel_playlists_weight())
+    }
+
+    // Worst case weight of deleting all channel playlists on channel deletion
+    fn delete_channel_playlists_weight() -> Weight {
+        WeightInfoContent::<T>::delete_playlist()
+            .saturating_mul(T::MaxPlaylistsPerChannel::get().into())
+    }
+
+    // Worst case weight of removing a deleted video from all channel playlists
+    // and cancelling its scheduled visibility changes
+    fn video_deletion_cleanup_weight() -> Weight {
+        let max_playlists: Weight = T::MaxPlaylistsPerChannel::get().into();
+        T::DbWeight::get().reads_writes(
+            max_playlists.saturating_mul(2).saturating_add(3),
+            max_playlists.saturating_add(3),
+        )
+    }
+
+    // Calculates weight for create_playlist extrinsic.
+    fn create_playlist_weight(params: &PlaylistCreationParameters<T>) -> Weight {
+        let a = params.videos.len() as u32;
+        let b = to_kb(params.meta.as_ref().map_or(0, |m| m.len() as u32));
+        WeightInfoContent::<T>::create_playlist(a, b)
+    }
+
+    // Calculates weight for update_playlist extrinsic.
+    fn update_playlist_weight(params: &PlaylistUpdateParameters<T>) -> Weight {
+        let a = params.new_videos.as_ref().map_or(0, |v| v.len() as u32);
+        let b = to_kb(params.new_meta.as_ref().map_or(0, |m| m.len() as u32));
+        WeightInfoContent::<T>::update_playlist(a, b)
     }
 
     // Calculates weight for create_video extrinsic.
@@ -4648,6 +6397,7 @@ impl<T: Config> Module<T> {
         } else {
             WeightInfoContent::<T>::delete_video_without_assets()
         }
+        .saturating_add(Self::video_deletion_cleanup_weight())
     }
 
     fn channel_account_withdrawable_balance(
@@ -4739,6 +6489,7 @@ impl<T: Config> Module<T> {
         //rationale
         let d = to_kb((*rationale).len() as u32);
         WeightInfoContent::<T>::delete_channel_as_moderator(a, b, c, d)
+            .saturating_add(Self::delete_channel_playlists_weight())
     }
 
     // Calculates weight for set_channel_visibility_as_moderator extrinsic.
@@ -4796,6 +6547,7 @@ impl<T: Config> Module<T> {
 
             WeightInfoContent::<T>::delete_video_as_moderator_without_assets(a)
         }
+        .saturating_add(Self::video_deletion_cleanup_weight())
     }
 
     // Calculates weight for accept_channel_transfer extrinsic.
@@ -4812,6 +6564,33 @@ impl<T: Config> Module<T> {
     }
 }
 
+// Expired data objects of the channel bag are removed from the channel assets.
+// Expired video assets are kept in the video assets (videos are not indexed by their assets)
+// until the video assets removal, which skips them in the storage module.
+impl<T: Config> storage::ExpiredDataObjectsHandler<T> for Module<T> {
+    fn on_data_objects_expired(
+        bag_id: &storage::BagId<T>,
+        data_objects: &BTreeSet<DataObjectId<T>>,
+    ) {
+        if let BagIdType::Dynamic(DynamicBagIdType::Channel(channel_id)) = bag_id {
+            if !ChannelById::<T>::contains_key(channel_id) {
+                return;
+            }
+
+            ChannelById::<T>::mutate(channel_id, |channel| {
+                // Removing the assets can't exceed the assets set bound.
+                if let Ok(updated_assets) = Self::create_updated_channel_assets_set(
+                    &channel.data_objects,
+                    &BTreeSet::new(),
+                    data_objects,
+                ) {
+                    channel.data_objects = updated_assets;
+                }
+            });
+        }
+    }
+}
+
 decl_event!(
     pub enum Event<T>
     where
@@ -4829,8 +6608,21 @@ decl_event!(
         DataObjectId = DataObjectId<T>,
         EnglishAuctionParams = EnglishAuctionParams<T>,
         OpenAuctionParams = OpenAuctionParams<T>,
+        DutchAuctionParams = DutchAuctionParams<T>,
         OpenAuctionId = <T as Config>::OpenAuctionId,
         NftIssuanceParameters = NftIssuanceParameters<T>,
+        NftCollectionId = <T as Config>::NftCollectionId,
+        NftCollectionParameters = NftCollectionParameters<T>,
+        InitTransactionalStatus = InitTransactionalStatus<T>,
+        PlaylistId = <T as Config>::PlaylistId,
+        PlaylistCreationParameters = PlaylistCreationParameters<T>,
+        PlaylistUpdateParameters = PlaylistUpdateParameters<T>,
+        VideoVisibilitySchedule = VideoVisibilityScheduleOf<T>,
+        SubscriptionId = <T as Config>::SubscriptionId,
+        SubscriptionTier = SubscriptionTier<T>,
+        Subscription = Subscription<T>,
+        SubscriptionPayment = SubscriptionPayment<T>,
+        RoyaltyRecipient = RoyaltyRecipientOf<T>,
         Balance = BalanceOf<T>,
         ChannelCreationParameters = ChannelCreationParameters<T>,
         ChannelUpdateParameters = ChannelUpdateParameters<T>,
@@ -4863,6 +6655,8 @@ decl_event!(
         ChannelPrivilegeLevelUpdated(ChannelId, ChannelPrivilegeLevel),
         ChannelStateBloatBondValueUpdated(Balance),
         VideoStateBloatBondValueUpdated(Balance),
+        PlaylistStateBloatBondValueUpdated(Balance),
+        SubscriptionStateBloatBondValueUpdated(Balance),
         ChannelAssetsRemoved(ContentActor, ChannelId, BTreeSet<DataObjectId>, Channel),
         ChannelDeleted(ContentActor, ChannelId),
         ChannelDeletedByModerator(ContentActor, ChannelId, Vec<u8> /* rationale */),
@@ -4905,6 +6699,15 @@ decl_event!(
         VideoDeleted(ContentActor, VideoId),
         VideoDeletedByModerator(ContentActor, VideoId, Vec<u8> /* rationale */),
         VideoVisibilitySetByModerator(ContentActor, VideoId, bool, Vec<u8> /* rationale */),
+        VideoVisibilityScheduleSet(ContentActor, VideoId, VideoVisibilitySchedule),
+        VideoVisibilitySetBySchedule(VideoId, bool /* is_hidden */),
+
+        // Subscriptions
+        ChannelSubscriptionTiersSet(ContentActor, ChannelId, Vec<SubscriptionTier>),
+        ChannelSubscribed(MemberId, SubscriptionId, Subscription),
+        ChannelUnsubscribed(MemberId, SubscriptionId),
+        SubscriptionPaymentPulled(SubscriptionId, SubscriptionPayment),
+        SubscriptionLapsed(SubscriptionId, SubscriptionLapseReason),
         VideoAssetsDeletedByModerator(
             ContentActor,
             VideoId,
@@ -4923,7 +6726,20 @@ decl_event!(
         // Nft auction
         EnglishAuctionStarted(ContentActor, VideoId, EnglishAuctionParams),
         OpenAuctionStarted(ContentActor, VideoId, OpenAuctionParams, OpenAuctionId),
+        DutchAuctionStarted(ContentActor, VideoId, DutchAuctionParams),
         NftIssued(ContentActor, VideoId, NftIssuanceParameters),
+        NftCollectionCreated(
+            ContentActor,
+            ChannelId,
+            NftCollectionId,
+            NftCollectionParameters,
+        ),
+        NftsBatchIssued(
+            ContentActor,
+            NftCollectionId,
+            BTreeSet<VideoId>,
+            InitTransactionalStatus,
+        ),
         NftDestroyed(ContentActor, VideoId),
         AuctionBidMade(MemberId, VideoId, Balance, Option<MemberId>),
         AuctionBidCanceled(MemberId, VideoId),
@@ -4936,10 +6752,24 @@ decl_event!(
         OfferCanceled(VideoId, ContentActor),
         NftSellOrderMade(VideoId, ContentActor, Balance),
         NftBought(VideoId, MemberId),
+        NftBoughtOnDutchAuction(VideoId, MemberId, Balance),
+        NftCreatorTokenPricingSet(ContentActor, VideoId, bool),
+        ChannelRoyaltySplitSet(ContentActor, ChannelId, BTreeMap<RoyaltyRecipient, Perbill>),
         BuyNowCanceled(VideoId, ContentActor),
         BuyNowPriceUpdated(VideoId, ContentActor, Balance),
         NftSlingedBackToTheOriginalArtist(VideoId, ContentActor),
 
+        // Playlists
+        PlaylistCreated(
+            ContentActor,
+            PlaylistId,
+            ChannelId,
+            PlaylistCreationParameters,
+        ),
+        PlaylistUpdated(ContentActor, PlaylistId, PlaylistUpdateParameters),
+        PlaylistDeleted(ContentActor, PlaylistId),
+        PlaylistDeletedByModerator(ContentActor, PlaylistId, Vec<u8> /* rationale */),
+
         /// Metaprotocols related event
         ChannelOwnerRemarked(ChannelId, Vec<u8>),
         ChannelAgentRemarked(ContentActor, ChannelId, Vec<u8>),
@@ -0,0 +1,182 @@
+use codec::Decode;
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+use common::bloat_bond::RepayableBloatBondOf;
+use common::membership::MembershipTypes;
+
+use crate::{
+    BalanceOf, Channel, ChannelAssetsSet, ChannelById, ChannelCollaboratorsMap, ChannelOwner,
+    ChannelTransferStatus, Config, ContentActorAuthenticator, LimitPerPeriod, Module, Nft,
+    NftCounter, NftOwner, OpenAuctionBid, OpenAuctionBidByVideoAndMember, PausedFeaturesSet,
+    Royalty, TransactionalStatus, VideoAssetsSet, VideoById, VideoRecord,
+};
+
+/// Channel layout preceding the channel playlists
+#[derive(Decode)]
+struct ChannelRecordV0<
+    ChannelOwner,
+    ChannelCollaboratorsMap,
+    Balance,
+    ChannelPrivilegeLevel,
+    PausedFeaturesSet,
+    ChannelTransferStatus,
+    ChannelAssetsSet,
+    LimitPerPeriod,
+    NftCounter,
+    TokenId,
+    RepayableBloatBond,
+> {
+    owner: ChannelOwner,
+    num_videos: u64,
+    collaborators: ChannelCollaboratorsMap,
+    cumulative_reward_claimed: Balance,
+    privilege_level: ChannelPrivilegeLevel,
+    paused_features: PausedFeaturesSet,
+    transfer_status: ChannelTransferStatus,
+    data_objects: ChannelAssetsSet,
+    daily_nft_limit: LimitPerPeriod,
+    weekly_nft_limit: LimitPerPeriod,
+    daily_nft_counter: NftCounter,
+    weekly_nft_counter: NftCounter,
+    creator_token_id: Option<TokenId>,
+    channel_state_bloat_bond: RepayableBloatBond,
+}
+
+type ChannelV0<T> = ChannelRecordV0<
+    ChannelOwner<
+        <T as MembershipTypes>::MemberId,
+        <T as ContentActorAuthenticator>::CuratorGroupId,
+    >,
+    ChannelCollaboratorsMap<T>,
+    BalanceOf<T>,
+    <T as Config>::ChannelPrivilegeLevel,
+    PausedFeaturesSet,
+    ChannelTransferStatus<
+        <T as MembershipTypes>::MemberId,
+        <T as ContentActorAuthenticator>::CuratorGroupId,
+        BalanceOf<T>,
+        <T as Config>::TransferId,
+        ChannelCollaboratorsMap<T>,
+    >,
+    ChannelAssetsSet<T>,
+    LimitPerPeriod<<T as frame_system::Config>::BlockNumber>,
+    NftCounter<<T as frame_system::Config>::BlockNumber>,
+    <T as project_token::Config>::TokenId,
+    RepayableBloatBondOf<T>,
+>;
+
+/// Nft layout preceding the creator token pricing and the nft collections
+#[derive(Decode)]
+struct OwnedNftV0<TransactionalStatus, MemberId, AuctionId> {
+    owner: NftOwner<MemberId>,
+    transactional_status: TransactionalStatus,
+    creator_royalty: Option<Royalty>,
+    open_auctions_nonce: AuctionId,
+}
+
+type NftV0<T> = OwnedNftV0<
+    TransactionalStatus<T>,
+    <T as MembershipTypes>::MemberId,
+    <T as Config>::OpenAuctionId,
+>;
+
+type VideoV0<T> = VideoRecord<
+    <T as storage::Config>::ChannelId,
+    NftV0<T>,
+    VideoAssetsSet<T>,
+    RepayableBloatBondOf<T>,
+>;
+
+/// Open auction bid layout preceding the creator token pricing
+#[derive(Decode)]
+struct OpenAuctionBidRecordV0<Balance, BlockNumber, AuctionId> {
+    amount: Balance,
+    made_at_block: BlockNumber,
+    auction_id: AuctionId,
+}
+
+type OpenAuctionBidV0<T> = OpenAuctionBidRecordV0<
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+    <T as Config>::OpenAuctionId,
+>;
+
+impl<T: Config> Module<T> {
+    /// Migrate the existing channels to the layout with the channel playlists. The existing
+    /// channels have no playlists.
+    pub fn migrate_channels_to_playlists_layout() -> Weight {
+        let mut channels_number: u64 = 0;
+
+        ChannelById::<T>::translate::<ChannelV0<T>, _>(|_, channel| {
+            channels_number = channels_number.saturating_add(1);
+
+            Some(Channel::<T> {
+                owner: channel.owner,
+                num_videos: channel.num_videos,
+                num_playlists: 0,
+                collaborators: channel.collaborators,
+                cumulative_reward_claimed: channel.cumulative_reward_claimed,
+                privilege_level: channel.privilege_level,
+                paused_features: channel.paused_features,
+                transfer_status: channel.transfer_status,
+                data_objects: channel.data_objects,
+                daily_nft_limit: channel.daily_nft_limit,
+                weekly_nft_limit: channel.weekly_nft_limit,
+                daily_nft_counter: channel.daily_nft_counter,
+                weekly_nft_counter: channel.weekly_nft_counter,
+                creator_token_id: channel.creator_token_id,
+                channel_state_bloat_bond: channel.channel_state_bloat_bond,
+            })
+        });
+
+        T::DbWeight::get().reads_writes(channels_number, channels_number)
+    }
+
+    /// Migrate the existing video nfts from the layout preceding the creator token pricing and
+    /// the nft collections, in a single pass. The existing nfts are priced in JOY and belong
+    /// to no collection.
+    pub fn migrate_video_nfts_to_creator_token_layout() -> Weight {
+        let mut videos_number: u64 = 0;
+
+        VideoById::<T>::translate::<VideoV0<T>, _>(|_, video| {
+            videos_number = videos_number.saturating_add(1);
+
+            Some(VideoRecord {
+                in_channel: video.in_channel,
+                nft_status: video.nft_status.map(|nft| Nft::<T> {
+                    owner: nft.owner,
+                    transactional_status: nft.transactional_status,
+                    creator_royalty: nft.creator_royalty,
+                    open_auctions_nonce: nft.open_auctions_nonce,
+                    priced_in_creator_token: false,
+                    collection_id: None,
+                }),
+                data_objects: video.data_objects,
+                video_state_bloat_bond: video.video_state_bloat_bond,
+            })
+        });
+
+        T::DbWeight::get().reads_writes(videos_number, videos_number)
+    }
+
+    /// Migrate the existing open auction bids to the layout with the creator token pricing.
+    /// The existing bids are escrowed in JOY.
+    pub fn migrate_open_auction_bids_to_creator_token_layout() -> Weight {
+        let mut bids_number: u64 = 0;
+
+        OpenAuctionBidByVideoAndMember::<T>::translate::<OpenAuctionBidV0<T>, _>(|_, _, bid| {
+            bids_number = bids_number.saturating_add(1);
+
+            Some(OpenAuctionBid::<T> {
+                amount: bid.amount,
+                made_at_block: bid.made_at_block,
+                auction_id: bid.auction_id,
+                in_creator_token: false,
+            })
+        });
+
+        T::DbWeight::get().reads_writes(bids_number, bids_number)
+    }
+}
@@ -1,7 +1,11 @@
 mod types;
 use common::costs::{burn_from_usable, has_sufficient_balance_for_payment};
+use frame_support::StorageDoubleMap;
+use project_token::types::TokenBalanceOf;
+use project_token::AccountInfoByTokenAndMember;
 use sp_std::borrow::ToOwned;
 use sp_std::cmp::min;
+use sp_std::convert::{TryFrom, TryInto};
 pub use types::*;
 
 use crate::*;
@@ -9,45 +13,146 @@ use crate::*;
 impl<T: Config> Module<T> {
     pub(crate) fn ensure_has_sufficient_balance_for_bid(
         participant: &T::AccountId,
+        participant_id: T::MemberId,
         bid: BalanceOf<T>,
         old_bid: Option<BalanceOf<T>>,
+        payment_token: Option<T::TokenId>,
     ) -> DispatchResult {
         let old_bid = old_bid.unwrap_or_else(Zero::zero);
 
-        ensure!(
-            has_sufficient_balance_for_payment::<T>(participant, bid.saturating_sub(old_bid)),
-            Error::<T>::InsufficientBalance
-        );
-        Ok(())
+        Self::ensure_sufficient_balance_to_pay_for_nft(
+            participant,
+            participant_id,
+            bid.saturating_sub(old_bid),
+            payment_token,
+        )
     }
 
-    /// Make bid transfer to the treasury account or get refunded if the old bid is greater than a new one.
+    /// Make bid transfer to the treasury account (or to the creator token escrow) or get
+    /// refunded if the old bid is greater than a new one.
     pub(crate) fn transfer_bid_to_treasury(
         participant: &T::AccountId,
+        participant_id: T::MemberId,
         bid: BalanceOf<T>,
         old_bid: Option<BalanceOf<T>>,
+        payment_token: Option<T::TokenId>,
     ) -> DispatchResult {
-        if let Some(old_bid) = old_bid {
-            if bid >= old_bid {
-                // Deposit the difference to the module account.
-                let bid_diff_amount = bid.saturating_sub(old_bid);
-                ContentTreasury::<T>::deposit(participant, bid_diff_amount)
-            } else {
-                // Withdraw the difference from the module account.
-                let bid_diff_amount = old_bid.saturating_sub(bid);
-                ContentTreasury::<T>::withdraw(participant, bid_diff_amount)
+        let old_bid = old_bid.unwrap_or_else(Zero::zero);
+        if bid >= old_bid {
+            // Deposit the difference to the module account / escrow.
+            let bid_diff_amount = bid.saturating_sub(old_bid);
+            match payment_token {
+                Some(token_id) => T::ProjectToken::lock_in_escrow(
+                    token_id,
+                    participant_id,
+                    Self::creator_token_amount(bid_diff_amount)?,
+                ),
+                None => ContentTreasury::<T>::deposit(participant, bid_diff_amount),
             }
         } else {
-            ContentTreasury::<T>::deposit(participant, bid)
+            // Withdraw the difference from the module account / escrow.
+            let bid_diff_amount = old_bid.saturating_sub(bid);
+            Self::withdraw_bid_payment(participant, participant_id, bid_diff_amount, payment_token)
         }
     }
 
-    /// Withdraw the bid from the treasury account.
+    /// Withdraw the bid from the treasury account (or from the creator token escrow).
     pub(crate) fn withdraw_bid_payment(
         participant: &T::AccountId,
+        participant_id: T::MemberId,
         bid: BalanceOf<T>,
+        payment_token: Option<T::TokenId>,
     ) -> DispatchResult {
-        ContentTreasury::<T>::withdraw(participant, bid)
+        match payment_token {
+            Some(token_id) => T::ProjectToken::release_from_escrow(
+                token_id,
+                participant_id,
+                participant_id,
+                Self::creator_token_amount(bid)?,
+            ),
+            None => ContentTreasury::<T>::withdraw(participant, bid),
+        }
+    }
+
+    /// Nft amounts are denominated in creator token base units when the nft is priced in the
+    /// channel's creator token. Fails if the amount cannot be represented as a token balance.
+    pub(crate) fn creator_token_amount(
+        amount: BalanceOf<T>,
+    ) -> Result<TokenBalanceOf<T>, DispatchError> {
+        let amount: u128 = amount
+            .try_into()
+            .map_err(|_| Error::<T>::CreatorTokenAmountOverflow)?;
+        TokenBalanceOf::<T>::try_from(amount)
+            .map_err(|_| Error::<T>::CreatorTokenAmountOverflow.into())
+    }
+
+    /// Creator token the nft is priced in, `None` if the nft is priced in JOY
+    pub(crate) fn ensure_nft_payment_token(
+        video: &Video<T>,
+        nft: &Nft<T>,
+    ) -> Result<Option<T::TokenId>, DispatchError> {
+        if nft.priced_in_creator_token {
+            Self::channel_by_id(video.in_channel)
+                .ensure_creator_token_issued::<T>()
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Members receiving a creator token payment for the nft:
+    /// - beneficiary: nft owner member (or channel owner member for channel owned nfts), who
+    ///   must hold a `token_id` account
+    /// - royalty receiver: channel owner member, if holding a `token_id` account
+    pub(crate) fn ensure_creator_token_payment(
+        token_id: T::TokenId,
+        video: &Video<T>,
+        nft: &Nft<T>,
+    ) -> Result<CreatorTokenPayment<T>, DispatchError> {
+        let has_token_account = |member_id: &T::MemberId| {
+            AccountInfoByTokenAndMember::<T>::contains_key(token_id, member_id)
+        };
+
+        let channel_owner_id = match Self::channel_by_id(video.in_channel).owner {
+            ChannelOwner::Member(member_id) => Some(member_id),
+            ChannelOwner::CuratorGroup(_) => None,
+        };
+
+        let beneficiary_id = match nft.owner {
+            NftOwner::Member(member_id) => Some(member_id),
+            NftOwner::ChannelOwner => channel_owner_id,
+        }
+        .filter(has_token_account)
+        .ok_or(Error::<T>::NftBeneficiaryHasNoCreatorTokenAccount)?;
+
+        // royalty split members not holding a `token_id` account are skipped
+        let royalty_split = Self::royalty_split_by_channel_id(video.in_channel)
+            .into_inner()
+            .into_iter()
+            .filter_map(|(recipient, share)| match recipient {
+                RoyaltyRecipient::Member(member_id) if has_token_account(&member_id) => {
+                    Some((member_id, share))
+                }
+                _ => None,
+            })
+            .collect();
+
+        Ok(CreatorTokenPayment::<T> {
+            token_id,
+            beneficiary_id,
+            royalty_receiver_id: channel_owner_id.filter(has_token_account),
+            royalty_split,
+        })
+    }
+
+    /// Payment in the channel's creator token, when nft is priced in it
+    pub(crate) fn ensure_nft_creator_token_payment(
+        video: &Video<T>,
+        nft: &Nft<T>,
+    ) -> Result<Option<CreatorTokenPayment<T>>, DispatchError> {
+        Self::ensure_nft_payment_token(video, nft)?
+            .map(|token_id| Self::ensure_creator_token_payment(token_id, video, nft))
+            .transpose()
     }
 
     /// Safety/bound checks for english auction parameters
@@ -115,6 +220,39 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Safety/bound checks for dutch auction parameters
+    pub(crate) fn validate_dutch_auction_params(
+        auction_params: &DutchAuctionParams<T>,
+    ) -> DispatchResult {
+        Self::ensure_starting_price_bounds_satisfied(auction_params.starting_price)?;
+
+        ensure!(
+            auction_params.floor_price >= Self::min_starting_price(),
+            Error::<T>::DutchAuctionFloorPriceLowerBoundExceeded
+        );
+
+        ensure!(
+            auction_params.floor_price < auction_params.starting_price,
+            Error::<T>::DutchAuctionFloorPriceMustBeLowerThanStartingPrice
+        );
+
+        ensure!(
+            !auction_params.price_decrease_per_block.is_zero(),
+            Error::<T>::DutchAuctionPriceDecreaseIsZero
+        );
+
+        Self::ensure_whitelist_bounds_satisfied(&auction_params.whitelist)?;
+
+        Self::ensure_whitelist_members_exist(&auction_params.whitelist)?;
+
+        // validate forward start limits
+        if let Some(starts_at) = auction_params.starts_at {
+            Self::ensure_starts_at_delta_bounds_satisfied(starts_at)?;
+        }
+
+        Ok(())
+    }
+
     /// Ensure starts at bounds satisfied
     pub(crate) fn ensure_starts_at_delta_bounds_satisfied(
         starts_at: T::BlockNumber,
@@ -252,15 +390,33 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
-    /// Ensure given participant has sufficient usable balance to cover the nft purchase
+    /// Ensure given participant has sufficient usable balance (or transferrable creator token
+    /// balance) to cover the nft purchase
     pub(crate) fn ensure_sufficient_balance_to_pay_for_nft(
         participant_account_id: &T::AccountId,
+        participant_id: T::MemberId,
         price: BalanceOf<T>,
+        payment_token: Option<T::TokenId>,
     ) -> DispatchResult {
-        ensure!(
-            has_sufficient_balance_for_payment::<T>(participant_account_id, price),
-            Error::<T>::InsufficientBalance
-        );
+        match payment_token {
+            Some(token_id) => {
+                let transferrable = project_token::Module::<T>::account_info_by_token_and_member(
+                    token_id,
+                    participant_id,
+                )
+                .transferrable::<T>(<frame_system::Pallet<T>>::block_number());
+                ensure!(
+                    transferrable >= Self::creator_token_amount(price)?,
+                    Error::<T>::InsufficientCreatorTokenBalance
+                );
+            }
+            None => {
+                ensure!(
+                    has_sufficient_balance_for_payment::<T>(participant_account_id, price),
+                    Error::<T>::InsufficientBalance
+                );
+            }
+        }
         Ok(())
     }
 
@@ -268,14 +424,21 @@ impl<T: Config> Module<T> {
     pub(crate) fn ensure_can_buy_now(
         nft: &Nft<T>,
         participant_account_id: &T::AccountId,
+        participant_id: T::MemberId,
         witness_price: BalanceOf<T>,
+        payment_token: Option<T::TokenId>,
     ) -> DispatchResult {
         if let TransactionalStatus::<T>::BuyNow(price) = &nft.transactional_status {
             ensure!(
                 *price == witness_price,
                 Error::<T>::InvalidBuyNowWitnessPriceProvided
             );
-            Self::ensure_sufficient_balance_to_pay_for_nft(participant_account_id, *price)
+            Self::ensure_sufficient_balance_to_pay_for_nft(
+                participant_account_id,
+                participant_id,
+                *price,
+                payment_token,
+            )
         } else {
             Err(Error::<T>::NftNotInBuyNowState.into())
         }
@@ -286,6 +449,7 @@ impl<T: Config> Module<T> {
         nft: &Nft<T>,
         participant_account_id: &T::AccountId,
         witness_price: Option<<T as balances::Config>::Balance>,
+        payment_token: Option<T::TokenId>,
     ) -> DispatchResult {
         if let TransactionalStatus::<T>::InitiatedOfferToMember(member_id, price) =
             &nft.transactional_status
@@ -300,7 +464,12 @@ impl<T: Config> Module<T> {
             ensure_member_auth_success::<T>(participant_account_id, member_id)?;
 
             if let Some(price) = price {
-                Self::ensure_sufficient_balance_to_pay_for_nft(participant_account_id, *price)?;
+                Self::ensure_sufficient_balance_to_pay_for_nft(
+                    participant_account_id,
+                    *member_id,
+                    *price,
+                    payment_token,
+                )?;
             }
             Ok(())
         } else {
@@ -311,18 +480,33 @@ impl<T: Config> Module<T> {
     /// Buy nft
     pub(crate) fn buy_now(
         nft: Nft<T>,
-        royalty_payment: Option<(Royalty, T::AccountId)>,
+        royalty_payment: Option<RoyaltyPayment<T>>,
         old_owner_account_id: Option<T::AccountId>,
         new_owner_account_id: T::AccountId,
         new_owner: T::MemberId,
+        creator_token_payment: Option<CreatorTokenPayment<T>>,
     ) -> Result<Nft<T>, DispatchError> {
         if let TransactionalStatus::<T>::BuyNow(price) = &nft.transactional_status {
-            Self::complete_payment(
-                royalty_payment,
-                price.to_owned(),
-                new_owner_account_id,
-                old_owner_account_id,
-            )?;
+            if let Some(ref payment) = creator_token_payment {
+                T::ProjectToken::lock_in_escrow(
+                    payment.token_id,
+                    new_owner,
+                    Self::creator_token_amount(*price)?,
+                )?;
+                Self::complete_creator_token_payment(
+                    payment,
+                    nft.creator_royalty,
+                    *price,
+                    new_owner,
+                )?;
+            } else {
+                Self::complete_payment(
+                    royalty_payment,
+                    price.to_owned(),
+                    new_owner_account_id,
+                    old_owner_account_id,
+                )?;
+            }
         }
 
         let updated_nft = nft
@@ -335,20 +519,35 @@ impl<T: Config> Module<T> {
     /// Completes nft offer
     pub(crate) fn complete_nft_offer(
         mut nft: Nft<T>,
-        royalty_payment: Option<(Royalty, T::AccountId)>,
+        royalty_payment: Option<RoyaltyPayment<T>>,
         owner_account_id: Option<T::AccountId>,
         new_owner_account_id: T::AccountId,
+        creator_token_payment: Option<CreatorTokenPayment<T>>,
     ) -> Result<Nft<T>, DispatchError> {
         if let TransactionalStatus::<T>::InitiatedOfferToMember(to, price) =
             &nft.transactional_status
         {
             if let Some(price) = price {
-                Self::complete_payment(
-                    royalty_payment,
-                    *price,
-                    new_owner_account_id,
-                    owner_account_id,
-                )?;
+                if let Some(ref payment) = creator_token_payment {
+                    T::ProjectToken::lock_in_escrow(
+                        payment.token_id,
+                        *to,
+                        Self::creator_token_amount(*price)?,
+                    )?;
+                    Self::complete_creator_token_payment(
+                        payment,
+                        nft.creator_royalty,
+                        *price,
+                        *to,
+                    )?;
+                } else {
+                    Self::complete_payment(
+                        royalty_payment,
+                        *price,
+                        new_owner_account_id,
+                        owner_account_id,
+                    )?;
+                }
             }
             nft.owner = NftOwner::Member(*to);
         }
@@ -363,7 +562,7 @@ impl<T: Config> Module<T> {
 
     /// Complete payment, either auction related or buy now/offer
     pub(crate) fn complete_payment(
-        royalty_payment: Option<(Royalty, T::AccountId)>,
+        royalty_payment: Option<RoyaltyPayment<T>>,
         amount: BalanceOf<T>,
         sender_account_id: T::AccountId,
         receiver_account_id: Option<T::AccountId>,
@@ -376,16 +575,29 @@ impl<T: Config> Module<T> {
         let platform_fee = platform_fee_pct.mul_floor(amount);
 
         // established net amount and pay royalties if necessary
-        let net_amount = if let Some((nominal_royalty_pct, creator_account)) = royalty_payment {
+        let net_amount = if let Some(royalty_payment) = royalty_payment {
             // min(creator_royalty, 100% - platform_fee_percentage) is used to avoid underflow
             let effective_royalty_pct = min(
-                nominal_royalty_pct,
+                royalty_payment.royalty,
                 Perbill::one().saturating_sub(platform_fee_pct),
             );
             let royalty = effective_royalty_pct.mul_floor(amount);
 
-            // deposit to creator account
-            let _ = Balances::<T>::deposit_creating(&creator_account, royalty);
+            // deposit royalty split shares, split shares sum up to at most 100%
+            let split_amount = royalty_payment.split.iter().fold(
+                BalanceOf::<T>::zero(),
+                |split_amount, (account_id, share)| {
+                    let share_amount = share.mul_floor(royalty);
+                    let _ = Balances::<T>::deposit_creating(account_id, share_amount);
+                    split_amount.saturating_add(share_amount)
+                },
+            );
+
+            // deposit remaining royalty to channel account
+            let _ = Balances::<T>::deposit_creating(
+                &royalty_payment.channel_account,
+                royalty.saturating_sub(split_amount),
+            );
 
             amount.saturating_sub(platform_fee).saturating_sub(royalty)
         } else {
@@ -399,22 +611,80 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    /// Complete payment in the channel's creator token, either auction related or buy now/offer.
+    /// `amount` is expected to be already escrowed by `sender_id`. No platform fee is charged.
+    pub(crate) fn complete_creator_token_payment(
+        payment: &CreatorTokenPayment<T>,
+        creator_royalty: Option<Royalty>,
+        amount: BalanceOf<T>,
+        sender_id: T::MemberId,
+    ) -> DispatchResult {
+        let amount = Self::creator_token_amount(amount)?;
+
+        let royalty = creator_royalty
+            .map(|royalty_pct| royalty_pct.mul_floor(amount))
+            .unwrap_or_else(Zero::zero);
+
+        // pay royalty split shares if necessary
+        let mut royalty_paid = TokenBalanceOf::<T>::zero();
+        for (member_id, share) in payment.royalty_split.iter() {
+            let share_amount = share.mul_floor(royalty);
+            if !share_amount.is_zero() {
+                T::ProjectToken::release_from_escrow(
+                    payment.token_id,
+                    sender_id,
+                    *member_id,
+                    share_amount,
+                )?;
+                royalty_paid = royalty_paid.saturating_add(share_amount);
+            }
+        }
+
+        // pay remaining royalty if necessary
+        if let Some(royalty_receiver_id) = payment.royalty_receiver_id {
+            let remaining_royalty = royalty.saturating_sub(royalty_paid);
+            if !remaining_royalty.is_zero() {
+                T::ProjectToken::release_from_escrow(
+                    payment.token_id,
+                    sender_id,
+                    royalty_receiver_id,
+                    remaining_royalty,
+                )?;
+            }
+            royalty_paid = royalty;
+        }
+
+        T::ProjectToken::release_from_escrow(
+            payment.token_id,
+            sender_id,
+            payment.beneficiary_id,
+            amount.saturating_sub(royalty_paid),
+        )
+    }
+
     pub(crate) fn complete_auction(
         nft: Nft<T>,
         video: &Video<T>,
-        royalty_payment: Option<(Royalty, T::AccountId)>,
+        royalty_payment: Option<RoyaltyPayment<T>>,
         winner_id: T::MemberId,
         amount: BalanceOf<T>,
+        creator_token_payment: Option<CreatorTokenPayment<T>>,
     ) -> Result<Nft<T>, DispatchError> {
-        let account_deposit_into = Self::ensure_nft_owner_has_beneficiary_account(video, &nft).ok();
-        let account_withdraw_from = ContentTreasury::<T>::module_account_id();
+        if let Some(ref payment) = creator_token_payment {
+            // winning bid is escrowed by the winner
+            Self::complete_creator_token_payment(payment, nft.creator_royalty, amount, winner_id)?;
+        } else {
+            let account_deposit_into =
+                Self::ensure_nft_owner_has_beneficiary_account(video, &nft).ok();
+            let account_withdraw_from = ContentTreasury::<T>::module_account_id();
 
-        Self::complete_payment(
-            royalty_payment,
-            amount,
-            account_withdraw_from,
-            account_deposit_into,
-        )?;
+            Self::complete_payment(
+                royalty_payment,
+                amount,
+                account_withdraw_from,
+                account_deposit_into,
+            )?;
+        }
 
         let updated_nft = nft
             .with_transactional_status(TransactionalStatus::<T>::Idle)
@@ -444,6 +714,17 @@ impl<T: Config> Module<T> {
         Self::ensure_video_exists(&video_id).and_then(|video| video.ensure_nft_is_issued::<T>())
     }
 
+    /// Ensure nft collection exists, return it
+    pub(crate) fn ensure_nft_collection_exists(
+        collection_id: &T::NftCollectionId,
+    ) -> Result<NftCollection<T>, Error<T>> {
+        ensure!(
+            NftCollectionById::<T>::contains_key(collection_id),
+            Error::<T>::NftCollectionDoesNotExist
+        );
+        Ok(NftCollectionById::<T>::get(collection_id))
+    }
+
     // NFT
 
     /// Get nft english auction record
@@ -468,6 +749,17 @@ impl<T: Config> Module<T> {
         }
     }
 
+    /// Get nft dutch auction record
+    pub(crate) fn ensure_in_dutch_auction_state(
+        nft: &Nft<T>,
+    ) -> Result<DutchAuction<T>, DispatchError> {
+        if let TransactionalStatus::<T>::DutchAuction(auction) = &nft.transactional_status {
+            Ok(auction.to_owned())
+        } else {
+            Err(Error::<T>::IsNotDutchAuctionType.into())
+        }
+    }
+
     ///  Ensure nft transactional status is set to `Idle`
     pub(crate) fn ensure_nft_transactional_status_is_idle(nft: &Nft<T>) -> DispatchResult {
         if let TransactionalStatus::<T>::Idle = nft.transactional_status {
@@ -504,13 +796,29 @@ impl<T: Config> Module<T> {
     pub(crate) fn build_royalty_payment(
         video: &Video<T>,
         creator_royalty: Option<Royalty>,
-    ) -> Option<(Royalty, T::AccountId)> {
+    ) -> Option<RoyaltyPayment<T>> {
         // payment is none if there is no royalty
-        if let Some(royalty) = creator_royalty {
-            let reward_account = ContentTreasury::<T>::account_for_channel(video.in_channel);
-            Some((royalty, reward_account))
-        } else {
-            None
-        }
+        creator_royalty.map(|royalty| {
+            // royalty split members without a controller account are skipped
+            let split = Self::royalty_split_by_channel_id(video.in_channel)
+                .into_inner()
+                .into_iter()
+                .filter_map(|(recipient, share)| {
+                    match recipient {
+                        RoyaltyRecipient::Member(member_id) => {
+                            T::MemberAuthenticator::controller_account_id(member_id).ok()
+                        }
+                        RoyaltyRecipient::Account(account_id) => Some(account_id),
+                    }
+                    .map(|account_id| (account_id, share))
+                })
+                .collect();
+
+            RoyaltyPayment::<T> {
+                royalty,
+                channel_account: ContentTreasury::<T>::account_for_channel(video.in_channel),
+                split,
+            }
+        })
     }
 }
@@ -1,6 +1,7 @@
 use super::*;
-use frame_support::BoundedBTreeSet;
+use frame_support::{BoundedBTreeMap, BoundedBTreeSet};
 use scale_info::TypeInfo;
+use sp_runtime::traits::{UniqueSaturatedFrom, UniqueSaturatedInto};
 
 /// Metadata for NFT issuance
 pub type NftMetadata = Vec<u8>;
@@ -11,16 +12,23 @@ pub type Royalty = Perbill;
 /// Nft transactional status
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub enum TransactionalStatusRecord<MemberId, Balance, EnglishAuctionType, OpenAuctionType> {
+pub enum TransactionalStatusRecord<
+    MemberId,
+    Balance,
+    EnglishAuctionType,
+    OpenAuctionType,
+    DutchAuctionType,
+> {
     Idle,
     InitiatedOfferToMember(MemberId, Option<Balance>),
     EnglishAuction(EnglishAuctionType),
     OpenAuction(OpenAuctionType),
     BuyNow(Balance),
+    DutchAuction(DutchAuctionType),
 }
 
-impl<MemberId, Balance, EnglishAuction, OpenAuction> Default
-    for TransactionalStatusRecord<MemberId, Balance, EnglishAuction, OpenAuction>
+impl<MemberId, Balance, EnglishAuction, OpenAuction, DutchAuction> Default
+    for TransactionalStatusRecord<MemberId, Balance, EnglishAuction, OpenAuction, DutchAuction>
 {
     fn default() -> Self {
         Self::Idle
@@ -30,15 +38,19 @@ impl<MemberId, Balance, EnglishAuction, OpenAuction> Default
 /// Owned Nft representation
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct OwnedNft<TransactionalStatus, MemberId, AuctionId> {
+pub struct OwnedNft<TransactionalStatus, MemberId, AuctionId, NftCollectionId> {
     pub owner: NftOwner<MemberId>,
     pub transactional_status: TransactionalStatus,
     pub creator_royalty: Option<Royalty>,
     pub open_auctions_nonce: AuctionId,
+    /// Whether nft prices & bids are denominated in the channel's creator token instead of JOY
+    pub priced_in_creator_token: bool,
+    /// Collection the nft was issued as part of, if any
+    pub collection_id: Option<NftCollectionId>,
 }
 
-impl<TransactionalStatus, MemberId, AuctionId: BaseArithmetic>
-    OwnedNft<TransactionalStatus, MemberId, AuctionId>
+impl<TransactionalStatus, MemberId, AuctionId: BaseArithmetic, NftCollectionId>
+    OwnedNft<TransactionalStatus, MemberId, AuctionId, NftCollectionId>
 {
     /// Create new Nft
     pub fn new(
@@ -51,6 +63,15 @@ impl<TransactionalStatus, MemberId, AuctionId: BaseArithmetic>
             transactional_status,
             creator_royalty,
             open_auctions_nonce: AuctionId::zero(),
+            priced_in_creator_token: false,
+            collection_id: None,
+        }
+    }
+
+    pub(crate) fn with_collection(self, collection_id: NftCollectionId) -> Self {
+        Self {
+            collection_id: Some(collection_id),
+            ..self
         }
     }
 
@@ -114,16 +135,29 @@ pub type NftIssuanceParameters<T> = NftIssuanceParametersRecord<
 /// Initial Transactional status for the Nft: See InitialTransactionalStatusRecord above
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub enum InitTransactionalStatusRecord<EnglishAuctionParams, OpenAuctionParams, MemberId, Balance> {
+pub enum InitTransactionalStatusRecord<
+    EnglishAuctionParams,
+    OpenAuctionParams,
+    MemberId,
+    Balance,
+    DutchAuctionParams,
+> {
     Idle,
     BuyNow(Balance),
     InitiatedOfferToMember(MemberId, Option<Balance>),
     EnglishAuction(EnglishAuctionParams),
     OpenAuction(OpenAuctionParams),
+    DutchAuction(DutchAuctionParams),
 }
 
-impl<EnglishAuctionParams, OpenAuctionParams, MemberId, Balance> Default
-    for InitTransactionalStatusRecord<EnglishAuctionParams, OpenAuctionParams, MemberId, Balance>
+impl<EnglishAuctionParams, OpenAuctionParams, MemberId, Balance, DutchAuctionParams> Default
+    for InitTransactionalStatusRecord<
+        EnglishAuctionParams,
+        OpenAuctionParams,
+        MemberId,
+        Balance,
+        DutchAuctionParams,
+    >
 {
     fn default() -> Self {
         Self::Idle
@@ -376,11 +410,13 @@ impl<
         &self,
         amount: Balance,
         made_at_block: BlockNumber,
+        in_creator_token: bool,
     ) -> OpenAuctionBidRecord<Balance, BlockNumber, AuctionId> {
         OpenAuctionBidRecord::<Balance, BlockNumber, AuctionId> {
             amount,
             made_at_block,
             auction_id: self.auction_id,
+            in_creator_token,
         }
     }
 
@@ -405,12 +441,117 @@ impl<
     }
 }
 
+/// Dutch Auction: the price starts at `starting_price` and decreases by
+/// `price_decrease_per_block` each block (down to `floor_price`).
+/// The first participant buying the nft at the current price settles the auction.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DutchAuctionRecord<BlockNumber, Balance, NftAuctionWhitelist> {
+    pub starting_price: Balance,
+    pub floor_price: Balance,
+    pub price_decrease_per_block: Balance,
+    pub whitelist: NftAuctionWhitelist,
+    pub start: BlockNumber, // starting block
+}
+
+impl<
+        BlockNumber: Copy + Saturating + PartialOrd + UniqueSaturatedInto<u64>,
+        Balance: Copy + PartialOrd + Saturating + UniqueSaturatedFrom<u64>,
+        MemberId: Ord,
+        MaxWhitelistSize: Get<u32>,
+    > DutchAuctionRecord<BlockNumber, Balance, BoundedBTreeSet<MemberId, MaxWhitelistSize>>
+{
+    pub fn try_new<T: Config>(
+        params: DutchAuctionParamsRecord<BlockNumber, Balance, MemberId>,
+        current_block: BlockNumber,
+    ) -> Result<Self, DispatchError> {
+        let dutch_auction = Self {
+            starting_price: params.starting_price,
+            floor_price: params.floor_price,
+            price_decrease_per_block: params.price_decrease_per_block,
+            whitelist: params
+                .whitelist
+                .try_into()
+                .map_err(|_| Error::<T>::MaxAuctionWhiteListLengthUpperBoundExceeded)?,
+            start: params.starts_at.unwrap_or(current_block),
+        };
+        Ok(dutch_auction)
+    }
+
+    /// Price at which the nft can be bought at block `now`:
+    /// `max(floor_price, starting_price - price_decrease_per_block * (now - start))`
+    pub(crate) fn current_price(&self, now: BlockNumber) -> Balance {
+        let elapsed_blocks: u64 = now.saturating_sub(self.start).unique_saturated_into();
+        let price_decrease = self
+            .price_decrease_per_block
+            .saturating_mul(Balance::unique_saturated_from(elapsed_blocks));
+        let price = self.starting_price.saturating_sub(price_decrease);
+        if price < self.floor_price {
+            self.floor_price
+        } else {
+            price
+        }
+    }
+
+    pub(crate) fn ensure_auction_started<T: Config>(&self, now: BlockNumber) -> DispatchResult {
+        ensure!(now >= self.start, Error::<T>::AuctionDidNotStart);
+        Ok(())
+    }
+
+    pub(crate) fn ensure_whitelisted_participant<T: Config>(
+        &self,
+        participant_id: MemberId,
+    ) -> DispatchResult {
+        ensure!(
+            self.whitelist.is_empty() || self.whitelist.contains(&participant_id),
+            Error::<T>::MemberIsNotAllowedToParticipate
+        );
+        Ok(())
+    }
+}
+
+/// Members involved in settling a nft payment made in the channel's creator token
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CreatorTokenPaymentRecord<TokenId, MemberId> {
+    /// Creator token the payment is made in
+    pub token_id: TokenId,
+    /// Member receiving the payment (net of royalty)
+    pub beneficiary_id: MemberId,
+    /// Member receiving the royalty: none if channel is not owned by a member holding the token
+    pub royalty_receiver_id: Option<MemberId>,
+    /// Members holding the token receiving a share of the royalty, as per channel royalty split
+    pub royalty_split: Vec<(MemberId, Perbill)>,
+}
+
+/// Recipient of a share of the royalties paid on channel nfts secondary sales
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, TypeInfo, MaxEncodedLen)]
+pub enum RoyaltyRecipient<MemberId, AccountId> {
+    /// Member, paid to its controller account
+    Member(MemberId),
+    /// Arbitrary account
+    Account(AccountId),
+}
+
+/// Royalty due on a nft payment made in JOY
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RoyaltyPaymentRecord<AccountId> {
+    /// Nominal royalty percentage of the payment amount
+    pub royalty: Royalty,
+    /// Channel account receiving the part of the royalty not covered by the split
+    pub channel_account: AccountId,
+    /// Accounts receiving a share of the royalty, as per channel royalty split
+    pub split: Vec<(AccountId, Perbill)>,
+}
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct OpenAuctionBidRecord<Balance, BlockNumber, AuctionId> {
     pub amount: Balance,
     pub made_at_block: BlockNumber,
     pub auction_id: AuctionId,
+    /// Whether the bid amount is escrowed in the channel's creator token instead of JOY
+    pub in_creator_token: bool,
 }
 
 impl<
@@ -484,6 +625,62 @@ pub struct OpenAuctionParamsRecord<BlockNumber, Balance, MemberId: Ord> {
     pub bid_lock_duration: BlockNumber,
 }
 
+/// Dutch Auction Init Params
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct DutchAuctionParamsRecord<BlockNumber, Balance, MemberId: Ord> {
+    pub starting_price: Balance,
+    pub floor_price: Balance,
+    pub price_decrease_per_block: Balance,
+    pub starts_at: Option<BlockNumber>, // auction starting block
+    pub whitelist: BTreeSet<MemberId>,
+}
+
+/// Nft collection: a series of nfts issued for videos of a single channel
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct NftCollectionRecord<ChannelId, Hash, NftAuctionWhitelist> {
+    /// Channel the collection belongs to
+    pub channel_id: ChannelId,
+    /// Hash of the collection name
+    pub name_hash: Hash,
+    /// Max number of nfts that can be issued as part of the collection
+    pub max_supply: u32,
+    /// Number of nfts issued so far as part of the collection
+    pub issued: u32,
+    /// Royalty shared by all collection nfts
+    pub royalty: Option<Royalty>,
+    /// Whitelist shared by all auctions collection nfts are issued in
+    pub whitelist: NftAuctionWhitelist,
+}
+
+impl<ChannelId, Hash, NftAuctionWhitelist>
+    NftCollectionRecord<ChannelId, Hash, NftAuctionWhitelist>
+{
+    /// Ensure `number` more nfts can be issued as part of the collection
+    pub(crate) fn ensure_can_issue<T: Config>(&self, number: u32) -> DispatchResult {
+        ensure!(
+            self.issued.saturating_add(number) <= self.max_supply,
+            Error::<T>::NftCollectionMaxSupplyExceeded
+        );
+        Ok(())
+    }
+}
+
+/// Parameters used to create a nft collection
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct NftCollectionParametersRecord<Hash, MemberId: Ord> {
+    /// Hash of the collection name
+    pub name_hash: Hash,
+    /// Max number of nfts that can be issued as part of the collection
+    pub max_supply: u32,
+    /// Royalty shared by all collection nfts
+    pub royalty: Option<Royalty>,
+    /// Whitelist shared by all auctions collection nfts are issued in
+    pub whitelist: BTreeSet<MemberId>,
+}
+
 // Aliases
 pub type NftAuctionWhitelist<T> = BoundedBTreeSet<
     <T as common::MembershipTypes>::MemberId,
@@ -504,6 +701,12 @@ pub type OpenAuction<T> = OpenAuctionRecord<
     NftAuctionWhitelist<T>,
 >;
 
+pub type DutchAuction<T> = DutchAuctionRecord<
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+    NftAuctionWhitelist<T>,
+>;
+
 pub type EnglishAuctionParams<T> = EnglishAuctionParamsRecord<
     <T as frame_system::Config>::BlockNumber,
     BalanceOf<T>,
@@ -516,16 +719,49 @@ pub type OpenAuctionParams<T> = OpenAuctionParamsRecord<
     <T as common::MembershipTypes>::MemberId,
 >;
 
+pub type DutchAuctionParams<T> = DutchAuctionParamsRecord<
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+    <T as common::MembershipTypes>::MemberId,
+>;
+
 pub type OpenAuctionBid<T> = OpenAuctionBidRecord<
     BalanceOf<T>,
     <T as frame_system::Config>::BlockNumber,
     <T as Config>::OpenAuctionId,
 >;
 
+pub type CreatorTokenPayment<T> = CreatorTokenPaymentRecord<
+    <T as project_token::Config>::TokenId,
+    <T as common::MembershipTypes>::MemberId,
+>;
+
+pub type RoyaltyRecipientOf<T> = RoyaltyRecipient<
+    <T as common::MembershipTypes>::MemberId,
+    <T as frame_system::Config>::AccountId,
+>;
+
+pub type RoyaltySplit<T> =
+    BoundedBTreeMap<RoyaltyRecipientOf<T>, Perbill, <T as Config>::MaxRoyaltySplitRecipients>;
+
+pub type RoyaltyPayment<T> = RoyaltyPaymentRecord<<T as frame_system::Config>::AccountId>;
+
 pub type Nft<T> = OwnedNft<
     TransactionalStatus<T>,
     <T as common::MembershipTypes>::MemberId,
     <T as Config>::OpenAuctionId,
+    <T as Config>::NftCollectionId,
+>;
+
+pub type NftCollection<T> = NftCollectionRecord<
+    <T as storage::Config>::ChannelId,
+    <T as frame_system::Config>::Hash,
+    NftAuctionWhitelist<T>,
+>;
+
+pub type NftCollectionParameters<T> = NftCollectionParametersRecord<
+    <T as frame_system::Config>::Hash,
+    <T as common::MembershipTypes>::MemberId,
 >;
 
 pub type TransactionalStatus<T> = TransactionalStatusRecord<
@@ -533,6 +769,7 @@ pub type TransactionalStatus<T> = TransactionalStatusRecord<
     BalanceOf<T>,
     EnglishAuction<T>,
     OpenAuction<T>,
+    DutchAuction<T>,
 >;
 
 pub type InitTransactionalStatus<T> = InitTransactionalStatusRecord<
@@ -540,4 +777,5 @@ pub type InitTransactionalStatus<T> = InitTransactionalStatusRecord<
     OpenAuctionParams<T>,
     <T as common::MembershipTypes>::MemberId,
     BalanceOf<T>,
+    DutchAuctionParams<T>,
 >;
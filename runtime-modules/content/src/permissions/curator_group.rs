@@ -57,6 +57,9 @@ pub mod iterable_enums {
         // Related extrinsics:
         // - `update_channel_nft_limit`
         UpdateChannelNftLimits,
+        // Related extrinsics:
+        // - `delete_playlist_as_moderator`
+        DeletePlaylist,
     }
 
     #[cfg_attr(feature = "std", derive(Serialize, Deserialize, EnumIter))]
@@ -275,6 +275,20 @@ pub fn ensure_actor_authorized_to_perform_video_update<T: Config>(
     ensure_actor_has_channel_permissions::<T>(sender, actor, channel, &required_permissions)
 }
 
+/// Ensure actor is authorized to schedule video publishing and unpublishing
+pub fn ensure_actor_authorized_to_schedule_video_visibility<T: Config>(
+    sender: &T::AccountId,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<Option<ChannelAgentPermissions>, DispatchError> {
+    ensure_actor_has_channel_permissions::<T>(
+        sender,
+        actor,
+        channel,
+        &[ChannelActionPermission::UpdateVideoMetadata],
+    )
+}
+
 /// Ensure actor is authorized to delete video
 pub fn ensure_actor_authorized_to_delete_video<T: Config>(
     sender: &T::AccountId,
@@ -317,6 +331,20 @@ pub fn ensure_actor_authorized_to_send_channel_agent_remark<T: Config>(
     )
 }
 
+/// Ensure actor is authorized to manage channel playlists
+pub fn ensure_actor_authorized_to_manage_playlists<T: Config>(
+    sender: &T::AccountId,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<Option<ChannelAgentPermissions>, DispatchError> {
+    ensure_actor_has_channel_permissions::<T>(
+        sender,
+        actor,
+        channel,
+        &[ChannelActionPermission::ManagePlaylists],
+    )
+}
+
 /// CHANNEL ASSET MANAGEMENT PERMISSIONS
 
 // Ensure channel is owned by curators
@@ -434,6 +462,32 @@ pub fn ensure_actor_authorized_to_manage_nft<T: Config>(
     Ok(())
 }
 
+// Ensure actor can manage channel royalty split
+pub fn ensure_actor_authorized_to_manage_royalty_split<T: Config>(
+    origin: T::Origin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<T::AccountId, DispatchError> {
+    let sender = ensure_signed(origin)?;
+    let required_permissions = vec![ChannelActionPermission::ManageRoyaltySplit];
+    ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok(sender)
+}
+
+// Ensure actor can manage channel subscription tiers
+pub fn ensure_actor_authorized_to_manage_subscription_tiers<T: Config>(
+    sender: &T::AccountId,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<Option<ChannelAgentPermissions>, DispatchError> {
+    ensure_actor_has_channel_permissions::<T>(
+        sender,
+        actor,
+        channel,
+        &[ChannelActionPermission::ManageSubscriptionTiers],
+    )
+}
+
 // Ensure actor can manage categories
 pub fn ensure_actor_authorized_to_manage_categories<T: Config>(
     origin: T::Origin,
@@ -517,6 +571,18 @@ pub fn ensure_authorized_to_update_video_state_bloat_bond<T: Config>(
 ) -> DispatchResult {
     ensure_lead_auth_success::<T>(sender)
 }
+
+pub fn ensure_authorized_to_update_playlist_state_bloat_bond<T: Config>(
+    sender: &T::AccountId,
+) -> DispatchResult {
+    ensure_lead_auth_success::<T>(sender)
+}
+
+pub fn ensure_authorized_to_update_subscription_state_bloat_bond<T: Config>(
+    sender: &T::AccountId,
+) -> DispatchResult {
+    ensure_lead_auth_success::<T>(sender)
+}
 /// Moderation actions (curator/lead)
 
 pub fn ensure_actor_authorized_to_perform_moderation_actions<T: Config>(
@@ -692,6 +758,17 @@ pub fn ensure_actor_authorized_to_reduce_creator_token_patronage_rate<T: Config>
     Ok(sender)
 }
 
+pub fn ensure_actor_authorized_to_manage_amm<T: Config>(
+    origin: T::Origin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<T::AccountId, DispatchError> {
+    let sender = ensure_signed(origin)?;
+    let required_permissions = vec![ChannelActionPermission::ManageAmm];
+    ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok(sender)
+}
+
 pub fn ensure_actor_authorized_to_manage_revenue_splits<T: Config>(
     origin: T::Origin,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -8,6 +8,7 @@ use super::curators;
 use super::fixtures::*;
 use super::mock::*;
 use crate::*;
+use frame_support::assert_ok;
 use storage::ModuleAccount as StorageModuleAccount;
 
 ///////////////////////////////////////////////////////////////////
@@ -219,6 +220,7 @@ fn unsuccessful_channel_creation_with_bucket_objects_size_limit_reached() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: STORAGE_BUCKET_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: create_cid(1),
+                    chunks_merkle_root: None,
                 }],
             })
             .with_default_storage_buckets()
@@ -251,6 +253,7 @@ fn unsuccessful_channel_creation_with_bucket_objects_number_limit_reached() {
                     .map(|_| DataObjectCreationParameters {
                         size: 1,
                         ipfs_content_id: create_cid(1),
+                        chunks_merkle_root: None,
                     })
                     .collect(),
             })
@@ -277,6 +280,7 @@ fn unsuccessful_channel_creation_with_data_limits_exceeded() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: VOUCHER_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: create_cid(1),
+                    chunks_merkle_root: None,
                 }],
             })
             .with_default_storage_buckets()
@@ -580,6 +584,7 @@ fn unsuccessful_channel_update_with_data_limits_exceeded() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: VOUCHER_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: create_cid(1),
+                    chunks_merkle_root: None,
                 }],
             })
             .call_and_assert(Err(storage::Error::<Test>::MaxDataObjectSizeExceeded.into()));
@@ -692,6 +697,7 @@ fn unsuccessful_channel_update_with_no_bucket_with_sufficient_object_size_limit(
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: STORAGE_BUCKET_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: create_cid(1),
+                    chunks_merkle_root: None,
                 }],
             })
             .call_and_assert(Err(
@@ -725,6 +731,7 @@ fn unsuccessful_channel_update_with_no_bucket_with_sufficient_object_number_limi
                     .map(|_| DataObjectCreationParameters {
                         size: 1,
                         ipfs_content_id: create_cid(1),
+                        chunks_merkle_root: None,
                     })
                     .collect(),
             })
@@ -3757,3 +3764,28 @@ fn successful_cancel_channel_transfer_by_owner_member() {
 //////////////////////////////////////////////////////////////////////
 /////////////// END OF CHANNEL AGENT PERMISSIONS TESTS ///////////////
 //////////////////////////////////////////////////////////////////////
+
+#[test]
+fn successful_channel_expired_assets_removal() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel();
+
+        let channel_assets: BTreeSet<_> =
+            Content::channel_by_id(ChannelId::one()).data_objects.into();
+        run_to_block(2 + DataObjectAcceptancePeriod::get());
+
+        assert_ok!(Storage::<Test>::delete_expired_data_objects(
+            Origin::signed(UNAUTHORIZED_MEMBER_ACCOUNT_ID),
+            Content::bag_id_for_channel(&ChannelId::one()),
+            channel_assets,
+        ));
+
+        assert!(Content::channel_by_id(ChannelId::one())
+            .data_objects
+            .is_empty());
+    })
+}
@@ -1,3 +1,4 @@
+mod amm;
 mod claim_patronage_credit;
 mod deissue;
 mod finalize_creator_token_sale;
@@ -0,0 +1,212 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_noop;
+use std::collections::BTreeMap;
+
+#[test]
+fn unsuccessful_activate_amm_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        ActivateAmmFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_activate_amm_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        ActivateAmmFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_activate_amm_with_zero_slope() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        assert_noop!(
+            Content::activate_amm(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                project_token::types::AmmParamsOf::<Test> {
+                    slope: 0,
+                    intercept: DEFAULT_AMM_INTERCEPT,
+                },
+            ),
+            project_token::Error::<Test>::AmmSlopeIsZero,
+        );
+    })
+}
+
+#[test]
+fn unsuccessful_activate_amm_during_active_sale() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        InitCreatorTokenSaleFixture::default().call_and_assert(Ok(()));
+        ActivateAmmFixture::default().call_and_assert(Err(
+            project_token::Error::<Test>::TokenIssuanceNotInIdleState.into(),
+        ));
+    })
+}
+
+#[test]
+fn activate_amm_fails_during_transfer() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        InitializeChannelTransferFixture::default()
+            .with_new_member_channel_owner(THIRD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+
+        ActivateAmmFixture::default()
+            .call_and_assert(Err(Error::<Test>::InvalidChannelTransferStatus.into()));
+    })
+}
+
+// Member channel
+
+#[test]
+fn unsuccessful_member_channel_amm_management_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManageAmm])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        ActivateAmmFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+        ActivateAmmFixture::default().call_and_assert(Ok(()));
+        DeactivateAmmFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_member_channel_amm_management_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageAmm])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        ActivateAmmFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+        DeactivateAmmFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_member_channel_amm_management_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        ActivateAmmFixture::default().call_and_assert(Ok(()));
+        DeactivateAmmFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_deactivate_amm_when_not_active() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        DeactivateAmmFixture::default()
+            .call_and_assert(Err(project_token::Error::<Test>::AmmNotActive.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_deissue_creator_token_with_active_amm() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default()
+            .with_initial_allocation(BTreeMap::new())
+            .call_and_assert(Ok(()));
+        ActivateAmmFixture::default().call_and_assert(Ok(()));
+        DeissueCreatorTokenFixture::default().call_and_assert(Err(
+            project_token::Error::<Test>::CannotDeissueTokenWithActiveAmm.into(),
+        ));
+    })
+}
+
+// Curator channel
+
+#[test]
+fn unsuccessful_curator_channel_amm_management_by_curator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManageAmm])
+            .setup();
+        IssueCreatorTokenFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .with_initial_allocation_to(LEAD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        ActivateAmmFixture::default()
+            .with_sender(DEFAULT_CURATOR_ACCOUNT_ID)
+            .with_actor(default_curator_actor())
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_curator_channel_amm_management_by_curator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageAmm])
+            .setup();
+        IssueCreatorTokenFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .with_initial_allocation_to(LEAD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        ActivateAmmFixture::default()
+            .with_sender(DEFAULT_CURATOR_ACCOUNT_ID)
+            .with_actor(default_curator_actor())
+            .call_and_assert(Ok(()));
+        DeactivateAmmFixture::default()
+            .with_sender(DEFAULT_CURATOR_ACCOUNT_ID)
+            .with_actor(default_curator_actor())
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_curator_channel_amm_management_by_lead() {
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel().setup();
+        IssueCreatorTokenFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .with_initial_allocation_to(LEAD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        ActivateAmmFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .call_and_assert(Ok(()));
+        DeactivateAmmFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .call_and_assert(Ok(()));
+    })
+}
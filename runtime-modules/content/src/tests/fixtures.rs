@@ -294,6 +294,7 @@ impl CreateChannelFixture {
                         )
                         .unwrap(),
                         num_videos: Zero::zero(),
+                        num_playlists: Zero::zero(),
                         cumulative_reward_claimed: Zero::zero(),
                         privilege_level: Zero::zero(),
                         paused_features: Default::default(),
@@ -3154,6 +3155,106 @@ impl DeissueCreatorTokenFixture {
     }
 }
 
+pub struct ActivateAmmFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    params: AmmParamsOf<Test>,
+}
+
+impl ActivateAmmFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            params: AmmParamsOf::<Test> {
+                slope: DEFAULT_AMM_SLOPE,
+                intercept: DEFAULT_AMM_INTERCEPT,
+            },
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+
+        let actual_result =
+            Content::activate_amm(origin, self.actor, self.channel_id, self.params.clone());
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+
+            let token_id = Content::channel_by_id(self.channel_id)
+                .creator_token_id
+                .unwrap();
+            let amm_curve = project_token::Module::<Test>::token_info_by_id(token_id)
+                .amm_curve
+                .unwrap();
+            assert_eq!(amm_curve.slope, self.params.slope);
+            assert_eq!(amm_curve.intercept, self.params.intercept);
+            assert_eq!(
+                amm_curve.fee_destination,
+                Some(ContentTreasury::<Test>::account_for_channel(
+                    self.channel_id
+                ))
+            );
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct DeactivateAmmFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+}
+
+impl DeactivateAmmFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = Origin::signed(self.sender);
+
+        let actual_result = Content::deactivate_amm(origin, self.actor, self.channel_id);
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+
+            let token_id = Content::channel_by_id(self.channel_id)
+                .creator_token_id
+                .unwrap();
+            assert!(project_token::Module::<Test>::token_info_by_id(token_id)
+                .amm_curve
+                .is_none());
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
 pub struct CancelChannelTransferFixture {
     origin: RawOrigin<U256>,
     channel_id: u64,
@@ -3490,6 +3591,12 @@ impl IssueNftFixture {
                     .unwrap();
                     TransactionalStatus::<Test>::OpenAuction(open_auction)
                 }
+                InitTransactionalStatus::<Test>::DutchAuction(params) => {
+                    let dutch_auction =
+                        DutchAuction::<Test>::try_new::<Test>(params, System::block_number())
+                            .unwrap();
+                    TransactionalStatus::<Test>::DutchAuction(dutch_auction)
+                }
             };
             assert!(video_post.nft_status.is_some());
             let nft_status = video_post.nft_status.unwrap();
@@ -4903,6 +5010,7 @@ pub fn create_data_object_candidates_helper(
         .map(|idx| DataObjectCreationParameters {
             size: DEFAULT_OBJECT_SIZE,
             ipfs_content_id: create_cid(idx),
+            chunks_merkle_root: None,
         })
         .collect()
 }
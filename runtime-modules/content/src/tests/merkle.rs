@@ -931,6 +931,7 @@ fn unsuccessfull_channel_payouts_update_with_insufficient_uploader_account_balan
             object_creation_params: DataObjectCreationParameters {
                 size: 1,
                 ipfs_content_id: create_cid(1),
+                chunks_merkle_root: None,
             },
         };
 
@@ -954,6 +955,7 @@ fn unsuccessfull_channel_payouts_update_with_unexpected_data_size_fee() {
             object_creation_params: DataObjectCreationParameters {
                 size: 1,
                 ipfs_content_id: create_cid(1),
+                chunks_merkle_root: None,
             },
         };
 
@@ -976,6 +978,7 @@ fn unsuccessfull_channel_payouts_update_with_unexpected_data_object_state_bloat_
             object_creation_params: DataObjectCreationParameters {
                 size: 1,
                 ipfs_content_id: create_cid(1),
+                chunks_merkle_root: None,
             },
         };
 
@@ -1052,6 +1055,7 @@ fn successful_channel_payouts_update() {
             object_creation_params: DataObjectCreationParameters {
                 size: 1,
                 ipfs_content_id: create_cid(1),
+                chunks_merkle_root: None,
             },
         };
 
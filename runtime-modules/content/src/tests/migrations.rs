@@ -0,0 +1,192 @@
+#![cfg(test)]
+use super::fixtures::*;
+use super::mock::*;
+use crate::*;
+use codec::Encode;
+use frame_support::assert_ok;
+use frame_support::storage::unhashed;
+
+#[test]
+fn migrate_video_nfts_to_creator_token_layout_ok() {
+    with_default_mock_builder(|| {
+        let video_state_bloat_bond = RepayableBloatBond::new(10u64, None);
+        let transactional_status = TransactionalStatus::<Test>::BuyNow(100u64);
+        let creator_royalty = Some(Royalty::from_percent(5));
+
+        // videos encoded with the nft layout preceding the creator token pricing
+        for (video_id, has_nft) in [(1u64, true), (2u64, false)] {
+            let nft_status = has_nft.then(|| {
+                (
+                    NftOwner::<MemberId>::Member(DEFAULT_MEMBER_ID),
+                    transactional_status.clone(),
+                    creator_royalty,
+                    3u64,
+                )
+            });
+            unhashed::put_raw(
+                &VideoById::<Test>::hashed_key_for(video_id),
+                &(
+                    ChannelId::one(),
+                    nft_status,
+                    VideoAssetsSet::<Test>::default(),
+                    video_state_bloat_bond.clone(),
+                )
+                    .encode(),
+            );
+            assert!(VideoById::<Test>::try_get(video_id).is_err());
+        }
+
+        Content::migrate_video_nfts_to_creator_token_layout();
+
+        let video = Video::<Test> {
+            in_channel: ChannelId::one(),
+            nft_status: None,
+            data_objects: Default::default(),
+            video_state_bloat_bond,
+        };
+        assert_eq!(
+            VideoById::<Test>::try_get(1u64),
+            Ok(Video::<Test> {
+                nft_status: Some(Nft::<Test> {
+                    owner: NftOwner::Member(DEFAULT_MEMBER_ID),
+                    transactional_status,
+                    creator_royalty,
+                    open_auctions_nonce: 3,
+                    priced_in_creator_token: false,
+                    collection_id: None,
+                }),
+                ..video.clone()
+            })
+        );
+        assert_eq!(VideoById::<Test>::try_get(2u64), Ok(video));
+    })
+}
+
+#[test]
+fn migrate_open_auction_bids_to_creator_token_layout_ok() {
+    with_default_mock_builder(|| {
+        let video_id = 1u64;
+
+        // open auction bid encoded with the layout preceding the creator token pricing
+        unhashed::put_raw(
+            &OpenAuctionBidByVideoAndMember::<Test>::hashed_key_for(video_id, DEFAULT_MEMBER_ID),
+            &(100u64, 5u64, 2u64).encode(),
+        );
+        assert!(
+            OpenAuctionBidByVideoAndMember::<Test>::try_get(video_id, DEFAULT_MEMBER_ID).is_err()
+        );
+
+        Content::migrate_open_auction_bids_to_creator_token_layout();
+
+        assert_eq!(
+            OpenAuctionBidByVideoAndMember::<Test>::try_get(video_id, DEFAULT_MEMBER_ID),
+            Ok(OpenAuctionBid::<Test> {
+                amount: 100,
+                made_at_block: 5,
+                auction_id: 2,
+                in_creator_token: false,
+            })
+        );
+    })
+}
+
+#[test]
+fn migrated_video_nft_out_of_any_collection_can_be_bought() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, ed() + DEFAULT_NFT_PRICE);
+        ContentTest::default().with_video().setup();
+        IssueNftFixture::default()
+            .with_params(NftIssuanceParameters::<Test> {
+                init_transactional_status: InitTransactionalStatus::<Test>::BuyNow(
+                    DEFAULT_NFT_PRICE,
+                ),
+                ..Default::default()
+            })
+            .call_and_assert(Ok(()));
+
+        // nft issued before the nft collections: encoded with the pre-series layout
+        let video = Content::video_by_id(VideoId::one());
+        let nft = video.nft_status.clone().unwrap();
+        unhashed::put_raw(
+            &VideoById::<Test>::hashed_key_for(VideoId::one()),
+            &(
+                video.in_channel,
+                Some((
+                    nft.owner,
+                    nft.transactional_status,
+                    nft.creator_royalty,
+                    nft.open_auctions_nonce,
+                )),
+                video.data_objects.clone(),
+                video.video_state_bloat_bond.clone(),
+            )
+                .encode(),
+        );
+
+        Content::migrate_video_nfts_to_creator_token_layout();
+
+        assert_eq!(VideoById::<Test>::try_get(VideoId::one()), Ok(video));
+        assert_ok!(Content::buy_nft(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            VideoId::one(),
+            SECOND_MEMBER_ID,
+            DEFAULT_NFT_PRICE,
+        ));
+        assert_eq!(
+            Content::video_by_id(VideoId::one())
+                .nft_status
+                .map(|nft| (nft.owner, nft.collection_id)),
+            Some((NftOwner::Member(SECOND_MEMBER_ID), None))
+        );
+    })
+}
+
+#[test]
+fn migrate_channels_to_playlists_layout_ok() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_videos(1, &[]);
+
+        // channel created before the playlists: encoded without the playlists number
+        let channel = Content::channel_by_id(ChannelId::one());
+        unhashed::put_raw(
+            &ChannelById::<Test>::hashed_key_for(ChannelId::one()),
+            &(
+                &channel.owner,
+                channel.num_videos,
+                &channel.collaborators,
+                channel.cumulative_reward_claimed,
+                channel.privilege_level,
+                &channel.paused_features,
+                &channel.transfer_status,
+                &channel.data_objects,
+                channel.daily_nft_limit,
+                channel.weekly_nft_limit,
+                &channel.daily_nft_counter,
+                &channel.weekly_nft_counter,
+                channel.creator_token_id,
+                &channel.channel_state_bloat_bond,
+            )
+                .encode(),
+        );
+        assert!(ChannelById::<Test>::try_get(ChannelId::one()).is_err());
+
+        Content::migrate_channels_to_playlists_layout();
+
+        assert_eq!(ChannelById::<Test>::try_get(ChannelId::one()), Ok(channel));
+        assert_ok!(Content::create_playlist(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            ChannelId::one(),
+            PlaylistCreationParameters::<Test> {
+                videos: vec![VideoId::one()],
+                meta: None,
+                expected_playlist_state_bloat_bond: Content::playlist_state_bloat_bond_value(),
+            },
+        ));
+        assert_eq!(Content::channel_by_id(ChannelId::one()).num_playlists, 1);
+    })
+}
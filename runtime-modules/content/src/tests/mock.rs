@@ -101,6 +101,8 @@ pub const UNAUTHORIZED_COLLABORATOR_MEMBER_ID: u64 = COLABORATOR_IDS[1];
 pub const DEFAULT_DATA_OBJECT_STATE_BLOAT_BOND: u64 = 0;
 pub const DEFAULT_CHANNEL_STATE_BLOAT_BOND: u64 = 25; // Should be >= ExistentialDeposit!
 pub const DEFAULT_VIDEO_STATE_BLOAT_BOND: u64 = 0;
+pub const DEFAULT_PLAYLIST_STATE_BLOAT_BOND: u64 = 0;
+pub const DEFAULT_SUBSCRIPTION_STATE_BLOAT_BOND: u64 = 0;
 pub const DEFAULT_OBJECT_SIZE: u64 = 5;
 pub const DATA_OBJECTS_NUMBER: u64 = 10; // MUST BE >= 1
 pub const OUTSTANDING_VIDEOS: u64 = 5;
@@ -134,6 +136,8 @@ pub const DEFAULT_ISSUER_TRANSFER_AMOUNT: u64 = 1_000_000;
 pub const DEFAULT_PATRONAGE_RATE: YearlyRate = YearlyRate(Permill::from_percent(1));
 pub const DEFAULT_REVENUE_SPLIT_DURATION: u64 = 1000;
 pub const DEFAULT_SPLIT_RATE: Permill = Permill::from_percent(10);
+pub const DEFAULT_AMM_SLOPE: u64 = 10;
+pub const DEFAULT_AMM_INTERCEPT: u64 = 100;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -146,6 +150,7 @@ frame_support::construct_runtime!(
     {
         System: frame_system,
         Balances: balances,
+        CollectiveFlip: randomness_collective_flip,
         Timestamp: pallet_timestamp,
         Membership: membership::{Pallet, Call, Storage, Event<T>},
         Storage: storage::{Pallet, Call, Storage, Event<T>},
@@ -211,6 +216,8 @@ impl balances::Config for Test {
     type WeightInfo = ();
 }
 
+impl randomness_collective_flip::Config for Test {}
+
 impl common::StorageOwnership for Test {
     type ChannelId = u64;
     type ContentId = u64;
@@ -304,6 +311,18 @@ parameter_types! {
     pub const DefaultChannelDynamicBagNumberOfStorageBuckets: u32 = 4;
     pub const MaxDataObjectSize: u64 = VOUCHER_OBJECTS_SIZE_LIMIT;
     pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 5;
+    pub const MaxStorageChallengesPerBlock: u32 = 1;
+    pub const StorageChallengeResponsePeriod: u64 = 10;
+    pub const MaxStorageChallengeFailures: u32 = 3;
+    pub const StorageChallengeSlashPenalty: u64 = 10;
+    pub const DataObjectChunkSize: u64 = 1024;
+    pub const StorageOperatorFeeShare: Perbill = Perbill::zero();
+    pub const DataObjectAcceptancePeriod: u64 = 10;
+    pub const MaxStorageBucketNumber: u64 = 40;
+    pub const MaxRebalancedBagsNumber: u32 = 10;
+    pub const MaxBlacklistedDataObjectsPerCall: u32 = 10;
+    pub const MaxDataObjectsAcceptanceRemovalsPerBlock: u32 = 10;
+    pub const MaxDataObjectsMigratedPerBlock: u32 = 10;
 }
 
 pub const STORAGE_WG_LEADER_ACCOUNT_ID: U256 = U256([100001, 0, 0, 0]);
@@ -343,6 +362,20 @@ impl storage::Config for Test {
     type DistributionWorkingGroup = DistributionWG;
     type WeightInfo = ();
     type ModuleAccountInitialBalance = ExistentialDeposit;
+    type Randomness = CollectiveFlip;
+    type MaxStorageChallengesPerBlock = MaxStorageChallengesPerBlock;
+    type StorageChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type MaxStorageChallengeFailures = MaxStorageChallengeFailures;
+    type StorageChallengeSlashPenalty = StorageChallengeSlashPenalty;
+    type DataObjectChunkSize = DataObjectChunkSize;
+    type StorageOperatorFeeShare = StorageOperatorFeeShare;
+    type DataObjectAcceptancePeriod = DataObjectAcceptancePeriod;
+    type ExpiredDataObjectsHandler = Content;
+    type MaxStorageBucketNumber = MaxStorageBucketNumber;
+    type MaxRebalancedBagsNumber = MaxRebalancedBagsNumber;
+    type MaxBlacklistedDataObjectsPerCall = MaxBlacklistedDataObjectsPerCall;
+    type MaxDataObjectsAcceptanceRemovalsPerBlock = MaxDataObjectsAcceptanceRemovalsPerBlock;
+    type MaxDataObjectsMigratedPerBlock = MaxDataObjectsMigratedPerBlock;
 }
 
 // Anyone can upload and delete without restriction
@@ -374,6 +407,15 @@ parameter_types! {
     pub const MinimumCashoutAllowedLimit: u64 = 1;
     pub const MaximumCashoutAllowedLimit: u64 = 1_000_000;
     pub const MaxNftAuctionWhitelistLength: u32 = 5;
+    pub const MaxNftsPerBatchIssuance: u32 = 5;
+    pub const MaxRoyaltySplitRecipients: u32 = 3;
+    pub const MaxVideosPerPlaylist: u32 = 5;
+    pub const MaxPlaylistsPerChannel: u32 = 3;
+    pub const MaxVideoVisibilityChangesPerBlock: u32 = 2;
+    pub const MaxSubscriptionTiersPerChannel: u32 = 3;
+    pub const MaxSubscriptionPaymentsPerBlock: u32 = 2;
+    pub const MinSubscriptionPeriod: u64 = 10;
+    pub const MinSubscriptionPrice: u64 = 5;
 }
 
 impl Config for Test {
@@ -388,6 +430,15 @@ impl Config for Test {
     /// Type of identifier for open auctions
     type OpenAuctionId = u64;
 
+    /// Type of identifier for nft collections
+    type NftCollectionId = u64;
+
+    /// Type of identifier for playlists
+    type PlaylistId = u64;
+
+    /// Type of identifier for channel subscriptions
+    type SubscriptionId = u64;
+
     /// The maximum number of curators per group constraint
     type MaxNumberOfCuratorsPerGroup = MaxNumberOfCuratorsPerGroup;
 
@@ -447,6 +498,33 @@ impl Config for Test {
 
     /// Max nft auction whitelist length
     type MaxNftAuctionWhitelistLength = MaxNftAuctionWhitelistLength;
+
+    /// Max number of nfts issued in a single batch
+    type MaxNftsPerBatchIssuance = MaxNftsPerBatchIssuance;
+
+    /// Max number of recipients in a channel royalty split
+    type MaxRoyaltySplitRecipients = MaxRoyaltySplitRecipients;
+
+    /// Max number of videos in a single playlist
+    type MaxVideosPerPlaylist = MaxVideosPerPlaylist;
+
+    /// Max number of playlists per channel
+    type MaxPlaylistsPerChannel = MaxPlaylistsPerChannel;
+
+    /// Max number of video visibility changes scheduled for a single block
+    type MaxVideoVisibilityChangesPerBlock = MaxVideoVisibilityChangesPerBlock;
+
+    /// Max number of subscription tiers per channel
+    type MaxSubscriptionTiersPerChannel = MaxSubscriptionTiersPerChannel;
+
+    /// Max number of subscription payments pulled in a single block
+    type MaxSubscriptionPaymentsPerBlock = MaxSubscriptionPaymentsPerBlock;
+
+    /// Min subscription period length
+    type MinSubscriptionPeriod = MinSubscriptionPeriod;
+
+    /// Min subscription tier price
+    type MinSubscriptionPrice = MinSubscriptionPrice;
 }
 
 pub const COUNCIL_INITIAL_BUDGET: u64 = 0;
@@ -593,6 +671,8 @@ pub struct ExtBuilder {
     nft_limits_enabled: bool,
     channel_state_bloat_bond_value: BalanceOf<Test>,
     video_state_bloat_bond_value: BalanceOf<Test>,
+    playlist_state_bloat_bond_value: BalanceOf<Test>,
+    subscription_state_bloat_bond_value: BalanceOf<Test>,
 }
 
 impl Default for ExtBuilder {
@@ -623,6 +703,8 @@ impl Default for ExtBuilder {
             nft_limits_enabled: true,
             channel_state_bloat_bond_value: DEFAULT_CHANNEL_STATE_BLOAT_BOND,
             video_state_bloat_bond_value: DEFAULT_VIDEO_STATE_BLOAT_BOND,
+            playlist_state_bloat_bond_value: DEFAULT_PLAYLIST_STATE_BLOAT_BOND,
+            subscription_state_bloat_bond_value: DEFAULT_SUBSCRIPTION_STATE_BLOAT_BOND,
         }
     }
 }
@@ -687,6 +769,8 @@ impl ExtBuilder {
             nft_limits_enabled: self.nft_limits_enabled,
             channel_state_bloat_bond_value: self.channel_state_bloat_bond_value,
             video_state_bloat_bond_value: self.video_state_bloat_bond_value,
+            playlist_state_bloat_bond_value: self.playlist_state_bloat_bond_value,
+            subscription_state_bloat_bond_value: self.subscription_state_bloat_bond_value,
         }
         .assimilate_storage(&mut t)
         .unwrap();
@@ -736,6 +820,20 @@ pub fn get_open_auction_params() -> OpenAuctionParams<Test> {
     }
 }
 
+pub const DEFAULT_DUTCH_AUCTION_STARTING_PRICE: u64 = 1000;
+pub const DEFAULT_DUTCH_AUCTION_FLOOR_PRICE: u64 = 100;
+pub const DEFAULT_DUTCH_AUCTION_PRICE_DECREASE: u64 = 50;
+
+pub fn get_dutch_auction_params() -> DutchAuctionParams<Test> {
+    DutchAuctionParams::<Test> {
+        starting_price: DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+        floor_price: DEFAULT_DUTCH_AUCTION_FLOOR_PRICE,
+        price_decrease_per_block: DEFAULT_DUTCH_AUCTION_PRICE_DECREASE,
+        whitelist: BTreeSet::new(),
+        starts_at: None,
+    }
+}
+
 // membership trait implementation and related stuff
 
 parameter_types! {
@@ -1031,6 +1129,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for DistributionWG {
     }
 }
 
+impl common::working_group::WorkingGroupStakeSlasher<Test, u64> for StorageWG {
+    fn slash_worker_stake(_worker_id: &u64, _penalty: u64) -> DispatchResult {
+        Ok(())
+    }
+}
+
 impl common::working_group::WorkingGroupBudgetHandler<U256, u64> for StorageWG {
     fn get_budget() -> u64 {
         unimplemented!()
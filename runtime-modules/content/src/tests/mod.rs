@@ -6,7 +6,11 @@ mod curators;
 mod fixtures;
 mod merkle;
 mod metaprotocol;
+mod migrations;
 pub(crate) mod mock;
 mod nft;
+mod playlists;
+mod subscriptions;
 mod transfers;
+mod video_visibility_schedule;
 mod videos;
@@ -1,15 +1,19 @@
 mod accept_incoming_offer;
+mod batch_issue_nft;
 mod buy_nft;
 mod cancel_buy_now;
 mod cancel_nft_auction;
 mod cancel_offer;
 mod cancel_open_auction_bid;
 mod claim_won_english_auction;
+mod creator_token_pricing;
 mod destroy_nft;
+mod dutch_auction;
 mod issue_nft;
 mod make_bid;
 mod offer_nft;
 mod pick_open_auction_winner;
+mod royalty_split;
 mod sell_nft;
 mod sling_nft_back;
 mod start_nft_auction;
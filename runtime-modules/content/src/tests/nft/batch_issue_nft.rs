@@ -0,0 +1,398 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use std::iter::FromIterator;
+
+type NftCollectionId = <Test as Config>::NftCollectionId;
+
+const DEFAULT_COLLECTION_MAX_SUPPLY: u32 = 3;
+
+fn get_nft_collection_params() -> NftCollectionParameters<Test> {
+    NftCollectionParameters::<Test> {
+        name_hash: Hashing::hash_of(b"collection"),
+        max_supply: DEFAULT_COLLECTION_MAX_SUPPLY,
+        royalty: Some(Perbill::from_percent(DEFAULT_ROYALTY)),
+        whitelist: BTreeSet::from_iter(vec![SECOND_MEMBER_ID, THIRD_MEMBER_ID]),
+    }
+}
+
+fn setup_channel_with_videos_and_nft_collection(number_of_videos: u8) -> NftCollectionId {
+    create_initial_storage_buckets_helper();
+    increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+    create_default_member_owned_channel_with_videos(number_of_videos, &[]);
+
+    let collection_id = Content::next_nft_collection_id();
+    assert_ok!(Content::create_nft_collection(
+        Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(DEFAULT_MEMBER_ID),
+        ChannelId::one(),
+        get_nft_collection_params(),
+    ));
+
+    collection_id
+}
+
+fn video_ids(range: std::ops::RangeInclusive<u64>) -> BTreeSet<VideoId> {
+    BTreeSet::from_iter(range)
+}
+
+#[test]
+fn create_nft_collection() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let collection_id = setup_channel_with_videos_and_nft_collection(1);
+        let params = get_nft_collection_params();
+
+        assert_eq!(
+            Content::nft_collection_by_id(collection_id),
+            NftCollection::<Test> {
+                channel_id: ChannelId::one(),
+                name_hash: params.name_hash,
+                max_supply: params.max_supply,
+                issued: 0,
+                royalty: params.royalty,
+                whitelist: params.whitelist.clone().try_into().unwrap(),
+            }
+        );
+        assert_eq!(Content::next_nft_collection_id(), collection_id + 1);
+
+        last_event_eq!(RawEvent::NftCollectionCreated(
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            ChannelId::one(),
+            collection_id,
+            params,
+        ));
+    })
+}
+
+#[test]
+fn create_nft_collection_fails_with_royalty_upper_bound_exceeded() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        assert_noop!(
+            Content::create_nft_collection(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                NftCollectionParameters::<Test> {
+                    royalty: Some(Content::max_creator_royalty() + Perbill::from_percent(1)),
+                    ..get_nft_collection_params()
+                },
+            ),
+            Error::<Test>::RoyaltyUpperBoundExceeded
+        );
+    })
+}
+
+#[test]
+fn create_nft_collection_fails_with_unauthorized_actor() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        assert_noop!(
+            Content::create_nft_collection(
+                Origin::signed(UNAUTHORIZED_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(UNAUTHORIZED_MEMBER_ID),
+                ChannelId::one(),
+                get_nft_collection_params(),
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+    })
+}
+
+#[test]
+fn batch_issue_nft() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let collection_id = setup_channel_with_videos_and_nft_collection(3);
+        let channel_daily_counter_pre = Content::channel_by_id(ChannelId::one())
+            .daily_nft_counter
+            .counter;
+
+        assert_ok!(Content::batch_issue_nft(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            collection_id,
+            video_ids(1..=3),
+            InitTransactionalStatus::<Test>::Idle,
+        ));
+
+        for video_id in 1..=3 {
+            assert_eq!(
+                Content::video_by_id(video_id).nft_status,
+                Some(
+                    Nft::<Test>::new(
+                        NftOwner::ChannelOwner,
+                        Some(Perbill::from_percent(DEFAULT_ROYALTY)),
+                        TransactionalStatus::<Test>::Idle,
+                    )
+                    .with_collection(collection_id)
+                )
+            );
+        }
+        assert_eq!(Content::nft_collection_by_id(collection_id).issued, 3);
+        assert_eq!(
+            Content::channel_by_id(ChannelId::one())
+                .daily_nft_counter
+                .counter,
+            channel_daily_counter_pre + 3
+        );
+
+        last_event_eq!(RawEvent::NftsBatchIssued(
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            collection_id,
+            video_ids(1..=3),
+            InitTransactionalStatus::<Test>::Idle,
+        ));
+    })
+}
+
+#[test]
+fn batch_issue_nft_applies_collection_whitelist_to_auctions() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let collection_id = setup_channel_with_videos_and_nft_collection(2);
+
+        assert_ok!(Content::batch_issue_nft(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            collection_id,
+            video_ids(1..=2),
+            InitTransactionalStatus::<Test>::DutchAuction(get_dutch_auction_params()),
+        ));
+
+        for video_id in 1..=2 {
+            assert!(matches!(
+                Content::video_by_id(video_id).nft_status,
+                Some(OwnedNft {
+                    transactional_status: TransactionalStatus::<Test>::DutchAuction(
+                        DutchAuctionRecord { ref whitelist, .. }
+                    ),
+                    ..
+                }) if whitelist.clone().into_inner() == get_nft_collection_params().whitelist
+            ));
+        }
+    })
+}
+
+#[test]
+fn batch_issue_nft_keeps_auction_whitelist_without_collection_whitelist() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_videos(1, &[]);
+
+        let collection_id = Content::next_nft_collection_id();
+        assert_ok!(Content::create_nft_collection(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            ChannelId::one(),
+            NftCollectionParameters::<Test> {
+                whitelist: BTreeSet::new(),
+                ..get_nft_collection_params()
+            },
+        ));
+
+        let auction_whitelist = BTreeSet::from_iter(vec![SECOND_MEMBER_ID, THIRD_MEMBER_ID]);
+        assert_ok!(Content::batch_issue_nft(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            collection_id,
+            video_ids(1..=1),
+            InitTransactionalStatus::<Test>::DutchAuction(DutchAuctionParams::<Test> {
+                whitelist: auction_whitelist.clone(),
+                ..get_dutch_auction_params()
+            }),
+        ));
+
+        assert!(matches!(
+            Content::video_by_id(1).nft_status,
+            Some(OwnedNft {
+                transactional_status: TransactionalStatus::<Test>::DutchAuction(
+                    DutchAuctionRecord { ref whitelist, .. }
+                ),
+                ..
+            }) if whitelist.clone().into_inner() == auction_whitelist
+        ));
+    })
+}
+
+#[test]
+fn batch_issue_nft_fails_with_collection_max_supply_exceeded() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let collection_id = setup_channel_with_videos_and_nft_collection(4);
+
+        assert_noop!(
+            Content::batch_issue_nft(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                collection_id,
+                video_ids(1..=4),
+                InitTransactionalStatus::<Test>::Idle,
+            ),
+            Error::<Test>::NftCollectionMaxSupplyExceeded
+        );
+    })
+}
+
+#[test]
+fn batch_issue_nft_fails_with_channel_daily_nft_limit_exceeded() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let collection_id = setup_channel_with_videos_and_nft_collection(3);
+        set_channel_daily_nft_limit(ChannelId::one(), 2);
+
+        assert_noop!(
+            Content::batch_issue_nft(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                collection_id,
+                video_ids(1..=3),
+                InitTransactionalStatus::<Test>::Idle,
+            ),
+            Error::<Test>::ChannelNftDailyLimitExceeded
+        );
+    })
+}
+
+#[test]
+fn batch_issue_nft_fails_with_nft_already_issued() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let collection_id = setup_channel_with_videos_and_nft_collection(2);
+        assert_ok!(Content::issue_nft(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            2,
+            NftIssuanceParameters::<Test>::default(),
+        ));
+
+        assert_noop!(
+            Content::batch_issue_nft(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                collection_id,
+                video_ids(1..=2),
+                InitTransactionalStatus::<Test>::Idle,
+            ),
+            Error::<Test>::NftAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn batch_issue_nft_fails_with_video_not_in_collection_channel() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let collection_id = setup_channel_with_videos_and_nft_collection(1);
+        // second channel with a video
+        create_default_member_owned_channel_with_video();
+
+        assert_noop!(
+            Content::batch_issue_nft(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                collection_id,
+                video_ids(1..=2),
+                InitTransactionalStatus::<Test>::Idle,
+            ),
+            Error::<Test>::VideoNotInNftCollectionChannel
+        );
+    })
+}
+
+#[test]
+fn batch_issue_nft_fails_with_empty_batch() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let collection_id = setup_channel_with_videos_and_nft_collection(1);
+
+        assert_noop!(
+            Content::batch_issue_nft(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                collection_id,
+                BTreeSet::new(),
+                InitTransactionalStatus::<Test>::Idle,
+            ),
+            Error::<Test>::NftBatchIssuanceIsEmpty
+        );
+    })
+}
+
+#[test]
+fn batch_issue_nft_fails_with_max_nfts_per_batch_issuance_exceeded() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let collection_id = setup_channel_with_videos_and_nft_collection(1);
+        let max_batch_size = <Test as Config>::MaxNftsPerBatchIssuance::get() as u64;
+
+        assert_noop!(
+            Content::batch_issue_nft(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                collection_id,
+                video_ids(1..=max_batch_size + 1),
+                InitTransactionalStatus::<Test>::Idle,
+            ),
+            Error::<Test>::MaxNftsPerBatchIssuanceExceeded
+        );
+    })
+}
+
+#[test]
+fn batch_issue_nft_fails_with_invalid_collection_id() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let collection_id = setup_channel_with_videos_and_nft_collection(1);
+
+        assert_noop!(
+            Content::batch_issue_nft(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                collection_id + 1,
+                video_ids(1..=1),
+                InitTransactionalStatus::<Test>::Idle,
+            ),
+            Error::<Test>::NftCollectionDoesNotExist
+        );
+    })
+}
@@ -0,0 +1,429 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use project_token::types::TokenAllocationOf;
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+const CREATOR_TOKEN_ALLOCATION: u64 = 1_000_000;
+
+fn setup_channel_with_creator_token_and_video() {
+    ContentTest::with_member_channel().with_video().setup();
+    IssueCreatorTokenFixture::default()
+        .with_initial_allocation(BTreeMap::from_iter(
+            [DEFAULT_MEMBER_ID, SECOND_MEMBER_ID, THIRD_MEMBER_ID]
+                .iter()
+                .map(|member_id| {
+                    (
+                        *member_id,
+                        TokenAllocationOf::<Test> {
+                            amount: CREATOR_TOKEN_ALLOCATION,
+                            vesting_schedule_params: None,
+                        },
+                    )
+                }),
+        ))
+        .call_and_assert(Ok(()));
+}
+
+fn creator_token_id() -> <Test as project_token::Config>::TokenId {
+    Content::channel_by_id(ChannelId::one())
+        .creator_token_id
+        .unwrap()
+}
+
+fn creator_token_balance(member_id: MemberId) -> u64 {
+    project_token::Module::<Test>::account_info_by_token_and_member(creator_token_id(), member_id)
+        .amount
+}
+
+fn escrowed_creator_token_balance(member_id: MemberId) -> u64 {
+    project_token::Module::<Test>::escrowed_amount_by_token_and_member(
+        creator_token_id(),
+        member_id,
+    )
+}
+
+// Issue nft owned by `THIRD_MEMBER_ID` with default royalty and price it in creator token
+fn setup_nft_priced_in_creator_token() -> VideoId {
+    let video_id = VideoId::one();
+    setup_channel_with_creator_token_and_video();
+
+    assert_ok!(Content::issue_nft(
+        Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(DEFAULT_MEMBER_ID),
+        video_id,
+        NftIssuanceParameters::<Test> {
+            royalty: Some(Perbill::from_percent(DEFAULT_ROYALTY)),
+            non_channel_owner: Some(THIRD_MEMBER_ID),
+            ..Default::default()
+        },
+    ));
+
+    assert_ok!(Content::set_nft_priced_in_creator_token(
+        Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(THIRD_MEMBER_ID),
+        video_id,
+        true,
+    ));
+
+    video_id
+}
+
+#[test]
+fn set_nft_priced_in_creator_token_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_priced_in_creator_token();
+
+        assert!(matches!(
+            Content::video_by_id(video_id).nft_status,
+            Some(OwnedNft {
+                priced_in_creator_token: true,
+                ..
+            })
+        ));
+
+        last_event_eq!(RawEvent::NftCreatorTokenPricingSet(
+            ContentActor::Member(THIRD_MEMBER_ID),
+            video_id,
+            true,
+        ));
+    })
+}
+
+#[test]
+fn set_nft_priced_in_creator_token_fails_with_creator_token_not_issued() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = VideoId::one();
+        ContentTest::with_member_channel().with_video().setup();
+        IssueNftFixture::default().call_and_assert(Ok(()));
+
+        assert_noop!(
+            Content::set_nft_priced_in_creator_token(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                video_id,
+                true,
+            ),
+            Error::<Test>::CreatorTokenNotIssued
+        );
+    })
+}
+
+#[test]
+fn set_nft_priced_in_creator_token_fails_with_beneficiary_without_token_account() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = VideoId::one();
+        setup_channel_with_creator_token_and_video();
+        IssueNftFixture::default()
+            .with_non_channel_owner(COLLABORATOR_MEMBER_ID)
+            .call_and_assert(Ok(()));
+
+        assert_noop!(
+            Content::set_nft_priced_in_creator_token(
+                Origin::signed(COLLABORATOR_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(COLLABORATOR_MEMBER_ID),
+                video_id,
+                true,
+            ),
+            Error::<Test>::NftBeneficiaryHasNoCreatorTokenAccount
+        );
+    })
+}
+
+#[test]
+fn set_nft_priced_in_creator_token_fails_with_nft_not_idle() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_priced_in_creator_token();
+        assert_ok!(Content::sell_nft(
+            Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+            video_id,
+            ContentActor::Member(THIRD_MEMBER_ID),
+            DEFAULT_NFT_PRICE,
+        ));
+
+        assert_noop!(
+            Content::set_nft_priced_in_creator_token(
+                Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(THIRD_MEMBER_ID),
+                video_id,
+                false,
+            ),
+            Error::<Test>::NftIsNotIdle
+        );
+    })
+}
+
+#[test]
+fn buy_nft_in_creator_token_ok_with_proper_royalty_accounting() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_priced_in_creator_token();
+        let royalty = Perbill::from_percent(DEFAULT_ROYALTY).mul_floor(DEFAULT_NFT_PRICE);
+        assert_ok!(Content::sell_nft(
+            Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+            video_id,
+            ContentActor::Member(THIRD_MEMBER_ID),
+            DEFAULT_NFT_PRICE,
+        ));
+        let joy_balance_pre = Balances::<Test>::usable_balance(SECOND_MEMBER_ACCOUNT_ID);
+
+        assert_ok!(Content::buy_nft(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            video_id,
+            SECOND_MEMBER_ID,
+            DEFAULT_NFT_PRICE,
+        ));
+
+        assert_eq!(
+            (
+                creator_token_balance(SECOND_MEMBER_ID),
+                creator_token_balance(DEFAULT_MEMBER_ID),
+                creator_token_balance(THIRD_MEMBER_ID),
+                escrowed_creator_token_balance(SECOND_MEMBER_ID),
+                Balances::<Test>::usable_balance(SECOND_MEMBER_ACCOUNT_ID),
+            ),
+            (
+                CREATOR_TOKEN_ALLOCATION - DEFAULT_NFT_PRICE,
+                CREATOR_TOKEN_ALLOCATION + royalty,
+                CREATOR_TOKEN_ALLOCATION + DEFAULT_NFT_PRICE - royalty,
+                0,
+                joy_balance_pre,
+            )
+        );
+        assert!(matches!(
+            Content::video_by_id(video_id).nft_status,
+            Some(OwnedNft {
+                owner: NftOwner::Member(SECOND_MEMBER_ID),
+                priced_in_creator_token: true,
+                ..
+            })
+        ));
+    })
+}
+
+#[test]
+fn buy_nft_in_creator_token_ok_with_royalty_split() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_priced_in_creator_token();
+        let royalty = Perbill::from_percent(DEFAULT_ROYALTY).mul_floor(DEFAULT_NFT_PRICE);
+        let third_member_share = Perbill::from_percent(50).mul_floor(royalty);
+        // collaborator holds no creator token account: its share goes to the channel owner
+        assert_ok!(Content::set_channel_royalty_split(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            ChannelId::one(),
+            BTreeMap::from_iter(vec![
+                (
+                    RoyaltyRecipient::Member(THIRD_MEMBER_ID),
+                    Perbill::from_percent(50)
+                ),
+                (
+                    RoyaltyRecipient::Member(COLLABORATOR_MEMBER_ID),
+                    Perbill::from_percent(20)
+                ),
+            ]),
+        ));
+        assert_ok!(Content::sell_nft(
+            Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+            video_id,
+            ContentActor::Member(THIRD_MEMBER_ID),
+            DEFAULT_NFT_PRICE,
+        ));
+
+        assert_ok!(Content::buy_nft(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            video_id,
+            SECOND_MEMBER_ID,
+            DEFAULT_NFT_PRICE,
+        ));
+
+        assert_eq!(
+            (
+                creator_token_balance(SECOND_MEMBER_ID),
+                creator_token_balance(DEFAULT_MEMBER_ID),
+                creator_token_balance(THIRD_MEMBER_ID),
+                escrowed_creator_token_balance(SECOND_MEMBER_ID),
+            ),
+            (
+                CREATOR_TOKEN_ALLOCATION - DEFAULT_NFT_PRICE,
+                CREATOR_TOKEN_ALLOCATION + royalty - third_member_share,
+                CREATOR_TOKEN_ALLOCATION + DEFAULT_NFT_PRICE - royalty + third_member_share,
+                0,
+            )
+        );
+    })
+}
+
+#[test]
+fn buy_nft_in_creator_token_fails_with_insufficient_creator_token_balance() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_priced_in_creator_token();
+        let price = CREATOR_TOKEN_ALLOCATION + 1;
+        assert_ok!(Content::sell_nft(
+            Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+            video_id,
+            ContentActor::Member(THIRD_MEMBER_ID),
+            price,
+        ));
+
+        assert_noop!(
+            Content::buy_nft(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                video_id,
+                SECOND_MEMBER_ID,
+                price,
+            ),
+            Error::<Test>::InsufficientCreatorTokenBalance
+        );
+    })
+}
+
+#[test]
+fn english_auction_in_creator_token_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_priced_in_creator_token();
+        let auction_duration = Content::min_auction_duration();
+        assert_ok!(Content::start_english_auction(
+            Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(THIRD_MEMBER_ID),
+            video_id,
+            EnglishAuctionParams::<Test> {
+                starting_price: Content::min_starting_price(),
+                buy_now_price: None,
+                extension_period: Content::min_auction_extension_period(),
+                min_bid_step: Content::min_bid_step(),
+                starts_at: None,
+                duration: auction_duration,
+                whitelist: BTreeSet::new(),
+            },
+        ));
+
+        let first_bid = Content::min_starting_price();
+        assert_ok!(Content::make_english_auction_bid(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            DEFAULT_MEMBER_ID,
+            video_id,
+            first_bid,
+        ));
+        assert_eq!(escrowed_creator_token_balance(DEFAULT_MEMBER_ID), first_bid);
+
+        // Outbid: first bidder gets refunded
+        let second_bid = first_bid + Content::min_bid_step();
+        assert_ok!(Content::make_english_auction_bid(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            video_id,
+            second_bid,
+        ));
+        assert_eq!(
+            (
+                creator_token_balance(DEFAULT_MEMBER_ID),
+                escrowed_creator_token_balance(DEFAULT_MEMBER_ID),
+                escrowed_creator_token_balance(SECOND_MEMBER_ID),
+            ),
+            (CREATOR_TOKEN_ALLOCATION, 0, second_bid)
+        );
+
+        run_to_block(auction_duration + 2);
+        assert_ok!(Content::settle_english_auction(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            video_id,
+        ));
+
+        let royalty = Perbill::from_percent(DEFAULT_ROYALTY).mul_floor(second_bid);
+        assert_eq!(
+            (
+                creator_token_balance(SECOND_MEMBER_ID),
+                escrowed_creator_token_balance(SECOND_MEMBER_ID),
+                creator_token_balance(DEFAULT_MEMBER_ID),
+                creator_token_balance(THIRD_MEMBER_ID),
+            ),
+            (
+                CREATOR_TOKEN_ALLOCATION - second_bid,
+                0,
+                CREATOR_TOKEN_ALLOCATION + royalty,
+                CREATOR_TOKEN_ALLOCATION + second_bid - royalty,
+            )
+        );
+    })
+}
+
+#[test]
+fn open_auction_bid_in_creator_token_refunded_on_cancel() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_priced_in_creator_token();
+        assert_ok!(Content::start_open_auction(
+            Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(THIRD_MEMBER_ID),
+            video_id,
+            get_open_auction_params(),
+        ));
+
+        let bid = Content::min_starting_price();
+        assert_ok!(Content::make_open_auction_bid(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            video_id,
+            bid,
+        ));
+        assert_eq!(
+            (
+                creator_token_balance(SECOND_MEMBER_ID),
+                escrowed_creator_token_balance(SECOND_MEMBER_ID),
+            ),
+            (CREATOR_TOKEN_ALLOCATION - bid, bid)
+        );
+        assert!(
+            Content::open_auction_bid_by_video_and_member(video_id, SECOND_MEMBER_ID)
+                .in_creator_token
+        );
+
+        assert_ok!(Content::cancel_open_auction(
+            Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(THIRD_MEMBER_ID),
+            video_id,
+        ));
+        assert_ok!(Content::cancel_open_auction_bid(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            video_id,
+        ));
+
+        assert_eq!(
+            (
+                creator_token_balance(SECOND_MEMBER_ID),
+                escrowed_creator_token_balance(SECOND_MEMBER_ID),
+            ),
+            (CREATOR_TOKEN_ALLOCATION, 0)
+        );
+    })
+}
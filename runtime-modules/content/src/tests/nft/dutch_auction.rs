@@ -0,0 +1,454 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use std::iter::FromIterator;
+
+fn setup_nft_in_dutch_auction(auction_params: DutchAuctionParams<Test>) -> VideoId {
+    let video_id = NextVideoId::<Test>::get();
+
+    create_initial_storage_buckets_helper();
+    increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+    create_default_member_owned_channel_with_video();
+
+    // Issue nft
+    assert_ok!(Content::issue_nft(
+        Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(DEFAULT_MEMBER_ID),
+        video_id,
+        NftIssuanceParameters::<Test>::default(),
+    ));
+
+    // Start dutch auction
+    assert_ok!(Content::start_dutch_auction(
+        Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(DEFAULT_MEMBER_ID),
+        video_id,
+        auction_params,
+    ));
+
+    video_id
+}
+
+#[test]
+fn start_dutch_auction() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let auction_params = get_dutch_auction_params();
+        let video_id = setup_nft_in_dutch_auction(auction_params.clone());
+
+        // Runtime tested state after call
+
+        // Ensure nft status changed to dutch auction
+        assert!(matches!(
+            Content::video_by_id(video_id).nft_status,
+            Some(OwnedNft {
+                transactional_status: TransactionalStatus::<Test>::DutchAuction(..),
+                ..
+            })
+        ));
+
+        // Last event checked
+        last_event_eq!(RawEvent::DutchAuctionStarted(
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            video_id,
+            auction_params,
+        ));
+    })
+}
+
+#[test]
+fn start_dutch_auction_fails_with_floor_price_not_lower_than_starting_price() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = NextVideoId::<Test>::get();
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        assert_ok!(Content::issue_nft(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            video_id,
+            NftIssuanceParameters::<Test>::default(),
+        ));
+
+        assert_noop!(
+            Content::start_dutch_auction(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                video_id,
+                DutchAuctionParams::<Test> {
+                    floor_price: DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+                    ..get_dutch_auction_params()
+                },
+            ),
+            Error::<Test>::DutchAuctionFloorPriceMustBeLowerThanStartingPrice
+        );
+    })
+}
+
+#[test]
+fn start_dutch_auction_fails_with_floor_price_lower_bound_exceeded() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = NextVideoId::<Test>::get();
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        assert_ok!(Content::issue_nft(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            video_id,
+            NftIssuanceParameters::<Test>::default(),
+        ));
+
+        assert_noop!(
+            Content::start_dutch_auction(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                video_id,
+                DutchAuctionParams::<Test> {
+                    floor_price: Content::min_starting_price() - 1,
+                    ..get_dutch_auction_params()
+                },
+            ),
+            Error::<Test>::DutchAuctionFloorPriceLowerBoundExceeded
+        );
+    })
+}
+
+#[test]
+fn start_dutch_auction_fails_with_zero_price_decrease() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = NextVideoId::<Test>::get();
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        assert_ok!(Content::issue_nft(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            video_id,
+            NftIssuanceParameters::<Test>::default(),
+        ));
+
+        assert_noop!(
+            Content::start_dutch_auction(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                video_id,
+                DutchAuctionParams::<Test> {
+                    price_decrease_per_block: 0,
+                    ..get_dutch_auction_params()
+                },
+            ),
+            Error::<Test>::DutchAuctionPriceDecreaseIsZero
+        );
+    })
+}
+
+#[test]
+fn start_dutch_auction_fails_with_nft_not_idle() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_in_dutch_auction(get_dutch_auction_params());
+
+        assert_noop!(
+            Content::start_dutch_auction(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                video_id,
+                get_dutch_auction_params(),
+            ),
+            Error::<Test>::NftIsNotIdle
+        );
+    })
+}
+
+#[test]
+fn start_dutch_auction_fails_with_auth_failed() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = NextVideoId::<Test>::get();
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        assert_ok!(Content::issue_nft(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            video_id,
+            NftIssuanceParameters::<Test>::default(),
+        ));
+
+        assert_noop!(
+            Content::start_dutch_auction(
+                Origin::signed(UNAUTHORIZED_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                video_id,
+                get_dutch_auction_params(),
+            ),
+            Error::<Test>::MemberAuthFailed
+        );
+    })
+}
+
+#[test]
+fn cancel_dutch_auction() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_in_dutch_auction(get_dutch_auction_params());
+
+        assert_ok!(Content::cancel_dutch_auction(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            video_id,
+        ));
+
+        // Runtime tested state after call
+
+        // Ensure nft status changed to idle
+        assert!(matches!(
+            Content::video_by_id(video_id).nft_status,
+            Some(OwnedNft {
+                transactional_status: TransactionalStatus::<Test>::Idle,
+                ..
+            })
+        ));
+
+        // Last event checked
+        last_event_eq!(RawEvent::AuctionCanceled(
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            video_id,
+        ));
+    })
+}
+
+#[test]
+fn cancel_dutch_auction_fails_with_nft_not_in_dutch_auction() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = NextVideoId::<Test>::get();
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        assert_ok!(Content::issue_nft(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            video_id,
+            NftIssuanceParameters::<Test>::default(),
+        ));
+
+        assert_noop!(
+            Content::cancel_dutch_auction(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                video_id,
+            ),
+            Error::<Test>::IsNotDutchAuctionType
+        );
+    })
+}
+
+#[test]
+fn buy_nft_on_dutch_auction_at_decayed_price() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_in_dutch_auction(get_dutch_auction_params());
+        increase_account_balance_helper(
+            SECOND_MEMBER_ACCOUNT_ID,
+            ed() + DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+        );
+
+        run_to_block(5);
+        let expected_price =
+            DEFAULT_DUTCH_AUCTION_STARTING_PRICE - 4 * DEFAULT_DUTCH_AUCTION_PRICE_DECREASE;
+        let balance_pre = balances::Pallet::<Test>::usable_balance(SECOND_MEMBER_ACCOUNT_ID);
+
+        assert_ok!(Content::buy_nft_on_dutch_auction(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            video_id,
+            SECOND_MEMBER_ID,
+            DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+        ));
+
+        // Runtime tested state after call
+
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(SECOND_MEMBER_ACCOUNT_ID),
+            balance_pre - expected_price
+        );
+
+        // Ensure nft owner & status updated
+        assert!(matches!(
+            Content::video_by_id(video_id).nft_status,
+            Some(OwnedNft {
+                transactional_status: TransactionalStatus::<Test>::Idle,
+                owner: NftOwner::Member(SECOND_MEMBER_ID),
+                ..
+            })
+        ));
+
+        // Last event checked
+        last_event_eq!(RawEvent::NftBoughtOnDutchAuction(
+            video_id,
+            SECOND_MEMBER_ID,
+            expected_price,
+        ));
+    })
+}
+
+#[test]
+fn buy_nft_on_dutch_auction_price_does_not_decay_below_floor() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_in_dutch_auction(get_dutch_auction_params());
+        increase_account_balance_helper(
+            SECOND_MEMBER_ACCOUNT_ID,
+            ed() + DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+        );
+
+        run_to_block(100);
+
+        assert_ok!(Content::buy_nft_on_dutch_auction(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            video_id,
+            SECOND_MEMBER_ID,
+            DEFAULT_DUTCH_AUCTION_FLOOR_PRICE,
+        ));
+
+        last_event_eq!(RawEvent::NftBoughtOnDutchAuction(
+            video_id,
+            SECOND_MEMBER_ID,
+            DEFAULT_DUTCH_AUCTION_FLOOR_PRICE,
+        ));
+    })
+}
+
+#[test]
+fn buy_nft_on_dutch_auction_fails_with_price_above_max_price() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_in_dutch_auction(get_dutch_auction_params());
+        increase_account_balance_helper(
+            SECOND_MEMBER_ACCOUNT_ID,
+            ed() + DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+        );
+
+        assert_noop!(
+            Content::buy_nft_on_dutch_auction(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                video_id,
+                SECOND_MEMBER_ID,
+                DEFAULT_DUTCH_AUCTION_STARTING_PRICE - 1,
+            ),
+            Error::<Test>::DutchAuctionPriceExceedsMaxPrice
+        );
+    })
+}
+
+#[test]
+fn buy_nft_on_dutch_auction_fails_with_auction_not_started() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_in_dutch_auction(DutchAuctionParams::<Test> {
+            starts_at: Some(5),
+            ..get_dutch_auction_params()
+        });
+        increase_account_balance_helper(
+            SECOND_MEMBER_ACCOUNT_ID,
+            ed() + DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+        );
+
+        assert_noop!(
+            Content::buy_nft_on_dutch_auction(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                video_id,
+                SECOND_MEMBER_ID,
+                DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+            ),
+            Error::<Test>::AuctionDidNotStart
+        );
+    })
+}
+
+#[test]
+fn buy_nft_on_dutch_auction_fails_with_non_whitelisted_member() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_in_dutch_auction(DutchAuctionParams::<Test> {
+            whitelist: BTreeSet::from_iter(vec![COLLABORATOR_MEMBER_ID, THIRD_MEMBER_ID]),
+            ..get_dutch_auction_params()
+        });
+        increase_account_balance_helper(
+            SECOND_MEMBER_ACCOUNT_ID,
+            ed() + DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+        );
+
+        assert_noop!(
+            Content::buy_nft_on_dutch_auction(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                video_id,
+                SECOND_MEMBER_ID,
+                DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+            ),
+            Error::<Test>::MemberIsNotAllowedToParticipate
+        );
+    })
+}
+
+#[test]
+fn buy_nft_on_dutch_auction_fails_with_insufficient_balance() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let video_id = setup_nft_in_dutch_auction(get_dutch_auction_params());
+
+        assert_noop!(
+            Content::buy_nft_on_dutch_auction(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                video_id,
+                SECOND_MEMBER_ID,
+                DEFAULT_DUTCH_AUCTION_STARTING_PRICE,
+            ),
+            Error::<Test>::InsufficientBalance
+        );
+    })
+}
@@ -80,6 +80,35 @@ fn nft_is_issued_with_open_auction_status_successfully() {
     })
 }
 
+#[test]
+fn nft_is_issued_with_dutch_auction_status_successfully() {
+    with_default_mock_builder(|| {
+        let video_id = 1u64;
+        ContentTest::with_member_channel().with_video().setup();
+
+        // Issue nft
+        assert_ok!(Content::issue_nft(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            video_id,
+            NftIssuanceParameters::<Test> {
+                init_transactional_status: InitTransactionalStatus::<Test>::DutchAuction(
+                    get_dutch_auction_params()
+                ),
+                ..Default::default()
+            },
+        ));
+
+        assert!(matches!(
+            Content::video_by_id(video_id).nft_status,
+            Some(Nft::<Test> {
+                transactional_status: TransactionalStatusRecord::DutchAuction(..),
+                ..
+            }),
+        ));
+    })
+}
+
 #[test]
 fn issue_nft_video_does_not_exist() {
     with_default_mock_builder(|| {
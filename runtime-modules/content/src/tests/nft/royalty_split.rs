@@ -0,0 +1,260 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+const ROYALTY_SPLIT_ACCOUNT_ID: U256 = U256([1000, 0, 0, 0]);
+
+fn get_royalty_split() -> BTreeMap<RoyaltyRecipientOf<Test>, Perbill> {
+    BTreeMap::from_iter(vec![
+        (
+            RoyaltyRecipient::Member(THIRD_MEMBER_ID),
+            Perbill::from_percent(50),
+        ),
+        (
+            RoyaltyRecipient::Account(ROYALTY_SPLIT_ACCOUNT_ID),
+            Perbill::from_percent(20),
+        ),
+    ])
+}
+
+fn set_royalty_split(royalty_split: BTreeMap<RoyaltyRecipientOf<Test>, Perbill>) {
+    assert_ok!(Content::set_channel_royalty_split(
+        Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(DEFAULT_MEMBER_ID),
+        ChannelId::one(),
+        royalty_split,
+    ));
+}
+
+#[test]
+fn set_channel_royalty_split() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+
+        set_royalty_split(get_royalty_split());
+
+        assert_eq!(
+            Content::royalty_split_by_channel_id(ChannelId::one()).into_inner(),
+            get_royalty_split()
+        );
+        last_event_eq!(RawEvent::ChannelRoyaltySplitSet(
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            ChannelId::one(),
+            get_royalty_split(),
+        ));
+    })
+}
+
+#[test]
+fn set_channel_royalty_split_with_empty_split_removes_it() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+        set_royalty_split(get_royalty_split());
+
+        set_royalty_split(BTreeMap::new());
+
+        assert!(!RoyaltySplitByChannelId::<Test>::contains_key(
+            ChannelId::one()
+        ));
+    })
+}
+
+#[test]
+fn set_channel_royalty_split_fails_with_shares_exceeding_royalty() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+
+        assert_noop!(
+            Content::set_channel_royalty_split(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                BTreeMap::from_iter(vec![
+                    (
+                        RoyaltyRecipient::Member(THIRD_MEMBER_ID),
+                        Perbill::from_percent(80),
+                    ),
+                    (
+                        RoyaltyRecipient::Account(ROYALTY_SPLIT_ACCOUNT_ID),
+                        Perbill::from_percent(21),
+                    ),
+                ]),
+            ),
+            Error::<Test>::RoyaltySplitSharesExceedRoyalty
+        );
+    })
+}
+
+#[test]
+fn set_channel_royalty_split_fails_with_max_recipients_exceeded() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+        let max_recipients = <Test as Config>::MaxRoyaltySplitRecipients::get() as u64;
+
+        assert_noop!(
+            Content::set_channel_royalty_split(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                BTreeMap::from_iter((0..=max_recipients).map(|i| {
+                    (
+                        RoyaltyRecipient::Account(U256::from(1000 + i)),
+                        Perbill::from_percent(1),
+                    )
+                })),
+            ),
+            Error::<Test>::MaxRoyaltySplitRecipientsExceeded
+        );
+    })
+}
+
+#[test]
+fn set_channel_royalty_split_fails_with_invalid_member() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+
+        assert_noop!(
+            Content::set_channel_royalty_split(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                BTreeMap::from_iter(vec![(
+                    RoyaltyRecipient::Member(9999),
+                    Perbill::from_percent(50),
+                )]),
+            ),
+            Error::<Test>::InvalidMemberProvided
+        );
+    })
+}
+
+#[test]
+fn set_channel_royalty_split_fails_with_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManageRoyaltySplit])
+            .setup();
+
+        assert_noop!(
+            Content::set_channel_royalty_split(
+                Origin::signed(COLLABORATOR_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(COLLABORATOR_MEMBER_ID),
+                ChannelId::one(),
+                get_royalty_split(),
+            ),
+            Error::<Test>::ChannelAgentInsufficientPermissions
+        );
+    })
+}
+
+#[test]
+fn set_channel_royalty_split_by_collaborator_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageRoyaltySplit])
+            .setup();
+
+        assert_ok!(Content::set_channel_royalty_split(
+            Origin::signed(COLLABORATOR_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(COLLABORATOR_MEMBER_ID),
+            ChannelId::one(),
+            get_royalty_split(),
+        ));
+    })
+}
+
+#[test]
+fn buy_nft_ok_with_royalty_split() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        let royalty = Perbill::from_percent(DEFAULT_ROYALTY).mul_floor(DEFAULT_NFT_PRICE);
+        let platform_fee = Content::platform_fee_percentage().mul_floor(DEFAULT_NFT_PRICE);
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, ed() + DEFAULT_NFT_PRICE);
+        // royalty split recipients accounts must exist to receive small shares
+        increase_account_balance_helper(THIRD_MEMBER_ACCOUNT_ID, ed());
+        increase_account_balance_helper(ROYALTY_SPLIT_ACCOUNT_ID, ed());
+        ContentTest::default().with_video().setup();
+        set_royalty_split(get_royalty_split());
+        IssueNftFixture::default()
+            .with_params(NftIssuanceParameters::<Test> {
+                royalty: Some(Perbill::from_percent(DEFAULT_ROYALTY)),
+                non_channel_owner: Some(COLLABORATOR_MEMBER_ID),
+                init_transactional_status: InitTransactionalStatus::<Test>::BuyNow(
+                    DEFAULT_NFT_PRICE,
+                ),
+                ..Default::default()
+            })
+            .call_and_assert(Ok(()));
+
+        assert_ok!(Content::buy_nft(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            VideoId::one(),
+            SECOND_MEMBER_ID,
+            DEFAULT_NFT_PRICE,
+        ));
+
+        let third_member_share = Perbill::from_percent(50).mul_floor(royalty);
+        let account_share = Perbill::from_percent(20).mul_floor(royalty);
+        assert_eq!(
+            (
+                channel_reward_account_balance(ChannelId::one()),
+                balances::Pallet::<Test>::usable_balance(THIRD_MEMBER_ACCOUNT_ID),
+                balances::Pallet::<Test>::usable_balance(ROYALTY_SPLIT_ACCOUNT_ID),
+                balances::Pallet::<Test>::usable_balance(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            ),
+            (
+                DEFAULT_CHANNEL_STATE_BLOAT_BOND + royalty - third_member_share - account_share,
+                ed() + third_member_share,
+                ed() + account_share,
+                DEFAULT_NFT_PRICE - platform_fee - royalty,
+            )
+        );
+    })
+}
+
+#[test]
+fn royalty_split_removed_on_channel_deletion() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+        set_royalty_split(get_royalty_split());
+
+        DeleteChannelFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .call_and_assert(Ok(()));
+
+        assert!(!RoyaltySplitByChannelId::<Test>::contains_key(
+            ChannelId::one()
+        ));
+    })
+}
@@ -0,0 +1,564 @@
+#![cfg(test)]
+use super::curators;
+use super::fixtures::*;
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+type PlaylistId = <Test as Config>::PlaylistId;
+
+const PLAYLIST_STATE_BLOAT_BOND: u64 = 10;
+
+fn get_playlist_creation_params(videos: Vec<VideoId>) -> PlaylistCreationParameters<Test> {
+    PlaylistCreationParameters::<Test> {
+        videos,
+        meta: Some(b"playlist".to_vec()),
+        expected_playlist_state_bloat_bond: Content::playlist_state_bloat_bond_value(),
+    }
+}
+
+fn create_playlist(videos: Vec<VideoId>) -> PlaylistId {
+    let playlist_id = Content::next_playlist_id();
+    assert_ok!(Content::create_playlist(
+        Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(DEFAULT_MEMBER_ID),
+        ChannelId::one(),
+        get_playlist_creation_params(videos),
+    ));
+    playlist_id
+}
+
+fn setup_channel_with_videos(number_of_videos: u8) {
+    create_initial_storage_buckets_helper();
+    increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+    create_default_member_owned_channel_with_videos(number_of_videos, &[]);
+}
+
+fn set_playlist_state_bloat_bond(playlist_state_bloat_bond: u64) {
+    assert_ok!(Content::update_playlist_state_bloat_bond(
+        Origin::signed(LEAD_ACCOUNT_ID),
+        playlist_state_bloat_bond,
+    ));
+}
+
+#[test]
+fn create_playlist_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(3);
+
+        let playlist_id = create_playlist(vec![3, 1, 2]);
+
+        assert_eq!(
+            Content::playlist_by_id(playlist_id),
+            Playlist::<Test> {
+                in_channel: ChannelId::one(),
+                videos: vec![3, 1, 2].try_into().unwrap(),
+                playlist_state_bloat_bond: RepayableBloatBond::new(
+                    DEFAULT_PLAYLIST_STATE_BLOAT_BOND,
+                    None
+                ),
+            }
+        );
+        assert!(PlaylistsByChannelId::<Test>::contains_key(
+            ChannelId::one(),
+            playlist_id
+        ));
+        assert_eq!(Content::channel_by_id(ChannelId::one()).num_playlists, 1);
+        assert_eq!(Content::next_playlist_id(), playlist_id + 1);
+
+        last_event_eq!(RawEvent::PlaylistCreated(
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            playlist_id,
+            ChannelId::one(),
+            get_playlist_creation_params(vec![3, 1, 2]),
+        ));
+    })
+}
+
+#[test]
+fn create_playlist_pays_state_bloat_bond() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+        set_playlist_state_bloat_bond(PLAYLIST_STATE_BLOAT_BOND);
+        let module_account = ContentTreasury::<Test>::module_account_id();
+        let module_account_balance_pre = balances::Pallet::<Test>::usable_balance(module_account);
+
+        let playlist_id = create_playlist(vec![1]);
+
+        assert_eq!(
+            Content::playlist_by_id(playlist_id)
+                .playlist_state_bloat_bond
+                .amount,
+            PLAYLIST_STATE_BLOAT_BOND
+        );
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(module_account),
+            module_account_balance_pre + PLAYLIST_STATE_BLOAT_BOND
+        );
+    })
+}
+
+#[test]
+fn create_playlist_fails_with_state_bloat_bond_changed() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+        set_playlist_state_bloat_bond(PLAYLIST_STATE_BLOAT_BOND);
+
+        assert_noop!(
+            Content::create_playlist(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                PlaylistCreationParameters::<Test> {
+                    expected_playlist_state_bloat_bond: DEFAULT_PLAYLIST_STATE_BLOAT_BOND,
+                    ..get_playlist_creation_params(vec![1])
+                },
+            ),
+            Error::<Test>::PlaylistStateBloatBondChanged
+        );
+    })
+}
+
+#[test]
+fn create_playlist_fails_with_video_not_in_playlist_channel() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+        // second channel with a video
+        create_default_member_owned_channel_with_video();
+
+        assert_noop!(
+            Content::create_playlist(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                get_playlist_creation_params(vec![1, 2]),
+            ),
+            Error::<Test>::VideoNotInPlaylistChannel
+        );
+    })
+}
+
+#[test]
+fn create_playlist_fails_with_invalid_video_id() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+
+        assert_noop!(
+            Content::create_playlist(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                get_playlist_creation_params(vec![1, 2]),
+            ),
+            Error::<Test>::VideoDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn create_playlist_fails_with_duplicate_videos() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(2);
+
+        assert_noop!(
+            Content::create_playlist(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                get_playlist_creation_params(vec![1, 2, 1]),
+            ),
+            Error::<Test>::PlaylistContainsDuplicateVideos
+        );
+    })
+}
+
+#[test]
+fn create_playlist_fails_with_max_videos_per_playlist_exceeded() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+        let max_videos = <Test as Config>::MaxVideosPerPlaylist::get() as u64;
+
+        assert_noop!(
+            Content::create_playlist(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                get_playlist_creation_params((1..=max_videos + 1).collect()),
+            ),
+            Error::<Test>::MaxVideosPerPlaylistExceeded
+        );
+    })
+}
+
+#[test]
+fn create_playlist_fails_with_max_playlists_per_channel_exceeded() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+        for _ in 0..<Test as Config>::MaxPlaylistsPerChannel::get() {
+            create_playlist(vec![1]);
+        }
+
+        assert_noop!(
+            Content::create_playlist(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                get_playlist_creation_params(vec![1]),
+            ),
+            Error::<Test>::MaxPlaylistsPerChannelExceeded
+        );
+    })
+}
+
+#[test]
+fn create_playlist_fails_with_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManagePlaylists])
+            .setup();
+
+        assert_noop!(
+            Content::create_playlist(
+                Origin::signed(COLLABORATOR_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(COLLABORATOR_MEMBER_ID),
+                ChannelId::one(),
+                get_playlist_creation_params(vec![]),
+            ),
+            Error::<Test>::ChannelAgentInsufficientPermissions
+        );
+    })
+}
+
+#[test]
+fn create_playlist_by_collaborator_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManagePlaylists])
+            .setup();
+        increase_account_balance_helper(COLLABORATOR_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        assert_ok!(Content::create_playlist(
+            Origin::signed(COLLABORATOR_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(COLLABORATOR_MEMBER_ID),
+            ChannelId::one(),
+            get_playlist_creation_params(vec![]),
+        ));
+    })
+}
+
+#[test]
+fn update_playlist_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(3);
+        let playlist_id = create_playlist(vec![1, 2]);
+        let params = PlaylistUpdateParameters::<Test> {
+            new_videos: Some(vec![2, 3, 1]),
+            new_meta: Some(b"new playlist".to_vec()),
+        };
+
+        assert_ok!(Content::update_playlist(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            playlist_id,
+            params.clone(),
+        ));
+
+        assert_eq!(
+            Content::playlist_by_id(playlist_id).videos.into_inner(),
+            vec![2, 3, 1]
+        );
+        last_event_eq!(RawEvent::PlaylistUpdated(
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            playlist_id,
+            params,
+        ));
+    })
+}
+
+#[test]
+fn update_playlist_fails_with_invalid_playlist_id() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+        let playlist_id = create_playlist(vec![1]);
+
+        assert_noop!(
+            Content::update_playlist(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                playlist_id + 1,
+                PlaylistUpdateParameters::<Test>::default(),
+            ),
+            Error::<Test>::PlaylistDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn update_playlist_fails_with_unauthorized_actor() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+        let playlist_id = create_playlist(vec![1]);
+
+        assert_noop!(
+            Content::update_playlist(
+                Origin::signed(UNAUTHORIZED_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(UNAUTHORIZED_MEMBER_ID),
+                playlist_id,
+                PlaylistUpdateParameters::<Test>::default(),
+            ),
+            Error::<Test>::ActorNotAuthorized
+        );
+    })
+}
+
+#[test]
+fn delete_playlist_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+        set_playlist_state_bloat_bond(PLAYLIST_STATE_BLOAT_BOND);
+        let sender_balance_pre =
+            balances::Pallet::<Test>::usable_balance(DEFAULT_MEMBER_ACCOUNT_ID);
+        let playlist_id = create_playlist(vec![1]);
+
+        assert_ok!(Content::delete_playlist(
+            Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            playlist_id,
+        ));
+
+        assert!(!PlaylistById::<Test>::contains_key(playlist_id));
+        assert!(!PlaylistsByChannelId::<Test>::contains_key(
+            ChannelId::one(),
+            playlist_id
+        ));
+        assert_eq!(Content::channel_by_id(ChannelId::one()).num_playlists, 0);
+        // bloat bond repaid
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(DEFAULT_MEMBER_ACCOUNT_ID),
+            sender_balance_pre
+        );
+        last_event_eq!(RawEvent::PlaylistDeleted(
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            playlist_id,
+        ));
+    })
+}
+
+#[test]
+fn delete_playlist_fails_with_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManagePlaylists])
+            .setup();
+        let playlist_id = create_playlist(vec![]);
+
+        assert_noop!(
+            Content::delete_playlist(
+                Origin::signed(COLLABORATOR_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(COLLABORATOR_MEMBER_ID),
+                playlist_id,
+            ),
+            Error::<Test>::ChannelAgentInsufficientPermissions
+        );
+    })
+}
+
+#[test]
+fn delete_playlist_as_moderator_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+        set_playlist_state_bloat_bond(PLAYLIST_STATE_BLOAT_BOND);
+        let module_account = ContentTreasury::<Test>::module_account_id();
+        let module_account_balance_pre = balances::Pallet::<Test>::usable_balance(module_account);
+        let playlist_id = create_playlist(vec![1]);
+        let curator_group_id = curators::add_curator_to_new_group_with_permissions(
+            DEFAULT_CURATOR_ID,
+            BTreeMap::from_iter(vec![(
+                0,
+                BTreeSet::from_iter(vec![ContentModerationAction::DeletePlaylist]),
+            )]),
+        );
+        let rationale = b"rationale".to_vec();
+
+        assert_ok!(Content::delete_playlist_as_moderator(
+            Origin::signed(DEFAULT_CURATOR_ACCOUNT_ID),
+            ContentActor::Curator(curator_group_id, DEFAULT_CURATOR_ID),
+            playlist_id,
+            rationale.clone(),
+        ));
+
+        assert!(!PlaylistById::<Test>::contains_key(playlist_id));
+        assert_eq!(Content::channel_by_id(ChannelId::one()).num_playlists, 0);
+        // bloat bond slashed
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(module_account),
+            ed()
+        );
+        last_event_eq!(RawEvent::PlaylistDeletedByModerator(
+            ContentActor::Curator(curator_group_id, DEFAULT_CURATOR_ID),
+            playlist_id,
+            rationale,
+        ));
+    })
+}
+
+#[test]
+fn delete_playlist_as_moderator_fails_without_moderation_permission() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(1);
+        let playlist_id = create_playlist(vec![1]);
+        let curator_group_id = curators::add_curator_to_new_group_with_permissions(
+            DEFAULT_CURATOR_ID,
+            BTreeMap::from_iter(vec![(
+                0,
+                BTreeSet::from_iter(vec![ContentModerationAction::DeleteVideo]),
+            )]),
+        );
+
+        assert_noop!(
+            Content::delete_playlist_as_moderator(
+                Origin::signed(DEFAULT_CURATOR_ACCOUNT_ID),
+                ContentActor::Curator(curator_group_id, DEFAULT_CURATOR_ID),
+                playlist_id,
+                b"rationale".to_vec(),
+            ),
+            Error::<Test>::CuratorModerationActionNotAllowed
+        );
+    })
+}
+
+#[test]
+fn video_deletion_removes_video_from_playlists() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_videos(2);
+        let first_playlist_id = create_playlist(vec![2, 1]);
+        let second_playlist_id = create_playlist(vec![2]);
+
+        DeleteVideoFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(
+            Content::playlist_by_id(first_playlist_id)
+                .videos
+                .into_inner(),
+            vec![2]
+        );
+        assert_eq!(
+            Content::playlist_by_id(second_playlist_id)
+                .videos
+                .into_inner(),
+            vec![2]
+        );
+    })
+}
+
+#[test]
+fn channel_deletion_removes_playlists() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+        let playlist_id = create_playlist(vec![]);
+
+        DeleteChannelFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .call_and_assert(Ok(()));
+
+        assert!(!PlaylistById::<Test>::contains_key(playlist_id));
+        assert!(!PlaylistsByChannelId::<Test>::contains_key(
+            ChannelId::one(),
+            playlist_id
+        ));
+    })
+}
+
+#[test]
+fn update_playlist_state_bloat_bond_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        set_playlist_state_bloat_bond(PLAYLIST_STATE_BLOAT_BOND);
+
+        assert_eq!(
+            Content::playlist_state_bloat_bond_value(),
+            PLAYLIST_STATE_BLOAT_BOND
+        );
+        last_event_eq!(RawEvent::PlaylistStateBloatBondValueUpdated(
+            PLAYLIST_STATE_BLOAT_BOND
+        ));
+    })
+}
+
+#[test]
+fn update_playlist_state_bloat_bond_fails_with_unauthorized_sender() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        assert_noop!(
+            Content::update_playlist_state_bloat_bond(
+                Origin::signed(UNAUTHORIZED_LEAD_ACCOUNT_ID),
+                PLAYLIST_STATE_BLOAT_BOND,
+            ),
+            Error::<Test>::LeadAuthFailed
+        );
+    })
+}
@@ -0,0 +1,867 @@
+#![cfg(test)]
+use super::fixtures::*;
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use project_token::types::TokenAllocationOf;
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+const SUBSCRIPTION_PRICE: u64 = 10;
+const SUBSCRIPTION_CREATOR_TOKEN_PRICE: u64 = 100;
+const SUBSCRIPTION_PERIOD: u64 = 10;
+const SUBSCRIPTION_STATE_BLOAT_BOND: u64 = 10;
+
+fn get_tier(creator_token_price: Option<u64>) -> SubscriptionTier<Test> {
+    SubscriptionTier::<Test> {
+        price: SUBSCRIPTION_PRICE,
+        creator_token_price,
+        period: SUBSCRIPTION_PERIOD,
+    }
+}
+
+fn set_tiers(tiers: Vec<SubscriptionTier<Test>>) {
+    assert_ok!(Content::set_channel_subscription_tiers(
+        Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(DEFAULT_MEMBER_ID),
+        ChannelId::one(),
+        tiers,
+    ));
+}
+
+fn subscribe(pay_in_creator_token: bool, max_payments: u32) -> u64 {
+    let subscription_id = Content::next_subscription_id();
+    assert_ok!(Content::subscribe_to_channel(
+        Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+        SECOND_MEMBER_ID,
+        ChannelId::one(),
+        0,
+        pay_in_creator_token,
+        max_payments,
+        Content::subscription_state_bloat_bond_value(),
+    ));
+    subscription_id
+}
+
+fn set_subscription_state_bloat_bond(subscription_state_bloat_bond: u64) {
+    assert_ok!(Content::update_subscription_state_bloat_bond(
+        Origin::signed(LEAD_ACCOUNT_ID),
+        subscription_state_bloat_bond,
+    ));
+}
+
+fn scheduled_payments(block: u64) -> Vec<u64> {
+    ScheduledSubscriptionPaymentsByBlock::<Test>::iter_prefix(block)
+        .map(|(subscription_id, _)| subscription_id)
+        .collect()
+}
+
+fn setup_channel_with_subscription_tier() {
+    ContentTest::with_member_channel().setup();
+    set_tiers(vec![get_tier(None)]);
+    increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+}
+
+fn setup_channel_with_creator_token_subscription_tier() {
+    ContentTest::with_member_channel().setup();
+    IssueCreatorTokenFixture::default()
+        .with_initial_allocation(BTreeMap::from_iter(
+            [DEFAULT_MEMBER_ID, SECOND_MEMBER_ID]
+                .iter()
+                .map(|member_id| {
+                    (
+                        *member_id,
+                        TokenAllocationOf::<Test> {
+                            amount: DEFAULT_CREATOR_TOKEN_ISSUANCE,
+                            vesting_schedule_params: None,
+                        },
+                    )
+                }),
+        ))
+        .call_and_assert(Ok(()));
+    set_tiers(vec![get_tier(Some(SUBSCRIPTION_CREATOR_TOKEN_PRICE))]);
+}
+
+fn creator_token_balance(member_id: MemberId) -> u64 {
+    let token_id = Content::channel_by_id(ChannelId::one())
+        .creator_token_id
+        .unwrap();
+    project_token::Module::<Test>::account_info_by_token_and_member(token_id, member_id).amount
+}
+
+#[test]
+fn set_channel_subscription_tiers_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+
+        set_tiers(vec![get_tier(None)]);
+
+        assert_eq!(
+            Content::subscription_tiers_by_channel_id(ChannelId::one()).into_inner(),
+            vec![get_tier(None)]
+        );
+        last_event_eq!(RawEvent::ChannelSubscriptionTiersSet(
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            ChannelId::one(),
+            vec![get_tier(None)],
+        ));
+    })
+}
+
+#[test]
+fn set_channel_subscription_tiers_with_empty_tiers_removes_them() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+        set_tiers(vec![get_tier(None)]);
+
+        set_tiers(vec![]);
+
+        assert!(!SubscriptionTiersByChannelId::<Test>::contains_key(
+            ChannelId::one()
+        ));
+    })
+}
+
+#[test]
+fn set_channel_subscription_tiers_fails_with_period_too_short() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+
+        assert_noop!(
+            Content::set_channel_subscription_tiers(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                vec![SubscriptionTier::<Test> {
+                    period: <Test as Config>::MinSubscriptionPeriod::get() - 1,
+                    ..get_tier(None)
+                }],
+            ),
+            Error::<Test>::SubscriptionPeriodTooShort
+        );
+    })
+}
+
+#[test]
+fn set_channel_subscription_tiers_fails_with_price_too_low() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+
+        assert_noop!(
+            Content::set_channel_subscription_tiers(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                vec![SubscriptionTier::<Test> {
+                    price: <Test as Config>::MinSubscriptionPrice::get() - 1,
+                    ..get_tier(None)
+                }],
+            ),
+            Error::<Test>::SubscriptionPriceTooLow
+        );
+    })
+}
+
+#[test]
+fn set_channel_subscription_tiers_fails_with_zero_creator_token_price() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_creator_token_subscription_tier();
+
+        assert_noop!(
+            Content::set_channel_subscription_tiers(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                vec![get_tier(Some(0))],
+            ),
+            Error::<Test>::SubscriptionPriceTooLow
+        );
+    })
+}
+
+#[test]
+fn set_channel_subscription_tiers_fails_with_max_tiers_exceeded() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+        let max_tiers = <Test as Config>::MaxSubscriptionTiersPerChannel::get() as usize;
+
+        assert_noop!(
+            Content::set_channel_subscription_tiers(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                vec![get_tier(None); max_tiers + 1],
+            ),
+            Error::<Test>::MaxSubscriptionTiersPerChannelExceeded
+        );
+    })
+}
+
+#[test]
+fn set_channel_subscription_tiers_fails_with_creator_token_price_and_no_token_issued() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+
+        assert_noop!(
+            Content::set_channel_subscription_tiers(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                ChannelId::one(),
+                vec![get_tier(Some(SUBSCRIPTION_CREATOR_TOKEN_PRICE))],
+            ),
+            Error::<Test>::CreatorTokenNotIssued
+        );
+    })
+}
+
+#[test]
+fn set_channel_subscription_tiers_fails_with_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManageSubscriptionTiers])
+            .setup();
+
+        assert_noop!(
+            Content::set_channel_subscription_tiers(
+                Origin::signed(COLLABORATOR_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(COLLABORATOR_MEMBER_ID),
+                ChannelId::one(),
+                vec![get_tier(None)],
+            ),
+            Error::<Test>::ChannelAgentInsufficientPermissions
+        );
+    })
+}
+
+#[test]
+fn subscribe_to_channel_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        let channel_balance_pre = channel_reward_account_balance(ChannelId::one());
+
+        let subscription_id = subscribe(false, 3);
+
+        let subscription = Subscription::<Test> {
+            channel_id: ChannelId::one(),
+            member_id: SECOND_MEMBER_ID,
+            payer: SECOND_MEMBER_ACCOUNT_ID,
+            payment: SubscriptionPayment::<Test>::Joy(SUBSCRIPTION_PRICE),
+            period: SUBSCRIPTION_PERIOD,
+            remaining_payments: 2,
+            next_payment_at: 1 + SUBSCRIPTION_PERIOD,
+            subscription_state_bloat_bond: RepayableBloatBond::new(
+                DEFAULT_SUBSCRIPTION_STATE_BLOAT_BOND,
+                None,
+            ),
+        };
+        assert_eq!(Content::subscription_by_id(subscription_id), subscription);
+        assert_eq!(
+            scheduled_payments(1 + SUBSCRIPTION_PERIOD),
+            vec![subscription_id]
+        );
+        assert_eq!(
+            channel_reward_account_balance(ChannelId::one()),
+            channel_balance_pre + SUBSCRIPTION_PRICE
+        );
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(SECOND_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE - SUBSCRIPTION_PRICE
+        );
+        last_event_eq!(RawEvent::ChannelSubscribed(
+            SECOND_MEMBER_ID,
+            subscription_id,
+            subscription,
+        ));
+    })
+}
+
+#[test]
+fn subscription_payments_are_pulled_on_initialize() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        let channel_balance_pre = channel_reward_account_balance(ChannelId::one());
+        let subscription_id = subscribe(false, 3);
+
+        run_to_block(1 + SUBSCRIPTION_PERIOD);
+
+        let subscription = Content::subscription_by_id(subscription_id);
+        assert_eq!(subscription.remaining_payments, 1);
+        assert_eq!(subscription.next_payment_at, 1 + 2 * SUBSCRIPTION_PERIOD);
+        assert!(scheduled_payments(1 + SUBSCRIPTION_PERIOD).is_empty());
+        assert_eq!(
+            scheduled_payments(1 + 2 * SUBSCRIPTION_PERIOD),
+            vec![subscription_id]
+        );
+        assert_eq!(
+            channel_reward_account_balance(ChannelId::one()),
+            channel_balance_pre + 2 * SUBSCRIPTION_PRICE
+        );
+        last_event_eq!(RawEvent::SubscriptionPaymentPulled(
+            subscription_id,
+            SubscriptionPayment::<Test>::Joy(SUBSCRIPTION_PRICE),
+        ));
+    })
+}
+
+#[test]
+fn subscription_lapses_when_payments_cap_reached() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        let subscription_id = subscribe(false, 2);
+
+        run_to_block(1 + 2 * SUBSCRIPTION_PERIOD);
+
+        assert!(!SubscriptionById::<Test>::contains_key(subscription_id));
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(SECOND_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE - 2 * SUBSCRIPTION_PRICE
+        );
+        last_event_eq!(RawEvent::SubscriptionLapsed(
+            subscription_id,
+            SubscriptionLapseReason::PaymentsCapReached,
+        ));
+    })
+}
+
+#[test]
+fn subscription_lapses_when_payment_fails() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+        set_tiers(vec![get_tier(None)]);
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, ed() + SUBSCRIPTION_PRICE);
+        let subscription_id = subscribe(false, 3);
+
+        run_to_block(1 + SUBSCRIPTION_PERIOD);
+
+        assert!(!SubscriptionById::<Test>::contains_key(subscription_id));
+        last_event_eq!(RawEvent::SubscriptionLapsed(
+            subscription_id,
+            SubscriptionLapseReason::PaymentFailed,
+        ));
+    })
+}
+
+#[test]
+fn subscription_lapses_on_channel_deletion() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        let subscription_id = subscribe(false, 3);
+
+        DeleteChannelFixture::default()
+            .with_sender(DEFAULT_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(DEFAULT_MEMBER_ID))
+            .call_and_assert(Ok(()));
+        assert!(!SubscriptionTiersByChannelId::<Test>::contains_key(
+            ChannelId::one()
+        ));
+
+        run_to_block(1 + SUBSCRIPTION_PERIOD);
+
+        assert!(!SubscriptionById::<Test>::contains_key(subscription_id));
+        last_event_eq!(RawEvent::SubscriptionLapsed(
+            subscription_id,
+            SubscriptionLapseReason::ChannelDeleted,
+        ));
+    })
+}
+
+#[test]
+fn subscription_payments_exceeding_block_capacity_are_pulled_in_next_block() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        let max_payments_per_block = <Test as Config>::MaxSubscriptionPaymentsPerBlock::get();
+        for _ in 0..max_payments_per_block {
+            subscribe(false, 3);
+        }
+        let subscription_id = subscribe(false, 3);
+
+        run_to_block(1 + SUBSCRIPTION_PERIOD);
+
+        assert_eq!(
+            scheduled_payments(1 + SUBSCRIPTION_PERIOD),
+            vec![subscription_id]
+        );
+        assert_eq!(
+            Content::subscription_by_id(subscription_id).remaining_payments,
+            2
+        );
+
+        run_to_block(2 + SUBSCRIPTION_PERIOD);
+
+        // pulled one block late: the next payment is due a full period later
+        let subscription = Content::subscription_by_id(subscription_id);
+        assert_eq!(subscription.remaining_payments, 1);
+        assert_eq!(subscription.next_payment_at, 2 + 2 * SUBSCRIPTION_PERIOD);
+        assert!(scheduled_payments(1 + SUBSCRIPTION_PERIOD).is_empty());
+        assert_eq!(
+            Content::subscription_payments_queue_head(),
+            Some(3 + SUBSCRIPTION_PERIOD)
+        );
+    })
+}
+
+#[test]
+fn subscription_payment_pulled_after_backlog_is_scheduled_after_current_block() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        let subscription_id = subscribe(false, 3);
+
+        // payments queue falling behind by more than a subscription period
+        SubscriptionPaymentsQueueHead::<Test>::put(1 + SUBSCRIPTION_PERIOD);
+        System::set_block_number(3 * SUBSCRIPTION_PERIOD);
+        run_to_block(1 + 3 * SUBSCRIPTION_PERIOD);
+
+        let subscription = Content::subscription_by_id(subscription_id);
+        assert_eq!(subscription.remaining_payments, 1);
+        assert_eq!(subscription.next_payment_at, 1 + 4 * SUBSCRIPTION_PERIOD);
+        assert_eq!(
+            scheduled_payments(1 + 4 * SUBSCRIPTION_PERIOD),
+            vec![subscription_id]
+        );
+    })
+}
+
+#[test]
+fn subscribe_to_channel_fails_with_invalid_tier() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+
+        assert_noop!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                ChannelId::one(),
+                1,
+                false,
+                3,
+                Content::subscription_state_bloat_bond_value(),
+            ),
+            Error::<Test>::SubscriptionTierDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn subscribe_to_channel_fails_with_zero_payments_cap() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+
+        assert_noop!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                ChannelId::one(),
+                0,
+                false,
+                0,
+                Content::subscription_state_bloat_bond_value(),
+            ),
+            Error::<Test>::SubscriptionPaymentsCapIsZero
+        );
+    })
+}
+
+#[test]
+fn subscribe_to_channel_fails_with_tier_not_priced_in_creator_token() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+
+        assert_noop!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                ChannelId::one(),
+                0,
+                true,
+                3,
+                Content::subscription_state_bloat_bond_value(),
+            ),
+            Error::<Test>::SubscriptionTierNotPricedInCreatorToken
+        );
+    })
+}
+
+#[test]
+fn subscribe_to_channel_fails_with_insufficient_balance() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+        set_tiers(vec![get_tier(None)]);
+
+        assert_noop!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                ChannelId::one(),
+                0,
+                false,
+                3,
+                Content::subscription_state_bloat_bond_value(),
+            ),
+            Error::<Test>::InsufficientBalanceForSubscriptionPayment
+        );
+    })
+}
+
+#[test]
+fn subscribe_to_channel_fails_with_member_auth_failed() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+
+        assert_noop!(
+            Content::subscribe_to_channel(
+                Origin::signed(UNAUTHORIZED_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                ChannelId::one(),
+                0,
+                false,
+                3,
+                Content::subscription_state_bloat_bond_value(),
+            ),
+            Error::<Test>::MemberAuthFailed
+        );
+    })
+}
+
+#[test]
+fn subscribe_to_channel_with_creator_token_payment_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_creator_token_subscription_tier();
+
+        let subscription_id = subscribe(true, 3);
+        run_to_block(1 + SUBSCRIPTION_PERIOD);
+
+        assert_eq!(
+            (
+                creator_token_balance(DEFAULT_MEMBER_ID),
+                creator_token_balance(SECOND_MEMBER_ID)
+            ),
+            (
+                DEFAULT_CREATOR_TOKEN_ISSUANCE + 2 * SUBSCRIPTION_CREATOR_TOKEN_PRICE,
+                DEFAULT_CREATOR_TOKEN_ISSUANCE - 2 * SUBSCRIPTION_CREATOR_TOKEN_PRICE
+            )
+        );
+        last_event_eq!(RawEvent::SubscriptionPaymentPulled(
+            subscription_id,
+            SubscriptionPayment::<Test>::CreatorToken(SUBSCRIPTION_CREATOR_TOKEN_PRICE),
+        ));
+    })
+}
+
+#[test]
+fn subscribe_to_channel_fails_with_insufficient_creator_token_balance() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_creator_token_subscription_tier();
+
+        assert_noop!(
+            Content::subscribe_to_channel(
+                Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+                THIRD_MEMBER_ID,
+                ChannelId::one(),
+                0,
+                true,
+                3,
+                Content::subscription_state_bloat_bond_value(),
+            ),
+            Error::<Test>::InsufficientCreatorTokenBalance
+        );
+    })
+}
+
+#[test]
+fn unsubscribe_from_channel_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        let subscription_id = subscribe(false, 3);
+
+        assert_ok!(Content::unsubscribe_from_channel(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            subscription_id,
+        ));
+
+        assert!(!SubscriptionById::<Test>::contains_key(subscription_id));
+        assert!(scheduled_payments(1 + SUBSCRIPTION_PERIOD).is_empty());
+        last_event_eq!(RawEvent::ChannelUnsubscribed(
+            SECOND_MEMBER_ID,
+            subscription_id
+        ));
+    })
+}
+
+#[test]
+fn subscribe_to_channel_pays_state_bloat_bond() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        set_subscription_state_bloat_bond(SUBSCRIPTION_STATE_BLOAT_BOND);
+        let module_account = ContentTreasury::<Test>::module_account_id();
+        let module_account_balance_pre = balances::Pallet::<Test>::usable_balance(module_account);
+
+        let subscription_id = subscribe(false, 3);
+
+        assert_eq!(
+            Content::subscription_by_id(subscription_id)
+                .subscription_state_bloat_bond
+                .amount,
+            SUBSCRIPTION_STATE_BLOAT_BOND
+        );
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(module_account),
+            module_account_balance_pre + SUBSCRIPTION_STATE_BLOAT_BOND
+        );
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(SECOND_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE - SUBSCRIPTION_PRICE - SUBSCRIPTION_STATE_BLOAT_BOND
+        );
+    })
+}
+
+#[test]
+fn subscribe_to_channel_fails_with_state_bloat_bond_changed() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        set_subscription_state_bloat_bond(SUBSCRIPTION_STATE_BLOAT_BOND);
+
+        assert_noop!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                ChannelId::one(),
+                0,
+                false,
+                3,
+                SUBSCRIPTION_STATE_BLOAT_BOND - 1,
+            ),
+            Error::<Test>::SubscriptionStateBloatBondChanged
+        );
+    })
+}
+
+#[test]
+fn subscribe_to_channel_fails_with_insufficient_balance_for_state_bloat_bond() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().setup();
+        set_tiers(vec![get_tier(None)]);
+        set_subscription_state_bloat_bond(SUBSCRIPTION_STATE_BLOAT_BOND);
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, ed() + SUBSCRIPTION_PRICE);
+
+        assert_noop!(
+            Content::subscribe_to_channel(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                ChannelId::one(),
+                0,
+                false,
+                3,
+                SUBSCRIPTION_STATE_BLOAT_BOND,
+            ),
+            Error::<Test>::InsufficientBalanceForSubscription
+        );
+    })
+}
+
+#[test]
+fn unsubscribe_from_channel_repays_state_bloat_bond() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        set_subscription_state_bloat_bond(SUBSCRIPTION_STATE_BLOAT_BOND);
+        let module_account = ContentTreasury::<Test>::module_account_id();
+        let module_account_balance_pre = balances::Pallet::<Test>::usable_balance(module_account);
+        let subscription_id = subscribe(false, 3);
+
+        assert_ok!(Content::unsubscribe_from_channel(
+            Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+            SECOND_MEMBER_ID,
+            subscription_id,
+        ));
+
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(module_account),
+            module_account_balance_pre
+        );
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(SECOND_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE - SUBSCRIPTION_PRICE
+        );
+    })
+}
+
+#[test]
+fn subscription_lapse_repays_state_bloat_bond() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        set_subscription_state_bloat_bond(SUBSCRIPTION_STATE_BLOAT_BOND);
+        let module_account = ContentTreasury::<Test>::module_account_id();
+        let module_account_balance_pre = balances::Pallet::<Test>::usable_balance(module_account);
+        let subscription_id = subscribe(false, 2);
+
+        run_to_block(1 + 2 * SUBSCRIPTION_PERIOD);
+
+        assert!(!SubscriptionById::<Test>::contains_key(subscription_id));
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(module_account),
+            module_account_balance_pre
+        );
+        assert_eq!(
+            balances::Pallet::<Test>::usable_balance(SECOND_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE - 2 * SUBSCRIPTION_PRICE
+        );
+    })
+}
+
+#[test]
+fn unsubscribe_from_channel_fails_with_subscription_not_owned_by_member() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        let subscription_id = subscribe(false, 3);
+
+        assert_noop!(
+            Content::unsubscribe_from_channel(
+                Origin::signed(THIRD_MEMBER_ACCOUNT_ID),
+                THIRD_MEMBER_ID,
+                subscription_id,
+            ),
+            Error::<Test>::SubscriptionNotOwnedByMember
+        );
+    })
+}
+
+#[test]
+fn unsubscribe_from_channel_fails_with_invalid_subscription_id() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        setup_channel_with_subscription_tier();
+        let subscription_id = subscribe(false, 3);
+
+        assert_noop!(
+            Content::unsubscribe_from_channel(
+                Origin::signed(SECOND_MEMBER_ACCOUNT_ID),
+                SECOND_MEMBER_ID,
+                subscription_id + 1,
+            ),
+            Error::<Test>::SubscriptionDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn update_subscription_state_bloat_bond_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        set_subscription_state_bloat_bond(SUBSCRIPTION_STATE_BLOAT_BOND);
+
+        assert_eq!(
+            Content::subscription_state_bloat_bond_value(),
+            SUBSCRIPTION_STATE_BLOAT_BOND
+        );
+        last_event_eq!(RawEvent::SubscriptionStateBloatBondValueUpdated(
+            SUBSCRIPTION_STATE_BLOAT_BOND
+        ));
+    })
+}
+
+#[test]
+fn update_subscription_state_bloat_bond_fails_with_unauthorized_sender() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        assert_noop!(
+            Content::update_subscription_state_bloat_bond(
+                Origin::signed(UNAUTHORIZED_LEAD_ACCOUNT_ID),
+                SUBSCRIPTION_STATE_BLOAT_BOND,
+            ),
+            Error::<Test>::LeadAuthFailed
+        );
+    })
+}
@@ -0,0 +1,247 @@
+#![cfg(test)]
+use super::fixtures::*;
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+
+fn get_schedule(
+    publish_at: Option<u64>,
+    unpublish_at: Option<u64>,
+) -> VideoVisibilityScheduleOf<Test> {
+    VideoVisibilityScheduleOf::<Test> {
+        publish_at,
+        unpublish_at,
+    }
+}
+
+fn set_schedule(video_id: VideoId, schedule: VideoVisibilityScheduleOf<Test>) {
+    assert_ok!(Content::set_video_visibility_schedule(
+        Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        ContentActor::Member(DEFAULT_MEMBER_ID),
+        video_id,
+        schedule,
+    ));
+}
+
+fn scheduled_changes(block: u64) -> Vec<VideoId> {
+    Content::scheduled_video_visibility_changes_by_block(block).into_inner()
+}
+
+#[test]
+fn set_video_visibility_schedule_ok() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().with_video().setup();
+
+        set_schedule(VideoId::one(), get_schedule(Some(5), Some(10)));
+
+        assert_eq!(
+            Content::video_visibility_schedule_by_id(VideoId::one()),
+            get_schedule(Some(5), Some(10))
+        );
+        assert_eq!(scheduled_changes(5), vec![VideoId::one()]);
+        assert_eq!(scheduled_changes(10), vec![VideoId::one()]);
+        last_event_eq!(RawEvent::VideoVisibilityScheduleSet(
+            ContentActor::Member(DEFAULT_MEMBER_ID),
+            VideoId::one(),
+            get_schedule(Some(5), Some(10)),
+        ));
+    })
+}
+
+#[test]
+fn scheduled_video_visibility_changes_are_applied_on_initialize() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().with_video().setup();
+        set_schedule(VideoId::one(), get_schedule(Some(5), Some(10)));
+
+        run_to_block(5);
+
+        last_event_eq!(RawEvent::VideoVisibilitySetBySchedule(
+            VideoId::one(),
+            false
+        ));
+        assert_eq!(
+            Content::video_visibility_schedule_by_id(VideoId::one()),
+            get_schedule(None, Some(10))
+        );
+        assert!(!ScheduledVideoVisibilityChangesByBlock::<Test>::contains_key(5));
+
+        run_to_block(10);
+
+        last_event_eq!(RawEvent::VideoVisibilitySetBySchedule(VideoId::one(), true));
+        assert!(!VideoVisibilityScheduleById::<Test>::contains_key(
+            VideoId::one()
+        ));
+        assert!(!ScheduledVideoVisibilityChangesByBlock::<Test>::contains_key(10));
+    })
+}
+
+#[test]
+fn set_video_visibility_schedule_replaces_previous_schedule() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().with_video().setup();
+        set_schedule(VideoId::one(), get_schedule(Some(5), Some(10)));
+
+        set_schedule(VideoId::one(), get_schedule(None, Some(7)));
+
+        assert!(!ScheduledVideoVisibilityChangesByBlock::<Test>::contains_key(5));
+        assert!(!ScheduledVideoVisibilityChangesByBlock::<Test>::contains_key(10));
+        assert_eq!(scheduled_changes(7), vec![VideoId::one()]);
+    })
+}
+
+#[test]
+fn set_empty_video_visibility_schedule_cancels_scheduled_changes() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().with_video().setup();
+        set_schedule(VideoId::one(), get_schedule(Some(5), Some(10)));
+
+        set_schedule(VideoId::one(), get_schedule(None, None));
+
+        assert!(!VideoVisibilityScheduleById::<Test>::contains_key(
+            VideoId::one()
+        ));
+        assert!(!ScheduledVideoVisibilityChangesByBlock::<Test>::contains_key(5));
+        assert!(!ScheduledVideoVisibilityChangesByBlock::<Test>::contains_key(10));
+    })
+}
+
+#[test]
+fn set_video_visibility_schedule_fails_with_past_block() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().with_video().setup();
+
+        assert_noop!(
+            Content::set_video_visibility_schedule(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                VideoId::one(),
+                get_schedule(Some(1), None),
+            ),
+            Error::<Test>::VideoVisibilityScheduledInThePast
+        );
+    })
+}
+
+#[test]
+fn set_video_visibility_schedule_fails_with_publishing_and_unpublishing_at_same_block() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().with_video().setup();
+
+        assert_noop!(
+            Content::set_video_visibility_schedule(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                VideoId::one(),
+                get_schedule(Some(5), Some(5)),
+            ),
+            Error::<Test>::InvalidVideoVisibilitySchedule
+        );
+    })
+}
+
+#[test]
+fn set_video_visibility_schedule_fails_with_max_changes_per_block_exceeded() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        let max_changes = <Test as Config>::MaxVideoVisibilityChangesPerBlock::get() as u8;
+        create_default_member_owned_channel_with_videos(max_changes + 1, &[]);
+        for video_id in 1..=u64::from(max_changes) {
+            set_schedule(video_id, get_schedule(Some(5), None));
+        }
+
+        assert_noop!(
+            Content::set_video_visibility_schedule(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                u64::from(max_changes) + 1,
+                get_schedule(Some(5), None),
+            ),
+            Error::<Test>::MaxVideoVisibilityChangesPerBlockExceeded
+        );
+    })
+}
+
+#[test]
+fn set_video_visibility_schedule_fails_with_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel()
+            .with_video()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::UpdateVideoMetadata])
+            .setup();
+
+        assert_noop!(
+            Content::set_video_visibility_schedule(
+                Origin::signed(COLLABORATOR_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(COLLABORATOR_MEMBER_ID),
+                VideoId::one(),
+                get_schedule(Some(5), None),
+            ),
+            Error::<Test>::ChannelAgentInsufficientPermissions
+        );
+    })
+}
+
+#[test]
+fn set_video_visibility_schedule_fails_with_invalid_video_id() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().with_video().setup();
+
+        assert_noop!(
+            Content::set_video_visibility_schedule(
+                Origin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+                ContentActor::Member(DEFAULT_MEMBER_ID),
+                VideoId::one() + 1,
+                get_schedule(Some(5), None),
+            ),
+            Error::<Test>::VideoDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn video_deletion_cancels_scheduled_visibility_changes() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        ContentTest::with_member_channel().with_video().setup();
+        set_schedule(VideoId::one(), get_schedule(Some(5), Some(10)));
+
+        DeleteVideoFixture::default().call_and_assert(Ok(()));
+
+        assert!(!VideoVisibilityScheduleById::<Test>::contains_key(
+            VideoId::one()
+        ));
+        assert!(!ScheduledVideoVisibilityChangesByBlock::<Test>::contains_key(5));
+        assert!(!ScheduledVideoVisibilityChangesByBlock::<Test>::contains_key(10));
+    })
+}
@@ -6,6 +6,7 @@ use super::curators;
 use super::fixtures::*;
 use super::mock::*;
 use crate::*;
+use frame_support::assert_ok;
 use storage::DynamicBagType;
 use storage::ModuleAccount as StorageModuleAccount;
 
@@ -412,6 +413,7 @@ fn unsuccessful_video_creation_due_to_bucket_having_insufficient_objects_size_le
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: STORAGE_BUCKET_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: create_cid(1),
+                    chunks_merkle_root: None,
                 }],
             })
             .call_and_assert(Err(
@@ -456,6 +458,7 @@ fn unsuccessful_video_creation_due_to_bucket_having_insufficient_objects_number_
                     .map(|_| DataObjectCreationParameters {
                         size: 1,
                         ipfs_content_id: create_cid(1),
+                        chunks_merkle_root: None,
                     })
                     .collect(),
             })
@@ -482,6 +485,7 @@ fn unsuccessful_video_creation_with_max_object_size_limits_exceeded() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: VOUCHER_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: create_cid(1),
+                    chunks_merkle_root: None,
                 }],
             })
             .call_and_assert(Err(storage::Error::<Test>::MaxDataObjectSizeExceeded.into()));
@@ -1009,6 +1013,7 @@ fn unsuccessful_video_update_due_to_bucket_having_insufficient_objects_size_left
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: STORAGE_BUCKET_OBJECTS_SIZE_LIMIT + 1,
                     ipfs_content_id: create_cid(1),
+                    chunks_merkle_root: None,
                 }],
             })
             .call_and_assert(Err(
@@ -1040,6 +1045,7 @@ fn unsuccessful_video_update_due_to_bucket_having_insufficient_objects_number_le
                     .map(|_| DataObjectCreationParameters {
                         size: 1,
                         ipfs_content_id: create_cid(1),
+                        chunks_merkle_root: None,
                     })
                     .collect(),
             })
@@ -1064,6 +1070,7 @@ fn unsuccessful_video_update_with_max_object_size_limits_exceeded() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: <Test as storage::Config>::MaxDataObjectSize::get() + 1,
                     ipfs_content_id: create_cid(1),
+                    chunks_merkle_root: None,
                 }],
             })
             .call_and_assert(Err(storage::Error::<Test>::MaxDataObjectSizeExceeded.into()));
@@ -1202,6 +1209,75 @@ fn successful_video_deletion_by_member_with_assets_removal() {
     })
 }
 
+#[test]
+fn successful_video_deletion_with_expired_assets() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+
+        CreateVideoFixture::default()
+            .with_assets(StorageAssets::<Test> {
+                expected_data_size_fee: Storage::<Test>::data_object_per_mega_byte_fee(),
+                object_creation_list: create_data_objects_helper(),
+            })
+            .call_and_assert(Ok(()));
+
+        let video_assets: BTreeSet<_> = Content::video_by_id(VideoId::one()).data_objects.into();
+        run_to_block(System::block_number() + DataObjectAcceptancePeriod::get() + 1);
+
+        assert_ok!(Storage::<Test>::delete_expired_data_objects(
+            Origin::signed(UNAUTHORIZED_MEMBER_ACCOUNT_ID),
+            Content::bag_id_for_channel(&ChannelId::one()),
+            video_assets,
+        ));
+
+        // expired assets are skipped on the video assets removal
+        DeleteVideoFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_video_deletion_with_one_expired_asset() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+
+        CreateVideoFixture::default()
+            .with_assets(StorageAssets::<Test> {
+                expected_data_size_fee: Storage::<Test>::data_object_per_mega_byte_fee(),
+                object_creation_list: create_data_objects_helper(),
+            })
+            .call_and_assert(Ok(()));
+
+        let video_assets: BTreeSet<_> = Content::video_by_id(VideoId::one()).data_objects.into();
+        let expired_asset = *video_assets.iter().next().unwrap();
+        run_to_block(System::block_number() + DataObjectAcceptancePeriod::get() + 1);
+
+        assert_ok!(Storage::<Test>::delete_expired_data_objects(
+            Origin::signed(UNAUTHORIZED_MEMBER_ACCOUNT_ID),
+            Content::bag_id_for_channel(&ChannelId::one()),
+            BTreeSet::from_iter(vec![expired_asset]),
+        ));
+
+        // the expired asset is kept in the video assets until the video assets removal
+        assert!(Content::video_by_id(VideoId::one())
+            .data_objects
+            .contains(&expired_asset));
+
+        DeleteVideoFixture::default().call_and_assert(Ok(()));
+
+        let channel_bag_id = Content::bag_id_for_channel(&ChannelId::one());
+        for data_object_id in video_assets {
+            assert!(!storage::DataObjectsById::<Test>::contains_key(
+                &channel_bag_id,
+                data_object_id
+            ));
+            assert!(!storage::ExpiredDataObjects::<Test>::contains_key(
+                &channel_bag_id,
+                data_object_id
+            ));
+        }
+    })
+}
+
 #[test]
 fn unsuccessful_video_deletion_with_pending_transfer() {
     with_default_mock_builder(|| {
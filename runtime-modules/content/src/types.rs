@@ -5,7 +5,7 @@ use common::{
 };
 use frame_support::parameter_types;
 use frame_support::storage::{
-    bounded_btree_map::BoundedBTreeMap, bounded_btree_set::BoundedBTreeSet,
+    bounded_btree_map::BoundedBTreeMap, bounded_btree_set::BoundedBTreeSet, bounded_vec::BoundedVec,
 };
 use frame_support::PalletId;
 use scale_info::TypeInfo;
@@ -89,16 +89,17 @@ impl<BlockNumber: BaseArithmetic + Copy> NftCounter<BlockNumber> {
         last_updated_period_number == current_period_number
     }
 
-    // Defines whether the counter is valid for the current block.
+    // Increments the counter by `number` for the current period.
     pub(crate) fn update_for_current_period(
         &mut self,
         current_block: BlockNumber,
         period_length: BlockNumber,
+        number: u64,
     ) {
         if self.is_current_period(current_block, period_length) {
-            self.counter = self.counter.saturating_add(1);
+            self.counter = self.counter.saturating_add(number);
         } else {
-            self.counter = 1;
+            self.counter = number;
         }
 
         self.last_updated = current_block;
@@ -238,6 +239,21 @@ pub mod iterable_enums {
         /// Allows deissuing a creator token (provided it has 0 supply)
         /// - `deissue_creator_token`
         DeissueCreatorToken,
+        /// Allows activating and deactivating creator token's bonding curve AMM through:
+        /// - `activate_amm`
+        /// - `deactivate_amm`
+        ManageAmm,
+        /// Allows setting the split of the royalties paid on channel nfts secondary sales through:
+        /// - `set_channel_royalty_split`
+        ManageRoyaltySplit,
+        /// Allows creating, updating and deleting channel playlists through:
+        /// - `create_playlist`
+        /// - `update_playlist`
+        /// - `delete_playlist`
+        ManagePlaylists,
+        /// Allows defining channel paid subscription tiers through:
+        /// - `set_channel_subscription_tiers`
+        ManageSubscriptionTiers,
     }
 }
 
@@ -279,6 +295,8 @@ pub struct ChannelRecord<
     pub owner: ChannelOwner<MemberId, CuratorGroupId>,
     /// The videos under this channel
     pub num_videos: u64,
+    /// The playlists under this channel
+    pub num_playlists: u64,
     /// Map from collaborator's MemberId to collaborator's ChannelAgentPermissions
     pub collaborators: ChannelCollaboratorsMap,
     /// Cumulative cashout
@@ -469,12 +487,18 @@ impl<
         self.transfer_status != ChannelTransferStatus::NoActiveTransfer
     }
 
-    pub fn increment_channel_nft_counters(&mut self, current_block: BlockNumber) {
-        self.daily_nft_counter
-            .update_for_current_period(current_block, self.daily_nft_limit.block_number_period);
+    pub fn increment_channel_nft_counters(&mut self, current_block: BlockNumber, number: u64) {
+        self.daily_nft_counter.update_for_current_period(
+            current_block,
+            self.daily_nft_limit.block_number_period,
+            number,
+        );
 
-        self.weekly_nft_counter
-            .update_for_current_period(current_block, self.weekly_nft_limit.block_number_period);
+        self.weekly_nft_counter.update_for_current_period(
+            current_block,
+            self.weekly_nft_limit.block_number_period,
+            number,
+        );
     }
 
     pub fn ensure_creator_token_issued<T: Config>(&self) -> Result<TokenId, DispatchError> {
@@ -716,6 +740,155 @@ pub type Video<T> = VideoRecord<
 
 pub type DataObjectId<T> = <T as storage::Config>::DataObjectId;
 
+/// Scheduled publishing and unpublishing of a video by its channel.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct VideoVisibilitySchedule<BlockNumber> {
+    /// Block at which the video becomes visible
+    pub publish_at: Option<BlockNumber>,
+    /// Block at which the video becomes hidden
+    pub unpublish_at: Option<BlockNumber>,
+}
+
+pub type VideoVisibilityScheduleOf<T> =
+    VideoVisibilitySchedule<<T as frame_system::Config>::BlockNumber>;
+
+/// Videos with a visibility change scheduled for the same block
+pub type ScheduledVideoVisibilityChanges<T> =
+    BoundedVec<<T as Config>::VideoId, <T as Config>::MaxVideoVisibilityChangesPerBlock>;
+
+/// Paid subscription tier defined by a channel.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SubscriptionTierRecord<Balance, TokenBalance, BlockNumber> {
+    /// Price in JOY charged each period
+    pub price: Balance,
+    /// Optional price in channel creator tokens, charged each period instead of `price`
+    pub creator_token_price: Option<TokenBalance>,
+    /// Length of the subscription period in blocks
+    pub period: BlockNumber,
+}
+
+pub type SubscriptionTier<T> = SubscriptionTierRecord<
+    BalanceOf<T>,
+    TokenBalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+pub type SubscriptionTiers<T> =
+    BoundedVec<SubscriptionTier<T>, <T as Config>::MaxSubscriptionTiersPerChannel>;
+
+/// Amount pulled from the subscriber each subscription period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum SubscriptionPaymentRecord<Balance, TokenBalance> {
+    /// Payment in JOY, sent to the channel account
+    Joy(Balance),
+    /// Payment in channel creator tokens, sent to the channel owner member
+    CreatorToken(TokenBalance),
+}
+
+impl<Balance: Default, TokenBalance> Default for SubscriptionPaymentRecord<Balance, TokenBalance> {
+    fn default() -> Self {
+        Self::Joy(Balance::default())
+    }
+}
+
+pub type SubscriptionPayment<T> = SubscriptionPaymentRecord<BalanceOf<T>, TokenBalanceOf<T>>;
+
+/// Member subscription to a channel: a capped recurring payment pulled from the subscriber.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct SubscriptionRecord<
+    ChannelId,
+    MemberId,
+    AccountId,
+    SubscriptionPayment,
+    BlockNumber,
+    RepayableBloatBond,
+> {
+    pub channel_id: ChannelId,
+    /// Subscriber
+    pub member_id: MemberId,
+    /// Account the payments are pulled from
+    pub payer: AccountId,
+    /// Amount pulled each period
+    pub payment: SubscriptionPayment,
+    /// Length of the subscription period in blocks
+    pub period: BlockNumber,
+    /// Number of further payments the subscriber authorized to be pulled
+    pub remaining_payments: u32,
+    /// Block at which the next payment is due
+    pub next_payment_at: BlockNumber,
+    /// State bloat bond paid for storing the subscription
+    pub subscription_state_bloat_bond: RepayableBloatBond,
+}
+
+pub type Subscription<T> = SubscriptionRecord<
+    <T as storage::Config>::ChannelId,
+    <T as common::MembershipTypes>::MemberId,
+    <T as frame_system::Config>::AccountId,
+    SubscriptionPayment<T>,
+    <T as frame_system::Config>::BlockNumber,
+    RepayableBloatBondOf<T>,
+>;
+
+/// Reason of a subscription termination by the payments processor
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum SubscriptionLapseReason {
+    /// All authorized payments were pulled and the last paid period has ended
+    PaymentsCapReached,
+    /// The subscriber could not cover the payment
+    PaymentFailed,
+    /// The channel was deleted
+    ChannelDeleted,
+}
+
+/// An ordered list of videos which belongs to a channel, e.g. a series.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PlaylistRecord<ChannelId, PlaylistVideos, RepayableBloatBond> {
+    pub in_channel: ChannelId,
+    /// Ordered list of channel videos
+    pub videos: PlaylistVideos,
+    /// State bloat bond paid for storing the playlist
+    pub playlist_state_bloat_bond: RepayableBloatBond,
+}
+
+pub type PlaylistVideos<T> =
+    BoundedVec<<T as Config>::VideoId, <T as Config>::MaxVideosPerPlaylist>;
+
+pub type Playlist<T> =
+    PlaylistRecord<<T as storage::Config>::ChannelId, PlaylistVideos<T>, RepayableBloatBondOf<T>>;
+
+/// Information about the playlist being created.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct PlaylistCreationParametersRecord<VideoId, Balance> {
+    /// Ordered list of channel videos
+    pub videos: Vec<VideoId>,
+    /// Metadata for the playlist.
+    pub meta: Option<Vec<u8>>,
+    /// Commitment for the playlist state bloat bond.
+    pub expected_playlist_state_bloat_bond: Balance,
+}
+
+pub type PlaylistCreationParameters<T> =
+    PlaylistCreationParametersRecord<<T as Config>::VideoId, BalanceOf<T>>;
+
+/// Information about the playlist being updated
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct PlaylistUpdateParametersRecord<VideoId> {
+    /// If set, new ordered list of channel videos
+    pub new_videos: Option<Vec<VideoId>>,
+    /// If set, metadata update for the playlist.
+    pub new_meta: Option<Vec<u8>>,
+}
+
+pub type PlaylistUpdateParameters<T> = PlaylistUpdateParametersRecord<<T as Config>::VideoId>;
+
 // alias for the proof element
 pub type ProofElement<T> = ProofElementRecord<<T as frame_system::Config>::Hash, Side>;
 
@@ -80,6 +80,8 @@ pub trait WeightInfo {
 	fn creator_token_issuer_transfer(_a: u32, _b: u32, ) -> Weight;
 	fn make_creator_token_permissionless() -> Weight;
 	fn deissue_creator_token() -> Weight;
+	fn activate_amm() -> Weight;
+	fn deactivate_amm() -> Weight;
 	fn init_creator_token_sale(_a: u32, ) -> Weight;
 	fn update_upcoming_creator_token_sale() -> Weight;
 	fn finalize_creator_token_sale() -> Weight;
@@ -95,6 +97,8 @@ pub trait WeightInfo {
 	fn claim_and_withdraw_member_channel_reward(_h: u32, ) -> Weight;
 	fn claim_and_withdraw_curator_channel_reward(_h: u32, ) -> Weight;
 	fn issue_nft(_w: u32, _b: u32, ) -> Weight;
+	fn create_nft_collection(_w: u32, ) -> Weight;
+	fn batch_issue_nft(_n: u32, ) -> Weight;
 	fn destroy_nft() -> Weight;
 	fn sling_nft_back() -> Weight;
 	fn offer_nft() -> Weight;
@@ -116,11 +120,28 @@ pub trait WeightInfo {
 	fn cancel_open_auction_bid() -> Weight;
 	fn pick_open_auction_winner() -> Weight;
 	fn make_open_auction_bid() -> Weight;
+	fn start_dutch_auction(_w: u32, ) -> Weight;
+	fn cancel_dutch_auction() -> Weight;
+	fn buy_nft_on_dutch_auction() -> Weight;
+	fn set_nft_priced_in_creator_token() -> Weight;
+	fn set_channel_royalty_split(_r: u32, ) -> Weight;
+	fn create_playlist(_a: u32, _b: u32, ) -> Weight;
+	fn update_playlist(_a: u32, _b: u32, ) -> Weight;
+	fn delete_playlist() -> Weight;
+	fn delete_playlist_as_moderator(_a: u32, ) -> Weight;
+	fn set_video_visibility_schedule() -> Weight;
+	fn on_initialize_video_visibility_changes(_n: u32, ) -> Weight;
+	fn set_channel_subscription_tiers(_a: u32, ) -> Weight;
+	fn subscribe_to_channel() -> Weight;
+	fn unsubscribe_from_channel() -> Weight;
+	fn on_initialize_subscription_payments(_n: u32, ) -> Weight;
 	fn channel_owner_remark(_b: u32, ) -> Weight;
 	fn channel_agent_remark(_b: u32, ) -> Weight;
 	fn nft_owner_remark(_b: u32, ) -> Weight;
 	fn update_channel_state_bloat_bond() -> Weight;
 	fn update_video_state_bloat_bond() -> Weight;
+	fn update_playlist_state_bloat_bond() -> Weight;
+	fn update_subscription_state_bloat_bond() -> Weight;
 }
 
 /// Weights for content using the Substrate node and recommended hardware.
@@ -781,6 +802,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
 	// Storage: Content CuratorGroupById (r:1 w:0)
 	// Storage: Token TokenInfoById (r:1 w:1)
+	fn activate_amm() -> Weight {
+		(341_218_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:1)
+	// Storage: Token AmmDeactivationThreshold (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn deactivate_amm() -> Weight {
+		(372_645_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:1)
 	// Storage: Token MinSaleDuration (r:1 w:0)
 	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
 	fn init_creator_token_sale(a: u32, ) -> Weight {
@@ -976,6 +1017,51 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(w as Weight)))
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Content MaxCreatorRoyalty (r:1 w:0)
+	// Storage: Content MinCreatorRoyalty (r:1 w:0)
+	// Storage: Content NextNftCollectionId (r:1 w:1)
+	// Storage: Content NftCollectionById (r:0 w:1)
+	fn create_nft_collection(w: u32, ) -> Weight {
+		(371_504_000 as Weight)
+			// Standard Error: 12_000
+			.saturating_add((412_000 as Weight).saturating_mul(w as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Content NftCollectionById (r:1 w:1)
+	// Storage: Content ChannelById (r:1 w:1)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Content VideoById (r:1 w:1)
+	// Storage: Content MaxAuctionDuration (r:1 w:0)
+	// Storage: Content MinAuctionDuration (r:1 w:0)
+	// Storage: Content MaxAuctionExtensionPeriod (r:1 w:0)
+	// Storage: Content MinAuctionExtensionPeriod (r:1 w:0)
+	// Storage: Content MaxBidStep (r:1 w:0)
+	// Storage: Content MinBidStep (r:1 w:0)
+	// Storage: Content AuctionStartsAtMaxDelta (r:1 w:0)
+	// Storage: Content MinStartingPrice (r:1 w:0)
+	// Storage: Content MaxStartingPrice (r:1 w:0)
+	// Storage: Membership MembershipById (r:20 w:0)
+	// Storage: Content MaxCreatorRoyalty (r:1 w:0)
+	// Storage: Content MinCreatorRoyalty (r:1 w:0)
+	// Storage: Content NftLimitsEnabled (r:1 w:0)
+	// Storage: Content GlobalDailyNftLimit (r:1 w:0)
+	// Storage: Content GlobalDailyNftCounter (r:1 w:1)
+	// Storage: Content GlobalWeeklyNftLimit (r:1 w:0)
+	// Storage: Content GlobalWeeklyNftCounter (r:1 w:1)
+	fn batch_issue_nft(n: u32, ) -> Weight {
+		(786_903_000 as Weight)
+			// Standard Error: 41_000
+			.saturating_add((27_118_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(40 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
 	// Storage: Content VideoById (r:1 w:1)
 	// Storage: Content ChannelById (r:1 w:0)
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
@@ -1189,6 +1275,194 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(10 as Weight))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
+	// Storage: Content VideoById (r:1 w:1)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Content MinStartingPrice (r:1 w:0)
+	// Storage: Content MaxStartingPrice (r:1 w:0)
+	// Storage: Content MaxAuctionWhiteListLength (r:1 w:0)
+	// Storage: Content AuctionStartsAtMaxDelta (r:1 w:0)
+	// Storage: Membership MembershipById (r:1 w:0)
+	fn start_dutch_auction(w: u32, ) -> Weight {
+		(510_255_000 as Weight)
+			// Standard Error: 53_000
+			.saturating_add((11_276_000 as Weight).saturating_mul(w as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(w as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Content VideoById (r:1 w:1)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	fn cancel_dutch_auction() -> Weight {
+		(479_989_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership MembershipById (r:2 w:0)
+	// Storage: Content VideoById (r:1 w:1)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Content PlatfromFeePercentage (r:1 w:0)
+	fn buy_nft_on_dutch_auction() -> Weight {
+		(290_114_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Content VideoById (r:1 w:1)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: ProjectToken AccountInfoByTokenAndMember (r:1 w:0)
+	fn set_nft_priced_in_creator_token() -> Weight {
+		(84_652_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Membership MembershipById (r:10 w:0)
+	// Storage: Content RoyaltySplitByChannelId (r:0 w:1)
+	fn set_channel_royalty_split(r: u32, ) -> Weight {
+		(71_024_000 as Weight)
+			// Standard Error: 19_000
+			.saturating_add((5_866_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Content ChannelById (r:1 w:1)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Content VideoById (r:50 w:0)
+	// Storage: Content PlaylistStateBloatBondValue (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Content NextPlaylistId (r:1 w:1)
+	// Storage: Content PlaylistsByChannelId (r:0 w:1)
+	// Storage: Content PlaylistById (r:0 w:1)
+	fn create_playlist(a: u32, b: u32, ) -> Weight {
+		(198_264_000 as Weight)
+			// Standard Error: 11_000
+			.saturating_add((9_452_000 as Weight).saturating_mul(a as Weight))
+			// Standard Error: 4_000
+			.saturating_add((701_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: Content PlaylistById (r:1 w:1)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Content VideoById (r:50 w:0)
+	fn update_playlist(a: u32, b: u32, ) -> Weight {
+		(102_511_000 as Weight)
+			// Standard Error: 10_000
+			.saturating_add((9_317_000 as Weight).saturating_mul(a as Weight))
+			// Standard Error: 4_000
+			.saturating_add((698_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(a as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Content PlaylistById (r:1 w:1)
+	// Storage: Content ChannelById (r:1 w:1)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Content PlaylistsByChannelId (r:0 w:1)
+	fn delete_playlist() -> Weight {
+		(139_872_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Content PlaylistById (r:1 w:1)
+	// Storage: Content ChannelById (r:1 w:1)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Content PlaylistsByChannelId (r:0 w:1)
+	fn delete_playlist_as_moderator(a: u32, ) -> Weight {
+		(131_205_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((712_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Content VideoById (r:1 w:0)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Storage: Content ScheduledVideoVisibilityChangesByBlock (r:4 w:4)
+	// Storage: Content VideoVisibilityScheduleById (r:1 w:1)
+	fn set_video_visibility_schedule() -> Weight {
+		(118_306_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Content ScheduledVideoVisibilityChangesByBlock (r:1 w:1)
+	// Storage: Content VideoVisibilityScheduleById (r:20 w:20)
+	fn on_initialize_video_visibility_changes(n: u32, ) -> Weight {
+		(6_384_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((13_207_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Storage: Content SubscriptionTiersByChannelId (r:0 w:1)
+	fn set_channel_subscription_tiers(a: u32, ) -> Weight {
+		(96_812_000 as Weight)
+			// Standard Error: 21_000
+			.saturating_add((1_348_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Content SubscriptionTiersByChannelId (r:1 w:0)
+	// Storage: Content SubscriptionStateBloatBondValue (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Storage: Token AccountInfoByTokenAndMember (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Content NextSubscriptionId (r:1 w:1)
+	// Storage: Content ScheduledSubscriptionPaymentsByBlock (r:0 w:1)
+	// Storage: Content SubscriptionById (r:0 w:1)
+	fn subscribe_to_channel() -> Weight {
+		(159_812_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Content SubscriptionById (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Content ScheduledSubscriptionPaymentsByBlock (r:0 w:1)
+	fn unsubscribe_from_channel() -> Weight {
+		(89_517_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Content SubscriptionPaymentsQueueHead (r:1 w:1)
+	// Storage: Content ScheduledSubscriptionPaymentsByBlock (r:2 w:100)
+	// Storage: Content SubscriptionById (r:50 w:50)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Storage: Token AccountInfoByTokenAndMember (r:100 w:100)
+	fn on_initialize_subscription_payments(n: u32, ) -> Weight {
+		(12_348_000 as Weight)
+			// Standard Error: 32_000
+			.saturating_add((101_207_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((5 as Weight).saturating_mul(n as Weight)))
+	}
 	// Storage: Content ChannelById (r:1 w:0)
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
@@ -1233,6 +1507,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content PlaylistStateBloatBondValue (r:0 w:1)
+	fn update_playlist_state_bloat_bond() -> Weight {
+		(47_118_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Content SubscriptionStateBloatBondValue (r:0 w:1)
+	fn update_subscription_state_bloat_bond() -> Weight {
+		(47_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -1342,6 +1632,12 @@ impl WeightInfo for () {
 	fn deissue_creator_token() -> Weight {
 		0
 	}
+	fn activate_amm() -> Weight {
+		0
+	}
+	fn deactivate_amm() -> Weight {
+		0
+	}
 	fn init_creator_token_sale(a: u32, ) -> Weight {
 		0
 	}
@@ -1387,6 +1683,12 @@ impl WeightInfo for () {
 	fn issue_nft(w: u32, b: u32, ) -> Weight {
 		0
 	}
+	fn create_nft_collection(w: u32, ) -> Weight {
+		0
+	}
+	fn batch_issue_nft(n: u32, ) -> Weight {
+		0
+	}
 	fn destroy_nft() -> Weight {
 		0
 	}
@@ -1450,6 +1752,51 @@ impl WeightInfo for () {
 	fn make_open_auction_bid() -> Weight {
 		0
 	}
+	fn start_dutch_auction(w: u32, ) -> Weight {
+		0
+	}
+	fn cancel_dutch_auction() -> Weight {
+		0
+	}
+	fn buy_nft_on_dutch_auction() -> Weight {
+		0
+	}
+	fn set_nft_priced_in_creator_token() -> Weight {
+		0
+	}
+	fn set_channel_royalty_split(r: u32, ) -> Weight {
+		0
+	}
+	fn create_playlist(a: u32, b: u32, ) -> Weight {
+		0
+	}
+	fn update_playlist(a: u32, b: u32, ) -> Weight {
+		0
+	}
+	fn delete_playlist() -> Weight {
+		0
+	}
+	fn delete_playlist_as_moderator(a: u32, ) -> Weight {
+		0
+	}
+	fn set_video_visibility_schedule() -> Weight {
+		0
+	}
+	fn on_initialize_video_visibility_changes(n: u32, ) -> Weight {
+		0
+	}
+	fn set_channel_subscription_tiers(a: u32, ) -> Weight {
+		0
+	}
+	fn subscribe_to_channel() -> Weight {
+		0
+	}
+	fn unsubscribe_from_channel() -> Weight {
+		0
+	}
+	fn on_initialize_subscription_payments(n: u32, ) -> Weight {
+		0
+	}
 	fn channel_owner_remark(b: u32, ) -> Weight {
 		0
 	}
@@ -1465,4 +1812,10 @@ impl WeightInfo for () {
 	fn update_video_state_bloat_bond() -> Weight {
 		0
 	}
+	fn update_playlist_state_bloat_bond() -> Weight {
+		0
+	}
+	fn update_subscription_state_bloat_bond() -> Weight {
+		0
+	}
 }
@@ -754,6 +754,51 @@ benchmarks! {
     verify {
         assert_last_event::<T>(RawEvent::CouncilorRemarked(candidates_id[0], msg).into());
     }
+
+    remove_councilor {
+        // periods easier to calculate
+        let current_block_number = Zero::zero();
+        System::<T>::set_block_number(current_block_number);
+        assert_eq!(System::<T>::block_number(), current_block_number, "Block number not updated");
+
+        // Worst case we have a council elected
+        let (_, candidates_id) = start_period_announce_multiple_candidates::<T>(
+            T::CouncilSize::get()
+        );
+
+        let winners = candidates_id.iter().map(|candidate_id| {
+            let option_id: T::MemberId = *candidate_id;
+            OptionResult {
+                option_id,
+                vote_power: Zero::zero(),
+            }
+        }).collect::<Vec<_>>();
+
+        Council::<T>::end_announcement_period(T::CouncilSize::get());
+
+        Council::<T>::end_election_period(&winners[..]);
+
+        assert_eq!(
+            Council::<T>::council_members().len(),
+            T::CouncilSize::get() as usize,
+            "Council not updated"
+        );
+
+        // Worst case the stake is slashed
+        let slashing_amount = T::MinCandidateStake::get();
+    }: _(RawOrigin::Root, candidates_id[0], Some(slashing_amount))
+    verify {
+        assert!(
+            !Council::<T>::council_members()
+                .iter()
+                .any(|council_member| *council_member.member_id() == candidates_id[0]),
+            "Councilor not removed"
+        );
+
+        assert_last_event::<T>(
+            RawEvent::CouncilorRemoved(candidates_id[0], slashing_amount).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -871,6 +916,14 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_remove_councilor() {
+        let config = default_genesis_config();
+        build_test_externalities(config).execute_with(|| {
+            assert_ok!(Council::<Runtime>::test_benchmark_remove_councilor());
+        })
+    }
+
     #[test]
     fn test_candidate_remark() {
         let config = default_genesis_config();
@@ -28,6 +28,7 @@
 //! - [plan_budget_refill](./struct.Module.html#method.plan_budget_refill)
 //! - [set_budget_increment](./struct.Module.html#method.set_budget_increment)
 //! - [set_councilor_reward](./struct.Module.html#method.set_councilor_reward)
+//! - [remove_councilor](./struct.Module.html#method.remove_councilor)
 //! - [funding_request](./struct.Module.html#method.funding_request)
 //! - [fund_council_budget](./struct.Module.html#method.fund_council_budget)
 //!
@@ -72,7 +73,9 @@ use frame_support::dispatch::DispatchResult;
 use frame_support::storage::weak_bounded_vec::WeakBoundedVec;
 use frame_support::traits::{Currency, Get, LockIdentifier};
 use frame_support::weights::Weight;
-use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, error::BadOrigin};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure, error::BadOrigin, IterableStorageMap,
+};
 use frame_system::ensure_root;
 use referendum::{CastVote, OptionResult, ReferendumManager};
 use scale_info::TypeInfo;
@@ -86,6 +89,7 @@ use staking_handler::StakingHandler;
 // declared modules
 mod benchmarking;
 mod mock;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 pub use weights::WeightInfo;
@@ -158,6 +162,16 @@ pub struct Candidate<AccountId, Balance, Hash, VotePower> {
     note_hash: Option<Hash>,
 }
 
+impl<AccountId, Balance, Hash, VotePower> Candidate<AccountId, Balance, Hash, VotePower> {
+    pub fn stake(&self) -> &Balance {
+        &self.stake
+    }
+
+    pub fn note_hash(&self) -> Option<&Hash> {
+        self.note_hash.as_ref()
+    }
+}
+
 /// Council member representation.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, PartialEq, Eq, Debug, Clone, TypeInfo, MaxEncodedLen)]
@@ -418,6 +432,12 @@ decl_event! {
         /// Councilor reward has been updated.
         CouncilorRewardUpdated(Balance),
 
+        /// Councilor was removed from the council before the end of the term.
+        /// Params:
+        /// - Member ID
+        /// - Amount of the councilor stake that was slashed
+        CouncilorRemoved(MemberId, Balance),
+
         /// Request has been funded
         RequestFunded(AccountId, Balance),
 
@@ -833,6 +853,40 @@ decl_module! {
         }
 
 
+        /// Removes the councilor from the council, leaving the seat empty until the next election.
+        /// The councilor stake is optionally slashed and the rest of it is unlocked. Any unpaid
+        /// reward of the councilor is discarded.
+        ///
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = CouncilWeightInfo::<T>::remove_councilor()]
+        pub fn remove_councilor(
+            origin,
+            councilor_id: T::MemberId,
+            slashing_amount: Option<Balance<T>>,
+        ) -> Result<(), Error<T>> {
+            // ensure action can be started
+            let council_member = EnsureChecks::<T>::can_remove_councilor(origin, &councilor_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // update state
+            let slashed_amount = Mutations::<T>::remove_councilor(&council_member, slashing_amount);
+
+            // emit event
+            Self::deposit_event(RawEvent::CouncilorRemoved(councilor_id, slashed_amount));
+
+            Ok(())
+        }
+
+
         /// Transfers funds from council budget to account
         ///
         /// # <weight>
@@ -1222,6 +1276,15 @@ impl<T: Config> Module<T> {
         }
     }
 
+    /// Candidates that announced their candidacy in the current election cycle.
+    pub fn current_candidates() -> Vec<(T::MemberId, CandidateOf<T>)> {
+        let current_cycle_id = AnnouncementPeriodNr::get();
+
+        Candidates::<T>::iter()
+            .filter(|(_, candidate)| candidate.cycle_id == current_cycle_id)
+            .collect()
+    }
+
     fn calculate_on_initialize_weight(mb_candidate_count: Option<u32>) -> Weight {
         // Minimum weight for progress stage
         let weight = CouncilWeightInfo::<T>::try_progress_stage_idle()
@@ -1271,6 +1334,16 @@ impl<T: Config> ReferendumConnection<T> for Module<T> {
     fn can_unlock_vote_stake(vote: &CastVoteOf<T>) -> Result<(), Error<T>> {
         let current_voting_cycle_id = AnnouncementPeriodNr::get();
 
+        // If the vote stake was locked for the additional election cycles...
+        if vote.lock_period > 0 {
+            // ..it is recoverable only after the last of them.
+            return if vote.locked_until_cycle() < current_voting_cycle_id {
+                Ok(())
+            } else {
+                Err(Error::CantReleaseStakeNow)
+            };
+        }
+
         // If the vote was cast before the latest Announcing stage...
         if vote.cycle_id != current_voting_cycle_id {
             // ..it is always recoverable.
@@ -1570,6 +1643,33 @@ impl<T: Config> Mutations<T> {
         CouncilorReward::<T>::put(councilor_reward);
     }
 
+    // Remove the member from the current council and release (or slash) their stake.
+    fn remove_councilor(
+        council_member: &CouncilMemberOf<T>,
+        slashing_amount: Option<Balance<T>>,
+    ) -> Balance<T> {
+        let slashed_amount = slashing_amount.map_or_else(Zero::zero, |amount| {
+            T::CouncilorLock::slash(&council_member.staking_account_id, Some(amount))
+        });
+
+        // release the rest of the stake
+        T::CouncilorLock::unlock(&council_member.staking_account_id);
+
+        // leave the seat empty until the next council is elected
+        let remaining_members = CouncilMembers::<T>::get()
+            .into_inner()
+            .into_iter()
+            .filter(|member| member.membership_id != council_member.membership_id)
+            .collect::<Vec<_>>();
+
+        CouncilMembers::<T>::put(WeakBoundedVec::<_, _>::force_from(
+            remaining_members,
+            Some("CouncilMembers"),
+        ));
+
+        slashed_amount
+    }
+
     // Pay reward to a single elected council member.
     fn pay_reward(
         member_index: usize,
@@ -1777,6 +1877,22 @@ impl<T: Config> EnsureChecks<T> {
 
         Ok(())
     }
+
+    // Ensures there is no problem in removing the councilor.
+    fn can_remove_councilor(
+        origin: T::Origin,
+        councilor_id: &T::MemberId,
+    ) -> Result<CouncilMemberOf<T>, Error<T>> {
+        ensure_root(origin)?;
+
+        let council_member = Module::<T>::council_members()
+            .into_inner()
+            .into_iter()
+            .find(|council_member| council_member.member_id() == councilor_id)
+            .ok_or(Error::<T>::NotCouncilor)?;
+
+        Ok(council_member)
+    }
 }
 
 impl<T: Config + common::membership::MembershipTypes>
@@ -1785,14 +1901,19 @@ impl<T: Config + common::membership::MembershipTypes>
     fn ensure_member_consulate(origin: T::Origin, member_id: T::MemberId) -> DispatchResult {
         EnsureChecks::<T>::ensure_user_membership(origin, &member_id)?;
 
-        let is_councilor = Self::council_members()
-            .iter()
-            .any(|council_member| council_member.member_id() == &member_id);
-
-        ensure!(is_councilor, Error::<T>::NotCouncilor);
+        ensure!(
+            Self::is_council_member(&member_id),
+            Error::<T>::NotCouncilor
+        );
 
         Ok(())
     }
+
+    fn is_council_member(member_id: &T::MemberId) -> bool {
+        Self::council_members()
+            .iter()
+            .any(|council_member| council_member.member_id() == member_id)
+    }
 }
 
 impl<T: Config + balances::Config> common::council::CouncilBudgetManager<T::AccountId, Balance<T>>
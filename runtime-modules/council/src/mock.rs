@@ -19,7 +19,8 @@ use frame_support::{ensure, parameter_types, StorageMap, StorageValue};
 use frame_system::{ensure_signed, EnsureRoot, EnsureSigned, EventRecord, Phase, RawOrigin};
 use rand::Rng;
 use referendum::{
-    CastVote, OptionResult, ReferendumManager, ReferendumStage, ReferendumStageRevealing,
+    BallotMode, CastVote, OptionResult, ReferendumManager, ReferendumStage,
+    ReferendumStageRevealing,
 };
 use sp_core::H256;
 
@@ -205,6 +206,9 @@ parameter_types! {
     pub const StakingCandidateLockId: [u8; 8] = [3; 8];
     pub const CandidateStake: u64 = 100;
     pub const MaxWinnerTargetCount: u32 = 10;
+    pub const MaxVoteLockPeriod: u32 = 6;
+    pub const ReferendumBallotMode: BallotMode = BallotMode::FirstPastThePost;
+    pub const MaxRankedOptions: u32 = 5;
     pub const ReferralCutMaximumPercent: u8 = 50;
 }
 
@@ -226,11 +230,17 @@ impl referendum::Config<ReferendumInstance> for Runtime {
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
 
+    type MaxVoteLockPeriod = MaxVoteLockPeriod;
+    type BallotMode = ReferendumBallotMode;
+    type MaxRankedOptions = MaxRankedOptions;
+
     fn calculate_vote_power(
         account_id: &<Self as frame_system::Config>::AccountId,
         stake: &Balance<Self>,
+        lock_period: u32,
     ) -> Self::VotePower {
-        let stake: u64 = *stake;
+        // the stake locked for the additional cycles has the proportionally greater power
+        let stake: u64 = *stake * (lock_period as u64 + 1);
         if *account_id == USER_REGULAR_POWER_VOTES {
             return stake * POWER_VOTE_STRENGTH;
         }
@@ -970,6 +980,16 @@ where
         commitment: T::Hash,
         stake: Balance<T>,
         expected_result: Result<(), ()>,
+    ) -> () {
+        Self::vote_for_candidate_with_lock_period(origin, commitment, stake, 0, expected_result);
+    }
+
+    pub fn vote_for_candidate_with_lock_period(
+        origin: OriginType<T::AccountId>,
+        commitment: T::Hash,
+        stake: Balance<T>,
+        lock_period: u32,
+        expected_result: Result<(), ()>,
     ) -> () {
         // check method returns expected result
         assert_eq!(
@@ -977,6 +997,7 @@ where
                 InstanceMockUtils::<T>::mock_origin(origin).into(),
                 commitment.into(),
                 stake.into(),
+                lock_period,
             )
             .is_ok(),
             expected_result.is_ok(),
@@ -1176,6 +1197,42 @@ where
         );
     }
 
+    pub fn remove_councilor(
+        origin: OriginType<T::AccountId>,
+        councilor_id: T::MemberId,
+        slashing_amount: Option<Balance<T>>,
+        expected_result: Result<(), Error<T>>,
+    ) {
+        // check method returns expected result
+        assert_eq!(
+            Module::<T>::remove_councilor(
+                InstanceMockUtils::<T>::mock_origin(origin),
+                councilor_id,
+                slashing_amount,
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            return;
+        }
+
+        assert!(!CouncilMembers::<T>::get()
+            .iter()
+            .any(|council_member| *council_member.member_id() == councilor_id));
+
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            Event::Council(RawEvent::CouncilorRemoved(
+                councilor_id.into(),
+                slashing_amount.unwrap_or_else(Zero::zero).into()
+            )),
+        );
+    }
+
     // simulate one council's election cycle
     pub fn simulate_council_cycle(params: CouncilCycleParams<T>) {
         let settings = params.council_settings;
@@ -0,0 +1,33 @@
+//! Runtime API definition for the council module.
+
+use crate::{Candidate, CouncilMember, CouncilStageUpdate};
+use codec::Codec;
+use sp_runtime::traits::One;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Read-only access to the council state for the node RPC and off-chain clients.
+    pub trait CouncilApi<AccountId, MemberId, Balance, BlockNumber, Hash, VotePower> where
+        AccountId: Codec,
+        MemberId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec + One,
+        Hash: Codec,
+        VotePower: Codec,
+    {
+        /// Current council stage and the block it was entered at.
+        fn stage() -> CouncilStageUpdate<BlockNumber>;
+
+        /// Candidates announced in the current election cycle.
+        fn candidates() -> Vec<(MemberId, Candidate<AccountId, Balance, Hash, VotePower>)>;
+
+        /// Members of the current council.
+        fn councilors() -> Vec<CouncilMember<AccountId, MemberId, Balance, BlockNumber>>;
+
+        /// Current council budget.
+        fn budget() -> Balance;
+
+        /// Block at which the council budget is going to be refilled next time.
+        fn next_budget_refill() -> BlockNumber;
+    }
+}
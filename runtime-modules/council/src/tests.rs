@@ -2,7 +2,7 @@
 
 use super::{
     AnnouncementPeriodNr, Budget, BudgetIncrement, Config, CouncilMemberOf, CouncilMembers,
-    CouncilStageAnnouncing, Error, Module,
+    CouncilStageAnnouncing, Error, Module, ReferendumConnection,
 };
 use crate::mock::*;
 use common::council::CouncilBudgetManager;
@@ -251,6 +251,37 @@ fn vote_stake_locks_after_new_election_starts() {
     });
 }
 
+#[test]
+fn vote_stake_locks_until_vote_lock_period_ends() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let vote = referendum::CastVote {
+            commitment: Default::default(),
+            cycle_id: 1,
+            stake: <Runtime as referendum::Config<ReferendumInstance>>::MinimumStake::get(),
+            vote_for: None,
+            lock_period: 2,
+        };
+
+        // The vote stake is locked in the vote's cycle and the two additional cycles
+        for cycle_id in 1..=3 {
+            AnnouncementPeriodNr::put(cycle_id);
+
+            assert_err!(
+                <Module<Runtime> as ReferendumConnection<Runtime>>::can_unlock_vote_stake(&vote),
+                Error::<Runtime>::CantReleaseStakeNow
+            );
+        }
+
+        AnnouncementPeriodNr::put(4);
+
+        assert_ok!(
+            <Module<Runtime> as ReferendumConnection<Runtime>>::can_unlock_vote_stake(&vote)
+        );
+    });
+}
+
 // Test that candidate can withdraw valid candidacy.
 #[test]
 fn council_candidacy_withdraw_candidacy() {
@@ -1149,6 +1180,40 @@ fn council_repeated_candidacy_unstakes() {
     });
 }
 
+// Test that only candidates of the current election cycle are reported as current candidates.
+#[test]
+fn council_current_candidates_skip_previous_cycles() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let council_settings = CouncilSettings::<Runtime>::extract_settings();
+        let not_elected_candidate_index = 2;
+
+        // run one council cycle - candidates that lost the election keep their records
+        let params = Mocks::run_full_council_cycle(1, &[], 0);
+
+        // forward to next candidacy announcing period
+        MockUtils::increase_block_number(council_settings.idle_stage_duration + 1);
+        assert!(Module::<Runtime>::current_candidates().is_empty());
+
+        let candidate = params.candidates_announcing[not_elected_candidate_index].clone();
+        Mocks::announce_candidacy(
+            candidate.origin.clone(),
+            candidate.account_id,
+            council_settings.min_candidate_stake,
+            Ok(()),
+        );
+
+        let current_candidates = Module::<Runtime>::current_candidates();
+        assert_eq!(current_candidates.len(), 1);
+        assert_eq!(current_candidates[0].0, candidate.membership_id);
+        assert_eq!(
+            *current_candidates[0].1.stake(),
+            council_settings.min_candidate_stake
+        );
+    });
+}
+
 /////////////////// Budget-related /////////////////////////////////////////////
 
 // Test that budget balance can be set from external source.
@@ -2097,3 +2162,81 @@ fn candidate_remark_unsuccessful_with_invalid_origin() {
         );
     });
 }
+
+#[test]
+fn remove_councilor_succeeds() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let council_settings = CouncilSettings::<Runtime>::extract_settings();
+        let params = Mocks::run_full_council_cycle(1, &[], 0);
+        let council_member = params.expected_final_council_members[0].clone();
+        let slashing_amount = council_settings.min_candidate_stake / 2;
+        let initial_balance =
+            balances::Pallet::<Runtime>::free_balance(council_member.staking_account_id);
+
+        Mocks::remove_councilor(
+            OriginType::Root,
+            council_member.membership_id,
+            Some(slashing_amount),
+            Ok(()),
+        );
+
+        assert_eq!(
+            Council::council_members().len(),
+            params.expected_final_council_members.len() - 1
+        );
+        assert_eq!(
+            CouncilorLock::current_stake(&council_member.staking_account_id),
+            0
+        );
+        assert_eq!(
+            balances::Pallet::<Runtime>::free_balance(council_member.staking_account_id),
+            initial_balance - slashing_amount
+        );
+
+        assert_err!(
+            Council::councilor_remark(
+                RawOrigin::Signed(council_member.membership_id).into(),
+                council_member.membership_id,
+                b"test".to_vec()
+            ),
+            Error::<Runtime>::NotCouncilor,
+        );
+    });
+}
+
+#[test]
+fn remove_councilor_fails_with_invalid_origin() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        let params = Mocks::run_full_council_cycle(1, &[], 0);
+        let council_member = params.expected_final_council_members[0].clone();
+
+        Mocks::remove_councilor(
+            OriginType::Signed(council_member.staking_account_id),
+            council_member.membership_id,
+            None,
+            Err(Error::<Runtime>::BadOrigin),
+        );
+    });
+}
+
+#[test]
+fn remove_councilor_fails_with_not_councilor() {
+    let config = default_genesis_config();
+
+    build_test_externalities(config).execute_with(|| {
+        Mocks::run_full_council_cycle(1, &[], 0);
+
+        let not_councilor_id = CANDIDATE_BASE_ID + 100;
+
+        Mocks::remove_councilor(
+            OriginType::Root,
+            not_councilor_id,
+            None,
+            Err(Error::<Runtime>::NotCouncilor),
+        );
+    });
+}
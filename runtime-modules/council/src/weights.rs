@@ -47,6 +47,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn set_budget_increment() -> Weight;
 	fn set_councilor_reward() -> Weight;
+	fn remove_councilor() -> Weight;
 	fn funding_request(_i: u32, ) -> Weight;
 	fn try_process_budget_refill_budget_only() -> Weight;
 	fn try_process_budget_payout_council_members_only() -> Weight;
@@ -77,6 +78,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		(29_330_000 as Weight)
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Council CouncilMembers (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_councilor() -> Weight {
+		(71_486_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 	// Storage: Council Budget (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn funding_request(i: u32, ) -> Weight {
@@ -215,6 +224,9 @@ impl WeightInfo for () {
 	fn set_councilor_reward() -> Weight {
 		0
 	}
+	fn remove_councilor() -> Weight {
+		0
+	}
 	fn funding_request(i: u32, ) -> Weight {
 		0
 	}
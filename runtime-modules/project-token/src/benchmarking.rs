@@ -35,6 +35,12 @@ const DEFAULT_SPLIT_PAYOUT: u64 = 2_000_000;
 const DEFAULT_SPLIT_PARTICIPATION: u64 =
     DEFAULT_SPLIT_PAYOUT * DEFAULT_TOKEN_ISSUANCE / DEFAULT_SPLIT_ALLOCATION;
 
+// Amm
+const DEFAULT_AMM_SLOPE: u32 = 10;
+const DEFAULT_AMM_INTERCEPT: u32 = 1_000_000;
+const DEFAULT_AMM_TRADE: u32 = 100;
+const DEFAULT_AMM_TRADING_FEE: Permill = Permill::from_percent(1);
+
 // Patronage
 const DEFAULT_PATRONAGE: YearlyRate = YearlyRate(Permill::from_percent(1));
 // Metadata
@@ -155,6 +161,20 @@ fn init_token_sale<T: Config>(token_id: T::TokenId) -> Result<TokenSaleId, Dispa
     Ok(sale_id)
 }
 
+fn activate_amm<T: Config>(token_id: T::TokenId) -> DispatchResult {
+    // make sure fee destination can receive fees
+    let _ =
+        Joy::<T>::deposit_creating(&token_owner_account::<T>(), T::JoyExistentialDeposit::get());
+    Token::<T>::activate_amm(
+        token_id,
+        Some(token_owner_account::<T>()),
+        AmmParamsOf::<T> {
+            slope: DEFAULT_AMM_SLOPE.into(),
+            intercept: DEFAULT_AMM_INTERCEPT.into(),
+        },
+    )
+}
+
 fn issue_revenue_split<T: Config>(token_id: T::TokenId, forced_id: Option<u32>) -> DispatchResult {
     // top up owner JOY balance
     let _ = Joy::<T>::deposit_creating(
@@ -585,6 +605,103 @@ benchmarks! {
             ).into()
         );
     }
+
+    // Worst case scenario:
+    // - new account is created
+    // - trading fee is set
+    // - amm.fee_destination.is_some()
+    buy_on_amm {
+        create_owner::<T>();
+        let participant = account::<T::AccountId>("participant", 0, SEED);
+        let member_id = create_member::<T>(&participant, b"participant");
+        let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
+
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        activate_amm::<T>(token_id)?;
+        BloatBond::<T>::set(bloat_bond);
+        AmmTradingFee::set(DEFAULT_AMM_TRADING_FEE);
+
+        let amm = Token::<T>::token_info_by_id(token_id).amm_curve.unwrap();
+        let joy_amount = amm.buy_price::<T>(DEFAULT_AMM_TRADE.into()).unwrap();
+        let joy_paid = joy_amount + DEFAULT_AMM_TRADING_FEE.mul_floor(joy_amount);
+        let _ = Joy::<T>::deposit_creating(
+            &participant,
+            T::JoyExistentialDeposit::get() + bloat_bond + joy_paid
+        );
+    }: _(
+        RawOrigin::Signed(participant.clone()),
+        token_id,
+        member_id,
+        DEFAULT_AMM_TRADE.into(),
+        joy_paid
+    )
+    verify {
+        assert_eq!(
+            Token::<T>::account_info_by_token_and_member(token_id, &member_id).amount,
+            DEFAULT_AMM_TRADE.into()
+        );
+        assert_last_event::<T>(
+            RawEvent::TokensBoughtOnAmm(
+                token_id,
+                member_id,
+                DEFAULT_AMM_TRADE.into(),
+                joy_paid
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - trading fee is set
+    // - amm.fee_destination.is_some()
+    sell_on_amm {
+        create_owner::<T>();
+        let participant = account::<T::AccountId>("participant", 0, SEED);
+        let member_id = create_member::<T>(&participant, b"participant");
+        let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
+
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        activate_amm::<T>(token_id)?;
+        BloatBond::<T>::set(bloat_bond);
+        AmmTradingFee::set(DEFAULT_AMM_TRADING_FEE);
+
+        let amm = Token::<T>::token_info_by_id(token_id).amm_curve.unwrap();
+        let joy_amount = amm.buy_price::<T>(DEFAULT_AMM_TRADE.into()).unwrap();
+        let joy_paid = joy_amount + DEFAULT_AMM_TRADING_FEE.mul_floor(joy_amount);
+        let _ = Joy::<T>::deposit_creating(
+            &participant,
+            T::JoyExistentialDeposit::get() + bloat_bond + joy_paid
+        );
+        Token::<T>::buy_on_amm(
+            RawOrigin::Signed(participant.clone()).into(),
+            token_id,
+            member_id,
+            DEFAULT_AMM_TRADE.into(),
+            joy_paid
+        )?;
+
+        let amm = Token::<T>::token_info_by_id(token_id).amm_curve.unwrap();
+        let joy_amount = amm.sell_price::<T>(DEFAULT_AMM_TRADE.into()).unwrap();
+        let joy_received = joy_amount - DEFAULT_AMM_TRADING_FEE.mul_floor(joy_amount);
+    }: _(
+        RawOrigin::Signed(participant.clone()),
+        token_id,
+        member_id,
+        DEFAULT_AMM_TRADE.into(),
+        joy_received
+    )
+    verify {
+        assert!(
+            Token::<T>::account_info_by_token_and_member(token_id, &member_id).amount.is_zero()
+        );
+        assert_last_event::<T>(
+            RawEvent::TokensSoldOnAmm(
+                token_id,
+                member_id,
+                DEFAULT_AMM_TRADE.into(),
+                joy_received
+            ).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -641,4 +758,18 @@ mod tests {
             assert_ok!(Token::test_benchmark_burn());
         });
     }
+
+    #[test]
+    fn test_buy_on_amm() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_buy_on_amm());
+        });
+    }
+
+    #[test]
+    fn test_sell_on_amm() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_sell_on_amm());
+        });
+    }
 }
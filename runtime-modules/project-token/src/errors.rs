@@ -159,5 +159,36 @@ decl_error! {
 
         /// Amount of tokens to burn exceeds total amount of tokens owned by the account
         BurnAmountGreaterThanAccountTokensAmount,
+
+        // ------ AMM ------------------------------------------------------
+
+        /// The token has no active AMM
+        AmmNotActive,
+
+        /// AMM slope cannot be zero
+        AmmSlopeIsZero,
+
+        /// Amount of tokens to buy or sell on the AMM cannot be zero
+        AmmTradeAmountIsZero,
+
+        /// Amount of tokens to sell exceeds the amount of tokens minted through the AMM
+        InsufficientAmmProvidedSupply,
+
+        /// JOY amount of the AMM trade is outside of the slippage bound provided by the user
+        AmmSlippageToleranceExceeded,
+
+        /// Amount of tokens minted through the AMM exceeds the AMM deactivation threshold
+        AmmOutstandingSupplyTooLarge,
+
+        /// Cannot deissue token with an active AMM
+        CannotDeissueTokenWithActiveAmm,
+
+        // ------ Escrow ------------------------------------------------------
+
+        /// Amount of tokens to release exceeds the amount of tokens held in escrow
+        InsufficientEscrowedTokens,
+
+        /// Cannot dust an account which still has tokens held in escrow
+        CannotDustAccountWithEscrowedTokens,
     }
 }
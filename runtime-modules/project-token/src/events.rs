@@ -1,7 +1,7 @@
 #![allow(clippy::unused_unit)]
 
 use crate::types::{
-    JoyBalanceOf, RevenueSplitId, TokenIssuanceParametersOf, TokenSaleId, TokenSaleOf,
+    AmmCurveOf, JoyBalanceOf, RevenueSplitId, TokenIssuanceParametersOf, TokenSaleId, TokenSaleOf,
     TransferPolicyOf, ValidatedTransfersOf,
 };
 use common::MembershipTypes;
@@ -22,6 +22,7 @@ decl_event! {
         TokenIssuanceParameters = TokenIssuanceParametersOf<T>,
         ValidatedTransfers = ValidatedTransfersOf<T>,
         TokenSale = TokenSaleOf<T>,
+        AmmCurve = AmmCurveOf<T>,
 
     {
         /// Token amount is transferred from src to dst
@@ -155,5 +156,49 @@ decl_event! {
         /// - member id
         /// - number of tokens burned
         TokensBurned(TokenId, MemberId, Balance),
+
+        /// AMM activated
+        /// Params:
+        /// - token id
+        /// - AMM curve
+        AmmActivated(TokenId, AmmCurve),
+
+        /// Tokens bought on AMM
+        /// Params:
+        /// - token id
+        /// - buyer's member id
+        /// - amount of tokens bought
+        /// - amount of JOY paid (including trading fee)
+        TokensBoughtOnAmm(TokenId, MemberId, Balance, JoyBalance),
+
+        /// Tokens sold on AMM
+        /// Params:
+        /// - token id
+        /// - seller's member id
+        /// - amount of tokens sold
+        /// - amount of JOY received (excluding trading fee)
+        TokensSoldOnAmm(TokenId, MemberId, Balance, JoyBalance),
+
+        /// AMM deactivated
+        /// Params:
+        /// - token id
+        /// - account receiving the remaining AMM reserve
+        /// - amount of JOY remaining in the AMM reserve
+        AmmDeactivated(TokenId, AccountId, JoyBalance),
+
+        /// Tokens locked in escrow
+        /// Params:
+        /// - token id
+        /// - member id of the account the tokens were taken from
+        /// - amount of tokens locked
+        TokensLockedInEscrow(TokenId, MemberId, Balance),
+
+        /// Escrowed tokens released
+        /// Params:
+        /// - token id
+        /// - member id of the member who escrowed the tokens
+        /// - member id of the account receiving the tokens
+        /// - amount of tokens released
+        TokensReleasedFromEscrow(TokenId, MemberId, MemberId, Balance),
     }
 }
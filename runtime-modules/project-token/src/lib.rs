@@ -50,6 +50,8 @@ use storage::UploadParameters;
 mod benchmarking;
 mod errors;
 mod events;
+mod migrations;
+pub mod runtime_api;
 mod tests;
 pub mod traits;
 pub mod types;
@@ -166,6 +168,20 @@ decl_storage! { generate_storage_info
 
         /// Platform fee (percentage) charged on top of each sale purchase (in JOY) and burned
         pub SalePlatformFee get(fn sale_platform_fee) config(): Permill;
+
+        /// Trading fee (percentage) charged on each AMM buy / sell (in JOY)
+        pub AmmTradingFee get(fn amm_trading_fee) config(): Permill;
+
+        /// Maximum share of the token supply that can be minted through the AMM (and not yet
+        /// sold back) in order for the AMM to be deactivated
+        pub AmmDeactivationThreshold get(fn amm_deactivation_threshold) config(): Permill;
+
+        /// Double map TokenId x MemberId => amount of member's tokens held in escrow by other
+        /// pallets (ie. nft auction bids priced in creator tokens)
+        pub EscrowedAmountByTokenAndMember get(fn escrowed_amount_by_token_and_member):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) T::MemberId => TokenBalanceOf<T>;
     }
 
     add_extra_genesis {
@@ -353,6 +369,7 @@ decl_module! {
         ///   controller account
         /// - `token_id` x `member_id` account must be an empty account
         ///   (`account_data.amount` == 0)
+        /// - `token_id` x `member_id` account must have no tokens held in escrow
         /// Postconditions:
         /// - Account information for `token_id` x `member_id` removed from storage
         /// - bloat bond refunded to `member_id` controller account
@@ -379,6 +396,11 @@ decl_module! {
                 &account_to_remove_info,
             )?;
 
+            ensure!(
+                Self::escrowed_amount_by_token_and_member(token_id, member_id).is_zero(),
+                Error::<T>::CannotDustAccountWithEscrowedTokens,
+            );
+
             // == MUTATION SAFE ==
             AccountInfoByTokenAndMember::<T>::remove(token_id, &member_id);
 
@@ -538,13 +560,7 @@ decl_module! {
             let sale_id = token_data.next_sale_id
                 .checked_sub(1)
                 .ok_or(Error::<T>::ArithmeticError)?;
-            let platform_fee = Self::sale_platform_fee();
-            let joy_amount = sale.unit_price.saturating_mul(amount.into());
-            let burn_amount = if sale.earnings_destination.is_some() {
-                platform_fee.mul_floor(joy_amount)
-            } else {
-                joy_amount
-            };
+            let (joy_amount, burn_amount) = Self::sale_purchase_price(&sale, amount);
             let transfer_amount = joy_amount.saturating_sub(burn_amount);
             let account_data = Self::ensure_account_data_exists(token_id, &member_id).ok();
             let bloat_bond = Self::bloat_bond();
@@ -803,6 +819,193 @@ decl_module! {
             Self::deposit_event(RawEvent::RevenueSplitLeft(token_id, member_id, staking_info.amount));
             Ok(())
         }
+
+        /// Mint tokens by buying them on the token's bonding curve AMM
+        ///
+        /// Preconditions:
+        /// - `amount` must be > 0
+        /// - origin signer must be `member_id` member controller account
+        /// - token by `token_id` must exist
+        /// - token must have an active AMM
+        /// - token supply can be modified (there is no active revenue split)
+        /// - if token is Permissioned: `token_id` x `member_id` account must exist
+        /// - let `joy_amount = amm.buy_price(amount)` and
+        ///   `fee_amount = amm_trading_fee.mul_floor(joy_amount)`, then:
+        ///   - `joy_amount + fee_amount` must be <= `max_joy_amount`
+        ///   - sender must have usable JOY balance >= `joy_amount + fee_amount`
+        ///     (+ `bloat_bond` in case new account needs to be created)
+        ///
+        /// Postconditions:
+        /// - `joy_amount` JOY transferred from sender to the module treasury account
+        /// - `fee_amount` JOY transferred from sender to `amm.fee_destination`
+        ///   (or burned if `amm.fee_destination` is None)
+        /// - if new token account created: `bloat_bond` transferred from sender to treasury
+        /// - buyer's account token amount increased by `amount`
+        /// - token supply increased by `amount`
+        /// - `amm.provided_supply` increased by `amount`, `amm.reserve` increased by `joy_amount`
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::buy_on_amm()]
+        pub fn buy_on_amm(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+            amount: TokenBalanceOf<T>,
+            max_joy_amount: JoyBalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(!amount.is_zero(), Error::<T>::AmmTradeAmountIsZero);
+
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            let token_data = Self::ensure_token_exists(token_id)?;
+            let amm = token_data.amm_curve.clone().ok_or(Error::<T>::AmmNotActive)?;
+            token_data.ensure_can_modify_supply::<T>()?;
+
+            let account_data = Self::ensure_account_data_exists(token_id, &member_id).ok();
+            if let TransferPolicy::Permissioned(_) = token_data.transfer_policy {
+                ensure!(account_data.is_some(), Error::<T>::AccountInformationDoesNotExist);
+            }
+
+            let joy_amount = amm.buy_price::<T>(amount)?;
+            let fee_amount = Self::amm_trading_fee().mul_floor(joy_amount);
+            let joy_paid = joy_amount.saturating_add(fee_amount);
+            ensure!(joy_paid <= max_joy_amount, Error::<T>::AmmSlippageToleranceExceeded);
+
+            let bloat_bond = Self::bloat_bond();
+            let total_cost = match account_data.as_ref() {
+                Some(_) => joy_paid,
+                None => joy_paid.saturating_add(bloat_bond),
+            };
+            ensure!(
+                has_sufficient_balance_for_payment::<T>(&sender, total_cost),
+                Error::<T>::InsufficientJoyBalance
+            );
+
+            // == MUTATION SAFE ==
+
+            let treasury = Self::module_treasury_account();
+            Self::transfer_joy(&sender, &treasury, joy_amount)?;
+            Self::pay_amm_fee(&sender, amm.fee_destination.as_ref(), fee_amount)?;
+
+            if account_data.is_some() {
+                AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |acc_data| {
+                    acc_data.increase_amount_by(amount);
+                });
+            } else {
+                Self::transfer_joy(&sender, &treasury, bloat_bond)?;
+                Self::do_insert_new_account_for_token(
+                    token_id,
+                    &member_id,
+                    AccountDataOf::<T>::new_with_amount_and_bond(
+                        amount,
+                        // No restrictions on repayable bloat bond,
+                        // since only usable balance is allowed
+                        RepayableBloatBond::new(bloat_bond, None)
+                    )
+                );
+            }
+
+            TokenInfoById::<T>::mutate(token_id, |token_data| {
+                token_data.increase_supply_by(amount);
+                if let Some(amm) = token_data.amm_curve.as_mut() {
+                    amm.record_buy(amount, joy_amount);
+                }
+            });
+
+            Self::deposit_event(RawEvent::TokensBoughtOnAmm(token_id, member_id, amount, joy_paid));
+
+            Ok(())
+        }
+
+        /// Burn tokens by selling them on the token's bonding curve AMM
+        ///
+        /// Preconditions:
+        /// - `amount` must be > 0
+        /// - origin signer must be `member_id` member controller account
+        /// - token by `token_id` must exist
+        /// - token must have an active AMM
+        /// - token supply can be modified (there is no active revenue split)
+        /// - `token_id` x `member_id` account must exist
+        /// - account's transferrable token balance must be >= `amount`
+        /// - `amount` must be <= `amm.provided_supply`
+        /// - let `joy_amount = amm.sell_price(amount)` and
+        ///   `fee_amount = amm_trading_fee.mul_floor(joy_amount)`, then
+        ///   `joy_amount - fee_amount` must be >= `min_joy_amount`
+        ///
+        /// Postconditions:
+        /// - `joy_amount - fee_amount` JOY transferred from the module treasury account to sender
+        /// - `fee_amount` JOY transferred from the module treasury account to
+        ///   `amm.fee_destination` (or burned if `amm.fee_destination` is None)
+        /// - seller's account token amount decreased by `amount`
+        /// - token supply decreased by `amount`
+        /// - `amm.provided_supply` decreased by `amount`, `amm.reserve` decreased by `joy_amount`
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::sell_on_amm()]
+        pub fn sell_on_amm(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+            amount: TokenBalanceOf<T>,
+            min_joy_amount: JoyBalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(!amount.is_zero(), Error::<T>::AmmTradeAmountIsZero);
+
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            let token_data = Self::ensure_token_exists(token_id)?;
+            let amm = token_data.amm_curve.clone().ok_or(Error::<T>::AmmNotActive)?;
+            token_data.ensure_can_modify_supply::<T>()?;
+
+            let account_data = Self::ensure_account_data_exists(token_id, &member_id)?;
+            account_data.ensure_can_transfer::<T>(Self::current_block(), amount)?;
+
+            let joy_amount = amm.sell_price::<T>(amount)?;
+            let fee_amount = Self::amm_trading_fee().mul_floor(joy_amount);
+            let joy_received = joy_amount.saturating_sub(fee_amount);
+            ensure!(joy_received >= min_joy_amount, Error::<T>::AmmSlippageToleranceExceeded);
+
+            let treasury = Self::module_treasury_account();
+            Self::ensure_can_transfer_joy(&treasury, joy_amount)?;
+
+            // == MUTATION SAFE ==
+
+            Self::transfer_joy(&treasury, &sender, joy_received)?;
+            Self::pay_amm_fee(&treasury, amm.fee_destination.as_ref(), fee_amount)?;
+
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, &member_id, |acc_data| {
+                acc_data.decrease_amount_by(amount);
+            });
+
+            TokenInfoById::<T>::mutate(token_id, |token_data| {
+                token_data.decrease_supply_by(amount);
+                if let Some(amm) = token_data.amm_curve.as_mut() {
+                    amm.record_sell(amount, joy_amount);
+                }
+            });
+
+            Self::deposit_event(RawEvent::TokensSoldOnAmm(token_id, member_id, amount, joy_received));
+
+            Ok(())
+        }
     }
 }
 
@@ -817,6 +1020,8 @@ impl<T: Config>
         TokenSaleParamsOf<T>,
         UploadContextOf<T>,
         TransfersWithVestingOf<T>,
+        AmmParamsOf<T>,
+        TokenBalanceOf<T>,
     > for Module<T>
 {
     /// Establish whether there's an unfinalized revenue split
@@ -1311,6 +1516,167 @@ impl<T: Config>
         Ok(())
     }
 
+    /// Activate the bonding curve AMM for the token
+    ///
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - token must be in Idle offering state
+    /// - previous sale must be finalized (`token_data.sale.is_none()`)
+    /// - `params.slope` must be > 0
+    ///
+    /// Postconditions:
+    /// - `token_data.amm_curve` set according to `params`, with empty provided supply
+    ///   and reserve and trading fees going to `fee_destination`
+    fn activate_amm(
+        token_id: T::TokenId,
+        fee_destination: Option<T::AccountId>,
+        params: AmmParamsOf<T>,
+    ) -> DispatchResult {
+        let token_data = Self::ensure_token_exists(token_id)?;
+        OfferingStateOf::<T>::ensure_idle_of::<T>(&token_data)?;
+        ensure!(
+            token_data.sale.is_none(),
+            Error::<T>::PreviousSaleNotFinalized
+        );
+        ensure!(!params.slope.is_zero(), Error::<T>::AmmSlopeIsZero);
+
+        // == MUTATION SAFE ==
+
+        let amm_curve = AmmCurveOf::<T>::from_params(params, fee_destination);
+        TokenInfoById::<T>::mutate(token_id, |token_data| {
+            token_data.amm_curve = Some(amm_curve.clone());
+        });
+
+        Self::deposit_event(RawEvent::AmmActivated(token_id, amm_curve));
+        Ok(())
+    }
+
+    /// Deactivate the bonding curve AMM of the token
+    ///
+    /// Returns the amount of JOY remaining in the AMM reserve.
+    ///
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - token must have an active AMM
+    /// - `amm.provided_supply` must be <= `amm_deactivation_threshold * token.total_supply`
+    ///
+    /// Postconditions:
+    /// - `amm.reserve` JOY transferred from the module treasury account to
+    ///   `reserve_destination`
+    /// - `token_data.amm_curve` is set to None
+    fn deactivate_amm(
+        token_id: T::TokenId,
+        reserve_destination: T::AccountId,
+    ) -> Result<JoyBalanceOf<T>, DispatchError> {
+        let token_data = Self::ensure_token_exists(token_id)?;
+        let amm = token_data.amm_curve.ok_or(Error::<T>::AmmNotActive)?;
+        ensure!(
+            amm.provided_supply
+                <= Self::amm_deactivation_threshold().mul_floor(token_data.total_supply),
+            Error::<T>::AmmOutstandingSupplyTooLarge
+        );
+
+        // == MUTATION SAFE ==
+
+        if !amm.reserve.is_zero() {
+            let treasury = Self::module_treasury_account();
+            Self::transfer_joy(&treasury, &reserve_destination, amm.reserve)?;
+        }
+
+        TokenInfoById::<T>::mutate(token_id, |token_data| {
+            token_data.amm_curve = None;
+        });
+
+        Self::deposit_event(RawEvent::AmmDeactivated(
+            token_id,
+            reserve_destination,
+            amm.reserve,
+        ));
+
+        Ok(amm.reserve)
+    }
+
+    /// Move tokens from member's account into escrow (ie. to back an nft auction bid)
+    ///
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - `token_id` x `member_id` account must exist
+    /// - `token_id` x `member_id` account transferrable balance must be >= `amount`
+    ///
+    /// Postconditions:
+    /// - `token_id` x `member_id` account amount decreased by `amount`
+    /// - `token_id` x `member_id` escrowed amount increased by `amount`
+    /// - token supply is unchanged
+    fn lock_in_escrow(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        amount: TokenBalanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_token_exists(token_id)?;
+        let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+        account_info.ensure_can_transfer::<T>(Self::current_block(), amount)?;
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_info| {
+            account_info.decrease_amount_by(amount);
+        });
+
+        EscrowedAmountByTokenAndMember::<T>::mutate(token_id, member_id, |escrowed| {
+            *escrowed = escrowed.saturating_add(amount);
+        });
+
+        Self::deposit_event(RawEvent::TokensLockedInEscrow(token_id, member_id, amount));
+
+        Ok(())
+    }
+
+    /// Release tokens escrowed by `src_member_id` into `dst_member_id` account
+    /// (the same member in case of a refund)
+    ///
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - `token_id` x `src_member_id` escrowed amount must be >= `amount`
+    /// - `token_id` x `dst_member_id` account must exist
+    ///
+    /// Postconditions:
+    /// - `token_id` x `src_member_id` escrowed amount decreased by `amount`
+    /// - `token_id` x `dst_member_id` account amount increased by `amount`
+    /// - token supply is unchanged
+    fn release_from_escrow(
+        token_id: T::TokenId,
+        src_member_id: T::MemberId,
+        dst_member_id: T::MemberId,
+        amount: TokenBalanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_token_exists(token_id)?;
+        let escrowed = Self::escrowed_amount_by_token_and_member(token_id, src_member_id);
+        ensure!(escrowed >= amount, Error::<T>::InsufficientEscrowedTokens);
+        Self::ensure_account_data_exists(token_id, &dst_member_id)?;
+
+        // == MUTATION SAFE ==
+
+        let escrowed_left = escrowed.saturating_sub(amount);
+        if escrowed_left.is_zero() {
+            EscrowedAmountByTokenAndMember::<T>::remove(token_id, src_member_id);
+        } else {
+            EscrowedAmountByTokenAndMember::<T>::insert(token_id, src_member_id, escrowed_left);
+        }
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, dst_member_id, |account_info| {
+            account_info.increase_amount_by(amount);
+        });
+
+        Self::deposit_event(RawEvent::TokensReleasedFromEscrow(
+            token_id,
+            src_member_id,
+            dst_member_id,
+            amount,
+        ));
+
+        Ok(())
+    }
+
     /// Allows the issuer to finalize an ended creator token sale and recover any leftover
     /// tokens that were not sold.
     ///
@@ -1588,6 +1954,11 @@ impl<T: Config> Module<T> {
             Error::<T>::CannotDeissueTokenWithOutstandingAccounts,
         );
 
+        ensure!(
+            token_info.amm_curve.is_none(),
+            Error::<T>::CannotDeissueTokenWithActiveAmm,
+        );
+
         // This is a extra, since when no account exists -> total_supply == 0
         debug_assert!(token_info.total_supply.is_zero());
 
@@ -1600,7 +1971,20 @@ impl<T: Config> Module<T> {
         purchase_amount: <T as Config>::Balance,
         cap: <T as Config>::Balance,
     ) -> DispatchResult {
-        let tokens_purchased = account_data
+        let tokens_purchased = Self::sale_purchased_amount(sale_id, account_data);
+        ensure!(
+            tokens_purchased.saturating_add(purchase_amount) <= cap,
+            Error::<T>::SalePurchaseCapExceeded
+        );
+        Ok(())
+    }
+
+    /// Amount of tokens purchased on sale `sale_id` by the owner of `account_data`
+    pub(crate) fn sale_purchased_amount(
+        sale_id: TokenSaleId,
+        account_data: &Option<AccountDataOf<T>>,
+    ) -> TokenBalanceOf<T> {
+        account_data
             .as_ref()
             .map_or(TokenBalanceOf::<T>::zero(), |ad| {
                 match ad.last_sale_total_purchased_amount {
@@ -1609,12 +1993,111 @@ impl<T: Config> Module<T> {
                     }
                     _ => TokenBalanceOf::<T>::zero(),
                 }
-            });
-        ensure!(
-            tokens_purchased.saturating_add(purchase_amount) <= cap,
-            Error::<T>::SalePurchaseCapExceeded
-        );
-        Ok(())
+            })
+    }
+
+    /// Computes the JOY price of `amount` tokens on `sale` together with the part of it
+    /// that gets burned (platform fee, or the whole price if the sale has no earnings destination)
+    pub(crate) fn sale_purchase_price(
+        sale: &TokenSaleOf<T>,
+        amount: TokenBalanceOf<T>,
+    ) -> (JoyBalanceOf<T>, JoyBalanceOf<T>) {
+        let joy_amount = sale.unit_price.saturating_mul(amount.into());
+        let burn_amount = if sale.earnings_destination.is_some() {
+            Self::sale_platform_fee().mul_floor(joy_amount)
+        } else {
+            joy_amount
+        };
+        (joy_amount, burn_amount)
+    }
+
+    /// Quote a purchase of `amount` tokens by `member_id` on the active sale of `token_id`
+    /// without executing it.
+    ///
+    /// Fails if the token doesn't exist or has no active sale. Any other
+    /// `purchase_tokens_on_sale` precondition the purchase wouldn't satisfy is reported in
+    /// `violations`, except for the buyer's JOY balance, which is compared against `total_cost`
+    /// by the caller.
+    pub fn quote_sale_purchase(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        amount: TokenBalanceOf<T>,
+    ) -> Result<SalePurchaseQuoteOf<T>, DispatchError> {
+        let token_data = Self::ensure_token_exists(token_id)?;
+        let sale = OfferingStateOf::<T>::ensure_sale_of::<T>(&token_data)?;
+        let sale_id = token_data
+            .next_sale_id
+            .checked_sub(1)
+            .ok_or(Error::<T>::ArithmeticError)?;
+        let account_data = Self::ensure_account_data_exists(token_id, &member_id).ok();
+        let (price, burned_amount) = Self::sale_purchase_price(&sale, amount);
+        let bloat_bond = match account_data.as_ref() {
+            Some(_) => JoyBalanceOf::<T>::zero(),
+            None => Self::bloat_bond(),
+        };
+        let vesting_schedule = sale.get_vesting_schedule(amount);
+        let cap_left = sale
+            .cap_per_member
+            .map(|cap| cap.saturating_sub(Self::sale_purchased_amount(sale_id, &account_data)));
+
+        let mut violations = Vec::new();
+        if amount.is_zero() {
+            violations.push(SalePurchaseViolation::PurchaseAmountIsZero);
+        }
+        if sale.quantity_left < amount {
+            violations.push(SalePurchaseViolation::NotEnoughTokensOnSale);
+        }
+        if cap_left.map_or(false, |cap_left| amount > cap_left) {
+            violations.push(SalePurchaseViolation::PurchaseCapExceeded);
+        }
+        if let TransferPolicy::Permissioned(_) = token_data.transfer_policy {
+            if account_data.is_none() {
+                violations.push(SalePurchaseViolation::AccountRequired);
+            }
+        }
+        if let (Some(_), Some(acc_data)) = (vesting_schedule.as_ref(), account_data.as_ref()) {
+            if acc_data
+                .ensure_can_add_or_update_vesting_schedule::<T>(
+                    Self::current_block(),
+                    VestingSource::Sale(sale_id),
+                )
+                .is_err()
+            {
+                violations.push(SalePurchaseViolation::MaxVestingSchedulesReached);
+            }
+        }
+
+        Ok(SalePurchaseQuote {
+            price,
+            burned_amount,
+            bloat_bond,
+            total_cost: price.saturating_add(bloat_bond),
+            vesting_schedule,
+            cap_left,
+            violations,
+        })
+    }
+
+    /// Quote the JOY dividend for staking `staked_amount` tokens in the current revenue split
+    /// of `token_id`.
+    ///
+    /// Fails if the token doesn't exist or has no active revenue split.
+    pub fn quote_split_dividend(
+        token_id: T::TokenId,
+        staked_amount: TokenBalanceOf<T>,
+    ) -> Result<JoyBalanceOf<T>, DispatchError> {
+        let token_info = Self::ensure_token_exists(token_id)?;
+        let split_info = token_info.revenue_split.ensure_active::<T>()?;
+
+        if staked_amount.is_zero() || token_info.total_supply.is_zero() {
+            return Ok(JoyBalanceOf::<T>::zero());
+        }
+
+        Ok(Self::compute_revenue_split_dividend(
+            staked_amount,
+            token_info.total_supply,
+            split_info.allocation,
+        ))
     }
 
     /// Returns the account for the current module used for both bloat bond & revenue split
@@ -1725,6 +2208,23 @@ impl<T: Config> Module<T> {
         )
     }
 
+    /// Transfer AMM trading fee from `payer` to `fee_destination` or burn it if no
+    /// destination was provided
+    pub(crate) fn pay_amm_fee(
+        payer: &T::AccountId,
+        fee_destination: Option<&T::AccountId>,
+        fee_amount: JoyBalanceOf<T>,
+    ) -> DispatchResult {
+        if fee_amount.is_zero() {
+            return Ok(());
+        }
+
+        match fee_destination {
+            Some(dst) => Self::transfer_joy(payer, dst, fee_amount),
+            None => burn_from_usable::<T>(payer, fee_amount).map(|_| ()),
+        }
+    }
+
     pub(crate) fn do_insert_new_account_for_token(
         token_id: T::TokenId,
         member_id: &T::MemberId,
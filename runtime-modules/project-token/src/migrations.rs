@@ -0,0 +1,63 @@
+use codec::Decode;
+use frame_support::storage::IterableStorageMap;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use sp_runtime::Permill;
+
+use crate::types::{
+    PatronageData, RevenueSplitId, RevenueSplitStateOf, TokenBalanceOf, TokenDataOf, TokenSaleId,
+    TokenSaleOf, TransferPolicy,
+};
+use crate::{Config, Module, TokenInfoById};
+
+/// Token info layout preceding the bonding curve AMM
+#[derive(Decode)]
+struct TokenDataV0<Balance, Hash, BlockNumber, TokenSale, RevenueSplitState> {
+    total_supply: Balance,
+    tokens_issued: Balance,
+    next_sale_id: TokenSaleId,
+    sale: Option<TokenSale>,
+    transfer_policy: TransferPolicy<Hash>,
+    symbol: Hash,
+    patronage_info: PatronageData<Balance, BlockNumber>,
+    accounts_number: u64,
+    revenue_split_rate: Permill,
+    revenue_split: RevenueSplitState,
+    next_revenue_split_id: RevenueSplitId,
+}
+
+type TokenDataV0Of<T> = TokenDataV0<
+    TokenBalanceOf<T>,
+    <T as frame_system::Config>::Hash,
+    <T as frame_system::Config>::BlockNumber,
+    TokenSaleOf<T>,
+    RevenueSplitStateOf<T>,
+>;
+
+impl<T: Config> Module<T> {
+    /// Migrate the token info of the existing tokens to the layout with the bonding curve AMM.
+    /// Existing tokens have no active AMM.
+    pub fn migrate_token_info_to_amm_layout() -> Weight {
+        let mut tokens_number: u64 = 0;
+
+        TokenInfoById::<T>::translate::<TokenDataV0Of<T>, _>(|_, token| {
+            tokens_number = tokens_number.saturating_add(1);
+            Some(TokenDataOf::<T> {
+                total_supply: token.total_supply,
+                tokens_issued: token.tokens_issued,
+                next_sale_id: token.next_sale_id,
+                sale: token.sale,
+                transfer_policy: token.transfer_policy,
+                symbol: token.symbol,
+                patronage_info: token.patronage_info,
+                accounts_number: token.accounts_number,
+                revenue_split_rate: token.revenue_split_rate,
+                revenue_split: token.revenue_split,
+                next_revenue_split_id: token.next_revenue_split_id,
+                amm_curve: None,
+            })
+        });
+
+        T::DbWeight::get().reads_writes(tokens_number, tokens_number)
+    }
+}
@@ -0,0 +1,31 @@
+//! Runtime API definition for the project token module.
+
+use crate::types::SalePurchaseQuote;
+use codec::Codec;
+use sp_runtime::DispatchError;
+
+sp_api::decl_runtime_apis! {
+    /// Quotes of token sale purchases and revenue split dividends for the node RPC and wallets.
+    pub trait ProjectTokenApi<TokenId, MemberId, Balance, JoyBalance, BlockNumber> where
+        TokenId: Codec,
+        MemberId: Codec,
+        Balance: Codec,
+        JoyBalance: Codec,
+        BlockNumber: Codec,
+    {
+        /// JOY cost, vesting schedule and violated preconditions of purchasing `amount` tokens
+        /// by `member_id` on the active sale of `token_id`.
+        fn quote_sale_purchase(
+            token_id: TokenId,
+            member_id: MemberId,
+            amount: Balance,
+        ) -> Result<SalePurchaseQuote<JoyBalance, Balance, BlockNumber>, DispatchError>;
+
+        /// JOY dividend for staking `staked_amount` tokens in the current revenue split of
+        /// `token_id`.
+        fn quote_split_dividend(
+            token_id: TokenId,
+            staked_amount: Balance,
+        ) -> Result<JoyBalance, DispatchError>;
+    }
+}
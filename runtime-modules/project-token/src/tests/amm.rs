@@ -0,0 +1,422 @@
+#[cfg(test)]
+use frame_support::assert_err;
+use sp_runtime::Permill;
+
+use crate::member;
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::types::{AmmCurve, Joy};
+use crate::{last_event_eq, Error, RawEvent};
+
+// price of the first `DEFAULT_AMM_BUY_AMOUNT` tokens minted through the default curve:
+// intercept * amount + slope * amount^2 / 2
+const DEFAULT_AMM_BUY_PRICE: u128 = DEFAULT_AMM_INTERCEPT * DEFAULT_AMM_BUY_AMOUNT
+    + DEFAULT_AMM_SLOPE * DEFAULT_AMM_BUY_AMOUNT * DEFAULT_AMM_BUY_AMOUNT / 2;
+
+fn build_amm_test_externalities(trading_fee: Permill) -> sp_io::TestExternalities {
+    build_test_externalities_with_balances(
+        GenesisConfigBuilder::new_empty()
+            .with_amm_trading_fee(trading_fee)
+            .build(),
+        vec![
+            (member!(1).1, ExistentialDeposit::get()),
+            (
+                member!(2).1,
+                DEFAULT_AMM_BUY_PRICE + DEFAULT_AMM_BUY_PRICE + ExistentialDeposit::get(),
+            ),
+        ],
+    )
+}
+
+// ------ ACTIVATE / DEACTIVATE ------
+
+#[test]
+fn activate_amm_fails_with_invalid_token_id() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        let result = ActivateAmmFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn activate_amm_fails_with_zero_slope() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ActivateAmmFixture::default().with_slope(0).execute_call();
+
+        assert_err!(result, Error::<Test>::AmmSlopeIsZero);
+    })
+}
+
+#[test]
+fn activate_amm_fails_with_token_sale_ongoing() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        InitTokenSaleFixture::default().execute_call().unwrap();
+
+        let result = ActivateAmmFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenIssuanceNotInIdleState);
+    })
+}
+
+#[test]
+fn activate_amm_fails_with_amm_already_active() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+
+        let result = ActivateAmmFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenIssuanceNotInIdleState);
+    })
+}
+
+#[test]
+fn activate_amm_ok_with_event_deposited() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        ActivateAmmFixture::default().execute_call().unwrap();
+
+        let expected_curve = AmmCurve {
+            slope: DEFAULT_AMM_SLOPE,
+            intercept: DEFAULT_AMM_INTERCEPT,
+            provided_supply: 0,
+            reserve: 0,
+            fee_destination: Some(member!(1).1),
+        };
+        assert_eq!(
+            Token::token_info_by_id(1).amm_curve,
+            Some(expected_curve.clone())
+        );
+        last_event_eq!(RawEvent::AmmActivated(1, expected_curve));
+    })
+}
+
+#[test]
+fn deactivate_amm_fails_with_amm_not_active() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = DeactivateAmmFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AmmNotActive);
+    })
+}
+
+#[test]
+fn deactivate_amm_fails_with_outstanding_supply_too_large() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+        BuyOnAmmFixture::default().execute_call().unwrap();
+
+        let result = DeactivateAmmFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AmmOutstandingSupplyTooLarge);
+    })
+}
+
+#[test]
+fn deactivate_amm_ok_with_reserve_transferred() {
+    build_test_externalities_with_balances(
+        GenesisConfigBuilder::new_empty()
+            .with_amm_deactivation_threshold(Permill::from_percent(1))
+            .build(),
+        vec![(
+            member!(2).1,
+            DEFAULT_AMM_BUY_PRICE + ExistentialDeposit::get(),
+        )],
+    )
+    .execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+        // DEFAULT_AMM_BUY_AMOUNT is 0.1% of the total supply
+        BuyOnAmmFixture::default().execute_call().unwrap();
+
+        DeactivateAmmFixture::default().execute_call().unwrap();
+
+        assert!(Token::token_info_by_id(1).amm_curve.is_none());
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(1).1),
+            DEFAULT_AMM_BUY_PRICE
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(Token::module_treasury_account()),
+            ExistentialDeposit::get()
+        );
+        last_event_eq!(RawEvent::AmmDeactivated(
+            1,
+            member!(1).1,
+            DEFAULT_AMM_BUY_PRICE
+        ));
+    })
+}
+
+// ------ BUY ------
+
+#[test]
+fn buy_on_amm_fails_with_amm_not_active() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = BuyOnAmmFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AmmNotActive);
+    })
+}
+
+#[test]
+fn buy_on_amm_fails_with_zero_amount() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+
+        let result = BuyOnAmmFixture::default().with_amount(0).execute_call();
+
+        assert_err!(result, Error::<Test>::AmmTradeAmountIsZero);
+    })
+}
+
+#[test]
+fn buy_on_amm_fails_with_slippage_tolerance_exceeded() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+
+        let result = BuyOnAmmFixture::default()
+            .with_max_joy_amount(DEFAULT_AMM_BUY_PRICE - 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AmmSlippageToleranceExceeded);
+    })
+}
+
+#[test]
+fn buy_on_amm_fails_with_insufficient_joy_balance() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+
+        let result = BuyOnAmmFixture::default()
+            .with_sender(member!(3).1)
+            .with_member_id(member!(3).0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+    })
+}
+
+#[test]
+fn buy_on_amm_fails_with_active_revenue_split() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+        increase_account_balance(&member!(1).1, DEFAULT_SPLIT_REVENUE);
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+
+        let result = BuyOnAmmFixture::default().execute_call();
+
+        assert_err!(
+            result,
+            Error::<Test>::CannotModifySupplyWhenRevenueSplitsAreActive
+        );
+    })
+}
+
+#[test]
+fn buy_on_amm_ok_with_state_and_balances_updated() {
+    let trading_fee = Permill::from_percent(10);
+    build_amm_test_externalities(trading_fee).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+        let fee_amount = trading_fee.mul_floor(DEFAULT_AMM_BUY_PRICE);
+        let buyer_balance_pre = Joy::<Test>::usable_balance(member!(2).1);
+
+        BuyOnAmmFixture::default().execute_call().unwrap();
+
+        let token_data = Token::token_info_by_id(1);
+        let amm_curve = token_data.amm_curve.unwrap();
+        assert_eq!(amm_curve.provided_supply, DEFAULT_AMM_BUY_AMOUNT);
+        assert_eq!(amm_curve.reserve, DEFAULT_AMM_BUY_PRICE);
+        assert_eq!(
+            token_data.total_supply,
+            DEFAULT_INITIAL_ISSUANCE + DEFAULT_AMM_BUY_AMOUNT
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(1, member!(2).0).amount,
+            DEFAULT_AMM_BUY_AMOUNT
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            buyer_balance_pre - DEFAULT_AMM_BUY_PRICE - fee_amount
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(1).1),
+            ExistentialDeposit::get() + fee_amount
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(Token::module_treasury_account()),
+            ExistentialDeposit::get() + DEFAULT_AMM_BUY_PRICE
+        );
+        last_event_eq!(RawEvent::TokensBoughtOnAmm(
+            1,
+            member!(2).0,
+            DEFAULT_AMM_BUY_AMOUNT,
+            DEFAULT_AMM_BUY_PRICE + fee_amount
+        ));
+    })
+}
+
+#[test]
+fn buy_on_amm_ok_with_fee_burned_when_no_fee_destination() {
+    let trading_fee = Permill::from_percent(10);
+    build_amm_test_externalities(trading_fee).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default()
+            .with_fee_destination(None)
+            .execute_call()
+            .unwrap();
+        let fee_amount = trading_fee.mul_floor(DEFAULT_AMM_BUY_PRICE);
+        let joy_issuance_pre = Joy::<Test>::total_issuance();
+
+        BuyOnAmmFixture::default().execute_call().unwrap();
+
+        assert_eq!(Joy::<Test>::total_issuance(), joy_issuance_pre - fee_amount);
+    })
+}
+
+#[test]
+fn buy_on_amm_price_increases_with_provided_supply() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+        BuyOnAmmFixture::default().execute_call().unwrap();
+
+        // second batch: intercept * a + slope * a * (2 * s + a) / 2, with s = a
+        let second_batch_price = DEFAULT_AMM_INTERCEPT * DEFAULT_AMM_BUY_AMOUNT
+            + DEFAULT_AMM_SLOPE * DEFAULT_AMM_BUY_AMOUNT * 3 * DEFAULT_AMM_BUY_AMOUNT / 2;
+        let result = BuyOnAmmFixture::default()
+            .with_max_joy_amount(second_batch_price - 1)
+            .execute_call();
+        assert_err!(result, Error::<Test>::AmmSlippageToleranceExceeded);
+
+        BuyOnAmmFixture::default()
+            .with_max_joy_amount(second_batch_price)
+            .execute_call()
+            .unwrap();
+        assert_eq!(
+            Token::token_info_by_id(1).amm_curve.unwrap().reserve,
+            DEFAULT_AMM_BUY_PRICE + second_batch_price
+        );
+    })
+}
+
+// ------ SELL ------
+
+#[test]
+fn sell_on_amm_fails_with_amm_not_active() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = SellOnAmmFixture::default()
+            .with_sender(member!(1).1)
+            .with_member_id(member!(1).0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AmmNotActive);
+    })
+}
+
+#[test]
+fn sell_on_amm_fails_with_insufficient_provided_supply() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+        BuyOnAmmFixture::default().execute_call().unwrap();
+
+        let result = SellOnAmmFixture::default()
+            .with_sender(member!(1).1)
+            .with_member_id(member!(1).0)
+            .with_amount(DEFAULT_AMM_BUY_AMOUNT + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientAmmProvidedSupply);
+    })
+}
+
+#[test]
+fn sell_on_amm_fails_with_insufficient_transferrable_balance() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+        BuyOnAmmFixture::default().execute_call().unwrap();
+
+        let result = SellOnAmmFixture::default()
+            .with_amount(DEFAULT_AMM_BUY_AMOUNT + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn sell_on_amm_fails_with_slippage_tolerance_exceeded() {
+    build_amm_test_externalities(Permill::zero()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+        BuyOnAmmFixture::default().execute_call().unwrap();
+
+        let result = SellOnAmmFixture::default()
+            .with_min_joy_amount(DEFAULT_AMM_BUY_PRICE + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AmmSlippageToleranceExceeded);
+    })
+}
+
+#[test]
+fn sell_on_amm_ok_with_state_and_balances_updated() {
+    let trading_fee = Permill::from_percent(10);
+    build_amm_test_externalities(trading_fee).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ActivateAmmFixture::default().execute_call().unwrap();
+        BuyOnAmmFixture::default().execute_call().unwrap();
+        let fee_amount = trading_fee.mul_floor(DEFAULT_AMM_BUY_PRICE);
+        let seller_balance_pre = Joy::<Test>::usable_balance(member!(2).1);
+        let fee_dst_balance_pre = Joy::<Test>::usable_balance(member!(1).1);
+
+        SellOnAmmFixture::default().execute_call().unwrap();
+
+        let token_data = Token::token_info_by_id(1);
+        let amm_curve = token_data.amm_curve.unwrap();
+        assert_eq!(amm_curve.provided_supply, 0);
+        assert_eq!(amm_curve.reserve, 0);
+        assert_eq!(token_data.total_supply, DEFAULT_INITIAL_ISSUANCE);
+        assert_eq!(
+            Token::account_info_by_token_and_member(1, member!(2).0).amount,
+            0
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            seller_balance_pre + DEFAULT_AMM_BUY_PRICE - fee_amount
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(1).1),
+            fee_dst_balance_pre + fee_amount
+        );
+        assert_eq!(
+            Joy::<Test>::usable_balance(Token::module_treasury_account()),
+            ExistentialDeposit::get()
+        );
+        last_event_eq!(RawEvent::TokensSoldOnAmm(
+            1,
+            member!(2).0,
+            DEFAULT_AMM_BUY_AMOUNT,
+            DEFAULT_AMM_BUY_PRICE - fee_amount
+        ));
+    })
+}
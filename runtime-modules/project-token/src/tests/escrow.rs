@@ -0,0 +1,149 @@
+#![cfg(test)]
+use frame_support::{assert_noop, assert_ok};
+
+use crate::tests::mock::*;
+use crate::tests::test_utils::TokenDataBuilder;
+use crate::traits::PalletToken;
+use crate::{balance, last_event_eq, member, origin, token, Error, RawEvent};
+
+fn build_escrow_test_externalities() -> sp_io::TestExternalities {
+    let (token_id, init_supply) = (token!(1), balance!(100));
+    let ((owner_id, _), (user_id, _)) = (member!(1), member!(2));
+
+    let token_data = TokenDataBuilder::new_empty().build();
+
+    let config = GenesisConfigBuilder::new_empty()
+        .with_token_and_owner(token_id, token_data, owner_id, init_supply)
+        .with_account(user_id, ConfigAccountData::default())
+        .build();
+
+    build_test_externalities(config)
+}
+
+#[test]
+fn lock_in_escrow_fails_with_invalid_token_id() {
+    build_escrow_test_externalities().execute_with(|| {
+        let result = Token::lock_in_escrow(token!(2), member!(1).0, balance!(10));
+
+        assert_noop!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn lock_in_escrow_fails_with_non_existing_account() {
+    build_escrow_test_externalities().execute_with(|| {
+        let result = Token::lock_in_escrow(token!(1), member!(3).0, balance!(10));
+
+        assert_noop!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn lock_in_escrow_fails_with_insufficient_transferrable_balance() {
+    build_escrow_test_externalities().execute_with(|| {
+        let result = Token::lock_in_escrow(token!(1), member!(1).0, balance!(101));
+
+        assert_noop!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn lock_in_escrow_ok() {
+    build_escrow_test_externalities().execute_with(|| {
+        let (token_id, (owner_id, _)) = (token!(1), member!(1));
+
+        assert_ok!(Token::lock_in_escrow(token_id, owner_id, balance!(30)));
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(token_id, owner_id).amount,
+            balance!(70)
+        );
+        assert_eq!(
+            Token::escrowed_amount_by_token_and_member(token_id, owner_id),
+            balance!(30)
+        );
+        // escrowed tokens are still part of the supply
+        assert_eq!(
+            Token::token_info_by_id(token_id).total_supply,
+            balance!(100)
+        );
+        last_event_eq!(RawEvent::TokensLockedInEscrow(
+            token_id,
+            owner_id,
+            balance!(30)
+        ));
+    })
+}
+
+#[test]
+fn release_from_escrow_fails_with_insufficient_escrowed_tokens() {
+    build_escrow_test_externalities().execute_with(|| {
+        let (token_id, (owner_id, _), (user_id, _)) = (token!(1), member!(1), member!(2));
+        assert_ok!(Token::lock_in_escrow(token_id, owner_id, balance!(30)));
+
+        let result = Token::release_from_escrow(token_id, owner_id, user_id, balance!(31));
+
+        assert_noop!(result, Error::<Test>::InsufficientEscrowedTokens);
+    })
+}
+
+#[test]
+fn release_from_escrow_fails_with_non_existing_destination_account() {
+    build_escrow_test_externalities().execute_with(|| {
+        let (token_id, (owner_id, _)) = (token!(1), member!(1));
+        assert_ok!(Token::lock_in_escrow(token_id, owner_id, balance!(30)));
+
+        let result = Token::release_from_escrow(token_id, owner_id, member!(3).0, balance!(30));
+
+        assert_noop!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn release_from_escrow_ok() {
+    build_escrow_test_externalities().execute_with(|| {
+        let (token_id, (owner_id, _), (user_id, _)) = (token!(1), member!(1), member!(2));
+        assert_ok!(Token::lock_in_escrow(token_id, owner_id, balance!(30)));
+
+        assert_ok!(Token::release_from_escrow(
+            token_id,
+            owner_id,
+            user_id,
+            balance!(20)
+        ));
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(token_id, user_id).amount,
+            balance!(20)
+        );
+        assert_eq!(
+            Token::escrowed_amount_by_token_and_member(token_id, owner_id),
+            balance!(10)
+        );
+        last_event_eq!(RawEvent::TokensReleasedFromEscrow(
+            token_id,
+            owner_id,
+            user_id,
+            balance!(20)
+        ));
+    })
+}
+
+#[test]
+fn dust_account_fails_with_tokens_held_in_escrow() {
+    build_escrow_test_externalities().execute_with(|| {
+        let (token_id, (owner_id, _), (user_id, user_acc)) = (token!(1), member!(1), member!(2));
+        assert_ok!(Token::lock_in_escrow(token_id, owner_id, balance!(30)));
+        assert_ok!(Token::release_from_escrow(
+            token_id,
+            owner_id,
+            user_id,
+            balance!(30)
+        ));
+        assert_ok!(Token::lock_in_escrow(token_id, user_id, balance!(30)));
+
+        let result = Token::dust_account(origin!(user_acc), token_id, user_id);
+
+        assert_noop!(result, Error::<Test>::CannotDustAccountWithEscrowedTokens);
+    })
+}
@@ -8,7 +8,7 @@ use crate::{
 use crate::{traits::PalletToken, types::VestingSource, SymbolsUsed};
 use frame_support::dispatch::DispatchResult;
 use frame_support::storage::{StorageDoubleMap, StorageMap};
-use sp_arithmetic::traits::One;
+use sp_arithmetic::traits::{One, Zero};
 use sp_runtime::{traits::Hash, DispatchError, Permill};
 
 use sp_std::iter::FromIterator;
@@ -71,6 +71,7 @@ pub fn default_single_data_object_upload_params() -> SingleDataObjectUploadParam
         object_creation_params: DataObjectCreationParameters {
             ipfs_content_id: Vec::from_iter(0..46),
             size: 1_000_000,
+            chunks_merkle_root: None,
         },
     }
 }
@@ -1006,3 +1007,195 @@ impl ExitRevenueSplitFixture {
         result
     }
 }
+
+pub struct ActivateAmmFixture {
+    token_id: TokenId,
+    fee_destination: Option<AccountId>,
+    params: AmmParams,
+}
+
+impl ActivateAmmFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            fee_destination: Some(member!(1).1),
+            params: AmmParams {
+                slope: DEFAULT_AMM_SLOPE,
+                intercept: DEFAULT_AMM_INTERCEPT,
+            },
+        }
+    }
+
+    pub fn with_slope(self, slope: JoyBalance) -> Self {
+        Self {
+            params: AmmParams {
+                slope,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn with_fee_destination(self, fee_destination: Option<AccountId>) -> Self {
+        Self {
+            fee_destination,
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::activate_amm(self.token_id, self.fee_destination, self.params.clone());
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct DeactivateAmmFixture {
+    token_id: TokenId,
+    reserve_destination: AccountId,
+}
+
+impl DeactivateAmmFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            reserve_destination: member!(1).1,
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::deactivate_amm(self.token_id, self.reserve_destination).map(|_| ());
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct BuyOnAmmFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+    amount: Balance,
+    max_joy_amount: JoyBalance,
+}
+
+impl BuyOnAmmFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            token_id: TokenId::one(),
+            member_id: member!(2).0,
+            amount: DEFAULT_AMM_BUY_AMOUNT,
+            max_joy_amount: JoyBalance::MAX,
+        }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_max_joy_amount(self, max_joy_amount: JoyBalance) -> Self {
+        Self {
+            max_joy_amount,
+            ..self
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::buy_on_amm(
+            Origin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.amount,
+            self.max_joy_amount,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct SellOnAmmFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+    amount: Balance,
+    min_joy_amount: JoyBalance,
+}
+
+impl SellOnAmmFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            token_id: TokenId::one(),
+            member_id: member!(2).0,
+            amount: DEFAULT_AMM_BUY_AMOUNT,
+            min_joy_amount: JoyBalance::zero(),
+        }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_min_joy_amount(self, min_joy_amount: JoyBalance) -> Self {
+        Self {
+            min_joy_amount,
+            ..self
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::sell_on_amm(
+            Origin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.amount,
+            self.min_joy_amount,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
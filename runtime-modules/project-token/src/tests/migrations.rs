@@ -0,0 +1,30 @@
+#[cfg(test)]
+use codec::Encode;
+use frame_support::storage::{unhashed, StorageMap};
+
+use crate::tests::mock::*;
+use crate::tests::test_utils::TokenDataBuilder;
+use crate::{token, TokenInfoById};
+
+#[test]
+fn migrate_token_info_to_amm_layout_ok() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        let token_id = token!(1);
+        let token_info = TokenDataBuilder::new_empty().build();
+
+        // token info encoded without the trailing `amm_curve: None`
+        let mut encoded_token_info = token_info.encode();
+        encoded_token_info.pop();
+        unhashed::put_raw(
+            &TokenInfoById::<Test>::hashed_key_for(token_id),
+            &encoded_token_info,
+        );
+        assert!(TokenInfoById::<Test>::try_get(token_id).is_err());
+
+        Token::migrate_token_info_to_amm_layout();
+
+        assert_eq!(TokenInfoById::<Test>::try_get(token_id), Ok(token_info));
+    })
+}
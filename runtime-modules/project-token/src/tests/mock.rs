@@ -37,6 +37,7 @@ pub type SingleDataObjectUploadParams = SingleDataObjectUploadParamsOf<Test>;
 pub type WhitelistParams = WhitelistParamsOf<Test>;
 pub type TokenSaleParams = TokenSaleParamsOf<Test>;
 pub type TokenSale = TokenSaleOf<Test>;
+pub type AmmParams = AmmParamsOf<Test>;
 pub type IssuanceParams = TokenIssuanceParametersOf<Test>;
 pub type VestingScheduleParams = VestingScheduleParamsOf<Test>;
 pub type IssuanceState = OfferingStateOf<Test>;
@@ -98,6 +99,18 @@ parameter_types! {
     pub const MinDistributionBucketsPerBag: u32 = 3;
     pub const MaxDistributionBucketsPerBag: u32 = 10;
     pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 5;
+    pub const MaxStorageChallengesPerBlock: u32 = 1;
+    pub const StorageChallengeResponsePeriod: u64 = 10;
+    pub const MaxStorageChallengeFailures: u32 = 3;
+    pub const StorageChallengeSlashPenalty: u128 = 10;
+    pub const DataObjectChunkSize: u64 = 1024;
+    pub const StorageOperatorFeeShare: Perbill = Perbill::zero();
+    pub const DataObjectAcceptancePeriod: u64 = 10;
+    pub const MaxStorageBucketNumber: u64 = 10;
+    pub const MaxRebalancedBagsNumber: u32 = 10;
+    pub const MaxBlacklistedDataObjectsPerCall: u32 = 10;
+    pub const MaxDataObjectsAcceptanceRemovalsPerBlock: u32 = 10;
+    pub const MaxDataObjectsMigratedPerBlock: u32 = 10;
     // constants for membership::Config
     pub const DefaultMembershipPrice: u64 = 100;
     pub const CandidateStake: u64 = 100;
@@ -117,6 +130,7 @@ frame_support::construct_runtime!(
     {
         System: frame_system,
         Balances: balances,
+        CollectiveFlip: randomness_collective_flip,
         Timestamp: pallet_timestamp,
         Membership: membership::{Pallet, Call, Storage, Event<T>},
         Storage: storage::{Pallet, Call, Storage, Event<T>},
@@ -152,6 +166,20 @@ impl storage::Config for Test {
     type DistributionWorkingGroup = DistributionWG;
     type ModuleAccountInitialBalance = ExistentialDeposit;
     type WeightInfo = ();
+    type Randomness = CollectiveFlip;
+    type MaxStorageChallengesPerBlock = MaxStorageChallengesPerBlock;
+    type StorageChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type MaxStorageChallengeFailures = MaxStorageChallengeFailures;
+    type StorageChallengeSlashPenalty = StorageChallengeSlashPenalty;
+    type DataObjectChunkSize = DataObjectChunkSize;
+    type StorageOperatorFeeShare = StorageOperatorFeeShare;
+    type DataObjectAcceptancePeriod = DataObjectAcceptancePeriod;
+    type ExpiredDataObjectsHandler = ();
+    type MaxStorageBucketNumber = MaxStorageBucketNumber;
+    type MaxRebalancedBagsNumber = MaxRebalancedBagsNumber;
+    type MaxBlacklistedDataObjectsPerCall = MaxBlacklistedDataObjectsPerCall;
+    type MaxDataObjectsAcceptanceRemovalsPerBlock = MaxDataObjectsAcceptanceRemovalsPerBlock;
+    type MaxDataObjectsMigratedPerBlock = MaxDataObjectsMigratedPerBlock;
 }
 
 impl common::MembershipTypes for Test {
@@ -178,6 +206,12 @@ impl Config for Test {
 pub struct StorageWG;
 pub struct DistributionWG;
 
+impl common::working_group::WorkingGroupStakeSlasher<Test, u128> for StorageWG {
+    fn slash_worker_stake(_worker_id: &u64, _penalty: u128) -> DispatchResult {
+        Ok(())
+    }
+}
+
 impl common::working_group::WorkingGroupBudgetHandler<u64, u128> for StorageWG {
     fn get_budget() -> u128 {
         unimplemented!()
@@ -370,6 +404,8 @@ impl balances::Config for Test {
     type WeightInfo = ();
 }
 
+impl randomness_collective_flip::Config for Test {}
+
 /// Implement membership trait for Test
 impl membership::Config for Test {
     type Event = Event;
@@ -502,6 +538,8 @@ pub struct GenesisConfigBuilder {
     pub(crate) min_revenue_split_duration: BlockNumber,
     pub(crate) min_revenue_split_time_to_start: BlockNumber,
     pub(crate) sale_platform_fee: Permill,
+    pub(crate) amm_trading_fee: Permill,
+    pub(crate) amm_deactivation_threshold: Permill,
 }
 
 /// test externalities + initial balances allocation
@@ -630,6 +668,11 @@ pub const DEFAULT_SPLIT_DURATION: u64 = 100;
 pub const DEFAULT_SPLIT_PARTICIPATION: u128 = 100_000;
 pub const DEFAULT_SPLIT_JOY_DIVIDEND: u128 = 10; // (participation / issuance) * revenue * rate
 
+// ------ Amm constants ----------------------
+pub const DEFAULT_AMM_SLOPE: u128 = 1;
+pub const DEFAULT_AMM_INTERCEPT: u128 = 10;
+pub const DEFAULT_AMM_BUY_AMOUNT: u128 = 1000;
+
 // ------ Storage Constants ------------------
 pub const STORAGE_WG_LEADER_ACCOUNT_ID: u64 = 100001;
 pub const DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID: u64 = 100002;
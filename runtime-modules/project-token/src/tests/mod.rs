@@ -1,7 +1,10 @@
 #![cfg(test)]
 
+mod amm;
 mod canonical;
+mod escrow;
 mod fixtures;
+mod migrations;
 pub mod mock;
 mod patronage;
 mod sale;
@@ -7,6 +7,7 @@ use crate::tests::fixtures::*;
 use crate::tests::mock::*;
 use crate::types::Joy;
 use crate::types::MerkleProofOf;
+use crate::types::SalePurchaseViolation;
 use crate::{member, merkle_proof, merkle_root};
 use frame_support::assert_ok;
 use sp_arithmetic::Permill;
@@ -906,6 +907,109 @@ fn unsuccesful_sale_purchase_with_invitation_locked_funds() {
     }
 }
 
+/////////////////////////////////////////////////////////
+///////////////// SALE PURCHASE QUOTES //////////////////
+/////////////////////////////////////////////////////////
+
+#[test]
+fn unsuccesful_sale_purchase_quote_when_no_sale() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        assert_eq!(
+            Token::quote_sale_purchase(1, member!(2).0, DEFAULT_SALE_PURCHASE_AMOUNT),
+            Err(Error::<Test>::NoActiveSale.into())
+        );
+    })
+}
+
+#[test]
+fn succesful_sale_purchase_quote_matches_purchase_cost() {
+    let bloat_bond = joy!(100);
+    let sale_platform_fee = Permill::from_percent(30);
+    let config = GenesisConfigBuilder::new_empty()
+        .with_bloat_bond(bloat_bond)
+        .with_sale_platform_fee(sale_platform_fee)
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        increase_account_balance(&member!(1).1, ed() + bloat_bond);
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        InitTokenSaleFixture::default().call_and_assert(Ok(()));
+        let buyer_balance =
+            ed() + bloat_bond + DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT * 2;
+        increase_account_balance(&member!(2).1, buyer_balance);
+
+        let price = DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT;
+        let sale = Token::token_info_by_id(1).sale.unwrap();
+        let quote =
+            Token::quote_sale_purchase(1, member!(2).0, DEFAULT_SALE_PURCHASE_AMOUNT).unwrap();
+        assert_eq!(quote.price, price);
+        assert_eq!(quote.burned_amount, sale_platform_fee.mul_floor(price));
+        assert_eq!(quote.bloat_bond, bloat_bond);
+        assert_eq!(quote.total_cost, price + bloat_bond);
+        assert_eq!(
+            quote.vesting_schedule,
+            sale.get_vesting_schedule(DEFAULT_SALE_PURCHASE_AMOUNT)
+        );
+        assert_eq!(quote.cap_left, None);
+        assert!(quote.violations.is_empty());
+
+        PurchaseTokensOnSaleFixture::default().call_and_assert(Ok(()));
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            buyer_balance - quote.total_cost
+        );
+
+        // No bloat bond required once the account exists
+        let quote =
+            Token::quote_sale_purchase(1, member!(2).0, DEFAULT_SALE_PURCHASE_AMOUNT).unwrap();
+        assert_eq!(quote.bloat_bond, 0);
+        assert_eq!(quote.total_cost, price);
+    })
+}
+
+#[test]
+fn succesful_sale_purchase_quote_with_violations() {
+    let config = GenesisConfigBuilder::new_empty().build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        InitTokenSaleFixture::default()
+            .with_cap_per_member(DEFAULT_SALE_PURCHASE_AMOUNT)
+            .call_and_assert(Ok(()));
+        increase_account_balance(
+            &member!(2).1,
+            ed() + DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+        PurchaseTokensOnSaleFixture::default().call_and_assert(Ok(()));
+
+        let quote = Token::quote_sale_purchase(1, member!(2).0, 0).unwrap();
+        assert_eq!(
+            quote.violations,
+            vec![SalePurchaseViolation::PurchaseAmountIsZero]
+        );
+
+        let quote = Token::quote_sale_purchase(1, member!(2).0, 1).unwrap();
+        assert_eq!(quote.cap_left, Some(0));
+        assert_eq!(
+            quote.violations,
+            vec![SalePurchaseViolation::PurchaseCapExceeded]
+        );
+
+        let quote =
+            Token::quote_sale_purchase(1, member!(2).0, DEFAULT_INITIAL_ISSUANCE + 1).unwrap();
+        assert_eq!(
+            quote.violations,
+            vec![
+                SalePurchaseViolation::NotEnoughTokensOnSale,
+                SalePurchaseViolation::PurchaseCapExceeded,
+            ]
+        );
+    })
+}
+
 /////////////////////////////////////////////////////////
 ////////////////// FINALIZE TOKEN SALE //////////////////
 /////////////////////////////////////////////////////////
@@ -674,6 +674,46 @@ fn participate_in_split_ok_with_dividends_transferred_to_claimer_joy_balance() {
     })
 }
 
+#[test]
+fn quote_split_dividend_fails_with_inactive_revenue_split() {
+    build_default_test_externalities_with_balances(vec![]).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        assert_err!(
+            Token::quote_split_dividend(1u64, DEFAULT_SPLIT_PARTICIPATION),
+            Error::<Test>::RevenueSplitNotActiveForToken
+        );
+    })
+}
+
+#[test]
+fn quote_split_dividend_ok_with_quote_equal_to_participation_dividend() {
+    build_default_test_externalities_with_balances(vec![(
+        member!(1).1,
+        DEFAULT_SPLIT_REVENUE + ExistentialDeposit::get(),
+    )])
+    .execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap(); // send participation to other acc
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+
+        // quote available before the split starts
+        assert_eq!(
+            Token::quote_split_dividend(1u64, DEFAULT_SPLIT_PARTICIPATION),
+            Ok(DEFAULT_SPLIT_JOY_DIVIDEND)
+        );
+        assert_eq!(Token::quote_split_dividend(1u64, 0), Ok(0));
+
+        increase_block_number_by(MIN_REVENUE_SPLIT_TIME_TO_START);
+        ParticipateInSplitFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            DEFAULT_SPLIT_JOY_DIVIDEND,
+        );
+    })
+}
+
 #[test]
 fn participate_in_split_ok_with_vesting_schedule_and_correct_transferrable_balance_accounting() {
     pub const TOTAL_AMOUNT: u128 = DEFAULT_SALE_PURCHASE_AMOUNT * 2;
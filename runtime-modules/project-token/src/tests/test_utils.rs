@@ -48,6 +48,7 @@ impl TokenDataBuilder {
             revenue_split: self.revenue_split,
             next_revenue_split_id: 0u32,
             revenue_split_rate: self.revenue_split_rate,
+            amm_curve: None,
         }
     }
 
@@ -121,6 +122,8 @@ impl GenesisConfigBuilder {
             min_revenue_split_duration: MIN_REVENUE_SPLIT_DURATION.into(),
             min_revenue_split_time_to_start: MIN_REVENUE_SPLIT_TIME_TO_START.into(),
             sale_platform_fee: Permill::zero(),
+            amm_trading_fee: Permill::zero(),
+            amm_deactivation_threshold: Permill::zero(),
         }
     }
 
@@ -162,6 +165,20 @@ impl GenesisConfigBuilder {
         }
     }
 
+    pub fn with_amm_trading_fee(self, amm_trading_fee: Permill) -> Self {
+        Self {
+            amm_trading_fee,
+            ..self
+        }
+    }
+
+    pub fn with_amm_deactivation_threshold(self, amm_deactivation_threshold: Permill) -> Self {
+        Self {
+            amm_deactivation_threshold,
+            ..self
+        }
+    }
+
     // add account & updates token supply
     pub fn with_account(
         mut self,
@@ -194,6 +211,8 @@ impl GenesisConfigBuilder {
             min_revenue_split_duration: self.min_revenue_split_duration,
             min_revenue_split_time_to_start: self.min_revenue_split_time_to_start,
             sale_platform_fee: self.sale_platform_fee,
+            amm_trading_fee: self.amm_trading_fee,
+            amm_deactivation_threshold: self.amm_deactivation_threshold,
         }
     }
 }
@@ -14,6 +14,8 @@ pub trait PalletToken<
     TokenSaleParams,
     UploadContext,
     TransfersWithVesting,
+    AmmParams,
+    TokenBalance,
 >
 {
     /// Issue token with specified characteristics
@@ -76,6 +78,34 @@ pub trait PalletToken<
     /// Finalize creator token sale and recover unsold tokens
     fn finalize_token_sale(token_id: TokenId) -> Result<JoyBalance, DispatchError>;
 
+    /// Activate bonding curve AMM for the token, with trading fees going to `fee_destination`
+    fn activate_amm(
+        token_id: TokenId,
+        fee_destination: Option<AccountId>,
+        params: AmmParams,
+    ) -> DispatchResult;
+
+    /// Deactivate token's AMM, sending the remaining JOY reserve to `reserve_destination`
+    fn deactivate_amm(
+        token_id: TokenId,
+        reserve_destination: AccountId,
+    ) -> Result<JoyBalance, DispatchError>;
+
+    /// Move `amount` of `member_id` account's transferrable tokens into escrow
+    fn lock_in_escrow(
+        token_id: TokenId,
+        member_id: MemberId,
+        amount: TokenBalance,
+    ) -> DispatchResult;
+
+    /// Release `amount` of tokens escrowed by `src_member_id` into `dst_member_id` account
+    fn release_from_escrow(
+        token_id: TokenId,
+        src_member_id: MemberId,
+        dst_member_id: MemberId,
+        amount: TokenBalance,
+    ) -> DispatchResult;
+
     /// Establish whether the token has an unfinalized revenue split
     fn is_revenue_split_inactive(token_id: TokenId) -> bool;
 
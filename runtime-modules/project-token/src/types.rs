@@ -82,7 +82,7 @@ pub struct AccountData<Balance, StakingStatus, RepayableBloatBond, VestingSchedu
 /// Info for the token
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
-pub struct TokenData<Balance, Hash, BlockNumber, TokenSale, RevenueSplitState> {
+pub struct TokenData<Balance, Hash, BlockNumber, TokenSale, RevenueSplitState, AmmCurve> {
     /// Current token's total supply (tokens_issued - tokens_burned)
     pub total_supply: Balance,
 
@@ -115,6 +115,9 @@ pub struct TokenData<Balance, Hash, BlockNumber, TokenSale, RevenueSplitState> {
 
     /// Latest Token Revenue split (active / inactive)
     pub next_revenue_split_id: RevenueSplitId,
+
+    /// Bonding curve AMM (if active)
+    pub amm_curve: Option<AmmCurve>,
 }
 
 /// Revenue Split State
@@ -569,6 +572,141 @@ where
     }
 }
 
+/// Parameters of the linear bonding curve used by the token's AMM.
+/// The JOY price of the token minted through the curve when `x` tokens were already
+/// minted through it is `intercept + slope * x`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub struct AmmParams<JoyBalance> {
+    /// Price increase (in JOY) per token minted through the curve
+    pub slope: JoyBalance,
+
+    /// Price (in JOY) of the first token minted through the curve
+    pub intercept: JoyBalance,
+}
+
+/// State of an active bonding curve AMM
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct AmmCurve<JoyBalance, Balance, AccountId> {
+    /// Price increase (in JOY) per token minted through the curve
+    pub slope: JoyBalance,
+
+    /// Price (in JOY) of the first token minted through the curve
+    pub intercept: JoyBalance,
+
+    /// Amount of tokens minted through the curve and not sold back yet
+    pub provided_supply: Balance,
+
+    /// Amount of JOY backing `provided_supply`, held by the module treasury account
+    pub reserve: JoyBalance,
+
+    /// Account receiving the trading fees.
+    /// If None: All trading fees are burned.
+    pub fee_destination: Option<AccountId>,
+}
+
+impl<JoyBalance: Default, Balance: Default, AccountId> Default
+    for AmmCurve<JoyBalance, Balance, AccountId>
+{
+    fn default() -> Self {
+        Self {
+            slope: Default::default(),
+            intercept: Default::default(),
+            provided_supply: Default::default(),
+            reserve: Default::default(),
+            fee_destination: None,
+        }
+    }
+}
+
+impl<JoyBalance, Balance, AccountId> AmmCurve<JoyBalance, Balance, AccountId>
+where
+    JoyBalance: AtLeast32BitUnsigned + Copy,
+    Balance: Saturating + Copy + Into<JoyBalance>,
+{
+    pub(crate) fn from_params(
+        params: AmmParams<JoyBalance>,
+        fee_destination: Option<AccountId>,
+    ) -> Self
+    where
+        Balance: Zero,
+    {
+        Self {
+            slope: params.slope,
+            intercept: params.intercept,
+            provided_supply: Balance::zero(),
+            reserve: JoyBalance::zero(),
+            fee_destination,
+        }
+    }
+
+    /// JOY amount (excluding fees) required to mint `amount` tokens through the curve.
+    /// Computes `intercept * amount + ceil(slope * amount * (2 * provided_supply + amount) / 2)`
+    pub(crate) fn buy_price<T: Config>(
+        &self,
+        amount: Balance,
+    ) -> Result<JoyBalance, DispatchError> {
+        let amount: JoyBalance = amount.into();
+        let provided_supply: JoyBalance = self.provided_supply.into();
+        let supply_sum = provided_supply
+            .checked_add(&provided_supply)
+            .and_then(|double_supply| double_supply.checked_add(&amount));
+        self.price_for::<T>(amount, supply_sum, true)
+    }
+
+    /// JOY amount (excluding fees) returned for burning `amount` tokens through the curve.
+    /// Computes `intercept * amount + floor(slope * amount * (2 * provided_supply - amount) / 2)`
+    pub(crate) fn sell_price<T: Config>(
+        &self,
+        amount: Balance,
+    ) -> Result<JoyBalance, DispatchError> {
+        let amount: JoyBalance = amount.into();
+        let provided_supply: JoyBalance = self.provided_supply.into();
+        ensure!(
+            amount <= provided_supply,
+            Error::<T>::InsufficientAmmProvidedSupply
+        );
+        let supply_sum = provided_supply
+            .checked_add(&provided_supply)
+            .and_then(|double_supply| double_supply.checked_sub(&amount));
+        self.price_for::<T>(amount, supply_sum, false)
+    }
+
+    fn price_for<T: Config>(
+        &self,
+        amount: JoyBalance,
+        supply_sum: Option<JoyBalance>,
+        round_up: bool,
+    ) -> Result<JoyBalance, DispatchError> {
+        let two = JoyBalance::one().saturating_add(JoyBalance::one());
+        let double_area = supply_sum
+            .and_then(|supply_sum| self.slope.checked_mul(&amount)?.checked_mul(&supply_sum))
+            .ok_or(Error::<T>::ArithmeticError)?;
+        let double_area = if round_up {
+            double_area
+                .checked_add(&JoyBalance::one())
+                .ok_or(Error::<T>::ArithmeticError)?
+        } else {
+            double_area
+        };
+        self.intercept
+            .checked_mul(&amount)
+            .and_then(|base| base.checked_add(&double_area.checked_div(&two)?))
+            .ok_or_else(|| Error::<T>::ArithmeticError.into())
+    }
+
+    pub(crate) fn record_buy(&mut self, amount: Balance, joy_amount: JoyBalance) {
+        self.provided_supply = self.provided_supply.saturating_add(amount);
+        self.reserve = self.reserve.saturating_add(joy_amount);
+    }
+
+    pub(crate) fn record_sell(&mut self, amount: Balance, joy_amount: JoyBalance) {
+        self.provided_supply = self.provided_supply.saturating_sub(amount);
+        self.reserve = self.reserve.saturating_sub(joy_amount);
+    }
+}
+
 /// Represents token's offering state
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug)]
 pub enum OfferingState<TokenSale> {
@@ -581,13 +719,16 @@ pub enum OfferingState<TokenSale> {
     /// Active sale state
     Sale(TokenSale),
 
-    /// state for IBCO, it might get decorated with the JOY reserve
-    /// amount for the token
+    /// Active bonding curve AMM state
     BondingCurve,
 }
 
 impl<TokenSale> OfferingState<TokenSale> {
     pub(crate) fn of<T: crate::Config>(token: &TokenDataOf<T>) -> OfferingStateOf<T> {
+        if token.amm_curve.is_some() {
+            return OfferingStateOf::<T>::BondingCurve;
+        }
+
         token
             .sale
             .as_ref()
@@ -631,6 +772,52 @@ impl<TokenSale> OfferingState<TokenSale> {
     }
 }
 
+/// Sale purchase precondition that a quoted purchase would not satisfy
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum SalePurchaseViolation {
+    /// Purchase amount is zero
+    PurchaseAmountIsZero,
+
+    /// Not enough tokens left on sale
+    NotEnoughTokensOnSale,
+
+    /// Purchase would exceed the sale's `cap_per_member`
+    PurchaseCapExceeded,
+
+    /// Token is permissioned and the member has no account yet
+    AccountRequired,
+
+    /// Member account has no room for the sale vesting schedule
+    MaxVestingSchedulesReached,
+}
+
+/// Outcome of a token sale purchase, computed without executing the purchase
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct SalePurchaseQuote<JoyBalance, Balance, BlockNumber> {
+    /// Price of the purchased tokens in JOY (`amount * sale.unit_price`)
+    pub price: JoyBalance,
+
+    /// Part of the price that is burned instead of reaching the sale earnings destination
+    pub burned_amount: JoyBalance,
+
+    /// Bloat bond required in case the member has no account for the token yet
+    pub bloat_bond: JoyBalance,
+
+    /// Total amount of JOY required from the buyer
+    pub total_cost: JoyBalance,
+
+    /// Vesting schedule the purchased tokens would be subject to
+    pub vesting_schedule: Option<VestingSchedule<BlockNumber, Balance>>,
+
+    /// Amount the member can still purchase under the sale's `cap_per_member`
+    pub cap_left: Option<Balance>,
+
+    /// Preconditions of `purchase_tokens_on_sale` the purchase would violate
+    pub violations: Vec<SalePurchaseViolation>,
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, TypeInfo)]
 pub struct TokenAllocation<Balance, VestingScheduleParams> {
     pub amount: Balance,
@@ -1182,6 +1369,7 @@ impl<JoyBalance, Balance, Hash, BlockNumber, VestingScheduleParams, MemberId, Ac
         BlockNumber,
         TokenSale<JoyBalance, Balance, BlockNumber, VestingScheduleParams, MemberId, AccountId>,
         RevenueSplitState<JoyBalance, BlockNumber>,
+        AmmCurve<JoyBalance, Balance, AccountId>,
     >
 where
     Balance: Zero + Copy + Saturating + Debug + From<u64> + UniqueSaturatedInto<u64> + Unsigned,
@@ -1291,6 +1479,7 @@ where
             next_revenue_split_id: 0,
             // TODO: revenue split rate might be subjected to constraints: https://github.com/Joystream/atlas/issues/2728
             revenue_split_rate: params.revenue_split_rate,
+            amm_curve: None,
         })
     }
 }
@@ -1426,6 +1615,7 @@ pub type TokenDataOf<T> = TokenData<
     <T as frame_system::Config>::BlockNumber,
     TokenSaleOf<T>,
     RevenueSplitStateOf<T>,
+    AmmCurveOf<T>,
 >;
 
 /// Alias for InitialAllocation
@@ -1484,6 +1674,17 @@ pub(crate) type TokenSaleOf<T> = TokenSale<
 /// Alias for OfferingState
 pub(crate) type OfferingStateOf<T> = OfferingState<TokenSaleOf<T>>;
 
+/// Alias for AmmParams
+pub type AmmParamsOf<T> = AmmParams<JoyBalanceOf<T>>;
+
+/// Alias for AmmCurve
+pub type AmmCurveOf<T> =
+    AmmCurve<JoyBalanceOf<T>, TokenBalanceOf<T>, <T as frame_system::Config>::AccountId>;
+
+/// Alias for SalePurchaseQuote
+pub type SalePurchaseQuoteOf<T> =
+    SalePurchaseQuote<JoyBalanceOf<T>, TokenBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
 /// Alias for UploadContext
 pub type UploadContextOf<T> = UploadContext<<T as frame_system::Config>::AccountId, BagId<T>>;
 
@@ -52,6 +52,8 @@ pub trait WeightInfo {
 	fn participate_in_split() -> Weight;
 	fn exit_revenue_split() -> Weight;
 	fn burn() -> Weight;
+	fn buy_on_amm() -> Weight;
+	fn sell_on_amm() -> Weight;
 }
 
 /// Weights for project_token using the Substrate node and recommended hardware.
@@ -130,6 +132,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:1)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Storage: Token AmmTradingFee (r:1 w:0)
+	// Storage: Token BloatBond (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	fn buy_on_amm() -> Weight {
+		(118_460_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Token TokenInfoById (r:1 w:1)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Storage: Token AmmTradingFee (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	fn sell_on_amm() -> Weight {
+		(97_230_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -155,4 +178,10 @@ impl WeightInfo for () {
 	fn burn() -> Weight {
 		0
 	}
+	fn buy_on_amm() -> Weight {
+		0
+	}
+	fn sell_on_amm() -> Weight {
+		0
+	}
 }
@@ -110,6 +110,19 @@ fn create_proposal_parameters<T: Config + membership::Config>(
     (account_id, member_id, general_proposal_paramters)
 }
 
+fn proposal_parameters_for_update<T: Config>() -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: One::one(),
+        grace_period: Zero::zero(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 60,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 60,
+        required_stake: Some(One::one()),
+        constitutionality: 1,
+    }
+}
+
 fn create_proposal_verify<T: Config>(
     account_id: T::AccountId,
     member_id: T::MemberId,
@@ -869,7 +882,8 @@ benchmarks! {
         let payload = content::ChannelPayoutsPayloadParametersRecord {
             object_creation_params: content::DataObjectCreationParameters {
                 size: u64::MAX,
-                ipfs_content_id: Vec::from_iter((0..(i * 1000)).map(|v| u8::MAX))
+                ipfs_content_id: Vec::from_iter((0..(i * 1000)).map(|v| u8::MAX)),
+                chunks_merkle_root: None,
             },
             expected_data_size_fee: u128::MAX.saturated_into::<T::Balance>(),
             expected_data_object_state_bloat_bond: u128::MAX.saturated_into::<T::Balance>()
@@ -896,6 +910,67 @@ benchmarks! {
             proposal_details
         );
     }
+
+    create_proposal_remove_councilor {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details =
+            ProposalDetails::RemoveCouncilor(member_id, Some(BalanceOf::<T>::one()));
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_update_proposal_parameters {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::UpdateProposalParameters(
+            ProposalKind::Signal,
+            proposal_parameters_for_update::<T>(),
+        );
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    update_proposal_parameters {
+        let proposal_parameters = proposal_parameters_for_update::<T>();
+    }: _(RawOrigin::Root, ProposalKind::Signal, proposal_parameters)
+    verify {
+        assert_eq!(
+            Codex::<T>::proposal_parameters_by_kind(ProposalKind::Signal),
+            Some(proposal_parameters)
+        );
+        assert_last_event::<T>(
+            RawEvent::ProposalParametersUpdated(ProposalKind::Signal, proposal_parameters).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1078,4 +1153,25 @@ mod tests {
             assert_ok!(ProposalsCodex::test_benchmark_create_proposal_update_channel_payouts());
         });
     }
+
+    #[test]
+    fn test_remove_councilor_proposal() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(ProposalsCodex::test_benchmark_create_proposal_remove_councilor());
+        });
+    }
+
+    #[test]
+    fn test_update_proposal_parameters_proposal() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(ProposalsCodex::test_benchmark_create_proposal_update_proposal_parameters());
+        });
+    }
+
+    #[test]
+    fn test_update_proposal_parameters() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(ProposalsCodex::test_benchmark_update_proposal_parameters());
+        });
+    }
 }
@@ -17,6 +17,10 @@
 //! ## Extrinsics
 //!
 //! - [create_proposal](./struct.Module.html#method.create_proposal) - creates proposal
+//! - [amend_proposal](./struct.Module.html#method.amend_proposal) - replaces the details of the
+//! active proposal
+//! - [update_proposal_parameters](./struct.Module.html#method.update_proposal_parameters) - sets
+//! new parameters for the proposals of the provided kind
 //!
 //! ### Dependencies:
 //! - [proposals engine](../substrate_proposals_engine_module/index.html)
@@ -69,8 +73,9 @@ use frame_support::dispatch::DispatchResult;
 use frame_support::traits::Get;
 use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure};
+use frame_system::ensure_root;
 use sp_arithmetic::traits::Zero;
-use sp_runtime::traits::CheckedAdd;
+use sp_runtime::traits::{CheckedAdd, Hash};
 use sp_runtime::SaturatedConversion;
 use sp_std::clone::Clone;
 use sp_std::collections::btree_set::BTreeSet;
@@ -86,8 +91,8 @@ use proposals_engine::{
     BalanceOf, ProposalCreationParameters, ProposalObserver, ProposalParameters,
 };
 pub use types::{
-    CreateOpeningParameters, FillOpeningParameters, GeneralProposalParams, ProposalDetails,
-    ProposalDetailsOf, ProposalEncoder, TerminateRoleParameters,
+    CreateOpeningParameters, FillOpeningParameters, GeneralProposalParams, ProposalAmendment,
+    ProposalDetails, ProposalDetailsOf, ProposalEncoder, ProposalKind, TerminateRoleParameters,
 };
 use working_group::{ApplicationId, OpeningId, OpeningType, WorkerId};
 
@@ -265,6 +270,16 @@ pub trait Config:
         ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
     >;
 
+    /// `Remove Councilor` proposal parameters
+    type RemoveCouncilorProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Update Proposal Parameters` proposal parameters
+    type UpdateProposalParametersProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
     /// Maximum total amount in funding request proposal
     type FundingRequestProposalMaxTotalAmount: Get<BalanceOf<Self>>;
 
@@ -273,6 +288,12 @@ pub trait Config:
 
     /// Max allowed number of validators in set max validator count proposal
     type SetMaxValidatorCountProposalMaxValidators: Get<u32>;
+
+    /// Max number of proposal details per batch proposal
+    type BatchProposalMaxProposals: Get<u32>;
+
+    /// Max number of amendments per proposal
+    type MaxProposalAmendments: Get<u32>;
 }
 
 /// Specialized alias of GeneralProposalParams
@@ -286,6 +307,7 @@ decl_event! {
     pub enum Event<T> where
         GeneralProposalParameters = GeneralProposalParameters<T>,
         ProposalDetailsOf = ProposalDetailsOf<T>,
+        ProposalParametersOf = ProposalParameters<<T as frame_system::Config>::BlockNumber, BalanceOf<T>>,
         <T as proposals_engine::Config>::ProposalId,
         <T as proposals_discussion::Config>::ThreadId
     {
@@ -296,6 +318,19 @@ decl_event! {
         /// - Proposal Details. Parameter of proposal with a variant for each kind of proposal
         /// - Id of a newly created proposal thread
         ProposalCreated(ProposalId, GeneralProposalParameters, ProposalDetailsOf, ThreadId),
+
+        /// A proposal was amended
+        /// Params:
+        /// - Id of the amended proposal
+        /// - New proposal details
+        /// - Number of the amendment
+        ProposalAmended(ProposalId, ProposalDetailsOf, u32),
+
+        /// Proposal parameters were updated
+        /// Params:
+        /// - Kind of the proposals
+        /// - New proposal parameters
+        ProposalParametersUpdated(ProposalKind, ProposalParametersOf),
     }
 }
 
@@ -377,6 +412,28 @@ decl_error! {
         /// Provided proposal id is not valid
         InvalidProposalId,
 
+        /// Invalid number of proposal details in the 'Batch' proposal.
+        InvalidBatchProposalNumberOfProposals,
+
+        /// 'Batch' proposal cannot contain another 'Batch' proposal.
+        NestedBatchProposal,
+
+        /// Amended proposal details must have the same proposal parameters.
+        InvalidAmendmentProposalParameters,
+
+        /// Amended proposal details must be of the same proposal type.
+        InvalidAmendmentProposalType,
+
+        /// Max number of the proposal amendments exceeded.
+        MaxProposalAmendmentsExceeded,
+
+        /// Parameters of the 'Update Proposal Parameters' proposal cannot be updated.
+        ProposalParametersNotUpdatable,
+
+        /// Invalid proposal parameters: voting period, approval and slashing thresholds and
+        /// constitutionality cannot be zero, percentages cannot exceed 100.
+        InvalidProposalParameters,
+
         /// Arithmeic Error
         ArithmeticError,
     }
@@ -388,6 +445,38 @@ decl_storage! { generate_storage_info
         /// Map proposal id to its discussion thread id
         pub ThreadIdByProposalId get(fn thread_id_by_proposal_id):
             map hasher(blake2_128_concat) T::ProposalId => T::ThreadId;
+
+        /// Map proposal id to its proposal kind. `Batch` proposals have no kind.
+        pub ProposalKindByProposalId get(fn proposal_kind_by_proposal_id):
+            map hasher(blake2_128_concat) T::ProposalId => Option<ProposalKind>;
+
+        /// Map proposal id to the number of its amendments
+        pub AmendmentCountByProposalId get(fn amendment_count_by_proposal_id):
+            map hasher(blake2_128_concat) T::ProposalId => u32;
+
+        /// Amendment history of the proposals by the amendment number
+        pub ProposalAmendmentByProposalId get(fn proposal_amendment_by_proposal_id):
+            double_map hasher(blake2_128_concat) T::ProposalId, hasher(blake2_128_concat) u32
+                => Option<ProposalAmendment<T::BlockNumber, T::Hash>>;
+
+        /// Map proposal kind to its proposal parameters. Falls back to the runtime defaults when
+        /// the parameters were not set.
+        pub ProposalParametersByKind get(fn proposal_parameters_by_kind):
+            map hasher(blake2_128_concat) ProposalKind
+                => Option<ProposalParameters<T::BlockNumber, BalanceOf<T>>>;
+    }
+    add_extra_genesis {
+        build(|_| {
+            // Initialize the proposal parameters with the runtime defaults
+            for kind in <ProposalKind as strum::IntoEnumIterator>::iter() {
+                if kind != ProposalKind::UpdateProposalParameters {
+                    <ProposalParametersByKind<T>>::insert(
+                        kind,
+                        Module::<T>::default_proposal_parameters(kind),
+                    );
+                }
+            }
+        });
     }
 }
 
@@ -485,6 +574,14 @@ decl_module! {
         const UpdateChannelPayoutsProposalParameters:
             ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateChannelPayoutsProposalParameters::get();
 
+        /// Exports `Remove Councilor` proposal parameters.
+        const RemoveCouncilorProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::RemoveCouncilorProposalParameters::get();
+
+        /// Exports `Update Proposal Parameters` proposal parameters.
+        const UpdateProposalParametersProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::UpdateProposalParametersProposalParameters::get();
+
         /// Maximum total amount in funding request proposal
         const FundingRequestProposalMaxTotalAmount: BalanceOf<T> =
             T::FundingRequestProposalMaxTotalAmount::get();
@@ -497,6 +594,14 @@ decl_module! {
         const SetMaxValidatorCountProposalMaxValidators: u32 =
             T::SetMaxValidatorCountProposalMaxValidators::get();
 
+        /// Max number of proposal details per batch proposal
+        const BatchProposalMaxProposals: u32 =
+            T::BatchProposalMaxProposals::get();
+
+        /// Max number of amendments per proposal
+        const MaxProposalAmendments: u32 =
+            T::MaxProposalAmendments::get();
+
 
         /// Create a proposal, the type of proposal depends on the `proposal_details` variant
         ///
@@ -571,8 +676,111 @@ decl_module! {
 
             <ThreadIdByProposalId<T>>::insert(proposal_id, discussion_thread_id);
 
+            if let Some(kind) = proposal_details.proposal_kind() {
+                <ProposalKindByProposalId<T>>::insert(proposal_id, kind);
+            }
+
             Self::deposit_event(RawEvent::ProposalCreated(proposal_id, general_proposal_parameters, proposal_details, discussion_thread_id));
         }
+
+        /// Replace the details of the active proposal. The new details must be of the same
+        /// proposal type and have the same proposal parameters. Voting restarts, the discussion
+        /// thread is kept.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (I)` where:
+        /// - `I` is the size of any parameter in `proposal_details`
+        ///   (in kilobytes if it's metadata)
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = Module::<T>::get_amend_proposal_weight(proposal_details)]
+        pub fn amend_proposal(
+            origin,
+            member_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            proposal_details: ProposalDetailsOf<T>,
+        ) {
+            Self::ensure_details_checks(&proposal_details)?;
+
+            let account_id =
+                T::MembershipOriginValidator::ensure_member_controller_account_origin(
+                    origin,
+                    member_id
+                )?;
+
+            Self::ensure_valid_proposal_id(&proposal_id)?;
+            let proposal = proposals_engine::Module::<T>::proposals(proposal_id);
+
+            ensure!(
+                proposal_details.proposal_kind() == Self::proposal_kind_by_proposal_id(proposal_id),
+                Error::<T>::InvalidAmendmentProposalType
+            );
+
+            ensure!(
+                Self::get_proposal_parameters(&proposal_details) == proposal.parameters,
+                Error::<T>::InvalidAmendmentProposalParameters
+            );
+
+            let amendment_count = Self::amendment_count_by_proposal_id(proposal_id);
+            ensure!(
+                amendment_count < T::MaxProposalAmendments::get(),
+                Error::<T>::MaxProposalAmendmentsExceeded
+            );
+            let amendment_nr = amendment_count.checked_add(1).ok_or(Error::<T>::ArithmeticError)?;
+
+            let proposal_code = T::ProposalEncoder::encode_proposal(
+                proposal_details.clone(),
+                account_id
+            );
+
+            <proposals_engine::Module<T>>::amend_proposal(proposal_id, member_id, proposal_code)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let amendment = ProposalAmendment {
+                amended_at: frame_system::Pallet::<T>::block_number(),
+                details_hash: T::Hashing::hash_of(&proposal_details),
+            };
+
+            <AmendmentCountByProposalId<T>>::insert(proposal_id, amendment_nr);
+            <ProposalAmendmentByProposalId<T>>::insert(proposal_id, amendment_nr, amendment);
+
+            Self::deposit_event(RawEvent::ProposalAmended(proposal_id, proposal_details, amendment_nr));
+        }
+
+        /// Set new parameters for the proposals of the provided kind. Affects only the proposals
+        /// created afterwards.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoCodex::<T>::update_proposal_parameters()]
+        pub fn update_proposal_parameters(
+            origin,
+            proposal_kind: ProposalKind,
+            proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        ) {
+            ensure_root(origin)?;
+
+            Self::ensure_proposal_parameters_update_is_valid(&proposal_kind, &proposal_parameters)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ProposalParametersByKind<T>>::insert(proposal_kind, proposal_parameters);
+
+            Self::deposit_event(RawEvent::ProposalParametersUpdated(proposal_kind, proposal_parameters));
+        }
     }
 }
 
@@ -865,83 +1073,198 @@ impl<T: Config> Module<T> {
                     );
                 }
             }
+            ProposalDetails::RemoveCouncilor(_, ref slashing_amount) => {
+                ensure!(
+                    *slashing_amount != Some(Zero::zero()),
+                    Error::<T>::SlashingStakeIsZero
+                );
+            }
+            ProposalDetails::UpdateProposalParameters(ref kind, ref parameters) => {
+                Self::ensure_proposal_parameters_update_is_valid(kind, parameters)?;
+            }
+            ProposalDetails::Batch(ref batch) => {
+                ensure!(
+                    !batch.is_empty(),
+                    Error::<T>::InvalidBatchProposalNumberOfProposals
+                );
+
+                ensure!(
+                    batch.len() <= T::BatchProposalMaxProposals::get() as usize,
+                    Error::<T>::InvalidBatchProposalNumberOfProposals
+                );
+
+                for details in batch {
+                    ensure!(
+                        !matches!(details, ProposalDetails::Batch(..)),
+                        Error::<T>::NestedBatchProposal
+                    );
+
+                    Self::ensure_details_checks(details)?;
+                }
+            }
         }
 
         Ok(())
     }
 
+    // Ensure the new proposal parameters can be set for the proposal kind
+    fn ensure_proposal_parameters_update_is_valid(
+        kind: &ProposalKind,
+        parameters: &ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+    ) -> DispatchResult {
+        ensure!(
+            *kind != ProposalKind::UpdateProposalParameters,
+            Error::<T>::ProposalParametersNotUpdatable
+        );
+
+        let max_percentage = 100;
+        ensure!(
+            !parameters.voting_period.is_zero()
+                && parameters.approval_quorum_percentage <= max_percentage
+                && parameters.approval_threshold_percentage > 0
+                && parameters.approval_threshold_percentage <= max_percentage
+                && parameters.slashing_quorum_percentage <= max_percentage
+                && parameters.slashing_threshold_percentage > 0
+                && parameters.slashing_threshold_percentage <= max_percentage
+                && parameters.constitutionality > 0,
+            Error::<T>::InvalidProposalParameters
+        );
+
+        Ok(())
+    }
+
     // Returns the proposal parameters according to ProposalDetials
     fn get_proposal_parameters(
         details: &ProposalDetailsOf<T>,
     ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
         match details {
-            ProposalDetails::Signal(..) => T::SignalProposalParameters::get(),
-            ProposalDetails::RuntimeUpgrade(..) => T::RuntimeUpgradeProposalParameters::get(),
-            ProposalDetails::FundingRequest(..) => T::FundingRequestProposalParameters::get(),
-            ProposalDetails::SetMaxValidatorCount(..) => {
-                T::SetMaxValidatorCountProposalParameters::get()
-            }
-            ProposalDetails::FillWorkingGroupLeadOpening(..) => {
+            ProposalDetails::Batch(batch) => batch
+                .iter()
+                .map(Self::get_proposal_parameters)
+                .reduce(Self::strictest_proposal_parameters)
+                .unwrap_or_default(),
+            _ => details
+                .proposal_kind()
+                .map(Self::proposal_parameters)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Returns the current parameters of the proposals of the provided kind.
+    pub fn proposal_parameters(
+        kind: ProposalKind,
+    ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+        Self::proposal_parameters_by_kind(kind)
+            .unwrap_or_else(|| Self::default_proposal_parameters(kind))
+    }
+
+    // Returns the runtime default parameters of the proposals of the provided kind
+    fn default_proposal_parameters(
+        kind: ProposalKind,
+    ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+        match kind {
+            ProposalKind::Signal => T::SignalProposalParameters::get(),
+            ProposalKind::RuntimeUpgrade => T::RuntimeUpgradeProposalParameters::get(),
+            ProposalKind::FundingRequest => T::FundingRequestProposalParameters::get(),
+            ProposalKind::SetMaxValidatorCount => T::SetMaxValidatorCountProposalParameters::get(),
+            ProposalKind::FillWorkingGroupLeadOpening => {
                 T::FillWorkingGroupLeadOpeningProposalParameters::get()
             }
-            ProposalDetails::UpdateWorkingGroupBudget(..) => {
+            ProposalKind::UpdateWorkingGroupBudget => {
                 T::UpdateWorkingGroupBudgetProposalParameters::get()
             }
-            ProposalDetails::DecreaseWorkingGroupLeadStake(..) => {
+            ProposalKind::DecreaseWorkingGroupLeadStake => {
                 T::DecreaseWorkingGroupLeadStakeProposalParameters::get()
             }
-            ProposalDetails::SlashWorkingGroupLead(..) => {
+            ProposalKind::SlashWorkingGroupLead => {
                 T::SlashWorkingGroupLeadProposalParameters::get()
             }
-            ProposalDetails::SetWorkingGroupLeadReward(..) => {
+            ProposalKind::SetWorkingGroupLeadReward => {
                 T::SetWorkingGroupLeadRewardProposalParameters::get()
             }
-            ProposalDetails::TerminateWorkingGroupLead(..) => {
+            ProposalKind::TerminateWorkingGroupLead => {
                 T::TerminateWorkingGroupLeadProposalParameters::get()
             }
-            ProposalDetails::CreateWorkingGroupLeadOpening(..) => {
+            ProposalKind::CreateWorkingGroupLeadOpening => {
                 T::CreateWorkingGroupLeadOpeningProposalParameters::get()
             }
-            ProposalDetails::AmendConstitution(..) => T::AmendConstitutionProposalParameters::get(),
-            ProposalDetails::SetMembershipPrice(..) => {
-                T::SetMembershipPriceProposalParameters::get()
-            }
-            ProposalDetails::CancelWorkingGroupLeadOpening(..) => {
+            ProposalKind::AmendConstitution => T::AmendConstitutionProposalParameters::get(),
+            ProposalKind::SetMembershipPrice => T::SetMembershipPriceProposalParameters::get(),
+            ProposalKind::CancelWorkingGroupLeadOpening => {
                 T::CancelWorkingGroupLeadOpeningProposalParameters::get()
             }
-            ProposalDetails::SetCouncilBudgetIncrement(..) => {
+            ProposalKind::SetCouncilBudgetIncrement => {
                 T::SetCouncilBudgetIncrementProposalParameters::get()
             }
-            ProposalDetails::SetCouncilorReward(..) => {
-                T::SetCouncilorRewardProposalParameters::get()
-            }
-            ProposalDetails::SetInitialInvitationBalance(..) => {
+            ProposalKind::SetCouncilorReward => T::SetCouncilorRewardProposalParameters::get(),
+            ProposalKind::SetInitialInvitationBalance => {
                 T::SetInitialInvitationBalanceProposalParameters::get()
             }
-            ProposalDetails::SetInitialInvitationCount(..) => {
+            ProposalKind::SetInitialInvitationCount => {
                 T::SetInvitationCountProposalParameters::get()
             }
-            ProposalDetails::SetMembershipLeadInvitationQuota(..) => {
+            ProposalKind::SetMembershipLeadInvitationQuota => {
                 T::SetMembershipLeadInvitationQuotaProposalParameters::get()
             }
-            ProposalDetails::SetReferralCut(..) => T::SetReferralCutProposalParameters::get(),
-            ProposalDetails::VetoProposal(..) => T::VetoProposalProposalParameters::get(),
-            ProposalDetails::UpdateGlobalNftLimit(..) => {
-                T::UpdateGlobalNftLimitProposalParameters::get()
-            }
-            ProposalDetails::UpdateChannelPayouts(..) => {
-                T::UpdateChannelPayoutsProposalParameters::get()
+            ProposalKind::SetReferralCut => T::SetReferralCutProposalParameters::get(),
+            ProposalKind::VetoProposal => T::VetoProposalProposalParameters::get(),
+            ProposalKind::UpdateGlobalNftLimit => T::UpdateGlobalNftLimitProposalParameters::get(),
+            ProposalKind::UpdateChannelPayouts => T::UpdateChannelPayoutsProposalParameters::get(),
+            ProposalKind::RemoveCouncilor => T::RemoveCouncilorProposalParameters::get(),
+            ProposalKind::UpdateProposalParameters => {
+                T::UpdateProposalParametersProposalParameters::get()
             }
         }
     }
 
+    // Combines the parameters of two proposals into the parameters that satisfy both of them:
+    // the longest periods, the highest quorums, thresholds, stake and constitutionality, and
+    // the lowest slashing quorum and threshold.
+    fn strictest_proposal_parameters(
+        first: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+        second: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
+    ) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+        ProposalParameters {
+            voting_period: first.voting_period.max(second.voting_period),
+            grace_period: first.grace_period.max(second.grace_period),
+            approval_quorum_percentage: first
+                .approval_quorum_percentage
+                .max(second.approval_quorum_percentage),
+            approval_threshold_percentage: first
+                .approval_threshold_percentage
+                .max(second.approval_threshold_percentage),
+            slashing_quorum_percentage: first
+                .slashing_quorum_percentage
+                .min(second.slashing_quorum_percentage),
+            slashing_threshold_percentage: first
+                .slashing_threshold_percentage
+                .min(second.slashing_threshold_percentage),
+            required_stake: first.required_stake.max(second.required_stake),
+            constitutionality: first.constitutionality.max(second.constitutionality),
+        }
+    }
+
     // Returns weight for the proposal creation according to parameters
     fn get_create_proposal_weight(
         general: &GeneralProposalParameters<T>,
         details: &ProposalDetailsOf<T>,
     ) -> Weight {
-        let title_length = general.title.len();
-        let description_length = general.description.len();
+        Self::get_proposal_details_weight(general.title.len(), general.description.len(), details)
+    }
+
+    // Returns weight for the proposal amendment. Amendment is bounded by the creation of the
+    // proposal with the same details and empty title and description.
+    fn get_amend_proposal_weight(details: &ProposalDetailsOf<T>) -> Weight {
+        Self::get_proposal_details_weight(0, 0, details)
+    }
+
+    // Returns weight for the proposal creation according to the proposal details and the
+    // title and description lengths
+    fn get_proposal_details_weight(
+        title_length: usize,
+        description_length: usize,
+        details: &ProposalDetailsOf<T>,
+    ) -> Weight {
         match details {
             ProposalDetails::Signal(signal) => WeightInfoCodex::<T>::create_proposal_signal(
                 to_kb(signal.len().saturated_into()),
@@ -1093,6 +1416,27 @@ impl<T: Config> Module<T> {
                 )
                 .saturated_into()
             }
+            ProposalDetails::RemoveCouncilor(..) => {
+                WeightInfoCodex::<T>::create_proposal_remove_councilor(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+                .saturated_into()
+            }
+            ProposalDetails::UpdateProposalParameters(..) => {
+                WeightInfoCodex::<T>::create_proposal_update_proposal_parameters(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+                .saturated_into()
+            }
+            ProposalDetails::Batch(batch) => batch.iter().fold(0, |weight: Weight, details| {
+                weight.saturating_add(Self::get_proposal_details_weight(
+                    title_length,
+                    description_length,
+                    details,
+                ))
+            }),
         }
     }
 }
@@ -1104,5 +1448,9 @@ impl<T: Config> ProposalObserver<T> for Module<T> {
         let thread_id = Self::thread_id_by_proposal_id(proposal_id);
 
         proposals_discussion::ThreadById::<T>::remove(thread_id);
+
+        <ProposalKindByProposalId<T>>::remove(proposal_id);
+        <AmendmentCountByProposalId<T>>::remove(proposal_id);
+        <ProposalAmendmentByProposalId<T>>::remove_prefix(proposal_id, None);
     }
 }
@@ -15,6 +15,7 @@ use frame_support::{
 };
 pub use frame_system;
 use frame_system::{EnsureRoot, EnsureSigned};
+use referendum::BallotMode;
 use sp_core::H256;
 use sp_runtime::curve::PiecewiseLinear;
 use sp_runtime::{
@@ -388,6 +389,7 @@ parameter_types! {
     pub const MaxActiveProposalLimit: u32 = 100;
     pub const LockId: LockIdentifier = [2; 8];
     pub const DispatchableCallCodeMaxLen: u32 = 1024 * 1024;
+    pub const MaxVoteDelegationPeriod: u64 = 100;
 }
 
 impl proposals_engine::Config for Test {
@@ -407,6 +409,7 @@ impl proposals_engine::Config for Test {
     type WeightInfo = ();
     type StakingAccountValidator = ();
     type DispatchableCallCodeMaxLen = DispatchableCallCodeMaxLen;
+    type MaxVoteDelegationPeriod = MaxVoteDelegationPeriod;
 }
 
 pub const STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER: u64 = 222;
@@ -450,6 +453,10 @@ impl common::council::CouncilOriginValidator<Origin, u64, u64> for () {
 
         Ok(())
     }
+
+    fn is_council_member(_: &u64) -> bool {
+        true
+    }
 }
 
 parameter_types! {
@@ -659,9 +666,13 @@ impl crate::Config for Test {
     type VetoProposalProposalParameters = DefaultProposalParameters;
     type UpdateGlobalNftLimitProposalParameters = DefaultProposalParameters;
     type UpdateChannelPayoutsProposalParameters = DefaultProposalParameters;
+    type RemoveCouncilorProposalParameters = DefaultProposalParameters;
+    type UpdateProposalParametersProposalParameters = DefaultProposalParameters;
     type FundingRequestProposalMaxTotalAmount = FundingRequestProposalMaxTotalAmount;
     type FundingRequestProposalMaxAccounts = FundingRequestProposalMaxAccounts;
     type SetMaxValidatorCountProposalMaxValidators = SetMaxValidatorCountProposalMaxValidators;
+    type BatchProposalMaxProposals = BatchProposalMaxProposals;
+    type MaxProposalAmendments = MaxProposalAmendments;
 }
 
 parameter_types! {
@@ -679,6 +690,8 @@ parameter_types! {
     pub const FundingRequestProposalMaxTotalAmount: Balance = 10_000_000_000_000;
     pub const FundingRequestProposalMaxAccounts: u32 = 100;
     pub const SetMaxValidatorCountProposalMaxValidators: u32 = 300;
+    pub const BatchProposalMaxProposals: u32 = 5;
+    pub const MaxProposalAmendments: u32 = 3;
 }
 
 pub type ReferendumInstance = referendum::Instance1;
@@ -716,6 +729,9 @@ parameter_types! {
     pub const MaxSaltLength: u64 = 32; // use some multiple of 8 for ez testing
     pub const VotingLockId: LockIdentifier = *b"referend";
     pub const MaxWinnerTargetCount: u32 = 10;
+    pub const MaxVoteLockPeriod: u32 = 6;
+    pub const ReferendumBallotMode: BallotMode = BallotMode::FirstPastThePost;
+    pub const MaxRankedOptions: u32 = 5;
 }
 
 impl referendum::Config<ReferendumInstance> for Test {
@@ -737,9 +753,14 @@ impl referendum::Config<ReferendumInstance> for Test {
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
 
+    type MaxVoteLockPeriod = MaxVoteLockPeriod;
+    type BallotMode = ReferendumBallotMode;
+    type MaxRankedOptions = MaxRankedOptions;
+
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
         _: &Self::Balance,
+        _: u32,
     ) -> Self::VotePower {
         1
     }
@@ -1474,6 +1474,7 @@ fn setup_council(start_id: u64) {
             RawOrigin::Signed(*voter_id).into(),
             commitment,
             BalanceOf::<Test>::max_value(),
+            0,
         )
         .unwrap();
     }
@@ -2506,6 +2507,7 @@ fn create_update_channel_payouts_common_checks_succeed() {
                     object_creation_params: content::DataObjectCreationParameters {
                         size: u64::MAX,
                         ipfs_content_id: Vec::from_iter((0..46).map(|_| u8::MAX)),
+                        chunks_merkle_root: None,
                     },
                     expected_data_size_fee: u128::MAX.saturated_into::<BalanceOf<Test>>(),
                     expected_data_object_state_bloat_bond: u128::MAX
@@ -2586,3 +2588,619 @@ fn create_update_channel_payouts_proposal_fails_when_min_cashout_exceeds_max_cas
         );
     });
 }
+
+#[test]
+fn create_remove_councilor_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let total_balance_issuance = 500000;
+        increase_total_balance_issuance(total_balance_issuance);
+
+        let general_proposal_parameters_no_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: None,
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
+            exact_execution_block: None,
+        };
+
+        let proposal_details = ProposalDetails::RemoveCouncilor(1, Some(100));
+
+        let proposal_fixture = ProposalTestFixture {
+            general_proposal_parameters: general_proposal_parameters.clone(),
+            proposal_details: proposal_details.clone(),
+            insufficient_rights_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::None.into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            invalid_stake_account_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_incorrect_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            proposal_parameters: <Test as crate::Config>::RemoveCouncilorProposalParameters::get(),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_remove_councilor_proposal_fails_with_zero_slashing_amount() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters,
+                ProposalDetails::RemoveCouncilor(1, Some(0))
+            ),
+            Err(Error::<Test>::SlashingStakeIsZero.into())
+        );
+    });
+}
+
+#[test]
+fn create_batch_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let total_balance_issuance = 500000;
+        increase_total_balance_issuance(total_balance_issuance);
+
+        let general_proposal_parameters_no_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: None,
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
+            exact_execution_block: None,
+        };
+
+        let proposal_details = ProposalDetails::Batch(vec![
+            ProposalDetails::SetMembershipPrice(100),
+            ProposalDetails::SetReferralCut(10),
+        ]);
+
+        let proposal_fixture = ProposalTestFixture {
+            general_proposal_parameters: general_proposal_parameters.clone(),
+            proposal_details: proposal_details.clone(),
+            insufficient_rights_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::None.into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            invalid_stake_account_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_incorrect_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            proposal_parameters: <Test as crate::Config>::SetMembershipPriceProposalParameters::get(
+            ),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_batch_proposal_fails_with_invalid_number_of_proposals() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters.clone(),
+                ProposalDetails::Batch(Vec::new())
+            ),
+            Err(Error::<Test>::InvalidBatchProposalNumberOfProposals.into())
+        );
+
+        let max_proposals = <Test as crate::Config>::BatchProposalMaxProposals::get();
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters,
+                ProposalDetails::Batch(vec![
+                    ProposalDetails::SetReferralCut(10);
+                    max_proposals as usize + 1
+                ])
+            ),
+            Err(Error::<Test>::InvalidBatchProposalNumberOfProposals.into())
+        );
+    });
+}
+
+#[test]
+fn create_batch_proposal_fails_with_nested_batch() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters,
+                ProposalDetails::Batch(vec![
+                    ProposalDetails::SetReferralCut(10),
+                    ProposalDetails::Batch(vec![ProposalDetails::SetReferralCut(20)]),
+                ])
+            ),
+            Err(Error::<Test>::NestedBatchProposal.into())
+        );
+    });
+}
+
+#[test]
+fn create_batch_proposal_fails_with_invalid_inner_proposal() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters,
+                ProposalDetails::Batch(vec![
+                    ProposalDetails::SetReferralCut(10),
+                    ProposalDetails::Signal(Vec::new()),
+                ])
+            ),
+            Err(Error::<Test>::SignalProposalIsEmpty.into())
+        );
+    });
+}
+
+fn create_signal_proposal_for_amendment() -> u32 {
+    increase_total_balance_issuance_using_account_id(1, 500000);
+
+    let general_proposal_parameters = GeneralProposalParameters::<Test> {
+        member_id: 1,
+        title: b"title".to_vec(),
+        description: b"body".to_vec(),
+        staking_account_id: Some(1),
+        exact_execution_block: None,
+    };
+
+    assert_eq!(
+        ProposalsCodex::create_proposal(
+            RawOrigin::Signed(1).into(),
+            general_proposal_parameters,
+            ProposalDetails::Signal(b"signal".to_vec()),
+        ),
+        Ok(())
+    );
+
+    ProposalsEngine::proposal_count()
+}
+
+#[test]
+fn amend_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = create_signal_proposal_for_amendment();
+        let thread_id = <crate::ThreadIdByProposalId<Test>>::get(proposal_id);
+
+        let proposal_details = ProposalDetails::Signal(b"amended signal".to_vec());
+
+        assert_eq!(
+            ProposalsCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                proposal_details.clone(),
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalsCodex::amendment_count_by_proposal_id(proposal_id),
+            1
+        );
+        assert_eq!(
+            ProposalsCodex::proposal_amendment_by_proposal_id(proposal_id, 1),
+            Some(ProposalAmendment {
+                amended_at: System::block_number(),
+                details_hash: <Test as frame_system::Config>::Hashing::hash_of(&proposal_details),
+            })
+        );
+        // the discussion thread is kept
+        assert_eq!(
+            <crate::ThreadIdByProposalId<Test>>::get(proposal_id),
+            thread_id
+        );
+
+        assert_last_event(RawEvent::ProposalAmended(proposal_id, proposal_details, 1).into());
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_invalid_proposal_id() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalsCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                1,
+                ProposalDetails::Signal(b"amended signal".to_vec()),
+            ),
+            Err(Error::<Test>::InvalidProposalId.into())
+        );
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_different_proposal_type() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = create_signal_proposal_for_amendment();
+
+        <crate::ProposalParametersByKind<Test>>::insert(
+            ProposalKind::AmendConstitution,
+            ProposalsCodex::proposal_parameters(ProposalKind::Signal),
+        );
+
+        assert_eq!(
+            ProposalsCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                ProposalDetails::AmendConstitution(b"amended constitution".to_vec()),
+            ),
+            Err(Error::<Test>::InvalidAmendmentProposalType.into())
+        );
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_exceeding_max_amendments() {
+    initial_test_ext().execute_with(|| {
+        let proposal_id = create_signal_proposal_for_amendment();
+        let max_amendments = <Test as crate::Config>::MaxProposalAmendments::get();
+
+        for i in 0..max_amendments {
+            assert_eq!(
+                ProposalsCodex::amend_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    proposal_id,
+                    ProposalDetails::Signal(vec![i as u8 + 1]),
+                ),
+                Ok(())
+            );
+        }
+
+        assert_eq!(
+            ProposalsCodex::amend_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                ProposalDetails::Signal(b"amended signal".to_vec()),
+            ),
+            Err(Error::<Test>::MaxProposalAmendmentsExceeded.into())
+        );
+    });
+}
+
+#[test]
+fn create_update_proposal_parameters_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let total_balance_issuance = 500000;
+        increase_total_balance_issuance(total_balance_issuance);
+
+        let general_proposal_parameters_no_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: None,
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
+            exact_execution_block: None,
+        };
+
+        let proposal_details = ProposalDetails::UpdateProposalParameters(
+            ProposalKind::Signal,
+            ProposalParameters {
+                voting_period: 100,
+                ..default_proposal_parameters()
+            },
+        );
+
+        let proposal_fixture = ProposalTestFixture {
+            general_proposal_parameters: general_proposal_parameters.clone(),
+            proposal_details: proposal_details.clone(),
+            insufficient_rights_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::None.into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            invalid_stake_account_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_incorrect_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            proposal_parameters:
+                <Test as crate::Config>::UpdateProposalParametersProposalParameters::get(),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_update_proposal_parameters_proposal_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters.clone(),
+                ProposalDetails::UpdateProposalParameters(
+                    ProposalKind::Signal,
+                    ProposalParameters {
+                        approval_threshold_percentage: 0,
+                        ..default_proposal_parameters()
+                    },
+                )
+            ),
+            Err(Error::<Test>::InvalidProposalParameters.into())
+        );
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters,
+                ProposalDetails::UpdateProposalParameters(
+                    ProposalKind::UpdateProposalParameters,
+                    default_proposal_parameters(),
+                )
+            ),
+            Err(Error::<Test>::ProposalParametersNotUpdatable.into())
+        );
+    });
+}
+
+#[test]
+fn update_proposal_parameters_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let new_parameters = ProposalParameters {
+            voting_period: 100,
+            approval_quorum_percentage: 80,
+            required_stake: Some(200),
+            ..default_proposal_parameters()
+        };
+
+        assert_eq!(
+            ProposalsCodex::update_proposal_parameters(
+                RawOrigin::Root.into(),
+                ProposalKind::Signal,
+                new_parameters,
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            ProposalsCodex::proposal_parameters_by_kind(ProposalKind::Signal),
+            Some(new_parameters)
+        );
+        assert_last_event(
+            RawEvent::ProposalParametersUpdated(ProposalKind::Signal, new_parameters).into(),
+        );
+
+        // new proposals of the kind use the updated parameters
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                GeneralProposalParameters::<Test> {
+                    member_id: 1,
+                    title: b"title".to_vec(),
+                    description: b"body".to_vec(),
+                    staking_account_id: Some(1),
+                    exact_execution_block: None,
+                },
+                ProposalDetails::Signal(b"signal".to_vec()),
+            ),
+            Ok(())
+        );
+
+        let proposal_id = ProposalsEngine::proposal_count();
+        assert_eq!(
+            ProposalsEngine::proposals(proposal_id).parameters,
+            new_parameters
+        );
+
+        // other proposal kinds are not affected
+        assert_eq!(
+            ProposalsCodex::proposal_parameters(ProposalKind::RuntimeUpgrade),
+            <Test as crate::Config>::RuntimeUpgradeProposalParameters::get()
+        );
+    });
+}
+
+#[test]
+fn update_proposal_parameters_fails_with_bad_origin() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalsCodex::update_proposal_parameters(
+                RawOrigin::Signed(1).into(),
+                ProposalKind::Signal,
+                default_proposal_parameters(),
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+    });
+}
+
+#[test]
+fn update_proposal_parameters_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalsCodex::update_proposal_parameters(
+                RawOrigin::Root.into(),
+                ProposalKind::Signal,
+                ProposalParameters {
+                    voting_period: 0,
+                    ..default_proposal_parameters()
+                },
+            ),
+            Err(Error::<Test>::InvalidProposalParameters.into())
+        );
+
+        assert_eq!(
+            ProposalsCodex::update_proposal_parameters(
+                RawOrigin::Root.into(),
+                ProposalKind::Signal,
+                ProposalParameters {
+                    slashing_quorum_percentage: 101,
+                    ..default_proposal_parameters()
+                },
+            ),
+            Err(Error::<Test>::InvalidProposalParameters.into())
+        );
+
+        assert_eq!(
+            ProposalsCodex::update_proposal_parameters(
+                RawOrigin::Root.into(),
+                ProposalKind::UpdateProposalParameters,
+                default_proposal_parameters(),
+            ),
+            Err(Error::<Test>::ProposalParametersNotUpdatable.into())
+        );
+    });
+}
@@ -1,6 +1,6 @@
 #![warn(missing_docs)]
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -11,8 +11,11 @@ use common::BalanceKind;
 use common::FundingRequestParameters;
 
 use content::NftLimitPeriod;
+use proposals_engine::ProposalParameters;
 use working_group::StakePolicy;
 
+pub use iterable_enums::ProposalKind;
+
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Config> {
     /// Encodes proposal using its details information.
@@ -31,11 +34,13 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
     working_group::OpeningId,
     <T as proposals_engine::Config>::ProposalId,
     content::UpdateChannelPayoutsParameters<T>,
+    <T as common::membership::MembershipTypes>::MemberId,
 >;
 
 /// Proposal details provide voters the information required for the perceived voting.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo)]
+#[codec(dumb_trait_bound)]
 pub enum ProposalDetails<
     Balance,
     BlockNumber,
@@ -44,6 +49,7 @@ pub enum ProposalDetails<
     OpeningId,
     ProposalId,
     UpdateChannelPayoutsParameters,
+    MemberId,
 > {
     /// The signal of the `Signal` proposal
     Signal(Vec<u8>),
@@ -118,6 +124,31 @@ pub enum ProposalDetails<
 
     /// `Update Channel Payouts` proposal
     UpdateChannelPayouts(UpdateChannelPayoutsParameters),
+
+    /// `Remove Councilor` proposal:
+    /// Remove the councilor from the council with possible slashing.
+    RemoveCouncilor(MemberId, Option<Balance>),
+
+    /// `Update Proposal Parameters` proposal:
+    /// Set new parameters for the proposals of the provided kind.
+    UpdateProposalParameters(ProposalKind, ProposalParameters<BlockNumber, Balance>),
+
+    /// `Batch` proposal:
+    /// Several non-batch proposal details executed atomically - either all of them or none.
+    Batch(
+        Vec<
+            ProposalDetails<
+                Balance,
+                BlockNumber,
+                AccountId,
+                WorkerId,
+                OpeningId,
+                ProposalId,
+                UpdateChannelPayoutsParameters,
+                MemberId,
+            >,
+        >,
+    ),
 }
 
 impl<
@@ -128,6 +159,7 @@ impl<
         OpeningId,
         ProposalId,
         UpdateChannelPayoutsParameters,
+        MemberId,
     > Default
     for ProposalDetails<
         Balance,
@@ -137,6 +169,7 @@ impl<
         OpeningId,
         ProposalId,
         UpdateChannelPayoutsParameters,
+        MemberId,
     >
 {
     fn default() -> Self {
@@ -144,6 +177,187 @@ impl<
     }
 }
 
+impl<
+        Balance,
+        BlockNumber,
+        AccountId,
+        WorkerId,
+        OpeningId,
+        ProposalId,
+        UpdateChannelPayoutsParameters,
+        MemberId,
+    >
+    ProposalDetails<
+        Balance,
+        BlockNumber,
+        AccountId,
+        WorkerId,
+        OpeningId,
+        ProposalId,
+        UpdateChannelPayoutsParameters,
+        MemberId,
+    >
+{
+    /// Returns the kind of the proposal. `Batch` proposal has no kind of its own: its parameters
+    /// are derived from the proposals it contains.
+    pub fn proposal_kind(&self) -> Option<ProposalKind> {
+        let kind = match self {
+            ProposalDetails::Signal(..) => ProposalKind::Signal,
+            ProposalDetails::RuntimeUpgrade(..) => ProposalKind::RuntimeUpgrade,
+            ProposalDetails::FundingRequest(..) => ProposalKind::FundingRequest,
+            ProposalDetails::SetMaxValidatorCount(..) => ProposalKind::SetMaxValidatorCount,
+            ProposalDetails::CreateWorkingGroupLeadOpening(..) => {
+                ProposalKind::CreateWorkingGroupLeadOpening
+            }
+            ProposalDetails::FillWorkingGroupLeadOpening(..) => {
+                ProposalKind::FillWorkingGroupLeadOpening
+            }
+            ProposalDetails::UpdateWorkingGroupBudget(..) => ProposalKind::UpdateWorkingGroupBudget,
+            ProposalDetails::DecreaseWorkingGroupLeadStake(..) => {
+                ProposalKind::DecreaseWorkingGroupLeadStake
+            }
+            ProposalDetails::SlashWorkingGroupLead(..) => ProposalKind::SlashWorkingGroupLead,
+            ProposalDetails::SetWorkingGroupLeadReward(..) => {
+                ProposalKind::SetWorkingGroupLeadReward
+            }
+            ProposalDetails::TerminateWorkingGroupLead(..) => {
+                ProposalKind::TerminateWorkingGroupLead
+            }
+            ProposalDetails::AmendConstitution(..) => ProposalKind::AmendConstitution,
+            ProposalDetails::CancelWorkingGroupLeadOpening(..) => {
+                ProposalKind::CancelWorkingGroupLeadOpening
+            }
+            ProposalDetails::SetMembershipPrice(..) => ProposalKind::SetMembershipPrice,
+            ProposalDetails::SetCouncilBudgetIncrement(..) => {
+                ProposalKind::SetCouncilBudgetIncrement
+            }
+            ProposalDetails::SetCouncilorReward(..) => ProposalKind::SetCouncilorReward,
+            ProposalDetails::SetInitialInvitationBalance(..) => {
+                ProposalKind::SetInitialInvitationBalance
+            }
+            ProposalDetails::SetInitialInvitationCount(..) => {
+                ProposalKind::SetInitialInvitationCount
+            }
+            ProposalDetails::SetMembershipLeadInvitationQuota(..) => {
+                ProposalKind::SetMembershipLeadInvitationQuota
+            }
+            ProposalDetails::SetReferralCut(..) => ProposalKind::SetReferralCut,
+            ProposalDetails::VetoProposal(..) => ProposalKind::VetoProposal,
+            ProposalDetails::UpdateGlobalNftLimit(..) => ProposalKind::UpdateGlobalNftLimit,
+            ProposalDetails::UpdateChannelPayouts(..) => ProposalKind::UpdateChannelPayouts,
+            ProposalDetails::RemoveCouncilor(..) => ProposalKind::RemoveCouncilor,
+            ProposalDetails::UpdateProposalParameters(..) => ProposalKind::UpdateProposalParameters,
+            ProposalDetails::Batch(..) => return None,
+        };
+
+        Some(kind)
+    }
+}
+
+// Proc macro (EnumIter) clippy::integer_arithmetic disable hack
+#[allow(clippy::integer_arithmetic)]
+mod iterable_enums {
+    use codec::{Decode, Encode, MaxEncodedLen};
+    use scale_info::TypeInfo;
+    #[cfg(feature = "std")]
+    use serde::{Deserialize, Serialize};
+    #[cfg(feature = "std")]
+    use strum_macros::EnumIter;
+
+    /// Kind of the proposal. Each kind has its own proposal parameters.
+    #[cfg_attr(feature = "std", derive(Serialize, Deserialize, EnumIter))]
+    #[derive(
+        Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord, TypeInfo, MaxEncodedLen,
+    )]
+    pub enum ProposalKind {
+        /// `Signal` proposal
+        Signal,
+
+        /// `Runtime Upgrade` proposal
+        RuntimeUpgrade,
+
+        /// `Funding Request` proposal
+        FundingRequest,
+
+        /// `Set Max Validator Count` proposal
+        SetMaxValidatorCount,
+
+        /// `Create Working Group Lead Opening` proposal
+        CreateWorkingGroupLeadOpening,
+
+        /// `Fill Working Group Lead Opening` proposal
+        FillWorkingGroupLeadOpening,
+
+        /// `Update Working Group Budget` proposal
+        UpdateWorkingGroupBudget,
+
+        /// `Decrease Working Group Lead Stake` proposal
+        DecreaseWorkingGroupLeadStake,
+
+        /// `Slash Working Group Lead Stake` proposal
+        SlashWorkingGroupLead,
+
+        /// `Set Working Group Lead Reward` proposal
+        SetWorkingGroupLeadReward,
+
+        /// `Terminate Working Group Lead` proposal
+        TerminateWorkingGroupLead,
+
+        /// `Amend constitution` proposal
+        AmendConstitution,
+
+        /// `Cancel Working Group Lead Opening` proposal
+        CancelWorkingGroupLeadOpening,
+
+        /// `Set Membership Price` proposal
+        SetMembershipPrice,
+
+        /// `Set Council Budget Increment` proposal
+        SetCouncilBudgetIncrement,
+
+        /// `Set Councilor Reward` proposal
+        SetCouncilorReward,
+
+        /// `Set Initial Invitation Balance` proposal
+        SetInitialInvitationBalance,
+
+        /// `Set Initial Invitation Count` proposal
+        SetInitialInvitationCount,
+
+        /// `Set Membership Lead Invitation Quota` proposal
+        SetMembershipLeadInvitationQuota,
+
+        /// `Set Referral Cut` proposal
+        SetReferralCut,
+
+        /// `Veto Proposal` proposal
+        VetoProposal,
+
+        /// `Update global NFT limit` proposal
+        UpdateGlobalNftLimit,
+
+        /// `Update Channel Payouts` proposal
+        UpdateChannelPayouts,
+
+        /// `Remove Councilor` proposal
+        RemoveCouncilor,
+
+        /// `Update Proposal Parameters` proposal
+        UpdateProposalParameters,
+    }
+}
+
+/// Amendment of the proposal details made by the proposer during the proposal voting.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ProposalAmendment<BlockNumber, Hash> {
+    /// Block at which the proposal was amended.
+    pub amended_at: BlockNumber,
+
+    /// Hash of the amended proposal details.
+    pub details_hash: Hash,
+}
+
 /// Proposal parameters common to all proposals
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Default, Clone, PartialEq, Eq, TypeInfo)]
@@ -67,6 +67,9 @@ pub trait WeightInfo {
 	fn create_proposal_set_referral_cut(_t: u32, _d: u32, ) -> Weight;
 	fn create_proposal_update_global_nft_limit(_t: u32, _d: u32, ) -> Weight;
 	fn create_proposal_update_channel_payouts(_t: u32, _d: u32, _i: u32, ) -> Weight;
+	fn create_proposal_remove_councilor(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_update_proposal_parameters(_t: u32, _d: u32, ) -> Weight;
+	fn update_proposal_parameters() -> Weight;
 }
 
 /// Weights for proposals_codex using the Substrate node and recommended hardware.
@@ -550,6 +553,52 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7 as Weight))
 			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	fn create_proposal_remove_councilor(t: u32, d: u32, ) -> Weight {
+		(98_112_000 as Weight)
+			// Standard Error: 18_000
+			.saturating_add((1_247_000 as Weight).saturating_mul(t as Weight))
+			// Standard Error: 18_000
+			.saturating_add((1_431_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: ProposalsCodex ProposalParametersByKind (r:1 w:0)
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	fn create_proposal_update_proposal_parameters(t: u32, d: u32, ) -> Weight {
+		(99_375_000 as Weight)
+			// Standard Error: 18_000
+			.saturating_add((1_251_000 as Weight).saturating_mul(t as Weight))
+			// Standard Error: 18_000
+			.saturating_add((1_428_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+	// Storage: ProposalsCodex ProposalParametersByKind (r:0 w:1)
+	fn update_proposal_parameters() -> Weight {
+		(14_207_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // Default implementation for tests
@@ -623,4 +672,13 @@ impl WeightInfo for () {
 	fn create_proposal_update_channel_payouts(t: u32, d: u32, i: u32, ) -> Weight {
 		0
 	}
+	fn create_proposal_remove_councilor(t: u32, d: u32, ) -> Weight {
+		0
+	}
+	fn create_proposal_update_proposal_parameters(t: u32, d: u32, ) -> Weight {
+		0
+	}
+	fn update_proposal_parameters() -> Weight {
+		0
+	}
 }
@@ -184,6 +184,7 @@ fn elect_council<
             RawOrigin::Signed(voters[i].0.clone()).into(),
             commitment,
             voter_stake,
+            0,
         )
         .unwrap();
     }
@@ -12,6 +12,7 @@ use frame_support::{
     PalletId,
 };
 use frame_system::{EnsureRoot, EnsureSigned};
+use referendum::BallotMode;
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -311,6 +312,10 @@ impl CouncilOriginValidator<Origin, u64, u128> for CouncilMock {
 
         Err(DispatchError::Other("Not a council"))
     }
+
+    fn is_council_member(actor_id: &u64) -> bool {
+        *actor_id == 2
+    }
 }
 
 parameter_types! {
@@ -319,6 +324,9 @@ parameter_types! {
     pub const MinimumVotingStake: u64 = 10000;
     pub const MaxSaltLength: u64 = 32; // use some multiple of 8 for ez testing
     pub const MaxWinnerTargetCount: u32 = 10;
+    pub const MaxVoteLockPeriod: u32 = 6;
+    pub const ReferendumBallotMode: BallotMode = BallotMode::FirstPastThePost;
+    pub const MaxRankedOptions: u32 = 5;
 }
 
 impl referendum::Config<ReferendumInstance> for Test {
@@ -340,9 +348,14 @@ impl referendum::Config<ReferendumInstance> for Test {
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
 
+    type MaxVoteLockPeriod = MaxVoteLockPeriod;
+    type BallotMode = ReferendumBallotMode;
+    type MaxRankedOptions = MaxRankedOptions;
+
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
         _: &Self::Balance,
+        _: u32,
     ) -> Self::VotePower {
         1
     }
@@ -283,6 +283,7 @@ fn elect_council<
             RawOrigin::Signed(voters[i].0.clone()).into(),
             commitment,
             voter_stake,
+            0,
         )
         .unwrap();
     }
@@ -407,6 +408,103 @@ benchmarks! {
         );
     }
 
+    delegate_vote {
+        let (council, _) = elect_council::<T>(0);
+        let councilor = council[0].clone();
+        let (account_councilor_id, member_councilor_id) =
+            (councilor.account_id, councilor.member_id);
+        let delegate = account::<T::AccountId>("delegate", 0, SEED);
+        let period = T::MaxVoteDelegationPeriod::get();
+        let expires_at = System::<T>::block_number() + period;
+    }: _ (
+            RawOrigin::Signed(account_councilor_id),
+            member_councilor_id,
+            delegate.clone(),
+            period
+        )
+    verify {
+        assert_eq!(
+            ProposalsEngine::<T>::vote_delegation_by_councilor(member_councilor_id),
+            Some(VoteDelegation { delegate: delegate.clone(), expires_at }),
+            "Vote delegation not stored"
+        );
+
+        assert_last_event::<T>(
+            RawEvent::VoteDelegated(member_councilor_id, delegate, expires_at).into()
+        );
+    }
+
+    revoke_vote_delegation {
+        let (council, _) = elect_council::<T>(0);
+        let councilor = council[0].clone();
+        let (account_councilor_id, member_councilor_id) =
+            (councilor.account_id, councilor.member_id);
+        let delegate = account::<T::AccountId>("delegate", 0, SEED);
+
+        ProposalsEngine::<T>::delegate_vote(
+            RawOrigin::Signed(account_councilor_id.clone()).into(),
+            member_councilor_id,
+            delegate,
+            T::MaxVoteDelegationPeriod::get(),
+        ).unwrap();
+    }: _ (RawOrigin::Signed(account_councilor_id), member_councilor_id)
+    verify {
+        assert!(
+            !VoteDelegationByCouncilor::<T>::contains_key(member_councilor_id),
+            "Vote delegation not removed"
+        );
+
+        assert_last_event::<T>(RawEvent::VoteDelegationRevoked(member_councilor_id).into());
+    }
+
+    vote_as_delegate {
+        let i in 0 .. MAX_KILOBYTES_METADATA;
+
+        let (council, last_id) = elect_council::<T>(0);
+        let voter = council[0].clone();
+        let (account_voter_id, member_voter_id) = (voter.account_id, voter.member_id);
+        let delegate = account::<T::AccountId>("delegate", 0, SEED);
+
+        ProposalsEngine::<T>::delegate_vote(
+            RawOrigin::Signed(account_voter_id).into(),
+            member_voter_id,
+            delegate.clone(),
+            T::MaxVoteDelegationPeriod::get(),
+        ).unwrap();
+
+        let (_, _, proposal_id) = create_proposal::<T>(last_id + 1, 1, 0, 0);
+        let rationale = vec![0u8; (i * 1000).try_into().unwrap()];
+    }: _ (
+            RawOrigin::Signed(delegate.clone()),
+            member_voter_id,
+            proposal_id,
+            VoteKind::Approve,
+            rationale.clone()
+        )
+    verify {
+        let voting_results = ProposalsEngine::<T>::proposals(proposal_id).voting_results;
+
+        assert_eq!(
+          voting_results,
+          VotingResults{ approvals: 1, abstentions: 0, rejections: 0, slashes: 0 },
+          "There should only be 1 approval"
+        );
+
+        assert_eq!(
+          ProposalsEngine::<T>::vote_record_by_proposal_by_voter(proposal_id, member_voter_id),
+          Some(VoteRecord {
+              vote_kind: VoteKind::Approve,
+              rationale_hash: T::Hashing::hash(&rationale),
+              delegate: Some(delegate),
+          }),
+          "Stored vote doesn't match"
+        );
+
+        assert_last_event::<T>(
+            RawEvent::Voted(member_voter_id, proposal_id, VoteKind::Approve, rationale).into()
+        );
+    }
+
     cancel_proposal {
 
         let (account_id, member_id, proposal_id) = create_proposal::<T>(0, 1, 0, 0);
@@ -776,6 +874,27 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_delegate_vote() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Engine::test_benchmark_delegate_vote());
+        });
+    }
+
+    #[test]
+    fn test_revoke_vote_delegation() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Engine::test_benchmark_revoke_vote_delegation());
+        });
+    }
+
+    #[test]
+    fn test_vote_as_delegate() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Engine::test_benchmark_vote_as_delegate());
+        });
+    }
+
     #[test]
     fn test_cancel_proposal() {
         initial_test_ext().execute_with(|| {
@@ -50,6 +50,12 @@
 //!
 //! ### Supported extrinsics
 //! - [vote](./struct.Module.html#method.vote) - registers a vote for the proposal
+//! - [delegate_vote](./struct.Module.html#method.delegate_vote) - allows an account to vote on
+//! behalf of the councilor for a limited period
+//! - [revoke_vote_delegation](./struct.Module.html#method.revoke_vote_delegation) - revokes the
+//! vote delegation of the councilor
+//! - [vote_as_delegate](./struct.Module.html#method.vote_as_delegate) - registers a vote for the
+//! proposal on behalf of the councilor
 //! - [cancel_proposal](./struct.Module.html#method.cancel_proposal) - cancels the proposal
 //! (can be canceled only by owner)
 //! - [veto_proposal](./struct.Module.html#method.veto_proposal) - vetoes the proposal
@@ -59,6 +65,8 @@
 //! provided parameters
 //! - [ensure_create_proposal_parameters_are_valid](./struct.Module.html#method.ensure_create_proposal_parameters_are_valid) -
 //! ensures that we can create the proposal
+//! - [amend_proposal](./struct.Module.html#method.amend_proposal) - replaces the executable code
+//! of the active proposal and restarts its voting
 //! - [reject_active_proposals](./struct.Module.html#method.reject_active_proposals) - rejects all
 //! active proposals.
 //! - [reactivate_pending_constitutionality_proposals](./struct.Module.html#method.reactivate_pending_constitutionality_proposals) -
@@ -148,12 +156,14 @@ use types::ProposalOf;
 pub use types::{
     ApprovedProposalDecision, BalanceOf, ExecutionStatus, Proposal, ProposalCodeDecoder,
     ProposalCreationParameters, ProposalDecision, ProposalExecutable, ProposalParameters,
-    ProposalStatus, VoteKind, VotersParameters, VotingResults,
+    ProposalStatus, VoteDelegation, VoteDelegationOf, VoteKind, VoteRecord, VoteRecordOf,
+    VotersParameters, VotingResults,
 };
 
 pub(crate) mod types;
 
 mod benchmarking;
+pub mod runtime_api;
 
 #[cfg(test)]
 mod tests;
@@ -162,14 +172,17 @@ pub use weights::WeightInfo;
 
 use codec::{Decode, MaxEncodedLen};
 use frame_support::dispatch::{DispatchError, DispatchResult, UnfilteredDispatchable};
-use frame_support::storage::{bounded_vec::BoundedVec, IterableStorageMap};
+use frame_support::storage::{
+    bounded_vec::BoundedVec, IterableStorageDoubleMap, IterableStorageMap,
+};
 use frame_support::traits::{Get, LockIdentifier};
 use frame_support::weights::{GetDispatchInfo, Weight};
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, Parameter, StorageDoubleMap,
 };
-use frame_system::{ensure_root, RawOrigin};
+use frame_system::{ensure_root, ensure_signed, RawOrigin};
 use sp_arithmetic::traits::{SaturatedConversion, Saturating, Zero};
+use sp_runtime::traits::Hash;
 use sp_std::convert::TryInto;
 use sp_std::vec::Vec;
 
@@ -251,6 +264,9 @@ pub trait Config:
 
     /// Validates staking account ownership for a member.
     type StakingAccountValidator: common::StakingAccountValidator<Self>;
+
+    /// Defines max period (in blocks) a councilor can delegate the voting for.
+    type MaxVoteDelegationPeriod: Get<Self::BlockNumber>;
 }
 
 /// Proposal state change observer.
@@ -274,6 +290,7 @@ decl_event!(
         <T as Config>::ProposalId,
         MemberId = MemberId<T>,
         <T as frame_system::Config>::BlockNumber,
+        <T as frame_system::Config>::AccountId,
     {
         /// Emits on proposal creation.
         /// Params:
@@ -312,6 +329,18 @@ decl_event!(
         /// - proposal id
         /// - message
         ProposerRemarked(MemberId, ProposalId, Vec<u8>),
+
+        /// Emits on the councilor delegating the voting
+        /// Params:
+        /// - councilor id
+        /// - delegate account id
+        /// - block starting from which the delegation is not valid
+        VoteDelegated(MemberId, AccountId, BlockNumber),
+
+        /// Emits on the councilor revoking the vote delegation
+        /// Params:
+        /// - councilor id
+        VoteDelegationRevoked(MemberId),
     }
 );
 
@@ -386,6 +415,24 @@ decl_error! {
 
         /// The size of encoded dispatchable call to be executed by the proposal is too big
         MaxDispatchableCallCodeSizeExceeded,
+
+        /// The proposal was approved by a council already and cannot be amended.
+        ProposalApprovedByCouncil,
+
+        /// Vote delegation period cannot be zero or exceed the max vote delegation period.
+        InvalidVoteDelegationPeriod,
+
+        /// The councilor has no vote delegation.
+        VoteDelegationNotFound,
+
+        /// The account is not the vote delegate of the councilor.
+        NotVoteDelegate,
+
+        /// The vote delegation has expired.
+        VoteDelegationExpired,
+
+        /// The member is not a councilor.
+        NotCouncilor,
     }
 }
 
@@ -409,6 +456,16 @@ decl_storage! { generate_storage_info
         /// Double map for preventing duplicate votes. Should be cleaned after usage.
         pub VoteExistsByProposalByVoter get(fn vote_by_proposal_by_voter):
             double_map hasher(blake2_128_concat) T::ProposalId, hasher(blake2_128_concat) MemberId<T> => VoteKind;
+
+        /// Double map of the councilor votes with their rationale hashes. Cleaned together with
+        /// the duplicate votes map.
+        pub VoteRecordByProposalByVoter get(fn vote_record_by_proposal_by_voter):
+            double_map hasher(blake2_128_concat) T::ProposalId, hasher(blake2_128_concat) MemberId<T>
+                => Option<VoteRecordOf<T>>;
+
+        /// Map of the accounts allowed to vote on behalf of the councilors.
+        pub VoteDelegationByCouncilor get(fn vote_delegation_by_councilor):
+            map hasher(blake2_128_concat) MemberId<T> => Option<VoteDelegationOf<T>>;
     }
 }
 
@@ -440,6 +497,9 @@ decl_module! {
         /// Exports const - staking handler lock id.
         const StakingHandlerLockId: LockIdentifier = T::StakingHandler::lock_id();
 
+        /// Exports const - max period (in blocks) a councilor can delegate the voting for.
+        const MaxVoteDelegationPeriod: T::BlockNumber = T::MaxVoteDelegationPeriod::get();
+
         /// Block Initialization. Perform voting period check, vote result tally, approved proposals
         /// grace period checks, and proposal execution.
         /// # <weight>
@@ -490,30 +550,114 @@ decl_module! {
         ) {
             T::CouncilOriginValidator::ensure_member_consulate(origin, voter_id)?;
 
-            ensure!(<Proposals<T>>::contains_key(proposal_id), Error::<T>::ProposalNotFound);
-            let mut proposal = Self::proposals(proposal_id);
+            Self::cast_vote(voter_id, proposal_id, vote, rationale, None)?;
+        }
+
+        /// Allow the account to vote on behalf of the councilor for the provided number of
+        /// blocks. Replaces the existing vote delegation of the councilor.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)` doesn't depend on the state or parameters
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoEngine::<T>::delegate_vote()]
+        pub fn delegate_vote(
+            origin,
+            councilor_id: MemberId<T>,
+            delegate: T::AccountId,
+            period: T::BlockNumber,
+        ) {
+            T::CouncilOriginValidator::ensure_member_consulate(origin, councilor_id)?;
 
             ensure!(
-                matches!(proposal.status, ProposalStatus::Active{..}),
-                Error::<T>::ProposalFinalized
+                !period.is_zero() && period <= T::MaxVoteDelegationPeriod::get(),
+                Error::<T>::InvalidVoteDelegationPeriod
             );
 
-            let did_not_vote_before = !<VoteExistsByProposalByVoter<T>>::contains_key(
-                proposal_id,
-                voter_id,
+            let expires_at = Self::current_block().saturating_add(period);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <VoteDelegationByCouncilor<T>>::insert(
+                councilor_id,
+                VoteDelegation {
+                    delegate: delegate.clone(),
+                    expires_at,
+                },
             );
 
-            ensure!(did_not_vote_before, Error::<T>::AlreadyVoted);
+            Self::deposit_event(RawEvent::VoteDelegated(councilor_id, delegate, expires_at));
+        }
+
+        /// Revoke the vote delegation of the councilor.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)` doesn't depend on the state or parameters
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoEngine::<T>::revoke_vote_delegation()]
+        pub fn revoke_vote_delegation(origin, councilor_id: MemberId<T>) {
+            T::CouncilOriginValidator::ensure_member_consulate(origin, councilor_id)?;
 
-            proposal.voting_results.add_vote(vote.clone());
+            ensure!(
+                <VoteDelegationByCouncilor<T>>::contains_key(councilor_id),
+                Error::<T>::VoteDelegationNotFound
+            );
 
             //
             // == MUTATION SAFE ==
             //
 
-            <Proposals<T>>::insert(proposal_id, proposal);
-            <VoteExistsByProposalByVoter<T>>::insert(proposal_id, voter_id, vote.clone());
-            Self::deposit_event(RawEvent::Voted(voter_id, proposal_id, vote, rationale));
+            <VoteDelegationByCouncilor<T>>::remove(councilor_id);
+
+            Self::deposit_event(RawEvent::VoteDelegationRevoked(councilor_id));
+        }
+
+        /// Vote on behalf of the councilor. Conditions: origin must be the unexpired vote
+        /// delegate of the councilor.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (R)` where:
+        /// - `R` is the size of `rationale` in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoEngine::<T>::vote_as_delegate(to_kb(rationale.len().saturated_into()))]
+        pub fn vote_as_delegate(
+            origin,
+            councilor_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            vote: VoteKind,
+            rationale: Vec<u8>,
+        ) {
+            let account_id = ensure_signed(origin)?;
+
+            let delegation = Self::vote_delegation_by_councilor(councilor_id)
+                .ok_or(Error::<T>::VoteDelegationNotFound)?;
+
+            ensure!(delegation.delegate == account_id, Error::<T>::NotVoteDelegate);
+
+            ensure!(
+                Self::current_block() < delegation.expires_at,
+                Error::<T>::VoteDelegationExpired
+            );
+
+            ensure!(
+                T::CouncilOriginValidator::is_council_member(&councilor_id),
+                Error::<T>::NotCouncilor
+            );
+
+            Self::cast_vote(councilor_id, proposal_id, vote, rationale, Some(account_id))?;
         }
 
         /// Cancel a proposal by its original proposer.
@@ -607,6 +751,55 @@ decl_module! {
 }
 
 impl<T: Config> Module<T> {
+    // Registers the councilor vote on the active proposal.
+    fn cast_vote(
+        voter_id: MemberId<T>,
+        proposal_id: T::ProposalId,
+        vote: VoteKind,
+        rationale: Vec<u8>,
+        delegate: Option<T::AccountId>,
+    ) -> DispatchResult {
+        ensure!(
+            <Proposals<T>>::contains_key(proposal_id),
+            Error::<T>::ProposalNotFound
+        );
+        let mut proposal = Self::proposals(proposal_id);
+
+        ensure!(
+            matches!(proposal.status, ProposalStatus::Active { .. }),
+            Error::<T>::ProposalFinalized
+        );
+
+        let did_not_vote_before =
+            !<VoteExistsByProposalByVoter<T>>::contains_key(proposal_id, voter_id);
+
+        ensure!(did_not_vote_before, Error::<T>::AlreadyVoted);
+
+        proposal.voting_results.add_vote(vote.clone());
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        let vote_record = VoteRecord {
+            vote_kind: vote.clone(),
+            rationale_hash: T::Hashing::hash(&rationale),
+            delegate,
+        };
+
+        <Proposals<T>>::insert(proposal_id, proposal);
+        <VoteExistsByProposalByVoter<T>>::insert(proposal_id, voter_id, vote.clone());
+        <VoteRecordByProposalByVoter<T>>::insert(proposal_id, voter_id, vote_record);
+        Self::deposit_event(RawEvent::Voted(voter_id, proposal_id, vote, rationale));
+
+        Ok(())
+    }
+
+    /// Votes registered for the proposal with the ids of the voting councilors.
+    pub fn proposal_votes(proposal_id: T::ProposalId) -> Vec<(MemberId<T>, VoteRecordOf<T>)> {
+        <VoteRecordByProposalByVoter<T>>::iter_prefix(proposal_id).collect()
+    }
+
     /// Create proposal. Requires 'proposal origin' membership.
     pub fn create_proposal(
         creation_params: ProposalCreationParameters<
@@ -667,6 +860,65 @@ impl<T: Config> Module<T> {
         Ok(proposal_id)
     }
 
+    /// Replaces the executable code of the active proposal that was not approved by any council
+    /// yet. The voting restarts: the voting results and the votes of the councilors are reset and
+    /// the voting period starts from the current block.
+    pub fn amend_proposal(
+        proposal_id: T::ProposalId,
+        proposer_id: MemberId<T>,
+        encoded_dispatchable_call_code: Vec<u8>,
+    ) -> DispatchResult {
+        ensure!(
+            <Proposals<T>>::contains_key(proposal_id),
+            Error::<T>::ProposalNotFound
+        );
+        let proposal = Self::proposals(proposal_id);
+
+        ensure!(proposer_id == proposal.proposer_id, Error::<T>::NotAuthor);
+        ensure!(
+            matches!(proposal.status, ProposalStatus::Active { .. }),
+            Error::<T>::ProposalFinalized
+        );
+        ensure!(
+            proposal.nr_of_council_confirmations == 0,
+            Error::<T>::ProposalApprovedByCouncil
+        );
+
+        let now = Self::current_block();
+        if let Some(execution_block) = proposal.exact_execution_block {
+            ensure!(
+                execution_block
+                    >= now
+                        .saturating_add(proposal.parameters.grace_period)
+                        .saturating_add(proposal.parameters.voting_period),
+                Error::<T>::InvalidExactExecutionBlock
+            );
+        }
+
+        let encoded_dispatchable_call_code: BoundedVec<u8, T::DispatchableCallCodeMaxLen> =
+            encoded_dispatchable_call_code
+                .try_into()
+                .map_err(|_| Error::<T>::MaxDispatchableCallCodeSizeExceeded)?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
+        <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id, None);
+        <VoteRecordByProposalByVoter<T>>::remove_prefix(&proposal_id, None);
+        <Proposals<T>>::insert(
+            proposal_id,
+            Proposal {
+                activated_at: now,
+                voting_results: VotingResults::default(),
+                ..proposal
+            },
+        );
+
+        Ok(())
+    }
+
     /// Performs all checks for the proposal creation:
     /// - title, body lengths
     /// - max active proposal
@@ -818,6 +1070,7 @@ impl<T: Config> Module<T> {
             })
             .for_each(|(proposal_id, proposal)| {
                 <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id, None);
+                <VoteRecordByProposalByVoter<T>>::remove_prefix(&proposal_id, None);
                 <Proposals<T>>::insert(proposal_id, proposal.clone());
 
                 // fire the proposal status update event
@@ -1028,6 +1281,7 @@ impl<T: Config> Module<T> {
         <Proposals<T>>::remove(proposal_id);
         <DispatchableCallCode<T>>::remove(proposal_id);
         <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id, None);
+        <VoteRecordByProposalByVoter<T>>::remove_prefix(&proposal_id, None);
         let _ = Self::decrease_active_proposal_counter();
 
         T::ProposalObserver::proposal_removed(proposal_id);
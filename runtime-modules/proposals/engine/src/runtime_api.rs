@@ -0,0 +1,22 @@
+//! Runtime API definition for the proposals engine module.
+
+use crate::{VoteDelegation, VoteRecord};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Read-only access to the proposal votes for the node RPC and off-chain clients.
+    pub trait ProposalsEngineApi<AccountId, MemberId, ProposalId, BlockNumber, Hash> where
+        AccountId: Codec,
+        MemberId: Codec,
+        ProposalId: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+    {
+        /// Votes of the councilors registered for the active proposal.
+        fn proposal_votes(proposal_id: ProposalId) -> Vec<(MemberId, VoteRecord<AccountId, Hash>)>;
+
+        /// Account allowed to vote on behalf of the councilor, if any.
+        fn vote_delegation(councilor_id: MemberId) -> Option<VoteDelegation<AccountId, BlockNumber>>;
+    }
+}
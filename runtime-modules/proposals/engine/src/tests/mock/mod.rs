@@ -14,6 +14,7 @@ use frame_support::{
 };
 pub use frame_system;
 use frame_system::{EnsureRoot, EnsureSigned};
+use referendum::BallotMode;
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -55,6 +56,9 @@ parameter_types! {
     pub const VotingLockId: LockIdentifier = *b"referend";
     pub const MinimumPeriod: u64 = 5;
     pub const MaxWinnerTargetCount: u32 = 10;
+    pub const MaxVoteLockPeriod: u32 = 6;
+    pub const ReferendumBallotMode: BallotMode = BallotMode::FirstPastThePost;
+    pub const MaxRankedOptions: u32 = 5;
 }
 
 impl referendum::Config<ReferendumInstance> for Test {
@@ -76,9 +80,14 @@ impl referendum::Config<ReferendumInstance> for Test {
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
 
+    type MaxVoteLockPeriod = MaxVoteLockPeriod;
+    type BallotMode = ReferendumBallotMode;
+    type MaxRankedOptions = MaxRankedOptions;
+
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
         _: &Self::Balance,
+        _: u32,
     ) -> Self::VotePower {
         1
     }
@@ -211,6 +220,7 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for Wg {
 
 parameter_types! {
     pub const DispatchableCallCodeMaxLen: u32 = 1024 * 1024;
+    pub const MaxVoteDelegationPeriod: u64 = 100;
 }
 
 impl crate::Config for Test {
@@ -230,6 +240,7 @@ impl crate::Config for Test {
     type WeightInfo = ();
     type StakingAccountValidator = ();
     type DispatchableCallCodeMaxLen = DispatchableCallCodeMaxLen;
+    type MaxVoteDelegationPeriod = MaxVoteDelegationPeriod;
 }
 
 pub const STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER: u64 = 222;
@@ -271,6 +282,10 @@ impl common::council::CouncilOriginValidator<Origin, u64, u64> for () {
 
         Ok(())
     }
+
+    fn is_council_member(_: &u64) -> bool {
+        true
+    }
 }
 
 // If changing count is required, we can upgrade the implementation as shown here:
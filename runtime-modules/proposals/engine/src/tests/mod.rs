@@ -9,7 +9,7 @@ use frame_support::dispatch::DispatchResult;
 use frame_support::traits::{
     Currency, LockableCurrency, OnFinalize, OnInitialize, WithdrawReasons,
 };
-use frame_support::{StorageDoubleMap, StorageMap, StorageValue};
+use frame_support::{assert_ok, StorageDoubleMap, StorageMap, StorageValue};
 use frame_system::RawOrigin;
 use frame_system::{EventRecord, Phase};
 
@@ -275,7 +275,7 @@ impl VoteGenerator {
 
 struct EventFixture;
 impl EventFixture {
-    fn assert_events(expected_raw_events: Vec<RawEvent<u32, u64, u64>>) {
+    fn assert_events(expected_raw_events: Vec<RawEvent<u32, u64, u64, u64>>) {
         let expected_events = expected_raw_events
             .iter()
             .map(|ev| EventRecord {
@@ -301,7 +301,7 @@ impl EventFixture {
         assert_eq!(System::events(), expected_events);
     }
 
-    pub fn assert_last_crate_event(expected_raw_event: RawEvent<u32, u64, u64>) {
+    pub fn assert_last_crate_event(expected_raw_event: RawEvent<u32, u64, u64, u64>) {
         let converted_event = TestEvent::ProposalsEngine(expected_raw_event);
 
         Self::assert_last_global_event(converted_event)
@@ -950,6 +950,396 @@ fn vote_proposal_event_emitted() {
     });
 }
 
+#[test]
+fn vote_record_is_stored_with_rationale_hash() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+        let rationale = b"rationale".to_vec();
+
+        assert_ok!(ProposalsEngine::vote(
+            RawOrigin::Signed(1).into(),
+            1,
+            proposal_id,
+            VoteKind::Approve,
+            rationale.clone(),
+        ));
+
+        let expected_vote_record = VoteRecord {
+            vote_kind: VoteKind::Approve,
+            rationale_hash: <Test as frame_system::Config>::Hashing::hash(&rationale),
+            delegate: None,
+        };
+
+        assert_eq!(
+            ProposalsEngine::vote_record_by_proposal_by_voter(proposal_id, 1),
+            Some(expected_vote_record.clone())
+        );
+        assert_eq!(
+            ProposalsEngine::proposal_votes(proposal_id),
+            vec![(1, expected_vote_record)]
+        );
+    });
+}
+
+#[test]
+fn delegate_vote_succeeds() {
+    initial_test_ext().execute_with(|| {
+        run_to_block_and_finalize(1);
+
+        let councilor_id = 1;
+        let delegate = 10;
+        let period = 20;
+
+        assert_ok!(ProposalsEngine::delegate_vote(
+            RawOrigin::Signed(1).into(),
+            councilor_id,
+            delegate,
+            period,
+        ));
+
+        let expires_at = System::block_number() + period;
+
+        assert_eq!(
+            ProposalsEngine::vote_delegation_by_councilor(councilor_id),
+            Some(VoteDelegation {
+                delegate,
+                expires_at
+            })
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::VoteDelegated(
+            councilor_id,
+            delegate,
+            expires_at,
+        ));
+    });
+}
+
+#[test]
+fn delegate_vote_fails_with_invalid_period() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalsEngine::delegate_vote(RawOrigin::Signed(1).into(), 1, 10, 0),
+            Err(Error::<Test>::InvalidVoteDelegationPeriod.into())
+        );
+
+        let max_period = <Test as crate::Config>::MaxVoteDelegationPeriod::get();
+
+        assert_eq!(
+            ProposalsEngine::delegate_vote(RawOrigin::Signed(1).into(), 1, 10, max_period + 1),
+            Err(Error::<Test>::InvalidVoteDelegationPeriod.into())
+        );
+    });
+}
+
+#[test]
+fn delegate_vote_fails_with_insufficient_rights() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalsEngine::delegate_vote(RawOrigin::None.into(), 1, 10, 20),
+            Err(DispatchError::BadOrigin)
+        );
+    });
+}
+
+#[test]
+fn revoke_vote_delegation_succeeds() {
+    initial_test_ext().execute_with(|| {
+        run_to_block_and_finalize(1);
+
+        let councilor_id = 1;
+
+        assert_ok!(ProposalsEngine::delegate_vote(
+            RawOrigin::Signed(1).into(),
+            councilor_id,
+            10,
+            20,
+        ));
+
+        assert_ok!(ProposalsEngine::revoke_vote_delegation(
+            RawOrigin::Signed(1).into(),
+            councilor_id,
+        ));
+
+        assert_eq!(
+            ProposalsEngine::vote_delegation_by_councilor(councilor_id),
+            None
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::VoteDelegationRevoked(councilor_id));
+    });
+}
+
+#[test]
+fn revoke_vote_delegation_fails_without_delegation() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalsEngine::revoke_vote_delegation(RawOrigin::Signed(1).into(), 1),
+            Err(Error::<Test>::VoteDelegationNotFound.into())
+        );
+    });
+}
+
+#[test]
+fn vote_as_delegate_succeeds() {
+    initial_test_ext().execute_with(|| {
+        run_to_block_and_finalize(1);
+
+        let councilor_id = 1;
+        let delegate = 10;
+        let rationale = b"rationale".to_vec();
+
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        assert_ok!(ProposalsEngine::delegate_vote(
+            RawOrigin::Signed(1).into(),
+            councilor_id,
+            delegate,
+            20,
+        ));
+
+        assert_ok!(ProposalsEngine::vote_as_delegate(
+            RawOrigin::Signed(delegate).into(),
+            councilor_id,
+            proposal_id,
+            VoteKind::Reject,
+            rationale.clone(),
+        ));
+
+        assert_eq!(
+            ProposalsEngine::vote_by_proposal_by_voter(proposal_id, councilor_id),
+            VoteKind::Reject
+        );
+        assert_eq!(
+            ProposalsEngine::vote_record_by_proposal_by_voter(proposal_id, councilor_id),
+            Some(VoteRecord {
+                vote_kind: VoteKind::Reject,
+                rationale_hash: <Test as frame_system::Config>::Hashing::hash(&rationale),
+                delegate: Some(delegate),
+            })
+        );
+        assert_eq!(
+            ProposalsEngine::proposals(proposal_id).voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 0,
+                rejections: 1,
+                slashes: 0,
+            }
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::Voted(
+            councilor_id,
+            proposal_id,
+            VoteKind::Reject,
+            rationale,
+        ));
+
+        // the councilor cannot vote again on the same proposal
+        assert_eq!(
+            ProposalsEngine::vote(
+                RawOrigin::Signed(1).into(),
+                councilor_id,
+                proposal_id,
+                VoteKind::Approve,
+                Vec::new(),
+            ),
+            Err(Error::<Test>::AlreadyVoted.into())
+        );
+    });
+}
+
+#[test]
+fn vote_as_delegate_fails_without_delegation() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        assert_eq!(
+            ProposalsEngine::vote_as_delegate(
+                RawOrigin::Signed(10).into(),
+                1,
+                proposal_id,
+                VoteKind::Approve,
+                Vec::new(),
+            ),
+            Err(Error::<Test>::VoteDelegationNotFound.into())
+        );
+    });
+}
+
+#[test]
+fn vote_as_delegate_fails_with_invalid_delegate() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        assert_ok!(ProposalsEngine::delegate_vote(
+            RawOrigin::Signed(1).into(),
+            1,
+            10,
+            20,
+        ));
+
+        assert_eq!(
+            ProposalsEngine::vote_as_delegate(
+                RawOrigin::Signed(11).into(),
+                1,
+                proposal_id,
+                VoteKind::Approve,
+                Vec::new(),
+            ),
+            Err(Error::<Test>::NotVoteDelegate.into())
+        );
+    });
+}
+
+#[test]
+fn vote_as_delegate_fails_with_expired_delegation() {
+    initial_test_ext().execute_with(|| {
+        let period = 2;
+
+        assert_ok!(ProposalsEngine::delegate_vote(
+            RawOrigin::Signed(1).into(),
+            1,
+            10,
+            period,
+        ));
+
+        run_to_block(System::block_number() + period);
+
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        assert_eq!(
+            ProposalsEngine::vote_as_delegate(
+                RawOrigin::Signed(10).into(),
+                1,
+                proposal_id,
+                VoteKind::Approve,
+                Vec::new(),
+            ),
+            Err(Error::<Test>::VoteDelegationExpired.into())
+        );
+    });
+}
+
+#[test]
+fn vote_records_are_removed_with_the_proposal() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        assert_eq!(ProposalsEngine::proposal_votes(proposal_id).len(), 1);
+
+        assert_ok!(ProposalsEngine::veto_proposal(
+            RawOrigin::Root.into(),
+            proposal_id
+        ));
+
+        assert!(ProposalsEngine::proposal_votes(proposal_id).is_empty());
+    });
+}
+
+#[test]
+fn amend_proposal_succeeds() {
+    initial_test_ext().execute_with(|| {
+        run_to_block_and_finalize(1);
+
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        run_to_block_and_finalize(2);
+
+        let amended_code = mock::proposals::Call::<Test>::dummy_proposal {
+            _title: b"amended title".to_vec(),
+            _description: b"amended description".to_vec(),
+        }
+        .encode();
+
+        assert_ok!(ProposalsEngine::amend_proposal(
+            proposal_id,
+            1,
+            amended_code.clone()
+        ));
+
+        let proposal = ProposalsEngine::proposals(proposal_id);
+
+        assert_eq!(proposal.activated_at, 2);
+        assert_eq!(proposal.voting_results, VotingResults::default());
+        assert_eq!(
+            ProposalsEngine::proposal_codes(proposal_id).to_vec(),
+            amended_code
+        );
+        assert!(ProposalsEngine::proposal_votes(proposal_id).is_empty());
+        assert!(!<crate::VoteExistsByProposalByVoter<Test>>::contains_key(
+            proposal_id,
+            1
+        ));
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_invalid_proposal_id() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalsEngine::amend_proposal(1, 1, Vec::new()),
+            Err(Error::<Test>::ProposalNotFound.into())
+        );
+    });
+}
+
+#[test]
+fn amend_proposal_fails_with_not_author() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(proposal_id, 2, Vec::new()),
+            Err(Error::<Test>::NotAuthor.into())
+        );
+    });
+}
+
+#[test]
+fn amend_proposal_fails_after_council_approval() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_constitutionality(2);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(2);
+
+        ProposalsEngine::reactivate_pending_constitutionality_proposals();
+
+        assert_eq!(
+            ProposalsEngine::proposals(proposal_id).nr_of_council_confirmations,
+            1
+        );
+
+        assert_eq!(
+            ProposalsEngine::amend_proposal(proposal_id, 1, Vec::new()),
+            Err(Error::<Test>::ProposalApprovedByCouncil.into())
+        );
+    });
+}
+
 #[test]
 fn create_proposal_and_expire_it() {
     initial_test_ext().execute_with(|| {
@@ -46,6 +46,31 @@ impl Default for VoteKind {
     }
 }
 
+/// Councilor vote on the proposal stored for the duration of the proposal voting.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct VoteRecord<AccountId, Hash> {
+    /// Kind of the vote.
+    pub vote_kind: VoteKind,
+
+    /// Hash of the vote rationale.
+    pub rationale_hash: Hash,
+
+    /// Delegate account that voted on behalf of the councilor, if any.
+    pub delegate: Option<AccountId>,
+}
+
+/// Account allowed to vote on behalf of a councilor.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct VoteDelegation<AccountId, BlockNumber> {
+    /// Account that votes on behalf of the councilor.
+    pub delegate: AccountId,
+
+    /// The delegation is not valid starting from this block.
+    pub expires_at: BlockNumber,
+}
+
 /// Proposal parameters required to manage proposal risk.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
@@ -440,3 +465,13 @@ pub(crate) type ProposalOf<T> = Proposal<
     BalanceOf<T>,
     <T as frame_system::Config>::AccountId,
 >;
+
+/// Type alias for the vote record of the councilor.
+pub type VoteRecordOf<T> =
+    VoteRecord<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::Hash>;
+
+/// Type alias for the vote delegation of the councilor.
+pub type VoteDelegationOf<T> = VoteDelegation<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+>;
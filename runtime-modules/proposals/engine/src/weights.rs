@@ -46,6 +46,9 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for proposals_engine.
 pub trait WeightInfo {
 	fn vote(_i: u32, ) -> Weight;
+	fn delegate_vote() -> Weight;
+	fn revoke_vote_delegation() -> Weight;
+	fn vote_as_delegate(_i: u32, ) -> Weight;
 	fn cancel_proposal() -> Weight;
 	fn veto_proposal() -> Weight;
 	fn proposer_remark() -> Weight;
@@ -64,12 +67,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Council CouncilMembers (r:1 w:0)
 	// Storage: ProposalEngine Proposals (r:1 w:1)
 	// Storage: ProposalEngine VoteExistsByProposalByVoter (r:1 w:1)
+	// Storage: ProposalEngine VoteRecordByProposalByVoter (r:0 w:1)
 	fn vote(i: u32, ) -> Weight {
 		(47_888_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((675_000 as Weight).saturating_mul(i as Weight))
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Council CouncilMembers (r:1 w:0)
+	// Storage: ProposalEngine VoteDelegationByCouncilor (r:0 w:1)
+	fn delegate_vote() -> Weight {
+		(28_412_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Storage: Council CouncilMembers (r:1 w:0)
+	// Storage: ProposalEngine VoteDelegationByCouncilor (r:1 w:1)
+	fn revoke_vote_delegation() -> Weight {
+		(27_903_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: ProposalEngine VoteDelegationByCouncilor (r:1 w:0)
+	// Storage: Council CouncilMembers (r:1 w:0)
+	// Storage: ProposalEngine Proposals (r:1 w:1)
+	// Storage: ProposalEngine VoteExistsByProposalByVoter (r:1 w:1)
+	// Storage: ProposalEngine VoteRecordByProposalByVoter (r:0 w:1)
+	fn vote_as_delegate(i: u32, ) -> Weight {
+		(49_107_000 as Weight)
+			// Standard Error: 1_000
+			.saturating_add((679_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Storage: ProposalEngine Proposals (r:1 w:1)
@@ -205,6 +237,15 @@ impl WeightInfo for () {
 	fn vote(i: u32, ) -> Weight {
 		0
 	}
+	fn delegate_vote() -> Weight {
+		0
+	}
+	fn revoke_vote_delegation() -> Weight {
+		0
+	}
+	fn vote_as_delegate(i: u32, ) -> Weight {
+		0
+	}
 	fn cancel_proposal() -> Weight {
 		0
 	}
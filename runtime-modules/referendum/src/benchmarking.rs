@@ -13,6 +13,8 @@ use sp_std::prelude::*;
 use crate::Module as Referendum;
 
 const SEED: u32 = 0;
+// Maximum number of the ranked ballots counted at the end of the revealing stage in benchmarks
+const MAX_RANKED_BALLOTS: u32 = 100;
 
 // This is needed to prevent a circular reference with council
 // when running the benchmarks. Since you need to vote for a valid
@@ -94,7 +96,7 @@ fn make_multiple_votes_for_multiple_options<
 
         intermediate_winners.push(OptionResult {
             option_id: option,
-            vote_power: T::calculate_vote_power(&account_id, &stake),
+            vote_power: T::calculate_vote_power(&account_id, &stake, 0),
         });
         votes.push(Vote::<T, I> {
             account_id,
@@ -140,6 +142,7 @@ fn vote_for<
         RawOrigin::Signed(account_id.clone()).into(),
         commitment,
         stake,
+        0,
     )
     .unwrap();
 
@@ -155,6 +158,7 @@ fn vote_for<
             stake,
             cycle_id: cycle_id.into(),
             vote_for: None,
+            lock_period: 0,
         },
         "Vote is not correctly Stored",
     );
@@ -224,6 +228,55 @@ fn move_to_block_before_initialize<T: Config<I>, I: Instance>(
     assert!(Stage::<T, I>::get() == target_stage, "Stage not reached");
 }
 
+// Intermediate winners are updated on the vote reveal only with the first past the post ballot
+// mode, the ranked ballots are counted at the end of the revealing stage.
+fn revealed_intermediate_winners<T: Config<I>, I: Instance>(
+    winners: Vec<OptionResult<T::MemberId, T::VotePower>>,
+) -> IntermediateWinnersOf<T, I> {
+    match T::BallotMode::get() {
+        BallotMode::FirstPastThePost => winners.try_into().unwrap(),
+        BallotMode::SingleTransferableVote => WeakBoundedVec::default(),
+    }
+}
+
+// Creates the given number of options in the order of their IDs.
+fn create_options<
+    T: Config<I>
+        + membership::Config
+        + OptionCreator<
+            <T as frame_system::Config>::AccountId,
+            <T as common::membership::MembershipTypes>::MemberId,
+        >,
+    I: Instance,
+>(
+    number_of_options: u32,
+) -> Vec<T::MemberId> {
+    (0..number_of_options)
+        .map(|id| {
+            let (account_id, option_id) = member_funded_account::<T, I>(id);
+            T::create_option(account_id, option_id);
+
+            option_id
+        })
+        .collect()
+}
+
+// Ranks the options differently for each voter: the options are rotated by the voter ID and
+// a varying number of the least preferred options is omitted.
+fn ranking_of<MemberId: Copy>(options: &[MemberId], voter_id: u32) -> Vec<MemberId> {
+    let options_number = options.len() as u32;
+    let rotation = voter_id % options_number;
+    let omitted = (voter_id / options_number) % options_number;
+
+    options
+        .iter()
+        .cycle()
+        .skip(rotation as usize)
+        .take((options_number - omitted) as usize)
+        .copied()
+        .collect()
+}
+
 fn get_byte(num: u32, byte_number: u8) -> u8 {
     ((num & (0xff << (8 * byte_number))) >> (8 * byte_number)) as u8
 }
@@ -349,11 +402,9 @@ fn add_and_reveal_multiple_votes_and_add_extra_unrevealed_vote<
     });
 
     let current_stage = ReferendumStage::Revealing(ReferendumStageRevealingOf::<T, I> {
-        intermediate_winners: multiple_votes
-            .intermediate_winners
-            .clone()
-            .try_into()
-            .unwrap(),
+        intermediate_winners: revealed_intermediate_winners::<T, I>(
+            multiple_votes.intermediate_winners.clone(),
+        ),
         started: target_block_number,
         winning_target_count: target_winners + 1,
         current_cycle_id: cycle_id.into(),
@@ -403,7 +454,9 @@ benchmarks_instance! {
 
         let target_stage = ReferendumStage::Revealing(
             ReferendumStageRevealingOf::<T, I> {
-                intermediate_winners: multiple_votes_with_extra.intermediate_winners.clone().try_into().unwrap(),
+                intermediate_winners: revealed_intermediate_winners::<T, I>(
+                    multiple_votes_with_extra.intermediate_winners.clone()
+                ),
                 started: started_voting_block_number + T::VoteStageDuration::get(),
                 winning_target_count: i + 1,
                 current_cycle_id: cycle_id,
@@ -427,6 +480,79 @@ benchmarks_instance! {
         );
     }
 
+    on_initialize_revealing_ranked {
+        let i in 1 .. MAX_RANKED_BALLOTS;
+
+        // a single winner maximizes the number of the counting rounds
+        start_voting_cycle::<T, I>(0);
+
+        let cycle_id = 1;
+        let salt = vec![0u8];
+        let stake = T::MinimumStake::get() + One::one();
+        let started_voting_block_number = System::<T>::block_number();
+
+        let options = create_options::<T, I>(T::MaxRankedOptions::get());
+
+        let voters: Vec<(T::AccountId, Vec<T::MemberId>)> = (0..i)
+            .map(|voter_id| {
+                let account_id = funded_account::<T, I>("voter", voter_id);
+                let ranked_options = ranking_of(&options, voter_id);
+                let commitment = Referendum::<T, I>::calculate_ranked_commitment(
+                    &account_id,
+                    &salt,
+                    &cycle_id,
+                    &ranked_options,
+                );
+
+                Referendum::<T, I>::vote(
+                    RawOrigin::Signed(account_id.clone()).into(),
+                    commitment,
+                    stake,
+                    0,
+                )
+                .unwrap();
+
+                (account_id, ranked_options)
+            })
+            .collect();
+
+        let revealing_started = T::VoteStageDuration::get() + started_voting_block_number;
+        let target_stage = || ReferendumStage::Revealing(ReferendumStageRevealingOf::<T, I> {
+            started: revealing_started,
+            winning_target_count: 1,
+            intermediate_winners: WeakBoundedVec::default(),
+            current_cycle_id: cycle_id,
+            ends_at: revealing_started + T::RevealStageDuration::get(),
+        });
+
+        move_to_block::<T, I>(revealing_started, target_stage());
+
+        voters.into_iter().for_each(|(account_id, ranked_options)| {
+            Referendum::<T, I>::reveal_ranked_vote(
+                RawOrigin::Signed(account_id).into(),
+                salt.clone(),
+                ranked_options,
+            )
+            .unwrap();
+        });
+
+        move_to_block_before_initialize::<T, I>(
+            revealing_started + T::RevealStageDuration::get(),
+            target_stage(),
+        );
+    }: { Referendum::<T, I>::on_initialize(System::<T>::block_number()); }
+    verify {
+        assert!(
+            Referendum::<T, I>::stage() == ReferendumStage::Inactive,
+            "Reveal perdiod hasn't ended",
+        );
+
+        assert!(
+            RankedBallots::<T, I>::iter().next().is_none(),
+            "Ranked ballots not removed",
+        );
+    }
+
     on_initialize_voting {
         let winning_target_count = 0;
         let cycle_id = 1;
@@ -480,7 +606,8 @@ benchmarks_instance! {
             );
 
         let stake = T::MinimumStake::get() + One::one();
-    }: _ (RawOrigin::Signed(account_id.clone()), commitment, stake)
+        let lock_period = T::MaxVoteLockPeriod::get();
+    }: _ (RawOrigin::Signed(account_id.clone()), commitment, stake, lock_period)
     verify {
         assert!(Votes::<T, I>::contains_key(account_id.clone()), "Vote wasn't added");
 
@@ -491,11 +618,14 @@ benchmarks_instance! {
                 stake,
                 cycle_id,
                 vote_for: None,
+                lock_period,
             },
             "Vote is not correctly Stored",
         );
 
-        assert_last_event::<T, I>(RawEvent::VoteCast(account_id.clone(), commitment, stake).into());
+        assert_last_event::<T, I>(
+            RawEvent::VoteCast(account_id.clone(), commitment, stake, lock_period).into()
+        );
     }
 
     reveal_vote_space_for_new_winner {
@@ -525,15 +655,20 @@ benchmarks_instance! {
             0,
             OptionResult{
                 option_id: multiple_votes_with_extra.member_id,
-                vote_power:
-                    T::calculate_vote_power(&multiple_votes_with_extra.account_id.clone(), &stake),
+                vote_power: T::calculate_vote_power(
+                    &multiple_votes_with_extra.account_id.clone(),
+                    &stake,
+                    0
+                ),
             }
         );
 
         assert!(
             Referendum::<T, I>::stage()
             == ReferendumStage::Revealing(ReferendumStageRevealing{
-                intermediate_winners: multiple_votes_with_extra.intermediate_winners.clone().try_into().unwrap(),
+                intermediate_winners: revealed_intermediate_winners::<T, I>(
+                    multiple_votes_with_extra.intermediate_winners.clone()
+                ),
                 winning_target_count: i + 1,
                 started: T::VoteStageDuration::get() + started_block_number,
                 current_cycle_id: cycle_id,
@@ -549,6 +684,7 @@ benchmarks_instance! {
                 stake,
                 cycle_id,
                 vote_for: Some(multiple_votes_with_extra.member_id),
+                lock_period: 0,
             },
             "Vote not revealed",
         );
@@ -588,7 +724,9 @@ benchmarks_instance! {
         assert!(
             Referendum::<T, I>::stage()
             == ReferendumStage::Revealing(ReferendumStageRevealing{
-                intermediate_winners: multiple_votes_with_extra.intermediate_winners.clone().try_into().unwrap(),
+                intermediate_winners: revealed_intermediate_winners::<T, I>(
+                    multiple_votes_with_extra.intermediate_winners.clone()
+                ),
                 winning_target_count: i + 1,
                 started: T::VoteStageDuration::get() + started_block_number,
                 current_cycle_id: cycle_id,
@@ -604,6 +742,7 @@ benchmarks_instance! {
                 stake,
                 cycle_id,
                 vote_for: Some(multiple_votes_with_extra.member_id),
+                lock_period: 0,
             },
             "Vote not revealed",
         );
@@ -644,13 +783,15 @@ benchmarks_instance! {
         multiple_votes_with_extra.intermediate_winners.insert(0, OptionResult{
             option_id: multiple_votes_with_extra.member_id,
             vote_power:
-                T::calculate_vote_power(&multiple_votes_with_extra.account_id.clone(), &stake),
+                T::calculate_vote_power(&multiple_votes_with_extra.account_id.clone(), &stake, 0),
         });
 
         assert!(
             Referendum::<T, I>::stage()
             == ReferendumStage::Revealing(ReferendumStageRevealing{
-                intermediate_winners: multiple_votes_with_extra.intermediate_winners.clone().try_into().unwrap(),
+                intermediate_winners: revealed_intermediate_winners::<T, I>(
+                    multiple_votes_with_extra.intermediate_winners.clone()
+                ),
                 winning_target_count: i + 1,
                 started: T::VoteStageDuration::get() + started_block_number,
                 current_cycle_id: cycle_id,
@@ -666,6 +807,7 @@ benchmarks_instance! {
                 stake,
                 cycle_id,
                 vote_for: Some(multiple_votes_with_extra.member_id),
+                lock_period: 0,
             },
             "Vote not revealed",
         );
@@ -715,7 +857,9 @@ benchmarks_instance! {
         assert!(
             Referendum::<T, I>::stage()
             == ReferendumStage::Revealing(ReferendumStageRevealing{
-                intermediate_winners: multiple_votes_with_extra.intermediate_winners.clone().try_into().unwrap(),
+                intermediate_winners: revealed_intermediate_winners::<T, I>(
+                    multiple_votes_with_extra.intermediate_winners.clone()
+                ),
                 winning_target_count: i + 1,
                 started: T::VoteStageDuration::get() + started_block_number,
                 current_cycle_id: cycle_id,
@@ -731,6 +875,7 @@ benchmarks_instance! {
                 stake,
                 cycle_id,
                 vote_for: Some(multiple_votes_with_extra.member_id),
+                lock_period: 0,
             },
             "Vote not revealed",
         );
@@ -744,6 +889,68 @@ benchmarks_instance! {
         );
     }
 
+    reveal_ranked_vote {
+        let i in 1 .. T::MaxRankedOptions::get();
+
+        start_voting_cycle::<T, I>(0);
+
+        let cycle_id = 1;
+        let salt = vec![0u8];
+
+        let ranked_options = create_options::<T, I>(i);
+
+        let account_id = funded_account::<T, I>("caller", 0);
+        let stake = T::MinimumStake::get() + One::one();
+        let commitment = Referendum::<T, I>::calculate_ranked_commitment(
+            &account_id,
+            &salt,
+            &cycle_id,
+            &ranked_options,
+        );
+
+        Referendum::<T, I>::vote(
+            RawOrigin::Signed(account_id.clone()).into(),
+            commitment,
+            stake,
+            0,
+        )
+        .unwrap();
+
+        let target_block_number = T::VoteStageDuration::get() + System::<T>::block_number();
+        let target_stage = ReferendumStage::Revealing(ReferendumStageRevealingOf::<T, I> {
+            started: target_block_number,
+            winning_target_count: 1,
+            intermediate_winners: WeakBoundedVec::default(),
+            current_cycle_id: cycle_id,
+            ends_at: target_block_number + T::RevealStageDuration::get(),
+        });
+
+        move_to_block::<T, I>(target_block_number, target_stage);
+    }: _ (RawOrigin::Signed(account_id.clone()), salt.clone(), ranked_options.clone())
+    verify {
+        assert_eq!(
+            Referendum::<T, I>::votes(account_id.clone()),
+            CastVote {
+                commitment,
+                stake,
+                cycle_id,
+                vote_for: ranked_options.first().copied(),
+                lock_period: 0,
+            },
+            "Vote not revealed",
+        );
+
+        assert_eq!(
+            Referendum::<T, I>::ranked_ballots(account_id.clone()).ranked_options.into_inner(),
+            ranked_options,
+            "Ranked ballot not stored",
+        );
+
+        assert_last_event::<T, I>(
+            RawEvent::RankedVoteRevealed(account_id, ranked_options, salt).into()
+        );
+    }
+
     release_vote_stake {
         start_voting_cycle::<T, I>(0);
 
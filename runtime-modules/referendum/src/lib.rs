@@ -17,10 +17,25 @@
 //! and the winning options can be decided by the total number of votes received or the total amount staked
 //! behind them.
 //!
+//! Voters can optionally lock their stake for additional referendum cycles (up to `MaxVoteLockPeriod`).
+//! The lock period is passed to the runtime's vote power calculation, so it can reward the longer
+//! commitment with a vote power multiplier, and to the runtime's stake unlocking check. A vote cast
+//! while the previous vote stake is still locked can't reduce the locked stake or the lock period.
+//!
+//! The winners are selected according to the runtime's `BallotMode`. With the first past the post
+//! mode each vote supports a single option and the options with the greatest total vote power win.
+//! With the single transferable vote mode voters can reveal a ranked ballot of up to
+//! `MaxRankedOptions` options, which is tallied using the Droop quota. The surplus of the elected
+//! options and the ballots of the eliminated options are transferred to the next preferences,
+//! so the vote is not split between like-minded options. A plain revealed vote counts as a ballot
+//! ranking a single option. The revealed ballots are stored per voter and tallied once at the end
+//! of the revealing stage, so no intermediate winners are available while revealing.
+//!
 //! ## Supported extrinsics
 //!
 //! - [vote](./struct.Module.html#method.vote)
 //! - [reveal_vote](./struct.Module.html#method.reveal_vote)
+//! - [reveal_ranked_vote](./struct.Module.html#method.reveal_ranked_vote)
 //! - [release_vote_stake](./struct.Module.html#method.release_vote_stake)
 //!
 //! ## Notes
@@ -55,14 +70,18 @@ use frame_support::traits::{EnsureOrigin, Get, LockIdentifier};
 use frame_support::weights::Weight;
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, error::BadOrigin,
-    storage::weak_bounded_vec::WeakBoundedVec, storage::StorageMap, Parameter, StorageValue,
+    storage::weak_bounded_vec::WeakBoundedVec, storage::IterableStorageMap, storage::StorageMap,
+    storage::StoragePrefixedMap, Parameter, StorageValue,
 };
 use frame_system::ensure_signed;
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::BaseArithmetic;
+use sp_arithmetic::{PerThing, Perbill};
 use sp_runtime::traits::{MaybeSerialize, Member, Saturating, Zero};
 use sp_runtime::SaturatedConversion;
-use sp_std::convert::TryInto;
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::convert::{TryFrom, TryInto};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -77,7 +96,9 @@ pub use benchmarking::OptionCreator;
 
 // declared modules
 mod benchmarking;
+mod migrations;
 mod mock;
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -156,6 +177,42 @@ pub struct CastVote<Hash, Currency, MemberId> {
     pub stake: Currency,
     // target option this vote favors; is `None` before the vote is revealed
     pub vote_for: Option<MemberId>,
+    // number of additional referendum cycles the stake stays locked for after the vote's cycle
+    pub lock_period: u32,
+}
+
+impl<Hash, Currency, MemberId> CastVote<Hash, Currency, MemberId> {
+    /// The last referendum cycle the vote stake is locked for.
+    pub fn locked_until_cycle(&self) -> u64 {
+        self.cycle_id.saturating_add(self.lock_period.into())
+    }
+}
+
+/// The way the revealed votes are counted to select the referendum winners.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum BallotMode {
+    /// Each vote supports a single option, the options with the greatest vote power win.
+    FirstPastThePost,
+    /// Votes rank the options in the order of preference and the winners are selected with
+    /// the single transferable vote.
+    SingleTransferableVote,
+}
+
+impl Default for BallotMode {
+    fn default() -> Self {
+        BallotMode::FirstPastThePost
+    }
+}
+
+/// Ranked ballot revealed in the referendum running with the single transferable vote.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, PartialEq, Eq, Debug, Default, Clone, TypeInfo, MaxEncodedLen)]
+pub struct RankedBallot<RankedOptions, VotePower> {
+    // options in the order of the voters' preference
+    pub ranked_options: RankedOptions,
+    // vote power of the revealed vote
+    pub vote_power: VotePower,
 }
 
 /////////////////// Type aliases ///////////////////////////////////////////////
@@ -178,6 +235,11 @@ pub type ReferendumStageRevealingOf<T, I> =
     ReferendumStageRevealing<<T as frame_system::Config>::BlockNumber, IntermediateWinnersOf<T, I>>;
 pub type OptionResultOf<T, I> =
     OptionResult<<T as common::membership::MembershipTypes>::MemberId, <T as Config<I>>::VotePower>;
+pub type RankedOptionsOf<T, I> = WeakBoundedVec<
+    <T as common::membership::MembershipTypes>::MemberId,
+    <T as Config<I>>::MaxRankedOptions,
+>;
+pub type RankedBallotOf<T, I> = RankedBallot<RankedOptionsOf<T, I>, <T as Config<I>>::VotePower>;
 
 // types aliases for check functions return values
 pub type CanRevealResult<T, I> = (
@@ -185,6 +247,11 @@ pub type CanRevealResult<T, I> = (
     <T as frame_system::Config>::AccountId,
     CastVoteOf<T>,
 );
+pub type CanRevealRankedResult<T, I> = (
+    <T as frame_system::Config>::AccountId,
+    CastVoteOf<T>,
+    RankedOptionsOf<T, I>,
+);
 
 /////////////////// Configs, Storage, Errors, and Events /////////////////////////
 
@@ -226,6 +293,14 @@ pub trait ReferendumManager<Origin, AccountId, MemberId, Hash> {
         cycle_id: &u64,
         vote_option_id: &MemberId,
     ) -> Hash;
+
+    /// Calculate commitment for a ranked vote.
+    fn calculate_ranked_commitment(
+        account_id: &AccountId,
+        salt: &[u8],
+        cycle_id: &u64,
+        ranked_options: &[MemberId],
+    ) -> Hash;
 }
 
 /// The main Referendum module's trait.
@@ -275,14 +350,25 @@ pub trait Config<I: Instance = DefaultInstance>:
     /// Maximum number of winning target count
     type MaxWinnerTargetCount: Get<u32>;
 
-    /// Calculate the vote's power for user and his stake.
+    /// Maximum number of additional referendum cycles the vote stake can be locked for.
+    type MaxVoteLockPeriod: Get<u32>;
+
+    /// The way the revealed votes are counted to select the referendum winners.
+    type BallotMode: Get<BallotMode>;
+
+    /// Maximum number of options ranked in a single ballot.
+    type MaxRankedOptions: Get<u32>;
+
+    /// Calculate the vote's power for user, his stake and the stake lock period.
     fn calculate_vote_power(
         account_id: &<Self as frame_system::Config>::AccountId,
         stake: &BalanceOf<Self>,
+        lock_period: u32,
     ) -> <Self as Config<I>>::VotePower;
 
     /// Checks if user can unlock his stake from the given vote.
-    /// Gives runtime an ability to penalize user for not revealing stake, etc.
+    /// Gives runtime an ability to penalize user for not revealing stake, enforce the vote's
+    /// lock period, etc.
     fn can_unlock_vote_stake(vote: &CastVote<Self::Hash, BalanceOf<Self>, Self::MemberId>) -> bool;
 
     /// Gives runtime an ability to react on referendum result.
@@ -317,6 +403,11 @@ decl_storage! { generate_storage_info
         /// Accounts that permanently opted out of voting in referendum.
         pub AccountsOptedOut get(fn accounts_opted_out): map hasher(blake2_128_concat)
             T::AccountId => ();
+
+        /// Ranked ballots revealed in the current referendum cycle by voters. Used only with
+        /// the single transferable vote ballot mode.
+        pub RankedBallots get(fn ranked_ballots): map hasher(blake2_128_concat)
+            T::AccountId => RankedBallotOf<T, I>;
     }
 }
 
@@ -343,11 +434,23 @@ decl_event! {
         ReferendumFinished(Vec<OptionResult<MemberId, VotePower>>),
 
         /// User cast a vote in referendum
-        VoteCast(AccountId, Hash, Balance),
+        /// Params:
+        /// - voter account
+        /// - vote commitment
+        /// - vote stake
+        /// - number of additional referendum cycles the stake is locked for
+        VoteCast(AccountId, Hash, Balance, u32),
 
         /// User revealed his vote
         VoteRevealed(AccountId, MemberId, Vec<u8>),
 
+        /// User revealed his ranked vote
+        /// Params:
+        /// - voter account
+        /// - options in the order of preference
+        /// - vote commitment salt
+        RankedVoteRevealed(AccountId, Vec<MemberId>, Vec<u8>),
+
         /// User released his stake
         StakeReleased(AccountId),
 
@@ -401,6 +504,25 @@ decl_error! {
 
         /// A vote cannot be cast from an account that already opted out of voting.
         AccountAlreadyOptedOutOfVoting,
+
+        /// Vote lock period exceeds `MaxVoteLockPeriod`.
+        VoteLockPeriodTooLong,
+
+        /// A vote cannot reduce the stake or the lock period of the previous vote
+        /// that is still locked.
+        LockedVoteStakeReduced,
+
+        /// Ranked votes can be revealed only with the single transferable vote ballot mode.
+        RankedBallotsNotSupported,
+
+        /// Ranked ballot must contain at least one option.
+        EmptyRankedBallot,
+
+        /// Ranked ballot contains more than `MaxRankedOptions` options.
+        RankedBallotTooLong,
+
+        /// Ranked ballot contains the same option more than once.
+        DuplicateRankedOption,
     }
 }
 
@@ -434,6 +556,15 @@ decl_module! {
         /// Minimum stake needed for voting
         const MinimumStake: BalanceOf<T> = T::MinimumStake::get();
 
+        /// Maximum number of additional referendum cycles the vote stake can be locked for.
+        const MaxVoteLockPeriod: u32 = T::MaxVoteLockPeriod::get();
+
+        /// The way the revealed votes are counted to select the referendum winners.
+        const BallotMode: BallotMode = T::BallotMode::get();
+
+        /// Maximum number of options ranked in a single ballot.
+        const MaxRankedOptions: u32 = T::MaxRankedOptions::get();
+
         /// Exports const - staking handler lock id.
         const StakingHandlerLockId: LockIdentifier = T::StakingHandler::lock_id();
 
@@ -441,17 +572,22 @@ decl_module! {
 
         // No origin so this is a priviledged call
         fn on_initialize() -> Weight {
-            Self::try_progress_stage(frame_system::Pallet::<T>::block_number());
+            let ranked_ballots_number =
+                Self::try_progress_stage(frame_system::Pallet::<T>::block_number());
 
             ReferendumWeightInfo::<T, I>::on_initialize_voting()
                 .max(ReferendumWeightInfo::<T, I>::on_initialize_revealing(
                         T::MaxWinnerTargetCount::get().saturated_into()
                 ))
+                .max(ReferendumWeightInfo::<T, I>::on_initialize_revealing_ranked(
+                        ranked_ballots_number
+                ))
         }
 
         /////////////////// User actions ///////////////////////////////////////
 
-        /// Cast a sealed vote in the referendum.
+        /// Cast a sealed vote in the referendum. The stake can be optionally locked for
+        /// `lock_period` additional referendum cycles in exchange for the greater vote power.
         ///
         /// # <weight>
         ///
@@ -461,24 +597,37 @@ decl_module! {
         ///    - `O(1)` doesn't depend on the state or parameters
         /// # </weight>
         #[weight = ReferendumWeightInfo::<T, I>::vote()]
-        pub fn vote(origin, commitment: T::Hash, stake: BalanceOf<T>) -> Result<(), Error<T, I>> {
+        pub fn vote(
+            origin,
+            commitment: T::Hash,
+            stake: BalanceOf<T>,
+            lock_period: u32,
+        ) -> Result<(), Error<T, I>> {
             // ensure action can be started
-            let (current_cycle_id, account_id) = EnsureChecks::<T, I>::can_vote(origin, &stake)?;
+            let (current_cycle_id, account_id) =
+                EnsureChecks::<T, I>::can_vote(origin, &stake, lock_period)?;
 
             //
             // == MUTATION SAFE ==
             //
 
             // start revealing phase - it can return error when stake fails to lock
-            Mutations::<T, I>::vote(&account_id, &commitment, &stake, &current_cycle_id);
+            Mutations::<T, I>::vote(
+                &account_id,
+                &commitment,
+                &stake,
+                &current_cycle_id,
+                lock_period,
+            );
 
             // emit event
-            Self::deposit_event(RawEvent::VoteCast(account_id, commitment, stake));
+            Self::deposit_event(RawEvent::VoteCast(account_id, commitment, stake, lock_period));
 
             Ok(())
         }
 
-        /// Reveal a sealed vote in the referendum.
+        /// Reveal a sealed vote in the referendum. With the single transferable vote ballot mode
+        /// the vote counts as a ranked ballot with a single option.
         ///
         /// # <weight>
         ///
@@ -513,6 +662,50 @@ decl_module! {
             Ok(())
         }
 
+        /// Reveal a sealed ranked vote in the referendum running with the single transferable vote
+        /// ballot mode. The options are listed in the order of the voter's preference. The ballot
+        /// is counted at the end of the revealing stage.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (R)` where:
+        /// - `R` is the number of ranked options
+        /// - DB:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = ReferendumWeightInfo::<T, I>::reveal_ranked_vote(
+            ranked_options.len().saturated_into(),
+        )]
+        pub fn reveal_ranked_vote(
+            origin,
+            salt: Vec<u8>,
+            ranked_options: Vec<<T as common::membership::MembershipTypes>::MemberId>
+        ) -> Result<(), Error<T, I>> {
+            let (account_id, cast_vote, bounded_ranked_options) =
+                EnsureChecks::<T, I>::can_reveal_ranked_vote::<Self>(
+                    origin,
+                    &salt,
+                    ranked_options.clone(),
+                )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // reveal the vote
+            Mutations::<T, I>::reveal_ranked_vote(
+                &account_id,
+                bounded_ranked_options,
+                cast_vote,
+            );
+
+            // emit event
+            Self::deposit_event(RawEvent::RankedVoteRevealed(account_id, ranked_options, salt));
+
+            Ok(())
+        }
+
         /// Release a locked stake.
         /// # <weight>
         ///
@@ -572,6 +765,38 @@ decl_module! {
 /////////////////// Inner logic ////////////////////////////////////////////////
 
 impl<T: Config<I>, I: Instance> Module<T, I> {
+    /// Current referendum stage with the intermediate winners as a plain vector.
+    pub fn current_stage() -> ReferendumStage<T::BlockNumber, Vec<OptionResultOf<T, I>>> {
+        match Stage::<T, I>::get() {
+            ReferendumStage::Inactive => ReferendumStage::Inactive,
+            ReferendumStage::Voting(stage_data) => ReferendumStage::Voting(stage_data),
+            ReferendumStage::Revealing(stage_data) => {
+                ReferendumStage::Revealing(ReferendumStageRevealing {
+                    started: stage_data.started,
+                    winning_target_count: stage_data.winning_target_count,
+                    intermediate_winners: stage_data.intermediate_winners.into_inner(),
+                    current_cycle_id: stage_data.current_cycle_id,
+                    ends_at: stage_data.ends_at,
+                })
+            }
+        }
+    }
+
+    /// Options currently winning the referendum. Empty outside of the revealing stage and with
+    /// the single transferable vote ballot mode, whose ballots are counted at the end of
+    /// the revealing stage.
+    pub fn intermediate_winners() -> Vec<OptionResultOf<T, I>> {
+        match Stage::<T, I>::get() {
+            ReferendumStage::Revealing(stage_data) => stage_data.intermediate_winners.into_inner(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Vote cast from the given account, if any.
+    pub fn vote_of(account_id: &T::AccountId) -> Option<CastVoteOf<T>> {
+        Votes::<T, I>::contains_key(account_id).then(|| Votes::<T, I>::get(account_id))
+    }
+
     // Calculate reveal_vote weight
     fn calculate_reveal_vote_weight(number_of_winners: u32) -> Weight {
         ReferendumWeightInfo::<T, I>::reveal_vote_space_for_new_winner(number_of_winners)
@@ -589,10 +814,17 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
             .max(ReferendumWeightInfo::<T, I>::reveal_vote_already_existing(
                 number_of_winners,
             ))
+            .max(match T::BallotMode::get() {
+                BallotMode::FirstPastThePost => 0,
+                // the vote is revealed as a single option ranked ballot
+                BallotMode::SingleTransferableVote => {
+                    ReferendumWeightInfo::<T, I>::reveal_ranked_vote(1)
+                }
+            })
     }
 
-    // Checkout expire of referendum stage.
-    fn try_progress_stage(now: T::BlockNumber) {
+    // Checkout expire of referendum stage. Returns the number of the counted ranked ballots.
+    fn try_progress_stage(now: T::BlockNumber) -> u32 {
         match Stage::<T, I>::get() {
             ReferendumStage::Inactive => (),
             ReferendumStage::Voting(stage_data) => {
@@ -602,10 +834,12 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
             }
             ReferendumStage::Revealing(stage_data) => {
                 if now == stage_data.ends_at {
-                    Self::end_reveal_period(stage_data);
+                    return Self::end_reveal_period(stage_data);
                 }
             }
         }
+
+        0
     }
 
     // Finish voting and start ravealing.
@@ -617,8 +851,16 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         Self::deposit_event(RawEvent::RevealingStageStarted(revealing_period_end_block));
     }
 
-    // Conclude the referendum.
-    fn end_reveal_period(stage_data: ReferendumStageRevealingOf<T, I>) {
+    // Conclude the referendum. Returns the number of the counted ranked ballots.
+    fn end_reveal_period(stage_data: ReferendumStageRevealingOf<T, I>) -> u32 {
+        // count the ranked ballots
+        let (stage_data, ranked_ballots_number) = match T::BallotMode::get() {
+            BallotMode::FirstPastThePost => (stage_data, 0),
+            BallotMode::SingleTransferableVote => {
+                Mutations::<T, I>::count_ranked_ballots(stage_data)
+            }
+        };
+
         // conclude referendum
         let winners = Mutations::<T, I>::conclude_referendum(stage_data);
 
@@ -627,6 +869,8 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 
         // emit event
         Self::deposit_event(RawEvent::ReferendumFinished(winners));
+
+        ranked_ballots_number
     }
 }
 
@@ -716,6 +960,25 @@ impl<T: Config<I>, I: Instance> ReferendumManager<T::Origin, T::AccountId, T::Me
 
         <T::Hashing as sp_runtime::traits::Hash>::hash(&payload)
     }
+
+    // Calculate commitment for a ranked vote.
+    fn calculate_ranked_commitment(
+        account_id: &<T as frame_system::Config>::AccountId,
+        salt: &[u8],
+        cycle_id: &u64,
+        ranked_options: &[<T as common::membership::MembershipTypes>::MemberId],
+    ) -> T::Hash {
+        let mut payload = account_id.encode();
+        let mut mut_ranked_options = ranked_options.encode();
+        let mut mut_salt = salt.encode();
+        let mut mut_cycle_id = cycle_id.encode();
+
+        payload.append(&mut mut_ranked_options);
+        payload.append(&mut mut_salt);
+        payload.append(&mut mut_cycle_id);
+
+        <T::Hashing as sp_runtime::traits::Hash>::hash(&payload)
+    }
 }
 
 /////////////////// Mutations //////////////////////////////////////////////////
@@ -729,6 +992,10 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
     fn start_voting_period(winning_target_count: &u32, cycle_id: &u64) -> T::BlockNumber {
         let now = <frame_system::Pallet<T>>::block_number();
         let ends_at = now.saturating_add(T::VoteStageDuration::get());
+
+        // clear ranked ballots of the discontinued referendum
+        RankedBallots::<T, I>::remove_all(None);
+
         // change referendum state
         Stage::<T, I>::put(ReferendumStage::Voting(ReferendumStageVoting::<
             T::BlockNumber,
@@ -783,6 +1050,7 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         commitment: &T::Hash,
         stake: &BalanceOf<T>,
         current_cycle_id: &u64,
+        lock_period: u32,
     ) {
         // Should call after `can_vote`
         T::StakingHandler::lock(account_id, *stake);
@@ -795,6 +1063,7 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
                 stake: *stake,
                 cycle_id: *current_cycle_id,
                 vote_for: None,
+                lock_period,
             },
         );
     }
@@ -806,8 +1075,17 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         option_id: &<T as common::membership::MembershipTypes>::MemberId,
         cast_vote: CastVoteOf<T>,
     ) {
+        // the vote counts as a single option ranked ballot
+        if T::BallotMode::get() == BallotMode::SingleTransferableVote {
+            let ranked_options =
+                WeakBoundedVec::force_from(vec![*option_id], Some("Referendum reveal_vote"));
+
+            return Self::reveal_ranked_vote(account_id, ranked_options, cast_vote);
+        }
+
         // prepare new values
-        let vote_power = T::calculate_vote_power(account_id, &cast_vote.stake);
+        let vote_power =
+            T::calculate_vote_power(account_id, &cast_vote.stake, cast_vote.lock_period);
         let total_vote_power = T::get_option_power(option_id) + vote_power;
         let option_result = OptionResult {
             option_id: *option_id,
@@ -834,6 +1112,64 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         Votes::<T, I>::mutate(account_id, |vote| (*vote).vote_for = Some(*option_id));
     }
 
+    // Reveal user's ranked ballot. The ballots are counted at the end of the revealing stage.
+    fn reveal_ranked_vote(
+        account_id: &<T as frame_system::Config>::AccountId,
+        ranked_options: RankedOptionsOf<T, I>,
+        cast_vote: CastVoteOf<T>,
+    ) {
+        let vote_power =
+            T::calculate_vote_power(account_id, &cast_vote.stake, cast_vote.lock_period);
+        let first_preference = ranked_options.first().copied();
+
+        // store the ballot
+        RankedBallots::<T, I>::insert(
+            account_id,
+            RankedBallot {
+                ranked_options,
+                vote_power,
+            },
+        );
+
+        // store revealed vote
+        Votes::<T, I>::mutate(account_id, |vote| (*vote).vote_for = first_preference);
+    }
+
+    // Count the revealed ranked ballots and let runtime update the options' vote power with
+    // their final tallies. The counted ballots are removed. Returns the stage data with
+    // the winners and the number of the counted ballots.
+    fn count_ranked_ballots(
+        stage_data: ReferendumStageRevealingOf<T, I>,
+    ) -> (ReferendumStageRevealingOf<T, I>, u32) {
+        let mut ballots_number: u32 = 0;
+
+        // ballots with the same ranking are aggregated
+        let mut ballots = BTreeMap::<Vec<T::MemberId>, T::VotePower>::new();
+        for (_, ballot) in RankedBallots::<T, I>::drain() {
+            ballots_number = ballots_number.saturating_add(1);
+
+            let vote_power = ballots
+                .entry(ballot.ranked_options.into_inner())
+                .or_default();
+            *vote_power = vote_power.saturating_add(ballot.vote_power);
+        }
+
+        let (winners, tallies) =
+            Self::tally_ranked_ballots(&ballots, stage_data.winning_target_count);
+
+        // let runtime update the vote power of the voted options
+        for (option_id, tally) in tallies.into_iter().filter(|(_, tally)| *tally > 0) {
+            T::increase_option_power(&option_id, &tally.saturated_into());
+        }
+
+        let new_stage_data = ReferendumStageRevealing {
+            intermediate_winners: winners,
+            ..stage_data
+        };
+
+        (new_stage_data, ballots_number)
+    }
+
     // Release stake associated to the user's last vote.
     fn release_vote_stake(account_id: &<T as frame_system::Config>::AccountId) {
         // unlock stake amount
@@ -944,6 +1280,143 @@ impl<T: Config<I>, I: Instance> Mutations<T, I> {
         WeakBoundedVec::force_from(new_winners, Some("Referendum try_winner_insert"))
     }
 
+    // Counts the ranked ballots with the single transferable vote using the Droop quota. The
+    // surplus of the elected options is transferred to the next preferences of their ballots
+    // and the ballots of the eliminated options are transferred in full. Ties are resolved in
+    // favour of the option with the lower ID. Returns the winners and the final tally of each
+    // option: at its election, elimination or the end of the counting. Utility for
+    // count_ranked_ballots() function.
+    fn tally_ranked_ballots(
+        ballots: &BTreeMap<Vec<T::MemberId>, T::VotePower>,
+        winning_target_count: u32,
+    ) -> (IntermediateWinnersOf<T, I>, BTreeMap<T::MemberId, u128>) {
+        let seats: usize = winning_target_count.saturated_into();
+
+        // options in the order of their IDs
+        let options: Vec<T::MemberId> = ballots
+            .keys()
+            .flatten()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        // ballot weights are counted in `u128` to keep the precision of the surplus transfers
+        let mut weights: Vec<u128> = ballots
+            .values()
+            .map(|vote_power| (*vote_power).saturated_into())
+            .collect();
+        let total_weight = weights
+            .iter()
+            .fold(0u128, |total, weight| total.saturating_add(*weight));
+        let quota = total_weight
+            .checked_div(u128::from(winning_target_count).saturating_add(1))
+            .unwrap_or_default()
+            .saturating_add(1);
+
+        let mut tallies = BTreeMap::<T::MemberId, u128>::new();
+        let mut elected = Vec::<(T::MemberId, u128)>::new();
+        let mut eliminated = Vec::<T::MemberId>::new();
+
+        loop {
+            let hopeful: Vec<T::MemberId> = options
+                .iter()
+                .filter(|option_id| {
+                    !eliminated.contains(option_id)
+                        && !elected.iter().any(|(id, _)| id == *option_id)
+                })
+                .copied()
+                .collect();
+
+            // the most preferred hopeful option of each ballot
+            let preferences: Vec<Option<T::MemberId>> = ballots
+                .keys()
+                .map(|ranked_options| {
+                    ranked_options
+                        .iter()
+                        .find(|option_id| hopeful.contains(option_id))
+                        .copied()
+                })
+                .collect();
+
+            let standings: Vec<(T::MemberId, u128)> = hopeful
+                .iter()
+                .map(|option_id| {
+                    let tally = preferences
+                        .iter()
+                        .zip(weights.iter())
+                        .filter(|(preference, _)| **preference == Some(*option_id))
+                        .fold(0u128, |tally, (_, weight)| tally.saturating_add(*weight));
+
+                    (*option_id, tally)
+                })
+                .collect();
+            tallies.extend(standings.iter().copied());
+
+            if elected.len() >= seats || hopeful.is_empty() {
+                break;
+            }
+
+            // all the remaining options fill the vacant seats
+            if elected.len().saturating_add(standings.len()) <= seats {
+                elected.extend(standings);
+                break;
+            }
+
+            // the first option with the highest tally
+            let leader = standings
+                .iter()
+                .fold(None, |leader, (option_id, tally)| match leader {
+                    Some((_, leader_tally)) if leader_tally >= *tally => leader,
+                    _ => Some((*option_id, *tally)),
+                });
+
+            match leader {
+                Some((option_id, tally)) if tally >= quota => {
+                    // transfer the surplus to the next preferences
+                    let surplus_ratio = Perbill::from_rational(tally.saturating_sub(quota), tally);
+                    preferences
+                        .iter()
+                        .zip(weights.iter_mut())
+                        .filter(|(preference, _)| **preference == Some(option_id))
+                        .for_each(|(_, weight)| *weight = surplus_ratio.mul_floor(*weight));
+
+                    elected.push((option_id, tally));
+                }
+                _ => {
+                    // eliminate the last option with the lowest tally
+                    let loser =
+                        standings
+                            .iter()
+                            .fold(None, |loser, (option_id, tally)| match loser {
+                                Some((_, loser_tally)) if loser_tally < *tally => loser,
+                                _ => Some((*option_id, *tally)),
+                            });
+
+                    if let Some((option_id, _)) = loser {
+                        eliminated.push(option_id);
+                    }
+                }
+            }
+        }
+
+        let mut winners: Vec<OptionResultOf<T, I>> = elected
+            .into_iter()
+            .map(|(option_id, tally)| OptionResult {
+                option_id,
+                vote_power: tally.saturated_into(),
+            })
+            .collect();
+
+        // stable sort keeps the election order of the options with the same vote power
+        winners.sort_by(|a, b| b.vote_power.cmp(&a.vote_power));
+
+        (
+            WeakBoundedVec::force_from(winners, Some("Referendum tally_ranked_ballots")),
+            tallies,
+        )
+    }
+
     // Add a new account to the set of accounts that opted out of voting.
     fn add_account_to_opted_out_set(account_id: T::AccountId) {
         AccountsOptedOut::<T, I>::insert(account_id, ());
@@ -982,10 +1455,13 @@ impl<T: Config<I>, I: Instance> EnsureChecks<T, I> {
     fn can_vote(
         origin: T::Origin,
         stake: &BalanceOf<T>,
+        lock_period: u32,
     ) -> Result<(u64, T::AccountId), Error<T, I>> {
         fn prevent_repeated_vote<T: Config<I>, I: Instance>(
             cycle_id: &u64,
             account_id: &T::AccountId,
+            stake: &BalanceOf<T>,
+            lock_period: u32,
         ) -> Result<(), Error<T, I>> {
             if !Votes::<T, I>::contains_key(&account_id) {
                 return Ok(());
@@ -998,6 +1474,17 @@ impl<T: Config<I>, I: Instance> EnsureChecks<T, I> {
                 return Err(Error::<T, I>::AlreadyVotedThisCycle);
             }
 
+            // don't allow the new vote to release the still locked stake
+            if existing_vote.locked_until_cycle() >= *cycle_id {
+                let locked_until_cycle = cycle_id.saturating_add(lock_period.into());
+
+                ensure!(
+                    *stake >= existing_vote.stake
+                        && locked_until_cycle >= existing_vote.locked_until_cycle(),
+                    Error::<T, I>::LockedVoteStakeReduced
+                );
+            }
+
             Ok(())
         }
 
@@ -1015,8 +1502,14 @@ impl<T: Config<I>, I: Instance> EnsureChecks<T, I> {
             _ => return Err(Error::ReferendumNotRunning),
         };
 
+        // ensure lock period is not too long
+        ensure!(
+            lock_period <= T::MaxVoteLockPeriod::get(),
+            Error::VoteLockPeriodTooLong
+        );
+
         // prevent repeated vote
-        prevent_repeated_vote::<T, I>(&current_cycle_id, &account_id)?;
+        prevent_repeated_vote::<T, I>(&current_cycle_id, &account_id, stake, lock_period)?;
 
         // ensure stake is enough for voting
         ensure!(stake >= &T::MinimumStake::get(), Error::InsufficientStake);
@@ -1086,6 +1579,82 @@ impl<T: Config<I>, I: Instance> EnsureChecks<T, I> {
         Ok((stage_data, account_id, cast_vote))
     }
 
+    fn can_reveal_ranked_vote<
+        R: ReferendumManager<T::Origin, T::AccountId, T::MemberId, T::Hash>,
+    >(
+        origin: T::Origin,
+        salt: &[u8],
+        ranked_options: Vec<<T as common::membership::MembershipTypes>::MemberId>,
+    ) -> Result<CanRevealRankedResult<T, I>, Error<T, I>> {
+        // ensure superuser requested action
+        let account_id = Self::ensure_regular_user(origin)?;
+
+        // ensure referendum is running
+        let stage_data = match Stage::<T, I>::get() {
+            ReferendumStage::Revealing(tmp_stage_data) => tmp_stage_data,
+            _ => return Err(Error::RevealingNotInProgress),
+        };
+
+        // ensure ranked ballots are counted
+        ensure!(
+            T::BallotMode::get() == BallotMode::SingleTransferableVote,
+            Error::RankedBallotsNotSupported
+        );
+
+        let cast_vote = Self::ensure_vote_exists(&account_id)?;
+
+        // ensure ballot ranks some options
+        ensure!(!ranked_options.is_empty(), Error::EmptyRankedBallot);
+
+        // ensure ballot is not too long
+        let bounded_ranked_options = RankedOptionsOf::<T, I>::try_from(ranked_options)
+            .map_err(|_| Error::<T, I>::RankedBallotTooLong)?;
+
+        // ensure options are ranked only once
+        for (index, option_id) in bounded_ranked_options.iter().enumerate() {
+            ensure!(
+                !bounded_ranked_options
+                    .iter()
+                    .skip(index.saturating_add(1))
+                    .any(|other_option_id| other_option_id == option_id),
+                Error::DuplicateRankedOption
+            );
+        }
+
+        // ask runtime if options are valid
+        if !bounded_ranked_options.iter().all(T::is_valid_option_id) {
+            return Err(Error::InvalidVote);
+        }
+
+        // ensure vote was cast for the running referendum
+        if stage_data.current_cycle_id != cast_vote.cycle_id {
+            return Err(Error::InvalidVote);
+        }
+
+        // ensure vote was not already revealed
+        if cast_vote.vote_for.is_some() {
+            return Err(Error::InvalidReveal);
+        }
+
+        // ensure salt is not too long
+        if salt.len() as u64 > T::MaxSaltLength::get() {
+            return Err(Error::SaltTooLong);
+        }
+
+        // ensure commitment corresponds to salt and ranked options
+        let commitment = R::calculate_ranked_commitment(
+            &account_id,
+            salt,
+            &stage_data.current_cycle_id,
+            &bounded_ranked_options,
+        );
+        if commitment != cast_vote.commitment {
+            return Err(Error::InvalidReveal);
+        }
+
+        Ok((account_id, cast_vote, bounded_ranked_options))
+    }
+
     fn can_release_vote_stake(origin: T::Origin) -> Result<T::AccountId, Error<T, I>> {
         // ensure superuser requested action
         let account_id = Self::ensure_regular_user(origin)?;
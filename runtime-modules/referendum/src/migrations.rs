@@ -0,0 +1,43 @@
+use codec::Decode;
+use frame_support::storage::IterableStorageMap;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+
+use crate::{BalanceOf, CastVoteOf, Config, Instance, Module, Votes};
+
+/// Vote layout preceding the vote stake lock period
+#[derive(Decode)]
+struct CastVoteV0<Hash, Currency, MemberId> {
+    commitment: Hash,
+    cycle_id: u64,
+    stake: Currency,
+    vote_for: Option<MemberId>,
+}
+
+type CastVoteV0Of<T> = CastVoteV0<
+    <T as frame_system::Config>::Hash,
+    BalanceOf<T>,
+    <T as common::membership::MembershipTypes>::MemberId,
+>;
+
+impl<T: Config<I>, I: Instance> Module<T, I> {
+    /// Migrate the existing votes to the layout with the stake lock period. The existing votes
+    /// have no additional lock period.
+    pub fn migrate_votes_to_lock_period_layout() -> Weight {
+        let mut votes_number: u64 = 0;
+
+        Votes::<T, I>::translate::<CastVoteV0Of<T>, _>(|_, vote| {
+            votes_number = votes_number.saturating_add(1);
+
+            Some(CastVoteOf::<T> {
+                commitment: vote.commitment,
+                cycle_id: vote.cycle_id,
+                stake: vote.stake,
+                vote_for: vote.vote_for,
+                lock_period: 0,
+            })
+        });
+
+        T::DbWeight::get().reads_writes(votes_number, votes_number)
+    }
+}
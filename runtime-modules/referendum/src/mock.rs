@@ -2,9 +2,9 @@
 
 /////////////////// Configuration //////////////////////////////////////////////
 use crate::{
-    AccountsOptedOut, BalanceOf, CastVote, Config, Error, Instance, Module, OptionResult, RawEvent,
-    ReferendumManager, ReferendumStage, ReferendumStageRevealing, ReferendumStageVoting, Stage,
-    Votes,
+    AccountsOptedOut, BalanceOf, BallotMode, CastVote, Config, Error, Instance, Module,
+    OptionResult, RankedBallots, RawEvent, ReferendumManager, ReferendumStage,
+    ReferendumStageRevealing, ReferendumStageVoting, Stage, Votes,
 };
 
 pub use crate::DefaultInstance;
@@ -52,6 +52,9 @@ parameter_types! {
     pub const MinimumStake: u64 = 10000;
     pub const LockId: LockIdentifier = *b"referend";
     pub const MaxWinnerTargetCount: u32 = 10;
+    pub const MaxVoteLockPeriod: u32 = 6;
+    pub const MaxRankedOptions: u32 = 5;
+    pub static ReferendumBallotMode: BallotMode = BallotMode::SingleTransferableVote;
 }
 
 thread_local! {
@@ -89,11 +92,18 @@ impl Config for Runtime {
 
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
 
+    type MaxVoteLockPeriod = MaxVoteLockPeriod;
+
+    type BallotMode = ReferendumBallotMode;
+    type MaxRankedOptions = MaxRankedOptions;
+
     fn calculate_vote_power(
         account_id: &<Self as frame_system::Config>::AccountId,
         stake: &BalanceOf<Self>,
+        lock_period: u32,
     ) -> <Self as Config<DefaultInstance>>::VotePower {
-        let stake: u64 = *stake;
+        // the stake locked for the additional cycles has the proportionally greater power
+        let stake: u64 = *stake * (lock_period as u64 + 1);
         if *account_id == USER_REGULAR_POWER_VOTES {
             return stake * POWER_VOTE_STRENGTH;
         }
@@ -463,6 +473,24 @@ where
         )
     }
 
+    pub fn calculate_ranked_commitment(
+        account_id: &<T as frame_system::Config>::AccountId,
+        ranked_options: &[<T as common::membership::MembershipTypes>::MemberId],
+        cycle_id: &u64,
+    ) -> (T::Hash, Vec<u8>) {
+        let salt = Self::generate_salt();
+
+        (
+            <Module<T, I> as ReferendumManager<
+                <T as frame_system::Config>::Origin,
+                <T as frame_system::Config>::AccountId,
+                <T as common::membership::MembershipTypes>::MemberId,
+                <T as frame_system::Config>::Hash,
+            >>::calculate_ranked_commitment(account_id, &salt, cycle_id, ranked_options),
+            salt,
+        )
+    }
+
     pub fn transform_results(input: Vec<T::VotePower>) -> BTreeMap<u64, T::VotePower> {
         BTreeMap::from_iter(
             input
@@ -666,6 +694,26 @@ impl InstanceMocks<Runtime, DefaultInstance> {
         stake: BalanceOf<Runtime>,
         cycle_id: u64,
         expected_result: Result<(), Error<Runtime, DefaultInstance>>,
+    ) -> () {
+        Self::vote_with_lock_period(
+            origin,
+            account_id,
+            commitment,
+            stake,
+            0,
+            cycle_id,
+            expected_result,
+        );
+    }
+
+    pub fn vote_with_lock_period(
+        origin: OriginType<<Runtime as frame_system::Config>::AccountId>,
+        account_id: <Runtime as frame_system::Config>::AccountId,
+        commitment: <Runtime as frame_system::Config>::Hash,
+        stake: BalanceOf<Runtime>,
+        lock_period: u32,
+        cycle_id: u64,
+        expected_result: Result<(), Error<Runtime, DefaultInstance>>,
     ) -> () {
         // check method returns expected result
         assert_eq!(
@@ -673,6 +721,7 @@ impl InstanceMocks<Runtime, DefaultInstance> {
                 InstanceMockUtils::<Runtime, DefaultInstance>::mock_origin(origin),
                 commitment,
                 stake,
+                lock_period,
             ),
             expected_result,
         );
@@ -688,6 +737,7 @@ impl InstanceMocks<Runtime, DefaultInstance> {
                 cycle_id,
                 stake,
                 vote_for: None,
+                lock_period,
             },
         );
 
@@ -697,7 +747,12 @@ impl InstanceMocks<Runtime, DefaultInstance> {
                 .last()
                 .unwrap()
                 .event,
-            Event::Referendum(RawEvent::VoteCast(account_id, commitment, stake))
+            Event::Referendum(RawEvent::VoteCast(
+                account_id,
+                commitment,
+                stake,
+                lock_period
+            ))
         );
     }
 
@@ -732,6 +787,55 @@ impl InstanceMocks<Runtime, DefaultInstance> {
         );
     }
 
+    pub fn reveal_ranked_vote(
+        origin: OriginType<<Runtime as frame_system::Config>::AccountId>,
+        account_id: <Runtime as frame_system::Config>::AccountId,
+        salt: Vec<u8>,
+        ranked_options: Vec<u64>,
+        expected_result: Result<(), Error<Runtime, DefaultInstance>>,
+    ) -> () {
+        // check method returns expected result
+        assert_eq!(
+            Module::<Runtime>::reveal_ranked_vote(
+                InstanceMockUtils::<Runtime, DefaultInstance>::mock_origin(origin),
+                salt.clone(),
+                ranked_options.clone(),
+            ),
+            expected_result,
+        );
+
+        if expected_result.is_err() {
+            return;
+        }
+
+        // check the first preference is stored as the vote target
+        assert_eq!(
+            Votes::<Runtime, DefaultInstance>::get(account_id).vote_for,
+            ranked_options.first().copied(),
+        );
+
+        // check the ballot is stored
+        assert_eq!(
+            RankedBallots::<Runtime, DefaultInstance>::get(account_id)
+                .ranked_options
+                .into_inner(),
+            ranked_options,
+        );
+
+        // check event was emitted
+        assert_eq!(
+            frame_system::Pallet::<Runtime>::events()
+                .last()
+                .unwrap()
+                .event,
+            Event::Referendum(RawEvent::RankedVoteRevealed(
+                account_id,
+                ranked_options,
+                salt
+            ))
+        );
+    }
+
     pub fn release_stake(
         origin: OriginType<<Runtime as frame_system::Config>::AccountId>,
         account_id: <Runtime as frame_system::Config>::AccountId,
@@ -0,0 +1,28 @@
+//! Runtime API definition for the referendum module.
+
+use crate::{CastVote, OptionResult, ReferendumStage};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Read-only access to the referendum state for the node RPC and off-chain clients.
+    pub trait ReferendumApi<AccountId, MemberId, Balance, BlockNumber, Hash, VotePower> where
+        AccountId: Codec,
+        MemberId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+        VotePower: Codec,
+    {
+        /// Current referendum stage. Intermediate winners are returned only in the revealing
+        /// stage.
+        fn stage() -> ReferendumStage<BlockNumber, Vec<OptionResult<MemberId, VotePower>>>;
+
+        /// Options currently winning the referendum, ordered by vote power. Empty outside of
+        /// the revealing stage and with the single transferable vote ballot mode.
+        fn intermediate_winners() -> Vec<OptionResult<MemberId, VotePower>>;
+
+        /// Vote cast from the given staking account, if any.
+        fn vote_of(account_id: AccountId) -> Option<CastVote<Hash, Balance, MemberId>>;
+    }
+}
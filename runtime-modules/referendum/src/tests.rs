@@ -1,8 +1,15 @@
 #![cfg(test)]
 
-use super::{Config, Error, OptionResult};
+use super::{
+    BallotMode, CastVote, Config, Error, Module, OptionResult, RankedBallots, ReferendumStage,
+    Votes,
+};
 use crate::mock::*;
+use codec::Encode;
 use frame_support::error::BadOrigin;
+use frame_support::storage::{unhashed, StorageMap};
+use sp_arithmetic::{PerThing, Perbill};
+use sp_core::H256;
 
 type Mocks = InstanceMocks<Runtime, DefaultInstance>;
 type MockUtils = InstanceMockUtils<Runtime, DefaultInstance>;
@@ -246,6 +253,62 @@ fn reveal() {
     });
 }
 
+/// Test that the state queries used by the runtime API reflect a revealed vote.
+#[test]
+fn state_queries_reflect_revealed_vote() {
+    build_test_externalities().execute_with(|| {
+        ReferendumBallotMode::set(&BallotMode::FirstPastThePost);
+
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let account_id = USER_ADMIN;
+        let origin = OriginType::Signed(account_id);
+        let cycle_id = 1;
+        let winning_target_count = 1;
+
+        let option_to_vote_for = 1;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let (commitment, salt) =
+            MockUtils::calculate_commitment(&account_id, &option_to_vote_for, &cycle_id);
+
+        assert_eq!(
+            Module::<Runtime>::current_stage(),
+            ReferendumStage::Inactive
+        );
+        assert_eq!(Module::<Runtime>::vote_of(&account_id), None);
+
+        Mocks::start_referendum_extrinsic(origin.clone(), winning_target_count, cycle_id, Ok(()));
+        Mocks::vote(
+            origin.clone(),
+            account_id,
+            commitment,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+        assert_eq!(
+            Module::<Runtime>::vote_of(&account_id).map(|vote| vote.vote_for),
+            Some(None)
+        );
+
+        MockUtils::move_to_block(voting_stage_duration + 1);
+        Mocks::reveal_vote(origin, account_id, salt, option_to_vote_for, Ok(()));
+
+        let winners = Module::<Runtime>::intermediate_winners();
+        assert_eq!(winners.len(), 1);
+        assert_eq!(winners[0].option_id, option_to_vote_for);
+        match Module::<Runtime>::current_stage() {
+            ReferendumStage::Revealing(stage_data) => {
+                assert_eq!(stage_data.intermediate_winners, winners)
+            }
+            _ => panic!("Referendum should be in the revealing stage"),
+        }
+        assert_eq!(
+            Module::<Runtime>::vote_of(&account_id).and_then(|vote| vote.vote_for),
+            Some(option_to_vote_for)
+        );
+    });
+}
+
 /// Test that a user can't vote outside of the voting stage.
 #[test]
 fn reveal_reveal_stage_not_running() {
@@ -1089,6 +1152,446 @@ fn winners_multiple_not_enough() {
     });
 }
 
+/////////////////// Vote lock period ////////////////////////////////////////////
+
+/// Test that the stake locked for the additional cycles has the greater vote power.
+#[test]
+fn vote_power_with_lock_period() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+        let account_superuser = USER_ADMIN;
+        let account_id1 = USER_REGULAR;
+        let origin = OriginType::Signed(account_superuser);
+        let origin_voter1 = OriginType::Signed(account_id1);
+        let cycle_id = 1;
+        let winning_target_count = 1;
+        let lock_period = 2;
+
+        let option_to_vote_for = 0;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let (commitment1, salt1) =
+            MockUtils::calculate_commitment(&account_id1, &option_to_vote_for, &cycle_id);
+
+        Mocks::start_referendum_extrinsic(origin, winning_target_count, cycle_id, Ok(()));
+        Mocks::vote_with_lock_period(
+            origin_voter1.clone(),
+            account_id1,
+            commitment1,
+            stake,
+            lock_period,
+            cycle_id,
+            Ok(()),
+        );
+        // voting period starts at block 1
+        MockUtils::move_to_block(voting_stage_duration + 1);
+
+        Mocks::check_voting_finished(winning_target_count, cycle_id);
+        Mocks::reveal_vote(
+            origin_voter1,
+            account_id1,
+            salt1,
+            option_to_vote_for,
+            Ok(()),
+        );
+        MockUtils::increase_block_number(reveal_stage_duration);
+
+        let vote_power = stake * (lock_period as u64 + 1);
+        Mocks::check_revealing_finished(
+            vec![OptionResult {
+                option_id: option_to_vote_for,
+                vote_power,
+            }],
+            MockUtils::transform_results(vec![vote_power]),
+        );
+    });
+}
+
+/// Test that the vote lock period can't exceed `MaxVoteLockPeriod`.
+#[test]
+fn voting_lock_period_too_long() {
+    build_test_externalities().execute_with(|| {
+        let account_id = USER_ADMIN;
+        let origin = OriginType::Signed(account_id);
+        let cycle_id = 1;
+
+        let winning_target_count = 1;
+        let option_to_vote_for = 0;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let lock_period = <Runtime as Config>::MaxVoteLockPeriod::get() + 1;
+        let (commitment, _) =
+            MockUtils::calculate_commitment(&account_id, &option_to_vote_for, &cycle_id);
+
+        Mocks::start_referendum_extrinsic(origin.clone(), winning_target_count, cycle_id, Ok(()));
+        Mocks::vote_with_lock_period(
+            origin,
+            account_id,
+            commitment,
+            stake,
+            lock_period,
+            cycle_id,
+            Err(Error::VoteLockPeriodTooLong),
+        );
+    });
+}
+
+/// Test that a new vote can't reduce the stake or the lock period of the still locked vote.
+#[test]
+fn voting_locked_vote_stake_reduced() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+        let account_id = USER_ADMIN;
+        let origin = OriginType::Signed(account_id);
+        let cycle_id1 = 1;
+        let cycle_id2 = 2;
+        let winning_target_count = 1;
+
+        let option_to_vote_for = 0;
+        let stake = <Runtime as Config>::MinimumStake::get() + 1;
+        let (commitment1, _) =
+            MockUtils::calculate_commitment(&account_id, &option_to_vote_for, &cycle_id1);
+
+        Mocks::start_referendum_extrinsic(origin.clone(), winning_target_count, cycle_id1, Ok(()));
+        Mocks::vote_with_lock_period(
+            origin.clone(),
+            account_id,
+            commitment1,
+            stake,
+            2,
+            cycle_id1,
+            Ok(()),
+        );
+        // voting period starts at block 1
+        MockUtils::move_to_block(voting_stage_duration + 1);
+        MockUtils::increase_block_number(reveal_stage_duration);
+
+        let (commitment2, _) =
+            MockUtils::calculate_commitment(&account_id, &option_to_vote_for, &cycle_id2);
+
+        Mocks::start_referendum_extrinsic(origin.clone(), winning_target_count, cycle_id2, Ok(()));
+        // the vote stake is locked until the cycle 3
+        Mocks::vote_with_lock_period(
+            origin.clone(),
+            account_id,
+            commitment2,
+            stake,
+            0,
+            cycle_id2,
+            Err(Error::LockedVoteStakeReduced),
+        );
+        Mocks::vote_with_lock_period(
+            origin.clone(),
+            account_id,
+            commitment2,
+            stake - 1,
+            1,
+            cycle_id2,
+            Err(Error::LockedVoteStakeReduced),
+        );
+        Mocks::vote_with_lock_period(origin, account_id, commitment2, stake, 1, cycle_id2, Ok(()));
+    });
+}
+
+/////////////////// Ranked ballots /////////////////////////////////////////////
+
+/// Test that the ballots of the eliminated option are transferred to the next preference.
+#[test]
+fn ranked_vote_eliminated_option_transfer() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+        let origin = OriginType::Signed(USER_ADMIN);
+        let voters = [USER_REGULAR, USER_REGULAR_2, USER_REGULAR_3];
+        let cycle_id = 1;
+        let winning_target_count = 1;
+
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let stakes = [3 * stake, 2 * stake, 2 * stake];
+        let ballots = [vec![0], vec![1, 2], vec![2, 1]];
+
+        Mocks::start_referendum_extrinsic(origin, winning_target_count, cycle_id, Ok(()));
+        let salts: Vec<Vec<u8>> = voters
+            .iter()
+            .zip(stakes.iter().zip(ballots.iter()))
+            .map(|(account_id, (stake, ranked_options))| {
+                let (commitment, salt) =
+                    MockUtils::calculate_ranked_commitment(account_id, ranked_options, &cycle_id);
+                Mocks::vote(
+                    OriginType::Signed(*account_id),
+                    *account_id,
+                    commitment,
+                    *stake,
+                    cycle_id,
+                    Ok(()),
+                );
+
+                salt
+            })
+            .collect();
+        // voting period starts at block 1
+        MockUtils::move_to_block(voting_stage_duration + 1);
+
+        Mocks::check_voting_finished(winning_target_count, cycle_id);
+        for ((account_id, salt), ranked_options) in voters.iter().zip(salts).zip(ballots) {
+            Mocks::reveal_ranked_vote(
+                OriginType::Signed(*account_id),
+                *account_id,
+                salt,
+                ranked_options,
+                Ok(()),
+            );
+        }
+        MockUtils::increase_block_number(reveal_stage_duration);
+
+        // the option 2 is eliminated first and its ballot elects the option 1
+        Mocks::check_revealing_finished(
+            vec![OptionResult {
+                option_id: 1,
+                vote_power: 4 * stake,
+            }],
+            MockUtils::transform_results(vec![3 * stake, 4 * stake, 2 * stake]),
+        );
+    });
+}
+
+/// Test that the surplus of the elected option is transferred to the next preference.
+#[test]
+fn ranked_vote_surplus_transfer() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+        let origin = OriginType::Signed(USER_ADMIN);
+        let voters = [USER_REGULAR, USER_REGULAR_2, USER_REGULAR_3];
+        let cycle_id = 1;
+        let winning_target_count = 2;
+
+        let stakes = [35000, 15000, 10000];
+        let ballots = [vec![0, 1], vec![2], vec![1]];
+
+        Mocks::start_referendum_extrinsic(origin, winning_target_count, cycle_id, Ok(()));
+        let salts: Vec<Vec<u8>> = voters
+            .iter()
+            .zip(stakes.iter().zip(ballots.iter()))
+            .map(|(account_id, (stake, ranked_options))| {
+                let (commitment, salt) =
+                    MockUtils::calculate_ranked_commitment(account_id, ranked_options, &cycle_id);
+                Mocks::vote(
+                    OriginType::Signed(*account_id),
+                    *account_id,
+                    commitment,
+                    *stake,
+                    cycle_id,
+                    Ok(()),
+                );
+
+                salt
+            })
+            .collect();
+        // voting period starts at block 1
+        MockUtils::move_to_block(voting_stage_duration + 1);
+
+        Mocks::check_voting_finished(winning_target_count, cycle_id);
+        for ((account_id, salt), ranked_options) in voters.iter().zip(salts).zip(ballots) {
+            Mocks::reveal_ranked_vote(
+                OriginType::Signed(*account_id),
+                *account_id,
+                salt,
+                ranked_options,
+                Ok(()),
+            );
+        }
+        MockUtils::increase_block_number(reveal_stage_duration);
+
+        // the Droop quota is 60000 / 3 + 1
+        let quota = 20001u128;
+        let surplus = Perbill::from_rational(35000 - quota, 35000).mul_floor(35000u128) as u64;
+        Mocks::check_revealing_finished(
+            vec![
+                OptionResult {
+                    option_id: 0,
+                    vote_power: 35000,
+                },
+                OptionResult {
+                    option_id: 1,
+                    vote_power: 10000 + surplus,
+                },
+            ],
+            MockUtils::transform_results(vec![35000, 10000 + surplus, 15000]),
+        );
+    });
+}
+
+/// Test that the ranked ballots are stored per voter and counted once at the end of
+/// the revealing stage.
+#[test]
+fn ranked_votes_counted_at_reveal_end() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let reveal_stage_duration = <Runtime as Config>::RevealStageDuration::get();
+        let origin = OriginType::Signed(USER_ADMIN);
+        let voters = [USER_REGULAR, USER_REGULAR_2];
+        let cycle_id = 1;
+        let winning_target_count = 1;
+
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let ranked_options = vec![1, 0];
+
+        Mocks::start_referendum_extrinsic(origin, winning_target_count, cycle_id, Ok(()));
+        let salts: Vec<Vec<u8>> = voters
+            .iter()
+            .map(|account_id| {
+                let (commitment, salt) =
+                    MockUtils::calculate_ranked_commitment(account_id, &ranked_options, &cycle_id);
+                Mocks::vote(
+                    OriginType::Signed(*account_id),
+                    *account_id,
+                    commitment,
+                    stake,
+                    cycle_id,
+                    Ok(()),
+                );
+
+                salt
+            })
+            .collect();
+        // voting period starts at block 1
+        MockUtils::move_to_block(voting_stage_duration + 1);
+
+        Mocks::check_voting_finished(winning_target_count, cycle_id);
+        for (account_id, salt) in voters.iter().zip(salts) {
+            Mocks::reveal_ranked_vote(
+                OriginType::Signed(*account_id),
+                *account_id,
+                salt,
+                ranked_options.clone(),
+                Ok(()),
+            );
+        }
+
+        // the ballots are not counted while revealing
+        assert!(Module::<Runtime>::intermediate_winners().is_empty());
+        assert_eq!(<Runtime as Config>::get_option_power(&1), 0);
+        MockUtils::increase_block_number(reveal_stage_duration);
+
+        Mocks::check_revealing_finished(
+            vec![OptionResult {
+                option_id: 1,
+                vote_power: 2 * stake,
+            }],
+            MockUtils::transform_results(vec![0, 2 * stake]),
+        );
+        for account_id in voters {
+            assert!(!RankedBallots::<Runtime, DefaultInstance>::contains_key(
+                account_id
+            ));
+        }
+    });
+}
+
+/// Test that the ranked ballot can't be revealed with the first past the post ballot mode.
+#[test]
+fn reveal_ranked_vote_not_supported() {
+    build_test_externalities().execute_with(|| {
+        ReferendumBallotMode::set(&BallotMode::FirstPastThePost);
+
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let account_id = USER_ADMIN;
+        let origin = OriginType::Signed(account_id);
+        let cycle_id = 1;
+        let winning_target_count = 1;
+
+        let ranked_options = vec![0, 1];
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let (commitment, salt) =
+            MockUtils::calculate_ranked_commitment(&account_id, &ranked_options, &cycle_id);
+
+        Mocks::start_referendum_extrinsic(origin.clone(), winning_target_count, cycle_id, Ok(()));
+        Mocks::vote(
+            origin.clone(),
+            account_id,
+            commitment,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+        // voting period starts at block 1
+        MockUtils::move_to_block(voting_stage_duration + 1);
+
+        Mocks::check_voting_finished(winning_target_count, cycle_id);
+        Mocks::reveal_ranked_vote(
+            origin,
+            account_id,
+            salt,
+            ranked_options,
+            Err(Error::RankedBallotsNotSupported),
+        );
+    });
+}
+
+/// Test that the ranked ballot must be non-empty, bounded, free of duplicates and match
+/// the commitment.
+#[test]
+fn reveal_ranked_vote_invalid_ballot() {
+    build_test_externalities().execute_with(|| {
+        let voting_stage_duration = <Runtime as Config>::VoteStageDuration::get();
+        let account_id = USER_ADMIN;
+        let origin = OriginType::Signed(account_id);
+        let cycle_id = 1;
+        let winning_target_count = 1;
+
+        let ranked_options = vec![0, 1, 2];
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let (commitment, salt) =
+            MockUtils::calculate_ranked_commitment(&account_id, &ranked_options, &cycle_id);
+
+        Mocks::start_referendum_extrinsic(origin.clone(), winning_target_count, cycle_id, Ok(()));
+        Mocks::vote(
+            origin.clone(),
+            account_id,
+            commitment,
+            stake,
+            cycle_id,
+            Ok(()),
+        );
+        // voting period starts at block 1
+        MockUtils::move_to_block(voting_stage_duration + 1);
+
+        Mocks::check_voting_finished(winning_target_count, cycle_id);
+        Mocks::reveal_ranked_vote(
+            origin.clone(),
+            account_id,
+            salt.clone(),
+            vec![],
+            Err(Error::EmptyRankedBallot),
+        );
+        let too_many_options =
+            (0..(<Runtime as Config>::MaxRankedOptions::get() as u64 + 1)).collect::<Vec<u64>>();
+        Mocks::reveal_ranked_vote(
+            origin.clone(),
+            account_id,
+            salt.clone(),
+            too_many_options,
+            Err(Error::RankedBallotTooLong),
+        );
+        Mocks::reveal_ranked_vote(
+            origin.clone(),
+            account_id,
+            salt.clone(),
+            vec![0, 1, 0],
+            Err(Error::DuplicateRankedOption),
+        );
+        Mocks::reveal_ranked_vote(
+            origin.clone(),
+            account_id,
+            salt.clone(),
+            vec![0, 2, 1],
+            Err(Error::InvalidReveal),
+        );
+        Mocks::reveal_ranked_vote(origin, account_id, salt, ranked_options, Ok(()));
+    });
+}
+
 /////////////////// Lifetime Releasing stake ///////////////////////////////////
 
 /// Test that referendum stake can be released after the referendum ends.
@@ -1224,3 +1727,37 @@ fn opt_out_of_voting_invalid_origin() {
         Mocks::opt_out_of_voting(none_origin.clone(), Err(BadOrigin.into()));
     });
 }
+
+/////////////////// Migrations /////////////////////////////////////////////////
+
+/// Test that the votes cast before the vote lock period are migrated with no lock period.
+#[test]
+fn migrate_votes_to_lock_period_layout() {
+    build_test_externalities().execute_with(|| {
+        let account_id = USER_REGULAR;
+        let commitment = H256::repeat_byte(1);
+        let cycle_id = 1;
+        let stake = <Runtime as Config>::MinimumStake::get();
+        let vote_for = Some(0u64);
+
+        // vote encoded with the layout preceding the lock period
+        unhashed::put_raw(
+            &Votes::<Runtime, DefaultInstance>::hashed_key_for(account_id),
+            &(commitment, cycle_id, stake, vote_for).encode(),
+        );
+        assert!(Votes::<Runtime, DefaultInstance>::try_get(account_id).is_err());
+
+        Module::<Runtime, DefaultInstance>::migrate_votes_to_lock_period_layout();
+
+        assert_eq!(
+            Votes::<Runtime, DefaultInstance>::try_get(account_id),
+            Ok(CastVote {
+                commitment,
+                cycle_id,
+                stake,
+                vote_for,
+                lock_period: 0,
+            })
+        );
+    });
+}
@@ -46,12 +46,14 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for referendum.
 pub trait WeightInfo {
 	fn on_initialize_revealing(_i: u32, ) -> Weight;
+	fn on_initialize_revealing_ranked(_i: u32, ) -> Weight;
 	fn on_initialize_voting() -> Weight;
 	fn vote() -> Weight;
 	fn reveal_vote_space_for_new_winner(_i: u32, ) -> Weight;
 	fn reveal_vote_space_not_in_winners(_i: u32, ) -> Weight;
 	fn reveal_vote_space_replace_last_winner(_i: u32, ) -> Weight;
 	fn reveal_vote_already_existing(_i: u32, ) -> Weight;
+	fn reveal_ranked_vote(_i: u32, ) -> Weight;
 	fn release_vote_stake() -> Weight;
 	fn opt_out_of_voting() -> Weight;
 }
@@ -70,6 +72,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Instance1Referendum Stage (r:1 w:1)
+	// Storage: Instance1Referendum RankedBallots (r:1 w:1)
+	// Storage: Council Candidates (r:10 w:10)
+	// Storage: Council AnnouncementPeriodNr (r:1 w:1)
+	// Storage: Council Stage (r:0 w:1)
+	fn on_initialize_revealing_ranked(i: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 1_158_000
+			.saturating_add((109_604_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+	}
+	// Storage: Instance1Referendum Stage (r:1 w:1)
 	fn on_initialize_voting() -> Weight {
 		(18_880_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
@@ -129,6 +145,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// Storage: Instance1Referendum Stage (r:1 w:0)
+	// Storage: Instance1Referendum Votes (r:1 w:1)
+	// Storage: Instance1Referendum RankedBallots (r:0 w:1)
+	fn reveal_ranked_vote(i: u32, ) -> Weight {
+		(49_604_000 as Weight)
+			// Standard Error: 97_000
+			.saturating_add((1_022_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	// Storage: Instance1Referendum Votes (r:1 w:1)
 	// Storage: Council AnnouncementPeriodNr (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
@@ -151,6 +177,9 @@ impl WeightInfo for () {
 	fn on_initialize_revealing(i: u32, ) -> Weight {
 		0
 	}
+	fn on_initialize_revealing_ranked(i: u32, ) -> Weight {
+		0
+	}
 	fn on_initialize_voting() -> Weight {
 		0
 	}
@@ -169,6 +198,9 @@ impl WeightInfo for () {
 	fn reveal_vote_already_existing(i: u32, ) -> Weight {
 		0
 	}
+	fn reveal_ranked_vote(i: u32, ) -> Weight {
+		0
+	}
 	fn release_vote_stake() -> Weight {
 		0
 	}
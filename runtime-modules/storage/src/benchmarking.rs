@@ -3,9 +3,9 @@
 use frame_benchmarking::{account, benchmarks};
 use frame_support::storage::{StorageDoubleMap, StorageMap, StorageValue};
 use frame_support::traits::Instance;
-use frame_support::traits::{Currency, Get};
+use frame_support::traits::{Currency, Get, OnInitialize};
 use frame_system::{EventRecord, RawOrigin};
-use sp_arithmetic::traits::{One, Zero};
+use sp_arithmetic::traits::{One, Saturating, Zero};
 use sp_runtime::traits::Bounded;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
@@ -23,11 +23,16 @@ use working_group::{
 };
 
 use crate::{
-    BagId, Balances, Base58Multihash, Blacklist, Call, Config, DataObjectCreationParameters,
-    DataObjectStorage, DistributionBucketByFamilyIdById, DistributionBucketFamilyById,
-    DistributionBucketId, DynamicBagType, Module, Module as Pallet, RawEvent, StaticBagId,
-    StorageBucketById, StorageBucketOperatorStatus, UploadParameters,
+    BagId, Balances, Base58Multihash, Blacklist, Call, ChunksMerkleRoot, Config,
+    DataObjectCreationParameters, DataObjectStorage, DataObjectsById,
+    DistributionBucketByFamilyIdById, DistributionBucketFamilyById, DistributionBucketId,
+    DynBagCreationParameters, DynamicBagId, DynamicBagType, ExpiredDataObjects, Module,
+    Module as Pallet, ModuleAccount, NextStorageChallengeId, RawEvent, StaticBagId,
+    StorageBucketById, StorageBucketChallengeFailures, StorageBucketEarnings,
+    StorageBucketEarningsById, StorageBucketOperatorStatus, StorageChallenge, StorageChallengeById,
+    StorageChallengesByDeadline, StorageOperatorsEarningsFund, StorageTreasury, UploadParameters,
 };
+use common::merkle_tree::helpers::{build_merkle_path_helper, generate_merkle_root_helper};
 use frame_support::sp_runtime::SaturatedConversion;
 
 // The storage working group instance alias.
@@ -43,6 +48,7 @@ pub const DISTRIBUTION_WG_LEADER_ACCOUNT_ID: u64 = 100004;
 pub const DEFAULT_STORAGE_WORKER_ACCOUNT_ID: u64 = 100002;
 pub const DEFAULT_DISTRIBUTION_WORKER_ACCOUNT_ID: u64 = 100003;
 pub const SECOND_WORKER_ACCOUNT_ID: u64 = 1;
+pub const EXPIRED_OBJECTS_UPLOADER_ACCOUNT_ID: u32 = 1000;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
     let events = System::<T>::events();
@@ -465,6 +471,128 @@ fn create_distribution_buckets<T: Config>(
 const DISTRIBUTION_BUCKET_FAMILIES_NUMBER: u32 = 7;
 const MAX_KILOBYTES_METADATA: u32 = 1000;
 const OBJECT_COUNT: u32 = 400;
+// Chunks Merkle proof depth of the max size data object.
+const MAX_CHUNKS_MERKLE_PROOF_DEPTH: u32 = 16;
+
+// Creates the storage bucket operated by the worker and assigns it to the bag.
+fn create_operated_storage_bucket_for_bag<T: Config>(
+    lead_account_id: T::AccountId,
+    worker_id: WorkerId<T>,
+    worker_account_id: T::AccountId,
+    bag_id: BagId<T>,
+) -> T::StorageBucketId {
+    let bucket_id = create_storage_bucket_helper::<T>(lead_account_id.clone());
+
+    set_storage_operator::<T>(
+        lead_account_id.clone(),
+        bucket_id,
+        worker_id,
+        worker_account_id,
+    );
+
+    Module::<T>::update_storage_buckets_voucher_max_limits(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        u64::MAX,
+        u64::MAX,
+    )
+    .unwrap();
+
+    Module::<T>::set_storage_bucket_voucher_limits(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        bucket_id,
+        u64::MAX,
+        u64::MAX,
+    )
+    .unwrap();
+
+    Module::<T>::update_storage_buckets_per_bag_limit(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        T::MaxStorageBucketsPerBag::get(),
+    )
+    .unwrap();
+
+    // Free uploads for the large data objects
+    Module::<T>::update_data_size_fee(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        Zero::zero(),
+    )
+    .unwrap();
+
+    Module::<T>::update_storage_buckets_for_bag(
+        RawOrigin::Signed(lead_account_id).into(),
+        bag_id,
+        BTreeSet::from_iter(vec![bucket_id]),
+        Default::default(),
+    )
+    .unwrap();
+
+    bucket_id
+}
+
+// Uploads the data objects with the chunks Merkle root built from the chunks.
+fn upload_challengeable_data_objects<T: Config>(
+    bag_id: BagId<T>,
+    account_id: T::AccountId,
+    chunks: &[Vec<u8>],
+    number: u32,
+) -> BTreeSet<T::DataObjectId> {
+    let chunks_merkle_root: ChunksMerkleRoot = generate_merkle_root_helper::<T, _>(chunks)
+        .last()
+        .unwrap()
+        .as_ref()
+        .try_into()
+        .unwrap();
+
+    let size = T::DataObjectChunkSize::get().saturating_mul(chunks.len().saturated_into());
+
+    let object_creation_list = create_cids(number, 1u8)
+        .into_iter()
+        .map(|cid| DataObjectCreationParameters {
+            size,
+            ipfs_content_id: cid,
+            chunks_merkle_root: Some(chunks_merkle_root),
+        })
+        .collect::<Vec<_>>();
+
+    <Module<T> as DataObjectStorage<T>>::upload_data_objects(UploadParameters::<T> {
+        bag_id,
+        state_bloat_bond_source_account_id: account_id,
+        expected_data_size_fee: Module::<T>::data_object_per_mega_byte_fee(),
+        expected_data_object_state_bloat_bond: Module::<T>::data_object_state_bloat_bond_value(),
+        object_creation_list,
+    })
+    .unwrap()
+}
+
+// Uploads the data objects with the same content ID.
+fn upload_data_objects_with_cid<T: Config>(
+    bag_id: BagId<T>,
+    account_id: T::AccountId,
+    cid: Vec<u8>,
+    number: u32,
+) -> BTreeSet<T::DataObjectId> {
+    let balance = Module::<T>::data_object_state_bloat_bond_value()
+        .saturating_mul(number.saturating_add(1).into())
+        .saturating_add(<T as balances::Config>::ExistentialDeposit::get());
+    let _ = Balances::<T>::make_free_balance_be(&account_id, balance);
+
+    let object_creation_list = (0..number)
+        .map(|_| DataObjectCreationParameters {
+            size: 1,
+            ipfs_content_id: cid.clone(),
+            chunks_merkle_root: None,
+        })
+        .collect::<Vec<_>>();
+
+    <Module<T> as DataObjectStorage<T>>::upload_data_objects(UploadParameters::<T> {
+        bag_id,
+        state_bloat_bond_source_account_id: account_id,
+        expected_data_size_fee: Module::<T>::data_object_per_mega_byte_fee(),
+        expected_data_object_state_bloat_bond: Module::<T>::data_object_state_bloat_bond_value(),
+        object_creation_list,
+    })
+    .unwrap()
+}
 
 benchmarks! {
     where_clause {
@@ -528,6 +656,19 @@ benchmarks! {
         );
     }
 
+    update_data_object_replication_target {
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let new_target = T::MaxStorageBucketsPerBag::get();
+
+    }: _ (RawOrigin::Signed(lead_account_id), new_target)
+    verify {
+
+        assert_eq!(Module::<T>::data_object_replication_target(), new_target);
+        assert_last_event::<T>(
+            RawEvent::DataObjectReplicationTargetUpdated(new_target).into()
+        );
+    }
+
     update_storage_buckets_voucher_max_limits {
         let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
         let new_size = 20u64;
@@ -944,6 +1085,7 @@ benchmarks! {
             .map(|cid| DataObjectCreationParameters{
                 size: i.saturated_into(),
                 ipfs_content_id: cid.clone(),
+                chunks_merkle_root: None,
             })
             .collect::<Vec<_>>();
 
@@ -981,6 +1123,381 @@ benchmarks! {
         );
     }
 
+    respond_to_storage_challenge {
+        let i in 1 .. T::DataObjectChunkSize::get().saturated_into();
+
+        let j in 0 .. MAX_CHUNKS_MERKLE_PROOF_DEPTH;
+
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) =
+            insert_storage_worker::<T>(lead_account_id.clone(), SECOND_WORKER_ACCOUNT_ID);
+        let bag_id = BagId::<T>::Static(StaticBagId::Council);
+        let bucket_id = create_operated_storage_bucket_for_bag::<T>(
+            lead_account_id,
+            worker_id,
+            worker_account_id.clone(),
+            bag_id.clone(),
+        );
+
+        // 2^(j-1) + 1 chunks result in the proof of length j for the first chunk
+        let chunks_number = if j == 0 { 1 } else { 2usize.pow(j - 1) + 1 };
+        let chunk = vec![1u8; i as usize];
+        let mut chunks = vec![Vec::new(); chunks_number];
+        chunks[0] = chunk.clone();
+        let proof = build_merkle_path_helper::<T, _>(&chunks, 0);
+
+        let data_object_id = *upload_challengeable_data_objects::<T>(
+            bag_id.clone(),
+            worker_account_id.clone(),
+            &chunks,
+            1,
+        )
+        .iter()
+        .next()
+        .unwrap();
+
+        let challenge_id = Module::<T>::next_storage_challenge_id();
+        StorageChallengeById::<T>::insert(challenge_id, StorageChallenge::<T> {
+            storage_bucket_id: bucket_id,
+            bag_id,
+            data_object_id,
+            chunk_index: 0,
+            deadline: System::<T>::block_number() + T::StorageChallengeResponsePeriod::get(),
+        });
+    }: _ (RawOrigin::Signed(worker_account_id), worker_id, challenge_id, chunk, proof)
+    verify {
+        assert!(!StorageChallengeById::<T>::contains_key(challenge_id));
+        assert_last_event::<T>(
+            RawEvent::StorageChallengeResponded(challenge_id, bucket_id).into()
+        );
+    }
+
+    claim_storage_bucket_earnings {
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) =
+            insert_storage_worker::<T>(lead_account_id.clone(), SECOND_WORKER_ACCOUNT_ID);
+        let bucket_id = create_operated_storage_bucket_for_bag::<T>(
+            lead_account_id,
+            worker_id,
+            worker_account_id.clone(),
+            BagId::<T>::Static(StaticBagId::Council),
+        );
+
+        let earnings: BalanceOf<T> = 1000u32.into();
+        let _ = Balances::<T>::deposit_creating(
+            &StorageTreasury::<T>::module_account_id(),
+            earnings,
+        );
+        StorageBucketEarningsById::<T>::insert(bucket_id, StorageBucketEarnings::<T> {
+            unclaimed: earnings,
+            earnings_per_byte_checkpoint: Module::<T>::storage_earnings_per_byte(),
+        });
+        StorageOperatorsEarningsFund::<T>::put(earnings);
+    }: _ (RawOrigin::Signed(worker_account_id), worker_id, bucket_id)
+    verify {
+        assert!(Module::<T>::storage_bucket_unclaimed_earnings(&bucket_id).is_zero());
+        assert_last_event::<T>(
+            RawEvent::StorageBucketEarningsClaimed(bucket_id, worker_id, earnings).into()
+        );
+    }
+
+    delete_expired_data_objects {
+        let i in 1 .. OBJECT_COUNT;
+
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) =
+            insert_storage_worker::<T>(lead_account_id.clone(), SECOND_WORKER_ACCOUNT_ID);
+        let bag_id = BagId::<T>::Static(StaticBagId::Council);
+        create_operated_storage_bucket_for_bag::<T>(
+            lead_account_id,
+            worker_id,
+            worker_account_id,
+            bag_id.clone(),
+        );
+
+        // every data object is paid by a different account: one bag update per data object
+        let uploader_balance = Module::<T>::data_object_state_bloat_bond_value()
+            .saturating_mul(2u32.into())
+            .saturating_add(<T as balances::Config>::ExistentialDeposit::get());
+        let data_objects = create_cids(i, 0u8)
+            .into_iter()
+            .enumerate()
+            .map(|(index, cid)| {
+                let account_id = T::AccountId::create_account_id(
+                    EXPIRED_OBJECTS_UPLOADER_ACCOUNT_ID + index as u32
+                );
+                let _ = Balances::<T>::make_free_balance_be(&account_id, uploader_balance);
+
+                <Module::<T> as DataObjectStorage::<T>>::upload_data_objects(UploadParameters::<T>{
+                    bag_id: bag_id.clone(),
+                    state_bloat_bond_source_account_id: account_id,
+                    expected_data_size_fee: Module::<T>::data_object_per_mega_byte_fee(),
+                    expected_data_object_state_bloat_bond:
+                        Module::<T>::data_object_state_bloat_bond_value(),
+                    object_creation_list: vec![DataObjectCreationParameters{
+                        size: 1,
+                        ipfs_content_id: cid,
+                        chunks_merkle_root: None,
+                    }],
+                })
+                .unwrap()
+            })
+            .flatten()
+            .collect::<BTreeSet<_>>();
+
+        System::<T>::set_block_number(
+            System::<T>::block_number()
+                .saturating_add(T::DataObjectAcceptancePeriod::get())
+                .saturating_add(One::one())
+        );
+    }: _ (RawOrigin::Signed(lead_account_id.clone()), bag_id.clone(), data_objects.clone())
+    verify {
+        for data_object_id in data_objects.iter() {
+            assert!(!DataObjectsById::<T>::contains_key(&bag_id, data_object_id));
+            assert!(ExpiredDataObjects::<T>::contains_key(&bag_id, data_object_id));
+        }
+        assert_last_event::<T>(RawEvent::DataObjectsExpired(bag_id, data_objects).into());
+    }
+
+    block_blacklisted_data_objects {
+        let i in 1 .. T::MaxBlacklistedDataObjectsPerCall::get();
+
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) =
+            insert_storage_worker::<T>(lead_account_id.clone(), SECOND_WORKER_ACCOUNT_ID);
+        let bag_id = BagId::<T>::Static(StaticBagId::Council);
+        create_operated_storage_bucket_for_bag::<T>(
+            lead_account_id.clone(),
+            worker_id,
+            worker_account_id.clone(),
+            bag_id.clone(),
+        );
+
+        let cid = create_cids(1, 0u8).into_iter().next().unwrap();
+        let data_objects =
+            upload_data_objects_with_cid::<T>(bag_id.clone(), worker_account_id, cid.clone(), i);
+
+        // Blacklisted without blocking the uploaded data objects.
+        let cid_bounded: Base58Multihash = cid.clone().try_into().unwrap();
+        Blacklist::insert(cid_bounded, ());
+    }: _ (RawOrigin::Signed(lead_account_id), cid)
+    verify {
+        for data_object_id in data_objects.iter() {
+            assert!(Module::<T>::data_object_by_id(&bag_id, data_object_id).blocked);
+        }
+        assert_last_event::<T>(RawEvent::DataObjectsBlocked(bag_id, data_objects).into());
+    }
+
+    remove_blocked_data_objects {
+        let i in 1 .. T::MaxBlacklistedDataObjectsPerCall::get();
+
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) =
+            insert_storage_worker::<T>(lead_account_id.clone(), SECOND_WORKER_ACCOUNT_ID);
+        let bag_id = BagId::<T>::Static(StaticBagId::Council);
+        create_operated_storage_bucket_for_bag::<T>(
+            lead_account_id.clone(),
+            worker_id,
+            worker_account_id.clone(),
+            bag_id.clone(),
+        );
+
+        let cid = create_cids(1, 0u8).into_iter().next().unwrap();
+        let data_objects =
+            upload_data_objects_with_cid::<T>(bag_id.clone(), worker_account_id, cid.clone(), i);
+
+        Module::<T>::update_blacklist(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            Default::default(),
+            BTreeSet::from_iter(vec![cid]),
+        )
+        .unwrap();
+    }: _ (RawOrigin::Signed(lead_account_id), bag_id.clone(), data_objects.clone())
+    verify {
+        for data_object_id in data_objects.iter() {
+            assert!(!DataObjectsById::<T>::contains_key(&bag_id, data_object_id));
+            assert!(ExpiredDataObjects::<T>::contains_key(&bag_id, data_object_id));
+        }
+        assert_last_event::<T>(RawEvent::BlockedDataObjectsRemoved(bag_id, data_objects).into());
+    }
+
+    rebalance_bags {
+        let i in 1 .. T::MaxRebalancedBagsNumber::get();
+        let j in (T::MinStorageBucketsPerBag::get() + 1) ..
+            T::MaxStorageBucketNumber::get().saturated_into::<u32>();
+
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) =
+            insert_storage_worker::<T>(lead_account_id.clone(), SECOND_WORKER_ACCOUNT_ID);
+
+        // The bags are stored by the rebalanced storage bucket and the non-operated ones
+        // required by the dynamic bag creation policy.
+        let bag_buckets_number = T::MinStorageBucketsPerBag::get();
+        let bag_buckets =
+            create_storage_buckets::<T>(lead_account_id.clone(), bag_buckets_number);
+        let storage_bucket_id = *bag_buckets.iter().next().unwrap();
+
+        // All other storage buckets are the operated rebalancing destinations.
+        for _ in bag_buckets_number .. j {
+            let bucket_id = create_storage_bucket_helper::<T>(lead_account_id.clone());
+            set_storage_operator::<T>(
+                lead_account_id.clone(),
+                bucket_id,
+                worker_id,
+                worker_account_id.clone(),
+            );
+        }
+
+        Module::<T>::update_number_of_storage_buckets_in_dynamic_bag_creation_policy(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            DynamicBagType::Member,
+            bag_buckets_number,
+        )
+        .unwrap();
+
+        let bag_ids = (0..i)
+            .map(|member_id| {
+                let dynamic_bag_id = DynamicBagId::<T>::Member(member_id.saturated_into());
+
+                <Module<T> as DataObjectStorage<T>>::create_dynamic_bag(
+                    DynBagCreationParameters::<T> {
+                        bag_id: dynamic_bag_id.clone(),
+                        object_creation_list: Vec::new(),
+                        state_bloat_bond_source_account_id: lead_account_id.clone(),
+                        expected_data_size_fee: Zero::zero(),
+                        expected_data_object_state_bloat_bond: Zero::zero(),
+                        storage_buckets: bag_buckets.clone(),
+                        distribution_buckets: Default::default(),
+                    }
+                )
+                .unwrap();
+
+                BagId::<T>::Dynamic(dynamic_bag_id)
+            })
+            .collect::<BTreeSet<_>>();
+    }: _ (RawOrigin::Signed(lead_account_id), storage_bucket_id, bag_ids.clone())
+    verify {
+        let bucket = StorageBucketById::<T>::get(&storage_bucket_id).unwrap();
+        assert_eq!(bucket.assigned_bags, 0);
+
+        for bag_id in bag_ids {
+            assert!(!Module::<T>::bag(bag_id).stored_by.contains(&storage_bucket_id));
+        }
+    }
+
+    on_initialize_storage_challenges {
+        let i in 0 .. T::MaxStorageChallengesPerBlock::get();
+
+        let max_challenges = T::MaxStorageChallengesPerBlock::get();
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) =
+            insert_storage_worker::<T>(lead_account_id.clone(), SECOND_WORKER_ACCOUNT_ID);
+        let bag_id = BagId::<T>::Static(StaticBagId::Council);
+        let bucket_ids = (0..max_challenges.max(1))
+            .map(|_| {
+                create_operated_storage_bucket_for_bag::<T>(
+                    lead_account_id.clone(),
+                    worker_id,
+                    worker_account_id.clone(),
+                    bag_id.clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // every data object is challengeable and accepted: each attempt issues a challenge
+        let data_objects = upload_challengeable_data_objects::<T>(
+            bag_id.clone(),
+            worker_account_id.clone(),
+            &[vec![1u8]],
+            max_challenges.max(1),
+        );
+        Module::<T>::accept_pending_data_objects(
+            RawOrigin::Signed(worker_account_id).into(),
+            worker_id,
+            bucket_ids[0],
+            bag_id.clone(),
+            data_objects.clone(),
+        )
+        .unwrap();
+
+        // every expired challenge leads to the storage bucket operator slashing
+        let block = System::<T>::block_number() + One::one();
+        let expired_challenge_ids = (0..i)
+            .zip(bucket_ids.iter().zip(data_objects.iter()))
+            .map(|(challenge_id, (bucket_id, data_object_id))| {
+                let challenge_id = challenge_id as u64;
+                StorageChallengeById::<T>::insert(challenge_id, StorageChallenge::<T> {
+                    storage_bucket_id: *bucket_id,
+                    bag_id: bag_id.clone(),
+                    data_object_id: *data_object_id,
+                    chunk_index: 0,
+                    deadline: block,
+                });
+                StorageBucketChallengeFailures::<T>::insert(
+                    bucket_id,
+                    T::MaxStorageChallengeFailures::get().saturating_sub(1),
+                );
+                challenge_id
+            })
+            .collect::<Vec<_>>();
+        StorageChallengesByDeadline::<T>::insert(block, expired_challenge_ids.try_into().unwrap());
+        NextStorageChallengeId::put(i as u64);
+    }: { Pallet::<T>::on_initialize(block); }
+    verify {
+        assert!(!StorageChallengesByDeadline::<T>::contains_key(block));
+        assert_eq!(
+            Module::<T>::next_storage_challenge_id(),
+            (i + max_challenges) as u64
+        );
+    }
+
+    on_initialize_data_objects_acceptance_removals {
+        let i in 1 .. T::MaxDataObjectsAcceptanceRemovalsPerBlock::get();
+
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) =
+            insert_storage_worker::<T>(lead_account_id.clone(), SECOND_WORKER_ACCOUNT_ID);
+        let bag_id = BagId::<T>::Static(StaticBagId::Council);
+        let bucket_id = create_operated_storage_bucket_for_bag::<T>(
+            lead_account_id.clone(),
+            worker_id,
+            worker_account_id.clone(),
+            bag_id.clone(),
+        );
+
+        let cid = create_cids(1, 0u8).into_iter().next().unwrap();
+        let data_objects =
+            upload_data_objects_with_cid::<T>(bag_id.clone(), worker_account_id.clone(), cid, i);
+        Module::<T>::accept_pending_data_objects(
+            RawOrigin::Signed(worker_account_id).into(),
+            worker_id,
+            bucket_id,
+            bag_id.clone(),
+            data_objects.clone(),
+        )
+        .unwrap();
+
+        // every data object falls below the replication target
+        Module::<T>::update_data_object_replication_target(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            1,
+        )
+        .unwrap();
+
+        Module::<T>::update_storage_buckets_for_bag(
+            RawOrigin::Signed(lead_account_id).into(),
+            bag_id.clone(),
+            Default::default(),
+            BTreeSet::from_iter(vec![bucket_id]),
+        )
+        .unwrap();
+    }: { Module::<T>::process_data_objects_acceptance_removals(); }
+    verify {
+        for data_object_id in data_objects.iter() {
+            assert!(!Module::<T>::data_object_by_id(&bag_id, data_object_id).accepted());
+        }
+    }
+
     create_distribution_bucket_family {
         let lead_account_id = insert_distribution_leader::<T>(DISTRIBUTION_WG_LEADER_ACCOUNT_ID);
         let family_id = Module::<T>::next_distribution_bucket_family_id();
@@ -1435,6 +1952,13 @@ mod tests {
         });
     }
 
+    #[test]
+    fn update_data_object_replication_target() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_update_data_object_replication_target());
+        });
+    }
+
     #[test]
     fn update_storage_buckets_voucher_max_limits() {
         build_test_externalities().execute_with(|| {
@@ -1534,6 +2058,62 @@ mod tests {
         });
     }
 
+    #[test]
+    fn respond_to_storage_challenge() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_respond_to_storage_challenge());
+        });
+    }
+
+    #[test]
+    fn claim_storage_bucket_earnings() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_claim_storage_bucket_earnings());
+        });
+    }
+
+    #[test]
+    fn delete_expired_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_delete_expired_data_objects());
+        });
+    }
+
+    #[test]
+    fn block_blacklisted_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_block_blacklisted_data_objects());
+        });
+    }
+
+    #[test]
+    fn remove_blocked_data_objects() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_remove_blocked_data_objects());
+        });
+    }
+
+    #[test]
+    fn rebalance_bags() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_rebalance_bags());
+        });
+    }
+
+    #[test]
+    fn on_initialize_storage_challenges() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_on_initialize_storage_challenges());
+        });
+    }
+
+    #[test]
+    fn on_initialize_data_objects_acceptance_removals() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_on_initialize_data_objects_acceptance_removals());
+        });
+    }
+
     #[test]
     fn create_distribution_bucket_family() {
         build_test_externalities().execute_with(|| {
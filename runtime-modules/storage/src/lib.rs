@@ -33,14 +33,22 @@
 //! updates "Storage buckets per bag" number limit.
 //! - [update_storage_buckets_voucher_max_limits](./struct.Module.html#method.update_storage_buckets_voucher_max_limits) -
 //! updates "Storage buckets voucher max limits".
+//! - [update_data_object_replication_target](./struct.Module.html#method.update_data_object_replication_target) -
+//! updates the target number of storage buckets accepting a data object.
 //! - [update_number_of_storage_buckets_in_dynamic_bag_creation_policy](./struct.Module.html#method.update_number_of_storage_buckets_in_dynamic_bag_creation_policy) -
 //! updates number of storage buckets used in given dynamic bag creation policy.
 //! - [update_blacklist](./struct.Module.html#method.update_blacklist) - adds and removes hashes to
 //! the current blacklist.
+//! - [block_blacklisted_data_objects](./struct.Module.html#method.block_blacklisted_data_objects) -
+//! blocks the existing data objects with the blacklisted content.
+//! - [remove_blocked_data_objects](./struct.Module.html#method.remove_blocked_data_objects) -
+//! removes the blocked data objects from the bag.
 //! - [update_storage_bucket_status](./struct.Module.html#method.update_storage_bucket_status) -
 //! updates whether new bags are being accepted for storage.
 //! - [set_storage_bucket_voucher_limits](./struct.Module.html#method.set_storage_bucket_voucher_limits) -
 //! sets storage bucket voucher limits.
+//! - [rebalance_bags](./struct.Module.html#method.rebalance_bags) - moves bags off an overloaded
+//! storage bucket to the storage buckets with the most free capacity.
 //!
 //!
 //! #### Storage provider extrinsics
@@ -50,6 +58,14 @@
 //! sets storage operator metadata.
 //! - [accept_pending_data_objects](./struct.Module.html#method.accept_pending_data_objects) - a
 //! storage provider signals that the data object was successfully uploaded to its storage.
+//! - [respond_to_storage_challenge](./struct.Module.html#method.respond_to_storage_challenge) - a
+//! storage provider proves it stores the challenged data object chunk.
+//! - [claim_storage_bucket_earnings](./struct.Module.html#method.claim_storage_bucket_earnings) - a
+//! storage provider claims the storage bucket share of the data size fees.
+//!
+//! #### Public extrinsics
+//! - [delete_expired_data_objects](./struct.Module.html#method.delete_expired_data_objects) -
+//! deletes data objects never accepted by a storage bucket before their acceptance deadline.
 //!
 //! #### Distribution working group leader extrinsics
 //! - [create_distribution_bucket_family](./struct.Module.html#method.create_distribution_bucket_family) -
@@ -95,6 +111,16 @@
 //! - delete_dynamic_bag
 //! - create_dynamic_bag
 //! - upload_and_delete_data_objects
+//!
+//! #### Runtime API
+//! Read-only queries are exposed via the [StorageApi](./runtime_api/trait.StorageApi.html)
+//! runtime API:
+//! - [bag_info](./struct.Module.html#method.bag_info) - bag storage and distribution buckets
+//! with the operators metadata.
+//! - [bag_data_objects](./struct.Module.html#method.bag_data_objects) - paginated data objects
+//! of a bag.
+//! - [upload_fee_quote](./struct.Module.html#method.upload_fee_quote) - data size fee and state
+//! bloat bonds required to upload data objects.
 
 //!
 //! ### Pallet constants
@@ -106,6 +132,18 @@
 //! - MaxDistributionBucketFamilyNumber
 //! - DistributionBucketsPerBagValueConstraint
 //! - MaxNumberOfPendingInvitationsPerDistributionBucket
+//! - MaxStorageChallengesPerBlock
+//! - StorageChallengeResponsePeriod
+//! - MaxStorageChallengeFailures
+//! - StorageChallengeSlashPenalty
+//! - DataObjectChunkSize
+//! - StorageOperatorFeeShare
+//! - DataObjectAcceptancePeriod
+//! - MaxStorageBucketNumber
+//! - MaxRebalancedBagsNumber
+//! - MaxBlacklistedDataObjectsPerCall
+//! - MaxDataObjectsAcceptanceRemovalsPerBlock
+//! - MaxDataObjectsMigratedPerBlock
 
 // Compiler demand.
 #![recursion_limit = "256"]
@@ -140,26 +178,29 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod migrations;
+pub mod runtime_api;
 pub mod weights;
 use frame_support::BoundedBTreeMap;
 pub use weights::WeightInfo;
 
 use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::{DispatchError, DispatchResult};
-use frame_support::traits::{Currency, Get};
+use frame_support::traits::{Currency, ExistenceRequirement, Get, Randomness};
 
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, parameter_types,
     storage::{bounded_btree_set::BoundedBTreeSet, bounded_vec::BoundedVec},
-    IterableStorageDoubleMap, PalletId, Parameter,
+    weights::Weight,
+    IterableStorageDoubleMap, IterableStorageMap, PalletId, Parameter,
 };
 use frame_system::ensure_signed;
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
-use sp_runtime::traits::{AccountIdConversion, MaybeSerialize, Member, Saturating};
-use sp_runtime::SaturatedConversion;
+use sp_runtime::traits::{AccountIdConversion, Hash, MaybeSerialize, Member, Saturating};
+use sp_runtime::{Perbill, SaturatedConversion};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::convert::TryInto;
@@ -169,9 +210,10 @@ use sp_std::vec::Vec;
 
 use common::bloat_bond::{RepayableBloatBond, RepayableBloatBondOf};
 use common::costs::{has_sufficient_balance_for_fees, pay_fee};
+use common::merkle_tree::{ProofElementRecord, Side};
 use common::to_kb;
 use common::working_group::WorkingGroup;
-use common::working_group::WorkingGroupAuthenticator;
+use common::working_group::{WorkingGroupAuthenticator, WorkingGroupStakeSlasher};
 
 type WeightInfoStorage<T> = <T as Config>::WeightInfo;
 
@@ -180,6 +222,9 @@ type DataObjAndStateBloatBondAndObjSize<T> =
 
 type DataObjectsWithIds<T> = Vec<(<T as Config>::DataObjectId, DataObjectOf<T>)>;
 
+// Scale of the storage operators earnings per used byte accumulator.
+const STORAGE_EARNINGS_PER_BYTE_PRECISION: u128 = 1_000_000_000_000;
+
 /// Public interface for the storage module.
 pub trait DataObjectStorage<T: Config> {
     /// Upload new data objects.
@@ -247,6 +292,8 @@ pub trait DataObjectStorage<T: Config> {
     /// - objects is not empty or DataObjectIdCollectionIsEmpty error returned
     /// - bag_id must exists or BagDoesntExist error returned
     /// - ALL specified data objects ids must be valid or DataObjectDoesntExist error returned
+    ///   (expired and removed blocked data objects are skipped, see `delete_expired_data_objects`
+    ///   and `remove_blocked_data_objects`)
     /// - Storage Treasury must have sufficient balance for the cumulative state bloat bond for all the object deleted or InsufficientTreasuryBalance error returned
     ///
     /// POSTCONDITIONS:
@@ -276,6 +323,8 @@ pub trait DataObjectStorage<T: Config> {
     /// Creates dynamic bag. BagId should provide the caller
     /// PRECONDITIONS:
     /// - params.bag_id must not exist yet or DynamicBagExists error returned
+    /// - if params.storage_buckets is empty: enough storage buckets accepting new bags with active
+    ///   operators and free capacity exist or NotEnoughStorageBucketsAvailable error returned
     /// - if objects to upload are specified:
     ///   - global uploading block not enabled or UploadingBlocked error returned
     ///   - size of each objects less than MaxDataObjectSize or MaxDataObjectSizeExceeded error returned
@@ -288,7 +337,7 @@ pub trait DataObjectStorage<T: Config> {
     ///
     /// POSTCONDITIONS
     /// - bag added to storage with correct object size/num if objects specified
-    /// - bag registered in provided storage buckets
+    /// - bag registered in provided (or picked by free capacity) storage buckets
     /// - bag registered in provided distribution buckets
     /// - relevant amount transferred from caller account to treasury account
     fn create_dynamic_bag(
@@ -448,13 +497,78 @@ pub trait Config: frame_system::Config + balances::Config + common::MembershipTy
 
     /// Storage working group pallet integration.
     type StorageWorkingGroup: common::working_group::WorkingGroupAuthenticator<Self>
-        + common::working_group::WorkingGroupBudgetHandler<Self::AccountId, BalanceOf<Self>>;
+        + common::working_group::WorkingGroupBudgetHandler<Self::AccountId, BalanceOf<Self>>
+        + common::working_group::WorkingGroupStakeSlasher<Self, BalanceOf<Self>>;
 
     type DistributionWorkingGroup: common::working_group::WorkingGroupAuthenticator<Self>
         + common::working_group::WorkingGroupBudgetHandler<Self::AccountId, BalanceOf<Self>>;
 
     /// Module account initial balance (existential deposit).
     type ModuleAccountInitialBalance: Get<BalanceOf<Self>>;
+
+    /// Randomness source used to pick the proof-of-storage challenges. The challenges are
+    /// only as unpredictable as this source: a collective coin flip lets the storage
+    /// operators foresee them shortly before they are issued.
+    type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+    /// Max number of proof-of-storage challenges issued per block.
+    type MaxStorageChallengesPerBlock: Get<u32>;
+
+    /// Number of blocks a storage operator has to respond to a proof-of-storage challenge.
+    type StorageChallengeResponsePeriod: Get<Self::BlockNumber>;
+
+    /// Number of consecutive failed proof-of-storage challenges that leads to
+    /// the storage bucket operator slashing.
+    type MaxStorageChallengeFailures: Get<u32>;
+
+    /// Stake amount slashed from the storage bucket operator on repeated
+    /// proof-of-storage challenge failures.
+    type StorageChallengeSlashPenalty: Get<BalanceOf<Self>>;
+
+    /// Size in bytes of the data object chunks used to build the chunks Merkle root.
+    type DataObjectChunkSize: Get<u64>;
+
+    /// Share of the data size fee paid to the storage bucket operators proportionally
+    /// to the storage bucket used size. The rest of the fee is burned.
+    type StorageOperatorFeeShare: Get<Perbill>;
+
+    /// Number of blocks a new data object has to be accepted by a storage bucket within.
+    /// Never accepted data objects can be deleted by anyone after that period.
+    type DataObjectAcceptancePeriod: Get<Self::BlockNumber>;
+
+    /// Integration with the modules owning the bags of the expired data objects.
+    type ExpiredDataObjectsHandler: ExpiredDataObjectsHandler<Self>;
+
+    /// Defines max allowed storage bucket number.
+    type MaxStorageBucketNumber: Get<u64>;
+
+    /// Max number of bags moved off a storage bucket in a single rebalancing call.
+    type MaxRebalancedBagsNumber: Get<u32>;
+
+    /// Max number of data objects with the blacklisted content blocked or removed in a single
+    /// call.
+    type MaxBlacklistedDataObjectsPerCall: Get<u32>;
+
+    /// Max number of data objects processed per block on removing their acceptance by
+    /// the storage buckets removed from their bags.
+    type MaxDataObjectsAcceptanceRemovalsPerBlock: Get<u32>;
+
+    /// Max number of data objects migrated to the current layout per block.
+    type MaxDataObjectsMigratedPerBlock: Get<u32>;
+}
+
+/// Notifies the module owning a bag about its data objects deleted without its action: never
+/// accepted by a storage bucket within the `DataObjectAcceptancePeriod` or removed by the storage
+/// working group leader because of the blacklisted content.
+/// The bag owner may keep referencing the expired data objects: they are skipped on the data
+/// objects removal, which makes them forgotten (`ExpiredDataObjects`).
+pub trait ExpiredDataObjectsHandler<T: Config> {
+    /// Handles the expired (and already deleted) data objects of the bag.
+    fn on_data_objects_expired(bag_id: &BagId<T>, data_objects: &BTreeSet<T::DataObjectId>);
+}
+
+impl<T: Config> ExpiredDataObjectsHandler<T> for () {
+    fn on_data_objects_expired(_bag_id: &BagId<T>, _data_objects: &BTreeSet<T::DataObjectId>) {}
 }
 
 /// Operations with local pallet account.
@@ -531,9 +645,9 @@ pub type BucketPair<T> = (
 /// object, as it is used by different parts of the Joystream system.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct DataObject<RepayableBloatBond> {
-    /// Defines whether the data object was accepted by a liason.
-    pub accepted: bool,
+pub struct DataObject<RepayableBloatBond, StorageBucketIdsSet> {
+    /// Storage buckets of the bag that accepted (confirmed storing) the data object.
+    pub accepted_by: StorageBucketIdsSet,
 
     /// Bloat bond for storing the data object in the runtime state.
     pub state_bloat_bond: RepayableBloatBond,
@@ -543,13 +657,144 @@ pub struct DataObject<RepayableBloatBond> {
 
     /// Content identifier presented as base-58 encoded multihash.
     pub ipfs_content_id: Base58Multihash,
+
+    /// Merkle root of the object chunks. Objects without it are never challenged.
+    pub chunks_merkle_root: Option<ChunksMerkleRoot>,
+
+    /// Defines whether the object content was blacklisted after the upload. Blocked objects
+    /// can't be accepted or challenged and are to be removed by the storage working group leader.
+    /// Removing the content from the blacklist doesn't unblock the object: the content is to be
+    /// uploaded again.
+    pub blocked: bool,
 }
 
 parameter_types! { pub const Base58MultihashLen: u32 = 46; }
 pub type Base58Multihash = BoundedVec<u8, Base58MultihashLen>;
 
+parameter_types! { pub const OperatorMetadataMaxLen: u32 = 2048; }
+/// Storage or distribution operator metadata (eg.: node URL). Longer metadata is not recorded
+/// on chain and is only available from the metadata set events.
+pub type OperatorMetadata = BoundedVec<u8, OperatorMetadataMaxLen>;
+
+/// Merkle root of the data object chunks (see `DataObjectChunkSize`), used by the
+/// proof-of-storage challenges.
+pub type ChunksMerkleRoot = [u8; 32];
+
+impl<RepayableBloatBond, StorageBucketId: Ord, MaxStorageBucketsPerBag: Get<u32>>
+    DataObject<RepayableBloatBond, BoundedBTreeSet<StorageBucketId, MaxStorageBucketsPerBag>>
+{
+    /// Defines whether the data object was accepted by at least one storage bucket.
+    pub fn accepted(&self) -> bool {
+        !self.accepted_by.is_empty()
+    }
+
+    /// Number of storage buckets that accepted the data object.
+    pub fn replication_count(&self) -> u32 {
+        self.accepted_by.len().saturated_into()
+    }
+}
+
 /// Type alias for DataObject.
-pub type DataObjectOf<T> = DataObject<RepayableBloatBondOf<T>>;
+pub type DataObjectOf<T> = DataObject<RepayableBloatBondOf<T>, StorageBucketIdsSet<T>>;
+
+/// Type alias for the proof-of-storage challenge ID.
+pub type StorageChallengeId = u64;
+
+/// Proof-of-storage challenge: the storage bucket operator must provide the specified
+/// data object chunk together with its Merkle proof before the deadline.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct StorageChallengeRecord<StorageBucketId, BagId, DataObjectId, BlockNumber> {
+    /// Challenged storage bucket.
+    pub storage_bucket_id: StorageBucketId,
+
+    /// Bag of the challenged data object.
+    pub bag_id: BagId,
+
+    /// Challenged data object.
+    pub data_object_id: DataObjectId,
+
+    /// Index of the requested data object chunk.
+    pub chunk_index: u64,
+
+    /// Block at which the unanswered challenge is considered failed.
+    pub deadline: BlockNumber,
+}
+
+/// Type alias for StorageChallengeRecord.
+pub type StorageChallenge<T> = StorageChallengeRecord<
+    <T as Config>::StorageBucketId,
+    BagId<T>,
+    <T as Config>::DataObjectId,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// Type alias for the proof-of-storage challenge response Merkle proof element.
+pub type ChunkProofElement<T> = ProofElementRecord<<T as frame_system::Config>::Hash, Side>;
+
+/// Type alias for the proof-of-storage challenges expiring at the same block.
+pub type StorageChallengeIds<T> =
+    BoundedVec<StorageChallengeId, <T as Config>::MaxStorageChallengesPerBlock>;
+
+/// Earnings of the storage bucket operator from the data size fees.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct StorageBucketEarningsRecord<Balance> {
+    /// Earnings accrued and not claimed yet.
+    pub unclaimed: Balance,
+
+    /// Value of the earnings per used byte accumulator at the last accrual.
+    pub earnings_per_byte_checkpoint: u128,
+}
+
+/// Type alias for the StorageBucketEarningsRecord.
+pub type StorageBucketEarnings<T> = StorageBucketEarningsRecord<BalanceOf<T>>;
+
+/// Acceptance deadline of the data object not accepted by any storage bucket yet.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DataObjectAcceptanceDeadlineRecord<AccountId, BlockNumber> {
+    /// Block after which the never accepted data object can be deleted.
+    pub deadline: BlockNumber,
+
+    /// Account that paid the state bloat bond for the data object.
+    pub state_bloat_bond_account_id: AccountId,
+}
+
+/// Type alias for the DataObjectAcceptanceDeadlineRecord.
+pub type DataObjectAcceptanceDeadline<T> = DataObjectAcceptanceDeadlineRecord<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// Removal of the bag data objects acceptance by the storage buckets removed from the bag.
+/// The bag data objects are processed in bounded batches on block initialization.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DataObjectsAcceptanceRemovalRecord<StorageBucketIdsSet, DataObjectId> {
+    /// Storage buckets removed from the bag.
+    pub storage_buckets: StorageBucketIdsSet,
+
+    /// Last processed data object of the bag (in the storage order).
+    pub last_data_object_id: Option<DataObjectId>,
+}
+
+/// Type alias for the DataObjectsAcceptanceRemovalRecord.
+pub type DataObjectsAcceptanceRemoval<T> =
+    DataObjectsAcceptanceRemovalRecord<StorageBucketIdsSet<T>, <T as Config>::DataObjectId>;
+
+/// Multi-block migration of the data objects to the current layout. The data objects are
+/// migrated in bounded batches on block initialization.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct DataObjectsMigrationRecord<BagId, DataObjectId> {
+    /// Last migrated data object with its bag (in the storage order).
+    pub last_data_object: Option<(BagId, DataObjectId)>,
+}
+
+/// Type alias for the DataObjectsMigrationRecord.
+pub type DataObjectsMigration<T> =
+    DataObjectsMigrationRecord<BagId<T>, <T as Config>::DataObjectId>;
 
 /// Type alias for bounded storage bucket ids set
 pub type StorageBucketIdsSet<T> =
@@ -661,6 +906,9 @@ pub struct DataObjectCreationParameters {
 
     /// Content identifier presented as IPFS hash.
     pub ipfs_content_id: Vec<u8>,
+
+    /// Optional Merkle root of the object chunks.
+    pub chunks_merkle_root: Option<ChunksMerkleRoot>,
 }
 
 /// Type alias for the BagIdType.
@@ -825,7 +1073,8 @@ pub struct DynBagCreationParametersRecord<
     /// Expected for the data object state bloat bond for the storage pallet.
     pub expected_data_object_state_bloat_bond: Balance,
 
-    /// Chosen storage buckets to assign on the dynamic bag creation.
+    /// Chosen storage buckets to assign on the dynamic bag creation. Storage buckets with the
+    /// most free capacity are picked when empty.
     pub storage_buckets: BTreeSet<StorageBucketId>,
 
     /// Chosen distribution buckets to assign on the dynamic bag creation.
@@ -1095,6 +1344,114 @@ impl<DistributionBucketInvitedOperators, DistributionBucketOperators>
     }
 }
 
+/// Type alias for the StorageBucketInfo.
+pub type StorageBucketInfoOf<T> = StorageBucketInfo<
+    <T as Config>::StorageBucketId,
+    WorkerId<T>,
+    <T as frame_system::Config>::AccountId,
+>;
+
+/// Storage bucket together with its operator metadata (returned by the runtime API).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct StorageBucketInfo<StorageBucketId, WorkerId, AccountId> {
+    /// Storage bucket ID.
+    pub id: StorageBucketId,
+
+    /// Storage bucket.
+    pub bucket: StorageBucketRecord<WorkerId, AccountId>,
+
+    /// Storage operator metadata (`None` if not recorded on chain: not set, set before
+    /// the runtime upgrade recording it or exceeding `OperatorMetadataMaxLen`).
+    pub operator_metadata: Option<Vec<u8>>,
+}
+
+/// Type alias for the DistributionBucketInfo.
+pub type DistributionBucketInfoOf<T> = DistributionBucketInfo<
+    <T as Config>::DistributionBucketFamilyId,
+    <T as Config>::DistributionBucketIndex,
+    WorkerId<T>,
+>;
+
+/// Distribution bucket together with its operators metadata (returned by the runtime API).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct DistributionBucketInfo<
+    DistributionBucketFamilyId: Ord,
+    DistributionBucketIndex: Ord,
+    WorkerId: Ord,
+> {
+    /// Distribution bucket ID.
+    pub id: DistributionBucketIdRecord<DistributionBucketFamilyId, DistributionBucketIndex>,
+
+    /// Distribution bucket.
+    pub bucket: DistributionBucketRecord<BTreeSet<WorkerId>, BTreeSet<WorkerId>>,
+
+    /// Distribution operators metadata (`None` if not recorded on chain, like the storage
+    /// operator metadata).
+    pub operators_metadata: Vec<(WorkerId, Option<Vec<u8>>)>,
+}
+
+/// Type alias for the BagInfo.
+pub type BagInfoOf<T> = BagInfo<
+    <T as Config>::StorageBucketId,
+    WorkerId<T>,
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::DistributionBucketFamilyId,
+    <T as Config>::DistributionBucketIndex,
+>;
+
+/// Bag together with its storage and distribution buckets (returned by the runtime API).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct BagInfo<
+    StorageBucketId,
+    WorkerId: Ord,
+    AccountId,
+    DistributionBucketFamilyId: Ord,
+    DistributionBucketIndex: Ord,
+> {
+    /// Total object size for bag.
+    pub objects_total_size: u64,
+
+    /// Total object number for bag.
+    pub objects_number: u64,
+
+    /// Storage buckets the bag is stored by.
+    pub storage_buckets: Vec<StorageBucketInfo<StorageBucketId, WorkerId, AccountId>>,
+
+    /// Distribution buckets the bag is distributed by.
+    pub distribution_buckets:
+        Vec<DistributionBucketInfo<DistributionBucketFamilyId, DistributionBucketIndex, WorkerId>>,
+}
+
+/// Type alias for the data object returned by the runtime API.
+pub type DataObjectInfoOf<T> =
+    DataObject<RepayableBloatBondOf<T>, BTreeSet<<T as Config>::StorageBucketId>>;
+
+/// Fees required to upload a collection of data objects (returned by the runtime API).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct UploadFeeQuote<Balance> {
+    /// Current size based pricing of new objects uploaded.
+    pub data_object_per_mega_byte_fee: Balance,
+
+    /// Current state bloat bond of a single data object.
+    pub data_object_state_bloat_bond: Balance,
+
+    /// Data size fee for the total size of the objects (rounded up to megabytes).
+    pub data_size_fee: Balance,
+
+    /// State bloat bond for all the objects.
+    pub total_state_bloat_bond: Balance,
+
+    /// Total amount required for the upload: data size fee plus state bloat bonds.
+    pub total_fee: Balance,
+}
+
+/// Type alias for the UploadFeeQuote.
+pub type UploadFeeQuoteOf<T> = UploadFeeQuote<BalanceOf<T>>;
+
 decl_storage! { generate_storage_info
     trait Store for Module<T: Config> as Storage {
         /// Defines whether all new uploads blocked
@@ -1113,6 +1470,13 @@ decl_storage! { generate_storage_info
         pub StorageBucketById get (fn storage_bucket_by_id): map hasher(blake2_128_concat)
             T::StorageBucketId => Option<StorageBucket<T>>;
 
+        /// Total number of storage buckets in the system.
+        pub StorageBucketNumber get(fn storage_bucket_number): u64;
+
+        /// Metadata of the storage buckets operators (eg.: storage node URL).
+        pub StorageOperatorMetadataByBucketId get (fn storage_operator_metadata): map
+            hasher(blake2_128_concat) T::StorageBucketId => Option<OperatorMetadata>;
+
         /// Blacklisted data object hashes.
         pub Blacklist get (fn blacklist): map hasher(blake2_128_concat) Base58Multihash => ();
 
@@ -1156,11 +1520,86 @@ decl_storage! { generate_storage_info
             hasher(blake2_128_concat) T::DistributionBucketFamilyId,
             hasher(blake2_128_concat) T::DistributionBucketIndex => DistributionBucket<T>;
 
+        /// Metadata of the distribution buckets operators (eg.: distributor node URL).
+        pub DistributionOperatorMetadataByBucketId get (fn distribution_operator_metadata):
+            double_map
+            hasher(blake2_128_concat) DistributionBucketId<T>,
+            hasher(blake2_128_concat) WorkerId<T> => Option<OperatorMetadata>;
+
         /// Total number of distribution bucket families in the system.
         pub DistributionBucketFamilyNumber get(fn distribution_bucket_family_number): u64;
 
         /// "Distribution buckets per bag" number limit.
         pub DistributionBucketsPerBagLimit get (fn distribution_buckets_per_bag_limit): u32;
+
+        /// Target number of storage buckets accepting each data object.
+        pub DataObjectReplicationTarget get (fn data_object_replication_target): u32;
+
+        /// Pending removals of the bag data objects acceptance by the storage buckets removed
+        /// from the bag.
+        pub DataObjectsAcceptanceRemovalByBagId get (fn data_objects_acceptance_removal_by_bag_id):
+            map hasher(blake2_128_concat) BagId<T> => Option<DataObjectsAcceptanceRemoval<T>>;
+
+        /// Pending migration of the data objects to the current layout. Data objects operations
+        /// are paused until it completes.
+        pub PendingDataObjectsMigration get (fn data_objects_migration):
+            Option<DataObjectsMigration<T>>;
+
+        /// Bags of the data objects eligible for the proof-of-storage challenges.
+        pub ChallengeableDataObjectBagById get (fn challengeable_data_object_bag_by_id): map
+            hasher(blake2_128_concat) T::DataObjectId => Option<BagId<T>>;
+
+        /// Proof-of-storage challenge id counter. Starts at zero.
+        pub NextStorageChallengeId get(fn next_storage_challenge_id): StorageChallengeId;
+
+        /// Pending proof-of-storage challenges.
+        pub StorageChallengeById get (fn storage_challenge_by_id): map
+            hasher(blake2_128_concat) StorageChallengeId => Option<StorageChallenge<T>>;
+
+        /// Pending proof-of-storage challenges by their deadline.
+        pub StorageChallengesByDeadline get (fn storage_challenges_by_deadline): map
+            hasher(blake2_128_concat) T::BlockNumber => StorageChallengeIds<T>;
+
+        /// Number of consecutive failed proof-of-storage challenges per storage bucket.
+        pub StorageBucketChallengeFailures get (fn storage_bucket_challenge_failures): map
+            hasher(blake2_128_concat) T::StorageBucketId => u32;
+
+        /// Total used size of all storage buckets (sum of the `Voucher::size_used`).
+        pub TotalStorageBucketsSizeUsed get (fn total_storage_buckets_size_used): u64;
+
+        /// Storage operators earnings per used byte accumulated since genesis
+        /// (scaled by `STORAGE_EARNINGS_PER_BYTE_PRECISION`).
+        pub StorageEarningsPerByte get (fn storage_earnings_per_byte): u128;
+
+        /// Storage bucket earnings from the data size fees.
+        pub StorageBucketEarningsById get (fn storage_bucket_earnings_by_id): map
+            hasher(blake2_128_concat) T::StorageBucketId => StorageBucketEarnings<T>;
+
+        /// Data size fee share paid to the storage treasury and not claimed by the storage
+        /// operators yet. Claims never exceed it: the state bloat bonds held by the treasury
+        /// are never used to pay the earnings.
+        pub StorageOperatorsEarningsFund get (fn storage_operators_earnings_fund): BalanceOf<T>;
+
+        /// Acceptance deadlines of the data objects not accepted by any storage bucket yet.
+        pub DataObjectAcceptanceDeadlineById get (fn data_object_acceptance_deadline_by_id): map
+            hasher(blake2_128_concat) T::DataObjectId => Option<DataObjectAcceptanceDeadline<T>>;
+
+        /// Data objects deleted on their acceptance deadline expiry (or removed as blocked)
+        /// and still referenced by the bag owners. Removing them from the bag is a no-op.
+        pub ExpiredDataObjects get (fn expired_data_objects): double_map
+            hasher(blake2_128_concat) BagId<T>,
+            hasher(blake2_128_concat) T::DataObjectId => ();
+
+        /// Bags of the not blocked data objects by their content ID. Used to block the existing
+        /// data objects on the content blacklisting.
+        pub DataObjectBagByContentId get (fn data_object_bag_by_content_id): double_map
+            hasher(blake2_128_concat) Base58Multihash,
+            hasher(blake2_128_concat) T::DataObjectId => Option<BagId<T>>;
+
+        /// Accounts that paid the state bloat bonds of the data objects. Not recorded for
+        /// the data objects uploaded before the runtime upgrade introducing it.
+        pub DataObjectStateBloatBondAccountById get (fn data_object_state_bloat_bond_account_id):
+            map hasher(blake2_128_concat) T::DataObjectId => Option<T::AccountId>;
     }
     add_extra_genesis {
         build(|_| {
@@ -1195,7 +1634,8 @@ decl_event! {
         <T as Config>::DistributionBucketFamilyId,
         DistributionBucketId = DistributionBucketId<T>,
         <T as Config>::DistributionBucketIndex,
-        DynamicBagCreationParameters = DynBagCreationParameters<T>
+        DynamicBagCreationParameters = DynBagCreationParameters<T>,
+        StorageChallenge = StorageChallenge<T>
     {
         /// Emits on creating the storage bucket.
         /// Params
@@ -1286,6 +1726,19 @@ decl_event! {
         /// - new objects number limit
         StorageBucketsVoucherMaxLimitsUpdated(u64, u64),
 
+        /// Emits on changing the data object replication target.
+        /// Params
+        /// - new replication target
+        DataObjectReplicationTargetUpdated(u32),
+
+        /// Emits when the data object replication count falls below the replication target
+        /// on removing storage buckets that accepted it.
+        /// Params
+        /// - bag ID
+        /// - data object ID
+        /// - new replication count
+        DataObjectReplicationBelowTarget(BagId, DataObjectId, u32),
+
         /// Emits on moving data objects between bags.
         /// Params
         /// - source bag ID
@@ -1497,7 +1950,56 @@ decl_event! {
             Vec<u8>,
         ),
 
+        /// Emits on issuing a proof-of-storage challenge.
+        /// Params
+        /// - challenge ID
+        /// - challenge record
+        StorageChallengeIssued(StorageChallengeId, StorageChallenge),
+
+        /// Emits on a successful response to a proof-of-storage challenge.
+        /// Params
+        /// - challenge ID
+        /// - storage bucket ID
+        StorageChallengeResponded(StorageChallengeId, StorageBucketId),
+
+        /// Emits on a proof-of-storage challenge left unanswered before its deadline.
+        /// Params
+        /// - challenge ID
+        /// - storage bucket ID
+        /// - number of consecutive failed challenges of the storage bucket
+        StorageChallengeFailed(StorageChallengeId, StorageBucketId, u32),
+
+        /// Emits on slashing the storage bucket operator for repeated challenge failures.
+        /// Params
+        /// - storage bucket ID
+        /// - operator worker ID
+        /// - slashed amount
+        StorageBucketOperatorSlashed(StorageBucketId, WorkerId, Balance),
+
+        /// Emits on claiming the storage bucket earnings.
+        /// Params
+        /// - storage bucket ID
+        /// - operator worker ID
+        /// - claimed amount
+        StorageBucketEarningsClaimed(StorageBucketId, WorkerId, Balance),
+
+        /// Emits on deleting the data objects never accepted before their acceptance deadline.
+        /// Params
+        /// - bag ID
+        /// - expired data object IDs
+        DataObjectsExpired(BagId, BTreeSet<DataObjectId>),
+
+        /// Emits on blocking the data objects with the blacklisted content.
+        /// Params
+        /// - bag ID
+        /// - blocked data object IDs
+        DataObjectsBlocked(BagId, BTreeSet<DataObjectId>),
 
+        /// Emits on removing the blocked data objects.
+        /// Params
+        /// - bag ID
+        /// - removed data object IDs
+        BlockedDataObjectsRemoved(BagId, BTreeSet<DataObjectId>),
     }
 }
 
@@ -1700,6 +2202,67 @@ decl_error! {
 
         /// Call Disabled
         CallDisabled,
+
+        /// Proof-of-storage challenge doesn't exist.
+        StorageChallengeDoesNotExist,
+
+        /// Proof-of-storage challenge response doesn't match the data object chunks Merkle root.
+        InvalidStorageChallengeResponse,
+
+        /// Provided data object chunk exceeds the chunk size.
+        DataObjectChunkSizeExceeded,
+
+        /// Data object replication target exceeds the max storage buckets per bag number.
+        DataObjectReplicationTargetTooHigh,
+
+        /// Storage bucket has no earnings to claim.
+        NoStorageBucketEarningsToClaim,
+
+        /// Cannot delete a storage bucket with the earnings left to claim.
+        StorageBucketHasUnclaimedEarnings,
+
+        /// Data object has no acceptance deadline: it was already accepted by a storage bucket.
+        DataObjectAcceptanceDeadlineNotSet,
+
+        /// Data object acceptance deadline has not passed yet.
+        DataObjectAcceptanceDeadlineNotPassed,
+
+        /// Max storage bucket number limit exceeded.
+        MaxStorageBucketNumberLimitExceeded,
+
+        /// Not enough storage buckets with free capacity are available for the bag.
+        NotEnoughStorageBucketsAvailable,
+
+        /// Invalid operation with bags: bag ID collection is empty.
+        BagIdCollectionIsEmpty,
+
+        /// Max number of bags rebalanced in a single call exceeded.
+        MaxRebalancedBagsNumberLimitExceeded,
+
+
+        /// The data object content is blacklisted: the data object is blocked.
+        DataObjectBlocked,
+
+        /// The data object is not blocked.
+        DataObjectNotBlocked,
+
+        /// The content ID is not blacklisted.
+        ContentIdNotBlacklisted,
+
+        /// Max number of blacklisted data objects processed in a single call exceeded.
+        MaxBlacklistedDataObjectsNumberLimitExceeded,
+
+        /// The storage bucket was removed from the bag recently: its acceptance of the bag data
+        /// objects is still being removed.
+        StorageBucketAcceptanceRemovalPending,
+
+        /// Too many storage buckets removed from the bag have their acceptance of the bag data
+        /// objects still being removed.
+        StorageBucketsAcceptanceRemovalLimitExceeded,
+
+        /// Data objects are being migrated to the current layout: data objects operations
+        /// are paused until the migration completes.
+        DataObjectsMigrationInProgress,
     }
 }
 
@@ -1751,9 +2314,74 @@ decl_module! {
         /// Exports const - max data object size in bytes.
         const MaxDataObjectSize: u64 = T::MaxDataObjectSize::get();
 
+        /// Exports const - max number of proof-of-storage challenges issued per block.
+        const MaxStorageChallengesPerBlock: u32 = T::MaxStorageChallengesPerBlock::get();
+
+        /// Exports const - number of blocks to respond to a proof-of-storage challenge.
+        const StorageChallengeResponsePeriod: T::BlockNumber =
+            T::StorageChallengeResponsePeriod::get();
+
+        /// Exports const - number of consecutive failed proof-of-storage challenges
+        /// that leads to the storage bucket operator slashing.
+        const MaxStorageChallengeFailures: u32 = T::MaxStorageChallengeFailures::get();
+
+        /// Exports const - storage bucket operator slash amount on repeated
+        /// proof-of-storage challenge failures.
+        const StorageChallengeSlashPenalty: BalanceOf<T> = T::StorageChallengeSlashPenalty::get();
+
+        /// Exports const - data object chunk size in bytes.
+        const DataObjectChunkSize: u64 = T::DataObjectChunkSize::get();
+
+        /// Exports const - share of the data size fee paid to the storage bucket operators.
+        const StorageOperatorFeeShare: Perbill = T::StorageOperatorFeeShare::get();
+
+        /// Exports const - number of blocks a new data object has to be accepted within.
+        const DataObjectAcceptancePeriod: T::BlockNumber = T::DataObjectAcceptancePeriod::get();
+
+        /// Exports const - max allowed storage bucket number.
+        const MaxStorageBucketNumber: u64 = T::MaxStorageBucketNumber::get();
+
+        /// Exports const - max number of bags moved off a storage bucket in a single call.
+        const MaxRebalancedBagsNumber: u32 = T::MaxRebalancedBagsNumber::get();
+
+        /// Exports const - max number of blacklisted data objects blocked or removed in a single
+        /// call.
+        const MaxBlacklistedDataObjectsPerCall: u32 = T::MaxBlacklistedDataObjectsPerCall::get();
+
+        /// Exports const - max number of data objects processed per block on removing their
+        /// acceptance by the storage buckets removed from their bags.
+        const MaxDataObjectsAcceptanceRemovalsPerBlock: u32 =
+            T::MaxDataObjectsAcceptanceRemovalsPerBlock::get();
+
+        /// Exports const - max number of data objects migrated to the current layout per block.
+        const MaxDataObjectsMigratedPerBlock: u32 = T::MaxDataObjectsMigratedPerBlock::get();
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let expired_challenges = Self::process_expired_storage_challenges(now);
+
+            // Data objects are neither challenged nor processed until they are migrated.
+            if let Some(migration) = Self::data_objects_migration() {
+                return WeightInfoStorage::<T>::on_initialize_storage_challenges(expired_challenges)
+                    .saturating_add(Self::migrate_data_objects_batch(migration));
+            }
+
+            Self::issue_storage_challenges(now);
+
+            let processed_data_objects = Self::process_data_objects_acceptance_removals();
+
+            // Challenge issuing is always accounted for its worst case.
+            WeightInfoStorage::<T>::on_initialize_storage_challenges(expired_challenges)
+                .saturating_add(
+                    WeightInfoStorage::<T>::on_initialize_data_objects_acceptance_removals(
+                        processed_data_objects
+                    )
+                )
+        }
+
         // ===== Storage Lead actions =====
 
-        /// Delete storage bucket. Must be empty. Storage operator must be missing.
+        /// Delete storage bucket. Must be empty and have no earnings left to claim.
+        /// Storage operator must be missing.
         /// <weight>
         ///
         /// ## Weight
@@ -1778,11 +2406,29 @@ decl_module! {
             // Check that no assigned bags left.
             ensure!(bucket.no_bags_assigned(), Error::<T>::StorageBucketIsBoundToBag);
 
+            // Check that no claimable earnings are lost with the storage bucket.
+            let earnings = Self::accrued_storage_bucket_earnings(
+                &storage_bucket_id,
+                bucket.voucher.size_used
+            );
+            ensure!(
+                earnings.unclaimed.min(Self::storage_operators_earnings_fund()).is_zero(),
+                Error::<T>::StorageBucketHasUnclaimedEarnings
+            );
+
+            let storage_bucket_number = Self::storage_bucket_number()
+                .checked_sub(1)
+                .ok_or(Error::<T>::ArithmeticError)?;
+
             //
             // == MUTATION SAFE ==
             //
 
+            StorageBucketNumber::put(storage_bucket_number);
+
             <StorageBucketById<T>>::remove(storage_bucket_id);
+            StorageBucketEarningsById::<T>::remove(storage_bucket_id);
+            StorageOperatorMetadataByBucketId::<T>::remove(storage_bucket_id);
 
             Self::deposit_event(
                 RawEvent::StorageBucketDeleted(storage_bucket_id)
@@ -1861,6 +2507,32 @@ decl_module! {
             Self::deposit_event(RawEvent::StorageBucketsPerBagLimitUpdated(new_limit));
         }
 
+        /// Updates the target number of storage buckets accepting each data object.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::update_data_object_replication_target()]
+        pub fn update_data_object_replication_target(origin, new_target: u32) {
+            <T as Config>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+
+            ensure!(
+                new_target <= T::MaxStorageBucketsPerBag::get(),
+                Error::<T>::DataObjectReplicationTargetTooHigh
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            DataObjectReplicationTarget::put(new_target);
+
+            Self::deposit_event(RawEvent::DataObjectReplicationTargetUpdated(new_target));
+        }
+
         /// Updates "Storage buckets voucher max limits".
         /// <weight>
         ///
@@ -1957,20 +2629,26 @@ decl_module! {
             );
         }
 
-        /// Add and remove hashes to the current blacklist.
+        /// Add and remove hashes to the current blacklist. The existing data objects with
+        /// the added hashes are blocked (at most `MaxBlacklistedDataObjectsPerCall` of them,
+        /// see `block_blacklisted_data_objects` for the rest). The blocked data objects stay
+        /// blocked when their hashes are removed from the blacklist.
         /// <weight>
         ///
         /// ## Weight
-        /// `O (W + V)` where:
+        /// `O (W + V + B)` where:
         /// - `W` is the number of items in `remove_hashes`
         /// - `V` is the number of items in `add_hashes`
+        /// - `B` is the `MaxBlacklistedDataObjectsPerCall` runtime constant value
         /// - DB:
-        ///    - `O(W)` - from the the generated weights
+        ///    - `O(W + V + B)` - from the the generated weights
         /// # </weight>
         #[weight = WeightInfoStorage::<T>::update_blacklist(
             remove_hashes.len().saturated_into(),
-            add_hashes.len().saturated_into())
-        ]
+            add_hashes.len().saturated_into()
+        ).saturating_add(WeightInfoStorage::<T>::block_blacklisted_data_objects(
+            T::MaxBlacklistedDataObjectsPerCall::get()
+        ))]
         pub fn update_blacklist(
             origin,
             remove_hashes: BTreeSet<Vec<u8>>,
@@ -2008,38 +2686,126 @@ decl_module! {
             CurrentBlacklistSize::put(updated_blacklist_size);
 
             Self::deposit_event(RawEvent::UpdateBlacklist(remove_hashes, add_hashes));
+
+            Self::block_data_objects_by_content_ids(&verified_add_hashes);
         }
 
-        /// Create storage bucket.
+        /// Block the existing data objects with the blacklisted content ID: continues the
+        /// blocking started by `update_blacklist` (at most `MaxBlacklistedDataObjectsPerCall`
+        /// data objects are blocked per call).
         /// <weight>
         ///
         /// ## Weight
-        /// `O (1)`
+        /// `O (B)` where:
+        /// - `B` is the `MaxBlacklistedDataObjectsPerCall` runtime constant value
         /// - DB:
-        ///    - O(1) doesn't depend on the state or parameters
+        ///    - `O(B)` - from the the generated weights
         /// # </weight>
-        #[weight = WeightInfoStorage::<T>::create_storage_bucket()]
-        pub fn create_storage_bucket(
-            origin,
-            invite_worker: Option<WorkerId<T>>,
-            accepting_new_bags: bool,
-            size_limit: u64,
-            objects_limit: u64,
-        ) {
+        #[weight = WeightInfoStorage::<T>::block_blacklisted_data_objects(
+            T::MaxBlacklistedDataObjectsPerCall::get()
+        )]
+        pub fn block_blacklisted_data_objects(origin, content_id: Vec<u8>) {
             <T as Config>::StorageWorkingGroup::ensure_leader_origin(origin)?;
 
-            let voucher = Voucher {
-                size_limit,
-                objects_limit,
-                ..Default::default()
+            let content_ids = Self::get_existing_hashes(&iter::once(content_id).collect())?;
+
+            ensure!(!content_ids.is_empty(), Error::<T>::ContentIdNotBlacklisted);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::block_data_objects_by_content_ids(&content_ids);
+        }
+
+        /// Remove the blocked data objects from the bag. The state bloat bonds are repaid to
+        /// the accounts that paid them. The state bloat bonds of the data objects uploaded before
+        /// the payers were recorded are kept by the storage treasury, unless their repayment is
+        /// restricted to an account.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the number of items in `data_objects`
+        /// - DB:
+        ///    - `O(W)` - from the the generated weights
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::remove_blocked_data_objects(
+            data_objects.len().saturated_into()
+        )]
+        pub fn remove_blocked_data_objects(
+            origin,
+            bag_id: BagId<T>,
+            data_objects: BTreeSet<T::DataObjectId>,
+        ) {
+            <T as Config>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+
+            let blocked_objects_by_account =
+                Self::validate_remove_blocked_data_objects_params(&bag_id, &data_objects)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for (account_id, objects) in blocked_objects_by_account {
+                Self::try_performing_bag_update(
+                    account_id,
+                    bag_id.clone(),
+                    Default::default(),
+                    objects,
+                )?;
+            }
+
+            for data_object_id in data_objects.iter() {
+                ExpiredDataObjects::<T>::insert(&bag_id, data_object_id, ());
+            }
+
+            T::ExpiredDataObjectsHandler::on_data_objects_expired(&bag_id, &data_objects);
+
+            Self::deposit_event(RawEvent::BlockedDataObjectsRemoved(bag_id, data_objects));
+        }
+
+        /// Create storage bucket.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::create_storage_bucket()]
+        pub fn create_storage_bucket(
+            origin,
+            invite_worker: Option<WorkerId<T>>,
+            accepting_new_bags: bool,
+            size_limit: u64,
+            objects_limit: u64,
+        ) {
+            <T as Config>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+
+            ensure!(
+                Self::storage_bucket_number() < T::MaxStorageBucketNumber::get(),
+                Error::<T>::MaxStorageBucketNumberLimitExceeded
+            );
+
+            let voucher = Voucher {
+                size_limit,
+                objects_limit,
+                ..Default::default()
             };
 
             Self::can_create_storage_bucket(&voucher, &invite_worker)?;
 
+            let storage_bucket_number = Self::storage_bucket_number()
+                .checked_add(1)
+                .ok_or(Error::<T>::ArithmeticError)?;
+
             //
             // == MUTATION SAFE ==
             //
 
+            StorageBucketNumber::put(storage_bucket_number);
+
             let operator_status = invite_worker
                 .map(StorageBucketOperatorStatus::InvitedStorageWorker)
                 .unwrap_or(StorageBucketOperatorStatus::Missing);
@@ -2126,8 +2892,81 @@ decl_module! {
             })?;
 
             Self::deposit_event(
-                RawEvent::StorageBucketsUpdatedForBag(bag_id, add_buckets, remove_buckets)
+                RawEvent::StorageBucketsUpdatedForBag(
+                    bag_id.clone(),
+                    add_buckets,
+                    remove_buckets.clone()
+                )
             );
+
+            if !remove_buckets.is_empty() {
+                Self::schedule_data_objects_acceptance_removal(&bag_id, &remove_buckets);
+            }
+        }
+
+        /// Moves the bags off the (overloaded) storage bucket. Each bag is moved to the storage
+        /// bucket with the most free capacity, that accepts new bags and has an active operator.
+        /// The data objects acceptance by the storage bucket is removed on block initialization.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W + V)` where:
+        /// - `W` is the number of items in `bag_ids`
+        /// - `V` is the `MaxStorageBucketNumber` runtime constant value
+        /// - DB:
+        ///    - `O(W + V)` - from the the generated weights
+        ///    - the data objects acceptance removal is scheduled with `O(1)` per bag and weighted
+        ///      on block initialization
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::rebalance_bags(
+            bag_ids.len().saturated_into(),
+            T::MaxStorageBucketNumber::get().saturated_into())
+        ]
+        pub fn rebalance_bags(
+            origin,
+            storage_bucket_id: T::StorageBucketId,
+            bag_ids: BTreeSet<BagId<T>>,
+        ) {
+            <T as Config>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+
+            let bag_moves = Self::validate_rebalance_bags_params(&storage_bucket_id, &bag_ids)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let remove_buckets = iter::once(storage_bucket_id).collect::<BTreeSet<_>>();
+
+            for (bag_id, destination_bucket_id, voucher_update) in bag_moves {
+                let add_buckets = iter::once(destination_bucket_id).collect::<BTreeSet<_>>();
+
+                Self::change_storage_buckets_vouchers(
+                    &add_buckets,
+                    &voucher_update,
+                    OperationType::Increase
+                );
+                Self::change_storage_buckets_vouchers(
+                    &remove_buckets,
+                    &voucher_update,
+                    OperationType::Decrease
+                );
+
+                Self::change_bag_assignments_for_storage_buckets(&add_buckets, &remove_buckets);
+
+                Bags::<T>::try_mutate(&bag_id, |bag| {
+                    bag.update_storage_buckets::<T>(&mut add_buckets.clone(), &remove_buckets)
+                })?;
+
+                Self::deposit_event(
+                    RawEvent::StorageBucketsUpdatedForBag(
+                        bag_id.clone(),
+                        add_buckets,
+                        remove_buckets.clone()
+                    )
+                );
+
+                Self::schedule_data_objects_acceptance_removal(&bag_id, &remove_buckets);
+            }
         }
 
         /// Cancel pending storage bucket invite. An invitation must be pending.
@@ -2224,6 +3063,8 @@ decl_module! {
                 ..bucket
             });
 
+            StorageOperatorMetadataByBucketId::<T>::remove(storage_bucket_id);
+
             Self::deposit_event(
                 RawEvent::StorageBucketOperatorRemoved(storage_bucket_id)
             );
@@ -2359,7 +3200,8 @@ decl_module! {
             );
         }
 
-        /// Sets storage operator metadata (eg.: storage node URL).
+        /// Sets storage operator metadata (eg.: storage node URL). Metadata exceeding
+        /// `OperatorMetadataMaxLen` is not recorded on chain.
         /// <weight>
         ///
         /// ## Weight
@@ -2388,6 +3230,10 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            StorageOperatorMetadataByBucketId::<T>::mutate(storage_bucket_id, |operator_metadata| {
+                *operator_metadata = Self::bounded_operator_metadata(&metadata)
+            });
+
             Self::deposit_event(
                 RawEvent::StorageOperatorMetadataSet(storage_bucket_id, worker_id, metadata)
             );
@@ -2429,11 +3275,14 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            // Accept data objects for a bag.
+            // Accept data objects for a bag by the storage bucket.
             for data_object_id in data_objects.iter() {
                 DataObjectsById::<T>::mutate(&bag_id, data_object_id, |data_object| {
-                    data_object.accepted = true;
+                    // Accepting buckets are bound to the bag: the set capacity can't be exceeded.
+                    let _ = data_object.accepted_by.try_insert(storage_bucket_id);
+                    Self::index_challengeable_data_object(&bag_id, *data_object_id, data_object);
                 });
+                DataObjectAcceptanceDeadlineById::<T>::remove(data_object_id);
             }
 
             Self::deposit_event(
@@ -2446,6 +3295,175 @@ decl_module! {
             );
         }
 
+        /// A storage provider responds to the proof-of-storage challenge with the requested
+        /// data object chunk and its Merkle proof. The Merkle leaves are the hashes of the
+        /// SCALE-encoded chunks, i.e. including their compact length prefix.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (C + P)` where:
+        /// - `C` is the length of `chunk`
+        /// - `P` is the length of `proof`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::respond_to_storage_challenge(
+            chunk.len().saturated_into(),
+            proof.len().saturated_into(),
+        )]
+        pub fn respond_to_storage_challenge(
+            origin,
+            worker_id: WorkerId<T>,
+            challenge_id: StorageChallengeId,
+            chunk: Vec<u8>,
+            proof: Vec<ChunkProofElement<T>>,
+        ) {
+            let transactor_account_id = ensure_signed(origin)?;
+
+            let challenge = Self::ensure_storage_challenge_exists(&challenge_id)?;
+
+            let bucket = Self::ensure_storage_bucket_exists(&challenge.storage_bucket_id)?;
+
+            Self::ensure_bucket_transactor_access(&bucket, worker_id, transactor_account_id)?;
+
+            ensure!(
+                chunk.len().saturated_into::<u64>() <= T::DataObjectChunkSize::get(),
+                Error::<T>::DataObjectChunkSizeExceeded
+            );
+
+            let data_object =
+                Self::ensure_data_object_exists(&challenge.bag_id, &challenge.data_object_id)?;
+
+            Self::verify_data_object_chunk_proof(
+                &data_object,
+                challenge.chunk_index,
+                &chunk,
+                &proof
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Deadline index entry is cleaned up on the deadline block.
+            StorageChallengeById::<T>::remove(challenge_id);
+
+            StorageBucketChallengeFailures::<T>::remove(challenge.storage_bucket_id);
+
+            Self::deposit_event(
+                RawEvent::StorageChallengeResponded(challenge_id, challenge.storage_bucket_id)
+            );
+        }
+
+        /// A storage provider claims the storage bucket earnings accrued from the data size fees.
+        /// The claimed amount is limited by the data size fees paid to the storage treasury and
+        /// not claimed yet (the rest of the earnings stays unclaimed).
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::claim_storage_bucket_earnings()]
+        pub fn claim_storage_bucket_earnings(
+            origin,
+            worker_id: WorkerId<T>,
+            storage_bucket_id: T::StorageBucketId,
+        ) {
+            let account_id = ensure_signed(origin.clone())?;
+
+            <T as Config>::StorageWorkingGroup::ensure_worker_origin(origin, &worker_id)?;
+
+            let bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            Self::ensure_bucket_invitation_accepted(&bucket, worker_id)?;
+
+            let earnings = Self::accrued_storage_bucket_earnings(
+                &storage_bucket_id,
+                bucket.voucher.size_used
+            );
+            let earnings_fund = Self::storage_operators_earnings_fund();
+            let amount = earnings.unclaimed.min(earnings_fund);
+
+            ensure!(!amount.is_zero(), Error::<T>::NoStorageBucketEarningsToClaim);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            StorageBucketEarningsById::<T>::insert(
+                storage_bucket_id,
+                StorageBucketEarnings::<T> {
+                    unclaimed: earnings.unclaimed.saturating_sub(amount),
+                    ..earnings
+                }
+            );
+            StorageOperatorsEarningsFund::<T>::put(earnings_fund.saturating_sub(amount));
+
+            <Balances<T> as Currency<T::AccountId>>::transfer(
+                &StorageTreasury::<T>::module_account_id(),
+                &account_id,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+
+            Self::deposit_event(
+                RawEvent::StorageBucketEarningsClaimed(storage_bucket_id, worker_id, amount)
+            );
+        }
+
+        // ===== Public actions =====
+
+        /// Delete data objects not accepted by any storage bucket before their acceptance
+        /// deadline. The state bloat bonds are repaid to the accounts that paid them.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W )` where:
+        /// - `W` is the number of items in `data_objects`
+        /// - DB:
+        ///    - `O(W)` - from the the generated weights
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::delete_expired_data_objects(
+            data_objects.len().saturated_into()
+        )]
+        pub fn delete_expired_data_objects(
+            origin,
+            bag_id: BagId<T>,
+            data_objects: BTreeSet<T::DataObjectId>,
+        ) {
+            ensure_signed(origin)?;
+
+            ensure!(!data_objects.is_empty(), Error::<T>::DataObjectIdCollectionIsEmpty);
+
+            Self::ensure_bag_exists(&bag_id)?;
+
+            let expired_objects_by_account =
+                Self::validate_expired_data_objects(&bag_id, &data_objects)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            for (account_id, objects) in expired_objects_by_account {
+                Self::try_performing_bag_update(
+                    account_id,
+                    bag_id.clone(),
+                    Default::default(),
+                    objects,
+                )?;
+            }
+
+            for data_object_id in data_objects.iter() {
+                ExpiredDataObjects::<T>::insert(&bag_id, data_object_id, ());
+            }
+
+            T::ExpiredDataObjectsHandler::on_data_objects_expired(&bag_id, &data_objects);
+
+            Self::deposit_event(RawEvent::DataObjectsExpired(bag_id, data_objects));
+        }
+
         // ===== Distribution Lead actions =====
 
         /// Create a distribution bucket family.
@@ -2924,6 +3942,8 @@ decl_module! {
                 }
             );
 
+            DistributionOperatorMetadataByBucketId::<T>::remove(&bucket_id, operator_worker_id);
+
             Self::deposit_event(
                 RawEvent::DistributionBucketOperatorRemoved(bucket_id, operator_worker_id)
             );
@@ -3011,7 +4031,8 @@ decl_module! {
             );
         }
 
-        /// Set distribution operator metadata for the distribution bucket.
+        /// Set distribution operator metadata for the distribution bucket. Metadata exceeding
+        /// `OperatorMetadataMaxLen` is not recorded on chain.
         /// <weight>
         ///
         /// ## Weight
@@ -3044,6 +4065,12 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            DistributionOperatorMetadataByBucketId::<T>::mutate(
+                &bucket_id,
+                worker_id,
+                |operator_metadata| *operator_metadata = Self::bounded_operator_metadata(&metadata)
+            );
+
             Self::deposit_event(
                 RawEvent::DistributionBucketMetadataSet(worker_id, bucket_id, metadata)
             );
@@ -3180,6 +4207,20 @@ impl<T: Config> DataObjectStorage<T> for Module<T> {
 
         for object_id in objects.iter() {
             DataObjectsById::<T>::swap(&src_bag_id, &object_id, &dest_bag_id, &object_id);
+            // Only the buckets storing both bags keep the data object accepted.
+            let content_id =
+                DataObjectsById::<T>::mutate(&dest_bag_id, &object_id, |data_object| {
+                    Self::retain_data_object_acceptance(data_object, |bucket_id| {
+                        dest_bag.stored_by.contains(bucket_id)
+                    });
+                    Self::index_challengeable_data_object(&dest_bag_id, *object_id, data_object);
+                    data_object.ipfs_content_id.clone()
+                });
+            DataObjectBagByContentId::<T>::mutate(&content_id, &object_id, |bag_id| {
+                if let Some(bag_id) = bag_id {
+                    *bag_id = dest_bag_id.clone();
+                }
+            });
         }
 
         // Change source bag.
@@ -3275,7 +4316,7 @@ impl<T: Config> DataObjectStorage<T> for Module<T> {
     }
 
     fn create_dynamic_bag(
-        params: DynBagCreationParameters<T>,
+        mut params: DynBagCreationParameters<T>,
     ) -> Result<(Bag<T>, BTreeSet<T::DataObjectId>), DispatchError> {
         if !params.object_creation_list.is_empty() {
             // ensure data object state bloat bond
@@ -3292,6 +4333,13 @@ impl<T: Config> DataObjectStorage<T> for Module<T> {
             );
         }
 
+        if params.storage_buckets.is_empty() {
+            params.storage_buckets = Self::pick_storage_buckets_for_dynamic_bag(
+                params.bag_id.clone().into(),
+                &params.object_creation_list,
+            )?;
+        }
+
         Self::validate_storage_buckets_for_dynamic_bag_type(
             params.bag_id.clone().into(),
             &params.storage_buckets,
@@ -3339,34 +4387,159 @@ impl<T: Config> DataObjectStorage<T> for Module<T> {
 }
 
 impl<T: Config> Module<T> {
-    // Increment distribution family number in the storage.
-    fn increment_distribution_family_number() -> DispatchResult {
-        let incremented = Self::distribution_bucket_family_number()
-            .checked_add(1)
-            .ok_or(Error::<T>::ArithmeticError)?;
-        DistributionBucketFamilyNumber::put(incremented);
-        Ok(())
-    }
+    /// Bag with its storage and distribution buckets, including the operators metadata.
+    ///
+    /// Fails if the dynamic bag doesn't exist.
+    pub fn bag_info(bag_id: BagId<T>) -> Result<BagInfoOf<T>, DispatchError> {
+        let bag = Self::ensure_bag_exists(&bag_id)?;
 
-    // Decrement distribution family number in the storage. No effect on zero number.
-    fn decrement_distribution_family_number() -> DispatchResult {
-        let decremented = Self::distribution_bucket_family_number()
-            .checked_sub(1)
-            .ok_or(Error::<T>::ArithmeticError)?;
-        DistributionBucketFamilyNumber::put(decremented);
-        Ok(())
-    }
+        let storage_buckets = bag
+            .stored_by
+            .iter()
+            .filter_map(|id| {
+                Self::storage_bucket_by_id(id).map(|bucket| StorageBucketInfo {
+                    id: *id,
+                    bucket,
+                    operator_metadata: Self::storage_operator_metadata(id)
+                        .map(|metadata| metadata.into_inner()),
+                })
+            })
+            .collect();
 
-    // Ensures the existence of the storage bucket.
-    // Returns the StorageBucket object or error.
-    fn ensure_storage_bucket_exists(
-        storage_bucket_id: &T::StorageBucketId,
-    ) -> Result<StorageBucket<T>, Error<T>> {
-        <StorageBucketById<T>>::get(storage_bucket_id).ok_or(Error::<T>::StorageBucketDoesntExist)
-    }
+        let distribution_buckets = bag
+            .distributed_by
+            .iter()
+            .map(|id| {
+                let bucket = Self::distribution_bucket_by_family_id_by_index(
+                    id.distribution_bucket_family_id,
+                    id.distribution_bucket_index,
+                );
+                let operators_metadata = bucket
+                    .operators
+                    .iter()
+                    .map(|worker_id| {
+                        let metadata = Self::distribution_operator_metadata(id, worker_id);
+                        (*worker_id, metadata.map(|metadata| metadata.into_inner()))
+                    })
+                    .collect();
+
+                DistributionBucketInfo {
+                    id: id.clone(),
+                    bucket: DistributionBucketRecord {
+                        accepting_new_bags: bucket.accepting_new_bags,
+                        distributing: bucket.distributing,
+                        pending_invitations: bucket.pending_invitations.into_inner(),
+                        operators: bucket.operators.into_inner(),
+                        assigned_bags: bucket.assigned_bags,
+                    },
+                    operators_metadata,
+                }
+            })
+            .collect();
 
-    // Ensures the correct invitation for the storage bucket and storage provider. Storage provider
-    // must be invited.
+        Ok(BagInfo {
+            objects_total_size: bag.objects_total_size,
+            objects_number: bag.objects_number,
+            storage_buckets,
+            distribution_buckets,
+        })
+    }
+
+    /// Data objects of the bag in the storage order: at most `limit` objects following
+    /// the `start_after` data object (from the first object if `None`). The last returned
+    /// data object ID is the `start_after` value for the next page.
+    ///
+    /// Fails if the dynamic bag doesn't exist.
+    pub fn bag_data_objects(
+        bag_id: BagId<T>,
+        start_after: Option<T::DataObjectId>,
+        limit: u32,
+    ) -> Result<Vec<(T::DataObjectId, DataObjectInfoOf<T>)>, DispatchError> {
+        Self::ensure_bag_exists(&bag_id)?;
+
+        let mut data_objects = DataObjectsById::<T>::iter_prefix(&bag_id);
+        if let Some(start_after) = start_after {
+            data_objects
+                .set_last_raw_key(DataObjectsById::<T>::hashed_key_for(&bag_id, start_after));
+        }
+
+        Ok(data_objects
+            .take(limit.saturated_into())
+            .map(|(id, obj)| {
+                let data_object = DataObject {
+                    accepted_by: obj.accepted_by.into_inner(),
+                    state_bloat_bond: obj.state_bloat_bond,
+                    size: obj.size,
+                    ipfs_content_id: obj.ipfs_content_id,
+                    chunks_merkle_root: obj.chunks_merkle_root,
+                    blocked: obj.blocked,
+                };
+                (id, data_object)
+            })
+            .collect())
+    }
+
+    /// Exact fees required to upload the data objects with the current storage parameters:
+    /// the data size fee and the state bloat bonds.
+    ///
+    /// Fails if any of the data objects couldn't be uploaded (blocked uploading, invalid size,
+    /// invalid or blacklisted content ID).
+    pub fn upload_fee_quote(
+        objects: Vec<DataObjectCreationParameters>,
+    ) -> Result<UploadFeeQuoteOf<T>, DispatchError> {
+        let (_, total_state_bloat_bond, objects_total_size) =
+            Self::construct_objects_from_list(&objects)?;
+
+        // Uploads don't charge the data size fee when no state bloat bond is requested.
+        let data_size_fee = if total_state_bloat_bond.is_zero() {
+            Zero::zero()
+        } else {
+            Self::calculate_data_storage_fee(objects_total_size)
+        };
+
+        Ok(UploadFeeQuote {
+            data_object_per_mega_byte_fee: Self::data_object_per_mega_byte_fee(),
+            data_object_state_bloat_bond: Self::data_object_state_bloat_bond_value(),
+            data_size_fee,
+            total_state_bloat_bond,
+            total_fee: data_size_fee.saturating_add(total_state_bloat_bond),
+        })
+    }
+
+    // Converts the operator metadata to the bounded collection recorded on chain.
+    // Metadata exceeding `OperatorMetadataMaxLen` is not recorded.
+    fn bounded_operator_metadata(metadata: &[u8]) -> Option<OperatorMetadata> {
+        metadata.to_vec().try_into().ok()
+    }
+
+    // Increment distribution family number in the storage.
+    fn increment_distribution_family_number() -> DispatchResult {
+        let incremented = Self::distribution_bucket_family_number()
+            .checked_add(1)
+            .ok_or(Error::<T>::ArithmeticError)?;
+        DistributionBucketFamilyNumber::put(incremented);
+        Ok(())
+    }
+
+    // Decrement distribution family number in the storage. No effect on zero number.
+    fn decrement_distribution_family_number() -> DispatchResult {
+        let decremented = Self::distribution_bucket_family_number()
+            .checked_sub(1)
+            .ok_or(Error::<T>::ArithmeticError)?;
+        DistributionBucketFamilyNumber::put(decremented);
+        Ok(())
+    }
+
+    // Ensures the existence of the storage bucket.
+    // Returns the StorageBucket object or error.
+    fn ensure_storage_bucket_exists(
+        storage_bucket_id: &T::StorageBucketId,
+    ) -> Result<StorageBucket<T>, Error<T>> {
+        <StorageBucketById<T>>::get(storage_bucket_id).ok_or(Error::<T>::StorageBucketDoesntExist)
+    }
+
+    // Ensures the correct invitation for the storage bucket and storage provider. Storage provider
+    // must be invited.
     fn ensure_bucket_storage_provider_invitation_status(
         bucket: &StorageBucket<T>,
         worker_id: WorkerId<T>,
@@ -3497,12 +4670,85 @@ impl<T: Config> Module<T> {
         Self::ensure_storage_bucket_bound(&bag, storage_bucket_id)?;
 
         for data_object_id in data_objects.iter() {
-            Self::ensure_data_object_exists(bag_id, data_object_id)?;
+            let data_object = Self::ensure_data_object_exists(bag_id, data_object_id)?;
+
+            ensure!(!data_object.blocked, Error::<T>::DataObjectBlocked);
         }
 
         Ok(())
     }
 
+    // Ensures validity of the `remove_blocked_data_objects` extrinsic parameters. Returns the data
+    // objects grouped by the accounts the state bloat bonds are repaid to.
+    fn validate_remove_blocked_data_objects_params(
+        bag_id: &BagId<T>,
+        data_objects: &BTreeSet<T::DataObjectId>,
+    ) -> Result<BTreeMap<T::AccountId, BTreeSet<T::DataObjectId>>, DispatchError> {
+        ensure!(
+            !data_objects.is_empty(),
+            Error::<T>::DataObjectIdCollectionIsEmpty
+        );
+
+        ensure!(
+            data_objects.len() <= T::MaxBlacklistedDataObjectsPerCall::get().saturated_into(),
+            Error::<T>::MaxBlacklistedDataObjectsNumberLimitExceeded
+        );
+
+        Self::ensure_bag_exists(bag_id)?;
+
+        let mut blocked_objects_by_account = BTreeMap::<_, BTreeSet<_>>::new();
+
+        for data_object_id in data_objects.iter() {
+            let data_object = Self::ensure_data_object_exists(bag_id, data_object_id)?;
+
+            ensure!(data_object.blocked, Error::<T>::DataObjectNotBlocked);
+
+            // Repaying to the storage treasury keeps the state bloat bond of the data object
+            // with no recorded payer (unless the repayment is restricted to an account).
+            let account_id = Self::data_object_state_bloat_bond_account_id(data_object_id)
+                .unwrap_or_else(StorageTreasury::<T>::module_account_id);
+
+            blocked_objects_by_account
+                .entry(account_id)
+                .or_default()
+                .insert(*data_object_id);
+        }
+
+        Ok(blocked_objects_by_account)
+    }
+
+    // Blocks at most `MaxBlacklistedDataObjectsPerCall` not yet blocked data objects with
+    // the provided content IDs. Emits the event per bag.
+    fn block_data_objects_by_content_ids(content_ids: &BTreeSet<Base58Multihash>) {
+        let data_objects_to_block = content_ids
+            .iter()
+            .flat_map(|cid| {
+                DataObjectBagByContentId::<T>::iter_prefix(cid)
+                    .map(move |(data_object_id, bag_id)| (cid.clone(), data_object_id, bag_id))
+            })
+            .take(T::MaxBlacklistedDataObjectsPerCall::get().saturated_into())
+            .collect::<Vec<_>>();
+
+        let mut blocked_data_objects = BTreeMap::<BagId<T>, BTreeSet<T::DataObjectId>>::new();
+
+        for (cid, data_object_id, bag_id) in data_objects_to_block {
+            DataObjectBagByContentId::<T>::remove(&cid, data_object_id);
+            ChallengeableDataObjectBagById::<T>::remove(data_object_id);
+            DataObjectsById::<T>::mutate(&bag_id, data_object_id, |data_object| {
+                data_object.blocked = true;
+            });
+
+            blocked_data_objects
+                .entry(bag_id)
+                .or_default()
+                .insert(data_object_id);
+        }
+
+        for (bag_id, data_objects) in blocked_data_objects {
+            Self::deposit_event(RawEvent::DataObjectsBlocked(bag_id, data_objects));
+        }
+    }
+
     // Ensures validity of the `update_storage_buckets_for_bag` extrinsic parameters
     fn validate_update_storage_buckets_for_bag_params(
         bag_id: &BagId<T>,
@@ -3514,6 +4760,9 @@ impl<T: Config> Module<T> {
             Error::<T>::StorageBucketIdCollectionsAreEmpty
         );
 
+        // Migrated data objects are accepted by the current storage buckets of their bag.
+        Self::ensure_data_objects_migrated()?;
+
         let bag = Self::ensure_bag_exists(bag_id)?;
 
         let new_bucket_number = bag
@@ -3540,6 +4789,13 @@ impl<T: Config> Module<T> {
             );
         }
 
+        let acceptance_removal_buckets = Self::data_objects_acceptance_removal_buckets(bag_id);
+
+        Self::ensure_data_objects_acceptance_removal_capacity(
+            &acceptance_removal_buckets,
+            remove_buckets,
+        )?;
+
         for bucket_id in add_buckets.iter() {
             let bucket = Self::ensure_storage_bucket_exists(bucket_id)?;
 
@@ -3552,6 +4808,11 @@ impl<T: Config> Module<T> {
                 !bag.stored_by.contains(bucket_id),
                 Error::<T>::StorageBucketIsBoundToBag
             );
+
+            ensure!(
+                !acceptance_removal_buckets.contains(bucket_id),
+                Error::<T>::StorageBucketAcceptanceRemovalPending
+            );
         }
 
         let voucher_update = VoucherUpdate {
@@ -3564,6 +4825,171 @@ impl<T: Config> Module<T> {
         Ok(voucher_update)
     }
 
+    // Validate the "Rebalance bags" operation data. Picks the destination storage bucket for
+    // each bag. Returns the bags with their destination storage buckets and voucher updates.
+    fn validate_rebalance_bags_params(
+        storage_bucket_id: &T::StorageBucketId,
+        bag_ids: &BTreeSet<BagId<T>>,
+    ) -> Result<Vec<(BagId<T>, T::StorageBucketId, VoucherUpdate)>, DispatchError> {
+        ensure!(!bag_ids.is_empty(), Error::<T>::BagIdCollectionIsEmpty);
+
+        ensure!(
+            bag_ids.len() <= T::MaxRebalancedBagsNumber::get().saturated_into::<usize>(),
+            Error::<T>::MaxRebalancedBagsNumberLimitExceeded
+        );
+
+        Self::ensure_storage_bucket_exists(storage_bucket_id)?;
+
+        // Candidate vouchers are updated after each pick to spread the bags across the buckets.
+        let mut candidates = Self::storage_buckets();
+
+        let mut bag_moves = Vec::new();
+        for bag_id in bag_ids.iter() {
+            let bag = Self::ensure_bag_exists(bag_id)?;
+
+            ensure!(
+                bag.stored_by.contains(storage_bucket_id),
+                Error::<T>::StorageBucketIsNotBoundToBag
+            );
+
+            let acceptance_removal_buckets = Self::data_objects_acceptance_removal_buckets(bag_id);
+
+            Self::ensure_data_objects_acceptance_removal_capacity(
+                &acceptance_removal_buckets,
+                &iter::once(*storage_bucket_id).collect(),
+            )?;
+
+            let voucher_update = VoucherUpdate {
+                objects_number: bag.objects_number,
+                objects_total_size: bag.objects_total_size,
+            };
+
+            // Storage buckets recently removed from the bag can't be picked.
+            let excluded_buckets = bag
+                .stored_by
+                .into_inner()
+                .union(&acceptance_removal_buckets)
+                .copied()
+                .collect();
+
+            let destination_bucket_id =
+                Self::select_storage_buckets(&candidates, &excluded_buckets, 1, &voucher_update)?
+                    .into_iter()
+                    .next()
+                    .ok_or(Error::<T>::NotEnoughStorageBucketsAvailable)?;
+
+            if let Some(bucket) = candidates.get_mut(&destination_bucket_id) {
+                bucket.voucher =
+                    voucher_update.get_updated_voucher(&bucket.voucher, OperationType::Increase);
+                bucket.register_bag_assignment();
+            }
+
+            bag_moves.push((bag_id.clone(), destination_bucket_id, voucher_update));
+        }
+
+        Ok(bag_moves)
+    }
+
+    // Returns all storage buckets in the system.
+    fn storage_buckets() -> BTreeMap<T::StorageBucketId, StorageBucket<T>> {
+        <StorageBucketById<T>>::iter().collect()
+    }
+
+    // Picks the storage buckets for the new dynamic bag according to its creation policy.
+    fn pick_storage_buckets_for_dynamic_bag(
+        dynamic_bag_type: DynamicBagType,
+        object_creation_list: &[DataObjectCreationParameters],
+    ) -> Result<BTreeSet<T::StorageBucketId>, DispatchError> {
+        let creation_policy = Self::get_dynamic_bag_creation_policy(dynamic_bag_type);
+
+        if creation_policy.number_of_storage_buckets == 0 {
+            return Ok(BTreeSet::new());
+        }
+
+        let voucher_update = object_creation_list
+            .iter()
+            .fold(VoucherUpdate::default(), |acc, obj| {
+                acc.add_object(obj.size)
+            });
+
+        Self::select_storage_buckets(
+            &Self::storage_buckets(),
+            &BTreeSet::new(),
+            creation_policy.number_of_storage_buckets,
+            &voucher_update,
+        )
+    }
+
+    // Selects the requested number of storage buckets with the most free capacity left after
+    // the voucher update. Ties are resolved by the lower assigned bags number.
+    fn select_storage_buckets(
+        candidates: &BTreeMap<T::StorageBucketId, StorageBucket<T>>,
+        excluded_buckets: &BTreeSet<T::StorageBucketId>,
+        buckets_number: u32,
+        voucher_update: &VoucherUpdate,
+    ) -> Result<BTreeSet<T::StorageBucketId>, DispatchError> {
+        let mut ranked_buckets = candidates
+            .iter()
+            .filter(|(bucket_id, _)| !excluded_buckets.contains(bucket_id))
+            .filter_map(|(bucket_id, bucket)| {
+                Self::storage_bucket_free_capacity(bucket, voucher_update)
+                    .map(|capacity| (capacity, bucket.assigned_bags, *bucket_id))
+            })
+            .collect::<Vec<_>>();
+
+        ensure!(
+            ranked_buckets.len() >= buckets_number.saturated_into::<usize>(),
+            Error::<T>::NotEnoughStorageBucketsAvailable
+        );
+
+        ranked_buckets.sort_by(|(capacity1, bags1, id1), (capacity2, bags2, id2)| {
+            capacity2
+                .cmp(capacity1)
+                .then(bags1.cmp(bags2))
+                .then(id1.cmp(id2))
+        });
+
+        Ok(ranked_buckets
+            .into_iter()
+            .take(buckets_number.saturated_into())
+            .map(|(_, _, bucket_id)| bucket_id)
+            .collect())
+    }
+
+    // Returns the storage bucket free capacity share left after the voucher update: the lowest
+    // of the free size and free objects number shares. Returns None when the bucket doesn't
+    // accept new bags, has no active storage operator or can't fit the voucher update.
+    fn storage_bucket_free_capacity(
+        bucket: &StorageBucket<T>,
+        voucher_update: &VoucherUpdate,
+    ) -> Option<Perbill> {
+        let has_operator = matches!(
+            bucket.operator_status,
+            StorageBucketOperatorStatus::StorageWorker(..)
+        );
+
+        if !bucket.accepting_new_bags || !has_operator {
+            return None;
+        }
+
+        let voucher = &bucket.voucher;
+
+        let free_size = voucher
+            .size_limit
+            .checked_sub(voucher.size_used)?
+            .checked_sub(voucher_update.objects_total_size)?;
+
+        let free_objects = voucher
+            .objects_limit
+            .checked_sub(voucher.objects_used)?
+            .checked_sub(voucher_update.objects_number)?;
+
+        let size_capacity = Perbill::from_rational(free_size, voucher.size_limit.max(1));
+        let objects_capacity = Perbill::from_rational(free_objects, voucher.objects_limit.max(1));
+
+        Some(size_capacity.min(objects_capacity))
+    }
+
     // Validate the "Move data objects between bags" operation data.
     fn validate_data_objects_on_moving(
         src_bag_id: &BagId<T>,
@@ -3708,6 +5134,7 @@ impl<T: Config> Module<T> {
                     ..bucket
                 })
             {
+                Self::accrue_storage_bucket_earnings(bucket_id, bucket.voucher.size_used);
                 <StorageBucketById<T>>::insert(bucket_id, bucket.clone());
                 Self::deposit_event(RawEvent::VoucherChanged(*bucket_id, bucket.voucher));
             }
@@ -3718,6 +5145,7 @@ impl<T: Config> Module<T> {
         obj: &DataObjectCreationParameters,
     ) -> Result<Base58Multihash, DispatchError> {
         ensure!(!Self::uploading_blocked(), Error::<T>::UploadingBlocked);
+        Self::ensure_data_objects_migrated()?;
         ensure!(
             obj.size <= T::MaxDataObjectSize::get(),
             Error::<T>::MaxDataObjectSizeExceeded,
@@ -3875,6 +5303,8 @@ impl<T: Config> Module<T> {
         bag_id: &BagId<T>,
         data_object_id: &T::DataObjectId,
     ) -> Result<DataObjectOf<T>, DispatchError> {
+        Self::ensure_data_objects_migrated()?;
+
         ensure!(
             <DataObjectsById<T>>::contains_key(bag_id, data_object_id),
             Error::<T>::DataObjectDoesntExist
@@ -3883,6 +5313,17 @@ impl<T: Config> Module<T> {
         Ok(Self::data_object_by_id(bag_id, data_object_id))
     }
 
+    // Ensures the data objects are migrated to the current layout: data objects operations
+    // are paused during the migration.
+    fn ensure_data_objects_migrated() -> DispatchResult {
+        ensure!(
+            Self::data_objects_migration().is_none(),
+            Error::<T>::DataObjectsMigrationInProgress
+        );
+
+        Ok(())
+    }
+
     // Ensures the existence of the distribution bucket family.
     // Returns the DistributionBucketFamily object or error.
     fn ensure_distribution_bucket_family_exists(
@@ -4298,6 +5739,7 @@ impl<T: Config> Module<T> {
 
         // Execute storage bucket updates
         for (id, updated_bucket) in updated_storage_buckets {
+            Self::accrue_storage_bucket_earnings(&id, updated_bucket.voucher.size_used);
             StorageBucketById::<T>::insert(&id, updated_bucket.clone());
             Self::deposit_event(RawEvent::VoucherChanged(id, updated_bucket.voucher.clone()));
         }
@@ -4316,11 +5758,11 @@ impl<T: Config> Module<T> {
             .iter()
             .map(|obj| {
                 let obj_id = NextDataObjectId::<T>::get();
-                DataObjectsById::<T>::insert(
-                    BagId::<T>::Dynamic(dynamic_bag_id.clone()),
-                    obj_id,
-                    obj,
-                );
+                let bag_id = BagId::<T>::Dynamic(dynamic_bag_id.clone());
+                Self::set_data_object_acceptance_deadline(obj_id, &account_id);
+                DataObjectStateBloatBondAccountById::<T>::insert(obj_id, &account_id);
+                DataObjectBagByContentId::<T>::insert(&obj.ipfs_content_id, obj_id, &bag_id);
+                DataObjectsById::<T>::insert(bag_id, obj_id, obj);
                 NextDataObjectId::<T>::put(obj_id.saturating_add(One::one()));
                 obj_id
             })
@@ -4345,7 +5787,7 @@ impl<T: Config> Module<T> {
             Self::validate_objects_to_remove(&bag_id, Some(&objects_to_remove))?;
         let storage_fee = Self::calculate_data_storage_fee(upload_objs_size);
         let upload_objs_num = objects_to_upload.len() as u64;
-        let remove_objs_num = objects_to_remove.len() as u64;
+        let remove_objs_num = remove_objs.len() as u64;
 
         // Get updated storage buckets: vouchers
         let updated_storage_buckets = Self::get_updated_storage_buckets_bag_update(
@@ -4375,6 +5817,7 @@ impl<T: Config> Module<T> {
 
         // Execute storage bucket updates
         for (id, updated_bucket) in updated_storage_buckets {
+            Self::accrue_storage_bucket_earnings(&id, updated_bucket.voucher.size_used);
             StorageBucketById::<T>::insert(&id, updated_bucket.clone());
             Self::deposit_event(RawEvent::VoucherChanged(id, updated_bucket.voucher.clone()));
         }
@@ -4383,16 +5826,28 @@ impl<T: Config> Module<T> {
         let module_account_id = StorageTreasury::<T>::module_account_id();
         for (id, obj) in remove_objs {
             DataObjectsById::<T>::remove(&bag_id, id);
+            ChallengeableDataObjectBagById::<T>::remove(id);
+            DataObjectAcceptanceDeadlineById::<T>::remove(id);
+            DataObjectStateBloatBondAccountById::<T>::remove(id);
+            DataObjectBagByContentId::<T>::remove(&obj.ipfs_content_id, id);
             // repay the bloat bond
             obj.state_bloat_bond
                 .repay::<T>(&module_account_id, &account_id, false)?;
         }
 
+        // Expired data objects are not referenced by the bag owner anymore
+        for id in objects_to_remove.iter() {
+            ExpiredDataObjects::<T>::remove(&bag_id, id);
+        }
+
         // Add data objects
         let created_objects_ids: BTreeSet<T::DataObjectId> = objects_to_insert
             .iter()
             .map(|obj| {
                 let obj_id = NextDataObjectId::<T>::get();
+                Self::set_data_object_acceptance_deadline(obj_id, &account_id);
+                DataObjectStateBloatBondAccountById::<T>::insert(obj_id, &account_id);
+                DataObjectBagByContentId::<T>::insert(&obj.ipfs_content_id, obj_id, &bag_id);
                 DataObjectsById::<T>::insert(&bag_id, obj_id, obj);
                 NextDataObjectId::<T>::put(obj_id.saturating_add(One::one()));
                 obj_id
@@ -4439,6 +5894,7 @@ impl<T: Config> Module<T> {
 
         // Execute storage bucket updates
         for (id, updated_bucket) in updated_storage_buckets {
+            Self::accrue_storage_bucket_earnings(&id, updated_bucket.voucher.size_used);
             StorageBucketById::<T>::insert(&id, updated_bucket.clone());
             Self::deposit_event(RawEvent::VoucherChanged(id, updated_bucket.voucher.clone()));
         }
@@ -4456,11 +5912,18 @@ impl<T: Config> Module<T> {
         let module_account_id = StorageTreasury::<T>::module_account_id();
         for (id, obj) in remove_objs {
             DataObjectsById::<T>::remove(&bag_id, id);
+            ChallengeableDataObjectBagById::<T>::remove(id);
+            DataObjectAcceptanceDeadlineById::<T>::remove(id);
+            DataObjectStateBloatBondAccountById::<T>::remove(id);
+            DataObjectBagByContentId::<T>::remove(&obj.ipfs_content_id, id);
             // Repay the bloat bond
             obj.state_bloat_bond
                 .repay::<T>(&module_account_id, account_id, false)?;
         }
 
+        ExpiredDataObjects::<T>::remove_prefix(&bag_id, None);
+        DataObjectsAcceptanceRemovalByBagId::<T>::remove(&bag_id);
+
         // Remove bag
         Bags::<T>::remove(&bag_id);
 
@@ -4496,12 +5959,14 @@ impl<T: Config> Module<T> {
             .map(|param| {
                 Self::upload_data_objects_checks(param).map(|bounded_cid| {
                     DataObject {
-                        accepted: false,
+                        accepted_by: Default::default(),
                         // Default value, possibly overriden later
                         // based on pay_data_objects_bloat_bonds result
                         state_bloat_bond: RepayableBloatBond::new(state_bloat_bond, None),
                         size: param.size,
                         ipfs_content_id: bounded_cid,
+                        chunks_merkle_root: param.chunks_merkle_root,
+                        blocked: false,
                     }
                 })
             })
@@ -4531,8 +5996,10 @@ impl<T: Config> Module<T> {
     ) -> Result<(DataObjectsWithIds<T>, u64), DispatchError> {
         let mut total_size = 0u64;
         if let Some(object_ids) = specific_objects {
+            // Expired data objects are already deleted.
             let objects = object_ids
                 .iter()
+                .filter(|id| !ExpiredDataObjects::<T>::contains_key(bag_id, id))
                 .map(|id| {
                     let obj = Self::ensure_data_object_exists(bag_id, id)?;
                     total_size = total_size.saturating_add(obj.size);
@@ -4541,6 +6008,8 @@ impl<T: Config> Module<T> {
                 .collect::<Result<_, DispatchError>>()?;
             Ok((objects, total_size))
         } else {
+            Self::ensure_data_objects_migrated()?;
+
             let objects = DataObjectsById::<T>::iter_prefix(&bag_id)
                 .map(|(id, obj)| {
                     total_size = total_size.saturating_add(obj.size);
@@ -4655,7 +6124,557 @@ impl<T: Config> Module<T> {
             .collect())
     }
 
+    // Pays the data size fee: the storage operators share is distributed among the storage
+    // buckets proportionally to their used size, the rest is burned.
     fn pay_storage_fee(source: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
-        pay_fee::<T>(source, None, amount).map(|_| ())
+        let operators_share = T::StorageOperatorFeeShare::get() * amount;
+        let total_size_used = Self::total_storage_buckets_size_used();
+
+        // Nothing is stored yet: the whole fee is burned.
+        if operators_share.is_zero() || total_size_used == 0 {
+            return pay_fee::<T>(source, None, amount).map(|_| ());
+        }
+
+        let treasury = StorageTreasury::<T>::module_account_id();
+        pay_fee::<T>(source, Some(&treasury), operators_share)?;
+        pay_fee::<T>(source, None, amount.saturating_sub(operators_share))?;
+
+        StorageOperatorsEarningsFund::<T>::mutate(|fund| {
+            *fund = fund.saturating_add(operators_share)
+        });
+
+        let earnings_per_byte = operators_share
+            .saturated_into::<u128>()
+            .saturating_mul(STORAGE_EARNINGS_PER_BYTE_PRECISION)
+            .checked_div(total_size_used.into())
+            .unwrap_or_default();
+        StorageEarningsPerByte::mutate(|accumulated| {
+            *accumulated = accumulated.saturating_add(earnings_per_byte)
+        });
+
+        Ok(())
+    }
+
+    // Keeps the data object eligible for the proof-of-storage challenges only while it has
+    // the chunks Merkle root and is accepted by a storage bucket (the challenges are sampled
+    // from the stored data objects only).
+    fn index_challengeable_data_object(
+        bag_id: &BagId<T>,
+        data_object_id: T::DataObjectId,
+        data_object: &DataObjectOf<T>,
+    ) {
+        if data_object.chunks_merkle_root.is_some() && !data_object.accepted_by.is_empty() {
+            ChallengeableDataObjectBagById::<T>::insert(data_object_id, bag_id.clone());
+        } else {
+            ChallengeableDataObjectBagById::<T>::remove(data_object_id);
+        }
+    }
+
+    // Sets the deadline for the new data object to be accepted by a storage bucket.
+    pub(crate) fn set_data_object_acceptance_deadline(
+        data_object_id: T::DataObjectId,
+        state_bloat_bond_account_id: &T::AccountId,
+    ) {
+        let deadline = <frame_system::Pallet<T>>::block_number()
+            .saturating_add(T::DataObjectAcceptancePeriod::get());
+
+        DataObjectAcceptanceDeadlineById::<T>::insert(
+            data_object_id,
+            DataObjectAcceptanceDeadline::<T> {
+                deadline,
+                state_bloat_bond_account_id: state_bloat_bond_account_id.clone(),
+            },
+        );
+    }
+
+    // Ensures the data objects exist and their acceptance deadlines passed. Returns the data
+    // objects grouped by the accounts the state bloat bonds are repaid to.
+    fn validate_expired_data_objects(
+        bag_id: &BagId<T>,
+        data_objects: &BTreeSet<T::DataObjectId>,
+    ) -> Result<BTreeMap<T::AccountId, BTreeSet<T::DataObjectId>>, DispatchError> {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let mut expired_objects_by_account = BTreeMap::<_, BTreeSet<_>>::new();
+
+        for data_object_id in data_objects.iter() {
+            Self::ensure_data_object_exists(bag_id, data_object_id)?;
+
+            let acceptance_deadline = Self::data_object_acceptance_deadline_by_id(data_object_id)
+                .ok_or(Error::<T>::DataObjectAcceptanceDeadlineNotSet)?;
+
+            ensure!(
+                acceptance_deadline.deadline < now,
+                Error::<T>::DataObjectAcceptanceDeadlineNotPassed
+            );
+
+            expired_objects_by_account
+                .entry(acceptance_deadline.state_bloat_bond_account_id)
+                .or_default()
+                .insert(*data_object_id);
+        }
+
+        Ok(expired_objects_by_account)
+    }
+
+    // Ensures the existence of the proof-of-storage challenge.
+    fn ensure_storage_challenge_exists(
+        challenge_id: &StorageChallengeId,
+    ) -> Result<StorageChallenge<T>, DispatchError> {
+        Self::storage_challenge_by_id(challenge_id)
+            .ok_or_else(|| Error::<T>::StorageChallengeDoesNotExist.into())
+    }
+
+    // Number of the `DataObjectChunkSize` chunks the data object is split into.
+    pub(crate) fn data_object_chunks_number(object_size: u64) -> u64 {
+        let chunk_size = T::DataObjectChunkSize::get().max(1);
+
+        object_size
+            .saturating_add(chunk_size.saturating_sub(1))
+            .saturating_div(chunk_size)
+    }
+
+    // Verifies the chunk Merkle proof against the data object chunks Merkle root.
+    // Leaves are the chunk hashes, the last node of an odd tree layer is paired with itself.
+    // Proof element sides must match the position of the requested chunk.
+    // Leaf and parent hashes are `T::Hashing::hash_of` the SCALE-encoded value: the leaf hash
+    // covers the chunk bytes prefixed with their compact length, the parent hash covers
+    // the `[left, right]` hashes pair. Off-chain provers must build the Merkle root the same way.
+    fn verify_data_object_chunk_proof(
+        data_object: &DataObjectOf<T>,
+        chunk_index: u64,
+        chunk: &[u8],
+        proof: &[ChunkProofElement<T>],
+    ) -> DispatchResult {
+        let chunks_merkle_root = data_object
+            .chunks_merkle_root
+            .ok_or(Error::<T>::InvalidStorageChallengeResponse)?;
+
+        let leaf = <T as frame_system::Config>::Hashing::hash_of(&chunk);
+        let layer_len = Self::data_object_chunks_number(data_object.size);
+
+        let (candidate_root, _, root_layer_len) = proof.iter().try_fold(
+            (leaf, chunk_index, layer_len),
+            |(hash, index, layer_len), el| {
+                ensure!(layer_len > 1, Error::<T>::InvalidStorageChallengeResponse);
+
+                let is_right_node = index & 1 == 1;
+                let has_sibling = is_right_node || index.saturating_add(1) < layer_len;
+                let expected_side = if is_right_node {
+                    Side::Left
+                } else {
+                    Side::Right
+                };
+                ensure!(
+                    !has_sibling || el.side == expected_side,
+                    Error::<T>::InvalidStorageChallengeResponse
+                );
+
+                let parent = if is_right_node {
+                    <T as frame_system::Config>::Hashing::hash_of(&[el.hash, hash])
+                } else {
+                    <T as frame_system::Config>::Hashing::hash_of(&[hash, el.hash])
+                };
+
+                Ok::<_, Error<T>>((
+                    parent,
+                    index.saturating_div(2),
+                    layer_len.saturating_add(1).saturating_div(2),
+                ))
+            },
+        )?;
+
+        ensure!(
+            root_layer_len == 1 && candidate_root.as_ref() == &chunks_merkle_root[..],
+            Error::<T>::InvalidStorageChallengeResponse
+        );
+
+        Ok(())
+    }
+
+    // Fails the proof-of-storage challenges with the deadline at the current block.
+    // Returns the number of processed challenges.
+    fn process_expired_storage_challenges(now: T::BlockNumber) -> u32 {
+        let challenge_ids = StorageChallengesByDeadline::<T>::take(now);
+
+        for challenge_id in challenge_ids.iter() {
+            // Responded challenges are already removed.
+            if let Some(challenge) = StorageChallengeById::<T>::take(challenge_id) {
+                Self::fail_storage_challenge(*challenge_id, challenge);
+            }
+        }
+
+        challenge_ids.len().saturated_into()
+    }
+
+    // Registers the challenge failure for the storage bucket and slashes its operator
+    // on reaching `MaxStorageChallengeFailures`. The challenge is dropped without penalty
+    // when the data object or the storage bucket operator is gone.
+    fn fail_storage_challenge(challenge_id: StorageChallengeId, challenge: StorageChallenge<T>) {
+        let storage_bucket_id = challenge.storage_bucket_id;
+
+        // Deleted and blocked data objects are not expected to be stored.
+        let data_object_stored =
+            DataObjectsById::<T>::try_get(&challenge.bag_id, &challenge.data_object_id)
+                .map_or(false, |data_object| !data_object.blocked);
+
+        if !data_object_stored {
+            return;
+        }
+
+        let worker_id = match Self::storage_bucket_by_id(storage_bucket_id)
+            .map(|bucket| bucket.operator_status)
+        {
+            Some(StorageBucketOperatorStatus::StorageWorker(worker_id, _)) => worker_id,
+            _ => return,
+        };
+
+        let failures = Self::storage_bucket_challenge_failures(storage_bucket_id).saturating_add(1);
+
+        Self::deposit_event(RawEvent::StorageChallengeFailed(
+            challenge_id,
+            storage_bucket_id,
+            failures,
+        ));
+
+        if failures < T::MaxStorageChallengeFailures::get() {
+            StorageBucketChallengeFailures::<T>::insert(storage_bucket_id, failures);
+            return;
+        }
+
+        StorageBucketChallengeFailures::<T>::remove(storage_bucket_id);
+
+        let penalty = T::StorageChallengeSlashPenalty::get();
+        if T::StorageWorkingGroup::slash_worker_stake(&worker_id, penalty).is_ok() {
+            Self::deposit_event(RawEvent::StorageBucketOperatorSlashed(
+                storage_bucket_id,
+                worker_id,
+                penalty,
+            ));
+        }
+    }
+
+    // Issues up to `MaxStorageChallengesPerBlock` proof-of-storage challenges
+    // for randomly picked stored data objects.
+    fn issue_storage_challenges(now: T::BlockNumber) {
+        let deadline = now.saturating_add(T::StorageChallengeResponsePeriod::get());
+
+        for attempt in 0..T::MaxStorageChallengesPerBlock::get() {
+            let random = Self::storage_challenge_random_value(attempt);
+
+            let challenge = Self::random_challengeable_data_object(random).and_then(
+                |(data_object_id, bag_id)| {
+                    Self::pick_storage_challenge(data_object_id, bag_id, random, deadline)
+                },
+            );
+
+            if let Some(challenge) = challenge {
+                let challenge_id = Self::next_storage_challenge_id();
+
+                if StorageChallengesByDeadline::<T>::try_mutate(deadline, |challenge_ids| {
+                    challenge_ids.try_push(challenge_id)
+                })
+                .is_err()
+                {
+                    break;
+                }
+
+                StorageChallengeById::<T>::insert(challenge_id, challenge.clone());
+                NextStorageChallengeId::put(challenge_id.saturating_add(1));
+
+                Self::deposit_event(RawEvent::StorageChallengeIssued(challenge_id, challenge));
+            }
+        }
+    }
+
+    // Picks a challengeable data object: the first one following a random cursor in the
+    // challengeable data objects index (wrapping around). The index keys are hashed, so
+    // the cursor key (hashed random data object ID) lands at a random index position.
+    fn random_challengeable_data_object(random: u64) -> Option<(T::DataObjectId, BagId<T>)> {
+        let cursor_data_object_id: T::DataObjectId = random.saturated_into();
+
+        let mut data_objects = ChallengeableDataObjectBagById::<T>::iter();
+        data_objects.set_last_raw_key(ChallengeableDataObjectBagById::<T>::hashed_key_for(
+            cursor_data_object_id,
+        ));
+
+        data_objects
+            .next()
+            .or_else(|| ChallengeableDataObjectBagById::<T>::iter().next())
+    }
+
+    // Builds the challenge for a challengeable data object and a random storage bucket
+    // with an active operator among the buckets that accepted the object.
+    fn pick_storage_challenge(
+        data_object_id: T::DataObjectId,
+        bag_id: BagId<T>,
+        random: u64,
+        deadline: T::BlockNumber,
+    ) -> Option<StorageChallenge<T>> {
+        let data_object = DataObjectsById::<T>::try_get(&bag_id, data_object_id).ok()?;
+
+        // Storage buckets removed from the bag may still be listed until the acceptance removal.
+        let stored_by = Self::bag(&bag_id).stored_by;
+        let accepted_by = data_object
+            .accepted_by
+            .iter()
+            .filter(|bucket_id| stored_by.contains(bucket_id))
+            .copied()
+            .collect::<Vec<_>>();
+        let bucket_index = random.checked_rem(accepted_by.len().saturated_into())?;
+        let storage_bucket_id = *accepted_by.get(bucket_index.saturated_into::<usize>())?;
+
+        let bucket = Self::storage_bucket_by_id(storage_bucket_id)?;
+        if !matches!(
+            bucket.operator_status,
+            StorageBucketOperatorStatus::StorageWorker(..)
+        ) {
+            return None;
+        }
+
+        let chunk_index = random
+            .rotate_right(32)
+            .checked_rem(Self::data_object_chunks_number(data_object.size))?;
+
+        Some(StorageChallenge::<T> {
+            storage_bucket_id,
+            bag_id,
+            data_object_id,
+            chunk_index,
+            deadline,
+        })
+    }
+
+    // Random value for the proof-of-storage challenge issued in the current block.
+    // Known limitation: `T::Randomness` (e.g. the collective coin flip) is predictable,
+    // so the storage operators can foresee the next challenges shortly before they are issued.
+    fn storage_challenge_random_value(attempt: u32) -> u64 {
+        let (seed, _) = T::Randomness::random(&(b"storage_challenge", attempt).encode());
+
+        u64::decode(&mut seed.as_ref()).unwrap_or_default()
+    }
+
+    /// Returns the number of storage buckets of the bag that accepted the data object.
+    pub fn data_object_replication_count(
+        bag_id: &BagId<T>,
+        data_object_id: &T::DataObjectId,
+    ) -> u32 {
+        let stored_by = Self::bag(bag_id).stored_by;
+
+        Self::data_object_by_id(bag_id, data_object_id)
+            .accepted_by
+            .iter()
+            .filter(|bucket_id| stored_by.contains(bucket_id))
+            .count()
+            .saturated_into()
+    }
+
+    // Storage buckets removed from the bag with their acceptance of the bag data objects
+    // still being removed.
+    fn data_objects_acceptance_removal_buckets(bag_id: &BagId<T>) -> BTreeSet<T::StorageBucketId> {
+        Self::data_objects_acceptance_removal_by_bag_id(bag_id)
+            .map(|removal| removal.storage_buckets.into_inner())
+            .unwrap_or_default()
+    }
+
+    // Ensures the storage buckets removed from the bag fit the pending acceptance removal.
+    fn ensure_data_objects_acceptance_removal_capacity(
+        acceptance_removal_buckets: &BTreeSet<T::StorageBucketId>,
+        remove_buckets: &BTreeSet<T::StorageBucketId>,
+    ) -> DispatchResult {
+        let buckets_number = acceptance_removal_buckets
+            .union(remove_buckets)
+            .count()
+            .saturated_into::<u32>();
+
+        ensure!(
+            buckets_number <= T::MaxStorageBucketsPerBag::get(),
+            Error::<T>::StorageBucketsAcceptanceRemovalLimitExceeded
+        );
+
+        Ok(())
+    }
+
+    // Schedules the removal of the bag data objects acceptance by the storage buckets removed
+    // from the bag. The bag data objects are processed on block initialization.
+    fn schedule_data_objects_acceptance_removal(
+        bag_id: &BagId<T>,
+        remove_buckets: &BTreeSet<T::StorageBucketId>,
+    ) {
+        DataObjectsAcceptanceRemovalByBagId::<T>::mutate(bag_id, |removal| {
+            let removal = removal.get_or_insert_with(Default::default);
+
+            // Capacity is checked on the parameters validation.
+            for bucket_id in remove_buckets.iter() {
+                let _ = removal.storage_buckets.try_insert(*bucket_id);
+            }
+
+            // Already processed data objects are to be processed for the new buckets as well.
+            removal.last_data_object_id = None;
+        });
+    }
+
+    // Processes the pending data objects acceptance removals: at most
+    // `MaxDataObjectsAcceptanceRemovalsPerBlock` data objects (a bag without data objects
+    // counts as one). Returns the number of the processed data objects.
+    fn process_data_objects_acceptance_removals() -> u32 {
+        let max_data_objects = T::MaxDataObjectsAcceptanceRemovalsPerBlock::get();
+        let mut processed_data_objects: u32 = 0;
+
+        while processed_data_objects < max_data_objects {
+            let next_removal = DataObjectsAcceptanceRemovalByBagId::<T>::iter().next();
+            let (bag_id, removal) = match next_removal {
+                Some(removal) => removal,
+                None => break,
+            };
+
+            let data_objects_number = Self::remove_data_objects_acceptance(
+                &bag_id,
+                removal,
+                max_data_objects.saturating_sub(processed_data_objects),
+            );
+
+            processed_data_objects =
+                processed_data_objects.saturating_add(data_objects_number.max(1));
+        }
+
+        processed_data_objects
+    }
+
+    // Removes the acceptance of at most `limit` bag data objects by the storage buckets removed
+    // from the bag, starting after the last processed data object. Returns the number of
+    // the processed data objects.
+    fn remove_data_objects_acceptance(
+        bag_id: &BagId<T>,
+        removal: DataObjectsAcceptanceRemoval<T>,
+        limit: u32,
+    ) -> u32 {
+        let replication_target = Self::data_object_replication_target();
+        let remove_buckets = removal.storage_buckets;
+
+        let mut data_objects_iter = DataObjectsById::<T>::iter_prefix(bag_id);
+        if let Some(last_data_object_id) = removal.last_data_object_id {
+            data_objects_iter.set_last_raw_key(DataObjectsById::<T>::hashed_key_for(
+                bag_id,
+                last_data_object_id,
+            ));
+        }
+        let data_objects = data_objects_iter
+            .take(limit.saturated_into())
+            .collect::<Vec<_>>();
+        let data_objects_number = data_objects.len().saturated_into::<u32>();
+
+        let last_data_object_id = data_objects.last().map(|(id, _)| *id);
+
+        for (data_object_id, mut data_object) in data_objects {
+            if !data_object
+                .accepted_by
+                .iter()
+                .any(|bucket_id| remove_buckets.contains(bucket_id))
+            {
+                continue;
+            }
+
+            let old_replication_count = data_object.replication_count();
+
+            Self::retain_data_object_acceptance(&mut data_object, |bucket_id| {
+                !remove_buckets.contains(bucket_id)
+            });
+
+            let new_replication_count = data_object.replication_count();
+            Self::index_challengeable_data_object(bag_id, data_object_id, &data_object);
+            DataObjectsById::<T>::insert(bag_id, data_object_id, data_object);
+
+            if old_replication_count >= replication_target
+                && new_replication_count < replication_target
+            {
+                Self::deposit_event(RawEvent::DataObjectReplicationBelowTarget(
+                    bag_id.clone(),
+                    data_object_id,
+                    new_replication_count,
+                ));
+            }
+        }
+
+        if data_objects_number < limit {
+            DataObjectsAcceptanceRemovalByBagId::<T>::remove(bag_id);
+        } else {
+            DataObjectsAcceptanceRemovalByBagId::<T>::insert(
+                bag_id,
+                DataObjectsAcceptanceRemoval::<T> {
+                    storage_buckets: remove_buckets,
+                    last_data_object_id,
+                },
+            );
+        }
+
+        data_objects_number
+    }
+
+    // Keeps the data object accepted only by the storage buckets matching the predicate.
+    fn retain_data_object_acceptance(
+        data_object: &mut DataObjectOf<T>,
+        predicate: impl Fn(&T::StorageBucketId) -> bool,
+    ) {
+        let accepted_by = data_object
+            .accepted_by
+            .iter()
+            .filter(|bucket_id| predicate(bucket_id))
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        // Retained set is a subset of the current one: the capacity can't be exceeded.
+        data_object.accepted_by = accepted_by.try_into().unwrap_or_default();
+    }
+
+    /// Returns the storage bucket earnings available for claiming.
+    pub fn storage_bucket_unclaimed_earnings(
+        storage_bucket_id: &T::StorageBucketId,
+    ) -> BalanceOf<T> {
+        Self::storage_bucket_by_id(storage_bucket_id)
+            .map(|bucket| {
+                Self::accrued_storage_bucket_earnings(storage_bucket_id, bucket.voucher.size_used)
+                    .unclaimed
+            })
+            .unwrap_or_default()
+    }
+
+    // Storage bucket earnings with the accrual for the used size since the last checkpoint.
+    fn accrued_storage_bucket_earnings(
+        storage_bucket_id: &T::StorageBucketId,
+        size_used: u64,
+    ) -> StorageBucketEarnings<T> {
+        let earnings = Self::storage_bucket_earnings_by_id(storage_bucket_id);
+        let earnings_per_byte = Self::storage_earnings_per_byte();
+
+        let accrued: BalanceOf<T> = earnings_per_byte
+            .saturating_sub(earnings.earnings_per_byte_checkpoint)
+            .saturating_mul(size_used.into())
+            .checked_div(STORAGE_EARNINGS_PER_BYTE_PRECISION)
+            .unwrap_or_default()
+            .saturated_into();
+
+        StorageBucketEarnings::<T> {
+            unclaimed: earnings.unclaimed.saturating_add(accrued),
+            earnings_per_byte_checkpoint: earnings_per_byte,
+        }
+    }
+
+    // Accrues the storage bucket earnings for the current used size and updates the total used
+    // size. Must be called before the storage bucket voucher update.
+    fn accrue_storage_bucket_earnings(storage_bucket_id: &T::StorageBucketId, new_size_used: u64) {
+        let old_size_used = Self::storage_bucket_by_id(storage_bucket_id)
+            .map(|bucket| bucket.voucher.size_used)
+            .unwrap_or_default();
+
+        StorageBucketEarningsById::<T>::insert(
+            storage_bucket_id,
+            Self::accrued_storage_bucket_earnings(storage_bucket_id, old_size_used),
+        );
+
+        TotalStorageBucketsSizeUsed::mutate(|total_size_used| {
+            *total_size_used = total_size_used
+                .saturating_sub(old_size_used)
+                .saturating_add(new_size_used)
+        });
     }
 }
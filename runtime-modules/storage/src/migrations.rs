@@ -0,0 +1,161 @@
+use codec::Decode;
+use frame_support::storage::{
+    unhashed, IterableStorageDoubleMap, IterableStorageMap, StorageDoubleMap, StorageMap,
+    StorageValue,
+};
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
+use sp_arithmetic::traits::Zero;
+use sp_runtime::SaturatedConversion;
+use sp_std::vec::Vec;
+
+use common::bloat_bond::RepayableBloatBondOf;
+
+use crate::{
+    Bags, Base58Multihash, Config, DataObjectBagByContentId, DataObjectOf, DataObjectsById,
+    DataObjectsMigration, Module, ModuleAccount, PendingDataObjectsMigration, StorageBucketById,
+    StorageBucketEarnings, StorageBucketEarningsById, StorageBucketNumber, StorageTreasury,
+    TotalStorageBucketsSizeUsed,
+};
+
+/// Data object layout preceding the per-bucket acceptance, proof-of-storage challenges
+/// and blacklist enforcement
+#[derive(Decode)]
+struct DataObjectV0<RepayableBloatBond> {
+    accepted: bool,
+    state_bloat_bond: RepayableBloatBond,
+    size: u64,
+    ipfs_content_id: Base58Multihash,
+}
+
+type DataObjectV0Of<T> = DataObjectV0<RepayableBloatBondOf<T>>;
+
+impl<T: Config> Module<T> {
+    /// Start the multi-block migration of the existing data objects to the layout with
+    /// the per-bucket acceptance: the data objects are migrated in bounded batches on block
+    /// initialization and data objects operations are paused until the migration completes.
+    pub fn migrate_data_objects_to_per_bucket_acceptance() -> Weight {
+        PendingDataObjectsMigration::<T>::put(DataObjectsMigration::<T>::default());
+
+        T::DbWeight::get().writes(1)
+    }
+
+    // Migrates at most `MaxDataObjectsMigratedPerBlock` data objects following the last migrated
+    // one, completing the migration once no data objects are left.
+    // Accepted data objects are considered accepted by all the storage buckets of their bag.
+    // Never accepted data objects get an acceptance deadline, so that they can be deleted once
+    // expired: their state bloat bonds have no recorded payer and are repaid to the storage
+    // treasury (unless the repayment is restricted to an account).
+    // Existing data objects have no chunks Merkle root (never challenged) and are not blocked:
+    // they are indexed by their content ID, so that they are blocked on their content
+    // blacklisting (the content blacklisted during the migration is blocked by
+    // `block_blacklisted_data_objects`).
+    pub(crate) fn migrate_data_objects_batch(migration: DataObjectsMigration<T>) -> Weight {
+        let limit = T::MaxDataObjectsMigratedPerBlock::get();
+
+        let mut data_object_keys = DataObjectsById::<T>::iter_keys();
+        if let Some((bag_id, data_object_id)) = migration.last_data_object {
+            data_object_keys
+                .set_last_raw_key(DataObjectsById::<T>::hashed_key_for(bag_id, data_object_id));
+        }
+        let data_object_keys = data_object_keys
+            .take(limit.saturated_into())
+            .collect::<Vec<_>>();
+        let data_objects_number = data_object_keys.len().saturated_into::<u64>();
+
+        if data_objects_number < limit.into() {
+            PendingDataObjectsMigration::<T>::kill();
+        } else {
+            PendingDataObjectsMigration::<T>::put(DataObjectsMigration::<T> {
+                last_data_object: data_object_keys.last().cloned(),
+            });
+        }
+
+        let mut pending_data_objects_number: u64 = 0;
+        for (bag_id, data_object_id) in data_object_keys {
+            // Data objects not matching the previous layout are left as is.
+            let data_object = match unhashed::get::<DataObjectV0Of<T>>(
+                &DataObjectsById::<T>::hashed_key_for(&bag_id, data_object_id),
+            ) {
+                Some(data_object) => data_object,
+                None => continue,
+            };
+
+            let accepted_by = if data_object.accepted {
+                Bags::<T>::get(&bag_id).stored_by
+            } else {
+                pending_data_objects_number = pending_data_objects_number.saturating_add(1);
+                Self::set_data_object_acceptance_deadline(
+                    data_object_id,
+                    &StorageTreasury::<T>::module_account_id(),
+                );
+                Default::default()
+            };
+
+            DataObjectBagByContentId::<T>::insert(
+                &data_object.ipfs_content_id,
+                data_object_id,
+                &bag_id,
+            );
+            DataObjectsById::<T>::insert(
+                &bag_id,
+                data_object_id,
+                DataObjectOf::<T> {
+                    accepted_by,
+                    state_bloat_bond: data_object.state_bloat_bond,
+                    size: data_object.size,
+                    ipfs_content_id: data_object.ipfs_content_id,
+                    chunks_merkle_root: None,
+                    blocked: false,
+                },
+            );
+        }
+
+        // Data object and its bag are read, data object and its content ID index are written,
+        // as well as the acceptance deadline of a never accepted data object.
+        T::DbWeight::get().reads_writes(
+            data_objects_number.saturating_mul(2).saturating_add(1),
+            data_objects_number
+                .saturating_mul(2)
+                .saturating_add(pending_data_objects_number)
+                .saturating_add(1),
+        )
+    }
+
+    /// Initialize the storage buckets earnings accrual for the existing storage buckets:
+    /// the total used size and the earnings checkpoints (nothing is accrued before the upgrade).
+    pub fn migrate_storage_buckets_earnings() -> Weight {
+        let earnings_per_byte = Self::storage_earnings_per_byte();
+        let mut buckets_number: u64 = 0;
+        let mut total_size_used: u64 = 0;
+
+        for (storage_bucket_id, bucket) in StorageBucketById::<T>::iter() {
+            buckets_number = buckets_number.saturating_add(1);
+            total_size_used = total_size_used.saturating_add(bucket.voucher.size_used);
+
+            StorageBucketEarningsById::<T>::insert(
+                storage_bucket_id,
+                StorageBucketEarnings::<T> {
+                    unclaimed: Zero::zero(),
+                    earnings_per_byte_checkpoint: earnings_per_byte,
+                },
+            );
+        }
+
+        TotalStorageBucketsSizeUsed::put(total_size_used);
+
+        T::DbWeight::get().reads_writes(
+            buckets_number.saturating_add(1),
+            buckets_number.saturating_add(1),
+        )
+    }
+
+    /// Initialize the storage bucket number with the number of the existing storage buckets.
+    pub fn migrate_storage_bucket_number() -> Weight {
+        let buckets_number = StorageBucketById::<T>::iter_keys().count() as u64;
+
+        StorageBucketNumber::put(buckets_number);
+
+        T::DbWeight::get().reads_writes(buckets_number, 1)
+    }
+}
@@ -0,0 +1,66 @@
+//! Runtime API definition for the storage module.
+
+use crate::{BagIdType, BagInfo, DataObject, DataObjectCreationParameters, UploadFeeQuote};
+use codec::Codec;
+use common::bloat_bond::RepayableBloatBond;
+use sp_runtime::DispatchError;
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Bags, buckets and upload fee queries for the node RPC and the upload tooling.
+    pub trait StorageApi<
+        AccountId,
+        Balance,
+        MemberId,
+        ChannelId,
+        DataObjectId,
+        StorageBucketId,
+        DistributionBucketFamilyId,
+        DistributionBucketIndex,
+        WorkerId,
+    > where
+        AccountId: Codec,
+        Balance: Codec,
+        MemberId: Codec,
+        ChannelId: Codec,
+        DataObjectId: Codec,
+        StorageBucketId: Codec + Ord,
+        DistributionBucketFamilyId: Codec + Ord,
+        DistributionBucketIndex: Codec + Ord,
+        WorkerId: Codec + Ord,
+    {
+        /// Storage and distribution buckets of the bag, including the operators metadata.
+        fn bag_info(
+            bag_id: BagIdType<MemberId, ChannelId>,
+        ) -> Result<
+            BagInfo<
+                StorageBucketId,
+                WorkerId,
+                AccountId,
+                DistributionBucketFamilyId,
+                DistributionBucketIndex,
+            >,
+            DispatchError,
+        >;
+
+        /// At most `limit` data objects of the bag following the `start_after` data object,
+        /// in the storage order.
+        fn bag_data_objects(
+            bag_id: BagIdType<MemberId, ChannelId>,
+            start_after: Option<DataObjectId>,
+            limit: u32,
+        ) -> Result<
+            Vec<(
+                DataObjectId,
+                DataObject<RepayableBloatBond<AccountId, Balance>, BTreeSet<StorageBucketId>>,
+            )>,
+            DispatchError,
+        >;
+
+        /// Data size fee and state bloat bonds required to upload the data objects.
+        fn upload_fee_quote(
+            objects: Vec<DataObjectCreationParameters>,
+        ) -> Result<UploadFeeQuote<Balance>, DispatchError>;
+    }
+}
@@ -6,6 +6,7 @@ use frame_support::storage::StorageMap;
 use frame_support::traits::{Currency, OnFinalize, OnInitialize};
 use frame_support::{assert_noop, assert_ok};
 use frame_system::{EventRecord, Phase, RawOrigin};
+use sp_runtime::Perbill;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::iter::FromIterator;
@@ -18,15 +19,18 @@ use super::mocks::{
     create_cid, Balances, CollectiveFlip, Event as TestEvent, Storage, System, Test,
     DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID, DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID,
     DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT, DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
-    DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID, DISTRIBUTION_WG_LEADER_ACCOUNT_ID,
-    STORAGE_WG_LEADER_ACCOUNT_ID, VOUCHER_OBJECTS_LIMIT, VOUCHER_SIZE_LIMIT,
+    DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID, DEFAULT_STORAGE_PROVIDER_ID,
+    DISTRIBUTION_WG_LEADER_ACCOUNT_ID, STORAGE_OPERATOR_FEE_SHARE, STORAGE_WG_LEADER_ACCOUNT_ID,
+    VOUCHER_OBJECTS_LIMIT, VOUCHER_SIZE_LIMIT,
 };
 
 use crate::{
-    BagId, DataObjectCreationParameters, DataObjectPerMegabyteFee, DataObjectStateBloatBondValue,
-    DataObjectStorage, DistributionBucket, DistributionBucketId, DynBagCreationParameters,
-    DynamicBagId, DynamicBagType, RawEvent, StorageBucketOperatorStatus, UploadParameters,
+    BagId, ChunkProofElement, ChunksMerkleRoot, DataObjectCreationParameters,
+    DataObjectPerMegabyteFee, DataObjectStateBloatBondValue, DataObjectStorage, DistributionBucket,
+    DistributionBucketId, DynBagCreationParameters, DynamicBagId, DynamicBagType, RawEvent,
+    StorageBucketOperatorStatus, StorageChallenge, StorageChallengeId, UploadParameters,
 };
+use common::merkle_tree::helpers::generate_merkle_root_helper;
 
 // Recommendation from Parity on testing on_finalize
 // https://substrate.dev/docs/en/next/development/module/tests
@@ -50,6 +54,10 @@ pub fn set_data_object_per_mega_byte_fee(mb_fee: u64) {
     DataObjectPerMegabyteFee::<Test>::put(mb_fee);
 }
 
+pub fn set_storage_operator_fee_share(share: Perbill) {
+    STORAGE_OPERATOR_FEE_SHARE.with(|fee_share| *fee_share.borrow_mut() = share);
+}
+
 pub fn set_data_object_state_bloat_bond_value(state_bloat_bond: u64) {
     DataObjectStateBloatBondValue::<Test>::put(state_bloat_bond);
 }
@@ -118,6 +126,27 @@ pub fn create_storage_bucket_and_assign_to_bag(
     bucket_id
 }
 
+pub fn create_operated_storage_bucket(objects_limit: u64, size_limit: u64) -> u64 {
+    set_max_voucher_limits();
+
+    let bucket_id = CreateStorageBucketFixture::new()
+        .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+        .with_invite_worker(Some(DEFAULT_STORAGE_PROVIDER_ID))
+        .with_objects_limit(objects_limit)
+        .with_size_limit(size_limit)
+        .call_and_assert(Ok(()))
+        .unwrap();
+
+    AcceptStorageBucketInvitationFixture::new()
+        .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+        .with_transactor_account_id(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID)
+        .with_storage_bucket_id(bucket_id)
+        .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+        .call_and_assert(Ok(()));
+
+    bucket_id
+}
+
 pub fn set_update_storage_buckets_per_bag_limit(new_limit: u32) {
     UpdateStorageBucketsPerBagLimitFixture::new()
         .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
@@ -151,6 +180,7 @@ impl EventFixture {
             DistributionBucketId<Test>,
             u64,
             DynBagCreationParameters<Test>,
+            StorageChallenge<Test>,
         >,
     ) {
         let converted_event = TestEvent::Storage(expected_raw_event);
@@ -172,6 +202,7 @@ impl EventFixture {
             DistributionBucketId<Test>,
             u64,
             DynBagCreationParameters<Test>,
+            StorageChallenge<Test>,
         >,
     ) {
         let converted_event = TestEvent::Storage(expected_raw_event);
@@ -330,6 +361,44 @@ impl UpdateStorageBucketForBagsFixture {
     }
 }
 
+#[derive(Fixture, new)]
+pub struct RebalanceBagsFixture {
+    #[new(value = "RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    storage_bucket_id: u64,
+
+    #[new(default)]
+    bag_ids: BTreeSet<BagId<Test>>,
+}
+
+impl RebalanceBagsFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let bucket_pre = Storage::storage_bucket_by_id(self.storage_bucket_id);
+
+        let actual_result = Storage::rebalance_bags(
+            self.origin.clone().into(),
+            self.storage_bucket_id,
+            self.bag_ids.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            for bag_id in self.bag_ids.iter() {
+                let bag = Storage::bag(bag_id);
+                assert!(!bag.stored_by.contains(&self.storage_bucket_id));
+            }
+        } else {
+            assert_eq!(
+                Storage::storage_bucket_by_id(self.storage_bucket_id),
+                bucket_pre
+            );
+        }
+    }
+}
+
 #[derive(Fixture, Default)]
 pub struct UploadFixture {
     params: UploadParameters<Test>,
@@ -478,6 +547,7 @@ pub fn create_data_object_candidates_with_size(
             DataObjectCreationParameters {
                 size,
                 ipfs_content_id,
+                chunks_merkle_root: None,
             }
         })
         .collect()
@@ -494,6 +564,7 @@ pub fn create_data_object_candidates(
         .map(|idx| DataObjectCreationParameters {
             size: DEFAULT_DATA_OBJECTS_SIZE,
             ipfs_content_id: create_cid(idx.into()),
+            chunks_merkle_root: None,
         })
         .collect()
 }
@@ -502,6 +573,14 @@ pub fn create_single_data_object() -> Vec<DataObjectCreationParameters> {
     create_data_object_candidates(1, 1)
 }
 
+pub fn create_chunks_merkle_root(chunks: &[Vec<u8>]) -> ChunksMerkleRoot {
+    generate_merkle_root_helper::<Test, _>(chunks)
+        .last()
+        .copied()
+        .unwrap()
+        .into()
+}
+
 #[derive(Fixture, new)]
 pub struct SetStorageOperatorMetadataFixture {
     #[new(value = "RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID)")]
@@ -1045,6 +1124,34 @@ impl UpdateStorageBucketsPerBagLimitFixture {
     }
 }
 
+#[derive(Fixture, new)]
+pub struct UpdateDataObjectReplicationTargetFixture {
+    #[new(value = "RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    new_target: u32,
+}
+
+impl UpdateDataObjectReplicationTargetFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_target = Storage::data_object_replication_target();
+
+        let actual_result = Storage::update_data_object_replication_target(
+            self.origin.clone().into(),
+            self.new_target,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(Storage::data_object_replication_target(), self.new_target);
+        } else {
+            assert_eq!(old_target, Storage::data_object_replication_target());
+        }
+    }
+}
+
 #[derive(Fixture, new)]
 pub struct SetStorageBucketVoucherLimitsFixture {
     #[new(value = "RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID)")]
@@ -1848,3 +1955,215 @@ impl UpdateDataObjectStateBloatBondValueFixture {
         }
     }
 }
+
+#[derive(Fixture, new)]
+pub struct RespondToStorageChallengeFixture {
+    #[new(value = "RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(value = "DEFAULT_STORAGE_PROVIDER_ID")]
+    worker_id: u64,
+
+    #[new(default)]
+    challenge_id: StorageChallengeId,
+
+    #[new(default)]
+    chunk: Vec<u8>,
+
+    #[new(default)]
+    proof: Vec<ChunkProofElement<Test>>,
+}
+
+impl RespondToStorageChallengeFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_challenge = Storage::storage_challenge_by_id(self.challenge_id);
+
+        let actual_result = Storage::respond_to_storage_challenge(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.challenge_id,
+            self.chunk.clone(),
+            self.proof.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_challenge = Storage::storage_challenge_by_id(self.challenge_id);
+        if actual_result.is_ok() {
+            assert!(new_challenge.is_none());
+        } else {
+            assert_eq!(old_challenge, new_challenge);
+        }
+    }
+}
+
+#[derive(Fixture, new)]
+pub struct ClaimStorageBucketEarningsFixture {
+    #[new(value = "RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(value = "DEFAULT_STORAGE_PROVIDER_ID")]
+    worker_id: u64,
+
+    #[new(default)]
+    storage_bucket_id: u64,
+}
+
+impl ClaimStorageBucketEarningsFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let account_id = match self.origin {
+            RawOrigin::Signed(account_id) => account_id,
+            _ => Default::default(),
+        };
+        let old_balance = Balances::usable_balance(account_id);
+        let earnings = Storage::storage_bucket_unclaimed_earnings(&self.storage_bucket_id);
+        let earnings_fund = Storage::storage_operators_earnings_fund();
+        let claimed = earnings.min(earnings_fund);
+
+        let actual_result = Storage::claim_storage_bucket_earnings(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.storage_bucket_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_balance = Balances::usable_balance(account_id);
+        if actual_result.is_ok() {
+            assert_eq!(new_balance, old_balance + claimed);
+            assert_eq!(
+                Storage::storage_bucket_unclaimed_earnings(&self.storage_bucket_id),
+                earnings - claimed
+            );
+            assert_eq!(
+                Storage::storage_operators_earnings_fund(),
+                earnings_fund - claimed
+            );
+        } else {
+            assert_eq!(new_balance, old_balance);
+            assert_eq!(
+                Storage::storage_bucket_unclaimed_earnings(&self.storage_bucket_id),
+                earnings
+            );
+            assert_eq!(Storage::storage_operators_earnings_fund(), earnings_fund);
+        }
+    }
+}
+
+#[derive(Fixture, new)]
+pub struct DeleteExpiredDataObjectsFixture {
+    #[new(value = "RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    bag_id: BagId<Test>,
+
+    #[new(default)]
+    data_objects: BTreeSet<u64>,
+}
+
+impl DeleteExpiredDataObjectsFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bag = Storage::bag(&self.bag_id);
+
+        let actual_result = Storage::delete_expired_data_objects(
+            self.origin.clone().into(),
+            self.bag_id.clone(),
+            self.data_objects.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bag = Storage::bag(&self.bag_id);
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_bag.objects_number,
+                old_bag.objects_number - self.data_objects.len() as u64
+            );
+            for data_object_id in self.data_objects.iter() {
+                assert!(!<crate::DataObjectsById<Test>>::contains_key(
+                    &self.bag_id,
+                    data_object_id
+                ));
+                assert!(<crate::ExpiredDataObjects<Test>>::contains_key(
+                    &self.bag_id,
+                    data_object_id
+                ));
+                assert!(Storage::data_object_acceptance_deadline_by_id(data_object_id).is_none());
+            }
+        } else {
+            assert_eq!(new_bag, old_bag);
+        }
+    }
+}
+
+#[derive(Fixture, new)]
+pub struct BlockBlacklistedDataObjectsFixture {
+    #[new(value = "RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    content_id: Vec<u8>,
+}
+
+impl BlockBlacklistedDataObjectsFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = Storage::block_blacklisted_data_objects(
+            self.origin.clone().into(),
+            self.content_id.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+    }
+}
+
+#[derive(Fixture, new)]
+pub struct RemoveBlockedDataObjectsFixture {
+    #[new(value = "RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    bag_id: BagId<Test>,
+
+    #[new(default)]
+    data_objects: BTreeSet<u64>,
+}
+
+impl RemoveBlockedDataObjectsFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bag = Storage::bag(&self.bag_id);
+
+        let actual_result = Storage::remove_blocked_data_objects(
+            self.origin.clone().into(),
+            self.bag_id.clone(),
+            self.data_objects.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bag = Storage::bag(&self.bag_id);
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_bag.objects_number,
+                old_bag.objects_number - self.data_objects.len() as u64
+            );
+            for data_object_id in self.data_objects.iter() {
+                assert!(!<crate::DataObjectsById<Test>>::contains_key(
+                    &self.bag_id,
+                    data_object_id
+                ));
+                assert!(<crate::ExpiredDataObjects<Test>>::contains_key(
+                    &self.bag_id,
+                    data_object_id
+                ));
+                assert!(
+                    !<crate::DataObjectStateBloatBondAccountById<Test>>::contains_key(
+                        data_object_id
+                    )
+                );
+            }
+        } else {
+            assert_eq!(new_bag, old_bag);
+        }
+    }
+}
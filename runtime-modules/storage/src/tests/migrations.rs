@@ -0,0 +1,231 @@
+use codec::Encode;
+use frame_support::storage::{unhashed, StorageDoubleMap, StorageMap, StorageValue};
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::convert::TryInto;
+use sp_std::iter::FromIterator;
+
+use frame_system::RawOrigin;
+
+use super::fixtures::{
+    create_storage_bucket_and_assign_to_bag, run_to_block, CreateStorageBucketFixture,
+    DeleteExpiredDataObjectsFixture, DeleteStorageBucketFixture,
+};
+use super::mocks::{
+    build_test_externalities, create_cid, DataObjectAcceptancePeriod,
+    MaxDataObjectsMigratedPerBlock, Storage, Test, DEFAULT_MEMBER_ACCOUNT_ID,
+    STORAGE_WG_LEADER_ACCOUNT_ID,
+};
+use crate::{
+    BagId, Bags, Base58Multihash, DataObject, DataObjectAcceptanceDeadline,
+    DataObjectBagByContentId, DataObjectOf, DataObjectStorage, DataObjectsById, Error,
+    ModuleAccount, RepayableBloatBond, StaticBagId, StorageBucketById, StorageBucketEarnings,
+    StorageBucketEarningsById, StorageBucketNumber, StorageEarningsPerByte, StorageTreasury,
+    TotalStorageBucketsSizeUsed,
+};
+use frame_support::assert_noop;
+use frame_support::traits::Get;
+
+// Puts the data object encoded with the `accepted: bool` layout.
+fn put_data_object_v0(bag_id: &BagId<Test>, data_object_id: u64, accepted: bool) {
+    let ipfs_content_id: Base58Multihash = create_cid(1).try_into().unwrap();
+
+    unhashed::put_raw(
+        &DataObjectsById::<Test>::hashed_key_for(bag_id, data_object_id),
+        &(
+            accepted,
+            RepayableBloatBond::new(10u64, None),
+            10u64,
+            &ipfs_content_id,
+        )
+            .encode(),
+    );
+}
+
+#[test]
+fn migrate_data_objects_to_per_bucket_acceptance_ok() {
+    build_test_externalities().execute_with(|| {
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        let bucket_id1 = create_storage_bucket_and_assign_to_bag(bag_id.clone(), None, 10, 100);
+        let bucket_id2 = create_storage_bucket_and_assign_to_bag(bag_id.clone(), None, 10, 100);
+
+        let ipfs_content_id: Base58Multihash = create_cid(1).try_into().unwrap();
+
+        for (data_object_id, accepted) in [(0u64, true), (1u64, false)] {
+            put_data_object_v0(&bag_id, data_object_id, accepted);
+            assert!(DataObjectsById::<Test>::try_get(&bag_id, data_object_id).is_err());
+        }
+
+        Storage::migrate_data_objects_to_per_bucket_acceptance();
+        run_to_block(1);
+
+        assert!(Storage::data_objects_migration().is_none());
+        let data_object: DataObjectOf<Test> = DataObject {
+            accepted_by: Default::default(),
+            state_bloat_bond: RepayableBloatBond::new(10u64, None),
+            size: 10,
+            ipfs_content_id: ipfs_content_id.clone(),
+            chunks_merkle_root: None,
+            blocked: false,
+        };
+        assert_eq!(
+            DataObjectsById::<Test>::try_get(&bag_id, 0u64),
+            Ok(DataObject {
+                accepted_by: BTreeSet::from_iter(vec![bucket_id1, bucket_id2])
+                    .try_into()
+                    .unwrap(),
+                ..data_object.clone()
+            })
+        );
+        assert_eq!(
+            DataObjectsById::<Test>::try_get(&bag_id, 1u64),
+            Ok(data_object)
+        );
+        // data objects uploaded before the content ID index are indexed
+        for data_object_id in [0u64, 1u64] {
+            assert_eq!(
+                DataObjectBagByContentId::<Test>::try_get(&ipfs_content_id, data_object_id),
+                Ok(bag_id.clone())
+            );
+        }
+        // never accepted data objects can expire
+        assert!(Storage::data_object_acceptance_deadline_by_id(0u64).is_none());
+        assert_eq!(
+            Storage::data_object_acceptance_deadline_by_id(1u64),
+            Some(DataObjectAcceptanceDeadline::<Test> {
+                deadline: 1 + DataObjectAcceptancePeriod::get(),
+                state_bloat_bond_account_id: StorageTreasury::<Test>::module_account_id(),
+            })
+        );
+    });
+}
+
+#[test]
+fn migrated_never_accepted_data_object_can_expire() {
+    build_test_externalities().execute_with(|| {
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        let bucket_id = create_storage_bucket_and_assign_to_bag(bag_id.clone(), None, 10, 100);
+
+        // data object uploaded before the per-bucket acceptance
+        put_data_object_v0(&bag_id, 0u64, false);
+        Bags::<Test>::mutate(&bag_id, |bag| {
+            bag.objects_number = 1;
+            bag.objects_total_size = 10;
+        });
+        StorageBucketById::<Test>::mutate(bucket_id, |bucket| {
+            let voucher = &mut bucket.as_mut().unwrap().voucher;
+            voucher.objects_used = 1;
+            voucher.size_used = 10;
+        });
+
+        Storage::migrate_data_objects_to_per_bucket_acceptance();
+        run_to_block(1);
+        run_to_block(2 + DataObjectAcceptancePeriod::get());
+
+        DeleteExpiredDataObjectsFixture::new()
+            .with_bag_id(bag_id)
+            .with_data_objects(BTreeSet::from_iter(vec![0u64]))
+            .call_and_assert(Ok(()));
+
+        let bucket = Storage::storage_bucket_by_id(&bucket_id).unwrap();
+        assert_eq!(bucket.voucher.objects_used, 0);
+        assert_eq!(bucket.voucher.size_used, 0);
+    });
+}
+
+#[test]
+fn migrate_data_objects_to_per_bucket_acceptance_in_batches() {
+    build_test_externalities().execute_with(|| {
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        let max_data_objects = MaxDataObjectsMigratedPerBlock::get() as u64;
+        let data_objects_number = max_data_objects + 5;
+        for data_object_id in 0..data_objects_number {
+            put_data_object_v0(&bag_id, data_object_id, false);
+        }
+        let migrated_data_objects_number = || {
+            (0..data_objects_number)
+                .filter(|id| DataObjectsById::<Test>::try_get(&bag_id, id).is_ok())
+                .count() as u64
+        };
+
+        Storage::migrate_data_objects_to_per_bucket_acceptance();
+        run_to_block(1);
+
+        assert_eq!(migrated_data_objects_number(), max_data_objects);
+        assert!(Storage::data_objects_migration().is_some());
+        // data objects operations are paused until the migration completes
+        assert_noop!(
+            <Storage as DataObjectStorage<Test>>::delete_data_objects(
+                DEFAULT_MEMBER_ACCOUNT_ID,
+                bag_id.clone(),
+                BTreeSet::from_iter(vec![0u64]),
+            ),
+            Error::<Test>::DataObjectsMigrationInProgress
+        );
+
+        run_to_block(2);
+
+        assert_eq!(migrated_data_objects_number(), data_objects_number);
+        assert!(Storage::data_objects_migration().is_none());
+    });
+}
+
+#[test]
+fn migrate_storage_buckets_earnings_ok() {
+    build_test_externalities().execute_with(|| {
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        let bucket_id1 = create_storage_bucket_and_assign_to_bag(bag_id.clone(), None, 10, 100);
+        let bucket_id2 = create_storage_bucket_and_assign_to_bag(bag_id, None, 10, 100);
+
+        // storage buckets used before the earnings accrual
+        for (bucket_id, size_used) in [(bucket_id1, 30u64), (bucket_id2, 50u64)] {
+            StorageBucketById::<Test>::mutate(bucket_id, |bucket| {
+                bucket.as_mut().unwrap().voucher.size_used = size_used
+            });
+            StorageBucketEarningsById::<Test>::remove(bucket_id);
+        }
+        TotalStorageBucketsSizeUsed::kill();
+        StorageEarningsPerByte::put(7);
+
+        Storage::migrate_storage_buckets_earnings();
+
+        assert_eq!(Storage::total_storage_buckets_size_used(), 80);
+        for bucket_id in [bucket_id1, bucket_id2] {
+            assert_eq!(
+                StorageBucketEarningsById::<Test>::try_get(bucket_id),
+                Ok(StorageBucketEarnings::<Test> {
+                    unclaimed: 0,
+                    earnings_per_byte_checkpoint: 7,
+                })
+            );
+        }
+    });
+}
+
+#[test]
+fn migrate_storage_bucket_number_ok() {
+    build_test_externalities().execute_with(|| {
+        let bucket_ids: Vec<u64> = (0..2)
+            .map(|_| {
+                CreateStorageBucketFixture::new()
+                    .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+                    .call_and_assert(Ok(()))
+                    .unwrap()
+            })
+            .collect();
+
+        // storage buckets created before the storage bucket number was tracked
+        StorageBucketNumber::kill();
+
+        Storage::migrate_storage_bucket_number();
+
+        assert_eq!(Storage::storage_bucket_number(), 2);
+
+        for bucket_id in bucket_ids {
+            DeleteStorageBucketFixture::new()
+                .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+                .with_storage_bucket_id(bucket_id)
+                .call_and_assert(Ok(()));
+        }
+        assert_eq!(Storage::storage_bucket_number(), 0);
+    });
+}
@@ -7,7 +7,7 @@ use common::locks::{
 pub use frame_support::traits::LockIdentifier;
 use frame_support::{
     ensure, parameter_types,
-    traits::{ConstU16, ConstU32, ConstU64},
+    traits::{ConstU16, ConstU32, ConstU64, Get},
     PalletId,
 };
 use frame_system::ensure_signed;
@@ -19,6 +19,7 @@ use sp_runtime::{
 };
 use sp_std::{
     cell::RefCell,
+    collections::btree_set::BTreeSet,
     convert::{TryFrom, TryInto},
 };
 use staking_handler::LockComparator;
@@ -122,6 +123,17 @@ parameter_types! {
     pub const DefaultChannelDynamicBagNumberOfStorageBuckets: u32 = 4;
     pub const MaxDataObjectSize: u64 = u64::MAX - 1000;
     pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 5;
+    pub const MaxStorageChallengesPerBlock: u32 = 3;
+    pub const StorageChallengeResponsePeriod: u64 = 5;
+    pub const MaxStorageChallengeFailures: u32 = 2;
+    pub const StorageChallengeSlashPenalty: u64 = 30;
+    pub const DataObjectChunkSize: u64 = 4;
+    pub const DataObjectAcceptancePeriod: u64 = 10;
+    pub const MaxStorageBucketNumber: u64 = 80;
+    pub const MaxRebalancedBagsNumber: u32 = 10;
+    pub const MaxBlacklistedDataObjectsPerCall: u32 = 10;
+    pub const MaxDataObjectsAcceptanceRemovalsPerBlock: u32 = 10;
+    pub const MaxDataObjectsMigratedPerBlock: u32 = 10;
 }
 
 pub const STORAGE_WG_LEADER_ACCOUNT_ID: u64 = 100001;
@@ -176,6 +188,20 @@ impl crate::Config for Test {
     type StorageWorkingGroup = StorageWG;
     type DistributionWorkingGroup = DistributionWG;
     type ModuleAccountInitialBalance = ExistentialDeposit;
+    type Randomness = CollectiveFlip;
+    type MaxStorageChallengesPerBlock = MaxStorageChallengesPerBlock;
+    type StorageChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type MaxStorageChallengeFailures = MaxStorageChallengeFailures;
+    type StorageChallengeSlashPenalty = StorageChallengeSlashPenalty;
+    type DataObjectChunkSize = DataObjectChunkSize;
+    type StorageOperatorFeeShare = StorageOperatorFeeShare;
+    type DataObjectAcceptancePeriod = DataObjectAcceptancePeriod;
+    type ExpiredDataObjectsHandler = ExpiredDataObjectsRecorder;
+    type MaxStorageBucketNumber = MaxStorageBucketNumber;
+    type MaxRebalancedBagsNumber = MaxRebalancedBagsNumber;
+    type MaxBlacklistedDataObjectsPerCall = MaxBlacklistedDataObjectsPerCall;
+    type MaxDataObjectsAcceptanceRemovalsPerBlock = MaxDataObjectsAcceptanceRemovalsPerBlock;
+    type MaxDataObjectsMigratedPerBlock = MaxDataObjectsMigratedPerBlock;
 }
 
 pub const DEFAULT_MEMBER_ID: u64 = 100;
@@ -246,6 +272,28 @@ pub const WORKING_GROUP_BUDGET: u64 = 100;
 
 thread_local! {
     pub static WG_BUDGET: RefCell<u64> = RefCell::new(WORKING_GROUP_BUDGET);
+    pub static STORAGE_WG_SLASHES: RefCell<Vec<(u64, u64)>> = RefCell::new(Vec::new());
+    pub static STORAGE_OPERATOR_FEE_SHARE: RefCell<Perbill> = RefCell::new(Perbill::zero());
+    pub static EXPIRED_DATA_OBJECTS: RefCell<Vec<(crate::BagId<Test>, BTreeSet<u64>)>> =
+        RefCell::new(Vec::new());
+}
+
+pub struct StorageOperatorFeeShare;
+impl Get<Perbill> for StorageOperatorFeeShare {
+    fn get() -> Perbill {
+        STORAGE_OPERATOR_FEE_SHARE.with(|share| *share.borrow())
+    }
+}
+
+pub struct ExpiredDataObjectsRecorder;
+impl crate::ExpiredDataObjectsHandler<Test> for ExpiredDataObjectsRecorder {
+    fn on_data_objects_expired(bag_id: &crate::BagId<Test>, data_objects: &BTreeSet<u64>) {
+        EXPIRED_DATA_OBJECTS.with(|expired| {
+            expired
+                .borrow_mut()
+                .push((bag_id.clone(), data_objects.clone()))
+        });
+    }
 }
 
 pub struct MembershipWG;
@@ -517,6 +565,14 @@ impl common::working_group::WorkingGroupBudgetHandler<u64, u64> for StorageWG {
     }
 }
 
+impl common::working_group::WorkingGroupStakeSlasher<Test, u64> for StorageWG {
+    fn slash_worker_stake(worker_id: &u64, penalty: u64) -> DispatchResult {
+        STORAGE_WG_SLASHES.with(|slashes| slashes.borrow_mut().push((*worker_id, penalty)));
+
+        Ok(())
+    }
+}
+
 impl common::working_group::WorkingGroupBudgetHandler<u64, u64> for DistributionWG {
     fn get_budget() -> u64 {
         unimplemented!()
@@ -1,12 +1,13 @@
 #![cfg(test)]
 
 mod fixtures;
+mod migrations;
 pub(crate) mod mocks;
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::{assert_err, assert_ok, StorageDoubleMap, StorageMap, StorageValue};
 use frame_system::RawOrigin;
-use sp_runtime::SaturatedConversion;
+use sp_runtime::{Perbill, SaturatedConversion};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::convert::TryInto;
@@ -14,23 +15,34 @@ use sp_std::iter::{repeat, FromIterator};
 
 use common::working_group::WorkingGroup;
 
+use common::merkle_tree::{helpers::build_merkle_path_helper, Side};
+
 use crate::{
-    BagId, Base58Multihash, Config, DataObject, DataObjectCreationParameters, DataObjectStorage,
+    BagId, Base58Multihash, ChallengeableDataObjectBagById, ChunkProofElement, Config, DataObject,
+    DataObjectAcceptanceDeadline, DataObjectCreationParameters,
+    DataObjectStateBloatBondAccountById, DataObjectStorage, DataObjectsAcceptanceRemovalByBagId,
     DistributionBucketFamily, DistributionBucketId, DynamicBagId, DynamicBagType, Error,
-    ModuleAccount, RawEvent, RepayableBloatBond, StaticBagId, StorageBucketOperatorStatus,
-    StorageTreasury, UploadParameters, Voucher,
+    ExpiredDataObjects, ModuleAccount, OperatorMetadataMaxLen, RawEvent, RepayableBloatBond,
+    StaticBagId, StorageBucketChallengeFailures, StorageBucketOperatorStatus, StorageChallengeById,
+    StorageChallengeId, StorageOperatorMetadataByBucketId, StorageTreasury, UploadFeeQuote,
+    UploadParameters, Voucher,
 };
 
 use mocks::{
-    build_test_externalities, create_cid, Balances, BlacklistSizeLimit,
-    DefaultChannelDynamicBagNumberOfStorageBuckets, DefaultMemberDynamicBagNumberOfStorageBuckets,
-    ExistentialDeposit, MaxDataObjectSize, MaxDistributionBucketFamilyNumber, Storage, Test,
-    ANOTHER_DISTRIBUTION_PROVIDER_ID, ANOTHER_STORAGE_PROVIDER_ID,
-    DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID, DEFAULT_DISTRIBUTION_PROVIDER_ID,
-    DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID, DEFAULT_STORAGE_BUCKETS_NUMBER,
-    DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT, DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
-    DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID, DEFAULT_STORAGE_PROVIDER_ID, DISTRIBUTION_PROVIDER_IDS,
-    DISTRIBUTION_WG_LEADER_ACCOUNT_ID, INITIAL_BALANCE, ONE_MB, STORAGE_WG_LEADER_ACCOUNT_ID,
+    build_test_externalities, create_cid, Balances, BlacklistSizeLimit, DataObjectAcceptancePeriod,
+    DataObjectChunkSize, DefaultChannelDynamicBagNumberOfStorageBuckets,
+    DefaultMemberDynamicBagNumberOfStorageBuckets, ExistentialDeposit,
+    MaxBlacklistedDataObjectsPerCall, MaxDataObjectSize, MaxDataObjectsAcceptanceRemovalsPerBlock,
+    MaxDistributionBucketFamilyNumber, MaxRebalancedBagsNumber, MaxStorageBucketNumber,
+    MaxStorageChallengesPerBlock, Storage, StorageChallengeResponsePeriod,
+    StorageChallengeSlashPenalty, Test, ANOTHER_DISTRIBUTION_PROVIDER_ID,
+    ANOTHER_STORAGE_PROVIDER_ID, DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID,
+    DEFAULT_DISTRIBUTION_PROVIDER_ID, DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID,
+    DEFAULT_STORAGE_BUCKETS_NUMBER, DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+    DEFAULT_STORAGE_BUCKET_SIZE_LIMIT, DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID,
+    DEFAULT_STORAGE_PROVIDER_ID, DISTRIBUTION_PROVIDER_IDS, DISTRIBUTION_WG_LEADER_ACCOUNT_ID,
+    EXPIRED_DATA_OBJECTS, INITIAL_BALANCE, ONE_MB, STORAGE_WG_LEADER_ACCOUNT_ID,
+    STORAGE_WG_SLASHES, VOUCHER_OBJECTS_LIMIT, VOUCHER_SIZE_LIMIT,
 };
 
 use fixtures::*;
@@ -154,6 +166,42 @@ fn create_storage_bucket_fails_with_invalid_storage_provider_id() {
     });
 }
 
+#[test]
+fn create_storage_bucket_fails_with_exceeding_storage_bucket_number_limit() {
+    build_test_externalities().execute_with(|| {
+        for _ in 0..MaxStorageBucketNumber::get() {
+            CreateStorageBucketFixture::new()
+                .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+                .call_and_assert(Ok(()));
+        }
+
+        assert_eq!(
+            Storage::storage_bucket_number(),
+            MaxStorageBucketNumber::get()
+        );
+
+        CreateStorageBucketFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(
+                Error::<Test>::MaxStorageBucketNumberLimitExceeded.into()
+            ));
+
+        DeleteStorageBucketFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(0)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Storage::storage_bucket_number(),
+            MaxStorageBucketNumber::get() - 1
+        );
+
+        CreateStorageBucketFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()));
+    });
+}
+
 #[test]
 fn accept_storage_bucket_invitation_succeeded() {
     build_test_externalities().execute_with(|| {
@@ -719,6 +767,185 @@ fn update_storage_buckets_succeeds_with_add_remove_within_limits() {
     });
 }
 
+#[test]
+fn rebalance_bags_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_default_update_storage_buckets_per_bag_limit();
+
+        let council_bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let working_group_bag_id =
+            BagId::<Test>::Static(StaticBagId::WorkingGroup(WorkingGroup::Storage));
+
+        let source_bucket_id = create_storage_bucket_and_assign_to_bag(
+            council_bag_id.clone(),
+            None,
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
+        let source_buckets = BTreeSet::from_iter(vec![source_bucket_id]);
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(working_group_bag_id.clone())
+            .with_add_bucket_ids(source_buckets.clone())
+            .call_and_assert(Ok(()));
+
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        let object_creation_list = create_single_data_object();
+        UploadFixture::default()
+            .with_params(UploadParameters::<Test> {
+                bag_id: council_bag_id.clone(),
+                state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+                object_creation_list: object_creation_list.clone(),
+                expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+                expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(
+                ),
+            })
+            .call_and_assert(Ok(()));
+
+        let first_bucket_id = create_operated_storage_bucket(
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
+        let second_bucket_id = create_operated_storage_bucket(
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
+
+        RebalanceBagsFixture::new()
+            .with_storage_bucket_id(source_bucket_id)
+            .with_bag_ids(BTreeSet::from_iter(vec![
+                council_bag_id.clone(),
+                working_group_bag_id.clone(),
+            ]))
+            .call_and_assert(Ok(()));
+
+        // The second bag goes to the storage bucket left with more free capacity.
+        assert_eq!(
+            Storage::bag(&council_bag_id).stored_by,
+            BTreeSet::from_iter(vec![first_bucket_id])
+        );
+        assert_eq!(
+            Storage::bag(&working_group_bag_id).stored_by,
+            BTreeSet::from_iter(vec![second_bucket_id])
+        );
+
+        let source_bucket = Storage::storage_bucket_by_id(source_bucket_id).unwrap();
+        assert_eq!(source_bucket.assigned_bags, 0);
+        assert_eq!(source_bucket.voucher.objects_used, 0);
+        assert_eq!(source_bucket.voucher.size_used, 0);
+
+        let first_bucket = Storage::storage_bucket_by_id(first_bucket_id).unwrap();
+        assert_eq!(first_bucket.assigned_bags, 1);
+        assert_eq!(first_bucket.voucher.objects_used, 1);
+        assert_eq!(first_bucket.voucher.size_used, object_creation_list[0].size);
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketsUpdatedForBag(
+            working_group_bag_id,
+            BTreeSet::from_iter(vec![second_bucket_id]),
+            source_buckets,
+        ));
+    });
+}
+
+#[test]
+fn rebalance_bags_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 11;
+
+        RebalanceBagsFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn rebalance_bags_fails_with_empty_bag_ids() {
+    build_test_externalities().execute_with(|| {
+        let bucket_id = create_default_storage_bucket_and_assign_to_bag(BagId::<Test>::Static(
+            StaticBagId::Council,
+        ));
+
+        RebalanceBagsFixture::new()
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Err(Error::<Test>::BagIdCollectionIsEmpty.into()));
+    });
+}
+
+#[test]
+fn rebalance_bags_fails_with_exceeding_the_bags_number_limit() {
+    build_test_externalities().execute_with(|| {
+        let bag_ids = (0..=MaxRebalancedBagsNumber::get())
+            .map(|member_id| BagId::<Test>::Dynamic(DynamicBagId::<Test>::Member(member_id.into())))
+            .collect::<BTreeSet<_>>();
+
+        RebalanceBagsFixture::new()
+            .with_bag_ids(bag_ids)
+            .call_and_assert(Err(
+                Error::<Test>::MaxRebalancedBagsNumberLimitExceeded.into()
+            ));
+    });
+}
+
+#[test]
+fn rebalance_bags_fails_with_non_existing_storage_bucket() {
+    build_test_externalities().execute_with(|| {
+        let non_existing_bucket_id = 1000;
+
+        RebalanceBagsFixture::new()
+            .with_storage_bucket_id(non_existing_bucket_id)
+            .with_bag_ids(BTreeSet::from_iter(vec![BagId::<Test>::Static(
+                StaticBagId::Council,
+            )]))
+            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn rebalance_bags_fails_with_non_bound_bag() {
+    build_test_externalities().execute_with(|| {
+        let bucket_id = create_default_storage_bucket_and_assign_to_bag(BagId::<Test>::Static(
+            StaticBagId::Council,
+        ));
+
+        let non_bound_bag_id =
+            BagId::<Test>::Static(StaticBagId::WorkingGroup(WorkingGroup::Storage));
+
+        RebalanceBagsFixture::new()
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_ids(BTreeSet::from_iter(vec![non_bound_bag_id]))
+            .call_and_assert(Err(Error::<Test>::StorageBucketIsNotBoundToBag.into()));
+    });
+}
+
+#[test]
+fn rebalance_bags_fails_with_no_available_storage_buckets() {
+    build_test_externalities().execute_with(|| {
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let bucket_id = create_default_storage_bucket_and_assign_to_bag(bag_id.clone());
+
+        // Storage buckets without an active operator or not accepting new bags are skipped.
+        create_storage_buckets(DEFAULT_STORAGE_BUCKETS_NUMBER);
+        let non_accepting_bucket_id = create_operated_storage_bucket(
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
+        UpdateStorageBucketStatusFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(non_accepting_bucket_id)
+            .with_new_status(false)
+            .call_and_assert(Ok(()));
+
+        RebalanceBagsFixture::new()
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_ids(BTreeSet::from_iter(vec![bag_id]))
+            .call_and_assert(Err(Error::<Test>::NotEnoughStorageBucketsAvailable.into()));
+    });
+}
+
 #[test]
 fn upload_succeeded() {
     build_test_externalities().execute_with(|| {
@@ -764,7 +991,9 @@ fn upload_succeeded() {
                     .try_into()
                     .unwrap(),
                 state_bloat_bond: RepayableBloatBond::new(data_object_state_bloat_bond, None),
-                accepted: false,
+                accepted_by: Default::default(),
+                chunks_merkle_root: None,
+                blocked: false,
             }
         );
 
@@ -1164,7 +1393,9 @@ fn upload_succeeded_with_dynamic_bag() {
                     .try_into()
                     .unwrap(),
                 state_bloat_bond: RepayableBloatBond::new(data_object_state_bloat_bond, None),
-                accepted: false,
+                accepted_by: Default::default(),
+                chunks_merkle_root: None,
+                blocked: false,
             }
         );
     });
@@ -1210,6 +1441,7 @@ fn upload_fails_with_zero_object_size() {
             object_creation_list: vec![DataObjectCreationParameters {
                 ipfs_content_id: vec![1],
                 size: 0,
+                chunks_merkle_root: None,
             }],
             expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
             expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(),
@@ -1231,6 +1463,7 @@ fn upload_fails_with_empty_object_cid() {
             object_creation_list: vec![DataObjectCreationParameters {
                 ipfs_content_id: Vec::new(),
                 size: 220,
+                chunks_merkle_root: None,
             }],
             expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
             expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(),
@@ -1442,6 +1675,11 @@ fn set_storage_operator_metadata_succeeded() {
             .with_metadata(metadata.clone())
             .call_and_assert(Ok(()));
 
+        assert_eq!(
+            Storage::storage_operator_metadata(bucket_id).map(|metadata| metadata.to_vec()),
+            Some(metadata.clone())
+        );
+
         EventFixture::assert_last_crate_event(RawEvent::StorageOperatorMetadataSet(
             bucket_id,
             storage_provider_id,
@@ -1450,6 +1688,71 @@ fn set_storage_operator_metadata_succeeded() {
     });
 }
 
+#[test]
+fn set_storage_operator_metadata_succeeded_without_recording_too_long_metadata() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let bucket_id = create_operated_storage_bucket(
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
+
+        SetStorageOperatorMetadataFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_metadata(b"http://localhost:4000".to_vec())
+            .call_and_assert(Ok(()));
+
+        let metadata = vec![1u8; OperatorMetadataMaxLen::get() as usize + 1];
+
+        SetStorageOperatorMetadataFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_metadata(metadata.clone())
+            .call_and_assert(Ok(()));
+
+        // the previously recorded metadata is outdated
+        assert!(!<StorageOperatorMetadataByBucketId<Test>>::contains_key(
+            bucket_id
+        ));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageOperatorMetadataSet(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            metadata,
+        ));
+    });
+}
+
+#[test]
+fn remove_storage_bucket_operator_clears_operator_metadata() {
+    build_test_externalities().execute_with(|| {
+        let bucket_id = create_operated_storage_bucket(
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
+
+        SetStorageOperatorMetadataFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_metadata(b"http://localhost:4000".to_vec())
+            .call_and_assert(Ok(()));
+
+        RemoveStorageBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+
+        assert!(!<StorageOperatorMetadataByBucketId<Test>>::contains_key(
+            bucket_id
+        ));
+    });
+}
+
 #[test]
 fn set_storage_operator_metadata_fails_with_invalid_origin() {
     build_test_externalities().execute_with(|| {
@@ -1559,8 +1862,8 @@ fn accept_pending_data_objects_succeeded() {
         let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
 
         let data_object = Storage::ensure_data_object_exists(&bag_id, &data_object_id).unwrap();
-        // Check `accepted` flag for the fist data object in the bag.
-        assert_eq!(data_object.accepted, false);
+        // Check acceptance of the first data object in the bag.
+        assert!(!data_object.accepted());
 
         AcceptPendingDataObjectsFixture::new()
             .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
@@ -1571,8 +1874,12 @@ fn accept_pending_data_objects_succeeded() {
             .call_and_assert(Ok(()));
 
         let data_object = Storage::ensure_data_object_exists(&bag_id, &data_object_id).unwrap();
-        // Check `accepted` flag for the fist data object in the bag.
-        assert_eq!(data_object.accepted, true);
+        // Check acceptance of the first data object in the bag.
+        assert!(data_object.accepted());
+        assert_eq!(
+            data_object.accepted_by.into_inner(),
+            BTreeSet::from_iter(vec![bucket_id])
+        );
 
         EventFixture::assert_last_crate_event(RawEvent::PendingDataObjectsAccepted(
             bucket_id,
@@ -1776,8 +2083,8 @@ fn accept_pending_data_objects_succeeded_with_dynamic_bag() {
 
         let bag_id = dynamic_bag_id.into();
         let data_object = Storage::ensure_data_object_exists(&bag_id, &data_object_id).unwrap();
-        // Check `accepted` flag for the fist data object in the bag.
-        assert_eq!(data_object.accepted, true);
+        // Check acceptance of the first data object in the bag.
+        assert!(data_object.accepted());
     });
 }
 
@@ -2700,6 +3007,7 @@ fn update_storage_bucket_for_bag_fails_with_voucher_limits_overflow() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: MaxDataObjectSize::get(),
                     ipfs_content_id: create_cid(1u8.into()),
+                    chunks_merkle_root: None,
                 }],
                 expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
                 expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(
@@ -2729,6 +3037,7 @@ fn update_storage_bucket_for_bag_fails_with_voucher_limits_overflow() {
                 object_creation_list: vec![DataObjectCreationParameters {
                     size: SIZE_LIMIT - MaxDataObjectSize::get() + 1,
                     ipfs_content_id: create_cid(2u8.into()),
+                    chunks_merkle_root: None,
                 }],
                 expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
                 expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(
@@ -3218,6 +3527,46 @@ fn delete_storage_bucket_fails_with_assigned_bag() {
     });
 }
 
+#[test]
+fn delete_storage_bucket_fails_with_unclaimed_earnings() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (council_bag_id, council_bucket_id, _, _) = setup_storage_operators_earnings();
+        upload_data_object_with_size(council_bag_id.clone(), 1, 100);
+        upload_data_object_with_size(council_bag_id.clone(), 2, 100);
+        assert_ok!(<Storage as DataObjectStorage<Test>>::delete_data_objects(
+            DEFAULT_MEMBER_ACCOUNT_ID,
+            council_bag_id.clone(),
+            BTreeSet::from_iter(vec![0, 1]),
+        ));
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(council_bag_id)
+            .with_remove_bucket_ids(BTreeSet::from_iter(vec![council_bucket_id]))
+            .call_and_assert(Ok(()));
+        assert_eq!(
+            Storage::storage_bucket_unclaimed_earnings(&council_bucket_id),
+            100
+        );
+
+        DeleteStorageBucketFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(council_bucket_id)
+            .call_and_assert(Err(Error::<Test>::StorageBucketHasUnclaimedEarnings.into()));
+
+        // the storage bucket can be deleted once the earnings are claimed
+        ClaimStorageBucketEarningsFixture::new()
+            .with_storage_bucket_id(council_bucket_id)
+            .call_and_assert(Ok(()));
+
+        DeleteStorageBucketFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(council_bucket_id)
+            .call_and_assert(Ok(()));
+    });
+}
+
 #[test]
 fn remove_storage_bucket_operator_succeeded() {
     build_test_externalities().execute_with(|| {
@@ -3462,6 +3811,46 @@ fn update_storage_buckets_per_bag_limit_fails_with_incorrect_value() {
     });
 }
 
+#[test]
+fn update_data_object_replication_target_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let new_target = 3;
+
+        UpdateDataObjectReplicationTargetFixture::new()
+            .with_new_target(new_target)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectReplicationTargetUpdated(
+            new_target,
+        ));
+    });
+}
+
+#[test]
+fn update_data_object_replication_target_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        UpdateDataObjectReplicationTargetFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_data_object_replication_target_fails_with_too_high_target() {
+    build_test_externalities().execute_with(|| {
+        let new_target = <Test as Config>::MaxStorageBucketsPerBag::get() + 1;
+
+        UpdateDataObjectReplicationTargetFixture::new()
+            .with_new_target(new_target)
+            .call_and_assert(Err(Error::<Test>::DataObjectReplicationTargetTooHigh.into()));
+    });
+}
+
 #[test]
 fn set_storage_bucket_voucher_limits_succeeded() {
     build_test_externalities().execute_with(|| {
@@ -3703,6 +4092,7 @@ fn create_dynamic_bag_fails_with_invalid_data_object_state_bloat_bond() {
             .with_objects(vec![DataObjectCreationParameters {
                 size: 1,
                 ipfs_content_id: vec![1],
+                chunks_merkle_root: None,
             }])
             .with_expected_data_object_state_bloat_bond(invalid_data_object_state_bloat_bond_value)
             .with_state_bloat_bond_account_id(DEFAULT_MEMBER_ACCOUNT_ID)
@@ -3729,34 +4119,104 @@ fn create_dynamic_bag_failed_with_existing_bag() {
     });
 }
 
-fn create_dynamic_bag(dynamic_bag_id: &DynamicBagId<Test>, storage_buckets: BTreeSet<u64>) {
-    CreateDynamicBagFixture::default()
-        .with_bag_id(dynamic_bag_id.clone())
-        .with_storage_buckets(storage_buckets)
-        .with_expected_data_object_state_bloat_bond(Storage::data_object_state_bloat_bond_value())
-        .call_and_assert(Ok(()));
-}
-
 #[test]
-fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy_succeeded() {
+fn create_dynamic_bag_succeeded_with_picked_storage_buckets() {
     build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
+        set_default_update_storage_buckets_per_bag_limit();
 
-        let dynamic_bag_type = DynamicBagType::Channel;
-        let new_bucket_number = 10;
+        let council_bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let busy_bucket_id = create_operated_storage_bucket(
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
 
-        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
+        UpdateStorageBucketForBagsFixture::new()
             .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_storage_buckets_number(new_bucket_number)
-            .with_dynamic_bag_type(dynamic_bag_type)
+            .with_bag_id(council_bag_id.clone())
+            .with_add_bucket_ids(BTreeSet::from_iter(vec![busy_bucket_id]))
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(
-            RawEvent::NumberOfStorageBucketsInDynamicBagCreationPolicyUpdated(
-                dynamic_bag_type,
-                new_bucket_number,
-            ),
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        UploadFixture::default()
+            .with_params(UploadParameters::<Test> {
+                bag_id: council_bag_id,
+                state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+                object_creation_list: create_single_data_object(),
+                expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+                expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(
+                ),
+            })
+            .call_and_assert(Ok(()));
+
+        // Storage buckets without an active operator are skipped.
+        create_storage_buckets(DEFAULT_STORAGE_BUCKETS_NUMBER);
+
+        let free_bucket_ids = (0..DefaultMemberDynamicBagNumberOfStorageBuckets::get())
+            .map(|_| {
+                create_operated_storage_bucket(
+                    DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+                    DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+                )
+            })
+            .collect::<BTreeSet<_>>();
+
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+
+        CreateDynamicBagFixture::default()
+            .with_bag_id(dynamic_bag_id.clone())
+            .call_and_assert(Ok(()));
+
+        let bag = Storage::bag(&BagId::<Test>::Dynamic(dynamic_bag_id));
+        assert_eq!(bag.stored_by, free_bucket_ids);
+    });
+}
+
+#[test]
+fn create_dynamic_bag_fails_with_not_enough_storage_buckets_to_pick() {
+    build_test_externalities().execute_with(|| {
+        create_storage_buckets(DefaultMemberDynamicBagNumberOfStorageBuckets::get());
+
+        (1..DefaultMemberDynamicBagNumberOfStorageBuckets::get()).for_each(|_| {
+            create_operated_storage_bucket(
+                DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+                DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+            );
+        });
+
+        CreateDynamicBagFixture::default()
+            .with_bag_id(DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID))
+            .call_and_assert(Err(Error::<Test>::NotEnoughStorageBucketsAvailable.into()));
+    });
+}
+
+fn create_dynamic_bag(dynamic_bag_id: &DynamicBagId<Test>, storage_buckets: BTreeSet<u64>) {
+    CreateDynamicBagFixture::default()
+        .with_bag_id(dynamic_bag_id.clone())
+        .with_storage_buckets(storage_buckets)
+        .with_expected_data_object_state_bloat_bond(Storage::data_object_state_bloat_bond_value())
+        .call_and_assert(Ok(()));
+}
+
+#[test]
+fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dynamic_bag_type = DynamicBagType::Channel;
+        let new_bucket_number = 10;
+
+        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_storage_buckets_number(new_bucket_number)
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(
+            RawEvent::NumberOfStorageBucketsInDynamicBagCreationPolicyUpdated(
+                dynamic_bag_type,
+                new_bucket_number,
+            ),
         );
     });
 }
@@ -5203,6 +5663,15 @@ fn set_distribution_operator_metadata_succeeded() {
             .with_metadata(metadata.clone())
             .call_and_assert(Ok(()));
 
+        assert_eq!(
+            Storage::distribution_operator_metadata(
+                Storage::create_distribution_bucket_id(family_id, bucket_index),
+                provider_id
+            )
+            .map(|metadata| metadata.to_vec()),
+            Some(metadata.clone())
+        );
+
         EventFixture::assert_last_crate_event(RawEvent::DistributionBucketMetadataSet(
             provider_id,
             Storage::create_distribution_bucket_id(family_id, bucket_index),
@@ -5580,6 +6049,7 @@ fn unsuccessful_dyn_bag_creation_with_zero_objects_size() {
             .map(|idx| DataObjectCreationParameters {
                 size: 0,
                 ipfs_content_id: vec![idx.try_into().unwrap()],
+                chunks_merkle_root: None,
             })
             .collect();
 
@@ -5604,6 +6074,7 @@ fn unsuccessful_dyn_bag_creation_with_object_size_exceeding_max_obj_size() {
                 // set size high on purpose to trigger error
                 size: MaxDataObjectSize::get() + 1,
                 ipfs_content_id: vec![idx.try_into().unwrap()],
+                chunks_merkle_root: None,
             })
             .collect();
 
@@ -5626,6 +6097,7 @@ fn unsuccessful_dyn_bag_creation_with_bucket_objects_size_limit_reached() {
             .with_objects(vec![DataObjectCreationParameters {
                 size: DEFAULT_STORAGE_BUCKET_SIZE_LIMIT + 1,
                 ipfs_content_id: create_cid(1u32.into()),
+                chunks_merkle_root: None,
             }])
             .with_storage_buckets(storage_buckets)
             .call_and_assert(Err(
@@ -5647,6 +6119,7 @@ fn unsuccessful_dyn_bag_creation_with_bucket_objects_number_limit_reached() {
                 .map(|idx| DataObjectCreationParameters {
                     size: 1,
                     ipfs_content_id: create_cid(idx.into()),
+                    chunks_merkle_root: None,
                 })
                 .collect();
 
@@ -5671,6 +6144,7 @@ fn unsuccessful_dyn_bag_creation_with_empty_ipfs_ids() {
             .map(|_| DataObjectCreationParameters {
                 size: DEFAULT_DATA_OBJECTS_SIZE,
                 ipfs_content_id: vec![],
+                chunks_merkle_root: None,
             })
             .collect();
         CreateDynamicBagFixture::default()
@@ -5758,6 +6232,7 @@ fn unsuccessful_dyn_bag_creation_with_blacklisted_ipfs_id() {
             .map(|idx| DataObjectCreationParameters {
                 size: DEFAULT_DATA_OBJECTS_SIZE,
                 ipfs_content_id: create_cid(idx.saturated_into()),
+                chunks_merkle_root: None,
             })
             .collect();
 
@@ -6301,6 +6776,251 @@ fn get_upload_costs_succeeds_overflow_sat() {
     });
 }
 
+#[test]
+fn upload_fee_quote_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let data_object_per_mega_byte_fee = 50;
+        set_data_object_per_mega_byte_fee(data_object_per_mega_byte_fee);
+
+        let data_object_state_bloat_bond = 10;
+        set_data_object_state_bloat_bond_value(data_object_state_bloat_bond);
+
+        // Total size is rounded up to 3 megabytes.
+        let objects = create_data_object_candidates_with_size(1, 2, ONE_MB + 1);
+        let quote = Storage::upload_fee_quote(objects.clone()).unwrap();
+
+        assert_eq!(
+            quote,
+            UploadFeeQuote {
+                data_object_per_mega_byte_fee,
+                data_object_state_bloat_bond,
+                data_size_fee: 3 * data_object_per_mega_byte_fee,
+                total_state_bloat_bond: 2 * data_object_state_bloat_bond,
+                total_fee: 3 * data_object_per_mega_byte_fee + 2 * data_object_state_bloat_bond,
+            }
+        );
+
+        // The quote covers the actual upload costs.
+        increase_account_balance(
+            &DEFAULT_MEMBER_ACCOUNT_ID,
+            INITIAL_BALANCE + quote.total_fee,
+        );
+
+        UploadFixture::default()
+            .with_params(UploadParameters::<Test> {
+                bag_id: BagId::<Test>::Static(StaticBagId::Council),
+                state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+                object_creation_list: objects,
+                expected_data_size_fee: data_object_per_mega_byte_fee,
+                expected_data_object_state_bloat_bond: data_object_state_bloat_bond,
+                ..Default::default()
+            })
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            INITIAL_BALANCE
+        );
+    });
+}
+
+#[test]
+fn upload_fee_quote_succeeded_without_data_size_fee_for_zero_state_bloat_bond() {
+    build_test_externalities().execute_with(|| {
+        set_data_object_per_mega_byte_fee(50);
+        set_data_object_state_bloat_bond_value(0);
+
+        let quote = Storage::upload_fee_quote(create_single_data_object()).unwrap();
+
+        assert_eq!(quote.data_size_fee, 0);
+        assert_eq!(quote.total_fee, 0);
+    });
+}
+
+#[test]
+fn upload_fee_quote_fails_with_invalid_data_objects() {
+    build_test_externalities().execute_with(|| {
+        let invalid_cid_objects = vec![DataObjectCreationParameters {
+            size: DEFAULT_DATA_OBJECTS_SIZE,
+            ipfs_content_id: vec![1],
+            chunks_merkle_root: None,
+        }];
+
+        assert_err!(
+            Storage::upload_fee_quote(invalid_cid_objects),
+            Error::<Test>::InvalidCidLength
+        );
+
+        let zero_size_objects = create_data_object_candidates_with_size(1, 1, 0);
+
+        assert_err!(
+            Storage::upload_fee_quote(zero_size_objects),
+            Error::<Test>::ZeroObjectSize
+        );
+    });
+}
+
+#[test]
+fn bag_info_succeeded() {
+    build_test_externalities().execute_with(|| {
+        set_default_distribution_buckets_per_bag_limit();
+
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+
+        let storage_bucket_id = create_storage_bucket_and_assign_to_bag(
+            bag_id.clone(),
+            Some(DEFAULT_STORAGE_PROVIDER_ID),
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
+        let storage_metadata = b"http://localhost:4000".to_vec();
+
+        SetStorageOperatorMetadataFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(storage_bucket_id)
+            .with_metadata(storage_metadata.clone())
+            .call_and_assert(Ok(()));
+
+        let (family_id, distribution_bucket_ids) =
+            create_distribution_bucket_family_with_buckets(1);
+        let distribution_bucket_id = distribution_bucket_ids[0].clone();
+        let bucket_index = distribution_bucket_id.distribution_bucket_index;
+        let provider_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
+        let distribution_metadata = b"http://localhost:5000".to_vec();
+
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_family_id(family_id)
+            .with_add_bucket_indices(BTreeSet::from_iter(vec![bucket_index]))
+            .call_and_assert(Ok(()));
+
+        InviteDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_operator_worker_id(provider_id)
+            .call_and_assert(Ok(()));
+
+        AcceptDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_worker_id(provider_id)
+            .call_and_assert(Ok(()));
+
+        SetDistributionBucketMetadataFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_worker_id(provider_id)
+            .with_metadata(distribution_metadata.clone())
+            .call_and_assert(Ok(()));
+
+        let bag_info = Storage::bag_info(bag_id).unwrap();
+
+        assert_eq!(bag_info.storage_buckets.len(), 1);
+        let storage_bucket_info = &bag_info.storage_buckets[0];
+        assert_eq!(storage_bucket_info.id, storage_bucket_id);
+        assert_eq!(
+            storage_bucket_info.bucket,
+            Storage::storage_bucket_by_id(storage_bucket_id).unwrap()
+        );
+        assert_eq!(
+            storage_bucket_info.operator_metadata,
+            Some(storage_metadata)
+        );
+
+        assert_eq!(bag_info.distribution_buckets.len(), 1);
+        let distribution_bucket_info = &bag_info.distribution_buckets[0];
+        assert_eq!(distribution_bucket_info.id, distribution_bucket_id);
+        assert_eq!(
+            distribution_bucket_info.bucket.operators,
+            BTreeSet::from_iter(vec![provider_id])
+        );
+        assert_eq!(distribution_bucket_info.bucket.assigned_bags, 1);
+        assert_eq!(
+            distribution_bucket_info.operators_metadata,
+            vec![(provider_id, Some(distribution_metadata))]
+        );
+    });
+}
+
+#[test]
+fn bag_info_fails_with_non_existing_dynamic_bag() {
+    build_test_externalities().execute_with(|| {
+        let bag_id: BagId<Test> = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID).into();
+
+        assert_err!(
+            Storage::bag_info(bag_id),
+            Error::<Test>::DynamicBagDoesntExist
+        );
+    });
+}
+
+#[test]
+fn bag_data_objects_succeeded_with_pagination() {
+    build_test_externalities().execute_with(|| {
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+
+        UploadFixture::default()
+            .with_params(UploadParameters::<Test> {
+                bag_id: bag_id.clone(),
+                state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+                object_creation_list: create_data_object_candidates(1, 3),
+                expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+                expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(
+                ),
+                ..Default::default()
+            })
+            .call_and_assert(Ok(()));
+
+        let page_ids = |start_after, limit| {
+            Storage::bag_data_objects(bag_id.clone(), start_after, limit)
+                .unwrap()
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect::<Vec<_>>()
+        };
+
+        // data objects are returned in the storage order
+        let first_page = page_ids(None, 2);
+        assert_eq!(first_page.len(), 2);
+        let second_page = page_ids(first_page.last().copied(), 2);
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(page_ids(second_page.last().copied(), 2), Vec::<u64>::new());
+        assert_eq!(
+            BTreeSet::from_iter(first_page.into_iter().chain(second_page)),
+            BTreeSet::from_iter(vec![0u64, 1, 2])
+        );
+
+        let (data_object_id, data_object) = Storage::bag_data_objects(bag_id.clone(), None, 1)
+            .unwrap()
+            .remove(0);
+        let stored_data_object = Storage::data_object_by_id(&bag_id, &data_object_id);
+        assert_eq!(data_object.size, stored_data_object.size);
+        assert_eq!(
+            data_object.ipfs_content_id,
+            stored_data_object.ipfs_content_id
+        );
+    });
+}
+
+#[test]
+fn bag_data_objects_fails_with_non_existing_dynamic_bag() {
+    build_test_externalities().execute_with(|| {
+        let bag_id: BagId<Test> = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID).into();
+
+        assert_err!(
+            Storage::bag_data_objects(bag_id, None, 10),
+            Error::<Test>::DynamicBagDoesntExist
+        );
+    });
+}
+
 #[test]
 fn uploading_objects_with_invalid_cid_length_should_fail() {
     build_test_externalities().execute_with(|| {
@@ -6321,6 +7041,7 @@ fn uploading_objects_with_invalid_cid_length_should_fail() {
         let object_creation_list = vec![DataObjectCreationParameters {
             size: DEFAULT_DATA_OBJECTS_SIZE,
             ipfs_content_id: b"test".to_vec(),
+            chunks_merkle_root: None,
         }];
 
         let upload_params = UploadParameters::<Test> {
@@ -6386,6 +7107,7 @@ fn creating_dynamic_bag_with_objects_having_invalid_cid_length_should_fail() {
         let object_creation_list = vec![DataObjectCreationParameters {
             size: DEFAULT_DATA_OBJECTS_SIZE,
             ipfs_content_id: b"test".to_vec(),
+            chunks_merkle_root: None,
         }];
 
         CreateDynamicBagFixture::default()
@@ -6402,3 +7124,1500 @@ fn creating_dynamic_bag_with_objects_having_invalid_cid_length_should_fail() {
             .call_and_assert(Err(Error::<Test>::InvalidCidLength.into()));
     })
 }
+
+fn storage_challenge_chunks() -> Vec<Vec<u8>> {
+    vec![vec![1u8; 4], vec![2u8; 4], vec![3u8; 3]]
+}
+
+fn storage_challenge_data_object_size() -> u64 {
+    storage_challenge_chunks()
+        .iter()
+        .map(|chunk| chunk.len() as u64)
+        .sum()
+}
+
+fn upload_challengeable_data_object(bag_id: BagId<Test>) {
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    let upload_params = UploadParameters::<Test> {
+        bag_id,
+        state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+        object_creation_list: vec![DataObjectCreationParameters {
+            size: storage_challenge_data_object_size(),
+            ipfs_content_id: create_cid(1),
+            chunks_merkle_root: Some(create_chunks_merkle_root(&storage_challenge_chunks())),
+        }],
+        expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(),
+        ..Default::default()
+    };
+
+    UploadFixture::default()
+        .with_params(upload_params)
+        .call_and_assert(Ok(()));
+}
+
+fn accept_data_object(bucket_id: u64, bag_id: BagId<Test>, data_object_id: u64) {
+    AcceptPendingDataObjectsFixture::new()
+        .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+        .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+        .with_storage_bucket_id(bucket_id)
+        .with_bag_id(bag_id)
+        .with_data_object_ids(BTreeSet::from_iter(vec![data_object_id]))
+        .call_and_assert(Ok(()));
+}
+
+// Uploads accepted challengeable data object (id 0) to the council bag stored by
+// the single storage bucket with the default storage provider.
+fn setup_challengeable_data_object() -> (u64, BagId<Test>) {
+    let bag_id: BagId<Test> = StaticBagId::Council.into();
+    let bucket_id = create_storage_bucket_and_assign_to_bag(
+        bag_id.clone(),
+        Some(DEFAULT_STORAGE_PROVIDER_ID),
+        1,
+        100,
+    );
+
+    upload_challengeable_data_object(bag_id.clone());
+    accept_data_object(bucket_id, bag_id.clone(), 0);
+
+    (bucket_id, bag_id)
+}
+
+fn set_storage_challenge_chunk_index(challenge_id: StorageChallengeId, chunk_index: u64) {
+    StorageChallengeById::<Test>::mutate(challenge_id, |challenge| {
+        if let Some(challenge) = challenge {
+            challenge.chunk_index = chunk_index;
+        }
+    });
+}
+
+fn storage_challenge_response(
+    challenge_id: StorageChallengeId,
+) -> (Vec<u8>, Vec<ChunkProofElement<Test>>) {
+    let chunk_index = Storage::storage_challenge_by_id(challenge_id)
+        .unwrap()
+        .chunk_index as usize;
+    let chunks = storage_challenge_chunks();
+
+    (
+        chunks[chunk_index].clone(),
+        build_merkle_path_helper::<Test, _>(&chunks, chunk_index),
+    )
+}
+
+fn respond_to_storage_challenge(challenge_id: StorageChallengeId) {
+    let (chunk, proof) = storage_challenge_response(challenge_id);
+
+    RespondToStorageChallengeFixture::new()
+        .with_challenge_id(challenge_id)
+        .with_chunk(chunk)
+        .with_proof(proof)
+        .call_and_assert(Ok(()));
+}
+
+fn storage_wg_slashes() -> Vec<(u64, u64)> {
+    STORAGE_WG_SLASHES.with(|slashes| slashes.borrow().clone())
+}
+
+#[test]
+fn storage_challenges_issued_on_initialize() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, bag_id) = setup_challengeable_data_object();
+
+        run_to_block(2);
+
+        let max_challenges = MaxStorageChallengesPerBlock::get() as u64;
+        let deadline = 2 + StorageChallengeResponsePeriod::get();
+        assert_eq!(Storage::next_storage_challenge_id(), max_challenges);
+        assert_eq!(
+            Storage::storage_challenges_by_deadline(deadline).into_inner(),
+            (0..max_challenges).collect::<Vec<_>>()
+        );
+
+        let challenge = Storage::storage_challenge_by_id(0).unwrap();
+        assert_eq!(challenge.storage_bucket_id, bucket_id);
+        assert_eq!(challenge.bag_id, bag_id);
+        assert_eq!(challenge.data_object_id, 0);
+        assert_eq!(challenge.deadline, deadline);
+        assert!(challenge.chunk_index < storage_challenge_chunks().len() as u64);
+
+        EventFixture::contains_crate_event(RawEvent::StorageChallengeIssued(0, challenge));
+    });
+}
+
+#[test]
+fn storage_challenges_not_issued_for_pending_data_objects() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        create_storage_bucket_and_assign_to_bag(
+            bag_id.clone(),
+            Some(DEFAULT_STORAGE_PROVIDER_ID),
+            1,
+            100,
+        );
+        upload_challengeable_data_object(bag_id);
+
+        run_to_block(2);
+
+        assert!(!ChallengeableDataObjectBagById::<Test>::contains_key(0));
+        assert_eq!(Storage::next_storage_challenge_id(), 0);
+    });
+}
+
+#[test]
+fn storage_challenges_issued_for_stored_data_objects_only() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        let bucket_id = create_storage_bucket_and_assign_to_bag(
+            bag_id.clone(),
+            Some(DEFAULT_STORAGE_PROVIDER_ID),
+            10,
+            100,
+        );
+        // pending (0..4), deleted (4) and accepted (5) challengeable data objects
+        for _ in 0..6 {
+            upload_challengeable_data_object(bag_id.clone());
+        }
+        accept_data_object(bucket_id, bag_id.clone(), 4);
+        accept_data_object(bucket_id, bag_id.clone(), 5);
+        assert_ok!(<Storage as DataObjectStorage<Test>>::delete_data_objects(
+            DEFAULT_MEMBER_ACCOUNT_ID,
+            bag_id,
+            BTreeSet::from_iter(vec![4]),
+        ));
+
+        run_to_block(2);
+
+        let max_challenges = MaxStorageChallengesPerBlock::get() as u64;
+        assert_eq!(Storage::next_storage_challenge_id(), max_challenges);
+        for challenge_id in 0..max_challenges {
+            assert_eq!(
+                Storage::storage_challenge_by_id(challenge_id)
+                    .unwrap()
+                    .data_object_id,
+                5
+            );
+        }
+    });
+}
+
+#[test]
+fn challengeable_data_object_unindexed_on_acceptance_removal() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, bag_id) = setup_challengeable_data_object();
+        assert_eq!(
+            ChallengeableDataObjectBagById::<Test>::get(0),
+            Some(bag_id.clone())
+        );
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id)
+            .with_remove_bucket_ids(BTreeSet::from_iter(vec![bucket_id]))
+            .call_and_assert(Ok(()));
+        run_to_block(2);
+
+        assert!(!ChallengeableDataObjectBagById::<Test>::contains_key(0));
+        assert_eq!(Storage::next_storage_challenge_id(), 0);
+    });
+}
+
+#[test]
+fn storage_challenges_not_issued_for_data_objects_without_chunks_merkle_root() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        let bucket_id = create_storage_bucket_and_assign_to_bag(
+            bag_id.clone(),
+            Some(DEFAULT_STORAGE_PROVIDER_ID),
+            1,
+            100,
+        );
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        UploadFixture::default()
+            .with_params(UploadParameters::<Test> {
+                bag_id: bag_id.clone(),
+                state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+                object_creation_list: create_single_data_object(),
+                expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+                expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(
+                ),
+                ..Default::default()
+            })
+            .call_and_assert(Ok(()));
+        accept_data_object(bucket_id, bag_id, 0);
+
+        run_to_block(2);
+
+        assert!(!ChallengeableDataObjectBagById::<Test>::contains_key(0));
+        assert_eq!(Storage::next_storage_challenge_id(), 0);
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, _) = setup_challengeable_data_object();
+        run_to_block(2);
+        StorageBucketChallengeFailures::<Test>::insert(bucket_id, 1);
+
+        respond_to_storage_challenge(0);
+
+        assert_eq!(Storage::storage_bucket_challenge_failures(bucket_id), 0);
+        EventFixture::assert_last_crate_event(RawEvent::StorageChallengeResponded(0, bucket_id));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_succeeded_for_every_chunk() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        setup_challengeable_data_object();
+        run_to_block(2);
+
+        for challenge_id in 0..MaxStorageChallengesPerBlock::get() as u64 {
+            set_storage_challenge_chunk_index(challenge_id, challenge_id);
+            respond_to_storage_challenge(challenge_id);
+        }
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_invalid_chunk() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        setup_challengeable_data_object();
+        run_to_block(2);
+        set_storage_challenge_chunk_index(0, 0);
+        let (_, proof) = storage_challenge_response(0);
+
+        RespondToStorageChallengeFixture::new()
+            .with_chunk(storage_challenge_chunks()[1].clone())
+            .with_proof(proof)
+            .call_and_assert(Err(Error::<Test>::InvalidStorageChallengeResponse.into()));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_invalid_proof_sides() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        setup_challengeable_data_object();
+        run_to_block(2);
+        set_storage_challenge_chunk_index(0, 0);
+        let (chunk, mut proof) = storage_challenge_response(0);
+        proof[0].side = Side::Left;
+
+        RespondToStorageChallengeFixture::new()
+            .with_chunk(chunk)
+            .with_proof(proof)
+            .call_and_assert(Err(Error::<Test>::InvalidStorageChallengeResponse.into()));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_proof_of_another_chunk() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        setup_challengeable_data_object();
+        run_to_block(2);
+        set_storage_challenge_chunk_index(0, 0);
+        let chunks = storage_challenge_chunks();
+
+        RespondToStorageChallengeFixture::new()
+            .with_chunk(chunks[1].clone())
+            .with_proof(build_merkle_path_helper::<Test, _>(&chunks, 1))
+            .call_and_assert(Err(Error::<Test>::InvalidStorageChallengeResponse.into()));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_incomplete_proof() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        setup_challengeable_data_object();
+        run_to_block(2);
+        let (chunk, mut proof) = storage_challenge_response(0);
+        proof.pop();
+
+        RespondToStorageChallengeFixture::new()
+            .with_chunk(chunk)
+            .with_proof(proof)
+            .call_and_assert(Err(Error::<Test>::InvalidStorageChallengeResponse.into()));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_chunk_size_exceeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        setup_challengeable_data_object();
+        run_to_block(2);
+        let (_, proof) = storage_challenge_response(0);
+
+        RespondToStorageChallengeFixture::new()
+            .with_chunk(vec![1u8; DataObjectChunkSize::get() as usize + 1])
+            .with_proof(proof)
+            .call_and_assert(Err(Error::<Test>::DataObjectChunkSizeExceeded.into()));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_non_existing_challenge() {
+    build_test_externalities().execute_with(|| {
+        RespondToStorageChallengeFixture::new()
+            .call_and_assert(Err(Error::<Test>::StorageChallengeDoesNotExist.into()));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_invalid_storage_provider() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        setup_challengeable_data_object();
+        run_to_block(2);
+        let (chunk, proof) = storage_challenge_response(0);
+
+        RespondToStorageChallengeFixture::new()
+            .with_worker_id(ANOTHER_STORAGE_PROVIDER_ID)
+            .with_chunk(chunk)
+            .with_proof(proof)
+            .call_and_assert(Err(Error::<Test>::InvalidStorageProvider.into()));
+    });
+}
+
+#[test]
+fn respond_to_storage_challenge_fails_with_invalid_transactor_account() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        setup_challengeable_data_object();
+        run_to_block(2);
+        let (chunk, proof) = storage_challenge_response(0);
+
+        RespondToStorageChallengeFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID))
+            .with_chunk(chunk)
+            .with_proof(proof)
+            .call_and_assert(Err(Error::<Test>::InvalidTransactorAccount.into()));
+    });
+}
+
+#[test]
+fn unanswered_storage_challenge_fails_at_deadline() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, _) = setup_challengeable_data_object();
+        run_to_block(2);
+        respond_to_storage_challenge(1);
+        respond_to_storage_challenge(2);
+
+        let deadline = 2 + StorageChallengeResponsePeriod::get();
+        run_to_block(deadline);
+
+        assert!(Storage::storage_challenge_by_id(0).is_none());
+        assert!(!crate::StorageChallengesByDeadline::<Test>::contains_key(
+            deadline
+        ));
+        assert_eq!(Storage::storage_bucket_challenge_failures(bucket_id), 1);
+        assert!(storage_wg_slashes().is_empty());
+        EventFixture::contains_crate_event(RawEvent::StorageChallengeFailed(0, bucket_id, 1));
+    });
+}
+
+#[test]
+fn storage_bucket_operator_slashed_on_repeated_storage_challenge_failures() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, _) = setup_challengeable_data_object();
+        run_to_block(2);
+
+        run_to_block(2 + StorageChallengeResponsePeriod::get());
+
+        let penalty = StorageChallengeSlashPenalty::get();
+        assert_eq!(
+            storage_wg_slashes(),
+            vec![(DEFAULT_STORAGE_PROVIDER_ID, penalty)]
+        );
+        EventFixture::contains_crate_event(RawEvent::StorageChallengeFailed(0, bucket_id, 1));
+        EventFixture::contains_crate_event(RawEvent::StorageChallengeFailed(1, bucket_id, 2));
+        EventFixture::contains_crate_event(RawEvent::StorageBucketOperatorSlashed(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            penalty,
+        ));
+        // failures counter is reset on slashing
+        EventFixture::contains_crate_event(RawEvent::StorageChallengeFailed(2, bucket_id, 1));
+        assert_eq!(Storage::storage_bucket_challenge_failures(bucket_id), 1);
+    });
+}
+
+#[test]
+fn storage_challenge_dropped_on_data_object_deletion() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, bag_id) = setup_challengeable_data_object();
+        run_to_block(2);
+
+        assert_ok!(<Storage as DataObjectStorage<Test>>::delete_data_objects(
+            DEFAULT_MEMBER_ACCOUNT_ID,
+            bag_id,
+            BTreeSet::from_iter(vec![0]),
+        ));
+        assert!(!ChallengeableDataObjectBagById::<Test>::contains_key(0));
+
+        run_to_block(2 + StorageChallengeResponsePeriod::get());
+
+        assert!(Storage::storage_challenge_by_id(0).is_none());
+        assert_eq!(Storage::storage_bucket_challenge_failures(bucket_id), 0);
+        assert!(storage_wg_slashes().is_empty());
+    });
+}
+
+#[test]
+fn challengeable_data_object_bag_updated_on_data_objects_moving() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let src_dynamic_bag_id = DynamicBagId::<Test>::Member(1u64);
+        let src_bag_id = BagId::<Test>::Dynamic(src_dynamic_bag_id.clone());
+        create_dynamic_bag(
+            &src_dynamic_bag_id,
+            create_storage_buckets(DEFAULT_STORAGE_BUCKETS_NUMBER),
+        );
+
+        let dest_dynamic_bag_id = DynamicBagId::<Test>::Member(2u64);
+        let dest_bag_id = BagId::<Test>::Dynamic(dest_dynamic_bag_id.clone());
+        create_dynamic_bag(
+            &dest_dynamic_bag_id,
+            create_storage_buckets(DEFAULT_STORAGE_BUCKETS_NUMBER),
+        );
+
+        // storage bucket storing both bags keeps the data object accepted
+        let bucket_id = create_operated_storage_bucket(
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
+        set_default_update_storage_buckets_per_bag_limit();
+        for bag_id in [src_bag_id.clone(), dest_bag_id.clone()] {
+            UpdateStorageBucketForBagsFixture::new()
+                .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+                .with_bag_id(bag_id)
+                .with_add_bucket_ids(BTreeSet::from_iter(vec![bucket_id]))
+                .call_and_assert(Ok(()));
+        }
+
+        upload_challengeable_data_object(src_bag_id.clone());
+        accept_data_object(bucket_id, src_bag_id.clone(), 0);
+        assert_eq!(
+            ChallengeableDataObjectBagById::<Test>::get(0),
+            Some(src_bag_id.clone())
+        );
+
+        MoveDataObjectsFixture::default()
+            .with_src_bag_id(src_bag_id)
+            .with_dest_bag_id(dest_bag_id.clone())
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            ChallengeableDataObjectBagById::<Test>::get(0),
+            Some(dest_bag_id)
+        );
+    });
+}
+
+// Uploads data object (id 0) to the council bag and accepts it by two storage buckets
+// operated by different storage providers.
+fn setup_data_object_accepted_by_two_storage_buckets() -> (BagId<Test>, u64, u64) {
+    let bag_id: BagId<Test> = StaticBagId::Council.into();
+    let bucket_id1 = create_storage_bucket_and_assign_to_bag(
+        bag_id.clone(),
+        Some(DEFAULT_STORAGE_PROVIDER_ID),
+        1,
+        100,
+    );
+    let bucket_id2 = create_storage_bucket_and_assign_to_bag(
+        bag_id.clone(),
+        Some(ANOTHER_STORAGE_PROVIDER_ID),
+        1,
+        100,
+    );
+
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+    UploadFixture::default()
+        .with_params(UploadParameters::<Test> {
+            bag_id: bag_id.clone(),
+            state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: create_single_data_object(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+            expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(),
+            ..Default::default()
+        })
+        .call_and_assert(Ok(()));
+
+    for (bucket_id, worker_id) in [
+        (bucket_id1, DEFAULT_STORAGE_PROVIDER_ID),
+        (bucket_id2, ANOTHER_STORAGE_PROVIDER_ID),
+    ] {
+        AcceptPendingDataObjectsFixture::new()
+            .with_worker_id(worker_id)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id.clone())
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Ok(()));
+    }
+
+    (bag_id, bucket_id1, bucket_id2)
+}
+
+#[test]
+fn data_object_accepted_by_multiple_storage_buckets() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, bucket_id1, bucket_id2) = setup_data_object_accepted_by_two_storage_buckets();
+
+        let data_object = Storage::data_object_by_id(&bag_id, 0u64);
+        assert_eq!(
+            data_object.accepted_by.into_inner(),
+            BTreeSet::from_iter(vec![bucket_id1, bucket_id2])
+        );
+        assert_eq!(Storage::data_object_replication_count(&bag_id, &0), 2);
+    });
+}
+
+#[test]
+fn data_object_replication_falls_below_target_on_removing_storage_bucket_from_bag() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bag_id, bucket_id1, bucket_id2) = setup_data_object_accepted_by_two_storage_buckets();
+        UpdateDataObjectReplicationTargetFixture::new()
+            .with_new_target(2)
+            .call_and_assert(Ok(()));
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_remove_bucket_ids(BTreeSet::from_iter(vec![bucket_id1]))
+            .call_and_assert(Ok(()));
+
+        // acceptance is removed on the next block initialization
+        run_to_block(2);
+
+        let data_object = Storage::data_object_by_id(&bag_id, 0u64);
+        assert_eq!(
+            data_object.accepted_by.into_inner(),
+            BTreeSet::from_iter(vec![bucket_id2])
+        );
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectReplicationBelowTarget(
+            bag_id, 0, 1,
+        ));
+    });
+}
+
+#[test]
+fn data_object_replication_below_target_not_reported_again() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bag_id, bucket_id1, _) = setup_data_object_accepted_by_two_storage_buckets();
+        UpdateDataObjectReplicationTargetFixture::new()
+            .with_new_target(3)
+            .call_and_assert(Ok(()));
+
+        let remove_buckets = BTreeSet::from_iter(vec![bucket_id1]);
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_remove_bucket_ids(remove_buckets.clone())
+            .call_and_assert(Ok(()));
+        run_to_block(2);
+
+        assert_eq!(Storage::data_object_replication_count(&bag_id, &0), 1);
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketsUpdatedForBag(
+            bag_id,
+            BTreeSet::new(),
+            remove_buckets,
+        ));
+    });
+}
+
+#[test]
+fn data_object_replication_count_excludes_removed_storage_buckets_before_acceptance_removal() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, bucket_id1, _) = setup_data_object_accepted_by_two_storage_buckets();
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_remove_bucket_ids(BTreeSet::from_iter(vec![bucket_id1]))
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Storage::data_object_by_id(&bag_id, 0u64).accepted_by.len(),
+            2
+        );
+        assert_eq!(Storage::data_object_replication_count(&bag_id, &0), 1);
+    });
+}
+
+#[test]
+fn data_objects_acceptance_removed_in_batches_on_block_initialization() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        let bucket_id = create_storage_bucket_and_assign_to_bag(
+            bag_id.clone(),
+            Some(DEFAULT_STORAGE_PROVIDER_ID),
+            VOUCHER_OBJECTS_LIMIT,
+            VOUCHER_SIZE_LIMIT,
+        );
+
+        let data_objects_number = MaxDataObjectsAcceptanceRemovalsPerBlock::get() + 5;
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        UploadFixture::default()
+            .with_params(UploadParameters::<Test> {
+                bag_id: bag_id.clone(),
+                state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+                object_creation_list: create_data_object_candidates_with_size(
+                    1,
+                    data_objects_number as u8,
+                    1,
+                ),
+                expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+                expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(
+                ),
+                ..Default::default()
+            })
+            .call_and_assert(Ok(()));
+
+        let data_object_ids = (0..data_objects_number as u64).collect::<BTreeSet<_>>();
+        AcceptPendingDataObjectsFixture::new()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id.clone())
+            .with_data_object_ids(data_object_ids.clone())
+            .call_and_assert(Ok(()));
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_remove_bucket_ids(BTreeSet::from_iter(vec![bucket_id]))
+            .call_and_assert(Ok(()));
+
+        let accepted_data_objects_number = || {
+            data_object_ids
+                .iter()
+                .filter(|id| Storage::data_object_by_id(&bag_id, *id).accepted())
+                .count()
+        };
+
+        run_to_block(2);
+        assert_eq!(accepted_data_objects_number(), 5);
+        assert!(DataObjectsAcceptanceRemovalByBagId::<Test>::contains_key(
+            &bag_id
+        ));
+
+        run_to_block(3);
+        assert_eq!(accepted_data_objects_number(), 0);
+        assert!(!DataObjectsAcceptanceRemovalByBagId::<Test>::contains_key(
+            &bag_id
+        ));
+    });
+}
+
+#[test]
+fn update_storage_buckets_for_bag_fails_with_storage_bucket_acceptance_removal_pending() {
+    build_test_externalities().execute_with(|| {
+        let (bag_id, bucket_id1, _) = setup_data_object_accepted_by_two_storage_buckets();
+        let buckets = BTreeSet::from_iter(vec![bucket_id1]);
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_remove_bucket_ids(buckets.clone())
+            .call_and_assert(Ok(()));
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_add_bucket_ids(buckets.clone())
+            .call_and_assert(Err(
+                Error::<Test>::StorageBucketAcceptanceRemovalPending.into()
+            ));
+
+        run_to_block(2);
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_add_bucket_ids(buckets)
+            .call_and_assert(Ok(()));
+
+        // acceptance is not restored
+        assert_eq!(Storage::data_object_replication_count(&bag_id, &0), 1);
+    });
+}
+
+#[test]
+fn data_object_acceptance_dropped_on_moving_to_bag_with_other_storage_buckets() {
+    build_test_externalities().execute_with(|| {
+        let (src_bag_id, _, _) = setup_data_object_accepted_by_two_storage_buckets();
+
+        let dest_dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let dest_bag_id = BagId::<Test>::Dynamic(dest_dynamic_bag_id.clone());
+        create_dynamic_bag(
+            &dest_dynamic_bag_id,
+            create_storage_buckets(DEFAULT_STORAGE_BUCKETS_NUMBER),
+        );
+
+        MoveDataObjectsFixture::default()
+            .with_src_bag_id(src_bag_id)
+            .with_dest_bag_id(dest_bag_id.clone())
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Ok(()));
+
+        assert!(!Storage::data_object_by_id(&dest_bag_id, 0u64).accepted());
+    });
+}
+
+fn upload_data_object_with_size(bag_id: BagId<Test>, cid_index: u8, size: u64) {
+    UploadFixture::default()
+        .with_params(UploadParameters::<Test> {
+            bag_id,
+            state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: create_data_object_candidates_with_size(cid_index, 1, size),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+            expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(),
+            ..Default::default()
+        })
+        .call_and_assert(Ok(()));
+}
+
+// Creates storage buckets for the council bag (operated by the default storage provider) and
+// the storage working group bag (operated by another storage provider) with the data size fee
+// of 200 per upload, half of which is paid to the storage operators.
+fn setup_storage_operators_earnings() -> (BagId<Test>, u64, BagId<Test>, u64) {
+    let council_bag_id: BagId<Test> = StaticBagId::Council.into();
+    let council_bucket_id = create_storage_bucket_and_assign_to_bag(
+        council_bag_id.clone(),
+        Some(DEFAULT_STORAGE_PROVIDER_ID),
+        10,
+        1000,
+    );
+
+    let wg_bag_id: BagId<Test> = StaticBagId::WorkingGroup(WorkingGroup::Storage).into();
+    let wg_bucket_id = create_storage_bucket_and_assign_to_bag(
+        wg_bag_id.clone(),
+        Some(ANOTHER_STORAGE_PROVIDER_ID),
+        10,
+        1000,
+    );
+
+    set_storage_operator_fee_share(Perbill::from_percent(50));
+    set_data_object_per_mega_byte_fee(200);
+    set_data_object_state_bloat_bond_value(10);
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    (council_bag_id, council_bucket_id, wg_bag_id, wg_bucket_id)
+}
+
+#[test]
+fn data_size_fee_burned_with_no_storage_used() {
+    build_test_externalities().execute_with(|| {
+        let (council_bag_id, council_bucket_id, _, _) = setup_storage_operators_earnings();
+
+        upload_data_object_with_size(council_bag_id, 1, 100);
+
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            init_module_acc_balance() + 10
+        );
+        assert_eq!(Storage::total_storage_buckets_size_used(), 100);
+        assert_eq!(
+            Storage::storage_bucket_unclaimed_earnings(&council_bucket_id),
+            0
+        );
+    });
+}
+
+#[test]
+fn data_size_fee_distributed_among_storage_buckets_by_used_size() {
+    build_test_externalities().execute_with(|| {
+        let (council_bag_id, council_bucket_id, wg_bag_id, wg_bucket_id) =
+            setup_storage_operators_earnings();
+
+        upload_data_object_with_size(council_bag_id.clone(), 1, 100);
+        // the council bucket is the only one storing data
+        upload_data_object_with_size(wg_bag_id, 2, 300);
+
+        assert_eq!(
+            Storage::storage_bucket_unclaimed_earnings(&council_bucket_id),
+            100
+        );
+        assert_eq!(Storage::storage_bucket_unclaimed_earnings(&wg_bucket_id), 0);
+
+        // the fee share is split 100:300 between the buckets
+        upload_data_object_with_size(council_bag_id, 3, 100);
+
+        assert_eq!(
+            Storage::storage_bucket_unclaimed_earnings(&council_bucket_id),
+            125
+        );
+        assert_eq!(
+            Storage::storage_bucket_unclaimed_earnings(&wg_bucket_id),
+            75
+        );
+        assert_eq!(Storage::total_storage_buckets_size_used(), 500);
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            init_module_acc_balance() + 3 * 10 + 200
+        );
+    });
+}
+
+#[test]
+fn claim_storage_bucket_earnings_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (council_bag_id, council_bucket_id, wg_bag_id, wg_bucket_id) =
+            setup_storage_operators_earnings();
+        upload_data_object_with_size(council_bag_id.clone(), 1, 100);
+        upload_data_object_with_size(wg_bag_id, 2, 300);
+        upload_data_object_with_size(council_bag_id, 3, 100);
+
+        ClaimStorageBucketEarningsFixture::new()
+            .with_storage_bucket_id(council_bucket_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketEarningsClaimed(
+            council_bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            125,
+        ));
+
+        ClaimStorageBucketEarningsFixture::new()
+            .with_worker_id(ANOTHER_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(wg_bucket_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketEarningsClaimed(
+            wg_bucket_id,
+            ANOTHER_STORAGE_PROVIDER_ID,
+            75,
+        ));
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            init_module_acc_balance() + 3 * 10
+        );
+    });
+}
+
+#[test]
+fn claim_storage_bucket_earnings_limited_by_earnings_fund() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (council_bag_id, council_bucket_id, _, _) = setup_storage_operators_earnings();
+        upload_data_object_with_size(council_bag_id.clone(), 1, 100);
+        upload_data_object_with_size(council_bag_id, 2, 100);
+
+        // earnings exceeding the paid fee share (e.g. accrued before the accrual was seeded)
+        <crate::StorageBucketEarningsById<Test>>::insert(
+            council_bucket_id,
+            crate::StorageBucketEarnings::<Test> {
+                unclaimed: 500,
+                earnings_per_byte_checkpoint: Storage::storage_earnings_per_byte(),
+            },
+        );
+        assert_eq!(Storage::storage_operators_earnings_fund(), 100);
+
+        ClaimStorageBucketEarningsFixture::new()
+            .with_storage_bucket_id(council_bucket_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketEarningsClaimed(
+            council_bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            100,
+        ));
+
+        // the state bloat bonds stay in the treasury
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            init_module_acc_balance() + 2 * 10
+        );
+
+        ClaimStorageBucketEarningsFixture::new()
+            .with_storage_bucket_id(council_bucket_id)
+            .call_and_assert(Err(Error::<Test>::NoStorageBucketEarningsToClaim.into()));
+    });
+}
+
+#[test]
+fn claim_storage_bucket_earnings_fails_with_no_earnings() {
+    build_test_externalities().execute_with(|| {
+        let (_, council_bucket_id, _, _) = setup_storage_operators_earnings();
+
+        ClaimStorageBucketEarningsFixture::new()
+            .with_storage_bucket_id(council_bucket_id)
+            .call_and_assert(Err(Error::<Test>::NoStorageBucketEarningsToClaim.into()));
+    });
+}
+
+#[test]
+fn claim_storage_bucket_earnings_fails_with_invalid_storage_provider() {
+    build_test_externalities().execute_with(|| {
+        let (council_bag_id, council_bucket_id, wg_bag_id, _) = setup_storage_operators_earnings();
+        upload_data_object_with_size(council_bag_id, 1, 100);
+        upload_data_object_with_size(wg_bag_id, 2, 300);
+
+        ClaimStorageBucketEarningsFixture::new()
+            .with_worker_id(ANOTHER_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(council_bucket_id)
+            .call_and_assert(Err(Error::<Test>::InvalidStorageProvider.into()));
+    });
+}
+
+#[test]
+fn claim_storage_bucket_earnings_fails_with_non_existing_storage_bucket() {
+    build_test_externalities().execute_with(|| {
+        let invalid_storage_bucket_id = 11000;
+
+        ClaimStorageBucketEarningsFixture::new()
+            .with_storage_bucket_id(invalid_storage_bucket_id)
+            .call_and_assert(Err(Error::<Test>::StorageBucketDoesntExist.into()));
+    });
+}
+
+// Uploads the data object (id 0) to the council bag stored by the single storage bucket
+// with the default storage provider. The data object is not accepted yet.
+fn setup_expirable_data_object() -> (u64, BagId<Test>) {
+    let bag_id: BagId<Test> = StaticBagId::Council.into();
+    let bucket_id = create_storage_bucket_and_assign_to_bag(
+        bag_id.clone(),
+        Some(DEFAULT_STORAGE_PROVIDER_ID),
+        10,
+        1000,
+    );
+
+    set_data_object_state_bloat_bond_value(10);
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+    upload_data_object_with_size(bag_id.clone(), 1, 100);
+
+    (bucket_id, bag_id)
+}
+
+fn run_to_data_object_acceptance_deadline_expiry() {
+    let deadline = Storage::data_object_acceptance_deadline_by_id(0u64)
+        .unwrap()
+        .deadline;
+
+    run_to_block(deadline + 1);
+}
+
+#[test]
+fn data_object_acceptance_deadline_set_on_upload() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        setup_expirable_data_object();
+
+        assert_eq!(
+            Storage::data_object_acceptance_deadline_by_id(0u64),
+            Some(DataObjectAcceptanceDeadline::<Test> {
+                deadline: starting_block + DataObjectAcceptancePeriod::get(),
+                state_bloat_bond_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            })
+        );
+    });
+}
+
+#[test]
+fn data_object_acceptance_deadline_removed_on_acceptance() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, bag_id) = setup_expirable_data_object();
+        accept_data_object(bucket_id, bag_id, 0);
+
+        assert!(Storage::data_object_acceptance_deadline_by_id(0u64).is_none());
+    });
+}
+
+#[test]
+fn delete_expired_data_objects_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, bag_id) = setup_expirable_data_object();
+        run_to_data_object_acceptance_deadline_expiry();
+
+        let data_objects = BTreeSet::from_iter(vec![0]);
+        let uploader_balance = Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID);
+
+        DeleteExpiredDataObjectsFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_data_objects(data_objects.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectsExpired(
+            bag_id.clone(),
+            data_objects.clone(),
+        ));
+
+        // the state bloat bond is repaid to the uploader
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            uploader_balance + 10
+        );
+        let bucket = Storage::storage_bucket_by_id(&bucket_id).unwrap();
+        assert_eq!(bucket.voucher.objects_used, 0);
+        assert_eq!(bucket.voucher.size_used, 0);
+        assert_eq!(
+            EXPIRED_DATA_OBJECTS.with(|expired| expired.borrow().clone()),
+            vec![(bag_id, data_objects)]
+        );
+    });
+}
+
+#[test]
+fn delete_expired_data_objects_fails_before_acceptance_deadline() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, bag_id) = setup_expirable_data_object();
+        run_to_block(1 + DataObjectAcceptancePeriod::get());
+
+        DeleteExpiredDataObjectsFixture::new()
+            .with_bag_id(bag_id)
+            .with_data_objects(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(
+                Error::<Test>::DataObjectAcceptanceDeadlineNotPassed.into()
+            ));
+    });
+}
+
+#[test]
+fn delete_expired_data_objects_fails_with_accepted_data_object() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, bag_id) = setup_expirable_data_object();
+        run_to_block(2 + DataObjectAcceptancePeriod::get());
+        accept_data_object(bucket_id, bag_id.clone(), 0);
+
+        DeleteExpiredDataObjectsFixture::new()
+            .with_bag_id(bag_id)
+            .with_data_objects(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(Error::<Test>::DataObjectAcceptanceDeadlineNotSet.into()));
+    });
+}
+
+#[test]
+fn delete_expired_data_objects_fails_with_empty_data_objects() {
+    build_test_externalities().execute_with(|| {
+        DeleteExpiredDataObjectsFixture::new()
+            .call_and_assert(Err(Error::<Test>::DataObjectIdCollectionIsEmpty.into()));
+    });
+}
+
+#[test]
+fn delete_expired_data_objects_fails_with_non_existing_data_object() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, bag_id) = setup_expirable_data_object();
+        run_to_data_object_acceptance_deadline_expiry();
+
+        DeleteExpiredDataObjectsFixture::new()
+            .with_bag_id(bag_id)
+            .with_data_objects(BTreeSet::from_iter(vec![0, 1]))
+            .call_and_assert(Err(Error::<Test>::DataObjectDoesntExist.into()));
+    });
+}
+
+#[test]
+fn delete_expired_data_objects_fails_with_already_expired_data_object() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, bag_id) = setup_expirable_data_object();
+        run_to_data_object_acceptance_deadline_expiry();
+
+        let fixture = DeleteExpiredDataObjectsFixture::new()
+            .with_bag_id(bag_id)
+            .with_data_objects(BTreeSet::from_iter(vec![0]));
+        fixture.call_and_assert(Ok(()));
+        fixture.call_and_assert(Err(Error::<Test>::DataObjectDoesntExist.into()));
+    });
+}
+
+#[test]
+fn deleting_expired_data_objects_by_bag_owner_is_noop() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, bag_id) = setup_expirable_data_object();
+        upload_data_object_with_size(bag_id.clone(), 2, 100);
+        run_to_data_object_acceptance_deadline_expiry();
+
+        DeleteExpiredDataObjectsFixture::new()
+            .with_bag_id(bag_id.clone())
+            .with_data_objects(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Ok(()));
+
+        assert_ok!(Storage::delete_data_objects(
+            DEFAULT_MEMBER_ACCOUNT_ID,
+            bag_id.clone(),
+            BTreeSet::from_iter(vec![0, 1]),
+        ));
+
+        let bag = Storage::bag(&bag_id);
+        assert_eq!(bag.objects_number, 0);
+        assert_eq!(bag.objects_total_size, 0);
+        assert!(!ExpiredDataObjects::<Test>::contains_key(&bag_id, 0));
+    });
+}
+
+fn upload_data_objects_with_same_cid(bag_id: BagId<Test>, cid_index: u32, number: usize) {
+    UploadFixture::default()
+        .with_params(UploadParameters::<Test> {
+            bag_id,
+            state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+            object_creation_list: repeat(DataObjectCreationParameters {
+                size: 1,
+                ipfs_content_id: create_cid(cid_index),
+                chunks_merkle_root: None,
+            })
+            .take(number)
+            .collect(),
+            expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+            expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(),
+            ..Default::default()
+        })
+        .call_and_assert(Ok(()));
+}
+
+fn blacklist_cid(cid_index: u32) {
+    UpdateBlacklistFixture::new()
+        .with_add_hashes(BTreeSet::from_iter(vec![create_cid(cid_index)]))
+        .call_and_assert(Ok(()));
+}
+
+fn bounded_cid(cid_index: u32) -> Base58Multihash {
+    create_cid(cid_index).try_into().unwrap()
+}
+
+#[test]
+fn update_blacklist_blocks_existing_data_objects() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, bag_id) = setup_expirable_data_object();
+        upload_data_object_with_size(bag_id.clone(), 2, 100);
+
+        assert_eq!(
+            Storage::data_object_bag_by_content_id(bounded_cid(1), 0),
+            Some(bag_id.clone())
+        );
+
+        blacklist_cid(1);
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectsBlocked(
+            bag_id.clone(),
+            BTreeSet::from_iter(vec![0]),
+        ));
+        assert!(Storage::data_object_by_id(&bag_id, 0).blocked);
+        assert!(!Storage::data_object_by_id(&bag_id, 1).blocked);
+        assert!(Storage::data_object_bag_by_content_id(bounded_cid(1), 0).is_none());
+        assert_eq!(
+            Storage::data_object_bag_by_content_id(bounded_cid(2), 1),
+            Some(bag_id)
+        );
+    });
+}
+
+#[test]
+fn update_blacklist_blocks_data_objects_in_every_bag() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, council_bag_id) = setup_expirable_data_object();
+        let wg_bag_id: BagId<Test> = StaticBagId::WorkingGroup(WorkingGroup::Storage).into();
+        create_storage_bucket_and_assign_to_bag(
+            wg_bag_id.clone(),
+            None,
+            DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT,
+            DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
+        );
+        upload_data_objects_with_same_cid(wg_bag_id.clone(), 1, 1);
+
+        blacklist_cid(1);
+
+        EventFixture::contains_crate_event(RawEvent::DataObjectsBlocked(
+            council_bag_id.clone(),
+            BTreeSet::from_iter(vec![0]),
+        ));
+        EventFixture::contains_crate_event(RawEvent::DataObjectsBlocked(
+            wg_bag_id.clone(),
+            BTreeSet::from_iter(vec![1]),
+        ));
+        assert!(Storage::data_object_by_id(&council_bag_id, 0).blocked);
+        assert!(Storage::data_object_by_id(&wg_bag_id, 1).blocked);
+    });
+}
+
+#[test]
+fn update_blacklist_removes_blocked_data_object_challenges() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, bag_id) = setup_challengeable_data_object();
+        assert_eq!(ChallengeableDataObjectBagById::<Test>::get(0), Some(bag_id));
+
+        blacklist_cid(1);
+
+        assert!(!ChallengeableDataObjectBagById::<Test>::contains_key(0));
+    });
+}
+
+#[test]
+fn block_blacklisted_data_objects_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        create_storage_bucket_and_assign_to_bag(bag_id.clone(), None, 100, 1000);
+        increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        let max_blocked = MaxBlacklistedDataObjectsPerCall::get() as usize;
+        upload_data_objects_with_same_cid(bag_id.clone(), 1, max_blocked + 1);
+
+        blacklist_cid(1);
+
+        let last_data_object_id = max_blocked as u64;
+        assert!(!Storage::data_object_by_id(&bag_id, last_data_object_id).blocked);
+
+        BlockBlacklistedDataObjectsFixture::new()
+            .with_content_id(create_cid(1))
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DataObjectsBlocked(
+            bag_id.clone(),
+            BTreeSet::from_iter(vec![last_data_object_id]),
+        ));
+        assert!(Storage::data_object_by_id(&bag_id, last_data_object_id).blocked);
+    });
+}
+
+#[test]
+fn block_blacklisted_data_objects_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        BlockBlacklistedDataObjectsFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .with_content_id(create_cid(1))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn block_blacklisted_data_objects_fails_with_not_blacklisted_content_id() {
+    build_test_externalities().execute_with(|| {
+        BlockBlacklistedDataObjectsFixture::new()
+            .with_content_id(create_cid(1))
+            .call_and_assert(Err(Error::<Test>::ContentIdNotBlacklisted.into()));
+    });
+}
+
+#[test]
+fn accept_pending_data_objects_fails_with_blocked_data_object() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, bag_id) = setup_expirable_data_object();
+        blacklist_cid(1);
+
+        AcceptPendingDataObjectsFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(bucket_id)
+            .with_bag_id(bag_id)
+            .with_data_object_ids(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(Error::<Test>::DataObjectBlocked.into()));
+    });
+}
+
+#[test]
+fn remove_blocked_data_objects_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (bucket_id, bag_id) = setup_expirable_data_object();
+        blacklist_cid(1);
+
+        let data_objects = BTreeSet::from_iter(vec![0]);
+        let lead_balance = Balances::usable_balance(&STORAGE_WG_LEADER_ACCOUNT_ID);
+        let member_balance = Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID);
+
+        RemoveBlockedDataObjectsFixture::new()
+            .with_bag_id(bag_id.clone())
+            .with_data_objects(data_objects.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::BlockedDataObjectsRemoved(
+            bag_id.clone(),
+            data_objects.clone(),
+        ));
+
+        // the state bloat bond is repaid to the uploader
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            member_balance + 10
+        );
+        assert_eq!(
+            Balances::usable_balance(&STORAGE_WG_LEADER_ACCOUNT_ID),
+            lead_balance
+        );
+        let bucket = Storage::storage_bucket_by_id(&bucket_id).unwrap();
+        assert_eq!(bucket.voucher.objects_used, 0);
+        assert_eq!(bucket.voucher.size_used, 0);
+        assert!(Storage::data_object_acceptance_deadline_by_id(0u64).is_none());
+        assert_eq!(
+            EXPIRED_DATA_OBJECTS.with(|expired| expired.borrow().clone()),
+            vec![(bag_id, data_objects)]
+        );
+    });
+}
+
+#[test]
+fn remove_blocked_data_objects_keeps_state_bloat_bond_with_no_recorded_payer() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, bag_id) = setup_expirable_data_object();
+        blacklist_cid(1);
+
+        // uploaded before the state bloat bond payers were recorded
+        DataObjectStateBloatBondAccountById::<Test>::remove(0u64);
+
+        let treasury_balance =
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id());
+        let lead_balance = Balances::usable_balance(&STORAGE_WG_LEADER_ACCOUNT_ID);
+        let member_balance = Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID);
+
+        RemoveBlockedDataObjectsFixture::new()
+            .with_bag_id(bag_id)
+            .with_data_objects(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&<StorageTreasury<Test>>::module_account_id()),
+            treasury_balance
+        );
+        assert_eq!(
+            Balances::usable_balance(&STORAGE_WG_LEADER_ACCOUNT_ID),
+            lead_balance
+        );
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_MEMBER_ACCOUNT_ID),
+            member_balance
+        );
+    });
+}
+
+#[test]
+fn remove_blocked_data_objects_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, bag_id) = setup_expirable_data_object();
+        blacklist_cid(1);
+
+        RemoveBlockedDataObjectsFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID))
+            .with_bag_id(bag_id)
+            .with_data_objects(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn remove_blocked_data_objects_fails_with_not_blocked_data_object() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, bag_id) = setup_expirable_data_object();
+
+        RemoveBlockedDataObjectsFixture::new()
+            .with_bag_id(bag_id)
+            .with_data_objects(BTreeSet::from_iter(vec![0]))
+            .call_and_assert(Err(Error::<Test>::DataObjectNotBlocked.into()));
+    });
+}
+
+#[test]
+fn remove_blocked_data_objects_fails_with_non_existing_data_object() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, bag_id) = setup_expirable_data_object();
+        blacklist_cid(1);
+
+        RemoveBlockedDataObjectsFixture::new()
+            .with_bag_id(bag_id)
+            .with_data_objects(BTreeSet::from_iter(vec![0, 1]))
+            .call_and_assert(Err(Error::<Test>::DataObjectDoesntExist.into()));
+    });
+}
+
+#[test]
+fn remove_blocked_data_objects_fails_with_empty_data_objects() {
+    build_test_externalities().execute_with(|| {
+        RemoveBlockedDataObjectsFixture::new()
+            .with_bag_id(StaticBagId::Council.into())
+            .call_and_assert(Err(Error::<Test>::DataObjectIdCollectionIsEmpty.into()));
+    });
+}
+
+#[test]
+fn remove_blocked_data_objects_fails_with_exceeded_data_objects_number() {
+    build_test_externalities().execute_with(|| {
+        let data_objects = (0..=MaxBlacklistedDataObjectsPerCall::get() as u64).collect();
+
+        RemoveBlockedDataObjectsFixture::new()
+            .with_bag_id(StaticBagId::Council.into())
+            .with_data_objects(data_objects)
+            .call_and_assert(Err(
+                Error::<Test>::MaxBlacklistedDataObjectsNumberLimitExceeded.into(),
+            ));
+    });
+}
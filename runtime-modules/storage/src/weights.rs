@@ -80,6 +80,15 @@ pub trait WeightInfo {
 	fn set_distribution_operator_metadata(_i: u32, ) -> Weight;
 	fn storage_operator_remark(_i: u32, ) -> Weight;
 	fn distribution_operator_remark(_i: u32, ) -> Weight;
+	fn respond_to_storage_challenge(_i: u32, _j: u32, ) -> Weight;
+	fn on_initialize_storage_challenges(_i: u32, ) -> Weight;
+	fn update_data_object_replication_target() -> Weight;
+	fn claim_storage_bucket_earnings() -> Weight;
+	fn delete_expired_data_objects(_i: u32, ) -> Weight;
+	fn rebalance_bags(_i: u32, _j: u32, ) -> Weight;
+	fn block_blacklisted_data_objects(_i: u32, ) -> Weight;
+	fn remove_blocked_data_objects(_i: u32, ) -> Weight;
+	fn on_initialize_data_objects_acceptance_removals(_i: u32, ) -> Weight;
 }
 
 /// Weights for storage using the Substrate node and recommended hardware.
@@ -88,10 +97,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
 	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
 	// Storage: Storage StorageBucketById (r:1 w:1)
+	// Storage: Storage StorageBucketEarningsById (r:1 w:1)
+	// Storage: Storage StorageEarningsPerByte (r:1 w:0)
+	// Storage: Storage StorageOperatorsEarningsFund (r:1 w:0)
+	// Storage: Storage StorageBucketNumber (r:1 w:1)
+	// Storage: Storage StorageOperatorMetadataByBucketId (r:0 w:1)
 	fn delete_storage_bucket() -> Weight {
 		(59_190_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 	}
 	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
 	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
@@ -162,12 +176,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
 	// Storage: Storage VoucherMaxObjectsSizeLimit (r:1 w:0)
 	// Storage: Storage VoucherMaxObjectsNumberLimit (r:1 w:0)
+	// Storage: Storage StorageBucketNumber (r:1 w:1)
 	// Storage: Storage NextStorageBucketId (r:1 w:1)
 	// Storage: Storage StorageBucketById (r:0 w:1)
 	fn create_storage_bucket() -> Weight {
 		(68_680_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(5 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
 	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
@@ -247,13 +262,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Storage StorageBucketById (r:1 w:0)
 	// Storage: Storage Bags (r:1 w:0)
 	// Storage: Storage DataObjectsById (r:1 w:1)
+	// Storage: Storage ChallengeableDataObjectBagById (r:0 w:1)
 	fn accept_pending_data_objects(i: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 19_000
-			.saturating_add((25_470_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add((26_950_000 as Weight).saturating_mul(i as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
 	}
 	// Storage: Instance9WorkingGroup CurrentLead (r:1 w:0)
 	// Storage: Instance9WorkingGroup WorkerById (r:1 w:0)
@@ -412,6 +428,145 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((931_000 as Weight).saturating_mul(i as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 	}
+	// Storage: Storage StorageChallengeById (r:1 w:1)
+	// Storage: Storage StorageBucketById (r:1 w:0)
+	// Storage: Storage DataObjectsById (r:1 w:0)
+	// Storage: Storage StorageBucketChallengeFailures (r:0 w:1)
+	fn respond_to_storage_challenge(i: u32, j: u32, ) -> Weight {
+		(31_845_000 as Weight)
+			// Standard Error: 0
+			.saturating_add((2_000 as Weight).saturating_mul(i as Weight))
+			// Standard Error: 12_000
+			.saturating_add((1_412_000 as Weight).saturating_mul(j as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Storage StorageChallengesByDeadline (r:1 w:2)
+	// Storage: Storage StorageChallengeById (r:1 w:1)
+	// Storage: Storage DataObjectsById (r:2 w:0)
+	// Storage: Storage StorageBucketById (r:2 w:0)
+	// Storage: Storage StorageBucketChallengeFailures (r:1 w:1)
+	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: Storage ChallengeableDataObjectBagById (r:2 w:0)
+	// Storage: Storage Bags (r:1 w:0)
+	// Storage: Storage NextStorageChallengeId (r:1 w:1)
+	fn on_initialize_storage_challenges(i: u32, ) -> Weight {
+		(131_542_000 as Weight)
+			// Standard Error: 41_000
+			.saturating_add((48_310_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(24 as Weight))
+			.saturating_add(T::DbWeight::get().reads((7 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(i as Weight)))
+	}
+	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
+	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Storage DataObjectReplicationTarget (r:0 w:1)
+	fn update_data_object_replication_target() -> Weight {
+		(45_861_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Storage StorageBucketById (r:1 w:0)
+	// Storage: Storage StorageBucketEarningsById (r:1 w:1)
+	// Storage: Storage StorageEarningsPerByte (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn claim_storage_bucket_earnings() -> Weight {
+		(72_413_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Storage Bags (r:2 w:1)
+	// Storage: Storage DataObjectsById (r:2 w:1)
+	// Storage: Storage DataObjectAcceptanceDeadlineById (r:1 w:1)
+	// Storage: Storage ExpiredDataObjects (r:1 w:2)
+	// Storage: Storage StorageBucketById (r:1 w:1)
+	// Storage: Storage StorageEarningsPerByte (r:1 w:0)
+	// Storage: Storage StorageBucketEarningsById (r:1 w:1)
+	// Storage: Storage TotalStorageBucketsSizeUsed (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Content ChannelById (r:1 w:1)
+	// Storage: Storage ChallengeableDataObjectBagById (r:0 w:1)
+	fn delete_expired_data_objects(i: u32, ) -> Weight {
+		(41_260_000 as Weight)
+			// Standard Error: 27_000
+			.saturating_add((68_910_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((11 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((11 as Weight).saturating_mul(i as Weight)))
+	}
+	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
+	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Storage StorageBucketById (r:81 w:2)
+	// Storage: Storage Bags (r:1 w:1)
+	// Storage: Storage StorageEarningsPerByte (r:1 w:0)
+	// Storage: Storage StorageBucketEarningsById (r:2 w:2)
+	// Storage: Storage TotalStorageBucketsSizeUsed (r:1 w:1)
+	// Storage: Storage DataObjectsAcceptanceRemovalByBagId (r:1 w:1)
+	fn rebalance_bags(i: u32, j: u32, ) -> Weight {
+		(0 as Weight)
+			// Standard Error: 212_000
+			.saturating_add((97_645_000 as Weight).saturating_mul(i as Weight))
+			// Standard Error: 21_000
+			.saturating_add((9_417_000 as Weight).saturating_mul(j as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((7 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(j as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((8 as Weight).saturating_mul(i as Weight)))
+	}
+	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
+	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Storage Blacklist (r:1 w:0)
+	// Storage: Storage DataObjectBagByContentId (r:1 w:1)
+	// Storage: Storage ChallengeableDataObjectBagById (r:0 w:1)
+	// Storage: Storage DataObjectsById (r:1 w:1)
+	fn block_blacklisted_data_objects(i: u32, ) -> Weight {
+		(30_120_000 as Weight)
+			// Standard Error: 14_000
+			.saturating_add((24_530_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(i as Weight)))
+	}
+	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
+	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
+	// Storage: Storage Bags (r:1 w:1)
+	// Storage: Storage DataObjectsById (r:1 w:1)
+	// Storage: Storage StorageBucketById (r:1 w:1)
+	// Storage: Storage ChallengeableDataObjectBagById (r:0 w:1)
+	// Storage: Storage DataObjectAcceptanceDeadlineById (r:0 w:1)
+	// Storage: Storage DataObjectStateBloatBondAccountById (r:1 w:1)
+	// Storage: Storage DataObjectBagByContentId (r:0 w:1)
+	// Storage: Storage ExpiredDataObjects (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn remove_blocked_data_objects(i: u32, ) -> Weight {
+		(52_370_000 as Weight)
+			// Standard Error: 25_000
+			.saturating_add((61_480_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(i as Weight)))
+	}
+	// Storage: Storage DataObjectsAcceptanceRemovalByBagId (r:1 w:1)
+	// Storage: Storage DataObjectReplicationTarget (r:1 w:0)
+	// Storage: Storage DataObjectsById (r:1 w:1)
+	// Storage: Storage ChallengeableDataObjectBagById (r:0 w:1)
+	fn on_initialize_data_objects_acceptance_removals(i: u32, ) -> Weight {
+		(12_410_000 as Weight)
+			// Standard Error: 9_000
+			.saturating_add((21_340_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(i as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(i as Weight)))
+	}
 }
 
 // Default implementation for tests
@@ -521,4 +676,31 @@ impl WeightInfo for () {
 	fn distribution_operator_remark(i: u32, ) -> Weight {
 		0
 	}
+	fn respond_to_storage_challenge(i: u32, j: u32, ) -> Weight {
+		0
+	}
+	fn on_initialize_storage_challenges(i: u32, ) -> Weight {
+		0
+	}
+	fn update_data_object_replication_target() -> Weight {
+		0
+	}
+	fn claim_storage_bucket_earnings() -> Weight {
+		0
+	}
+	fn delete_expired_data_objects(i: u32, ) -> Weight {
+		0
+	}
+	fn rebalance_bags(i: u32, j: u32, ) -> Weight {
+		0
+	}
+	fn block_blacklisted_data_objects(i: u32, ) -> Weight {
+		0
+	}
+	fn remove_blocked_data_objects(i: u32, ) -> Weight {
+		0
+	}
+	fn on_initialize_data_objects_acceptance_removals(i: u32, ) -> Weight {
+		0
+	}
 }
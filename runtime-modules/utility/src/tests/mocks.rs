@@ -10,6 +10,7 @@ use frame_support::{
     traits::{ConstU16, ConstU32, ConstU64, EnsureOneOf, LockIdentifier, OnFinalize, OnInitialize},
 };
 use frame_system::{EnsureRoot, EnsureSigned, EventRecord, RawOrigin};
+use referendum::BallotMode;
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -417,6 +418,9 @@ parameter_types! {
     pub const MaxSaltLength: u64 = 32; // use some multiple of 8 for ez testing
     pub const VotingLockId: LockIdentifier = *b"referend";
     pub const MaxWinnerTargetCount: u32 = 10;
+    pub const MaxVoteLockPeriod: u32 = 6;
+    pub const ReferendumBallotMode: BallotMode = BallotMode::FirstPastThePost;
+    pub const MaxRankedOptions: u32 = 5;
 }
 
 impl referendum::Config<ReferendumInstance> for Test {
@@ -430,10 +434,14 @@ impl referendum::Config<ReferendumInstance> for Test {
     type MinimumStake = MinimumVotingStake;
     type WeightInfo = ();
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxVoteLockPeriod = MaxVoteLockPeriod;
+    type BallotMode = ReferendumBallotMode;
+    type MaxRankedOptions = MaxRankedOptions;
 
     fn calculate_vote_power(
         _: &<Self as frame_system::Config>::AccountId,
         _: &Self::Balance,
+        _: u32,
     ) -> Self::VotePower {
         1
     }
@@ -589,6 +597,10 @@ impl common::council::CouncilOriginValidator<Origin, u64, u64> for () {
 
         Ok(())
     }
+
+    fn is_council_member(_: &u64) -> bool {
+        true
+    }
 }
 
 impl common::membership::MembershipTypes for Test {
@@ -1669,6 +1669,18 @@ impl<T: Config<I>, I: Instance> common::working_group::WorkingGroupAuthenticator
     }
 }
 
+impl<T: Config<I>, I: Instance> common::working_group::WorkingGroupStakeSlasher<T, BalanceOf<T>>
+    for Module<T, I>
+{
+    fn slash_worker_stake(worker_id: &WorkerId<T>, penalty: BalanceOf<T>) -> DispatchResult {
+        let worker = checks::ensure_worker_exists::<T, I>(worker_id)?;
+
+        Self::slash(*worker_id, &worker.staking_account_id, penalty, None);
+
+        Ok(())
+    }
+}
+
 impl<T: Config<I>, I: Instance>
     common::working_group::WorkingGroupBudgetHandler<T::AccountId, BalanceOf<T>> for Module<T, I>
 {
@@ -46,7 +46,18 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
         proposal_details: ProposalDetailsOf<Runtime>,
         member_controller_account: <Runtime as frame_system::Config>::AccountId,
     ) -> Vec<u8> {
-        let call = match proposal_details {
+        ExtrinsicProposalEncoder::proposal_call(proposal_details, member_controller_account)
+            .encode()
+    }
+}
+
+impl ExtrinsicProposalEncoder {
+    // Creates the runtime call executing the proposal with the provided details.
+    fn proposal_call(
+        proposal_details: ProposalDetailsOf<Runtime>,
+        member_controller_account: <Runtime as frame_system::Config>::AccountId,
+    ) -> Call {
+        match proposal_details {
             ProposalDetails::Signal(signal) => {
                 Call::JoystreamUtility(joystream_utility::Call::execute_signal_proposal { signal })
             }
@@ -159,9 +170,30 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                     uploader_account: member_controller_account,
                 })
             }
-        };
-
-        call.encode()
+            ProposalDetails::RemoveCouncilor(councilor_id, slashing_amount) => {
+                Call::Council(council::Call::remove_councilor {
+                    councilor_id,
+                    slashing_amount,
+                })
+            }
+            ProposalDetails::UpdateProposalParameters(proposal_kind, proposal_parameters) => {
+                Call::ProposalsCodex(proposals_codex::Call::update_proposal_parameters {
+                    proposal_kind,
+                    proposal_parameters,
+                })
+            }
+            ProposalDetails::Batch(batch) => {
+                // `batch_all` reverts every call of the batch if any of them fails
+                Call::Utility(substrate_utility::Call::batch_all {
+                    calls: batch
+                        .into_iter()
+                        .map(|proposal_details| {
+                            Self::proposal_call(proposal_details, member_controller_account.clone())
+                        })
+                        .collect(),
+                })
+            }
+        }
     }
 }
 
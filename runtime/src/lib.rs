@@ -122,7 +122,7 @@ use integration::proposals::{CouncilManager, ExtrinsicProposalEncoder};
 use common::working_group::{WorkingGroup, WorkingGroupBudgetHandler};
 use council::ReferendumConnection;
 use pallet_staking::EraPayout;
-use referendum::{CastVote, OptionResult};
+use referendum::{BallotMode, CastVote, OptionResult};
 use staking_handler::{LockComparator, StakingManager};
 
 // Node dependencies
@@ -130,9 +130,11 @@ pub use common;
 pub use council;
 pub use forum;
 pub use membership;
+pub use project_token;
 
 pub use proposals_engine::ProposalParameters;
 pub use referendum;
+pub use storage;
 pub use working_group;
 
 pub use content;
@@ -145,10 +147,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 12,
-    spec_version: 2001,
+    spec_version: 2002,
     impl_version: 0,
     apis: crate::runtime_api::EXPORTED_RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -813,6 +815,15 @@ parameter_types! {
     pub const MinimumCashoutAllowedLimit: Balance = dollars!(10);
     pub const MaximumCashoutAllowedLimit: Balance = dollars!(100_000);
     pub const MaxNftAuctionWhitelistLength: MaxNumber = 20;
+    pub const MaxNftsPerBatchIssuance: MaxNumber = 50;
+    pub const MaxRoyaltySplitRecipients: MaxNumber = 10;
+    pub const MaxVideosPerPlaylist: MaxNumber = 100;
+    pub const MaxPlaylistsPerChannel: MaxNumber = 20;
+    pub const MaxVideoVisibilityChangesPerBlock: MaxNumber = 20;
+    pub const MaxSubscriptionTiersPerChannel: MaxNumber = 5;
+    pub const MaxSubscriptionPaymentsPerBlock: MaxNumber = 50;
+    pub const MinSubscriptionPeriod: BlockNumber = DAYS;
+    pub const MinSubscriptionPrice: Balance = dollars!(1);
 
     // Channel bloat bond related:
     pub ChannelCleanupTxFee: Balance = compute_fee(
@@ -849,6 +860,34 @@ parameter_types! {
         DefaultStorageDepositCleanupProfit::get()
     );
 
+    // Playlist bloat bond related:
+    pub PlaylistCleanupTxFee: Balance = compute_fee(
+        Call::Content(content::Call::<Runtime>::delete_playlist {
+            actor: Default::default(),
+            playlist_id: 0,
+        })
+    );
+    pub PlaylistEntryMaxSize: u32 = map_entry_max_size::<content::PlaylistById::<Runtime>>();
+    pub PlaylistStateBloatBondValue: Balance = single_bloat_bond_with_cleanup(
+        PlaylistEntryMaxSize::get(),
+        PlaylistCleanupTxFee::get(),
+        DefaultStorageDepositCleanupProfit::get()
+    );
+
+    // Subscription bloat bond related:
+    pub SubscriptionCleanupTxFee: Balance = compute_fee(
+        Call::Content(content::Call::<Runtime>::unsubscribe_from_channel {
+            member_id: 0,
+            subscription_id: 0,
+        })
+    );
+    pub SubscriptionEntryMaxSize: u32 = map_entry_max_size::<content::SubscriptionById::<Runtime>>();
+    pub SubscriptionStateBloatBondValue: Balance = single_bloat_bond_with_cleanup(
+        SubscriptionEntryMaxSize::get(),
+        SubscriptionCleanupTxFee::get(),
+        DefaultStorageDepositCleanupProfit::get()
+    );
+
     // TODO: Adjust those?
     pub const MaxNumberOfAssetsPerChannel: MaxNumber = 10;
     pub const MaxNumberOfAssetsPerVideo: MaxNumber = 20;
@@ -859,6 +898,9 @@ impl content::Config for Runtime {
     type Event = Event;
     type VideoId = VideoId;
     type OpenAuctionId = OpenAuctionId;
+    type NftCollectionId = NftCollectionId;
+    type PlaylistId = PlaylistId;
+    type SubscriptionId = SubscriptionId;
     type MaxNumberOfCuratorsPerGroup = MaxNumberOfCuratorsPerGroup;
     type DataObjectStorage = Storage;
     type WeightInfo = content::weights::SubstrateWeight<Runtime>;
@@ -880,6 +922,15 @@ impl content::Config for Runtime {
     type MinimumCashoutAllowedLimit = MinimumCashoutAllowedLimit;
     type MaximumCashoutAllowedLimit = MaximumCashoutAllowedLimit;
     type MaxNftAuctionWhitelistLength = MaxNftAuctionWhitelistLength;
+    type MaxNftsPerBatchIssuance = MaxNftsPerBatchIssuance;
+    type MaxRoyaltySplitRecipients = MaxRoyaltySplitRecipients;
+    type MaxVideosPerPlaylist = MaxVideosPerPlaylist;
+    type MaxPlaylistsPerChannel = MaxPlaylistsPerChannel;
+    type MaxVideoVisibilityChangesPerBlock = MaxVideoVisibilityChangesPerBlock;
+    type MaxSubscriptionTiersPerChannel = MaxSubscriptionTiersPerChannel;
+    type MaxSubscriptionPaymentsPerBlock = MaxSubscriptionPaymentsPerBlock;
+    type MinSubscriptionPeriod = MinSubscriptionPeriod;
+    type MinSubscriptionPrice = MinSubscriptionPrice;
 }
 
 parameter_types! {
@@ -1019,6 +1070,17 @@ parameter_types! {
     pub const BudgetRefillPeriod: BlockNumber = 6;
 }
 
+// Vote stake lock period is measured in council election cycles
+parameter_types! {
+    pub const MaxVoteLockPeriod: u32 = 6;
+}
+
+// Council members are elected with the single transferable vote
+parameter_types! {
+    pub const ReferendumBallotMode: BallotMode = BallotMode::SingleTransferableVote;
+    pub const MaxRankedOptions: u32 = 10;
+}
+
 impl referendum::Config<ReferendumInstance> for Runtime {
     type Event = Event;
     type MaxSaltLength = MaxSaltLength;
@@ -1030,12 +1092,18 @@ impl referendum::Config<ReferendumInstance> for Runtime {
     type MinimumStake = MinimumVotingStake;
     type WeightInfo = referendum::weights::SubstrateWeight<Runtime>;
     type MaxWinnerTargetCount = MaxWinnerTargetCount;
+    type MaxVoteLockPeriod = MaxVoteLockPeriod;
+    type BallotMode = ReferendumBallotMode;
+    type MaxRankedOptions = MaxRankedOptions;
 
+    // The stake locked for `n` additional council election cycles has `n + 1` times
+    // the vote power.
     fn calculate_vote_power(
         _account_id: &<Self as frame_system::Config>::AccountId,
         stake: &Balance,
+        lock_period: u32,
     ) -> Self::VotePower {
-        *stake
+        stake.saturating_mul(Balance::from(lock_period).saturating_add(1))
     }
 
     fn can_unlock_vote_stake(vote: &CastVote<Self::Hash, Balance, Self::MemberId>) -> bool {
@@ -1106,6 +1174,18 @@ parameter_types! {
     pub const MaxDistributionBucketsPerBag: u32 = 51;
     pub const MaxDataObjectSize: u64 = giga_bytes!(60);
     pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 20; // TODO: adjust value
+    pub const MaxStorageChallengesPerBlock: u32 = 5;
+    pub const StorageChallengeResponsePeriod: BlockNumber = 10 * MINUTES;
+    pub const MaxStorageChallengeFailures: u32 = 3;
+    pub const StorageChallengeSlashPenalty: Balance = dollars!(100);
+    pub const DataObjectChunkSize: u64 = mega_bytes!(1);
+    pub const StorageOperatorFeeShare: Perbill = Perbill::from_percent(50);
+    pub const DataObjectAcceptancePeriod: BlockNumber = 7 * DAYS;
+    pub const MaxStorageBucketNumber: u64 = 200;
+    pub const MaxRebalancedBagsNumber: u32 = 100;
+    pub const MaxBlacklistedDataObjectsPerCall: u32 = 100;
+    pub const MaxDataObjectsAcceptanceRemovalsPerBlock: u32 = 500;
+    pub const MaxDataObjectsMigratedPerBlock: u32 = 1000;
 
     // Data object bloat bond related:
     // To calculate the cost of removing a data object we substract the cost of removing a video
@@ -1187,6 +1267,20 @@ impl storage::Config for Runtime {
     type StorageWorkingGroup = StorageWorkingGroup;
     type DistributionWorkingGroup = DistributionWorkingGroup;
     type ModuleAccountInitialBalance = ExistentialDeposit;
+    type Randomness = RandomnessCollectiveFlip;
+    type MaxStorageChallengesPerBlock = MaxStorageChallengesPerBlock;
+    type StorageChallengeResponsePeriod = StorageChallengeResponsePeriod;
+    type MaxStorageChallengeFailures = MaxStorageChallengeFailures;
+    type StorageChallengeSlashPenalty = StorageChallengeSlashPenalty;
+    type DataObjectChunkSize = DataObjectChunkSize;
+    type StorageOperatorFeeShare = StorageOperatorFeeShare;
+    type DataObjectAcceptancePeriod = DataObjectAcceptancePeriod;
+    type ExpiredDataObjectsHandler = Content;
+    type MaxStorageBucketNumber = MaxStorageBucketNumber;
+    type MaxRebalancedBagsNumber = MaxRebalancedBagsNumber;
+    type MaxBlacklistedDataObjectsPerCall = MaxBlacklistedDataObjectsPerCall;
+    type MaxDataObjectsAcceptanceRemovalsPerBlock = MaxDataObjectsAcceptanceRemovalsPerBlock;
+    type MaxDataObjectsMigratedPerBlock = MaxDataObjectsMigratedPerBlock;
 }
 
 impl common::membership::MembershipTypes for Runtime {
@@ -1520,6 +1614,7 @@ parameter_types! {
     pub const ProposalRejectionFee: Balance = dollars!(5);
     pub const ProposalMaxActiveProposalLimit: u32 = 20;
     pub const DispatchableCallCodeMaxLen: u32 = mega_bytes!(3);
+    pub const ProposalMaxVoteDelegationPeriod: BlockNumber = days!(30);
 }
 
 #[cfg(not(feature = "runtime-benchmarks"))]
@@ -1552,6 +1647,7 @@ impl proposals_engine::Config for Runtime {
     type WeightInfo = proposals_engine::weights::SubstrateWeight<Runtime>;
     type StakingAccountValidator = Members;
     type DispatchableCallCodeMaxLen = DispatchableCallCodeMaxLen;
+    type MaxVoteDelegationPeriod = ProposalMaxVoteDelegationPeriod;
 }
 
 impl Default for Call {
@@ -1634,6 +1730,8 @@ parameter_types! {
     pub const FundingRequestProposalMaxTotalAmount: Balance = dollars!(10_000);
     pub const FundingRequestProposalMaxAccounts: u32 = 20;
     pub const SetMaxValidatorCountProposalMaxValidators: u32 = 100;
+    pub const BatchProposalMaxProposals: u32 = 10;
+    pub const MaxProposalAmendments: u32 = 5;
 }
 
 const_assert!(
@@ -1675,9 +1773,13 @@ impl proposals_codex::Config for Runtime {
     type VetoProposalProposalParameters = VetoProposalProposalParameters;
     type UpdateGlobalNftLimitProposalParameters = UpdateGlobalNftLimitProposalParameters;
     type UpdateChannelPayoutsProposalParameters = UpdateChannelPayoutsProposalParameters;
+    type RemoveCouncilorProposalParameters = RemoveCouncilorProposalParameters;
+    type UpdateProposalParametersProposalParameters = UpdateProposalParametersProposalParameters;
     type FundingRequestProposalMaxTotalAmount = FundingRequestProposalMaxTotalAmount;
     type FundingRequestProposalMaxAccounts = FundingRequestProposalMaxAccounts;
     type SetMaxValidatorCountProposalMaxValidators = SetMaxValidatorCountProposalMaxValidators;
+    type BatchProposalMaxProposals = BatchProposalMaxProposals;
+    type MaxProposalAmendments = MaxProposalAmendments;
     type WeightInfo = proposals_codex::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1689,6 +1791,7 @@ impl pallet_constitution::Config for Runtime {
 parameter_types! {
     pub const BountyModuleId: PalletId = PalletId(*b"m:bounty"); // module : bounty
     pub const ClosedContractSizeLimit: u32 = 50;
+    pub const MaxMilestonesPerBounty: u32 = 10;
 
     // Bounty work entry stake related:
     pub BountyWorkEntryCleanupTxFee: Balance = compute_fee(
@@ -1747,6 +1850,7 @@ impl bounty::Config for Runtime {
     type MinWorkEntrantStake = MinWorkEntrantStake;
     type FunderStateBloatBondAmount = FunderStateBloatBondAmount;
     type CreatorStateBloatBondAmount = CreatorStateBloatBondAmount;
+    type MaxMilestonesPerBounty = MaxMilestonesPerBounty;
 }
 
 parameter_types! {
@@ -1853,7 +1957,7 @@ construct_runtime!(
         // --- Proposals
         ProposalsEngine: proposals_engine::{Pallet, Call, Storage, Event<T>},
         ProposalsDiscussion: proposals_discussion::{Pallet, Call, Storage, Event<T>, Config},
-        ProposalsCodex: proposals_codex::{Pallet, Call, Storage, Event<T>},
+        ProposalsCodex: proposals_codex::{Pallet, Call, Storage, Event<T>, Config},
         // --- Working groups
         ForumWorkingGroup: working_group::<Instance1>::{Pallet, Call, Storage, Event<T>},
         StorageWorkingGroup: working_group::<Instance2>::{Pallet, Call, Storage, Event<T>},
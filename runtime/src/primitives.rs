@@ -54,6 +54,15 @@ pub type VideoId = u64;
 /// Content Directory Open Auction identifier.
 pub type OpenAuctionId = u64;
 
+/// Content Directory Nft Collection identifier.
+pub type NftCollectionId = u64;
+
+/// Content Directory Playlist identifier.
+pub type PlaylistId = u64;
+
+/// Content Directory channel subscription identifier.
+pub type SubscriptionId = u64;
+
 /// Curator group identifier.
 pub type CuratorGroupId = u64;
 
@@ -106,4 +106,10 @@ parameter_types! {
 
     pub UpdateChannelPayoutsProposalParameters: ProposalParameters<BlockNumber, Balance> =
         update_channel_payouts_proposal();
+
+    pub RemoveCouncilorProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        remove_councilor_proposal();
+
+    pub UpdateProposalParametersProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        update_proposal_parameters_proposal();
 }
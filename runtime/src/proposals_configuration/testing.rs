@@ -331,3 +331,31 @@ pub(crate) fn update_channel_payouts_proposal() -> ProposalParameters<BlockNumbe
         constitutionality: 1,
     }
 }
+
+// Proposal parameters for the 'Remove Councilor' proposal
+pub(crate) fn remove_councilor_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 30,
+        grace_period: 0,
+        approval_quorum_percentage: 66,
+        approval_threshold_percentage: 66,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(currency::DOLLARS.saturating_mul(100)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Update Proposal Parameters' proposal
+pub(crate) fn update_proposal_parameters_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: 100,
+        grace_period: 40,
+        approval_quorum_percentage: 80,
+        approval_threshold_percentage: 100,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(currency::DOLLARS.saturating_mul(1000)),
+        constitutionality: 2,
+    }
+}
@@ -12,10 +12,13 @@ use sp_runtime::{generic, ApplyExtrinsicResult};
 use sp_std::vec::Vec;
 
 use crate::{
-    AccountId, AllPalletsWithSystem, AuthorityDiscovery, AuthorityDiscoveryId, Babe, Balance,
-    BlockNumber, Call, EpochDuration, Grandpa, GrandpaAuthorityList, GrandpaId, Historical, Index,
-    InherentDataExt, ProposalsEngine, Runtime, RuntimeVersion, SessionKeys, Signature, System,
-    TransactionPayment, BABE_GENESIS_EPOCH_CONFIG, VERSION,
+    AccountId, ActorId, AllPalletsWithSystem, AuthorityDiscovery, AuthorityDiscoveryId, Babe,
+    Balance, BlockNumber, Bounty, Call, ChannelId, Content, Council, DataObjectId,
+    DistributionBucketFamilyId, DistributionBucketIndex, EpochDuration, Grandpa,
+    GrandpaAuthorityList, GrandpaId, Hash, Historical, Index, InherentDataExt, MemberId,
+    ProjectToken, ProposalsEngine, Referendum, Runtime, RuntimeVersion, SessionKeys, Signature,
+    Storage, StorageBucketId, System, TokenId, TransactionPayment, BABE_GENESIS_EPOCH_CONFIG,
+    VERSION,
 };
 
 use frame_support::weights::Weight;
@@ -65,7 +68,18 @@ impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
     fn on_runtime_upgrade() -> Weight {
         ProposalsEngine::cancel_active_and_pending_proposals();
 
-        10_000_000 // TODO: adjust weight
+        // Storage migrations of this release
+        let migrations_weight = ProjectToken::migrate_token_info_to_amm_layout()
+            .saturating_add(Storage::migrate_data_objects_to_per_bucket_acceptance())
+            .saturating_add(Storage::migrate_storage_buckets_earnings())
+            .saturating_add(Storage::migrate_storage_bucket_number())
+            .saturating_add(Referendum::migrate_votes_to_lock_period_layout())
+            .saturating_add(Bounty::migrate_bounties_to_milestones_layout())
+            .saturating_add(Content::migrate_channels_to_playlists_layout())
+            .saturating_add(Content::migrate_video_nfts_to_creator_token_layout())
+            .saturating_add(Content::migrate_open_auction_bids_to_creator_token_layout());
+
+        migrations_weight.saturating_add(10_000_000) // TODO: adjust weight
     }
 }
 
@@ -286,6 +300,145 @@ impl_runtime_apis! {
         }
     }
 
+    impl council::runtime_api::CouncilApi<
+        Block,
+        AccountId,
+        MemberId,
+        Balance,
+        BlockNumber,
+        Hash,
+        Balance,
+    > for Runtime {
+        fn stage() -> council::CouncilStageUpdate<BlockNumber> {
+            Council::stage()
+        }
+
+        fn candidates() -> Vec<(MemberId, council::CandidateOf<Runtime>)> {
+            Council::current_candidates()
+        }
+
+        fn councilors() -> Vec<council::CouncilMemberOf<Runtime>> {
+            Council::council_members().into_inner()
+        }
+
+        fn budget() -> Balance {
+            Council::budget()
+        }
+
+        fn next_budget_refill() -> BlockNumber {
+            Council::next_budget_refill()
+        }
+    }
+
+    impl referendum::runtime_api::ReferendumApi<
+        Block,
+        AccountId,
+        MemberId,
+        Balance,
+        BlockNumber,
+        Hash,
+        Balance,
+    > for Runtime {
+        fn stage() -> referendum::ReferendumStage<
+            BlockNumber,
+            Vec<referendum::OptionResult<MemberId, Balance>>,
+        > {
+            Referendum::current_stage()
+        }
+
+        fn intermediate_winners() -> Vec<referendum::OptionResult<MemberId, Balance>> {
+            Referendum::intermediate_winners()
+        }
+
+        fn vote_of(account_id: AccountId) -> Option<referendum::CastVote<Hash, Balance, MemberId>> {
+            Referendum::vote_of(&account_id)
+        }
+    }
+
+    impl proposals_engine::runtime_api::ProposalsEngineApi<
+        Block,
+        AccountId,
+        MemberId,
+        u32,
+        BlockNumber,
+        Hash,
+    > for Runtime {
+        fn proposal_votes(
+            proposal_id: u32,
+        ) -> Vec<(MemberId, proposals_engine::VoteRecord<AccountId, Hash>)> {
+            ProposalsEngine::proposal_votes(proposal_id)
+        }
+
+        fn vote_delegation(
+            councilor_id: MemberId,
+        ) -> Option<proposals_engine::VoteDelegation<AccountId, BlockNumber>> {
+            ProposalsEngine::vote_delegation_by_councilor(councilor_id)
+        }
+    }
+
+    impl project_token::runtime_api::ProjectTokenApi<
+        Block,
+        TokenId,
+        MemberId,
+        Balance,
+        Balance,
+        BlockNumber,
+    > for Runtime {
+        fn quote_sale_purchase(
+            token_id: TokenId,
+            member_id: MemberId,
+            amount: Balance,
+        ) -> Result<
+            project_token::types::SalePurchaseQuote<Balance, Balance, BlockNumber>,
+            sp_runtime::DispatchError,
+        > {
+            ProjectToken::quote_sale_purchase(token_id, member_id, amount)
+        }
+
+        fn quote_split_dividend(
+            token_id: TokenId,
+            staked_amount: Balance,
+        ) -> Result<Balance, sp_runtime::DispatchError> {
+            ProjectToken::quote_split_dividend(token_id, staked_amount)
+        }
+    }
+
+    impl storage::runtime_api::StorageApi<
+        Block,
+        AccountId,
+        Balance,
+        MemberId,
+        ChannelId,
+        DataObjectId,
+        StorageBucketId,
+        DistributionBucketFamilyId,
+        DistributionBucketIndex,
+        ActorId,
+    > for Runtime {
+        fn bag_info(
+            bag_id: storage::BagId<Runtime>,
+        ) -> Result<storage::BagInfoOf<Runtime>, sp_runtime::DispatchError> {
+            Storage::bag_info(bag_id)
+        }
+
+        fn bag_data_objects(
+            bag_id: storage::BagId<Runtime>,
+            start_after: Option<DataObjectId>,
+            limit: u32,
+        ) -> Result<
+            Vec<(DataObjectId, storage::DataObjectInfoOf<Runtime>)>,
+            sp_runtime::DispatchError,
+        > {
+            Storage::bag_data_objects(bag_id, start_after, limit)
+        }
+
+        fn upload_fee_quote(
+            objects: Vec<storage::DataObjectCreationParameters>,
+        ) -> Result<storage::UploadFeeQuoteOf<Runtime>, sp_runtime::DispatchError> {
+            Storage::upload_fee_quote(objects)
+        }
+    }
+
     impl sp_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             SessionKeys::generate(seed)
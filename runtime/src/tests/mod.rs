@@ -144,6 +144,7 @@ pub(crate) fn setup_new_council(cycle_id: u64) {
             RawOrigin::Signed(voter.clone()).into(),
             commitment,
             voter_stake,
+            0,
         )
         .unwrap();
     }
@@ -313,6 +314,10 @@ pub(crate) fn max_proposal_stake() -> Balance {
         .push(<Runtime as proposals_codex::Config>::UpdateChannelPayoutsProposalParameters::get());
     stakes
         .push(<Runtime as proposals_codex::Config>::UpdateGlobalNftLimitProposalParameters::get());
+    stakes.push(<Runtime as proposals_codex::Config>::RemoveCouncilorProposalParameters::get());
+    stakes.push(
+        <Runtime as proposals_codex::Config>::UpdateProposalParametersProposalParameters::get(),
+    );
 
     stakes
         .iter()
@@ -985,6 +985,52 @@ fn set_referral_cut_proposal_succeeds() {
     });
 }
 
+#[test]
+fn batch_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        // start at block 1
+        run_to_block(1);
+
+        let member_id = create_new_members(1)[0];
+        let account_id = account_from_member_id(member_id);
+        let membership_price = Membership::membership_price() + 100;
+        let referral_cut = Membership::referral_cut() + 1;
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            let general_proposal_parameters = GeneralProposalParameters::<Runtime> {
+                member_id,
+                title: b"title".to_vec(),
+                description: b"body".to_vec(),
+                staking_account_id: Some(account_id.clone()),
+                exact_execution_block: None,
+            };
+
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(account_id.clone()).into(),
+                general_proposal_parameters,
+                ProposalDetails::Batch(vec![
+                    ProposalDetails::SetMembershipPrice(membership_price),
+                    ProposalDetails::SetReferralCut(referral_cut),
+                ]),
+            )
+        })
+        .with_member_id(member_id as u64);
+
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        let grace_period = sp_std::cmp::max(
+            <Runtime as proposals_codex::Config>::SetMembershipPriceProposalParameters::get()
+                .grace_period,
+            <Runtime as proposals_codex::Config>::SetReferralCutProposalParameters::get()
+                .grace_period,
+        );
+        run_to_block(System::block_number() + grace_period + 1);
+
+        assert_eq!(Membership::membership_price(), membership_price);
+        assert_eq!(Membership::referral_cut(), referral_cut);
+    });
+}
+
 // TODO:
 // We ignore this test because it needs to be re-written to take into account
 // that constitutionality > 1